@@ -0,0 +1,6 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use miden_objects::fuzz::fuzz_proven_tx;
+
+fuzz_target!(|bytes: &[u8]| fuzz_proven_tx(bytes));
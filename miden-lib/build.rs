@@ -18,6 +18,9 @@ const ASM_DIR: &str = "asm";
 const ASM_MIDEN_DIR: &str = "miden";
 const ASM_NOTE_SCRIPTS_DIR: &str = "note_scripts";
 const ASM_KERNELS_DIR: &str = "kernels/transaction";
+const ASM_TX_KERNEL_DIR: &str = "miden/kernels/tx";
+const KERNEL_ERRORS_FILE: &str = "kernel_errors.rs";
+const KERNEL_ABI_FILE: &str = "kernel_abi.rs";
 
 // PRE-PROCESSING
 // ================================================================================================
@@ -54,6 +57,12 @@ fn main() -> io::Result<()> {
         &target_dir.join(ASM_NOTE_SCRIPTS_DIR),
     )?;
 
+    // generate the mapping of kernel error codes to Rust error variants
+    generate_kernel_errors(&source_dir, &Path::new(&build_dir).join(KERNEL_ERRORS_FILE))?;
+
+    // generate the description of the procedures and events of the transaction kernel
+    generate_kernel_abi(&source_dir, &Path::new(&build_dir).join(KERNEL_ABI_FILE))?;
+
     Ok(())
 }
 
@@ -157,6 +166,294 @@ fn compile_kernels(source_dir: &Path, target_dir: &Path) -> io::Result<()> {
     Ok(())
 }
 
+// GENERATE KERNEL ERRORS
+// ================================================================================================
+
+/// Generates the `KernelError` enum from the error constants defined in the MASM code.
+///
+/// Every constant named `ERR_<NAME>` becomes a variant named after `<NAME>` in upper camel case,
+/// and the comment lines directly preceding the constant become the documentation and the message
+/// of the variant.
+fn generate_kernel_errors(source_dir: &Path, target_file: &Path) -> io::Result<()> {
+    let mut errors = Vec::new();
+
+    let mut todo = vec![source_dir.to_path_buf()];
+    while let Some(dir) = todo.pop() {
+        for entry in fs::read_dir(dir)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                todo.push(path);
+            } else if is_masm_file(&path)? {
+                errors.extend(extract_errors(&fs::read_to_string(&path)?)?);
+            }
+        }
+    }
+
+    errors.sort_by_key(|(code, ..)| *code);
+    for pair in errors.windows(2) {
+        if pair[0].0 == pair[1].0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("error code {:#010X} is defined more than once", pair[0].0),
+            ));
+        }
+    }
+
+    let mut variants = String::new();
+    let mut from_code = String::new();
+    let mut to_code = String::new();
+    let mut messages = String::new();
+    for (code, name, message) in errors.iter() {
+        variants.push_str(&format!("    /// {message}\n    {name},\n"));
+        from_code.push_str(&format!("            {code:#010X} => Some(Self::{name}),\n"));
+        to_code.push_str(&format!("            Self::{name} => {code:#010X},\n"));
+        messages.push_str(&format!("            Self::{name} => {message:?},\n"));
+    }
+
+    let contents = format!(
+        "\
+/// Errors raised by failed assertions in the transaction kernel and the Miden library.
+///
+/// This enum is generated from the error codes defined in the MASM code.
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum KernelError {{
+{variants}}}
+
+impl KernelError {{
+    /// Returns the error associated with the provided error code, or None if the code is not a
+    /// known kernel error code.
+    pub fn from_code(code: u32) -> Option<Self> {{
+        match code {{
+{from_code}            _ => None,
+        }}
+    }}
+
+    /// Returns the error code of this error.
+    pub fn code(&self) -> u32 {{
+        match self {{
+{to_code}        }}
+    }}
+
+    /// Returns a description of this error.
+    pub fn message(&self) -> &'static str {{
+        match self {{
+{messages}        }}
+    }}
+}}
+"
+    );
+
+    fs::write(target_file, contents)
+}
+
+/// Extracts (code, variant name, message) tuples of all error constants in the provided MASM
+/// source.
+fn extract_errors(source: &str) -> io::Result<Vec<(u32, String, String)>> {
+    let mut errors = Vec::new();
+    let mut comment = Vec::new();
+
+    for line in source.lines() {
+        let line = line.trim();
+        if let Some(text) = line.strip_prefix('#') {
+            comment.push(text.trim().to_string());
+        } else if let Some(constant) = line.strip_prefix("const.ERR_") {
+            let (name, value) = constant.split_once('=').ok_or_else(|| {
+                io::Error::new(io::ErrorKind::InvalidData, format!("invalid constant: {line}"))
+            })?;
+            let value = value.split_whitespace().next().unwrap_or_default();
+            let code = match value.strip_prefix("0x") {
+                Some(hex) => u32::from_str_radix(hex, 16),
+                None => value.parse(),
+            }
+            .map_err(|_| {
+                io::Error::new(io::ErrorKind::InvalidData, format!("invalid error code: {line}"))
+            })?;
+
+            let message = comment.join(" ");
+            errors.push((code, to_upper_camel_case(name), message));
+            comment.clear();
+        } else {
+            comment.clear();
+        }
+    }
+
+    Ok(errors)
+}
+
+/// Converts a SCREAMING_SNAKE_CASE identifier into UpperCamelCase.
+fn to_upper_camel_case(name: &str) -> String {
+    name.split('_')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_string() + &chars.as_str().to_lowercase(),
+                None => String::new(),
+            }
+        })
+        .collect()
+}
+
+// GENERATE KERNEL ABI
+// ================================================================================================
+
+/// Generates the descriptions of the procedures exported by the transaction kernel and of the
+/// events emitted by it from the MASM code.
+///
+/// Every procedure exported from the kernel API is described by its name, its documentation, and
+/// the stack inputs and outputs listed in the `Stack:`/`Inputs:` and `Output:`/`Outputs:` lines
+/// of its documentation. Every constant named `<NAME>_EVENT` in the kernel modules becomes an
+/// event described by the comment lines directly preceding the constant.
+fn generate_kernel_abi(source_dir: &Path, target_file: &Path) -> io::Result<()> {
+    let procedures = extract_kernel_procedures(&fs::read_to_string(
+        source_dir.join(ASM_KERNELS_DIR).join("api.masm"),
+    )?)?;
+
+    let mut events = Vec::new();
+    for dir in [source_dir.join(ASM_KERNELS_DIR), source_dir.join(ASM_TX_KERNEL_DIR)] {
+        for path in get_masm_files(dir)? {
+            events.extend(extract_events(&fs::read_to_string(&path)?)?);
+        }
+    }
+
+    events.sort_by_key(|(id, ..)| *id);
+    for pair in events.windows(2) {
+        if pair[0].0 == pair[1].0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("event id {} is defined more than once", pair[0].0),
+            ));
+        }
+    }
+
+    let mut procedure_items = String::new();
+    for (name, description, inputs, outputs) in procedures.iter() {
+        procedure_items.push_str(&format!(
+            "    KernelProcedure {{
+        name: {name:?},
+        description: {description:?},
+        inputs: &{inputs:?},
+        outputs: &{outputs:?},
+    }},
+"
+        ));
+    }
+
+    let mut event_items = String::new();
+    for (id, name, description) in events.iter() {
+        event_items.push_str(&format!(
+            "    KernelEvent {{ name: {name:?}, id: {id}, description: {description:?} }},\n"
+        ));
+    }
+
+    let contents = format!(
+        "\
+/// The procedures exported by the transaction kernel, in the order in which they are defined.
+///
+/// This list is generated from the kernel API defined in the MASM code.
+const KERNEL_PROCEDURES: &[KernelProcedure] = &[
+{procedure_items}];
+
+/// The events emitted by the transaction kernel, sorted by their IDs.
+///
+/// This list is generated from the event constants defined in the MASM code.
+const KERNEL_EVENTS: &[KernelEvent] = &[
+{event_items}];
+"
+    );
+
+    fs::write(target_file, contents)
+}
+
+/// Extracts (name, description, inputs, outputs) tuples of all procedures exported from the
+/// provided MASM source.
+#[allow(clippy::type_complexity)]
+fn extract_kernel_procedures(
+    source: &str,
+) -> io::Result<Vec<(String, String, Vec<String>, Vec<String>)>> {
+    let mut procedures = Vec::new();
+    let mut doc = Vec::new();
+
+    for line in source.lines() {
+        let line = line.trim();
+        if let Some(text) = line.strip_prefix("#!") {
+            doc.push(text.trim().to_string());
+            continue;
+        }
+
+        if let Some(name) = line.strip_prefix("export.") {
+            let name = name.split(['.', ' ']).next().unwrap_or_default().to_string();
+            let mut description = Vec::new();
+            let mut inputs = None;
+            let mut outputs = None;
+            for text in doc.iter() {
+                if let Some(stack) = text.strip_prefix("Stack:").or(text.strip_prefix("Inputs:")) {
+                    inputs = Some(parse_stack(stack));
+                } else if let Some(stack) =
+                    text.strip_prefix("Output:").or(text.strip_prefix("Outputs:"))
+                {
+                    outputs = Some(parse_stack(stack));
+                } else {
+                    description.push(text.as_str());
+                }
+            }
+
+            let (Some(inputs), Some(outputs)) = (inputs, outputs) else {
+                return Err(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "kernel procedure {name} does not document its stack inputs and outputs"
+                    ),
+                ));
+            };
+
+            let description = description.join("\n").trim().replace("\n\n\n", "\n\n");
+            procedures.push((name, description, inputs, outputs));
+        }
+        doc.clear();
+    }
+
+    Ok(procedures)
+}
+
+/// Extracts (id, name, description) tuples of all event constants in the provided MASM source.
+fn extract_events(source: &str) -> io::Result<Vec<(u32, String, String)>> {
+    let mut events = Vec::new();
+    let mut comment = Vec::new();
+
+    for line in source.lines() {
+        let line = line.trim();
+        if let Some(text) = line.strip_prefix('#') {
+            comment.push(text.trim().to_string());
+            continue;
+        }
+
+        if let Some((name, value)) = line.strip_prefix("const.").and_then(|c| c.split_once('=')) {
+            if name.ends_with("_EVENT") {
+                let value = value.split_whitespace().next().unwrap_or_default();
+                let id = value.parse().map_err(|_| {
+                    io::Error::new(io::ErrorKind::InvalidData, format!("invalid event id: {line}"))
+                })?;
+                events.push((id, name.to_string(), comment.join(" ")));
+            }
+        }
+        comment.clear();
+    }
+
+    Ok(events)
+}
+
+/// Splits the stack description `[a, b, ...]` into its elements.
+fn parse_stack(stack: &str) -> Vec<String> {
+    stack
+        .trim()
+        .trim_start_matches('[')
+        .trim_end_matches(']')
+        .split(',')
+        .map(|element| element.trim().to_string())
+        .filter(|element| !element.is_empty())
+        .collect()
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -0,0 +1,310 @@
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use miden_objects::{
+    accounts::{AccountCode, AccountId},
+    assembly::ModuleAst,
+    assets::Asset,
+    AccountError, Digest, Felt, Word,
+};
+
+use super::TransactionKernel;
+
+// ACCOUNT COMPONENT
+// ================================================================================================
+
+/// The compiled code of an account component together with the names of its exported procedures.
+///
+/// Account components are usually declared via the [account_component!](crate::account_component)
+/// macro, which keeps the MASM source of a component and the Rust constants describing it in a
+/// single place.
+#[derive(Debug, Clone)]
+pub struct AccountComponent {
+    code: AccountCode,
+    procedure_names: Vec<String>,
+}
+
+impl AccountComponent {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [AccountComponent] compiled from the provided MASM source.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - Compilation of the provided source fails.
+    /// - The source exports a procedure which is not in the list of declared procedures.
+    /// - A declared procedure is not exported by the source.
+    pub fn compile(source: &str, procedures: &[&str]) -> Result<Self, AccountError> {
+        let module = ModuleAst::parse(source)
+            .map_err(|err| AccountError::AccountCodeAssemblerError(err.into()))?;
+
+        // the assembler returns the roots of re-exported procedures followed by the roots of local
+        // exported procedures, in the order in which they are declared in the module
+        let procedure_names: Vec<String> = module
+            .reexported_procs()
+            .iter()
+            .map(|proc| proc.name().to_string())
+            .chain(
+                module
+                    .procs()
+                    .iter()
+                    .filter(|proc| proc.is_export)
+                    .map(|proc| proc.name.to_string()),
+            )
+            .collect();
+
+        if let Some(name) = procedure_names.iter().find(|name| !procedures.contains(&name.as_str()))
+        {
+            return Err(AccountError::AccountCodeProcedureNotDeclared(name.clone()));
+        }
+        if let Some(name) =
+            procedures.iter().find(|name| !procedure_names.iter().any(|n| n == *name))
+        {
+            return Err(AccountError::AccountCodeProcedureNotExported(name.to_string()));
+        }
+
+        let code = AccountCode::new(module, &TransactionKernel::assembler())?;
+        debug_assert_eq!(code.num_procedures(), procedure_names.len());
+
+        Ok(Self { code, procedure_names })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a reference to the compiled code of the component.
+    pub fn code(&self) -> &AccountCode {
+        &self.code
+    }
+
+    /// Returns the MAST root of the exported procedure with the specified name, if any.
+    pub fn procedure_root(&self, name: &str) -> Option<Digest> {
+        self.procedure_names
+            .iter()
+            .position(|proc_name| proc_name == name)
+            .map(|index| self.code.procedures()[index])
+    }
+
+    // CONVERSIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Consumes `self` and returns the compiled code of the component.
+    pub fn into_code(self) -> AccountCode {
+        self.code
+    }
+}
+
+// PROCEDURE CALLS
+// ================================================================================================
+
+/// A value which can be passed as an argument to an account procedure.
+pub trait ProcedureArg {
+    /// Appends the elements representing this value to `target`, in the order in which they are
+    /// expected on the stack, starting from the top of the stack.
+    fn write_stack_elements(&self, target: &mut Vec<Felt>);
+}
+
+impl ProcedureArg for Felt {
+    fn write_stack_elements(&self, target: &mut Vec<Felt>) {
+        target.push(*self);
+    }
+}
+
+impl ProcedureArg for u32 {
+    fn write_stack_elements(&self, target: &mut Vec<Felt>) {
+        target.push(Felt::from(*self));
+    }
+}
+
+impl ProcedureArg for Word {
+    fn write_stack_elements(&self, target: &mut Vec<Felt>) {
+        // a word [a0, a1, a2, a3] is laid out on the stack as [a3, a2, a1, a0]
+        target.extend(self.iter().rev());
+    }
+}
+
+impl ProcedureArg for AccountId {
+    fn write_stack_elements(&self, target: &mut Vec<Felt>) {
+        target.push((*self).into());
+    }
+}
+
+impl ProcedureArg for Asset {
+    fn write_stack_elements(&self, target: &mut Vec<Felt>) {
+        Word::from(*self).write_stack_elements(target);
+    }
+}
+
+/// The arguments of a call to a procedure of an account component.
+///
+/// This trait is implemented by the argument structs generated by the
+/// [account_component!](crate::account_component) macro.
+pub trait ProcedureCall {
+    /// The name of the called procedure.
+    const PROCEDURE: &'static str;
+
+    /// Returns the arguments of the call in the order in which they are expected on the stack,
+    /// starting from the top of the stack.
+    fn stack_inputs(&self) -> Vec<Felt>;
+
+    /// Returns MASM code which puts the arguments onto the stack and calls the procedure of the
+    /// provided component.
+    ///
+    /// # Errors
+    /// Returns an error if the procedure is not exported by the provided component.
+    fn to_masm(&self, component: &AccountComponent) -> Result<String, AccountError> {
+        let root = component
+            .procedure_root(Self::PROCEDURE)
+            .ok_or_else(|| AccountError::AccountCodeProcedureNotExported(Self::PROCEDURE.into()))?;
+
+        let mut code = String::new();
+        let inputs = self.stack_inputs();
+        if !inputs.is_empty() {
+            // the last pushed element ends up on top of the stack
+            let elements: Vec<String> =
+                inputs.iter().rev().map(|e| e.as_int().to_string()).collect();
+            code.push_str(&format!("push.{} ", elements.join(".")));
+        }
+        code.push_str(&format!("call.{}", root.to_hex()));
+
+        Ok(code)
+    }
+}
+
+// ACCOUNT COMPONENT MACRO
+// ================================================================================================
+
+/// Items used by the code generated by the [account_component!](crate::account_component) macro.
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::vec::Vec;
+
+    pub use miden_objects::{AccountError, Felt};
+}
+
+/// Declares an account component from its MASM source, its storage slots and the procedures it
+/// exports.
+///
+/// The macro generates a module with the specified name containing:
+/// - `SOURCE`: the MASM source of the component.
+/// - A `u8` constant with the index of each declared storage slot.
+/// - `PROCEDURES`: the names of the exported procedures.
+/// - `compile()`: a function returning the compiled [AccountComponent], which fails if the
+///   procedures exported by the source do not match the declared procedures.
+/// - For each procedure, a struct with the arguments of the procedure implementing
+///   [ProcedureCall]. The fields are expected on the stack in the order in which they are
+///   declared, with the first field on top of the stack.
+///
+/// The roots of the procedures can be obtained from the compiled component via
+/// [AccountComponent::procedure_root()].
+///
+/// ```
+/// use miden_lib::account_component;
+/// use miden_objects::Felt;
+///
+/// account_component! {
+///     /// A component with a counter.
+///     pub mod counter {
+///         source: "
+///             use.miden::account
+///
+///             export.increment
+///                 push.0 exec.account::get_item
+///                 movup.4 add push.0 exec.account::set_item dropw dropw
+///             end
+///         ",
+///         storage: {
+///             /// The value of the counter.
+///             COUNTER_SLOT = 0,
+///         },
+///         procedures: {
+///             /// Increments the counter by the specified amount.
+///             increment => IncrementArgs { amount: miden_objects::Felt },
+///         },
+///     }
+/// }
+///
+/// use miden_lib::accounts::component::ProcedureCall;
+///
+/// let component = counter::compile().unwrap();
+/// let call = counter::IncrementArgs { amount: Felt::new(5) }.to_masm(&component).unwrap();
+/// assert!(call.starts_with("push.5 call."));
+/// ```
+#[macro_export]
+macro_rules! account_component {
+    (
+        $(#[$meta:meta])*
+        $vis:vis mod $name:ident {
+            source: $source:expr,
+            storage: {
+                $($(#[$slot_meta:meta])* $slot:ident = $slot_index:expr),* $(,)?
+            },
+            procedures: {
+                $(
+                    $(#[$proc_meta:meta])*
+                    $proc:ident => $args:ident { $($arg:ident: $arg_ty:ty),* $(,)? }
+                ),* $(,)?
+            } $(,)?
+        }
+    ) => {
+        $(#[$meta])*
+        $vis mod $name {
+            #[allow(unused_imports)]
+            use super::*;
+
+            /// The MASM source of the component.
+            pub const SOURCE: &str = $source;
+
+            $(
+                $(#[$slot_meta])*
+                pub const $slot: u8 = $slot_index;
+            )*
+
+            /// The names of the procedures exported by the component.
+            pub const PROCEDURES: &[&str] = &[$(stringify!($proc)),*];
+
+            /// Returns the compiled component.
+            ///
+            /// # Errors
+            /// Returns an error if the source fails to compile, or if the procedures exported by
+            /// the source do not match the declared procedures.
+            pub fn compile() -> ::core::result::Result<
+                $crate::accounts::component::AccountComponent,
+                $crate::accounts::component::__private::AccountError,
+            > {
+                $crate::accounts::component::AccountComponent::compile(SOURCE, PROCEDURES)
+            }
+
+            $(
+                $(#[$proc_meta])*
+                #[derive(Debug, Clone, PartialEq, Eq)]
+                pub struct $args {
+                    $(pub $arg: $arg_ty,)*
+                }
+
+                impl $crate::accounts::component::ProcedureCall for $args {
+                    const PROCEDURE: &'static str = stringify!($proc);
+
+                    fn stack_inputs(
+                        &self,
+                    ) -> $crate::accounts::component::__private::Vec<
+                        $crate::accounts::component::__private::Felt,
+                    > {
+                        #[allow(unused_mut)]
+                        let mut inputs = $crate::accounts::component::__private::Vec::new();
+                        $(
+                            $crate::accounts::component::ProcedureArg::write_stack_elements(
+                                &self.$arg,
+                                &mut inputs,
+                            );
+                        )*
+                        inputs
+                    }
+                }
+            )*
+        }
+    };
+}
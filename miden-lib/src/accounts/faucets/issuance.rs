@@ -0,0 +1,283 @@
+use alloc::string::ToString;
+
+use miden_objects::{
+    accounts::{Account, AccountId, AccountStorage, AccountStub, AccountType},
+    crypto::merkle::{LeafIndex, MerklePath},
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    AccountError, Felt, Word, ZERO,
+};
+
+use super::MAX_MAX_SUPPLY;
+use crate::transaction::memory::FAUCET_STORAGE_DATA_SLOT;
+
+// FAUCET ISSUANCE
+// ================================================================================================
+
+/// Issuance state of a fungible faucet created via [create_basic_fungible_faucet()].
+///
+/// The state is decoded from two storage slots of the faucet:
+/// - slot [FaucetIssuance::METADATA_SLOT] contains the token metadata as
+///   [max_supply, decimals, token_symbol, 0].
+/// - slot [FaucetIssuance::ISSUANCE_SLOT] contains the total issuance as
+///   [0, 0, 0, total_issuance].
+///
+/// [create_basic_fungible_faucet()]: super::create_basic_fungible_faucet
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FaucetIssuance {
+    faucet_id: AccountId,
+    max_supply: u64,
+    total_issuance: u64,
+}
+
+impl FaucetIssuance {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// The index of the storage slot containing the token metadata of the faucet.
+    pub const METADATA_SLOT: u8 = 1;
+
+    /// The index of the storage slot containing the total issuance of the faucet.
+    pub const ISSUANCE_SLOT: u8 = FAUCET_STORAGE_DATA_SLOT;
+
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the issuance state of the specified faucet account.
+    ///
+    /// # Errors
+    /// Returns an error if the account is not a fungible faucet, or if its storage does not
+    /// contain a valid issuance state.
+    pub fn from_account(account: &Account) -> Result<Self, AccountError> {
+        Self::from_storage(account.id(), account.storage())
+    }
+
+    /// Returns the issuance state of the specified faucet decoded from the provided storage.
+    ///
+    /// # Errors
+    /// Returns an error if the account is not a fungible faucet, or if the storage does not
+    /// contain a valid issuance state.
+    pub fn from_storage(
+        faucet_id: AccountId,
+        storage: &AccountStorage,
+    ) -> Result<Self, AccountError> {
+        Self::from_slot_values(
+            faucet_id,
+            storage.get_item(Self::METADATA_SLOT).into(),
+            storage.get_item(Self::ISSUANCE_SLOT).into(),
+        )
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the ID of the faucet.
+    pub fn faucet_id(&self) -> AccountId {
+        self.faucet_id
+    }
+
+    /// Returns the maximum supply of the faucet.
+    pub fn max_supply(&self) -> u64 {
+        self.max_supply
+    }
+
+    /// Returns the total amount of assets issued by the faucet which have not been burned.
+    pub fn total_issuance(&self) -> u64 {
+        self.total_issuance
+    }
+
+    /// Returns the amount of assets which can still be minted by the faucet.
+    pub fn remaining_supply(&self) -> u64 {
+        self.max_supply - self.total_issuance
+    }
+
+    /// Returns the value of the issuance storage slot which encodes this issuance state.
+    pub fn to_slot_value(&self) -> Word {
+        [ZERO, ZERO, ZERO, Felt::new(self.total_issuance)]
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Increases the total issuance by the specified amount.
+    ///
+    /// # Errors
+    /// Returns an error if the total issuance would exceed the maximum supply.
+    pub fn mint(&mut self, amount: u64) -> Result<(), AccountError> {
+        if amount > self.remaining_supply() {
+            return Err(AccountError::FungibleFaucetInvalidIssuance(format!(
+                "Minting {amount} assets would exceed the max supply of {}",
+                self.max_supply
+            )));
+        }
+
+        self.total_issuance += amount;
+        Ok(())
+    }
+
+    /// Decreases the total issuance by the specified amount.
+    ///
+    /// # Errors
+    /// Returns an error if the amount is greater than the total issuance.
+    pub fn burn(&mut self, amount: u64) -> Result<(), AccountError> {
+        if amount > self.total_issuance {
+            return Err(AccountError::FungibleFaucetInvalidIssuance(format!(
+                "Burning {amount} assets would exceed the total issuance of {}",
+                self.total_issuance
+            )));
+        }
+
+        self.total_issuance -= amount;
+        Ok(())
+    }
+
+    /// Writes this issuance state into the issuance slot of the provided storage.
+    ///
+    /// # Errors
+    /// Returns an error if the issuance slot of the storage cannot be updated.
+    pub fn write_into_storage(&self, storage: &mut AccountStorage) -> Result<(), AccountError> {
+        storage.set_item(Self::ISSUANCE_SLOT, self.to_slot_value())?;
+        Ok(())
+    }
+
+    // PROOFS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a proof of the issuance state of the specified faucet account, which can be
+    /// verified against the public state of the account via [FaucetIssuanceProof::verify()].
+    ///
+    /// # Errors
+    /// Returns an error if the account is not a fungible faucet, or if its storage does not
+    /// contain a valid issuance state.
+    pub fn prove(account: &Account) -> Result<FaucetIssuanceProof, AccountError> {
+        // make sure the account contains a valid issuance state
+        Self::from_account(account)?;
+
+        let storage = account.storage();
+        let open = |index: u8| {
+            let leaf_index =
+                LeafIndex::new(index as u64).expect("index is u8 - index within range");
+            storage.slots().open(&leaf_index).path
+        };
+
+        Ok(FaucetIssuanceProof {
+            metadata: storage.get_item(Self::METADATA_SLOT).into(),
+            issuance: storage.get_item(Self::ISSUANCE_SLOT).into(),
+            metadata_path: open(Self::METADATA_SLOT),
+            issuance_path: open(Self::ISSUANCE_SLOT),
+        })
+    }
+
+    // HELPER FUNCTIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Decodes the issuance state of the specified faucet from the values of the metadata and the
+    /// issuance slots.
+    fn from_slot_values(
+        faucet_id: AccountId,
+        metadata: Word,
+        issuance: Word,
+    ) -> Result<Self, AccountError> {
+        if faucet_id.account_type() != AccountType::FungibleFaucet {
+            return Err(AccountError::AccountIdNotFungibleFaucet(faucet_id));
+        }
+
+        let max_supply = metadata[0].as_int();
+        if max_supply > MAX_MAX_SUPPLY {
+            return Err(AccountError::FungibleFaucetInvalidMetadata(
+                "Max supply must be < 2^63".to_string(),
+            ));
+        }
+
+        if issuance[..3] != [ZERO; 3] {
+            return Err(AccountError::FungibleFaucetInvalidIssuance(
+                "Issuance slot must be [0, 0, 0, total_issuance]".to_string(),
+            ));
+        }
+        let total_issuance = issuance[3].as_int();
+        if total_issuance > max_supply {
+            return Err(AccountError::FungibleFaucetInvalidIssuance(
+                "Total issuance must not exceed max supply".to_string(),
+            ));
+        }
+
+        Ok(Self { faucet_id, max_supply, total_issuance })
+    }
+}
+
+// FAUCET ISSUANCE PROOF
+// ================================================================================================
+
+/// Merkle openings of the metadata and the issuance slots of a fungible faucet.
+///
+/// The proof allows anyone who knows the public state of the faucet (i.e., its [AccountStub]) to
+/// verify the issuance state claimed by the faucet without having access to the full storage of
+/// the faucet. To trust the verified issuance state, the hash of the stub must be checked against
+/// the faucet hash recorded in the account database of the chain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FaucetIssuanceProof {
+    metadata: Word,
+    issuance: Word,
+    metadata_path: MerklePath,
+    issuance_path: MerklePath,
+}
+
+impl FaucetIssuanceProof {
+    /// Returns the value of the metadata slot opened by this proof.
+    pub fn metadata(&self) -> Word {
+        self.metadata
+    }
+
+    /// Returns the value of the issuance slot opened by this proof.
+    pub fn issuance(&self) -> Word {
+        self.issuance
+    }
+
+    /// Verifies this proof against the provided public state of the faucet and returns the
+    /// proven issuance state.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The stub does not belong to a fungible faucet.
+    /// - The opening of the metadata or the issuance slot is invalid against the storage root of
+    ///   the stub.
+    /// - The opened slots do not contain a valid issuance state.
+    pub fn verify(&self, faucet: &AccountStub) -> Result<FaucetIssuance, AccountError> {
+        let storage_root = faucet.storage_root();
+        let slots = [
+            (FaucetIssuance::METADATA_SLOT, self.metadata, &self.metadata_path),
+            (FaucetIssuance::ISSUANCE_SLOT, self.issuance, &self.issuance_path),
+        ];
+        for (index, value, path) in slots {
+            if !path.verify(index as u64, value.into(), &storage_root) {
+                return Err(AccountError::StorageSlotInvalidProof(index));
+            }
+        }
+
+        FaucetIssuance::from_slot_values(faucet.id(), self.metadata, self.issuance)
+    }
+}
+
+impl Serializable for FaucetIssuanceProof {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.metadata.write_into(target);
+        self.issuance.write_into(target);
+        self.metadata_path.write_into(target);
+        self.issuance_path.write_into(target);
+    }
+}
+
+impl Deserializable for FaucetIssuanceProof {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let metadata = Word::read_from(source)?;
+        let issuance = Word::read_from(source)?;
+        let metadata_path = MerklePath::read_from(source)?;
+        let issuance_path = MerklePath::read_from(source)?;
+
+        Ok(Self {
+            metadata,
+            issuance,
+            metadata_path,
+            issuance_path,
+        })
+    }
+}
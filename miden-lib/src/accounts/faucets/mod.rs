@@ -1,16 +1,24 @@
-use alloc::string::ToString;
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
 
 use miden_objects::{
     accounts::{
         Account, AccountCode, AccountId, AccountStorage, AccountStorageType, AccountType, SlotItem,
         StorageSlot,
     },
-    assembly::LibraryPath,
-    assets::{AssetVault, TokenSymbol},
-    AccountError, Felt, Word, ZERO,
+    assembly::{LibraryPath, ProgramAst},
+    assets::{Asset, AssetVault, TokenSymbol},
+    crypto::merkle::Smt,
+    AccountError, Felt, TransactionScriptError, Word, ZERO,
 };
 
 use super::{AuthScheme, Library, MidenLib, TransactionKernel};
+use crate::transaction::memory::FAUCET_STORAGE_DATA_SLOT;
+
+mod issuance;
+pub use issuance::{FaucetIssuance, FaucetIssuanceProof};
 
 // FUNGIBLE FAUCET
 // ================================================================================================
@@ -28,8 +36,8 @@ const MAX_DECIMALS: u8 = 12;
 /// `distribute` requires authentication. The authentication procedure is defined by the specified
 /// authentication scheme. `burn` does not require authentication and can be called by anyone.
 ///
-/// Public key information for the scheme is stored in the account storage at slot 0. The token
-/// metadata is stored in the account storage at slot 1.
+/// Public key information for the scheme is stored in the account storage at slot 0, and the
+/// version of the scheme at slot 252. The token metadata is stored in the account storage at slot 1.
 pub fn create_basic_fungible_faucet(
     init_seed: [u8; 32],
     symbol: TokenSymbol,
@@ -41,9 +49,7 @@ pub fn create_basic_fungible_faucet(
     // Atm we only have RpoFalcon512 as authentication scheme and this is also the default in the
     // faucet contract, so we can just use the public key as storage slot 0.
     // TODO: consider using a trait when we have more auth schemes.
-    let auth_data: Word = match auth_scheme {
-        AuthScheme::RpoFalcon512 { pub_key } => pub_key.into(),
-    };
+    let auth_data_item = auth_scheme.storage_slot();
 
     let miden = MidenLib::default();
     let path = "miden::contracts::faucets::basic_fungible";
@@ -71,16 +77,15 @@ pub fn create_basic_fungible_faucet(
     // We store the authentication data and the token metadata in the account storage:
     // - slot 0: authentication data
     // - slot 1: token metadata as [max_supply, decimals, token_symbol, 0]
+    // - slot 252: version of the authentication scheme
     let account_storage = AccountStorage::new(vec![
-        SlotItem {
-            index: 0,
-            slot: StorageSlot::new_value(auth_data),
-        },
+        auth_data_item,
         SlotItem {
             index: 1,
             slot: StorageSlot::new_value(metadata),
         },
-    ])?;
+    ])?
+    .with_auth_scheme_version(auth_scheme.version().into());
     let account_vault = AssetVault::new(&[]).expect("error on empty vault");
 
     let account_seed = AccountId::get_account_seed(
@@ -96,3 +101,116 @@ pub fn create_basic_fungible_faucet(
         account_seed,
     ))
 }
+
+// NON-FUNGIBLE FAUCET
+// ================================================================================================
+
+/// Creates a new faucet account with basic non-fungible faucet interface, account storage type,
+/// and specified authentication scheme.
+///
+/// The basic non-fungible faucet interface exposes two procedures:
+/// - `distribute`, which mints a non-fungible asset and creates a note for the provided recipient.
+/// - `burn`, which burns the provided asset.
+///
+/// `distribute` requires authentication. The authentication procedure is defined by the specified
+/// authentication scheme. `burn` does not require authentication and can be called by anyone.
+///
+/// Public key information for the scheme is stored in the account storage at slot 0, and the
+/// version of the scheme at slot 252. The assets issued by the faucet are tracked by the kernel in
+/// a storage map at slot 254, which is initially empty.
+pub fn create_basic_non_fungible_faucet(
+    init_seed: [u8; 32],
+    account_storage_type: AccountStorageType,
+    auth_scheme: AuthScheme,
+) -> Result<(Account, Word), AccountError> {
+    let auth_data_item = auth_scheme.storage_slot();
+
+    let miden = MidenLib::default();
+    let path = "miden::contracts::faucets::basic_non_fungible";
+    let faucet_code_ast = miden
+        .get_module_ast(&LibraryPath::new(path).unwrap())
+        .expect("Getting module AST failed");
+
+    let account_assembler = TransactionKernel::assembler();
+    let account_code = AccountCode::new(faucet_code_ast.clone(), &account_assembler)?;
+
+    let account_storage = AccountStorage::new(vec![
+        auth_data_item,
+        SlotItem {
+            index: FAUCET_STORAGE_DATA_SLOT,
+            slot: StorageSlot::new_map(*Smt::new().root()),
+        },
+    ])?
+    .with_auth_scheme_version(auth_scheme.version().into());
+    let account_vault = AssetVault::new(&[]).expect("error on empty vault");
+
+    let account_seed = AccountId::get_account_seed(
+        init_seed,
+        AccountType::NonFungibleFaucet,
+        account_storage_type,
+        account_code.root(),
+        account_storage.root(),
+    )?;
+    let account_id = AccountId::new(account_seed, account_code.root(), account_storage.root())?;
+    Ok((
+        Account::new(account_id, account_vault, account_storage, account_code, ZERO),
+        account_seed,
+    ))
+}
+
+// BURN TRANSACTION SCRIPT
+// ================================================================================================
+
+/// Returns the code of a transaction script which burns the provided assets from the faucet the
+/// transaction is executed against.
+///
+/// Each asset is burned via the `miden::asset::burn` procedure. After all assets have been burned,
+/// the script authenticates the transaction using the specified authentication scheme, which also
+/// increments the nonce of the faucet.
+///
+/// The assets to be burned must be provided to the transaction via input notes whose scripts do
+/// not move these assets anywhere else (e.g., into the faucet's vault).
+///
+/// # Errors
+/// Returns an error if the transaction script could not be parsed.
+pub fn create_burn_tx_script_code(
+    assets: &[Asset],
+    auth_scheme: &AuthScheme,
+) -> Result<ProgramAst, TransactionScriptError> {
+    let auth_scheme_procedure = match auth_scheme {
+        AuthScheme::RpoFalcon512 { .. } => "auth_tx_rpo_falcon512",
+    };
+
+    let burn_assets = assets
+        .iter()
+        .map(|asset| {
+            let word: Word = (*asset).into();
+            format!(
+                "
+        push.{}.{}.{}.{}
+        exec.asset::burn dropw",
+                word[0].as_int(),
+                word[1].as_int(),
+                word[2].as_int(),
+                word[3].as_int()
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("");
+
+    let tx_script_src = format!(
+        "
+    use.miden::asset
+    use.miden::contracts::auth::basic->auth_tx
+
+    begin
+        {burn_assets}
+
+        call.auth_tx::{auth_scheme_procedure}
+    end
+    "
+    );
+
+    ProgramAst::parse(&tx_script_src)
+        .map_err(|err| TransactionScriptError::ScriptCompilationError(err.into()))
+}
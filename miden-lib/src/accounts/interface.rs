@@ -0,0 +1,158 @@
+use alloc::{collections::BTreeSet, vec::Vec};
+
+use miden_objects::{accounts::AccountCode, notes::ScriptInfo, Digest};
+
+use super::component::AccountComponent;
+
+// KNOWN INTERFACE
+// ================================================================================================
+
+/// A standard account component shipped with the Miden library, identified by the procedures it
+/// exports.
+///
+/// New variants are added as new standard components are shipped.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[non_exhaustive]
+pub enum KnownInterface {
+    /// The `receive_asset`, `send_asset` and `move_asset_to_note` procedures of the basic wallet
+    /// (`miden::contracts::wallets::basic`).
+    BasicWallet,
+    /// The `distribute` and `burn` procedures of the basic fungible faucet
+    /// (`miden::contracts::faucets::basic_fungible`).
+    BasicFungibleFaucet,
+    /// The `distribute` and `burn` procedures of the basic non-fungible faucet
+    /// (`miden::contracts::faucets::basic_non_fungible`).
+    BasicNonFungibleFaucet,
+    /// The `auth_tx_rpo_falcon512` procedure of the basic authentication component
+    /// (`miden::contracts::auth::basic`).
+    RpoFalcon512Auth,
+    /// The `receive_asset`, `allow_faucet` and `disallow_faucet` procedures of the asset
+    /// allowlist (`miden::contracts::compliance::allowlist`).
+    AssetAllowlist,
+    /// The `sweep_asset` procedure of the inactivity recovery component
+    /// (`miden::contracts::recovery::inactivity`).
+    InactivityRecovery,
+    /// The `announce_key`, `activate_key` and `cancel_key` procedures of the time-locked key
+    /// rotation component (`miden::contracts::auth::key_rotation`).
+    KeyRotation,
+}
+
+impl KnownInterface {
+    /// All interfaces known to this version of the library.
+    pub const ALL: [Self; 7] = [
+        Self::BasicWallet,
+        Self::BasicFungibleFaucet,
+        Self::BasicNonFungibleFaucet,
+        Self::RpoFalcon512Auth,
+        Self::AssetAllowlist,
+        Self::InactivityRecovery,
+        Self::KeyRotation,
+    ];
+
+    /// Returns the path of the library module exporting the procedures of this interface.
+    pub fn module_path(&self) -> &'static str {
+        match self {
+            Self::BasicWallet => "miden::contracts::wallets::basic",
+            Self::BasicFungibleFaucet => "miden::contracts::faucets::basic_fungible",
+            Self::BasicNonFungibleFaucet => "miden::contracts::faucets::basic_non_fungible",
+            Self::RpoFalcon512Auth => "miden::contracts::auth::basic",
+            Self::AssetAllowlist => "miden::contracts::compliance::allowlist",
+            Self::InactivityRecovery => "miden::contracts::recovery::inactivity",
+            Self::KeyRotation => "miden::contracts::auth::key_rotation",
+        }
+    }
+
+    /// Returns the names of the procedures of this interface.
+    pub fn procedure_names(&self) -> &'static [&'static str] {
+        match self {
+            Self::BasicWallet => &["receive_asset", "send_asset", "move_asset_to_note"],
+            Self::BasicFungibleFaucet | Self::BasicNonFungibleFaucet => &["distribute", "burn"],
+            Self::RpoFalcon512Auth => &["auth_tx_rpo_falcon512"],
+            Self::AssetAllowlist => &["receive_asset", "allow_faucet", "disallow_faucet"],
+            Self::InactivityRecovery => &["sweep_asset"],
+            Self::KeyRotation => &["announce_key", "activate_key", "cancel_key"],
+        }
+    }
+
+    /// Returns the MAST roots of the procedures of this interface.
+    ///
+    /// The roots are obtained by compiling a component which re-exports the procedures of this
+    /// interface from the Miden library.
+    pub fn procedure_roots(&self) -> Vec<Digest> {
+        let mut source = format!("use.{}->component\n", self.module_path());
+        for name in self.procedure_names() {
+            source.push_str(&format!("export.component::{name}\n"));
+        }
+
+        AccountComponent::compile(&source, self.procedure_names())
+            .expect("interfaces of the Miden library must compile")
+            .into_code()
+            .procedures()
+            .to_vec()
+    }
+}
+
+// ACCOUNT INTERFACE
+// ================================================================================================
+
+/// The interface of an account, i.e., the set of MAST roots of the procedures exposed by its code.
+///
+/// An account supports a [KnownInterface] if its code exposes all procedures of the interface.
+/// Since note scripts invoke account procedures by their MAST roots, this allows senders to check
+/// whether the target account of a note can consume the note before creating it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountInterface {
+    procedures: BTreeSet<Digest>,
+}
+
+impl AccountInterface {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the interface exposed by the provided account code.
+    pub fn new(code: &AccountCode) -> Self {
+        Self {
+            procedures: code.procedures().iter().copied().collect(),
+        }
+    }
+
+    /// Returns the known interfaces supported by the provided account code, in the order in which
+    /// they are listed in [KnownInterface::ALL].
+    pub fn detect(code: &AccountCode) -> Vec<KnownInterface> {
+        let interface = Self::new(code);
+        KnownInterface::ALL
+            .into_iter()
+            .filter(|known_interface| interface.supports(*known_interface))
+            .collect()
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns true if the account exposes all procedures of the specified interface.
+    pub fn supports(&self, interface: KnownInterface) -> bool {
+        interface.procedure_roots().iter().all(|root| self.procedures.contains(root))
+    }
+
+    /// Returns true if the account supports the interface required to consume notes with the
+    /// specified script of the Miden library (see
+    /// [standard_script_registry()](crate::notes::standard_script_registry)).
+    ///
+    /// Returns false for scripts which are not provided by this version of the library, as their
+    /// requirements are unknown.
+    pub fn supports_note_script(&self, script_info: &ScriptInfo) -> bool {
+        if script_info.version() != 1 {
+            return false;
+        }
+
+        let required_interface = match script_info.name() {
+            "P2ID" | "P2IDR" | "P2IDT" | "P2PK" | "SWAP" | "SWAPP" | "BASKET_SWAP"
+            | "GROUP_CLAIM" => KnownInterface::BasicWallet,
+            "RECOVERY" => KnownInterface::InactivityRecovery,
+            "VOUCHER" => KnownInterface::BasicFungibleFaucet,
+            _ => return false,
+        };
+
+        self.supports(required_interface)
+    }
+}
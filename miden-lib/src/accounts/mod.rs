@@ -1,4 +1,6 @@
 use super::{auth::AuthScheme, transaction::TransactionKernel, Library, MidenLib};
 
+pub mod component;
 pub mod faucets;
+pub mod interface;
 pub mod wallets;
@@ -0,0 +1,143 @@
+use alloc::collections::BTreeMap;
+
+use miden_objects::{
+    accounts::{Account, AccountId},
+    assets::Asset,
+    notes::{Note, ScriptRegistry},
+    transaction::InputNote,
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+};
+
+use crate::notes::standard_script_registry;
+
+// BALANCE SHEET
+// ================================================================================================
+
+/// Balances of an account aggregated over the account state and a set of unspent notes.
+///
+/// Balances are tracked per faucet and are split into three categories:
+/// - on-account balances, i.e., the assets stored in the vault of the account.
+/// - incoming claimable balances, i.e., the assets of unspent `P2ID` and `P2IDR` notes which
+///   target the account and thus can be consumed by it.
+/// - locked in outgoing balances, i.e., the assets of unspent notes sent by the account which
+///   cannot be consumed by the account itself. These assets have already left the account, but
+///   have not yet been received by their recipients.
+///
+/// For fungible faucets, the balance is the total amount of the assets issued by the faucet; for
+/// non-fungible faucets, the balance is the number of the assets issued by the faucet.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BalanceSheet {
+    on_account: BTreeMap<AccountId, u64>,
+    incoming_claimable: BTreeMap<AccountId, u64>,
+    locked_in_outgoing: BTreeMap<AccountId, u64>,
+}
+
+impl BalanceSheet {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Computes the balance sheet of the specified account from its state and the provided
+    /// unspent notes.
+    ///
+    /// Notes are recognized via the scripts of the standard script registry (see
+    /// [standard_script_registry()]). Notes which neither target the account nor were sent by it
+    /// are ignored.
+    pub fn compute(account: &Account, unspent_notes: &[InputNote]) -> Self {
+        let registry = standard_script_registry().expect("standard scripts are well formed");
+
+        let mut sheet = Self::default();
+        for asset in account.vault().assets() {
+            add_asset(&mut sheet.on_account, asset);
+        }
+
+        for note in unspent_notes.iter().map(InputNote::note) {
+            let balances = if is_claimable_by(note, account.id(), &registry) {
+                &mut sheet.incoming_claimable
+            } else if note.metadata().sender() == account.id() {
+                &mut sheet.locked_in_outgoing
+            } else {
+                continue;
+            };
+
+            for asset in note.assets().iter() {
+                add_asset(balances, *asset);
+            }
+        }
+
+        sheet
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the balances of the assets stored in the vault of the account.
+    pub fn on_account(&self) -> &BTreeMap<AccountId, u64> {
+        &self.on_account
+    }
+
+    /// Returns the balances of the assets of unspent notes which can be consumed by the account.
+    pub fn incoming_claimable(&self) -> &BTreeMap<AccountId, u64> {
+        &self.incoming_claimable
+    }
+
+    /// Returns the balances of the assets of unspent notes sent by the account.
+    pub fn locked_in_outgoing(&self) -> &BTreeMap<AccountId, u64> {
+        &self.locked_in_outgoing
+    }
+
+    /// Returns the balance of the assets issued by the specified faucet which are available to
+    /// the account, i.e., the sum of the on-account and the incoming claimable balances.
+    pub fn available_balance(&self, faucet_id: AccountId) -> u64 {
+        let on_account = self.on_account.get(&faucet_id).copied().unwrap_or_default();
+        let incoming = self.incoming_claimable.get(&faucet_id).copied().unwrap_or_default();
+        on_account + incoming
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Adds the specified asset to the provided per-faucet balances.
+fn add_asset(balances: &mut BTreeMap<AccountId, u64>, asset: Asset) {
+    let (faucet_id, amount) = match asset {
+        Asset::Fungible(asset) => (asset.faucet_id(), asset.amount()),
+        Asset::NonFungible(asset) => (asset.faucet_id(), 1),
+    };
+    *balances.entry(faucet_id).or_default() += amount;
+}
+
+/// Returns true if the specified note is a `P2ID` or a `P2IDR` note targeting the specified
+/// account.
+fn is_claimable_by(note: &Note, account_id: AccountId, registry: &ScriptRegistry) -> bool {
+    match registry.get_note_script(note) {
+        Some(script_info) if matches!(script_info.name(), "P2ID" | "P2IDR") => {
+            note.inputs().values().first() == Some(&account_id.into())
+        },
+        _ => false,
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for BalanceSheet {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.on_account.write_into(target);
+        self.incoming_claimable.write_into(target);
+        self.locked_in_outgoing.write_into(target);
+    }
+}
+
+impl Deserializable for BalanceSheet {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let on_account = BTreeMap::<AccountId, u64>::read_from(source)?;
+        let incoming_claimable = BTreeMap::<AccountId, u64>::read_from(source)?;
+        let locked_in_outgoing = BTreeMap::<AccountId, u64>::read_from(source)?;
+
+        Ok(Self {
+            on_account,
+            incoming_claimable,
+            locked_in_outgoing,
+        })
+    }
+}
@@ -1,31 +1,49 @@
-use alloc::string::{String, ToString};
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+    vec::Vec,
+};
 
 use miden_objects::{
     accounts::{
-        Account, AccountCode, AccountId, AccountStorage, AccountStorageType, AccountType,
-        StorageSlot,
+        Account, AccountCode, AccountId, AccountStorage, AccountStorageType, AccountType, SlotItem,
+        StorageArray, StorageSlot,
     },
-    assembly::ModuleAst,
+    assembly::{ModuleAst, ProgramAst},
     assets::AssetVault,
-    AccountError, Word, ZERO,
+    AccountError, Digest, Felt, Word, ONE, ZERO,
 };
 
 use super::{AuthScheme, TransactionKernel};
 
+mod balance;
+pub use balance::BalanceSheet;
+
+mod rate_limit;
+pub use rate_limit::NoteRateLimit;
+
 // BASIC WALLET
 // ================================================================================================
 
 /// Creates a new account with basic wallet interface, the specified authentication scheme and the account storage type.
 /// Basic wallets can be specified to have either mutable or immutable code.
 ///
-/// The basic wallet interface exposes two procedures:
+/// The basic wallet interface exposes three procedures:
 /// - `receive_asset`, which can be used to add an asset to the account.
 /// - `send_asset`, which can be used to remove an asset from the account and put into a note
 ///    addressed to the specified recipient.
+/// - `move_asset_to_note`, which can be used to remove an asset from the account and add it to a
+///    note previously created by the transaction.
 ///
-/// Both methods require authentication. The authentication procedure is defined by the specified
+/// All methods require authentication. The authentication procedure is defined by the specified
 /// authentication scheme. Public key information for the scheme is stored in the account storage
-/// at slot 0.
+/// at slot 0, and the version of the scheme at slot 252. The scheme can be replaced via the
+/// `set_auth_scheme` procedure (see [create_auth_scheme_migration_tx_script_code()]).
+///
+/// The wallet also exposes a keep-alive authentication procedure for the scheme, which
+/// authenticates transactions that only increment the account nonce.
+///
+/// [create_auth_scheme_migration_tx_script_code()]: crate::create_auth_scheme_migration_tx_script_code
 pub fn create_basic_wallet(
     init_seed: [u8; 32],
     auth_scheme: AuthScheme,
@@ -38,18 +56,547 @@ pub fn create_basic_wallet(
         ));
     }
 
-    let (auth_scheme_procedure, storage_slot_0_data): (&str, Word) = match auth_scheme {
-        AuthScheme::RpoFalcon512 { pub_key } => ("basic::auth_tx_rpo_falcon512", pub_key.into()),
+    let (auth_scheme_procedure, keep_alive_procedure) = match auth_scheme {
+        AuthScheme::RpoFalcon512 { .. } => {
+            ("basic::auth_tx_rpo_falcon512", "basic::keep_alive_rpo_falcon512")
+        },
+    };
+    let auth_data_item = auth_scheme.storage_slot();
+
+    let account_code_string: String = format!(
+        "
+    use.miden::contracts::wallets::basic->basic_wallet
+    use.miden::contracts::auth::basic
+
+    export.basic_wallet::receive_asset
+    export.basic_wallet::send_asset
+    export.basic_wallet::move_asset_to_note
+    export.{auth_scheme_procedure}
+    export.{keep_alive_procedure}
+    export.basic::set_auth_scheme
+
+    "
+    );
+    let account_code_src: &str = &account_code_string;
+
+    let account_code_ast = ModuleAst::parse(account_code_src)
+        .map_err(|e| AccountError::AccountCodeAssemblerError(e.into()))?;
+    let account_assembler = TransactionKernel::assembler();
+    let account_code = AccountCode::new(account_code_ast.clone(), &account_assembler)?;
+
+    let account_storage = AccountStorage::new(vec![auth_data_item])?
+        .with_auth_scheme_version(auth_scheme.version().into());
+    let account_vault = AssetVault::new(&[]).expect("error on empty vault");
+
+    let account_seed = AccountId::get_account_seed(
+        init_seed,
+        account_type,
+        account_storage_type,
+        account_code.root(),
+        account_storage.root(),
+    )?;
+    let account_id = AccountId::new(account_seed, account_code.root(), account_storage.root())?;
+    Ok((
+        Account::new(account_id, account_vault, account_storage, account_code, ZERO),
+        account_seed,
+    ))
+}
+
+// RECOVERABLE WALLET
+// ================================================================================================
+
+/// Creates a new account with basic wallet interface which can be recovered by the specified `heir`
+/// account after `inactivity_period` blocks of inactivity, i.e., a wallet with a dead man's switch.
+///
+/// The recoverable wallet interface exposes four procedures:
+/// - `receive_asset`, `send_asset` and `move_asset_to_note` of the basic wallet interface.
+/// - `sweep_asset`, which can be used by notes sent by the `heir` to move assets out of the
+///   account once the account has been inactive for at least `inactivity_period` blocks. Such
+///   notes can be created via [create_recovery_note](crate::notes::create_recovery_note).
+///
+/// Every transaction authenticated by the owner of the account records the number of the reference
+/// block of the transaction as the last activity height of the account. The account storage is
+/// laid out as follows:
+/// - slot 0: public key information for the authentication scheme.
+/// - slot 1: recovery configuration as [heir_account_id, inactivity_period, 0, 0].
+/// - slot 2: last activity height as [last_active_block, 0, 0, 0].
+pub fn create_recoverable_wallet(
+    init_seed: [u8; 32],
+    auth_scheme: AuthScheme,
+    heir: AccountId,
+    inactivity_period: u32,
+    account_type: AccountType,
+    account_storage_type: AccountStorageType,
+) -> Result<(Account, Word), AccountError> {
+    if matches!(account_type, AccountType::FungibleFaucet | AccountType::NonFungibleFaucet) {
+        return Err(AccountError::AccountIdInvalidFieldElement(
+            "Recoverable wallet accounts cannot have a faucet account type".to_string(),
+        ));
+    }
+
+    let auth_scheme_procedure = match auth_scheme {
+        AuthScheme::RpoFalcon512 { .. } => "inactivity::auth_tx_rpo_falcon512",
+    };
+    let auth_data_item = auth_scheme.storage_slot();
+
+    let account_code_string: String = format!(
+        "
+    use.miden::contracts::wallets::basic->basic_wallet
+    use.miden::contracts::recovery::inactivity
+    use.miden::contracts::auth::basic
+
+    export.basic_wallet::receive_asset
+    export.basic_wallet::send_asset
+    export.basic_wallet::move_asset_to_note
+    export.inactivity::sweep_asset
+    export.{auth_scheme_procedure}
+    export.basic::set_auth_scheme
+
+    "
+    );
+    let account_code_src: &str = &account_code_string;
+
+    let account_code_ast = ModuleAst::parse(account_code_src)
+        .map_err(|e| AccountError::AccountCodeAssemblerError(e.into()))?;
+    let account_assembler = TransactionKernel::assembler();
+    let account_code = AccountCode::new(account_code_ast.clone(), &account_assembler)?;
+
+    let recovery_config = [heir.into(), Felt::from(inactivity_period), ZERO, ZERO];
+    let account_storage = AccountStorage::new(vec![
+        auth_data_item,
+        SlotItem {
+            index: 1,
+            slot: StorageSlot::new_value(recovery_config),
+        },
+        SlotItem {
+            index: 2,
+            slot: StorageSlot::new_value([ZERO; 4]),
+        },
+    ])?
+    .with_auth_scheme_version(auth_scheme.version().into());
+    let account_vault = AssetVault::new(&[]).expect("error on empty vault");
+
+    let account_seed = AccountId::get_account_seed(
+        init_seed,
+        account_type,
+        account_storage_type,
+        account_code.root(),
+        account_storage.root(),
+    )?;
+    let account_id = AccountId::new(account_seed, account_code.root(), account_storage.root())?;
+    Ok((
+        Account::new(account_id, account_vault, account_storage, account_code, ZERO),
+        account_seed,
+    ))
+}
+
+// ALLOWLIST WALLET
+// ================================================================================================
+
+/// Storage slot at which the allowlist of an allowlist wallet is stored.
+const ALLOWLIST_SLOT: u8 = 1;
+
+/// Depth of the storage array holding the allowlist, which is indexed by faucet ID.
+const ALLOWLIST_DEPTH: u8 = 64;
+
+/// Creates a new account with basic wallet interface which only receives assets issued by the
+/// faucets on an allowlist stored in the account storage.
+///
+/// The allowlist wallet interface exposes five procedures:
+/// - `receive_asset`, which can be used to add an asset issued by a faucet on the allowlist to
+///   the account.
+/// - `send_asset` and `move_asset_to_note` of the basic wallet interface.
+/// - `allow_faucet` and `disallow_faucet`, which can be used to add faucets to and remove faucets
+///   from the allowlist. Transactions updating the allowlist can be built via
+///   [build_allowlist_update_script()].
+///
+/// Notes sending assets to the account must add them via
+/// `miden::contracts::compliance::allowlist::receive_asset`; notes which rely on the
+/// `receive_asset` procedure of the basic wallet (e.g., P2ID notes) cannot be consumed by the
+/// account. The account storage is laid out as follows:
+/// - slot 0: public key information for the authentication scheme.
+/// - slot 1: the allowlist as an array of depth 64 indexed by faucet ID, in which the items of
+///   the `allowed_faucets` are set to [1, 0, 0, 0].
+pub fn create_allowlist_wallet(
+    init_seed: [u8; 32],
+    auth_scheme: AuthScheme,
+    allowed_faucets: &[AccountId],
+    account_type: AccountType,
+    account_storage_type: AccountStorageType,
+) -> Result<(Account, Word), AccountError> {
+    if matches!(account_type, AccountType::FungibleFaucet | AccountType::NonFungibleFaucet) {
+        return Err(AccountError::AccountIdInvalidFieldElement(
+            "Allowlist wallet accounts cannot have a faucet account type".to_string(),
+        ));
+    }
+
+    let auth_scheme_procedure = match auth_scheme {
+        AuthScheme::RpoFalcon512 { .. } => "basic::auth_tx_rpo_falcon512",
+    };
+    let auth_data_item = auth_scheme.storage_slot();
+
+    let account_code_string: String = format!(
+        "
+    use.miden::contracts::wallets::basic->basic_wallet
+    use.miden::contracts::compliance::allowlist
+    use.miden::contracts::auth::basic
+
+    export.allowlist::receive_asset
+    export.basic_wallet::send_asset
+    export.basic_wallet::move_asset_to_note
+    export.allowlist::allow_faucet
+    export.allowlist::disallow_faucet
+    export.{auth_scheme_procedure}
+    export.basic::set_auth_scheme
+
+    "
+    );
+    let account_code_src: &str = &account_code_string;
+
+    let account_code_ast = ModuleAst::parse(account_code_src)
+        .map_err(|e| AccountError::AccountCodeAssemblerError(e.into()))?;
+    let account_assembler = TransactionKernel::assembler();
+    let account_code = AccountCode::new(account_code_ast.clone(), &account_assembler)?;
+
+    let allowlist = StorageArray::new(
+        ALLOWLIST_DEPTH,
+        allowed_faucets
+            .iter()
+            .map(|&faucet_id| (faucet_id.into(), [ONE, ZERO, ZERO, ZERO])),
+    )?;
+    let account_storage = AccountStorage::with_arrays(
+        vec![
+            auth_data_item,
+            SlotItem {
+                index: ALLOWLIST_SLOT,
+                slot: StorageSlot::new_array(allowlist.root().into(), ALLOWLIST_DEPTH),
+            },
+        ],
+        BTreeMap::from([(ALLOWLIST_SLOT, allowlist)]),
+    )?
+    .with_auth_scheme_version(auth_scheme.version().into());
+    let account_vault = AssetVault::new(&[]).expect("error on empty vault");
+
+    let account_seed = AccountId::get_account_seed(
+        init_seed,
+        account_type,
+        account_storage_type,
+        account_code.root(),
+        account_storage.root(),
+    )?;
+    let account_id = AccountId::new(account_seed, account_code.root(), account_storage.root())?;
+    Ok((
+        Account::new(account_id, account_vault, account_storage, account_code, ZERO),
+        account_seed,
+    ))
+}
+
+/// Returns a transaction script which adds the faucets in `allow` to and removes the faucets in
+/// `disallow` from the allowlist of an account created via [create_allowlist_wallet()].
+///
+/// The script authenticates the transaction using the specified authentication scheme, so it must
+/// be compiled together with the secret key of the account owner (e.g., via
+/// `TransactionExecutor::compile_tx_script()`). Faucets are added before they are removed, so a
+/// faucet contained in both lists is not on the allowlist after the transaction.
+///
+/// # Errors
+/// Returns an error if the generated script cannot be parsed.
+pub fn build_allowlist_update_script(
+    allow: &[AccountId],
+    disallow: &[AccountId],
+    auth_scheme: AuthScheme,
+) -> Result<ProgramAst, AccountError> {
+    let auth_scheme_procedure = match auth_scheme {
+        AuthScheme::RpoFalcon512 { .. } => "auth_tx_rpo_falcon512",
+    };
+
+    let mut updates = String::new();
+    for &faucet_id in allow {
+        let faucet_id = u64::from(faucet_id);
+        updates.push_str(&format!("push.{faucet_id} call.allowlist::allow_faucet drop\n"));
+    }
+    for &faucet_id in disallow {
+        let faucet_id = u64::from(faucet_id);
+        updates.push_str(&format!("push.{faucet_id} call.allowlist::disallow_faucet drop\n"));
+    }
+
+    let tx_script_src = format!(
+        "
+    use.miden::contracts::compliance::allowlist
+    use.miden::contracts::auth::basic
+
+    begin
+        {updates}
+        call.basic::{auth_scheme_procedure}
+    end
+    "
+    );
+
+    ProgramAst::parse(&tx_script_src).map_err(|e| AccountError::AccountCodeAssemblerError(e.into()))
+}
+
+// RATE LIMITED WALLET
+// ================================================================================================
+
+/// Creates a new account with basic wallet interface which can create at most a configured number
+/// of notes within a window of a configured number of blocks.
+///
+/// The rate limited wallet interface exposes four procedures:
+/// - `receive_asset` and `move_asset_to_note` of the basic wallet interface.
+/// - `send_asset`, which records the created note against the current window and fails if the
+///   number of notes created within the window exceeds the configured maximum.
+/// - the authentication procedure of the specified authentication scheme.
+///
+/// Since `send_asset` is the only exported procedure which creates notes, the limit applies to all
+/// notes created by the account, including notes created by transactions executed on behalf of the
+/// account by the network. The account storage is laid out as follows:
+/// - slot 0: public key information for the authentication scheme.
+/// - slot 1: rate limit configuration as [max_notes, period, 0, 0].
+/// - slot 2: state of the current window as [window_start, num_notes, 0, 0].
+pub fn create_rate_limited_wallet(
+    init_seed: [u8; 32],
+    auth_scheme: AuthScheme,
+    rate_limit: NoteRateLimit,
+    account_type: AccountType,
+    account_storage_type: AccountStorageType,
+) -> Result<(Account, Word), AccountError> {
+    if matches!(account_type, AccountType::FungibleFaucet | AccountType::NonFungibleFaucet) {
+        return Err(AccountError::AccountIdInvalidFieldElement(
+            "Rate limited wallet accounts cannot have a faucet account type".to_string(),
+        ));
+    }
+
+    let auth_scheme_procedure = match auth_scheme {
+        AuthScheme::RpoFalcon512 { .. } => "basic::auth_tx_rpo_falcon512",
+    };
+    let auth_data_item = auth_scheme.storage_slot();
+
+    let account_code_string: String = format!(
+        "
+    use.miden::contracts::wallets::basic->basic_wallet
+    use.miden::contracts::limits::rate_limit
+    use.miden::contracts::auth::basic
+
+    export.basic_wallet::receive_asset
+    export.rate_limit::send_asset
+    export.basic_wallet::move_asset_to_note
+    export.{auth_scheme_procedure}
+    export.basic::set_auth_scheme
+
+    "
+    );
+    let account_code_src: &str = &account_code_string;
+
+    let account_code_ast = ModuleAst::parse(account_code_src)
+        .map_err(|e| AccountError::AccountCodeAssemblerError(e.into()))?;
+    let account_assembler = TransactionKernel::assembler();
+    let account_code = AccountCode::new(account_code_ast.clone(), &account_assembler)?;
+
+    let account_storage = AccountStorage::new(vec![
+        auth_data_item,
+        SlotItem {
+            index: 1,
+            slot: StorageSlot::new_value(rate_limit.into()),
+        },
+        SlotItem {
+            index: 2,
+            slot: StorageSlot::new_value([ZERO; 4]),
+        },
+    ])?
+    .with_auth_scheme_version(auth_scheme.version().into());
+    let account_vault = AssetVault::new(&[]).expect("error on empty vault");
+
+    let account_seed = AccountId::get_account_seed(
+        init_seed,
+        account_type,
+        account_storage_type,
+        account_code.root(),
+        account_storage.root(),
+    )?;
+    let account_id = AccountId::new(account_seed, account_code.root(), account_storage.root())?;
+    Ok((
+        Account::new(account_id, account_vault, account_storage, account_code, ZERO),
+        account_seed,
+    ))
+}
+
+// SCRIPT ALLOWLIST WALLET
+// ================================================================================================
+
+/// Storage slot at which the allowlist of a script allowlist wallet is stored.
+const SCRIPT_ALLOWLIST_SLOT: u8 = 1;
+
+/// Depth of the storage array holding the script allowlist, which is indexed by the most
+/// significant element of the script root.
+const SCRIPT_ALLOWLIST_DEPTH: u8 = 64;
+
+/// Creates a new account with basic wallet interface which can only create notes whose script is
+/// on an allowlist stored in the account storage.
+///
+/// The script allowlist wallet interface exposes seven procedures:
+/// - `receive_asset`, `send_asset` and `move_asset_to_note` of the basic wallet interface.
+/// - `allow_script` and `disallow_script`, which can be used to add note scripts to and remove
+///   note scripts from the allowlist.
+/// - `auth_output_note`, the output note hook of the account, which is invoked by the transaction
+///   kernel for each note created by a transaction and fails if the script of the note is not on
+///   the allowlist.
+/// - the authentication procedure of the specified authentication scheme.
+///
+/// The output note hook requires the details of the recipient of every created note, so
+/// transactions creating notes must provide them via
+/// `TransactionArgs::add_expected_output_note()`. The account storage is laid out as follows:
+/// - slot 0: public key information for the authentication scheme.
+/// - slot 1: the allowlist as an array of depth 64 indexed by the most significant element of the
+///   script root, in which the items of the `allowed_scripts` are set to their roots.
+/// - slot 253: the root of the `auth_output_note` procedure.
+pub fn create_script_allowlist_wallet(
+    init_seed: [u8; 32],
+    auth_scheme: AuthScheme,
+    allowed_scripts: &[Digest],
+    account_type: AccountType,
+    account_storage_type: AccountStorageType,
+) -> Result<(Account, Word), AccountError> {
+    if matches!(account_type, AccountType::FungibleFaucet | AccountType::NonFungibleFaucet) {
+        return Err(AccountError::AccountIdInvalidFieldElement(
+            "Script allowlist wallet accounts cannot have a faucet account type".to_string(),
+        ));
+    }
+
+    let auth_scheme_procedure = match auth_scheme {
+        AuthScheme::RpoFalcon512 { .. } => "basic::auth_tx_rpo_falcon512",
+    };
+    let auth_data_item = auth_scheme.storage_slot();
+
+    let account_code_string: String = format!(
+        "
+    use.miden::contracts::wallets::basic->basic_wallet
+    use.miden::contracts::policies::script_allowlist
+    use.miden::contracts::auth::basic
+
+    export.basic_wallet::receive_asset
+    export.basic_wallet::send_asset
+    export.basic_wallet::move_asset_to_note
+    export.script_allowlist::allow_script
+    export.script_allowlist::disallow_script
+    export.script_allowlist::auth_output_note
+    export.{auth_scheme_procedure}
+    export.basic::set_auth_scheme
+
+    "
+    );
+    let account_code_src: &str = &account_code_string;
+
+    let account_code_ast = ModuleAst::parse(account_code_src)
+        .map_err(|e| AccountError::AccountCodeAssemblerError(e.into()))?;
+    let account_assembler = TransactionKernel::assembler();
+    let account_code = AccountCode::new(account_code_ast.clone(), &account_assembler)?;
+
+    // the output note hook is the auth_output_note procedure of the script allowlist component
+    let output_note_hook_ast = ModuleAst::parse(
+        "
+    use.miden::contracts::policies::script_allowlist
+
+    export.script_allowlist::auth_output_note
+    ",
+    )
+    .map_err(|e| AccountError::AccountCodeAssemblerError(e.into()))?;
+    let output_note_hook =
+        AccountCode::new(output_note_hook_ast, &account_assembler)?.procedures()[0];
+
+    let allowlist = StorageArray::new(
+        SCRIPT_ALLOWLIST_DEPTH,
+        allowed_scripts
+            .iter()
+            .map(|script_root| (script_root[3].as_int(), (*script_root).into())),
+    )?;
+    let account_storage = AccountStorage::with_arrays(
+        vec![
+            auth_data_item,
+            SlotItem {
+                index: SCRIPT_ALLOWLIST_SLOT,
+                slot: StorageSlot::new_array(allowlist.root().into(), SCRIPT_ALLOWLIST_DEPTH),
+            },
+        ],
+        BTreeMap::from([(SCRIPT_ALLOWLIST_SLOT, allowlist)]),
+    )?
+    .with_auth_scheme_version(auth_scheme.version().into())
+    .with_output_note_hook(output_note_hook.into());
+    let account_vault = AssetVault::new(&[]).expect("error on empty vault");
+
+    let account_seed = AccountId::get_account_seed(
+        init_seed,
+        account_type,
+        account_storage_type,
+        account_code.root(),
+        account_storage.root(),
+    )?;
+    let account_id = AccountId::new(account_seed, account_code.root(), account_storage.root())?;
+    Ok((
+        Account::new(account_id, account_vault, account_storage, account_code, ZERO),
+        account_seed,
+    ))
+}
+
+// TX SCRIPT ALLOWLIST WALLET
+// ================================================================================================
+
+/// Storage slot at which the allowlist of a transaction script allowlist wallet is stored.
+const TX_SCRIPT_ALLOWLIST_SLOT: u8 = 1;
+
+/// Depth of the storage array holding the transaction script allowlist, which is indexed by the
+/// most significant element of the script root.
+const TX_SCRIPT_ALLOWLIST_DEPTH: u8 = 64;
+
+/// Creates a new account with basic wallet interface which approves transactions executing a
+/// transaction script on an allowlist stored in the account storage without a signature.
+///
+/// The transaction script allowlist wallet interface exposes seven procedures:
+/// - `receive_asset`, `send_asset` and `move_asset_to_note` of the basic wallet interface.
+/// - `allow_tx_script` and `disallow_tx_script`, which can be used to add transaction scripts to
+///   and remove transaction scripts from the allowlist. Transactions updating the allowlist can be
+///   built via [build_tx_script_allowlist_update_script()].
+/// - the authentication procedure of the specified authentication scheme, which increments the
+///   nonce without a signature if the transaction executes a script on the allowlist and does not
+///   consume any notes, and requires a signature by the account owner otherwise.
+///
+/// Since the args of a transaction script are not restricted, anyone can execute a transaction
+/// script on the allowlist against the account with arbitrary args. The account storage is laid
+/// out as follows:
+/// - slot 0: public key information for the authentication scheme.
+/// - slot 1: the allowlist as an array of depth 64 indexed by the most significant element of the
+///   script root, in which the items of the `allowed_tx_scripts` are set to their roots.
+pub fn create_tx_script_allowlist_wallet(
+    init_seed: [u8; 32],
+    auth_scheme: AuthScheme,
+    allowed_tx_scripts: &[Digest],
+    account_type: AccountType,
+    account_storage_type: AccountStorageType,
+) -> Result<(Account, Word), AccountError> {
+    if matches!(account_type, AccountType::FungibleFaucet | AccountType::NonFungibleFaucet) {
+        return Err(AccountError::AccountIdInvalidFieldElement(
+            "Transaction script allowlist wallet accounts cannot have a faucet account type"
+                .to_string(),
+        ));
+    }
+
+    let auth_scheme_procedure = match auth_scheme {
+        AuthScheme::RpoFalcon512 { .. } => "tx_script_allowlist::auth_tx_rpo_falcon512",
     };
+    let auth_data_item = auth_scheme.storage_slot();
 
     let account_code_string: String = format!(
         "
     use.miden::contracts::wallets::basic->basic_wallet
+    use.miden::contracts::auth::tx_script_allowlist
     use.miden::contracts::auth::basic
 
     export.basic_wallet::receive_asset
     export.basic_wallet::send_asset
+    export.basic_wallet::move_asset_to_note
+    export.tx_script_allowlist::allow_tx_script
+    export.tx_script_allowlist::disallow_tx_script
     export.{auth_scheme_procedure}
+    export.basic::set_auth_scheme
 
     "
     );
@@ -60,10 +607,23 @@ pub fn create_basic_wallet(
     let account_assembler = TransactionKernel::assembler();
     let account_code = AccountCode::new(account_code_ast.clone(), &account_assembler)?;
 
-    let account_storage = AccountStorage::new(vec![miden_objects::accounts::SlotItem {
-        index: 0,
-        slot: StorageSlot::new_value(storage_slot_0_data),
-    }])?;
+    let allowlist = StorageArray::new(
+        TX_SCRIPT_ALLOWLIST_DEPTH,
+        allowed_tx_scripts
+            .iter()
+            .map(|script_root| (script_root[3].as_int(), (*script_root).into())),
+    )?;
+    let account_storage = AccountStorage::with_arrays(
+        vec![
+            auth_data_item,
+            SlotItem {
+                index: TX_SCRIPT_ALLOWLIST_SLOT,
+                slot: StorageSlot::new_array(allowlist.root().into(), TX_SCRIPT_ALLOWLIST_DEPTH),
+            },
+        ],
+        BTreeMap::from([(TX_SCRIPT_ALLOWLIST_SLOT, allowlist)]),
+    )?
+    .with_auth_scheme_version(auth_scheme.version().into());
     let account_vault = AssetVault::new(&[]).expect("error on empty vault");
 
     let account_seed = AccountId::get_account_seed(
@@ -79,3 +639,265 @@ pub fn create_basic_wallet(
         account_seed,
     ))
 }
+
+/// Returns a transaction script which adds the transaction scripts in `allow` to and removes the
+/// transaction scripts in `disallow` from the allowlist of an account created via
+/// [create_tx_script_allowlist_wallet()].
+///
+/// The script authenticates the transaction using the specified authentication scheme, and since
+/// the script itself is not on the allowlist, it must be compiled together with the secret key of
+/// the account owner (e.g., via `TransactionExecutor::compile_tx_script()`). Scripts are added
+/// before they are removed, so a script contained in both lists is not on the allowlist after the
+/// transaction.
+///
+/// # Errors
+/// Returns an error if the generated script cannot be parsed.
+pub fn build_tx_script_allowlist_update_script(
+    allow: &[Digest],
+    disallow: &[Digest],
+    auth_scheme: AuthScheme,
+) -> Result<ProgramAst, AccountError> {
+    let auth_scheme_procedure = match auth_scheme {
+        AuthScheme::RpoFalcon512 { .. } => "auth_tx_rpo_falcon512",
+    };
+
+    let to_masm = |script_root: &Digest| {
+        script_root
+            .iter()
+            .map(|felt| felt.as_int().to_string())
+            .collect::<Vec<_>>()
+            .join(".")
+    };
+
+    let mut updates = String::new();
+    for script_root in allow {
+        let script_root = to_masm(script_root);
+        updates.push_str(&format!(
+            "push.{script_root} call.tx_script_allowlist::allow_tx_script dropw\n"
+        ));
+    }
+    for script_root in disallow {
+        let script_root = to_masm(script_root);
+        updates.push_str(&format!(
+            "push.{script_root} call.tx_script_allowlist::disallow_tx_script dropw\n"
+        ));
+    }
+
+    let tx_script_src = format!(
+        "
+    use.miden::contracts::auth::tx_script_allowlist
+
+    begin
+        {updates}
+        call.tx_script_allowlist::{auth_scheme_procedure}
+    end
+    "
+    );
+
+    ProgramAst::parse(&tx_script_src).map_err(|e| AccountError::AccountCodeAssemblerError(e.into()))
+}
+
+// KEY ROTATION WALLET
+// ================================================================================================
+
+/// Storage slot at which the rotation configuration of a key rotation wallet is stored.
+const KEY_ROTATION_CONFIG_SLOT: u8 = 1;
+
+/// Storage slot at which the public key announced by the owner of a key rotation wallet is stored.
+const KEY_ROTATION_PENDING_KEY_SLOT: u8 = 2;
+
+/// Storage slot at which the announcement height of a key rotation wallet is stored.
+const KEY_ROTATION_ANNOUNCEMENT_SLOT: u8 = 3;
+
+/// Creates a new account with basic wallet interface whose public key can only be rotated
+/// `rotation_delay` blocks after the new key has been announced.
+///
+/// The key rotation wallet interface exposes seven procedures:
+/// - `receive_asset`, `send_asset` and `move_asset_to_note` of the basic wallet interface.
+/// - `announce_key`, which records a new public key together with the number of the reference
+///   block of the transaction. Transactions announcing a key can be built via
+///   [build_key_announcement_script()].
+/// - `activate_key`, which replaces the public key of the account with the announced key once at
+///   least `rotation_delay` blocks have passed since the announcement. Transactions activating a
+///   key can be built via [build_key_activation_script()].
+/// - `cancel_key`, which discards the announced key. Transactions cancelling a rotation can be
+///   built via [build_key_cancellation_script()].
+/// - the authentication procedure of the specified authentication scheme.
+///
+/// Unlike other wallets, the account does not expose `set_auth_scheme`, as it would allow an
+/// attacker who stole the key of the account to replace the key without a delay. The account
+/// storage is laid out as follows:
+/// - slot 0: public key information for the authentication scheme.
+/// - slot 1: rotation configuration as [rotation_delay, 0, 0, 0].
+/// - slot 2: the announced public key, or an empty word if no key has been announced.
+/// - slot 3: announcement height as [announced_at, 0, 0, 0].
+pub fn create_key_rotation_wallet(
+    init_seed: [u8; 32],
+    auth_scheme: AuthScheme,
+    rotation_delay: u32,
+    account_type: AccountType,
+    account_storage_type: AccountStorageType,
+) -> Result<(Account, Word), AccountError> {
+    if matches!(account_type, AccountType::FungibleFaucet | AccountType::NonFungibleFaucet) {
+        return Err(AccountError::AccountIdInvalidFieldElement(
+            "Key rotation wallet accounts cannot have a faucet account type".to_string(),
+        ));
+    }
+
+    let auth_scheme_procedure = match auth_scheme {
+        AuthScheme::RpoFalcon512 { .. } => "basic::auth_tx_rpo_falcon512",
+    };
+    let auth_data_item = auth_scheme.storage_slot();
+
+    let account_code_string: String = format!(
+        "
+    use.miden::contracts::wallets::basic->basic_wallet
+    use.miden::contracts::auth::key_rotation
+    use.miden::contracts::auth::basic
+
+    export.basic_wallet::receive_asset
+    export.basic_wallet::send_asset
+    export.basic_wallet::move_asset_to_note
+    export.key_rotation::announce_key
+    export.key_rotation::activate_key
+    export.key_rotation::cancel_key
+    export.{auth_scheme_procedure}
+
+    "
+    );
+    let account_code_src: &str = &account_code_string;
+
+    let account_code_ast = ModuleAst::parse(account_code_src)
+        .map_err(|e| AccountError::AccountCodeAssemblerError(e.into()))?;
+    let account_assembler = TransactionKernel::assembler();
+    let account_code = AccountCode::new(account_code_ast.clone(), &account_assembler)?;
+
+    let account_storage = AccountStorage::new(vec![
+        auth_data_item,
+        SlotItem {
+            index: KEY_ROTATION_CONFIG_SLOT,
+            slot: StorageSlot::new_value([Felt::from(rotation_delay), ZERO, ZERO, ZERO]),
+        },
+        SlotItem {
+            index: KEY_ROTATION_PENDING_KEY_SLOT,
+            slot: StorageSlot::new_value([ZERO; 4]),
+        },
+        SlotItem {
+            index: KEY_ROTATION_ANNOUNCEMENT_SLOT,
+            slot: StorageSlot::new_value([ZERO; 4]),
+        },
+    ])?
+    .with_auth_scheme_version(auth_scheme.version().into());
+    let account_vault = AssetVault::new(&[]).expect("error on empty vault");
+
+    let account_seed = AccountId::get_account_seed(
+        init_seed,
+        account_type,
+        account_storage_type,
+        account_code.root(),
+        account_storage.root(),
+    )?;
+    let account_id = AccountId::new(account_seed, account_code.root(), account_storage.root())?;
+    Ok((
+        Account::new(account_id, account_vault, account_storage, account_code, ZERO),
+        account_seed,
+    ))
+}
+
+/// Returns a transaction script which announces the public key of the `new_auth_scheme` for an
+/// account created via [create_key_rotation_wallet()].
+///
+/// The script authenticates the transaction using the current authentication scheme of the
+/// account, so it must be compiled together with the current secret key of the account owner
+/// (e.g., via `TransactionExecutor::compile_tx_script()`). The key can be activated via
+/// [build_key_activation_script()] once the rotation delay of the account has elapsed.
+///
+/// # Errors
+/// Returns an error if the generated script cannot be parsed.
+pub fn build_key_announcement_script(
+    new_auth_scheme: AuthScheme,
+    auth_scheme: AuthScheme,
+) -> Result<ProgramAst, AccountError> {
+    let auth_scheme_procedure = match auth_scheme {
+        AuthScheme::RpoFalcon512 { .. } => "auth_tx_rpo_falcon512",
+    };
+    let pub_key_item = new_auth_scheme.storage_slot();
+    let pub_key = pub_key_item
+        .slot
+        .value
+        .iter()
+        .map(|felt| felt.as_int().to_string())
+        .collect::<Vec<_>>()
+        .join(".");
+
+    let tx_script_src = format!(
+        "
+    use.miden::contracts::auth::key_rotation
+    use.miden::contracts::auth::basic
+
+    begin
+        push.{pub_key} call.key_rotation::announce_key
+        call.basic::{auth_scheme_procedure}
+    end
+    "
+    );
+
+    ProgramAst::parse(&tx_script_src).map_err(|e| AccountError::AccountCodeAssemblerError(e.into()))
+}
+
+/// Returns a transaction script which activates the public key announced for an account created
+/// via [create_key_rotation_wallet()].
+///
+/// The key is activated before the transaction is authenticated, so the transaction is
+/// authenticated using the announced authentication scheme, i.e., `auth_scheme` must be the
+/// announced scheme and the script must be compiled together with the new secret key.
+///
+/// # Errors
+/// Returns an error if the generated script cannot be parsed.
+pub fn build_key_activation_script(auth_scheme: AuthScheme) -> Result<ProgramAst, AccountError> {
+    let auth_scheme_procedure = match auth_scheme {
+        AuthScheme::RpoFalcon512 { .. } => "auth_tx_rpo_falcon512",
+    };
+
+    let tx_script_src = format!(
+        "
+    use.miden::contracts::auth::key_rotation
+    use.miden::contracts::auth::basic
+
+    begin
+        call.key_rotation::activate_key
+        call.basic::{auth_scheme_procedure}
+    end
+    "
+    );
+
+    ProgramAst::parse(&tx_script_src).map_err(|e| AccountError::AccountCodeAssemblerError(e.into()))
+}
+
+/// Returns a transaction script which cancels the key rotation announced for an account created
+/// via [create_key_rotation_wallet()].
+///
+/// The script authenticates the transaction using the current authentication scheme of the
+/// account, so it must be compiled together with the current secret key of the account owner.
+///
+/// # Errors
+/// Returns an error if the generated script cannot be parsed.
+pub fn build_key_cancellation_script(auth_scheme: AuthScheme) -> Result<ProgramAst, AccountError> {
+    let auth_scheme_procedure = match auth_scheme {
+        AuthScheme::RpoFalcon512 { .. } => "auth_tx_rpo_falcon512",
+    };
+
+    let tx_script_src = format!(
+        "
+    use.miden::contracts::auth::key_rotation
+    use.miden::contracts::auth::basic
+
+    begin
+        call.key_rotation::cancel_key
+        call.basic::{auth_scheme_procedure}
+    end
+    "
+    );
+
+    ProgramAst::parse(&tx_script_src).map_err(|e| AccountError::AccountCodeAssemblerError(e.into()))
+}
@@ -0,0 +1,52 @@
+use miden_objects::{AccountError, Felt, Word, ZERO};
+
+// NOTE RATE LIMIT
+// ================================================================================================
+
+/// Configuration of the note rate limit component, which limits the number of notes an account
+/// can create within a window of `period` blocks to `max_notes`.
+///
+/// A window is opened by the first note created after the previous window expired, and starts at
+/// the reference block of the transaction which created this note.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NoteRateLimit {
+    max_notes: u32,
+    period: u32,
+}
+
+impl NoteRateLimit {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [NoteRateLimit] allowing the account to create up to `max_notes` notes
+    /// within every window of `period` blocks.
+    ///
+    /// # Errors
+    /// Returns an error if either `max_notes` or `period` is zero.
+    pub fn new(max_notes: u32, period: u32) -> Result<Self, AccountError> {
+        if max_notes == 0 || period == 0 {
+            return Err(AccountError::NoteRateLimitInvalidConfig { max_notes, period });
+        }
+
+        Ok(Self { max_notes, period })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the maximum number of notes the account can create within a window.
+    pub fn max_notes(&self) -> u32 {
+        self.max_notes
+    }
+
+    /// Returns the length of a window in blocks.
+    pub fn period(&self) -> u32 {
+        self.period
+    }
+}
+
+impl From<NoteRateLimit> for Word {
+    fn from(rate_limit: NoteRateLimit) -> Self {
+        [Felt::from(rate_limit.max_notes), Felt::from(rate_limit.period), ZERO, ZERO]
+    }
+}
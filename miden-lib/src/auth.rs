@@ -1,4 +1,16 @@
-use miden_objects::crypto::dsa::rpo_falcon512;
+use alloc::{string::ToString, vec::Vec};
+
+use miden_objects::{
+    accounts::{Account, SlotItem, StorageSlot},
+    assembly::ProgramAst,
+    crypto::dsa::rpo_falcon512,
+    AccountError, Felt, TransactionScriptError, Word, ZERO,
+};
+
+use crate::transaction::memory::AUTH_SCHEME_STORAGE_SLOT;
+
+// AUTH SCHEME
+// ================================================================================================
 
 /// Defines authentication schemes available to standard and faucet accounts.
 pub enum AuthScheme {
@@ -8,3 +20,138 @@ pub enum AuthScheme {
     /// use RPO256. This makes the signature more efficient to verify in Miden VM.
     RpoFalcon512 { pub_key: rpo_falcon512::PublicKey },
 }
+
+impl AuthScheme {
+    /// Detects the authentication scheme of the specified account.
+    ///
+    /// The version of the scheme is read from the reserved auth scheme slot of the account storage
+    /// (see [AUTH_SCHEME_STORAGE_SLOT]), and the public key information of the scheme is read from
+    /// slot 0.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The authentication scheme of the account is not versioned, i.e., the auth scheme slot is
+    ///   empty.
+    /// - The version stored in the auth scheme slot is unknown.
+    pub fn detect(account: &Account) -> Result<Self, AccountError> {
+        let storage = account.storage();
+        let version = storage.get_item(AUTH_SCHEME_STORAGE_SLOT);
+        if version == Default::default() {
+            return Err(AccountError::AuthSchemeNotVersioned);
+        }
+
+        match AuthSchemeVersion::try_from(Word::from(version))? {
+            AuthSchemeVersion::RpoFalcon512V1 => Ok(Self::RpoFalcon512 {
+                pub_key: rpo_falcon512::PublicKey::new(storage.get_item(0).into()),
+            }),
+        }
+    }
+
+    /// Returns the version of this authentication scheme.
+    pub fn version(&self) -> AuthSchemeVersion {
+        match self {
+            Self::RpoFalcon512 { .. } => AuthSchemeVersion::RpoFalcon512V1,
+        }
+    }
+
+    /// Returns the storage item which records the public key information of this authentication
+    /// scheme in slot 0 of the account storage.
+    ///
+    /// The version of the scheme is stored in the reserved auth scheme slot, and thus, must be set
+    /// via [AccountStorage::with_auth_scheme_version()].
+    ///
+    /// [AccountStorage::with_auth_scheme_version()]: miden_objects::accounts::AccountStorage::with_auth_scheme_version
+    pub fn storage_slot(&self) -> SlotItem {
+        let auth_data: Word = match self {
+            Self::RpoFalcon512 { pub_key } => (*pub_key).into(),
+        };
+
+        SlotItem {
+            index: 0,
+            slot: StorageSlot::new_value(auth_data),
+        }
+    }
+
+    /// Returns the name of the procedure of `miden::contracts::auth::basic` which authenticates
+    /// transactions using this scheme.
+    fn auth_procedure(&self) -> &'static str {
+        match self {
+            Self::RpoFalcon512 { .. } => "auth_tx_rpo_falcon512",
+        }
+    }
+}
+
+// AUTH SCHEME VERSION
+// ================================================================================================
+
+/// Versions of the authentication schemes implemented by `miden::contracts::auth`.
+///
+/// The version of the authentication scheme of an account is stored in its storage as
+/// [version, 0, 0, 0], so that accounts can be migrated when the authentication procedures
+/// change.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum AuthSchemeVersion {
+    /// RPO Falcon512 signatures verified by `auth_tx_rpo_falcon512` against the public key stored
+    /// in slot 0.
+    RpoFalcon512V1 = 1,
+}
+
+impl From<AuthSchemeVersion> for Word {
+    fn from(version: AuthSchemeVersion) -> Self {
+        [Felt::from(version as u8), ZERO, ZERO, ZERO]
+    }
+}
+
+impl TryFrom<Word> for AuthSchemeVersion {
+    type Error = AccountError;
+
+    fn try_from(value: Word) -> Result<Self, Self::Error> {
+        if value == Word::from(Self::RpoFalcon512V1) {
+            Ok(Self::RpoFalcon512V1)
+        } else {
+            Err(AccountError::AuthSchemeUnknownVersion(value))
+        }
+    }
+}
+
+// MIGRATION TRANSACTION SCRIPT
+// ================================================================================================
+
+/// Returns the code of a transaction script which migrates an account from the `current` to the
+/// `target` authentication scheme.
+///
+/// The script first authenticates the transaction using the current authentication scheme, and
+/// then replaces the version and the public key information of the scheme via the
+/// `set_auth_scheme` procedure of `miden::contracts::auth::basic`, which must be exposed by the
+/// account. Transactions executed after the migration are authenticated using the target scheme.
+///
+/// # Errors
+/// Returns an error if the transaction script could not be parsed.
+pub fn create_auth_scheme_migration_tx_script_code(
+    current: &AuthScheme,
+    target: &AuthScheme,
+) -> Result<ProgramAst, TransactionScriptError> {
+    let pub_key_item = target.storage_slot();
+    let [pub_key, version] =
+        [pub_key_item.slot.value, target.version().into()].map(|word: Word| {
+            word.iter().map(|felt| felt.as_int().to_string()).collect::<Vec<_>>().join(".")
+        });
+    let auth_scheme_procedure = current.auth_procedure();
+
+    let tx_script_src = format!(
+        "
+    use.miden::contracts::auth::basic->auth_tx
+
+    begin
+        call.auth_tx::{auth_scheme_procedure}
+
+        push.{pub_key} push.{version}
+        call.auth_tx::set_auth_scheme dropw dropw
+    end
+    "
+    );
+
+    ProgramAst::parse(&tx_script_src)
+        .map_err(|err| TransactionScriptError::ScriptCompilationError(err.into()))
+}
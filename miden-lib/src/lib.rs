@@ -12,7 +12,7 @@ use miden_objects::{
 };
 
 mod auth;
-pub use auth::AuthScheme;
+pub use auth::{create_auth_scheme_migration_tx_script_code, AuthScheme, AuthSchemeVersion};
 
 pub mod accounts;
 pub mod notes;
@@ -2,17 +2,19 @@ use alloc::vec::Vec;
 
 use miden_objects::{
     accounts::AccountId,
-    assets::Asset,
+    assets::{Asset, FungibleAsset},
     crypto::rand::FeltRng,
+    encoding,
     notes::{
-        Note, NoteAssets, NoteExecutionMode, NoteInputs, NoteMetadata, NoteRecipient, NoteTag,
-        NoteType,
+        AccountGroup, Note, NoteAssets, NoteExecutionMode, NoteInputs, NoteMetadata, NoteRecipient,
+        NoteTag, NoteType, ScriptInfo, ScriptRegistry,
     },
-    NoteError, Word, ZERO,
+    Felt, NoteError, Word, ZERO,
 };
 
 use self::utils::build_note_script;
 
+pub mod swap;
 pub mod utils;
 
 // STANDARDIZED SCRIPTS
@@ -83,12 +85,85 @@ pub fn create_p2idr_note<R: FeltRng>(
     Ok(Note::new(vault, metadata, recipient))
 }
 
+/// Generates a P2IDT note - pay to id with a time lock until a certain block height.
+///
+/// This script enables the transfer of assets from the `sender` account to the `target` account
+/// by specifying the target's account ID. Unlike P2ID notes, the note can only be consumed by the
+/// target in transactions whose reference block is at or after `release_height`, which allows
+/// scheduling payments for a later point in time.
+///
+/// The passed-in `rng` is used to generate a serial number for the note. The returned note's tag
+/// is set to the target's account ID.
+///
+/// # Errors
+/// Returns an error if deserialization or compilation of the `P2IDT` script fails.
+pub fn create_p2id_timelocked_note<R: FeltRng>(
+    sender: AccountId,
+    target: AccountId,
+    assets: Vec<Asset>,
+    note_type: NoteType,
+    release_height: u32,
+    mut rng: R,
+) -> Result<Note, NoteError> {
+    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/P2IDT.masb"));
+    let note_script = build_note_script(bytes)?;
+
+    let inputs = NoteInputs::new(vec![target.into(), release_height.into()])?;
+    let tag = NoteTag::from_account_id(target, NoteExecutionMode::Local)?;
+    let serial_num = rng.draw_word();
+    let aux = ZERO;
+
+    let vault = NoteAssets::new(assets)?;
+    let metadata = NoteMetadata::new(sender, note_type, tag, aux)?;
+    let recipient = NoteRecipient::new(serial_num, note_script, inputs);
+    Ok(Note::new(vault, metadata, recipient))
+}
+
+/// Generates a P2PK note - pay to public key note.
+///
+/// This script enables the transfer of assets from the `sender` account to whoever can produce a
+/// Falcon signature with the secret key corresponding to `target_pub_key`, independently of the
+/// ID of the consuming account. This allows sending assets to users who have not created an
+/// account yet. The signature must be made over hash(INPUT_NOTES_COM, ACCOUNT_ID) of the
+/// consuming transaction (see the `P2PK` script for details).
+///
+/// The passed-in `rng` is used to generate a serial number for the note. The returned note's tag
+/// is derived from the public key in the same way as the tag of a P2ID note is derived from the
+/// target's account ID, i.e., it contains the 16 most significant bits of the first element of
+/// `target_pub_key`.
+///
+/// # Errors
+/// Returns an error if deserialization or compilation of the `P2PK` script fails.
+pub fn create_p2pk_note<R: FeltRng>(
+    sender: AccountId,
+    target_pub_key: Word,
+    assets: Vec<Asset>,
+    note_type: NoteType,
+    mut rng: R,
+) -> Result<Note, NoteError> {
+    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/P2PK.masb"));
+    let note_script = build_note_script(bytes)?;
+
+    let inputs = NoteInputs::new(target_pub_key.to_vec())?;
+    let tag = NoteTag::from(((target_pub_key[0].as_int() & 0xffff000000000000) >> 34) as u32);
+    let serial_num = rng.draw_word();
+    let aux = ZERO;
+
+    let metadata = NoteMetadata::new(sender, note_type, tag, aux)?;
+    let vault = NoteAssets::new(assets)?;
+    let recipient = NoteRecipient::new(serial_num, note_script, inputs);
+    Ok(Note::new(vault, metadata, recipient))
+}
+
 /// Generates a SWAP note - swap of assets between two accounts.
 ///
 /// This script enables a swap of 2 assets between the `sender` account and any other account that
 /// is willing to consume the note. The consumer will receive the `offered_asset` and will create a
 /// new P2ID note with `sender` as target, containing the `requested_asset`.
 ///
+/// The returned note's tag is built via [build_swap_tag](utils::build_swap_tag), i.e., public
+/// notes are tagged for the swap use case of the pair of assets.
+///
 /// # Errors
 /// Returns an error if deserialization or compilation of the `SWAP` script fails.
 pub fn create_swap_note<R: FeltRng>(
@@ -118,15 +193,336 @@ pub fn create_swap_note<R: FeltRng>(
         payback_tag.inner().into(),
     ])?;
 
+    let tag = utils::build_swap_tag(sender, &offered_asset, &requested_asset, note_type)?;
+    let serial_num = rng.draw_word();
+    let aux = ZERO;
+
+    let metadata = NoteMetadata::new(sender, note_type, tag, aux)?;
+    let vault = NoteAssets::new(vec![offered_asset])?;
+    let recipient = NoteRecipient::new(serial_num, note_script, inputs);
+    let note = Note::new(vault, metadata, recipient);
+
+    Ok((note, payback_serial_num))
+}
+
+/// Generates a SWAPP note - partially fillable swap of fungible assets between two accounts.
+///
+/// This script enables a swap of 2 fungible assets between the `sender` account and any other
+/// account that is willing to consume the note, where the consumer may fill only a part of the
+/// offer. The consumer receives the filled part of the `offered_asset` and creates a new P2ID note
+/// with `sender` as target, containing the filled part of the `requested_asset`. If the offer is
+/// not filled completely, the consumer also creates a new SWAPP note offering the remainder of the
+/// `offered_asset` for the remainder of the `requested_asset` at the same price. The notes created
+/// by a fill can be computed via [PartialSwapFill](swap::PartialSwapFill).
+///
+/// The returned note's tag is built via [build_swap_tag](utils::build_swap_tag). The payback notes
+/// are created with the specified `note_type`, while the remainder notes are created with the type
+/// and the tag of the note being filled.
+///
+/// The passed-in `rng` is used to generate the serial number of the note and the seed from which
+/// the serial numbers of the payback and remainder notes are derived. The serial number of the
+/// payback note created by the first fill is returned together with the note.
+///
+/// # Errors
+/// Returns an error if:
+/// - `offered_asset` or `requested_asset` is not a fungible asset or has a zero amount.
+/// - The product of the offered and the requested amounts is not smaller than 2^63.
+/// - Deserialization or compilation of the `SWAPP` script fails.
+pub fn create_partial_swap_note<R: FeltRng>(
+    sender: AccountId,
+    offered_asset: Asset,
+    requested_asset: Asset,
+    note_type: NoteType,
+    mut rng: R,
+) -> Result<(Note, Word), NoteError> {
+    swap::validate_partial_swap_assets(offered_asset, requested_asset)?;
+
+    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/SWAPP.masb"));
+    let note_script = build_note_script(bytes)?;
+
+    let serial_seed = rng.draw_word();
+    let payback_tag = NoteTag::from_account_id(sender, NoteExecutionMode::Local)?;
+
+    let mut inputs = Word::from(requested_asset).to_vec();
+    inputs.extend_from_slice(&serial_seed);
+    inputs.extend_from_slice(&[sender.into(), payback_tag.inner().into(), note_type.into(), ZERO]);
+    let inputs = NoteInputs::new(inputs)?;
+
+    let tag = utils::build_swap_tag(sender, &offered_asset, &requested_asset, note_type)?;
+    let serial_num = rng.draw_word();
+    let aux = ZERO;
+
+    let metadata = NoteMetadata::new(sender, note_type, tag, aux)?;
+    let vault = NoteAssets::new(vec![offered_asset])?;
+    let recipient = NoteRecipient::new(serial_num, note_script, inputs);
+    let note = Note::new(vault, metadata, recipient);
+
+    Ok((note, serial_seed))
+}
+
+/// Generates a BASKET_SWAP note - swap of a basket of assets for another basket of assets between
+/// two accounts.
+///
+/// This script enables an atomic multi-asset swap between the `sender` account and any other
+/// account that is willing to consume the note. The consumer will receive all `offered` assets and
+/// will create a single P2ID note with `sender` as target, containing all `requested` assets. The
+/// payback note is created with the specified `note_type`.
+///
+/// The passed-in `rng` is used to generate the serial numbers of the note and of the payback note.
+/// The serial number of the payback note is returned together with the note.
+///
+/// # Errors
+/// Returns an error if:
+/// - `offered` or `requested` is empty or contains duplicate assets.
+/// - `requested` contains more than 30 assets.
+/// - Deserialization or compilation of the `BASKET_SWAP` script fails.
+pub fn create_basket_swap_note<R: FeltRng>(
+    sender: AccountId,
+    offered: Vec<Asset>,
+    requested: Vec<Asset>,
+    note_type: NoteType,
+    mut rng: R,
+) -> Result<(Note, Word), NoteError> {
+    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/BASKET_SWAP.masb"));
+    let note_script = build_note_script(bytes)?;
+
+    // validate the requested basket in the same way as the assets of the payback note
+    let requested = NoteAssets::new(requested)?;
+
+    let payback_serial_num = rng.draw_word();
+    let payback_recipient = utils::build_p2id_recipient(sender, payback_serial_num)?;
+    let payback_tag = NoteTag::from_account_id(sender, NoteExecutionMode::Local)?;
+
+    let mut inputs = payback_recipient.as_elements().to_vec();
+    inputs.extend_from_slice(&[
+        payback_tag.inner().into(),
+        note_type.into(),
+        Felt::new(requested.num_assets() as u64),
+        ZERO,
+    ]);
+    for asset in requested.iter() {
+        inputs.extend_from_slice(&Word::from(*asset));
+    }
+    let inputs = NoteInputs::new(inputs)?;
+
     // TODO: build the tag for the SWAP use case
     let tag = 0.into();
     let serial_num = rng.draw_word();
     let aux = ZERO;
 
     let metadata = NoteMetadata::new(sender, note_type, tag, aux)?;
-    let vault = NoteAssets::new(vec![offered_asset])?;
+    let vault = NoteAssets::new(offered)?;
     let recipient = NoteRecipient::new(serial_num, note_script, inputs);
     let note = Note::new(vault, metadata, recipient);
 
     Ok((note, payback_serial_num))
 }
+
+/// Generates a RECOVERY note - sweep of assets out of an inactive account by its heir.
+///
+/// This script enables the `heir` of a `target` account created via
+/// [create_recoverable_wallet](crate::accounts::wallets::create_recoverable_wallet) to sweep the
+/// `sweep_assets` out of the `target` account once the inactivity period configured for the
+/// account has elapsed. When the note is consumed by the `target` account, the `assets` carried by
+/// the note are added to the account, and each of the `sweep_assets` is sent to the `heir` in a
+/// separate P2ID note.
+///
+/// The passed-in `rng` is used to generate the serial numbers of the note and of the P2ID notes
+/// created for the `heir`. The returned note's tag is set to the target's account ID. The serial
+/// numbers of the P2ID notes are returned in the same order as the `sweep_assets`.
+///
+/// # Errors
+/// Returns an error if:
+/// - `assets` is empty.
+/// - `sweep_assets` is empty or contains more than 15 assets.
+/// - Deserialization or compilation of the `RECOVERY` script fails.
+pub fn create_recovery_note<R: FeltRng>(
+    heir: AccountId,
+    target: AccountId,
+    assets: Vec<Asset>,
+    sweep_assets: Vec<Asset>,
+    note_type: NoteType,
+    mut rng: R,
+) -> Result<(Note, Vec<Word>), NoteError> {
+    if sweep_assets.is_empty() {
+        return Err(NoteError::empty_asset_list());
+    }
+
+    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/RECOVERY.masb"));
+    let note_script = build_note_script(bytes)?;
+
+    let sweep_tag = NoteTag::from_account_id(heir, NoteExecutionMode::Local)?;
+    let mut inputs = vec![sweep_tag.inner().into(), note_type.into(), ZERO, ZERO];
+    let mut sweep_serial_nums = Vec::with_capacity(sweep_assets.len());
+    for asset in sweep_assets {
+        let sweep_serial_num = rng.draw_word();
+        let sweep_recipient = utils::build_p2id_recipient(heir, sweep_serial_num)?;
+        inputs.extend_from_slice(sweep_recipient.as_elements());
+        inputs.extend_from_slice(&Word::from(asset));
+        sweep_serial_nums.push(sweep_serial_num);
+    }
+    let inputs = NoteInputs::new(inputs)?;
+
+    let tag = NoteTag::from_account_id(target, NoteExecutionMode::Local)?;
+    let serial_num = rng.draw_word();
+    let aux = ZERO;
+
+    let metadata = NoteMetadata::new(heir, note_type, tag, aux)?;
+    let vault = NoteAssets::new(assets)?;
+    let recipient = NoteRecipient::new(serial_num, note_script, inputs);
+    let note = Note::new(vault, metadata, recipient);
+
+    Ok((note, sweep_serial_nums))
+}
+
+/// Generates a VOUCHER note - a note which mints fungible assets of the issuing faucet when it is
+/// redeemed.
+///
+/// This script enables the `faucet` to distribute `amount` of its fungible asset to the `target`
+/// account without minting the asset upfront. The voucher is redeemed by consuming it in a
+/// transaction against the `faucet`, which mints the asset and sends it to the `target` in a P2ID
+/// note. Redeeming a voucher does not require a signature of the faucet owner, so any account can
+/// execute the redeeming transaction. Only vouchers issued by the `faucet` itself can be redeemed.
+///
+/// As every note has to carry an asset, the voucher carries a zero-amount asset of the `faucet`.
+///
+/// The passed-in `rng` is used to generate the serial numbers of the voucher and of the P2ID note
+/// created for the `target`. The returned note's tag is set to the faucet's account ID. The serial
+/// number of the P2ID note is returned together with the voucher.
+///
+/// # Errors
+/// Returns an error if:
+/// - `faucet` is not a fungible faucet.
+/// - `amount` cannot be encoded into a single field element.
+/// - Deserialization or compilation of the `VOUCHER` script fails.
+pub fn create_voucher_note<R: FeltRng>(
+    faucet: AccountId,
+    target: AccountId,
+    amount: u64,
+    note_type: NoteType,
+    mut rng: R,
+) -> Result<(Note, Word), NoteError> {
+    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/VOUCHER.masb"));
+    let note_script = build_note_script(bytes)?;
+
+    let payout_serial_num = rng.draw_word();
+    let payout_recipient = utils::build_p2id_recipient(target, payout_serial_num)?;
+    let payout_tag = NoteTag::from_account_id(target, NoteExecutionMode::Local)?;
+
+    let amount = encoding::felt_from_u64(amount).map_err(NoteError::InvalidInputEncoding)?;
+    let mut inputs = vec![amount, payout_tag.inner().into(), note_type.into(), ZERO];
+    inputs.extend_from_slice(payout_recipient.as_elements());
+    let inputs = NoteInputs::new(inputs)?;
+
+    let tag = NoteTag::from_account_id(faucet, NoteExecutionMode::Local)?;
+    let serial_num = rng.draw_word();
+    let aux = ZERO;
+
+    let stamp = FungibleAsset::new(faucet, 0).map_err(NoteError::InvalidAssetData)?;
+
+    let metadata = NoteMetadata::new(faucet, note_type, tag, aux)?;
+    let vault = NoteAssets::new(vec![stamp.into()])?;
+    let recipient = NoteRecipient::new(serial_num, note_script, inputs);
+    let note = Note::new(vault, metadata, recipient);
+
+    Ok((note, payout_serial_num))
+}
+
+/// Generates a GROUP_CLAIM note - a note which can be consumed by any member of a group of
+/// accounts.
+///
+/// This script enables the transfer of assets from the `sender` account to whichever member of the
+/// `group` consumes the note first, e.g., to distribute funds to the members of a DAO. The
+/// consuming account proves its membership via a
+/// [GroupMembership](miden_objects::notes::GroupMembership), whose note args and Merkle path must
+/// be provided to the consuming transaction.
+///
+/// The passed-in `rng` is used to generate a serial number for the note. The returned note is
+/// public, and its tag is set to the tag of the group, so that it can be discovered by all members
+/// of the group.
+///
+/// # Errors
+/// Returns an error if deserialization or compilation of the `GROUP_CLAIM` script fails.
+pub fn create_group_claim_note<R: FeltRng>(
+    sender: AccountId,
+    group: &AccountGroup,
+    assets: Vec<Asset>,
+    mut rng: R,
+) -> Result<Note, NoteError> {
+    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/GROUP_CLAIM.masb"));
+    let note_script = build_note_script(bytes)?;
+
+    let inputs = NoteInputs::new(Word::from(group.root()).to_vec())?;
+    let tag = group.tag();
+    let serial_num = rng.draw_word();
+    let aux = ZERO;
+
+    let metadata = NoteMetadata::new(sender, NoteType::Public, tag, aux)?;
+    let vault = NoteAssets::new(assets)?;
+    let recipient = NoteRecipient::new(serial_num, note_script, inputs);
+    Ok(Note::new(vault, metadata, recipient))
+}
+
+// SCRIPT REGISTRY
+// ================================================================================================
+
+/// Returns a [ScriptRegistry] containing all note scripts provided by the Miden library.
+///
+/// The registry contains version 1 of the `P2ID`, `P2IDR`, `P2IDT`, `P2PK`, `SWAP`, `SWAPP`,
+/// `BASKET_SWAP`, `RECOVERY`, `VOUCHER`, and `GROUP_CLAIM` scripts, registered under these names.
+/// Applications can extend the returned registry with their own scripts.
+///
+/// # Errors
+/// Returns an error if deserialization of any of the scripts fails.
+pub fn standard_script_registry() -> Result<ScriptRegistry, NoteError> {
+    let scripts: [(&str, &[u8]); 10] = [
+        (
+            "P2ID",
+            include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/P2ID.masb")),
+        ),
+        (
+            "P2IDR",
+            include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/P2IDR.masb")),
+        ),
+        (
+            "P2IDT",
+            include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/P2IDT.masb")),
+        ),
+        (
+            "P2PK",
+            include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/P2PK.masb")),
+        ),
+        (
+            "SWAP",
+            include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/SWAP.masb")),
+        ),
+        (
+            "SWAPP",
+            include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/SWAPP.masb")),
+        ),
+        (
+            "BASKET_SWAP",
+            include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/BASKET_SWAP.masb")),
+        ),
+        (
+            "RECOVERY",
+            include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/RECOVERY.masb")),
+        ),
+        (
+            "VOUCHER",
+            include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/VOUCHER.masb")),
+        ),
+        (
+            "GROUP_CLAIM",
+            include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/GROUP_CLAIM.masb")),
+        ),
+    ];
+
+    let mut registry = ScriptRegistry::new();
+    for (name, bytes) in scripts {
+        let note_script = build_note_script(bytes)?;
+        registry.register(note_script.hash(), ScriptInfo::new(name, 1)?)?;
+    }
+
+    Ok(registry)
+}
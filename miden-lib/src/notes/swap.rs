@@ -0,0 +1,581 @@
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+use miden_objects::{
+    accounts::AccountId,
+    assets::{Asset, FungibleAsset},
+    encoding,
+    notes::{
+        Note, NoteAssets, NoteEnvelope, NoteId, NoteInputs, NoteMetadata, NoteRecipient,
+        NoteScript, NoteTag, NoteType,
+    },
+    transaction::{ExecutedTransaction, TransactionArgs, TransactionScript},
+    Digest, Felt, NoteError, Word, ZERO,
+};
+
+use super::utils::build_note_script;
+
+// CONSTANTS
+// ================================================================================================
+
+/// The number of inputs of a SWAP note: the payback recipient, the requested asset, and the tag
+/// of the payback note.
+const SWAP_NOTE_NUM_INPUTS: usize = 9;
+
+/// The type of the payback note created by the SWAP script.
+const PAYBACK_NOTE_TYPE: NoteType = NoteType::OffChain;
+
+/// The number of inputs of a SWAPP note: the requested asset, the serial seed, and the maker and
+/// the tag and type of the payback note.
+const PARTIAL_SWAP_NOTE_NUM_INPUTS: usize = 12;
+
+/// The upper bound (exclusive) of the product of the offered and requested amounts of a SWAPP
+/// note, which allows the SWAPP script to compare prices using field arithmetic.
+const MAX_PARTIAL_SWAP_AMOUNTS_PRODUCT: u128 = 1 << 63;
+
+// SWAP OFFER
+// ================================================================================================
+
+/// An offer to swap assets, as made by a SWAP note created via
+/// [create_swap_note](super::create_swap_note).
+///
+/// The account consuming a SWAP note receives the offered asset, and creates a P2ID note
+/// containing the requested asset for the maker of the offer, i.e., the sender of the SWAP note.
+/// The payback note is created as an off-chain note with the payback recipient and tag specified
+/// in the inputs of the SWAP note.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SwapOffer {
+    note_id: NoteId,
+    maker: AccountId,
+    offered_asset: Asset,
+    requested_asset: Asset,
+    payback_recipient: Digest,
+    payback_tag: NoteTag,
+}
+
+impl SwapOffer {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the offer made by the provided SWAP note.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The script of the note is not the SWAP script.
+    /// - The note does not have exactly 9 inputs.
+    /// - The note does not carry exactly one asset.
+    /// - The requested asset or the tag of the payback note is not encoded correctly.
+    /// - Deserialization or compilation of the `SWAP` script fails.
+    pub fn from_note(note: &Note) -> Result<Self, NoteError> {
+        let expected = swap_script()?.hash();
+        let actual = note.script().hash();
+        if actual != expected {
+            return Err(NoteError::NoteScriptMismatch { expected, actual });
+        }
+
+        let inputs = note.inputs().values();
+        if inputs.len() != SWAP_NOTE_NUM_INPUTS {
+            return Err(NoteError::UnexpectedNumNoteInputs {
+                expected: SWAP_NOTE_NUM_INPUTS,
+                actual: inputs.len(),
+            });
+        }
+
+        let offered_asset = match note.assets().iter().as_slice() {
+            [asset] => *asset,
+            [] => return Err(NoteError::empty_asset_list()),
+            assets => return Err(NoteError::too_many_assets(1, assets.len())),
+        };
+
+        let payback_recipient = Digest::new(inputs[0..4].try_into().expect("recipient is a word"));
+        let requested_asset: Word = inputs[4..8].try_into().expect("asset is a word");
+        let requested_asset =
+            Asset::try_from(requested_asset).map_err(NoteError::InvalidAssetData)?;
+        let payback_tag =
+            encoding::u32_from_felt(inputs[8]).map_err(NoteError::InvalidInputEncoding)?;
+
+        Ok(Self {
+            note_id: note.id(),
+            maker: note.metadata().sender(),
+            offered_asset,
+            requested_asset,
+            payback_recipient,
+            payback_tag: payback_tag.into(),
+        })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the ID of the SWAP note making this offer.
+    pub fn note_id(&self) -> NoteId {
+        self.note_id
+    }
+
+    /// Returns the ID of the account which made this offer.
+    pub fn maker(&self) -> AccountId {
+        self.maker
+    }
+
+    /// Returns the asset received by the account filling this offer.
+    pub fn offered_asset(&self) -> Asset {
+        self.offered_asset
+    }
+
+    /// Returns the asset sent to the maker by the account filling this offer.
+    pub fn requested_asset(&self) -> Asset {
+        self.requested_asset
+    }
+
+    /// Returns the recipient of the payback note.
+    pub fn payback_recipient(&self) -> Digest {
+        self.payback_recipient
+    }
+
+    /// Returns the tag of the payback note.
+    pub fn payback_tag(&self) -> NoteTag {
+        self.payback_tag
+    }
+
+    /// Returns the price of this offer, or None if either of the assets is not fungible or the
+    /// offered amount is zero.
+    pub fn price(&self) -> Option<SwapPrice> {
+        match (self.requested_asset, self.offered_asset) {
+            (Asset::Fungible(requested), Asset::Fungible(offered)) => {
+                SwapPrice::new(requested.amount(), offered.amount())
+            },
+            _ => None,
+        }
+    }
+
+    /// Returns true if this offer and the other offer can be filled against each other, i.e., if
+    /// the asset offered by each of them covers the asset requested by the other one.
+    ///
+    /// A fungible asset covers a fungible asset of the same faucet with a lower or equal amount,
+    /// and a non-fungible asset covers only itself.
+    pub fn crosses(&self, other: &Self) -> bool {
+        covers(&self.offered_asset, &other.requested_asset)
+            && covers(&other.offered_asset, &self.requested_asset)
+    }
+
+    /// Returns the envelope of the payback note created when the specified account fills this
+    /// offer.
+    ///
+    /// # Errors
+    /// Returns an error if the metadata of the payback note is invalid.
+    pub fn payback_note(&self, taker: AccountId) -> Result<NoteEnvelope, NoteError> {
+        let assets = NoteAssets::new(vec![self.requested_asset])?;
+        let note_id = NoteId::new(self.payback_recipient, assets.commitment());
+        let metadata = NoteMetadata::new(taker, PAYBACK_NOTE_TYPE, self.payback_tag, ZERO)?;
+        NoteEnvelope::new(note_id, metadata)
+    }
+}
+
+// SWAP PRICE
+// ================================================================================================
+
+/// The price of an offer to swap fungible assets, i.e., the amount of the requested asset asked
+/// for the amount of the offered asset.
+///
+/// Prices are compared exactly, without converting them into floating point numbers, such that
+/// a lower price is the better price for the account filling the offer.
+#[derive(Debug, Clone, Copy)]
+pub struct SwapPrice {
+    requested_amount: u64,
+    offered_amount: u64,
+}
+
+impl SwapPrice {
+    /// Returns the price of `requested_amount` units of the requested asset for `offered_amount`
+    /// units of the offered asset, or None if `offered_amount` is zero.
+    pub fn new(requested_amount: u64, offered_amount: u64) -> Option<Self> {
+        (offered_amount != 0).then_some(Self { requested_amount, offered_amount })
+    }
+
+    /// Returns the amount of the requested asset.
+    pub fn requested_amount(&self) -> u64 {
+        self.requested_amount
+    }
+
+    /// Returns the amount of the offered asset.
+    pub fn offered_amount(&self) -> u64 {
+        self.offered_amount
+    }
+
+    /// Returns the amount of the requested asset asked for the specified amount of the offered
+    /// asset, rounded up.
+    pub fn quote(&self, offered_amount: u64) -> u128 {
+        (self.requested_amount as u128 * offered_amount as u128)
+            .div_ceil(self.offered_amount as u128)
+    }
+
+    /// Returns the price as a floating point number, e.g., for display purposes.
+    pub fn as_f64(&self) -> f64 {
+        self.requested_amount as f64 / self.offered_amount as f64
+    }
+}
+
+impl PartialEq for SwapPrice {
+    fn eq(&self, other: &Self) -> bool {
+        self.cmp(other) == Ordering::Equal
+    }
+}
+
+impl Eq for SwapPrice {}
+
+impl PartialOrd for SwapPrice {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for SwapPrice {
+    fn cmp(&self, other: &Self) -> Ordering {
+        let lhs = self.requested_amount as u128 * other.offered_amount as u128;
+        let rhs = other.requested_amount as u128 * self.offered_amount as u128;
+        lhs.cmp(&rhs)
+    }
+}
+
+// SWAP FILL
+// ================================================================================================
+
+/// A builder of a transaction filling one or more [SwapOffer]s.
+///
+/// The transaction consumes the SWAP notes of all offers and thus creates a payback note for each
+/// of them. The account executing the transaction must hold the requested assets and expose the
+/// procedures of the basic wallet.
+#[derive(Debug, Clone)]
+pub struct SwapFill {
+    taker: AccountId,
+    offers: Vec<SwapOffer>,
+    tx_script: Option<TransactionScript>,
+}
+
+impl SwapFill {
+    /// Returns a new [SwapFill] for a transaction executed against the specified account.
+    pub fn new(taker: AccountId) -> Self {
+        Self {
+            taker,
+            offers: Vec::new(),
+            tx_script: None,
+        }
+    }
+
+    /// Adds the provided offer to the offers filled by the transaction.
+    pub fn with_offer(mut self, offer: SwapOffer) -> Self {
+        self.offers.push(offer);
+        self
+    }
+
+    /// Sets the transaction script executed by the transaction, e.g., to authenticate it.
+    pub fn with_tx_script(mut self, tx_script: TransactionScript) -> Self {
+        self.tx_script = Some(tx_script);
+        self
+    }
+
+    /// Returns the request for the transaction filling the offers.
+    ///
+    /// # Errors
+    /// Returns an error if the metadata of any of the payback notes is invalid.
+    pub fn build(self) -> Result<SwapFillRequest, NoteError> {
+        let note_ids = self.offers.iter().map(SwapOffer::note_id).collect();
+        let payback_notes = self
+            .offers
+            .iter()
+            .map(|offer| offer.payback_note(self.taker))
+            .collect::<Result<_, _>>()?;
+        let tx_args = match self.tx_script {
+            Some(tx_script) => TransactionArgs::with_tx_script(tx_script),
+            None => TransactionArgs::default(),
+        };
+
+        Ok(SwapFillRequest { note_ids, payback_notes, tx_args })
+    }
+}
+
+/// The request for a transaction filling one or more [SwapOffer]s, as built by [SwapFill].
+#[derive(Debug, Clone)]
+pub struct SwapFillRequest {
+    note_ids: Vec<NoteId>,
+    payback_notes: Vec<NoteEnvelope>,
+    tx_args: TransactionArgs,
+}
+
+impl SwapFillRequest {
+    /// Returns the IDs of the SWAP notes consumed by the transaction.
+    pub fn note_ids(&self) -> &[NoteId] {
+        &self.note_ids
+    }
+
+    /// Returns the envelopes of the payback notes created by the transaction, in the order in
+    /// which the offers were added.
+    pub fn payback_notes(&self) -> &[NoteEnvelope] {
+        &self.payback_notes
+    }
+
+    /// Returns the arguments of the transaction.
+    pub fn tx_args(&self) -> &TransactionArgs {
+        &self.tx_args
+    }
+
+    /// Returns true if the provided transaction consumed all SWAP notes and created all payback
+    /// notes of this request.
+    pub fn is_fulfilled_by(&self, executed_tx: &ExecutedTransaction) -> bool {
+        let consumed: Vec<NoteId> =
+            executed_tx.input_notes().iter().map(|note| note.id()).collect();
+        let created: Vec<NoteEnvelope> =
+            executed_tx.output_notes().iter().map(NoteEnvelope::from).collect();
+
+        self.note_ids.iter().all(|note_id| consumed.contains(note_id))
+            && self.payback_notes.iter().all(|note| created.contains(note))
+    }
+}
+
+// PARTIAL SWAP FILL
+// ================================================================================================
+
+/// A fill of the offer made by a SWAPP note created via
+/// [create_partial_swap_note](super::create_partial_swap_note).
+///
+/// The account filling the offer sends a part of the requested asset to the maker of the offer in
+/// a P2ID payback note, and receives the corresponding part of the offered asset. Unless the offer
+/// is filled completely, the account also creates a remainder note, i.e., a SWAPP note offering
+/// the rest of the offered asset for the rest of the requested asset.
+///
+/// As the SWAPP script computes the recipients of these notes at runtime, the details of the notes
+/// which are public must be provided to the transaction, which is done together with setting the
+/// note args of the SWAPP note via [PartialSwapFill::extend_tx_args()].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartialSwapFill {
+    note_id: NoteId,
+    requested_fill: u64,
+    offered_fill: u64,
+    payback_note: Note,
+    remainder_note: Option<Note>,
+}
+
+impl PartialSwapFill {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the fill of the offer made by the provided SWAPP note, in which the `taker` sends
+    /// `requested_fill` units of the requested asset to the maker.
+    ///
+    /// The taker receives as many units of the offered asset as the price of the offer allows,
+    /// rounded down, or the whole offered asset if the requested asset is filled completely.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The script of the note is not the SWAPP script.
+    /// - The note does not have exactly 12 inputs.
+    /// - The note does not carry exactly one fungible asset, or the requested asset is not a
+    ///   fungible asset.
+    /// - The maker or the tag or type of the payback note is not encoded correctly.
+    /// - `requested_fill` is zero or greater than the requested amount.
+    /// - Deserialization or compilation of the `SWAPP` or `P2ID` script fails.
+    pub fn new(note: &Note, taker: AccountId, requested_fill: u64) -> Result<Self, NoteError> {
+        let expected = partial_swap_script()?.hash();
+        let actual = note.script().hash();
+        if actual != expected {
+            return Err(NoteError::NoteScriptMismatch { expected, actual });
+        }
+
+        let inputs = note.inputs().values();
+        if inputs.len() != PARTIAL_SWAP_NOTE_NUM_INPUTS {
+            return Err(NoteError::UnexpectedNumNoteInputs {
+                expected: PARTIAL_SWAP_NOTE_NUM_INPUTS,
+                actual: inputs.len(),
+            });
+        }
+
+        let offered = match note.assets().iter().as_slice() {
+            [Asset::Fungible(asset)] => *asset,
+            [asset] => return Err(NoteError::InvalidPartialSwapAsset(*asset)),
+            [] => return Err(NoteError::empty_asset_list()),
+            assets => return Err(NoteError::too_many_assets(1, assets.len())),
+        };
+
+        let requested: Word = inputs[0..4].try_into().expect("asset is a word");
+        let requested = match Asset::try_from(requested).map_err(NoteError::InvalidAssetData)? {
+            Asset::Fungible(asset) => asset,
+            asset => return Err(NoteError::InvalidPartialSwapAsset(asset)),
+        };
+        let serial_seed: Word = inputs[4..8].try_into().expect("serial seed is a word");
+        let maker = AccountId::try_from(inputs[8]).map_err(NoteError::InvalidNoteSender)?;
+        let payback_tag =
+            encoding::u32_from_felt(inputs[9]).map_err(NoteError::InvalidInputEncoding)?;
+        let payback_note_type = NoteType::try_from(inputs[10])?;
+
+        if requested_fill == 0 || requested_fill > requested.amount() {
+            return Err(NoteError::InvalidPartialSwapFill {
+                requested_fill,
+                requested_amount: requested.amount(),
+            });
+        }
+
+        let requested_remainder = requested.amount() - requested_fill;
+        let offered_fill = match requested_remainder {
+            0 => offered.amount(),
+            _ => {
+                (requested_fill as u128 * offered.amount() as u128 / requested.amount() as u128)
+                    as u64
+            },
+        };
+
+        // the payback note is a P2ID note for the maker with the serial seed as its serial number
+        let payback_asset = fungible_asset(requested.faucet_id(), requested_fill)?;
+        let payback_recipient =
+            NoteRecipient::new(serial_seed, p2id_script()?, NoteInputs::new(vec![maker.into()])?);
+        let payback_metadata =
+            NoteMetadata::new(taker, payback_note_type, payback_tag.into(), ZERO)?;
+        let payback_note =
+            Note::new(NoteAssets::new(vec![payback_asset])?, payback_metadata, payback_recipient);
+
+        // the remainder note uses hash(SERIAL_SEED, ZERO) both as its serial number and as its
+        // serial seed
+        let remainder_note = if requested_remainder == 0 {
+            None
+        } else {
+            let remainder_seed: Word = NoteRecipient::serial_num_commitment(serial_seed).into();
+            let requested_asset = fungible_asset(requested.faucet_id(), requested_remainder)?;
+            let offered_asset =
+                fungible_asset(offered.faucet_id(), offered.amount() - offered_fill)?;
+
+            let mut remainder_inputs = Word::from(requested_asset).to_vec();
+            remainder_inputs.extend_from_slice(&remainder_seed);
+            remainder_inputs.extend_from_slice(&inputs[8..12]);
+
+            let recipient = NoteRecipient::new(
+                remainder_seed,
+                note.script().clone(),
+                NoteInputs::new(remainder_inputs)?,
+            );
+            let metadata =
+                NoteMetadata::new(taker, note.metadata().note_type(), note.metadata().tag(), ZERO)?;
+            Some(Note::new(NoteAssets::new(vec![offered_asset])?, metadata, recipient))
+        };
+
+        Ok(Self {
+            note_id: note.id(),
+            requested_fill,
+            offered_fill,
+            payback_note,
+            remainder_note,
+        })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the ID of the SWAPP note being filled.
+    pub fn note_id(&self) -> NoteId {
+        self.note_id
+    }
+
+    /// Returns the amount of the requested asset sent to the maker.
+    pub fn requested_fill(&self) -> u64 {
+        self.requested_fill
+    }
+
+    /// Returns the amount of the offered asset received by the taker.
+    pub fn offered_fill(&self) -> u64 {
+        self.offered_fill
+    }
+
+    /// Returns true if this fill fills the offer completely, i.e., if no remainder note is
+    /// created.
+    pub fn is_complete(&self) -> bool {
+        self.remainder_note.is_none()
+    }
+
+    /// Returns the note args which provide the filled amounts to the SWAPP script, i.e.,
+    /// [requested_fill, offered_fill, 0, 0].
+    pub fn note_args(&self) -> Word {
+        [Felt::new(self.requested_fill), Felt::new(self.offered_fill), ZERO, ZERO]
+    }
+
+    /// Returns the P2ID note created for the maker.
+    pub fn payback_note(&self) -> &Note {
+        &self.payback_note
+    }
+
+    /// Returns the SWAPP note offering the remainder of the offer, or None if the offer is filled
+    /// completely.
+    pub fn remainder_note(&self) -> Option<&Note> {
+        self.remainder_note.as_ref()
+    }
+
+    // PUBLIC MODIFIERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Adds the note args of the SWAPP note and the details of the public notes created by this
+    /// fill to the provided transaction arguments.
+    pub fn extend_tx_args(&self, tx_args: &mut TransactionArgs) {
+        tx_args.add_note_args(self.note_id, self.note_args());
+        for note in core::iter::once(&self.payback_note).chain(self.remainder_note.as_ref()) {
+            if note.metadata().note_type() == NoteType::Public {
+                tx_args.add_expected_output_note(note);
+            }
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the SWAP note script.
+fn swap_script() -> Result<NoteScript, NoteError> {
+    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/SWAP.masb"));
+    build_note_script(bytes)
+}
+
+/// Returns the SWAPP note script.
+fn partial_swap_script() -> Result<NoteScript, NoteError> {
+    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/SWAPP.masb"));
+    build_note_script(bytes)
+}
+
+/// Returns the P2ID note script.
+fn p2id_script() -> Result<NoteScript, NoteError> {
+    let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/P2ID.masb"));
+    build_note_script(bytes)
+}
+
+/// Makes sure the provided assets can be swapped via a SWAPP note, i.e., that both of them are
+/// fungible assets with non-zero amounts, and that the product of their amounts is smaller than
+/// 2^63.
+pub(super) fn validate_partial_swap_assets(
+    offered_asset: Asset,
+    requested_asset: Asset,
+) -> Result<(), NoteError> {
+    let amount = |asset: Asset| match asset {
+        Asset::Fungible(fungible) if fungible.amount() != 0 => Ok(fungible.amount()),
+        _ => Err(NoteError::InvalidPartialSwapAsset(asset)),
+    };
+    let offered_amount = amount(offered_asset)?;
+    let requested_amount = amount(requested_asset)?;
+
+    if offered_amount as u128 * requested_amount as u128 >= MAX_PARTIAL_SWAP_AMOUNTS_PRODUCT {
+        return Err(NoteError::PartialSwapAmountsTooLarge { offered_amount, requested_amount });
+    }
+
+    Ok(())
+}
+
+/// Returns a fungible asset of the specified faucet with the specified amount.
+fn fungible_asset(faucet_id: AccountId, amount: u64) -> Result<Asset, NoteError> {
+    FungibleAsset::new(faucet_id, amount)
+        .map(Asset::from)
+        .map_err(NoteError::InvalidAssetData)
+}
+
+/// Returns true if the offered asset covers the requested asset.
+fn covers(offered: &Asset, requested: &Asset) -> bool {
+    match (offered, requested) {
+        (Asset::Fungible(offered), Asset::Fungible(requested)) => {
+            offered.is_from_same_faucet(requested) && offered.amount() >= requested.amount()
+        },
+        (offered, requested) => offered == requested,
+    }
+}
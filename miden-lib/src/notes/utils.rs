@@ -1,10 +1,32 @@
 use miden_objects::{
-    accounts::AccountId, assembly::ProgramAst, notes::NoteScript, Digest, Hasher, NoteError, Word,
-    ZERO,
+    accounts::AccountId,
+    assembly::ProgramAst,
+    assets::Asset,
+    notes::{NoteExecutionMode, NoteInputs, NoteRecipient, NoteScript, NoteTag, NoteType},
+    Digest, NoteError, Word,
 };
 
 use crate::transaction::TransactionKernel;
 
+// NOTE UTILS
+// ================================================================================================
+
+// The MAST roots of the procedures exported by the `miden::note_utils` module. Note scripts which
+// rely on these procedures can use these constants to verify the procedures they invoke.
+const NOTE_UTILS_MASTS: [&str; 4] = [
+    "0xb4777fa25955082fd36da137e60047d3ef74268c774050c3ebf2bb6338316c3d",
+    "0xe5e7558115a18f1bb94340c37c80435f6e5566cae9830ac7f5df0ceba8255f12",
+    "0x710582a7c102751d01f390227536925e92cf38a5ac0f2a947568b6f09bf4ae1c",
+    "0xd70eea887f79bf6c280028c1fb053c8be95263950f2ed09c5a7cea6192bb0beb",
+];
+pub const NOTE_UTILS_ASSERT_CONSUMER_IS_MAST_ROOT: &str = NOTE_UTILS_MASTS[0];
+pub const NOTE_UTILS_ADD_NOTE_ASSETS_TO_ACCOUNT_MAST_ROOT: &str = NOTE_UTILS_MASTS[1];
+pub const NOTE_UTILS_CREATE_P2ID_PAYBACK_MAST_ROOT: &str = NOTE_UTILS_MASTS[2];
+pub const NOTE_UTILS_CHECK_HEIGHT_WINDOW_MAST_ROOT: &str = NOTE_UTILS_MASTS[3];
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
 /// Creates the note_script from inputs
 pub fn build_note_script(bytes: &[u8]) -> Result<NoteScript, NoteError> {
     let note_assembler = TransactionKernel::assembler();
@@ -15,21 +37,35 @@ pub fn build_note_script(bytes: &[u8]) -> Result<NoteScript, NoteError> {
     Ok(note_script)
 }
 
-/// Creates the RECIPIENT for the P2ID note script created by the SWAP script
+/// Creates the RECIPIENT for the P2ID note script created by the SWAP and VOUCHER scripts
+///
+/// The same recipient is computed in MASM by the `miden::note_utils::build_p2id_recipient`
+/// procedure.
 pub fn build_p2id_recipient(target: AccountId, serial_num: Word) -> Result<Digest, NoteError> {
     // TODO: add lazy_static initialization or compile-time optimization instead of re-generating
     // the script hash every time we call the SWAP script
     let bytes = include_bytes!(concat!(env!("OUT_DIR"), "/assets/note_scripts/P2ID.masb"));
     let note_script = build_note_script(bytes)?;
 
-    let script_hash = note_script.hash();
+    let note_inputs = NoteInputs::new(vec![target.into()])?;
 
-    let serial_num_hash = Hasher::merge(&[serial_num.into(), Digest::default()]);
-
-    let merge_script = Hasher::merge(&[serial_num_hash, script_hash]);
+    Ok(NoteRecipient::new(serial_num, note_script, note_inputs).digest())
+}
 
-    Ok(Hasher::merge(&[
-        merge_script,
-        Hasher::hash_elements(&[target.into(), ZERO, ZERO, ZERO]),
-    ]))
+/// Returns the tag of a note created by the `sender` offering the `offered_asset` in exchange for
+/// the `requested_asset`, as used by the SWAP and SWAPP scripts.
+///
+/// Public notes are tagged for the swap use case (see [NoteTag::for_swap()]), so that they can be
+/// discovered by any account interested in the pair of assets. The details of other notes must be
+/// shared with the consumers by the `sender`, and so such notes are tagged for the `sender`.
+pub fn build_swap_tag(
+    sender: AccountId,
+    offered_asset: &Asset,
+    requested_asset: &Asset,
+    note_type: NoteType,
+) -> Result<NoteTag, NoteError> {
+    match note_type {
+        NoteType::Public => Ok(NoteTag::for_swap(offered_asset, requested_asset)),
+        _ => NoteTag::from_account_id(sender, NoteExecutionMode::Local),
+    }
 }
@@ -6,10 +6,13 @@ use vm_processor::{ContextId, MemAdviceProvider, Process, ProcessState};
 use super::Library;
 
 mod test_account;
+mod test_account_component;
 mod test_asset;
 mod test_asset_vault;
 mod test_epilogue;
 mod test_faucet;
+mod test_kernel_abi;
+mod test_memory_layout;
 mod test_note;
 mod test_prologue;
 mod test_tx;
@@ -1,3 +1,5 @@
+use alloc::vec::Vec;
+
 use miden_objects::{
     accounts::{
         AccountId, AccountType, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_INSUFFICIENT_ONES,
@@ -5,17 +7,22 @@ use miden_objects::{
         ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
         ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
     },
+    block::{FeeParameters, KernelConfig, KernelLimits},
     crypto::merkle::LeafIndex,
+    MAX_ACCOUNT_PROC_CALL_DEPTH, TX_KERNEL_VERSION,
 };
 use mock::{
     constants::{
         CHILD_ROOT_PARENT_LEAF_INDEX, CHILD_SMT_DEPTH, CHILD_STORAGE_INDEX_0, CHILD_STORAGE_VALUE_0,
     },
     mock::{
-        account::{storage_item_0, storage_item_1, MockAccountType},
+        account::{
+            storage_array_2, storage_item_0, storage_item_1, MockAccountType,
+            STORAGE_ARRAY_ITEM_INDEX_2, STORAGE_ARRAY_VALUE_2, STORAGE_INDEX_2,
+        },
         host::MockHost,
         notes::AssetPreservationStatus,
-        transaction::{mock_executed_tx, mock_inputs},
+        transaction::{mock_executed_tx, mock_inputs, mock_inputs_with_kernel_config},
     },
     prepare_transaction,
     procedures::{output_notes_data_procedure, prepare_word},
@@ -273,6 +280,32 @@ fn test_get_item() {
     }
 }
 
+#[test]
+fn test_get_auth_scheme() {
+    let (tx_inputs, tx_args) =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+
+    // the mock account does not store a version in the auth scheme slot
+    let code = "
+        use.miden::account
+        use.miden::kernels::tx::prologue
+
+        begin
+            # prepare the transaction
+            exec.prologue::prepare_transaction
+
+            # get the version of the authentication scheme
+            exec.account::get_auth_scheme
+
+            # assert the version is empty
+            padw assert_eqw
+        end
+        ";
+
+    let transaction = prepare_transaction(tx_inputs, tx_args, code, None);
+    let _process = run_tx(&transaction).unwrap();
+}
+
 #[test]
 fn test_set_item() {
     let (tx_inputs, tx_args) =
@@ -291,41 +324,151 @@ fn test_set_item() {
     let code = format!(
         "
     use.miden::account
-    use.miden::kernels::tx::memory
     use.miden::kernels::tx::prologue
 
     begin
         # prepare the transaction
         exec.prologue::prepare_transaction
 
+        # push values which must be preserved below the inputs of the procedure
+        push.1.2.3.4.5.6.7.8.9
+
         # push the new storage item onto the stack
         push.{new_value}
 
         # push the account storage item index
         push.{new_item_index}
 
-        # get the item
+        # set the item
         exec.account::set_item
 
-        # assert empty old value
+        # assert the new storage root is correct
+        push.{new_root} assert_eqw
+
+        # assert empty old value
         padw assert_eqw
 
-        # get the new storage root
-        exec.memory::get_acct_storage_root
+        # assert the values below the inputs were preserved
+        push.9 assert_eq push.8 assert_eq push.7 assert_eq push.6 assert_eq push.5 assert_eq
+        push.4 assert_eq push.3 assert_eq push.2 assert_eq push.1 assert_eq
+    end
+    ",
+        new_value = prepare_word(&new_item_value),
+        new_item_index = new_item_index.value(),
+        new_root = prepare_word(&account_smt.root()),
+    );
+
+    let transaction = prepare_transaction(tx_inputs, tx_args, &code, None);
+    let _process = run_tx(&transaction).unwrap();
+}
+
+#[test]
+fn test_get_array_item() {
+    let (tx_inputs, tx_args) =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+
+    let code = format!(
+        "
+    use.miden::account
+    use.miden::kernels::tx::prologue
+
+    begin
+        # prepare the transaction
+        exec.prologue::prepare_transaction
+
+        # get the array item
+        push.{item_index}.{slot_index}
+        exec.account::get_array_item
 
         # assert the item value is correct
+        push.{item_value} assert_eqw
+
+        # items which were not set are empty
+        push.0.{slot_index}
+        exec.account::get_array_item
+        padw assert_eqw
+    end
+    ",
+        slot_index = STORAGE_INDEX_2,
+        item_index = STORAGE_ARRAY_ITEM_INDEX_2,
+        item_value = prepare_word(&STORAGE_ARRAY_VALUE_2),
+    );
+
+    let transaction = prepare_transaction(tx_inputs, tx_args, &code, None);
+    let _process = run_tx(&transaction).unwrap();
+}
+
+#[test]
+fn test_set_array_item() {
+    let (tx_inputs, tx_args) =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+
+    // compute the expected array root
+    let mut array = storage_array_2();
+    let new_item_index = 7;
+    let new_item_value: Word = [Felt::new(91), Felt::new(92), Felt::new(93), Felt::new(94)];
+    array.set(new_item_index, new_item_value).unwrap();
+
+    let code = format!(
+        "
+    use.miden::account
+    use.miden::kernels::tx::prologue
+
+    begin
+        # prepare the transaction
+        exec.prologue::prepare_transaction
+
+        # set the array item
+        push.{new_value}.{new_item_index}.{slot_index}
+        exec.account::set_array_item
+
+        # assert empty old value
+        padw assert_eqw
+
+        # assert the new item value can be read back
+        push.{new_item_index}.{slot_index}
+        exec.account::get_array_item
+        push.{new_value} assert_eqw
+
+        # assert the slot holds the new array root
+        push.{slot_index}
+        exec.account::get_item
         push.{new_root} assert_eqw
     end
     ",
+        slot_index = STORAGE_INDEX_2,
         new_value = prepare_word(&new_item_value),
-        new_item_index = new_item_index.value(),
-        new_root = prepare_word(&account_smt.root()),
+        new_root = prepare_word(&array.root()),
     );
 
     let transaction = prepare_transaction(tx_inputs, tx_args, &code, None);
     let _process = run_tx(&transaction).unwrap();
 }
 
+#[test]
+fn test_set_array_item_fails_on_value_slot() {
+    let (tx_inputs, tx_args) =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+
+    let code = format!(
+        "
+    use.miden::account
+    use.miden::kernels::tx::prologue
+
+    begin
+        exec.prologue::prepare_transaction
+
+        push.1.2.3.4.0.{slot_index}
+        exec.account::set_array_item
+    end
+    ",
+        slot_index = storage_item_0().index,
+    );
+
+    let transaction = prepare_transaction(tx_inputs, tx_args, &code, None);
+    assert!(run_tx(&transaction).is_err());
+}
+
 // TODO: reenable once storage map support is implemented
 #[ignore]
 #[test]
@@ -446,3 +589,139 @@ fn test_authenticate_procedure() {
         }
     }
 }
+
+// PROCEDURE GUARD TESTS
+// ================================================================================================
+
+#[test]
+fn test_enter_and_exit_procedure() {
+    let (tx_inputs, tx_args) =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+    let account = tx_inputs.account();
+
+    let proc0_root = account.code().procedure_tree().get_leaf(&LeafIndex::new(0).unwrap());
+    let proc1_root = account.code().procedure_tree().get_leaf(&LeafIndex::new(1).unwrap());
+
+    let code = format!(
+        "
+        use.miden::kernels::tx::account
+        use.miden::kernels::tx::memory
+        use.miden::kernels::tx::prologue
+
+        begin
+            exec.prologue::prepare_transaction
+
+            # enter two different procedures and assert the call depth was incremented
+            push.{proc0_root} exec.account::enter_procedure
+            push.{proc1_root} exec.account::enter_procedure
+            exec.memory::get_acct_proc_call_depth push.2 assert_eq
+
+            # exit both procedures and assert the call depth was decremented
+            push.{proc1_root} exec.account::exit_procedure
+            push.{proc0_root} exec.account::exit_procedure
+            exec.memory::get_acct_proc_call_depth push.0 assert_eq
+
+            # the procedure can be entered again after it has been exited
+            push.{proc0_root} exec.account::enter_procedure
+        end
+        ",
+        proc0_root = prepare_word(&proc0_root),
+        proc1_root = prepare_word(&proc1_root),
+    );
+
+    let transaction = prepare_transaction(tx_inputs, tx_args, &code, None);
+    let _process = run_tx(&transaction).unwrap();
+}
+
+#[test]
+fn test_enter_procedure_fails() {
+    let (tx_inputs, _tx_args) =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+    let account = tx_inputs.account();
+
+    let proc0_root =
+        prepare_word(&account.code().procedure_tree().get_leaf(&LeafIndex::new(0).unwrap()));
+
+    let test_cases = vec![
+        // re-entering a procedure which is already being executed
+        format!("push.{proc0_root} exec.account::enter_procedure push.{proc0_root} exec.account::enter_procedure"),
+        // exiting a procedure which is not being executed
+        format!("push.{proc0_root} exec.account::exit_procedure"),
+        // exceeding the maximum call depth
+        format!("push.{MAX_ACCOUNT_PROC_CALL_DEPTH} exec.memory::set_acct_proc_call_depth push.{proc0_root} exec.account::enter_procedure"),
+        // entering a procedure which is not part of the account code
+        format!("push.{} exec.account::enter_procedure", prepare_word(&[ONE, ZERO, ONE, ZERO])),
+    ];
+
+    for test_case in test_cases.into_iter() {
+        let (tx_inputs, tx_args) =
+            mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+
+        let code = format!(
+            "
+            use.miden::kernels::tx::account
+            use.miden::kernels::tx::memory
+            use.miden::kernels::tx::prologue
+
+            begin
+                exec.prologue::prepare_transaction
+                {test_case}
+            end
+            "
+        );
+
+        let transaction = prepare_transaction(tx_inputs, tx_args, &code, None);
+        let process = run_tx(&transaction);
+
+        assert!(process.is_err());
+    }
+}
+
+#[test]
+fn test_enter_procedure_call_depth_is_configurable() {
+    // only a single guarded procedure can be executing at a time
+    let kernel_config = KernelConfig::new(
+        TX_KERNEL_VERSION,
+        KernelConfig::ALL_FEATURES,
+        FeeParameters::default(),
+        KernelLimits::default().with_max_account_proc_call_depth(1),
+    )
+    .unwrap();
+
+    let code = |num_entered_procs: u64| {
+        let (tx_inputs, tx_args) = mock_inputs_with_kernel_config(
+            MockAccountType::StandardExisting,
+            AssetPreservationStatus::Preserved,
+            kernel_config.clone(),
+        );
+
+        let enter_procs = (0..num_entered_procs)
+            .map(|idx| {
+                let proc_root = tx_inputs
+                    .account()
+                    .code()
+                    .procedure_tree()
+                    .get_leaf(&LeafIndex::new(idx).unwrap());
+                format!("push.{} exec.account::enter_procedure", prepare_word(&proc_root))
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+
+        let code = format!(
+            "
+            use.miden::kernels::tx::account
+            use.miden::kernels::tx::prologue
+
+            begin
+                exec.prologue::prepare_transaction
+                {enter_procs}
+            end
+            "
+        );
+
+        prepare_transaction(tx_inputs, tx_args, &code, None)
+    };
+
+    assert!(run_tx(&code(1)).is_ok());
+    assert!(run_tx(&code(2)).is_err());
+}
@@ -0,0 +1,128 @@
+use alloc::vec::Vec;
+
+use miden_objects::{
+    accounts::{AccountCode, AccountId, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN},
+    assembly::ModuleAst,
+    AccountError, Felt, Word, ONE, ZERO,
+};
+
+use crate::{
+    account_component,
+    accounts::component::{AccountComponent, ProcedureCall},
+    transaction::TransactionKernel,
+};
+
+account_component! {
+    /// A component with a counter which also receives assets.
+    mod counter {
+        source: "
+            use.miden::account
+            use.miden::contracts::wallets::basic->wallet
+
+            const.COUNTER_SLOT=1
+            const.OWNER_SLOT=2
+
+            export.wallet::receive_asset
+
+            export.increment
+                push.COUNTER_SLOT exec.account::get_item
+                movup.4 add push.COUNTER_SLOT exec.account::set_item dropw dropw
+            end
+
+            export.set_owner
+                push.OWNER_SLOT exec.account::set_item dropw dropw
+            end
+        ",
+        storage: {
+            /// The value of the counter.
+            COUNTER_SLOT = 1,
+            /// The owner of the counter.
+            OWNER_SLOT = 2,
+        },
+        procedures: {
+            /// Receives an asset.
+            receive_asset => ReceiveAssetArgs { asset: Word },
+            /// Increments the counter by the specified amount.
+            increment => IncrementArgs { amount: Felt },
+            /// Sets the owner of the counter.
+            set_owner => SetOwnerArgs { owner: Word },
+        },
+    }
+}
+
+#[test]
+fn test_account_component_procedure_roots() {
+    let component = counter::compile().unwrap();
+    assert_eq!(counter::COUNTER_SLOT, 1);
+    assert_eq!(counter::OWNER_SLOT, 2);
+    assert_eq!(counter::PROCEDURES, ["receive_asset", "increment", "set_owner"]);
+
+    // every declared procedure can be found in the compiled code
+    let roots: Vec<_> = counter::PROCEDURES
+        .iter()
+        .map(|name| component.procedure_root(name).unwrap())
+        .collect();
+    assert!(roots.iter().all(|root| component.code().has_procedure(*root)));
+    assert_eq!(component.code().num_procedures(), roots.len());
+    assert_eq!(component.procedure_root("decrement"), None);
+
+    // the root of the re-exported procedure is the root of the basic wallet procedure
+    let wallet_module = ModuleAst::parse(
+        "
+        use.miden::contracts::wallets::basic->wallet
+        export.wallet::receive_asset
+        ",
+    )
+    .unwrap();
+    let wallet_code = AccountCode::new(wallet_module, &TransactionKernel::assembler()).unwrap();
+    assert_eq!(roots[0], wallet_code.procedures()[0]);
+}
+
+#[test]
+fn test_account_component_interface_mismatch() {
+    let err =
+        AccountComponent::compile(counter::SOURCE, &["receive_asset", "increment"]).unwrap_err();
+    assert_eq!(err, AccountError::AccountCodeProcedureNotDeclared("set_owner".into()));
+
+    let err = AccountComponent::compile(
+        counter::SOURCE,
+        &["receive_asset", "increment", "set_owner", "decrement"],
+    )
+    .unwrap_err();
+    assert_eq!(err, AccountError::AccountCodeProcedureNotExported("decrement".into()));
+}
+
+#[test]
+fn test_account_component_procedure_call() {
+    let component = counter::compile().unwrap();
+
+    let owner: AccountId = ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN.try_into().unwrap();
+    let args = counter::SetOwnerArgs {
+        owner: [owner.into(), ONE, ZERO, Felt::new(7)],
+    };
+    assert_eq!(args.stack_inputs(), vec![Felt::new(7), ZERO, ONE, owner.into()]);
+
+    let root = component.procedure_root("set_owner").unwrap();
+    assert_eq!(
+        args.to_masm(&component).unwrap(),
+        format!("push.{}.1.0.7 call.{}", u64::from(owner), root.to_hex())
+    );
+
+    let args = counter::IncrementArgs { amount: Felt::new(3) };
+    let root = component.procedure_root("increment").unwrap();
+    assert_eq!(args.to_masm(&component).unwrap(), format!("push.3 call.{}", root.to_hex()));
+
+    // a call can only be made to a component exporting the procedure
+    let other = AccountComponent::compile(
+        "
+        use.miden::contracts::wallets::basic->wallet
+        export.wallet::receive_asset
+        ",
+        &["receive_asset"],
+    )
+    .unwrap();
+    assert_eq!(
+        args.to_masm(&other).unwrap_err(),
+        AccountError::AccountCodeProcedureNotExported("increment".into())
+    );
+}
@@ -1,16 +1,14 @@
+use miden_objects::{vm::StackOutputs, TransactionOutputError};
 use mock::{
     mock::{notes::AssetPreservationStatus, transaction::mock_executed_tx},
     procedures::output_notes_data_procedure,
     run_within_tx_kernel,
 };
 
-use super::{
-    build_module_path, ContextId, MemAdviceProvider, ProcessState, Word, TX_KERNEL_DIR, ZERO,
-};
+use super::{build_module_path, ContextId, MemAdviceProvider, ProcessState, TX_KERNEL_DIR, ZERO};
 use crate::transaction::{
     memory::{CREATED_NOTE_ASSET_HASH_OFFSET, CREATED_NOTE_SECTION_OFFSET, NOTE_MEM_SIZE},
-    ToTransactionKernelInputs, FINAL_ACCOUNT_HASH_WORD_IDX, OUTPUT_NOTES_COMMITMENT_WORD_IDX,
-    TX_SCRIPT_ROOT_WORD_IDX,
+    ToTransactionKernelInputs, TransactionKernel, TransactionKernelOutputs,
 };
 
 const EPILOGUE_FILE: &str = "epilogue.masm";
@@ -46,26 +44,43 @@ fn test_epilogue() {
     )
     .unwrap();
 
+    let kernel_outputs =
+        TransactionKernelOutputs::parse(&process.stack.build_stack_outputs()).unwrap();
+
     // assert tx script root is correct
     assert_eq!(
-        process.stack.get_word(TX_SCRIPT_ROOT_WORD_IDX),
-        executed_transaction
-            .tx_args()
-            .tx_script()
-            .as_ref()
-            .map_or_else(Word::default, |s| **s.hash())
+        kernel_outputs.tx_script_root(),
+        executed_transaction.tx_args().tx_script().map(|s| *s.hash())
     );
 
     // assert created notes commitment is correct
     assert_eq!(
-        process.stack.get_word(OUTPUT_NOTES_COMMITMENT_WORD_IDX),
-        executed_transaction.output_notes().commitment().as_elements()
+        kernel_outputs.output_notes_commitment(),
+        executed_transaction.output_notes().commitment()
     );
 
     // assert final account hash is correct
+    assert_eq!(kernel_outputs.final_account_hash(), executed_transaction.final_account().hash());
+
+    // assert transaction log commitment is correct
+    assert_eq!(kernel_outputs.tx_log_commitment(), executed_transaction.log().commitment());
+
+    // assert the outputs round-trip through the expected stack layout
+    assert_eq!(
+        TransactionKernel::build_output_stack(
+            kernel_outputs.final_account_hash(),
+            kernel_outputs.output_notes_commitment(),
+            kernel_outputs.tx_script_root(),
+            kernel_outputs.tx_log_commitment(),
+        ),
+        process.stack.build_stack_outputs()
+    );
+
+    // the kernel outputs are rejected if the stack was not truncated
+    let overflowing_stack = StackOutputs::new(vec![ZERO; 17], vec![ZERO; 2]).unwrap();
     assert_eq!(
-        process.stack.get_word(FINAL_ACCOUNT_HASH_WORD_IDX),
-        executed_transaction.final_account().hash().as_elements(),
+        TransactionKernelOutputs::parse(&overflowing_stack),
+        Err(TransactionOutputError::OutputStackInvalidLength { expected: 16, actual: 17 })
     );
 
     // assert stack has been truncated correctly
@@ -0,0 +1,67 @@
+use std::vec::Vec;
+
+use miden_objects::vm::CodeBlock;
+
+use crate::transaction::{TransactionEvent, TransactionKernel};
+
+// TESTS
+// ================================================================================================
+
+#[test]
+fn test_kernel_abi_procedures() {
+    let abi = TransactionKernel::abi();
+
+    // every procedure exported from the kernel API is described
+    let exported: Vec<&str> = TransactionKernel::kernel()
+        .lines()
+        .filter_map(|line| line.trim().strip_prefix("export."))
+        .collect();
+    let described: Vec<&str> = abi.procedures.iter().map(|procedure| procedure.name).collect();
+    assert_eq!(described, exported);
+
+    let procedure = abi.procedure("get_account_item").unwrap();
+    assert_eq!(procedure.inputs, ["index", "0", "0", "0"]);
+    assert_eq!(procedure.outputs, ["VALUE"]);
+    assert!(procedure.description.starts_with("Gets an item from the account storage."));
+    assert!(abi.procedure("get_account_items").is_none());
+}
+
+#[test]
+fn test_kernel_abi_events() {
+    let abi = TransactionKernel::abi();
+
+    // the events described by the ABI are exactly the events handled by the transaction host
+    assert_eq!(abi.events.len(), 9);
+    for event in abi.events {
+        assert!(TransactionEvent::try_from(event.id).is_ok(), "unknown event {}", event.name);
+    }
+
+    let event = abi.event(TransactionEvent::NoteCreated as u32).unwrap();
+    assert_eq!(event.name, "NEW_NOTE_EVENT");
+    assert!(abi.event(0).is_none());
+}
+
+#[test]
+fn test_kernel_dyn_block_hash() {
+    // the kernel authenticates callers invoked via `dyncall` by comparing the caller against a
+    // hardcoded hash of the dynamic code block, which must match the hash computed by the VM
+    let dyn_block_hash: Vec<u64> = (0..4)
+        .map(|i| {
+            let name = format!("const.DYN_BLOCK_HASH_{i}=");
+            TransactionKernel::kernel()
+                .lines()
+                .find_map(|line| line.trim().strip_prefix(name.as_str()))
+                .unwrap_or_else(|| panic!("kernel does not define DYN_BLOCK_HASH_{i}"))
+                .parse()
+                .unwrap()
+        })
+        .collect();
+
+    let expected: Vec<u64> = CodeBlock::new_dyn()
+        .hash()
+        .as_elements()
+        .iter()
+        .map(|felt| felt.as_int())
+        .collect();
+    assert_eq!(dyn_block_hash, expected);
+}
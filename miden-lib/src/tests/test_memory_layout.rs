@@ -0,0 +1,131 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    env, fs,
+    path::PathBuf,
+    string::{String, ToString},
+    vec::Vec,
+};
+
+use miden_objects::TX_KERNEL_VERSION;
+
+use crate::transaction::memory::MEMORY_LAYOUT;
+
+// CONSTANTS
+// ================================================================================================
+
+/// Path of the memory layout snapshot relative to the crate root.
+const SNAPSHOT_PATH: &str = "src/tests/snapshots/memory_layout.txt";
+
+/// If this environment variable is set, the snapshot is regenerated instead of being checked.
+const UPDATE_SNAPSHOTS_VAR: &str = "UPDATE_SNAPSHOTS";
+
+// TESTS
+// ================================================================================================
+
+#[test]
+fn test_memory_layout_snapshot() {
+    let path = PathBuf::from(env!("CARGO_MANIFEST_DIR")).join(SNAPSHOT_PATH);
+    let current = render_layout();
+
+    if env::var_os(UPDATE_SNAPSHOTS_VAR).is_some() {
+        fs::write(&path, &current).unwrap();
+        return;
+    }
+
+    let snapshot = fs::read_to_string(&path).unwrap();
+    if snapshot == current {
+        return;
+    }
+
+    let (snapshot_version, snapshot_layout) = parse_layout(&snapshot);
+    let (_, current_layout) = parse_layout(&current);
+    let diff = diff_layouts(&snapshot_layout, &current_layout);
+
+    if snapshot_version == Some(TX_KERNEL_VERSION) {
+        panic!(
+            "the kernel memory layout changed without a bump of the kernel version \
+            ({TX_KERNEL_VERSION}):\n{diff}\nbump TX_KERNEL_VERSION and regenerate the snapshot by \
+            running the tests with {UPDATE_SNAPSHOTS_VAR}=1"
+        );
+    } else {
+        panic!(
+            "the kernel memory layout snapshot is outdated (kernel version {snapshot_version:?}, \
+            expected {TX_KERNEL_VERSION}):\n{diff}\nregenerate the snapshot by running the tests \
+            with {UPDATE_SNAPSHOTS_VAR}=1"
+        );
+    }
+}
+
+#[test]
+fn test_memory_layout_is_complete() {
+    // all constants defined in the memory module must be part of the layout exactly once
+    let source = include_str!("../transaction/memory.rs");
+    let defined: BTreeSet<&str> = source
+        .lines()
+        .filter_map(|line| line.strip_prefix("pub const "))
+        .filter_map(|line| line.split(':').next())
+        .filter(|name| *name != "MEMORY_LAYOUT")
+        .collect();
+
+    let listed: Vec<&str> = MEMORY_LAYOUT
+        .iter()
+        .flat_map(|section| section.constants.iter().map(|(name, _)| *name))
+        .collect();
+
+    let missing: Vec<&str> =
+        defined.iter().copied().filter(|name| !listed.contains(name)).collect();
+    assert!(missing.is_empty(), "constants missing from MEMORY_LAYOUT: {missing:?}");
+    assert_eq!(listed.len(), defined.len(), "MEMORY_LAYOUT lists constants more than once");
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Renders the current memory layout and the kernel version into the snapshot format.
+fn render_layout() -> String {
+    let mut result = format!("tx kernel version: {TX_KERNEL_VERSION}\n");
+    for section in MEMORY_LAYOUT {
+        result.push_str(&format!("\n[{}]\n", section.name));
+        for (name, value) in section.constants {
+            result.push_str(&format!("{name} = {value}\n"));
+        }
+    }
+    result
+}
+
+/// Parses a snapshot into the kernel version and a map of "section/constant" to constant value.
+fn parse_layout(snapshot: &str) -> (Option<u32>, BTreeMap<String, String>) {
+    let mut version = None;
+    let mut section = "";
+    let mut layout = BTreeMap::new();
+
+    for line in snapshot.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        if let Some(value) = line.strip_prefix("tx kernel version:") {
+            version = value.trim().parse().ok();
+        } else if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            section = name;
+        } else if let Some((name, value)) = line.split_once('=') {
+            layout.insert(format!("{section}/{}", name.trim()), value.trim().to_string());
+        }
+    }
+
+    (version, layout)
+}
+
+/// Returns a readable description of the differences between two memory layouts.
+fn diff_layouts(expected: &BTreeMap<String, String>, actual: &BTreeMap<String, String>) -> String {
+    let mut diff = String::new();
+    for (name, value) in expected {
+        match actual.get(name) {
+            None => diff.push_str(&format!("  removed: {name} (was {value})\n")),
+            Some(new_value) if new_value != value => {
+                diff.push_str(&format!("  changed: {name}: {value} -> {new_value}\n"))
+            },
+            Some(_) => (),
+        }
+    }
+    for (name, value) in actual.iter().filter(|(name, _)| !expected.contains_key(*name)) {
+        diff.push_str(&format!("  added: {name} = {value}\n"));
+    }
+    diff
+}
@@ -1,9 +1,15 @@
-use alloc::{collections::BTreeMap, string::String};
+use alloc::{collections::BTreeMap, string::String, vec::Vec};
 
 use miden_objects::{
-    notes::Note,
+    accounts::{
+        AccountCode, AccountId, ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN,
+        ACCOUNT_ID_SENDER,
+    },
+    assembly::ModuleAst,
+    crypto::rand::RpoRandomCoin,
+    notes::{Note, NoteRecipient, NoteType},
     transaction::{PreparedTransaction, TransactionArgs},
-    WORD_SIZE,
+    Digest, Word, WORD_SIZE,
 };
 use mock::{
     consumed_note_data_ptr,
@@ -17,7 +23,20 @@ use mock::{
 };
 
 use super::{ContextId, Felt, Process, ProcessState, ZERO};
-use crate::transaction::memory::CURRENT_CONSUMED_NOTE_PTR;
+use crate::{
+    notes::{
+        create_p2id_note,
+        utils::{
+            build_p2id_recipient, NOTE_UTILS_ADD_NOTE_ASSETS_TO_ACCOUNT_MAST_ROOT,
+            NOTE_UTILS_ASSERT_CONSUMER_IS_MAST_ROOT, NOTE_UTILS_CHECK_HEIGHT_WINDOW_MAST_ROOT,
+            NOTE_UTILS_CREATE_P2ID_PAYBACK_MAST_ROOT,
+        },
+    },
+    transaction::{
+        memory::{CURRENT_CONSUMED_NOTE_PTR, NOTE_METADATA_NOTE_TYPE_IDX, NOTE_METADATA_TAG_IDX},
+        TransactionKernel,
+    },
+};
 
 #[test]
 fn test_get_sender_no_sender() {
@@ -73,6 +92,61 @@ fn test_get_sender() {
     assert_eq!(process.stack.get(0), sender);
 }
 
+#[test]
+fn test_get_tag_and_note_type() {
+    let (tx_inputs, tx_args) =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+
+    // calling get_tag and get_note_type should return the tag and the type of the note
+    let code = "
+        use.miden::kernels::tx::prologue
+        use.miden::kernels::tx::note->note_internal
+        use.miden::note
+
+        begin
+            exec.prologue::prepare_transaction
+            exec.note_internal::prepare_note
+            dropw dropw dropw dropw
+            exec.note::get_note_type
+            exec.note::get_tag
+        end
+        ";
+
+    let transaction = prepare_transaction(tx_inputs, tx_args, code, None);
+    let process = run_tx(&transaction).unwrap();
+
+    let metadata = Word::from(transaction.input_notes().get_note(0).note().metadata());
+    assert_eq!(process.stack.get(0), metadata[NOTE_METADATA_TAG_IDX]);
+    assert_eq!(process.stack.get(1), metadata[NOTE_METADATA_NOTE_TYPE_IDX]);
+}
+
+#[test]
+fn test_get_tag_no_note() {
+    let (tx_inputs, tx_args) =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+
+    let code = "
+        use.miden::kernels::tx::memory
+        use.miden::kernels::tx::prologue
+        use.miden::note
+
+        begin
+            exec.prologue::prepare_transaction
+
+            # force the current consumed note pointer to 0
+            push.0 exec.memory::set_current_consumed_note_ptr
+
+            # get the tag
+            exec.note::get_tag
+        end
+        ";
+
+    let transaction = prepare_transaction(tx_inputs, tx_args, code, None);
+    let process = run_tx(&transaction);
+
+    assert!(process.is_err());
+}
+
 #[test]
 fn test_get_vault_data() {
     let (tx_inputs, tx_args) =
@@ -299,6 +373,49 @@ fn test_get_inputs() {
     let _process = run_tx(&transaction).unwrap();
 }
 
+#[test]
+fn test_get_inputs_len_and_input() {
+    let (tx_inputs, tx_args) =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+    let note0 = tx_inputs.input_notes().get_note(0).note().clone();
+    let num_inputs = note0.inputs().num_values();
+
+    let input_assertions = note0
+        .inputs()
+        .values()
+        .iter()
+        .enumerate()
+        .map(|(index, input)| format!("push.{index} exec.note::get_input push.{input} assert_eq\n"))
+        .collect::<String>();
+
+    let code = |body: &str| {
+        format!(
+            "
+            use.miden::kernels::tx::prologue
+            use.miden::kernels::tx::note->note_internal
+            use.miden::note
+
+            begin
+                exec.prologue::prepare_transaction
+                exec.note_internal::prepare_note
+                dropw dropw
+                {body}
+            end
+            "
+        )
+    };
+
+    // the number of inputs and every input can be read individually
+    let body = format!("exec.note::get_inputs_len eq.{num_inputs} assert\n{input_assertions}");
+    let transaction = prepare_transaction(tx_inputs.clone(), tx_args.clone(), &code(&body), None);
+    let _process = run_tx(&transaction).unwrap();
+
+    // reading past the last input fails
+    let body = format!("push.{num_inputs} exec.note::get_input");
+    let transaction = prepare_transaction(tx_inputs, tx_args, &code(&body), None);
+    assert!(run_tx(&transaction).is_err());
+}
+
 #[test]
 fn test_note_setup() {
     let (tx_inputs, tx_args) =
@@ -361,6 +478,241 @@ fn test_note_script_and_note_args() {
     assert_eq!(process.stack.get_word(1), note_args[1]);
 }
 
+#[test]
+fn test_get_note_args() {
+    let note_args = [Felt::new(93), Felt::new(94), Felt::new(95), Felt::new(96)];
+
+    let (tx_inputs, mut tx_args) =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+    tx_args.add_note_args(tx_inputs.input_notes().get_note(0).note().id(), note_args);
+
+    let code = "
+        use.miden::kernels::tx::prologue
+        use.miden::kernels::tx::note->note_internal
+        use.miden::note
+
+        begin
+            exec.prologue::prepare_transaction
+            exec.note_internal::prepare_note dropw dropw
+            exec.note::get_args
+        end
+        ";
+
+    let transaction = prepare_transaction(tx_inputs, tx_args, code, None);
+    let process = run_tx(&transaction).unwrap();
+
+    assert_eq!(process.stack.get_word(0), note_args);
+}
+
+#[test]
+fn test_get_note_args_no_note() {
+    let (tx_inputs, tx_args) =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+
+    let code = "
+        use.miden::kernels::tx::memory
+        use.miden::kernels::tx::prologue
+        use.miden::note
+
+        begin
+            exec.prologue::prepare_transaction
+
+            # force the current consumed note pointer to 0
+            push.0 exec.memory::set_current_consumed_note_ptr
+
+            # get the note args
+            exec.note::get_args
+        end
+        ";
+
+    let transaction = prepare_transaction(tx_inputs, tx_args, code, None);
+    let process = run_tx(&transaction);
+
+    assert!(process.is_err());
+}
+
+#[test]
+fn test_note_utils_assert_consumer_is() {
+    let (tx_inputs, tx_args) =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+    let account_id = tx_inputs.account().id();
+
+    let code = |consumer_id: Felt| {
+        format!(
+            "
+            use.miden::kernels::tx::prologue
+            use.miden::note_utils
+
+            begin
+                exec.prologue::prepare_transaction
+                push.{consumer_id} exec.note_utils::assert_consumer_is
+            end
+            "
+        )
+    };
+
+    let transaction =
+        prepare_transaction(tx_inputs.clone(), tx_args.clone(), &code(account_id.into()), None);
+    assert!(run_tx(&transaction).is_ok());
+
+    let other_id = Felt::from(account_id) + Felt::from(1_u8);
+    let transaction = prepare_transaction(tx_inputs, tx_args, &code(other_id), None);
+    assert!(run_tx(&transaction).is_err());
+}
+
+#[test]
+fn test_note_utils_check_height_window() {
+    let (tx_inputs, tx_args) =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+    let block_num = tx_inputs.block_header().block_num();
+
+    let code = |start_block: u32, end_block: u32| {
+        format!(
+            "
+            use.miden::kernels::tx::prologue
+            use.miden::note_utils
+
+            begin
+                exec.prologue::prepare_transaction
+                push.{end_block}.{start_block} exec.note_utils::check_height_window
+            end
+            "
+        )
+    };
+
+    for (start_block, end_block, is_valid) in [
+        (block_num, block_num, true),
+        (0, block_num + 10, true),
+        (block_num + 1, block_num + 10, false),
+        (0, block_num - 1, false),
+    ] {
+        let transaction = prepare_transaction(
+            tx_inputs.clone(),
+            tx_args.clone(),
+            &code(start_block, end_block),
+            None,
+        );
+        assert_eq!(run_tx(&transaction).is_ok(), is_valid);
+    }
+}
+
+#[test]
+fn test_note_utils_compute_recipient() {
+    let (tx_inputs, tx_args) =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+    let note = tx_inputs.input_notes().get_note(0).note().clone();
+
+    let code = format!(
+        "
+        use.miden::kernels::tx::prologue
+        use.miden::note_utils
+
+        begin
+            exec.prologue::prepare_transaction
+            push.{inputs_hash} push.{script_root} push.{serial_num}
+            exec.note_utils::compute_serial_num_commitment
+            exec.note_utils::compute_recipient
+        end
+        ",
+        inputs_hash = prepare_word(&note.inputs().commitment()),
+        script_root = prepare_word(&note.script().hash()),
+        serial_num = prepare_word(&note.serial_num()),
+    );
+
+    let transaction = prepare_transaction(tx_inputs, tx_args, &code, None);
+    let process = run_tx(&transaction).unwrap();
+
+    // the recipient computed in MASM matches the recipient computed in Rust
+    let serial_num_commitment = NoteRecipient::serial_num_commitment(note.serial_num());
+    let recipient = NoteRecipient::digest_from_parts(
+        serial_num_commitment,
+        note.script().hash(),
+        note.inputs().commitment(),
+    );
+    assert_eq!(recipient, note.recipient_digest());
+
+    let mut expected: Word = recipient.into();
+    expected.reverse();
+    assert_eq!(process.stack.trace_state()[..WORD_SIZE], expected);
+}
+
+#[test]
+fn test_note_utils_build_p2id_recipient() {
+    let (tx_inputs, tx_args) =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let target = AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let rng = RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]);
+    let p2id_note = create_p2id_note(sender, target, vec![], NoteType::Public, rng).unwrap();
+
+    // the root of the P2ID note script hardcoded in note_utils::build_p2id_recipient matches the
+    // root of the P2ID note script
+    let note_utils_source = include_str!("../../asm/miden/note_utils.masm");
+    let p2id_script_root = note_utils_source
+        .lines()
+        .skip_while(|line| !line.contains("push the root of the P2ID note script"))
+        .nth(1)
+        .and_then(|line| line.trim().strip_prefix("push."))
+        .expect("the root of the P2ID note script is not pushed by note_utils");
+    let p2id_script_root = p2id_script_root
+        .split('.')
+        .map(|element| Felt::new(element.parse().unwrap()))
+        .collect::<Vec<_>>();
+    let p2id_script_root = Digest::new(p2id_script_root.try_into().unwrap());
+    assert_eq!(p2id_script_root, p2id_note.script().hash());
+
+    let code = format!(
+        "
+        use.miden::kernels::tx::prologue
+        use.miden::note_utils
+
+        begin
+            exec.prologue::prepare_transaction
+            push.{target} push.{serial_num}
+            exec.note_utils::build_p2id_recipient
+        end
+        ",
+        target = Felt::from(target),
+        serial_num = prepare_word(&p2id_note.serial_num()),
+    );
+
+    let transaction = prepare_transaction(tx_inputs, tx_args, &code, None);
+    let process = run_tx(&transaction).unwrap();
+
+    // the recipient computed in MASM matches the recipient of the P2ID note
+    let recipient = build_p2id_recipient(target, p2id_note.serial_num()).unwrap();
+    assert_eq!(recipient, p2id_note.recipient_digest());
+
+    let mut expected: Word = recipient.into();
+    expected.reverse();
+    assert_eq!(process.stack.trace_state()[..WORD_SIZE], expected);
+}
+
+#[test]
+fn test_note_utils_mast_roots() {
+    let code = "
+        use.miden::note_utils
+
+        export.note_utils::assert_consumer_is
+        export.note_utils::add_note_assets_to_account
+        export.note_utils::create_p2id_payback
+        export.note_utils::check_height_window
+        ";
+    let module = ModuleAst::parse(code).unwrap();
+    let code = AccountCode::new(module, &TransactionKernel::assembler()).unwrap();
+
+    for root in [
+        NOTE_UTILS_ASSERT_CONSUMER_IS_MAST_ROOT,
+        NOTE_UTILS_ADD_NOTE_ASSETS_TO_ACCOUNT_MAST_ROOT,
+        NOTE_UTILS_CREATE_P2ID_PAYBACK_MAST_ROOT,
+        NOTE_UTILS_CHECK_HEIGHT_WINDOW_MAST_ROOT,
+    ] {
+        let root = Digest::try_from(root).unwrap();
+        assert!(code.procedures().contains(&root), "unexpected note utils procedure roots");
+    }
+}
+
 fn note_setup_stack_assertions(process: &Process<MockHost>, inputs: &PreparedTransaction) {
     let mut expected_stack = [ZERO; 16];
 
@@ -2,8 +2,10 @@ use alloc::{collections::BTreeMap, vec::Vec};
 
 use miden_objects::{
     assembly::ProgramAst,
+    crypto::merkle::Smt,
     transaction::{PreparedTransaction, TransactionArgs, TransactionScript},
-    Digest,
+    Digest, FeeParameters, Hasher, KernelConfig, KernelLimits, TransactionInputError,
+    TX_KERNEL_VERSION,
 };
 use mock::{
     consumed_note_data_ptr,
@@ -11,26 +13,32 @@ use mock::{
         account::{generate_account_seed, AccountSeedType, MockAccountType},
         host::MockHost,
         notes::AssetPreservationStatus,
-        transaction::{mock_inputs, mock_inputs_with_account_seed},
+        transaction::{
+            mock_inputs, mock_inputs_with_account_seed, mock_inputs_with_kernel_config,
+            mock_inputs_with_note_expiration, mock_inputs_with_nullifier_tree,
+        },
     },
     prepare_transaction, run_tx, run_tx_with_inputs,
 };
-use vm_processor::AdviceInputs;
+use vm_processor::{AdviceInputs, ExecutionError};
 
 use super::{build_module_path, ContextId, Felt, Process, ProcessState, Word, TX_KERNEL_DIR, ZERO};
 use crate::transaction::{
     memory::{
-        MemoryOffset, ACCT_CODE_ROOT_PTR, ACCT_DB_ROOT_PTR, ACCT_ID_AND_NONCE_PTR, ACCT_ID_PTR,
-        ACCT_STORAGE_ROOT_PTR, ACCT_STORAGE_SLOT_TYPE_DATA_OFFSET, ACCT_VAULT_ROOT_PTR,
-        BATCH_ROOT_PTR, BLK_HASH_PTR, BLOCK_METADATA_PTR, BLOCK_NUMBER_IDX,
-        CHAIN_MMR_NUM_LEAVES_PTR, CHAIN_MMR_PEAKS_PTR, CHAIN_ROOT_PTR, CONSUMED_NOTE_ARGS_OFFSET,
-        CONSUMED_NOTE_ASSETS_HASH_OFFSET, CONSUMED_NOTE_ASSETS_OFFSET, CONSUMED_NOTE_ID_OFFSET,
-        CONSUMED_NOTE_INPUTS_HASH_OFFSET, CONSUMED_NOTE_METADATA_OFFSET,
-        CONSUMED_NOTE_NUM_ASSETS_OFFSET, CONSUMED_NOTE_NUM_INPUTS_OFFSET,
-        CONSUMED_NOTE_SCRIPT_ROOT_OFFSET, CONSUMED_NOTE_SECTION_OFFSET,
-        CONSUMED_NOTE_SERIAL_NUM_OFFSET, INIT_ACCT_HASH_PTR, INIT_NONCE_PTR, NOTE_ROOT_PTR,
-        NULLIFIER_COM_PTR, NULLIFIER_DB_ROOT_PTR, PREV_BLOCK_HASH_PTR, PROOF_HASH_PTR,
-        PROTOCOL_VERSION_IDX, TIMESTAMP_IDX, TX_SCRIPT_ROOT_PTR,
+        MemoryOffset, ACCEPTED_FEE_ASSETS_ROOT_PTR, ACCT_CODE_ROOT_PTR, ACCT_DB_ROOT_PTR,
+        ACCT_ID_AND_NONCE_PTR, ACCT_ID_PTR, ACCT_STORAGE_ROOT_PTR,
+        ACCT_STORAGE_SLOT_TYPE_DATA_OFFSET, ACCT_VAULT_ROOT_PTR, BATCH_ROOT_PTR, BLK_HASH_PTR,
+        BLOCK_METADATA_PTR, BLOCK_NUMBER_IDX, CHAIN_MMR_NUM_LEAVES_PTR, CHAIN_MMR_PEAKS_PTR,
+        CHAIN_ROOT_PTR, CONSUMED_NOTE_ARGS_OFFSET, CONSUMED_NOTE_ASSETS_HASH_OFFSET,
+        CONSUMED_NOTE_ASSETS_OFFSET, CONSUMED_NOTE_ID_OFFSET, CONSUMED_NOTE_INPUTS_HASH_OFFSET,
+        CONSUMED_NOTE_METADATA_OFFSET, CONSUMED_NOTE_NUM_ASSETS_OFFSET,
+        CONSUMED_NOTE_NUM_INPUTS_OFFSET, CONSUMED_NOTE_SCRIPT_ROOT_OFFSET,
+        CONSUMED_NOTE_SECTION_OFFSET, CONSUMED_NOTE_SERIAL_NUM_OFFSET, FEE_ASSETS_PTR,
+        FEE_ASSET_IDX_PTR, INIT_ACCT_HASH_PTR, INIT_NONCE_PTR, KERNEL_CONFIG_HASH_PTR,
+        KERNEL_LIMITS_PTR, KERNEL_PARAMS_PTR, NOTE_ARGS_COMMITMENT_PTR,
+        NOTE_BLOCK_ROOTS_SECTION_OFFSET, NOTE_ROOT_PTR, NULLIFIER_COM_PTR, NULLIFIER_DB_ROOT_PTR,
+        NUM_NOTE_BLOCKS_PTR, PREV_BLOCK_HASH_PTR, PROOF_HASH_PTR, PROTOCOL_VERSION_IDX,
+        TIMESTAMP_IDX, TX_SCRIPT_ARGS_PTR, TX_SCRIPT_ROOT_PTR,
     },
     TransactionKernel,
 };
@@ -75,11 +83,12 @@ fn test_transaction_prologue() {
         (tx_inputs.input_notes().get_note(1).note().id(), note_args[1]),
     ]);
 
-    let tx_args = TransactionArgs::new(
+    let mut tx_args = TransactionArgs::new(
         Some(tx_script),
         Some(note_args_map),
         tx_args_notes.advice_map().clone(),
     );
+    tx_args.set_tx_script_args([Felt::new(93); 4]);
 
     let transaction = prepare_transaction(tx_inputs.clone(), tx_args, code, Some(assembly_file));
 
@@ -87,6 +96,7 @@ fn test_transaction_prologue() {
 
     global_input_memory_assertions(&process, &transaction);
     block_data_memory_assertions(&process, &transaction);
+    kernel_config_memory_assertions(&process, &transaction);
     chain_mmr_memory_assertions(&process, &transaction);
     account_data_memory_assertions(&process, &transaction);
     consumed_notes_memory_assertions(&process, &transaction, &note_args);
@@ -122,6 +132,12 @@ fn global_input_memory_assertions(process: &Process<MockHost>, inputs: &Prepared
         read_root_mem_value(process, TX_SCRIPT_ROOT_PTR),
         **inputs.tx_args().tx_script().as_ref().unwrap().hash()
     );
+
+    // The transaction script args should be stored at the TX_SCRIPT_ARGS_PTR
+    assert_eq!(
+        read_root_mem_value(process, TX_SCRIPT_ARGS_PTR),
+        inputs.tx_args().tx_script_args()
+    );
 }
 
 fn block_data_memory_assertions(process: &Process<MockHost>, inputs: &PreparedTransaction) {
@@ -192,6 +208,25 @@ fn block_data_memory_assertions(process: &Process<MockHost>, inputs: &PreparedTr
     );
 }
 
+fn kernel_config_memory_assertions(process: &Process<MockHost>, inputs: &PreparedTransaction) {
+    // The kernel config hash should be stored at the KERNEL_CONFIG_HASH_PTR
+    assert_eq!(
+        read_root_mem_value(process, KERNEL_CONFIG_HASH_PTR),
+        inputs.block_header().kernel_config_hash().as_elements()
+    );
+
+    // The kernel config should be stored at the KERNEL_PARAMS_PTR, KERNEL_LIMITS_PTR,
+    // FEE_ASSETS_PTR and ACCEPTED_FEE_ASSETS_ROOT_PTR
+    let kernel_config = inputs.tx_inputs().kernel_config().to_elements();
+    assert_eq!(read_root_mem_value(process, KERNEL_PARAMS_PTR), kernel_config[..4]);
+    assert_eq!(read_root_mem_value(process, KERNEL_LIMITS_PTR), kernel_config[4..8]);
+    assert_eq!(read_root_mem_value(process, FEE_ASSETS_PTR), kernel_config[8..12]);
+    assert_eq!(read_root_mem_value(process, ACCEPTED_FEE_ASSETS_ROOT_PTR), kernel_config[12..]);
+
+    // The fee is paid in the native fee asset by default
+    assert_eq!(read_root_mem_value(process, FEE_ASSET_IDX_PTR)[0], ZERO);
+}
+
 fn chain_mmr_memory_assertions(process: &Process<MockHost>, prepared_tx: &PreparedTransaction) {
     // update the chain MMR to point to the block against which this transaction is being executed
     let mut chain_mmr = prepared_tx.tx_inputs().block_chain().clone();
@@ -264,6 +299,38 @@ fn consumed_notes_memory_assertions(
         [Felt::new(inputs.input_notes().num_notes() as u64), ZERO, ZERO, ZERO],
     );
 
+    // The commitment to the note args should be stored at the NOTE_ARGS_COMMITMENT_PTR
+    let note_args_commitment =
+        inputs.input_notes().iter().fold(Digest::default(), |commitment, input_note| {
+            let args = inputs.tx_args().get_note_args(input_note.id()).copied().unwrap_or_default();
+            Hasher::merge(&[commitment, args.into()])
+        });
+    assert_eq!(
+        read_root_mem_value(process, NOTE_ARGS_COMMITMENT_PTR),
+        note_args_commitment.as_elements()
+    );
+
+    // Every block in which the notes were created should be authenticated once, and its note root
+    // should be stored in the order in which the blocks are first referenced by the notes
+    let mut note_block_roots = Vec::new();
+    let mut note_blocks = Vec::new();
+    for input_note in inputs.input_notes().iter() {
+        if !note_blocks.contains(&input_note.origin().block_num) {
+            note_blocks.push(input_note.origin().block_num);
+            note_block_roots.push(input_note.proof().note_root());
+        }
+    }
+    assert_eq!(
+        read_root_mem_value(process, NUM_NOTE_BLOCKS_PTR)[0],
+        Felt::from(note_blocks.len() as u32)
+    );
+    for (note_root, block_idx) in note_block_roots.iter().zip(0_u32..) {
+        assert_eq!(
+            read_root_mem_value(process, NOTE_BLOCK_ROOTS_SECTION_OFFSET + block_idx),
+            note_root.as_elements()
+        );
+    }
+
     for (input_note, note_idx) in inputs.input_notes().iter().zip(0_u32..) {
         let note = input_note.note();
 
@@ -502,6 +569,178 @@ pub fn test_prologue_create_account_invalid_seed() {
     assert!(process.is_err());
 }
 
+#[test]
+fn test_prologue_unsupported_kernel_version() {
+    let kernel_config = KernelConfig::new(
+        TX_KERNEL_VERSION + 1,
+        0,
+        FeeParameters::default(),
+        KernelLimits::default(),
+    )
+    .unwrap();
+    let (tx_inputs, tx_args) = mock_inputs_with_kernel_config(
+        MockAccountType::StandardExisting,
+        AssetPreservationStatus::Preserved,
+        kernel_config,
+    );
+
+    let code = "
+    use.miden::kernels::tx::prologue
+
+    begin
+        exec.prologue::prepare_transaction
+    end
+    ";
+
+    let transaction = prepare_transaction(tx_inputs, tx_args, code, None);
+    let process = run_tx(&transaction);
+    assert!(process.is_err());
+}
+
+#[test]
+fn test_prologue_verify_nullifier_proofs() {
+    // the nullifier tree contains nullifiers of notes other than the input notes
+    let nullifier_tree = Smt::with_entries(
+        (1..4).map(|i| (Hasher::hash(&[i]), [Felt::new(i.into()), ZERO, ZERO, ZERO])),
+    )
+    .unwrap();
+    let (tx_inputs, tx_args) = mock_inputs_with_nullifier_tree(
+        MockAccountType::StandardExisting,
+        AssetPreservationStatus::Preserved,
+        &nullifier_tree,
+    );
+
+    let proofs = tx_inputs
+        .input_notes()
+        .iter()
+        .map(|note| nullifier_tree.open(&note.note().nullifier().inner()))
+        .collect::<Vec<_>>();
+    let tx_inputs = tx_inputs.with_nullifier_proofs(proofs).unwrap();
+
+    let code = "
+    use.miden::kernels::tx::prologue
+
+    begin
+        exec.prologue::prepare_transaction
+    end
+    ";
+
+    let transaction = prepare_transaction(tx_inputs, tx_args, code, None);
+    let process = run_tx(&transaction);
+    assert!(process.is_ok());
+}
+
+#[test]
+fn test_prologue_consumed_input_note_nullifier() {
+    let (tx_inputs, _) =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+    let nullifier = tx_inputs.input_notes().get_note(1).note().nullifier().inner();
+    let nullifier_tree =
+        Smt::with_entries([(nullifier, [Felt::new(3), ZERO, ZERO, ZERO])]).unwrap();
+
+    let (tx_inputs, tx_args) = mock_inputs_with_nullifier_tree(
+        MockAccountType::StandardExisting,
+        AssetPreservationStatus::Preserved,
+        &nullifier_tree,
+    );
+
+    // proofs of consumed notes are rejected when attached to the inputs
+    let proofs = tx_inputs
+        .input_notes()
+        .iter()
+        .map(|note| nullifier_tree.open(&note.note().nullifier().inner()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        tx_inputs.clone().with_nullifier_proofs(proofs.clone()),
+        Err(TransactionInputError::InputNoteAlreadyConsumed(
+            tx_inputs.input_notes().get_note(1).id()
+        ))
+    );
+
+    // and the kernel fails to verify the nullifiers of consumed notes
+    let mut advice_inputs = AdviceInputs::default();
+    for proof in proofs {
+        let leaf = proof.leaf();
+        advice_inputs.extend_merkle_store(
+            proof.path().inner_nodes(leaf.index().value(), leaf.hash()).unwrap(),
+        );
+        advice_inputs.extend_map([(leaf.hash(), leaf.to_elements())]);
+    }
+
+    let code = "
+    begin
+        exec.prepare_transaction
+        exec.verify_input_note_nullifiers
+    end
+    ";
+
+    let assembly_file = build_module_path(TX_KERNEL_DIR, PROLOGUE_FILE);
+    let transaction = prepare_transaction(tx_inputs, tx_args, code, Some(assembly_file));
+    let process = run_tx_with_inputs(&transaction, advice_inputs);
+    assert!(process.is_err());
+}
+
+#[test]
+fn test_prologue_duplicate_input_note() {
+    let (tx_inputs, tx_args) =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+
+    // overwrite the nullifier of the second note with the nullifier of the first note after the
+    // input notes were processed
+    let code = "
+    begin
+        exec.prepare_transaction
+        exec.assert_unique_input_note_nullifiers
+
+        push.0 exec.memory::get_consumed_note_nullifier
+        push.1 exec.memory::get_consumed_note_nullifier_ptr mem_storew dropw
+
+        exec.assert_unique_input_note_nullifiers
+    end
+    ";
+
+    let assembly_file = build_module_path(TX_KERNEL_DIR, PROLOGUE_FILE);
+    let transaction = prepare_transaction(tx_inputs, tx_args, code, Some(assembly_file));
+    assert!(matches!(
+        run_tx(&transaction),
+        Err(ExecutionError::FailedAssertion { err_code: 0x00020062, .. })
+    ));
+}
+
+#[test]
+fn test_prologue_input_note_expiration() {
+    let code = "
+    use.miden::kernels::tx::prologue
+
+    begin
+        exec.prologue::prepare_transaction
+    end
+    ";
+
+    // notes which expire at or after the reference block can be consumed
+    for expiration in [4, 5] {
+        let (tx_inputs, tx_args) = mock_inputs_with_note_expiration(
+            MockAccountType::StandardExisting,
+            AssetPreservationStatus::Preserved,
+            expiration,
+        );
+        let transaction = prepare_transaction(tx_inputs, tx_args, code, None);
+        assert!(run_tx(&transaction).is_ok());
+    }
+
+    // notes which expired before the reference block are rejected
+    let (tx_inputs, tx_args) = mock_inputs_with_note_expiration(
+        MockAccountType::StandardExisting,
+        AssetPreservationStatus::Preserved,
+        3,
+    );
+    let transaction = prepare_transaction(tx_inputs, tx_args, code, None);
+    assert!(matches!(
+        run_tx(&transaction),
+        Err(ExecutionError::FailedAssertion { err_code: 0x0002005F, .. })
+    ));
+}
+
 #[test]
 fn test_get_blk_version() {
     let (tx_inputs, tx_args) =
@@ -1,26 +1,33 @@
 use alloc::vec::Vec;
 
 use miden_objects::{
-    accounts::ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
-    notes::{Note, NoteAssets, NoteInputs, NoteMetadata, NoteRecipient, NoteType},
-    transaction::{OutputNote, OutputNotes},
-    Word, ONE, ZERO,
+    accounts::{
+        AccountId, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1,
+    },
+    notes::{
+        validate_note_emission, Note, NoteAssets, NoteInputs, NoteMetadata, NoteRecipient, NoteTag,
+        NoteType,
+    },
+    transaction::{OutputNote, OutputNotes, TransactionSummary, TxLog, TxLogEntry},
+    FeeParameters, KernelConfig, KernelLimits, Word, ONE, TX_KERNEL_VERSION, ZERO,
 };
 use mock::{
     mock::{
-        account::MockAccountType, host::MockHost, notes::AssetPreservationStatus,
-        transaction::mock_inputs,
+        account::MockAccountType,
+        host::MockHost,
+        notes::AssetPreservationStatus,
+        transaction::{mock_inputs, mock_inputs_with_kernel_config},
     },
     prepare_transaction,
     procedures::prepare_word,
-    run_tx, run_within_tx_kernel,
+    run_tx,
 };
 
-use super::{ContextId, Felt, MemAdviceProvider, Process, ProcessState, StackInputs};
+use super::{ContextId, Felt, Process, ProcessState};
 use crate::transaction::memory::{
     CREATED_NOTE_ASSETS_OFFSET, CREATED_NOTE_METADATA_OFFSET, CREATED_NOTE_NUM_ASSETS_OFFSET,
     CREATED_NOTE_RECIPIENT_OFFSET, CREATED_NOTE_SECTION_OFFSET, NOTE_MEM_SIZE,
-    NUM_CREATED_NOTES_PTR,
+    NUM_CREATED_NOTES_PTR, NUM_TX_LOG_ENTRIES_PTR, TX_LOG_COMMITMENT_PTR,
 };
 
 #[test]
@@ -96,6 +103,127 @@ fn test_create_note() {
     );
 }
 
+#[test]
+fn test_add_asset_to_note() {
+    let (tx_inputs, tx_args) =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+
+    let recipient = [ZERO, ONE, Felt::new(2), Felt::new(3)];
+    let tag = Felt::new(4);
+    let asset = [Felt::new(10), ZERO, ZERO, Felt::new(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN)];
+    let asset_2 = [Felt::new(20), ZERO, ZERO, Felt::new(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1)];
+
+    let code = format!(
+        "
+    use.miden::kernels::tx::prologue
+    use.miden::tx
+
+    begin
+        exec.prologue::prepare_transaction
+
+        push.{recipient}
+        push.{PUBLIC_NOTE}
+        push.{tag}
+        push.{asset}
+
+        exec.tx::create_note
+        # => [note_ptr]
+
+        push.{asset_2}
+        exec.tx::add_asset_to_note
+        # => [ASSET_2, note_ptr]
+
+        dropw
+    end
+    ",
+        recipient = prepare_word(&recipient),
+        PUBLIC_NOTE = NoteType::Public as u8,
+        asset = prepare_word(&asset),
+        asset_2 = prepare_word(&asset_2),
+    );
+
+    let transaction = prepare_transaction(tx_inputs, tx_args, &code, None);
+    let process = run_tx(&transaction).unwrap();
+
+    assert_eq!(
+        read_root_mem_value(&process, CREATED_NOTE_SECTION_OFFSET + CREATED_NOTE_NUM_ASSETS_OFFSET),
+        [Felt::new(2), ZERO, ZERO, ZERO],
+        "number of assets must be incremented by 1",
+    );
+
+    assert_eq!(
+        read_root_mem_value(&process, CREATED_NOTE_SECTION_OFFSET + CREATED_NOTE_ASSETS_OFFSET),
+        asset,
+        "first asset must be stored at the correct memory location",
+    );
+
+    assert_eq!(
+        read_root_mem_value(&process, CREATED_NOTE_SECTION_OFFSET + CREATED_NOTE_ASSETS_OFFSET + 1),
+        asset_2,
+        "added asset must be stored after the first asset",
+    );
+
+    assert_eq!(
+        process.stack.get(0),
+        Felt::from(CREATED_NOTE_SECTION_OFFSET),
+        "top item on the stack is a pointer to the created note"
+    );
+}
+
+#[test]
+fn test_add_asset_to_note_fails() {
+    let recipient = [ZERO, ONE, Felt::new(2), Felt::new(3)];
+    let tag = Felt::new(4);
+    let asset = [Felt::new(10), ZERO, ZERO, Felt::new(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN)];
+
+    // a fungible asset issued by the same faucet cannot be added to the note, and assets can be
+    // added only to notes which were created by the transaction
+    let cases = [
+        ([Felt::new(20), ZERO, ZERO, Felt::new(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN)], 0),
+        ([Felt::new(20), ZERO, ZERO, Felt::new(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1)], 1),
+        (
+            [Felt::new(20), ZERO, ZERO, Felt::new(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1)],
+            NOTE_MEM_SIZE,
+        ),
+    ];
+
+    for (asset_2, ptr_offset) in cases {
+        let (tx_inputs, tx_args) =
+            mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+
+        let code = format!(
+            "
+        use.miden::kernels::tx::prologue
+        use.miden::tx
+
+        begin
+            exec.prologue::prepare_transaction
+
+            push.{recipient}
+            push.{PUBLIC_NOTE}
+            push.{tag}
+            push.{asset}
+
+            exec.tx::create_note
+            # => [note_ptr]
+
+            add.{ptr_offset} push.{asset_2}
+            exec.tx::add_asset_to_note
+        end
+        ",
+            recipient = prepare_word(&recipient),
+            PUBLIC_NOTE = NoteType::Public as u8,
+            asset = prepare_word(&asset),
+            asset_2 = prepare_word(&asset_2),
+        );
+
+        let transaction = prepare_transaction(tx_inputs, tx_args, &code, None);
+        let process = run_tx(&transaction);
+
+        assert!(process.is_err(), "adding the asset must fail (pointer offset {ptr_offset})");
+    }
+}
+
 #[test]
 fn test_create_note_with_invalid_tag() {
     let (tx_inputs, tx_args) =
@@ -133,20 +261,130 @@ fn test_create_note_with_invalid_tag() {
     assert!(process.is_err(), "Transaction should have failed because the tag is invalid");
 }
 
+#[test]
+fn test_create_note_with_ttl() {
+    let (tx_inputs, tx_args) =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+
+    let recipient = [ZERO, ONE, Felt::new(2), Felt::new(3)];
+    let tag = Felt::new(4);
+    let asset = [Felt::new(10), ZERO, ZERO, Felt::new(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN)];
+    let ttl = 100_u64;
+
+    // the TTL is encoded next to the note type, and is only accepted for public notes
+    for note_type in [NoteType::Public, NoteType::OffChain] {
+        let note_type_and_ttl = Felt::new(ttl << 8 | note_type as u64);
+        let code = format!(
+            "
+        use.miden::kernels::tx::prologue
+        use.miden::tx
+
+        begin
+            exec.prologue::prepare_transaction
+
+            push.{recipient}
+            push.{note_type_and_ttl}
+            push.{tag}
+            push.{asset}
+
+            exec.tx::create_note
+        end
+        ",
+            recipient = prepare_word(&recipient),
+            asset = prepare_word(&asset),
+        );
+
+        let transaction = prepare_transaction(tx_inputs.clone(), tx_args.clone(), &code, None);
+        let process = run_tx(&transaction);
+
+        match note_type {
+            NoteType::Public => assert_eq!(
+                read_root_mem_value(
+                    &process.unwrap(),
+                    CREATED_NOTE_SECTION_OFFSET + CREATED_NOTE_METADATA_OFFSET
+                )[2],
+                note_type_and_ttl,
+                "the TTL must be stored in the metadata of the note",
+            ),
+            _ => assert!(process.is_err(), "only public notes can have a TTL"),
+        }
+    }
+}
+
+#[test]
+fn test_create_note_emission_policy() {
+    let (tx_inputs, tx_args) =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+    let storage_mode = tx_inputs.account().id().storage_type();
+
+    let recipient = [ZERO, ONE, Felt::new(2), Felt::new(3)];
+    let asset = [Felt::new(10), ZERO, ZERO, Felt::new(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN)];
+
+    let note_types = [NoteType::Public, NoteType::OffChain, NoteType::Encrypted];
+    let tags = [4, 0b01 << 30 | 4, 0b100 << 29 | 4, 0b101 << 29 | 4, 0b11 << 30 | 4];
+
+    // the kernel must accept exactly the notes which are accepted by the emission policy
+    for note_type in note_types {
+        for tag in tags {
+            let code = format!(
+                "
+            use.miden::kernels::tx::prologue
+            use.miden::tx
+
+            begin
+                exec.prologue::prepare_transaction
+
+                push.{recipient}
+                push.{note_type}
+                push.{tag}
+                push.{asset}
+
+                exec.tx::create_note
+            end
+            ",
+                recipient = prepare_word(&recipient),
+                note_type = note_type as u8,
+                asset = prepare_word(&asset),
+            );
+
+            let transaction = prepare_transaction(tx_inputs.clone(), tx_args.clone(), &code, None);
+            let process = run_tx(&transaction);
+
+            let expected = validate_note_emission(storage_mode, note_type, NoteTag::from(tag));
+            assert_eq!(
+                process.is_ok(),
+                expected.is_ok(),
+                "unexpected outcome for note type {note_type:?} and tag {tag:#x}: {expected:?}"
+            );
+        }
+    }
+}
+
 #[test]
 fn test_create_note_too_many_notes() {
     let recipient = [ZERO, ONE, Felt::new(2), Felt::new(3)];
     let tag = Felt::new(4);
     let asset = [Felt::new(10), ZERO, ZERO, Felt::new(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN)];
 
+    let limits = KernelLimits::default().with_max_output_notes(4).with_max_log_entries(0);
+    let kernel_config =
+        KernelConfig::new(TX_KERNEL_VERSION, 0, FeeParameters::default(), limits).unwrap();
+    let (tx_inputs, tx_args) = mock_inputs_with_kernel_config(
+        MockAccountType::StandardExisting,
+        AssetPreservationStatus::Preserved,
+        kernel_config,
+    );
+
     let code = format!(
         "
-    use.miden::kernels::tx::constants
     use.miden::kernels::tx::memory
+    use.miden::kernels::tx::prologue
     use.miden::tx
 
     begin
-        exec.constants::get_max_num_created_notes
+        exec.prologue::prepare_transaction
+
+        exec.memory::get_kernel_max_output_notes
         exec.memory::set_num_created_notes
 
         push.{recipient}
@@ -163,8 +401,8 @@ fn test_create_note_too_many_notes() {
         PUBLIC_NOTE = NoteType::Public as u8,
     );
 
-    let process =
-        run_within_tx_kernel("", &code, StackInputs::default(), MemAdviceProvider::default(), None);
+    let transaction = prepare_transaction(tx_inputs, tx_args, &code, None);
+    let process = run_tx(&transaction);
 
     // assert the process failed
     assert!(process.is_err());
@@ -287,6 +525,213 @@ fn test_get_output_notes_hash() {
     assert_eq!(process.get_stack_word(0), *expected_output_notes_hash);
 }
 
+#[test]
+fn test_get_block_timestamp() {
+    let (tx_inputs, tx_args) =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+    let code = "
+    use.miden::kernels::tx::prologue
+    use.miden::tx
+
+    begin
+        exec.prologue::prepare_transaction
+        exec.tx::get_block_timestamp
+    end
+    ";
+
+    let transaction = prepare_transaction(tx_inputs.clone(), tx_args, code, None);
+    let process = run_tx(&transaction).unwrap();
+
+    assert_eq!(process.stack.get(0), tx_inputs.block_header().timestamp());
+}
+
+#[test]
+fn test_get_kernel_config() {
+    let limits = KernelLimits::default()
+        .with_max_input_notes(16)
+        .with_max_output_notes(32)
+        .with_max_log_entries(8)
+        .with_max_account_proc_call_depth(4);
+    let kernel_config = KernelConfig::new(
+        TX_KERNEL_VERSION,
+        KernelConfig::TX_LOG_FEATURE,
+        FeeParameters::new(10, 2)
+            .with_native_asset(AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap()),
+        limits,
+    )
+    .unwrap();
+    let (tx_inputs, tx_args) = mock_inputs_with_kernel_config(
+        MockAccountType::StandardExisting,
+        AssetPreservationStatus::Preserved,
+        kernel_config.clone(),
+    );
+    let code = "
+    use.miden::kernels::tx::prologue
+    use.miden::tx
+
+    begin
+        exec.prologue::prepare_transaction
+        exec.tx::get_kernel_config
+    end
+    ";
+
+    let transaction = prepare_transaction(tx_inputs, tx_args, code, None);
+    let process = run_tx(&transaction).unwrap();
+
+    let expected = kernel_config.to_elements();
+    assert_eq!(process.get_stack_word(0), expected[..4]);
+    assert_eq!(process.get_stack_word(1), expected[4..8]);
+}
+
+#[test]
+fn test_get_summary_commitment() {
+    let (tx_inputs, mut tx_args) =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+
+    let note_args = [Felt::new(91), Felt::new(92), Felt::new(93), Felt::new(94)];
+    tx_args.add_note_args(tx_inputs.input_notes().get_note(1).id(), note_args);
+    tx_args.set_tx_script_args([ONE, Felt::new(2), Felt::new(3), Felt::new(4)]);
+
+    let code = "
+    use.miden::kernels::tx::prologue
+    use.miden::tx
+
+    begin
+        exec.prologue::prepare_transaction
+        exec.tx::get_args_commitment
+        exec.tx::get_summary_commitment
+    end
+    ";
+
+    let summary = TransactionSummary::from_transaction(
+        tx_inputs.account(),
+        tx_inputs.input_notes(),
+        &OutputNotes::new(vec![]).unwrap(),
+        &tx_args,
+    );
+
+    let transaction = prepare_transaction(tx_inputs, tx_args, code, None);
+    let process = run_tx(&transaction).unwrap();
+
+    assert_eq!(process.get_stack_word(0), summary.message().as_elements());
+    assert_eq!(process.get_stack_word(1), summary.args_commitment().as_elements());
+}
+
+#[test]
+fn test_emit_event() {
+    let (tx_inputs, tx_args) =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+
+    let entry_1 = [ONE, Felt::new(2), Felt::new(3), Felt::new(4)];
+    let entry_2 = [Felt::new(5), ZERO, ZERO, Felt::new(6)];
+
+    let code = format!(
+        "
+    use.miden::kernels::tx::prologue
+    use.miden::kernels::tx::tx
+
+    begin
+        exec.prologue::prepare_transaction
+
+        push.{entry_1}
+        exec.tx::emit_event
+
+        push.{entry_2}
+        exec.tx::emit_event
+    end
+    ",
+        entry_1 = prepare_word(&entry_1),
+        entry_2 = prepare_word(&entry_2),
+    );
+
+    let transaction = prepare_transaction(tx_inputs, tx_args, &code, None);
+    let process = run_tx(&transaction).unwrap();
+
+    let expected_log =
+        TxLog::new(vec![TxLogEntry::new(entry_1), TxLogEntry::new(entry_2)]).unwrap();
+
+    assert_eq!(
+        read_root_mem_value(&process, NUM_TX_LOG_ENTRIES_PTR),
+        [Felt::new(2), ZERO, ZERO, ZERO],
+        "number of log entries must increment by 1 for each emitted event",
+    );
+    assert_eq!(
+        read_root_mem_value(&process, TX_LOG_COMMITMENT_PTR),
+        *expected_log.commitment(),
+        "log commitment must be a sequential hash of the emitted entries",
+    );
+}
+
+#[test]
+fn test_emit_event_too_many_entries() {
+    let kernel_config = KernelConfig::new(
+        TX_KERNEL_VERSION,
+        KernelConfig::TX_LOG_FEATURE,
+        FeeParameters::default(),
+        KernelLimits::default().with_max_log_entries(4),
+    )
+    .unwrap();
+    let (tx_inputs, tx_args) = mock_inputs_with_kernel_config(
+        MockAccountType::StandardExisting,
+        AssetPreservationStatus::Preserved,
+        kernel_config,
+    );
+    let code = "
+    use.miden::kernels::tx::memory
+    use.miden::kernels::tx::prologue
+    use.miden::kernels::tx::tx
+
+    begin
+        exec.prologue::prepare_transaction
+
+        exec.memory::get_kernel_max_log_entries
+        exec.memory::set_num_tx_log_entries
+
+        push.1.2.3.4
+        exec.tx::emit_event
+    end
+    ";
+
+    let transaction = prepare_transaction(tx_inputs, tx_args, code, None);
+    let process = run_tx(&transaction);
+
+    assert!(
+        process.is_err(),
+        "emitting more than the maximum number of log entries must fail"
+    );
+}
+
+#[test]
+fn test_emit_event_tx_log_disabled() {
+    let kernel_config =
+        KernelConfig::new(TX_KERNEL_VERSION, 0, FeeParameters::default(), KernelLimits::default())
+            .unwrap();
+    let (tx_inputs, tx_args) = mock_inputs_with_kernel_config(
+        MockAccountType::StandardExisting,
+        AssetPreservationStatus::Preserved,
+        kernel_config,
+    );
+    let code = "
+    use.miden::kernels::tx::prologue
+    use.miden::kernels::tx::tx
+
+    begin
+        exec.prologue::prepare_transaction
+
+        push.1.2.3.4
+        exec.tx::emit_event
+    end
+    ";
+
+    let transaction = prepare_transaction(tx_inputs, tx_args, code, None);
+    let process = run_tx(&transaction);
+
+    assert!(
+        process.is_err(),
+        "emitting log entries must fail if the transaction log is disabled"
+    );
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
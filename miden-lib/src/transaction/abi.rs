@@ -0,0 +1,70 @@
+use miden_objects::TX_KERNEL_VERSION;
+
+use super::memory::{MemoryLayoutSection, MEMORY_LAYOUT};
+
+// KERNEL ABI
+// ================================================================================================
+
+/// A structured description of the interface of the transaction kernel.
+///
+/// The descriptions of the procedures and events are generated from the MASM code at build time,
+/// and thus always match the kernel compiled into this crate. This allows external tooling, such
+/// as debuggers and code generators, to consume the interface of the kernel without parsing the
+/// MASM code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KernelAbi {
+    /// The version of the transaction kernel.
+    pub version: u32,
+    /// The procedures exported by the kernel, in the order in which they are defined.
+    pub procedures: &'static [KernelProcedure],
+    /// The events emitted by the kernel, sorted by their IDs.
+    pub events: &'static [KernelEvent],
+    /// The layout of the kernel memory, grouped by section.
+    pub memory: &'static [MemoryLayoutSection],
+}
+
+impl KernelAbi {
+    /// Returns the description of the kernel procedure with the specified name, if any.
+    pub fn procedure(&self, name: &str) -> Option<&'static KernelProcedure> {
+        self.procedures.iter().find(|procedure| procedure.name == name)
+    }
+
+    /// Returns the description of the kernel event with the specified ID, if any.
+    pub fn event(&self, id: u32) -> Option<&'static KernelEvent> {
+        self.events.iter().find(|event| event.id == id)
+    }
+}
+
+/// A description of a procedure exported by the transaction kernel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KernelProcedure {
+    /// The name of the procedure.
+    pub name: &'static str,
+    /// The documentation of the procedure, excluding its stack inputs and outputs.
+    pub description: &'static str,
+    /// The elements of the operand stack consumed by the procedure, top of the stack first.
+    pub inputs: &'static [&'static str],
+    /// The elements of the operand stack produced by the procedure, top of the stack first.
+    pub outputs: &'static [&'static str],
+}
+
+/// A description of an event emitted by the transaction kernel.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KernelEvent {
+    /// The name of the constant defining the event in the MASM code.
+    pub name: &'static str,
+    /// The ID of the event.
+    pub id: u32,
+    /// A description of the event.
+    pub description: &'static str,
+}
+
+/// The interface of the transaction kernel compiled into this crate.
+pub const KERNEL_ABI: KernelAbi = KernelAbi {
+    version: TX_KERNEL_VERSION,
+    procedures: KERNEL_PROCEDURES,
+    events: KERNEL_EVENTS,
+    memory: MEMORY_LAYOUT,
+};
+
+include!(concat!(env!("OUT_DIR"), "/kernel_abi.rs"));
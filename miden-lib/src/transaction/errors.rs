@@ -12,6 +12,10 @@ use miden_objects::{
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub enum TransactionKernelError {
+    DuplicateCreatedNotePtr(Felt),
+    FailedToAddAssetToNote(NoteError),
+    InconsistentNoteRecipient { expected: Digest, actual: Digest },
+    InvalidCreatedNotePtr(Felt),
     InvalidStorageSlotIndex(u64),
     MalformedAccountId(AccountError),
     MalformedAsset(AssetError),
@@ -30,6 +34,21 @@ pub enum TransactionKernelError {
 impl fmt::Display for TransactionKernelError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
+            TransactionKernelError::DuplicateCreatedNotePtr(ptr) => {
+                write!(f, "a note was already created at pointer {ptr}")
+            },
+            TransactionKernelError::FailedToAddAssetToNote(err) => {
+                write!(f, "failed to add asset to an output note: {err}")
+            },
+            TransactionKernelError::InconsistentNoteRecipient { expected, actual } => {
+                write!(
+                    f,
+                    "recipient details of a created note hash to {actual} instead of {expected}"
+                )
+            },
+            TransactionKernelError::InvalidCreatedNotePtr(ptr) => {
+                write!(f, "pointer {ptr} does not point to a note created by the transaction")
+            },
             TransactionKernelError::InvalidStorageSlotIndex(index) => {
                 let num_slots = AccountStorage::NUM_STORAGE_SLOTS;
                 write!(f, "storage slot index {index} is invalid, must be smaller than {num_slots}")
@@ -81,6 +100,20 @@ impl fmt::Display for TransactionKernelError {
 #[cfg(feature = "std")]
 impl std::error::Error for TransactionKernelError {}
 
+// KERNEL ERROR
+// ================================================================================================
+
+include!(concat!(env!("OUT_DIR"), "/kernel_errors.rs"));
+
+impl fmt::Display for KernelError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} (error code {:#010X})", self.message(), self.code())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for KernelError {}
+
 // TRANSACTION EVENT PARSING ERROR
 // ================================================================================================
 
@@ -105,3 +138,28 @@ impl fmt::Display for TransactionEventParsingError {
 
 #[cfg(feature = "std")]
 impl std::error::Error for TransactionEventParsingError {}
+
+// TRANSACTION TRACE PARSING ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub enum TransactionTraceParsingError {
+    InvalidTransactionTrace(u32),
+    NotTransactionTrace(u32),
+}
+
+impl fmt::Display for TransactionTraceParsingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::InvalidTransactionTrace(trace_id) => {
+                write!(f, "trace {trace_id} is not a valid transaction kernel trace")
+            },
+            Self::NotTransactionTrace(trace_id) => {
+                write!(f, "trace {trace_id} is not a transaction kernel trace")
+            },
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TransactionTraceParsingError {}
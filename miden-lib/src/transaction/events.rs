@@ -1,6 +1,6 @@
 use core::fmt;
 
-use super::TransactionEventParsingError;
+use super::{TransactionEventParsingError, TransactionTraceParsingError};
 
 // TRANSACTION EVENT
 // ================================================================================================
@@ -11,6 +11,9 @@ const ACCOUNT_STORAGE_SET_ITEM: u32 = 0x2_0002; // 131074
 const ACCOUNT_INCREMENT_NONCE: u32 = 0x2_0003; // 131075
 const ACCOUNT_PUSH_PROCEDURE_INDEX: u32 = 0x2_0004; // 131076
 const NOTE_CREATED: u32 = 0x2_0005; // 131077
+const ACCOUNT_STORAGE_SET_ARRAY_ITEM: u32 = 0x2_0006; // 131078
+const TX_LOG_ENTRY: u32 = 0x2_0007; // 131079
+const NOTE_ASSET_ADDED: u32 = 0x2_0008; // 131080
 
 /// Events which may be emitted by a transaction kernel.
 ///
@@ -28,6 +31,9 @@ pub enum TransactionEvent {
     AccountIncrementNonce = ACCOUNT_INCREMENT_NONCE,
     AccountPushProcedureIndex = ACCOUNT_PUSH_PROCEDURE_INDEX,
     NoteCreated = NOTE_CREATED,
+    AccountStorageSetArrayItem = ACCOUNT_STORAGE_SET_ARRAY_ITEM,
+    TxLogEntry = TX_LOG_ENTRY,
+    NoteAssetAdded = NOTE_ASSET_ADDED,
 }
 
 impl TransactionEvent {
@@ -56,7 +62,79 @@ impl TryFrom<u32> for TransactionEvent {
             ACCOUNT_INCREMENT_NONCE => Ok(TransactionEvent::AccountIncrementNonce),
             ACCOUNT_PUSH_PROCEDURE_INDEX => Ok(TransactionEvent::AccountPushProcedureIndex),
             NOTE_CREATED => Ok(TransactionEvent::NoteCreated),
+            ACCOUNT_STORAGE_SET_ARRAY_ITEM => Ok(TransactionEvent::AccountStorageSetArrayItem),
+            TX_LOG_ENTRY => Ok(TransactionEvent::TxLogEntry),
+            NOTE_ASSET_ADDED => Ok(TransactionEvent::NoteAssetAdded),
             _ => Err(TransactionEventParsingError::InvalidTransactionEvent(value)),
         }
     }
 }
+
+// TRANSACTION TRACE
+// ================================================================================================
+
+const PROLOGUE_START: u32 = 0x2_0000; // 131072
+const PROLOGUE_END: u32 = 0x2_0001; // 131073
+const NOTES_PROCESSING_START: u32 = 0x2_0002; // 131074
+const NOTES_PROCESSING_END: u32 = 0x2_0003; // 131075
+const NOTE_EXECUTION_START: u32 = 0x2_0004; // 131076
+const NOTE_EXECUTION_END: u32 = 0x2_0005; // 131077
+const TX_SCRIPT_PROCESSING_START: u32 = 0x2_0006; // 131078
+const TX_SCRIPT_PROCESSING_END: u32 = 0x2_0007; // 131079
+const EPILOGUE_START: u32 = 0x2_0008; // 131080
+const EPILOGUE_END: u32 = 0x2_0009; // 131081
+
+/// Traces which mark the start and the end of the stages of a transaction kernel.
+///
+/// The traces are emitted via the `trace.<trace_id>` instruction, and are only passed to the host
+/// if tracing is enabled in the execution options. Trace IDs are structured in the same way as
+/// event IDs (see [TransactionEvent]), but form a separate namespace.
+#[repr(u32)]
+#[derive(Debug, Clone, Copy, Eq, PartialEq)]
+pub enum TransactionTrace {
+    PrologueStart = PROLOGUE_START,
+    PrologueEnd = PROLOGUE_END,
+    NotesProcessingStart = NOTES_PROCESSING_START,
+    NotesProcessingEnd = NOTES_PROCESSING_END,
+    NoteExecutionStart = NOTE_EXECUTION_START,
+    NoteExecutionEnd = NOTE_EXECUTION_END,
+    TxScriptProcessingStart = TX_SCRIPT_PROCESSING_START,
+    TxScriptProcessingEnd = TX_SCRIPT_PROCESSING_END,
+    EpilogueStart = EPILOGUE_START,
+    EpilogueEnd = EPILOGUE_END,
+}
+
+impl TransactionTrace {
+    /// Value of the top 16 bits of a transaction kernel trace ID.
+    pub const TRACE_ID_PREFIX: u16 = 2;
+}
+
+impl fmt::Display for TransactionTrace {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{self:?}")
+    }
+}
+
+impl TryFrom<u32> for TransactionTrace {
+    type Error = TransactionTraceParsingError;
+
+    fn try_from(value: u32) -> Result<Self, Self::Error> {
+        if value >> 16 != Self::TRACE_ID_PREFIX as u32 {
+            return Err(TransactionTraceParsingError::NotTransactionTrace(value));
+        }
+
+        match value {
+            PROLOGUE_START => Ok(TransactionTrace::PrologueStart),
+            PROLOGUE_END => Ok(TransactionTrace::PrologueEnd),
+            NOTES_PROCESSING_START => Ok(TransactionTrace::NotesProcessingStart),
+            NOTES_PROCESSING_END => Ok(TransactionTrace::NotesProcessingEnd),
+            NOTE_EXECUTION_START => Ok(TransactionTrace::NoteExecutionStart),
+            NOTE_EXECUTION_END => Ok(TransactionTrace::NoteExecutionEnd),
+            TX_SCRIPT_PROCESSING_START => Ok(TransactionTrace::TxScriptProcessingStart),
+            TX_SCRIPT_PROCESSING_END => Ok(TransactionTrace::TxScriptProcessingEnd),
+            EPILOGUE_START => Ok(TransactionTrace::EpilogueStart),
+            EPILOGUE_END => Ok(TransactionTrace::EpilogueEnd),
+            _ => Err(TransactionTraceParsingError::InvalidTransactionTrace(value)),
+        }
+    }
+}
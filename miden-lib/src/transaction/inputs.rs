@@ -1,10 +1,12 @@
-use alloc::vec::Vec;
+use alloc::{collections::BTreeMap, vec::Vec};
 
 use miden_objects::{
     accounts::Account,
+    assets::PartialVault,
+    crypto::merkle::SmtProof,
     transaction::{
-        ChainMmr, ExecutedTransaction, InputNotes, PreparedTransaction, TransactionArgs,
-        TransactionInputs, TransactionScript, TransactionWitness,
+        ChainMmr, ExecutedTransaction, FeePayment, InputNotes, PreparedTransaction,
+        TransactionArgs, TransactionInputs, TransactionWitness,
     },
     vm::{AdviceInputs, StackInputs},
     Felt, Word, ZERO,
@@ -26,7 +28,7 @@ impl ToTransactionKernelInputs for PreparedTransaction {
         let account = self.account();
         let stack_inputs = TransactionKernel::build_input_stack(
             account.id(),
-            account.proof_init_hash(),
+            self.tx_inputs().account_init_hash(),
             self.input_notes().commitment(),
             self.block_header().hash(),
         );
@@ -43,7 +45,7 @@ impl ToTransactionKernelInputs for ExecutedTransaction {
         let account = self.initial_account();
         let stack_inputs = TransactionKernel::build_input_stack(
             account.id(),
-            account.proof_init_hash(),
+            self.tx_inputs().account_init_hash(),
             self.input_notes().commitment(),
             self.block_header().hash(),
         );
@@ -61,7 +63,7 @@ impl ToTransactionKernelInputs for TransactionWitness {
 
         let stack_inputs = TransactionKernel::build_input_stack(
             account.id(),
-            account.proof_init_hash(),
+            self.tx_inputs().account_init_hash(),
             self.input_notes().commitment(),
             self.block_header().hash(),
         );
@@ -88,13 +90,23 @@ fn extend_advice_inputs(
     advice_inputs: &mut AdviceInputs,
 ) {
     // build the advice stack
-    build_advice_stack(tx_inputs, tx_args.tx_script(), advice_inputs);
+    build_advice_stack(tx_inputs, tx_args, advice_inputs);
 
     // build the advice map and Merkle store for relevant components
     add_chain_mmr_to_advice_inputs(tx_inputs.block_chain(), advice_inputs);
-    add_account_to_advice_inputs(tx_inputs.account(), tx_inputs.account_seed(), advice_inputs);
+    add_account_to_advice_inputs(
+        tx_inputs.account(),
+        tx_inputs.account_seed(),
+        tx_inputs.partial_vault(),
+        advice_inputs,
+    );
     add_input_notes_to_advice_inputs(tx_inputs.input_notes(), tx_args, advice_inputs);
+    if let Some(proofs) = tx_inputs.nullifier_proofs() {
+        add_nullifier_proofs_to_advice_inputs(proofs, advice_inputs);
+    }
+    add_fee_assets_to_advice_inputs(tx_inputs, tx_args, advice_inputs);
     advice_inputs.extend_map(tx_args.advice_map().clone());
+    advice_inputs.extend_merkle_store(tx_args.merkle_store().inner_nodes());
 }
 
 // ADVICE STACK BUILDER
@@ -110,17 +122,30 @@ fn extend_advice_inputs(
 ///  elements[16..19]  = batch root
 ///  elements[20..23]  = proof hash
 ///  elements[24..27]  = [block_num, version, timestamp, ZERO]
-///  elements[28..31]  = [ZERO; 4]
+///  elements[28..31]  = kernel config hash
 ///  elements[32..35]  = notes root
-///  elements[36..39]  = [account ID, ZERO, ZERO, account nonce]
-///  elements[40..43]  = account vault root
-///  elements[44..47]  = account storage root
-///  elements[48..51]  = account code root
-///  elements[52]      = number of input notes
-///  elements[53..57]  = account seed, if one was provided; otherwise [ZERO; 4]
+///  elements[36..39]  = [kernel version, features, base fee, fee per note]
+///  elements[40..43]  = [max input notes, max output notes, max log entries,
+///                       max account procedure call depth]
+///  elements[44..47]  = [native fee faucet ID, number of accepted fee assets, ZERO, ZERO]
+///  elements[48..51]  = accepted fee assets root
+///  elements[52..55]  = [account ID, ZERO, ZERO, account nonce]
+///  elements[56..59]  = account vault root
+///  elements[60..63]  = account storage root
+///  elements[64..67]  = account code root
+///  elements[68]      = number of input notes
+///  elements[69]      = 1 if input note nullifier proofs were provided; otherwise 0
+///  elements[70..73]  = transaction script root, if one was provided; otherwise [ZERO; 4]
+///  elements[74..77]  = transaction script args
+///  elements[78..81]  = root of the selected transaction script entrypoint
+///  elements[82]      = per-note cycle budget, or ZERO if the budget is not enforced
+///  elements[83]      = 1 if created notes with the same metadata and recipient are merged;
+///                      otherwise 0
+///  elements[84]      = index of the fee asset: ZERO if the fee is paid in the native fee asset,
+///                      otherwise the index of the accepted fee asset plus one
 fn build_advice_stack(
     tx_inputs: &TransactionInputs,
-    tx_script: Option<&TransactionScript>,
+    tx_args: &TransactionArgs,
     inputs: &mut AdviceInputs,
 ) {
     // push block header info into the stack
@@ -132,21 +157,27 @@ fn build_advice_stack(
     inputs.extend_stack(header.batch_root());
     inputs.extend_stack(header.proof_hash());
     inputs.extend_stack([header.block_num().into(), header.version(), header.timestamp(), ZERO]);
-    inputs.extend_stack([ZERO; 4]);
+    inputs.extend_stack(header.kernel_config_hash());
     inputs.extend_stack(header.note_root());
 
+    // push the kernel config onto the stack
+    inputs.extend_stack(tx_inputs.kernel_config().to_elements());
+
     // push core account items onto the stack
     let account = tx_inputs.account();
     inputs.extend_stack([account.id().into(), ZERO, ZERO, account.nonce()]);
-    inputs.extend_stack(account.vault().commitment());
+    inputs.extend_stack(tx_inputs.account_vault_root());
     inputs.extend_stack(account.storage().root());
     inputs.extend_stack(account.code().root());
 
     // push the number of input notes onto the stack
     inputs.extend_stack([Felt::from(tx_inputs.input_notes().num_notes() as u32)]);
 
+    // push the flag indicating whether the nullifier proofs should be verified onto the stack
+    inputs.extend_stack([Felt::from(tx_inputs.nullifier_proofs().is_some())]);
+
     // push tx_script root onto the stack
-    if let Some(tx_script) = tx_script {
+    if let Some(tx_script) = tx_args.tx_script() {
         // insert the transaction script hash into the advice stack
         inputs.extend_stack(*tx_script.hash());
     } else {
@@ -154,6 +185,21 @@ fn build_advice_stack(
         // script root
         inputs.extend_stack(Word::default());
     }
+
+    // push tx_script args onto the stack
+    inputs.extend_stack(tx_args.tx_script_args());
+
+    // push the selected tx_script entrypoint onto the stack; empty if no entrypoint is selected
+    inputs.extend_stack(tx_args.tx_script_entrypoint().map(Word::from).unwrap_or_default());
+
+    // push the per-note cycle budget onto the stack
+    inputs.extend_stack([Felt::from(tx_args.note_cycle_budget().unwrap_or_default())]);
+
+    // push the flag indicating whether created notes should be merged onto the stack
+    inputs.extend_stack([Felt::from(tx_args.merge_output_notes())]);
+
+    // push the index of the fee asset onto the stack
+    inputs.extend_stack([Felt::from(fee_asset_index(tx_inputs, tx_args))]);
 }
 
 // CHAIN MMR INJECTOR
@@ -189,17 +235,21 @@ fn add_chain_mmr_to_advice_inputs(mmr: &ChainMmr, inputs: &mut AdviceInputs) {
 ///
 /// Inserts the following items into the Merkle store:
 /// - The Merkle nodes associated with the storage slots tree.
-/// - The Merkle nodes associated with the account vault tree.
+/// - The Merkle nodes associated with the storage arrays.
+/// - The Merkle nodes associated with the account vault tree (or with the partial vault, when
+///   one is provided).
 /// - The Merkle nodes associated with the account code procedures tree.
 ///
 /// Inserts the following entries into the advice map:
 /// - The storage types commitment |-> storage slot types vector.
 /// - The account procedure root |-> procedure index, for each account procedure.
-/// - The node |-> (key, value), for all leaf nodes of the asset vault SMT.
+/// - The node |-> (key, value), for all non-empty leaf nodes of the asset vault SMT (or for the
+///   non-empty leaf nodes tracked by the partial vault, when one is provided).
 /// - [account_id, 0, 0, 0] |-> account_seed, when account seed is provided.
 fn add_account_to_advice_inputs(
     account: &Account,
     account_seed: Option<Word>,
+    partial_vault: Option<&PartialVault>,
     inputs: &mut AdviceInputs,
 ) {
     // --- account storage ----------------------------------------------------
@@ -208,6 +258,11 @@ fn add_account_to_advice_inputs(
     // extend the merkle store with the storage items
     inputs.extend_merkle_store(account.storage().slots().inner_nodes());
 
+    // extend the merkle store with the storage array items
+    for array in storage.arrays().values() {
+        inputs.extend_merkle_store(array.inner_nodes());
+    }
+
     // extend advice map with storage types commitment |-> storage types
     inputs.extend_map([(
         storage.layout_commitment(),
@@ -215,14 +270,31 @@ fn add_account_to_advice_inputs(
     )]);
 
     // --- account vault ------------------------------------------------------
-    let vault = account.vault();
-
-    // extend the merkle store with account vault data
-    inputs.extend_merkle_store(vault.asset_tree().inner_nodes());
-
-    // populate advice map with Sparse Merkle Tree leaf nodes
-    inputs
-        .extend_map(vault.asset_tree().leaves().map(|(_, leaf)| (leaf.hash(), leaf.to_elements())));
+    match partial_vault {
+        Some(partial_vault) => {
+            // extend the merkle store with the paths to the tracked vault leaves
+            inputs.extend_merkle_store(partial_vault.inner_nodes());
+
+            // populate advice map with the tracked non-empty Sparse Merkle Tree leaf nodes
+            inputs.extend_map(
+                partial_vault
+                    .leaves()
+                    .filter(|leaf| !leaf.is_empty())
+                    .map(|leaf| (leaf.hash(), leaf.to_elements())),
+            );
+        },
+        None => {
+            let vault = account.vault();
+
+            // extend the merkle store with account vault data
+            inputs.extend_merkle_store(vault.asset_tree().inner_nodes());
+
+            // populate advice map with Sparse Merkle Tree leaf nodes
+            inputs.extend_map(
+                vault.asset_tree().leaves().map(|(_, leaf)| (leaf.hash(), leaf.to_elements())),
+            );
+        },
+    }
 
     // --- account code -------------------------------------------------------
     let code = account.code();
@@ -263,10 +335,16 @@ fn add_account_to_advice_inputs(
 ///   ...
 ///   out[34 + num_assets * 4..] = Word::default() (this is conditional padding only applied
 ///                                                 if the number of assets is odd)
-///   out[-10]      = origin.block_number
-///   out[-9..-5]   = origin.SUB_HASH
-///   out[-5..-1]   = origin.NOTE_ROOT
-///   out[-1]       = origin.node_index
+///   out[..]      = block_idx
+///   out[..]      = origin.block_number (only for the first note of a block)
+///   out[..]      = origin.SUB_HASH (only for the first note of a block)
+///   out[..]      = origin.NOTE_ROOT (only for the first note of a block)
+///   out[-1]      = origin.node_index
+///
+/// Where block_idx is the index of the block the note was created in among the distinct blocks of
+/// the input notes, in the order of their first occurrence. The kernel authenticates every block
+/// against the chain MMR only once, when processing the first note created in it, and verifies the
+/// inclusion proofs of subsequent notes of the block against the note root authenticated then.
 ///
 /// Inserts the following items into the Merkle store:
 /// - The Merkle nodes associated with the note's authentication path.
@@ -286,6 +364,7 @@ fn add_input_notes_to_advice_inputs(
     }
 
     let mut note_data = Vec::new();
+    let mut note_blocks = BTreeMap::new();
     for input_note in notes.iter() {
         let note = input_note.note();
         let assets = note.assets();
@@ -320,9 +399,15 @@ fn add_input_notes_to_advice_inputs(
         note_data.push((assets.num_assets() as u32).into());
         note_data.extend(assets.to_padded_assets());
 
-        note_data.push(proof.origin().block_num.into());
-        note_data.extend(*proof.sub_hash());
-        note_data.extend(*proof.note_root());
+        // the block of the note is authenticated only if no previous note was created in it
+        let num_note_blocks = note_blocks.len();
+        let block_idx = *note_blocks.entry(proof.origin().block_num).or_insert(num_note_blocks);
+        note_data.push((block_idx as u32).into());
+        if block_idx == num_note_blocks {
+            note_data.push(proof.origin().block_num.into());
+            note_data.extend(*proof.sub_hash());
+            note_data.extend(*proof.note_root());
+        }
         note_data.push(
             proof
                 .origin()
@@ -336,3 +421,77 @@ fn add_input_notes_to_advice_inputs(
     // insert the combined note data into the advice map
     inputs.extend_map([(notes.commitment(), note_data)]);
 }
+
+// NULLIFIER PROOFS INJECTOR
+// ------------------------------------------------------------------------------------------------
+
+/// Inserts the proofs of non-inclusion of the input note nullifiers into the provided advice
+/// inputs.
+///
+/// Inserts the following items into the Merkle store:
+/// - The Merkle nodes associated with the path to the nullifier tree leaf of each nullifier.
+///
+/// Inserts the following entries into the advice map:
+/// - The leaf node |-> (key, value) pairs of the leaf, for all non-empty opened leaves.
+fn add_nullifier_proofs_to_advice_inputs(proofs: &[SmtProof], inputs: &mut AdviceInputs) {
+    for proof in proofs {
+        let leaf = proof.leaf();
+        inputs.extend_merkle_store(
+            proof
+                .path()
+                .inner_nodes(leaf.index().value(), leaf.hash())
+                .expect("nullifier proof path is invalid"),
+        );
+
+        if !leaf.is_empty() {
+            inputs.extend_map([(leaf.hash(), leaf.to_elements())]);
+        }
+    }
+}
+
+// FEE ASSETS INJECTOR
+// ------------------------------------------------------------------------------------------------
+
+/// Inserts the data required to pay the fee of the transaction into the provided advice inputs.
+///
+/// Inserts the following items into the Merkle store:
+/// - The Merkle nodes associated with the accepted fee assets tree of the kernel config.
+/// - The Merkle nodes associated with the path to the fee oracle in the account database and with
+///   the path to the rate slot in the storage of the oracle, when the fee is paid in an accepted
+///   fee asset.
+///
+/// Inserts the following entries into the advice map:
+/// - The oracle hash |-> [oracle ID, 0, 0, oracle nonce, VAULT_ROOT, STORAGE_ROOT, CODE_ROOT],
+///   when the fee is paid in an accepted fee asset.
+fn add_fee_assets_to_advice_inputs(
+    tx_inputs: &TransactionInputs,
+    tx_args: &TransactionArgs,
+    inputs: &mut AdviceInputs,
+) {
+    inputs.extend_merkle_store(tx_inputs.kernel_config().accepted_fee_assets_tree().inner_nodes());
+
+    if let FeePayment::Converted { witness, .. } = tx_args.fee_payment() {
+        inputs.extend_merkle_store(witness.inner_nodes());
+        inputs.extend_map([(witness.oracle().hash(), witness.oracle_data())]);
+    }
+}
+
+// HELPER FUNCTIONS
+// ------------------------------------------------------------------------------------------------
+
+/// Returns the index of the fee asset as expected by the transaction kernel, i.e., 0 if the fee is
+/// paid in the native fee asset, and the index of the accepted fee asset plus one otherwise.
+///
+/// If the fee is paid in an asset which is not accepted by the kernel config, u32::MAX is returned
+/// so that the transaction is rejected by the kernel.
+fn fee_asset_index(tx_inputs: &TransactionInputs, tx_args: &TransactionArgs) -> u32 {
+    match tx_args.fee_payment().faucet_id() {
+        None => 0,
+        Some(faucet_id) => tx_inputs
+            .kernel_config()
+            .fees()
+            .find_accepted_asset(faucet_id)
+            .map(|(idx, _)| idx as u32 + 1)
+            .unwrap_or(u32::MAX),
+    }
+}
@@ -1,3 +1,5 @@
+use miden_objects::accounts::AccountStorage;
+
 // TYPE ALIASES
 // ================================================================================================
 
@@ -18,6 +20,20 @@ pub type StorageSlot = u8;
 /// Non-fungible faucet: The faucet data consists of SMT root containing minted non-fungible assets.
 pub const FAUCET_STORAGE_DATA_SLOT: StorageSlot = 254;
 
+/// The account storage slot at which the root of the output note hook of the account is stored.
+/// If the slot is empty, the account does not have an output note hook.
+///
+/// The slot is reserved, i.e., it cannot be initialized via account storage items (see
+/// [AccountStorage::with_output_note_hook()]).
+pub const OUTPUT_NOTE_HOOK_STORAGE_SLOT: StorageSlot = AccountStorage::OUTPUT_NOTE_HOOK_SLOT_INDEX;
+
+/// The account storage slot at which the version of the authentication scheme of the account is
+/// stored as [version, 0, 0, 0]. If the slot is empty, the authentication scheme is not versioned.
+///
+/// The slot is reserved, i.e., it cannot be initialized via account storage items (see
+/// [AccountStorage::with_auth_scheme_version()]).
+pub const AUTH_SCHEME_STORAGE_SLOT: StorageSlot = AccountStorage::AUTH_SCHEME_SLOT_INDEX;
+
 /// The account storage slot at which the slot types commitment is stored.
 pub const SLOT_TYPES_COMMITMENT_STORAGE_SLOT: StorageSlot = 255;
 
@@ -39,6 +55,41 @@ pub const INPUT_VAULT_ROOT_PTR: MemoryAddress = 3;
 /// The memory address at which the output vault root is stored
 pub const OUTPUT_VAULT_ROOT_PTR: MemoryAddress = 4;
 
+/// The memory address at which the current depth of guarded account procedure calls is stored.
+pub const ACCT_PROC_CALL_DEPTH_PTR: MemoryAddress = 5;
+
+/// The memory address at which the commitment to the transaction log entries is stored.
+pub const TX_LOG_COMMITMENT_PTR: MemoryAddress = 6;
+
+/// The memory address at which the number of transaction log entries is stored.
+pub const NUM_TX_LOG_ENTRIES_PTR: MemoryAddress = 7;
+
+/// The memory address at which the commitment to the args of the consumed notes is stored.
+pub const NOTE_ARGS_COMMITMENT_PTR: MemoryAddress = 8;
+
+/// The memory address at which the number of blocks authenticated for the consumed notes is
+/// stored.
+pub const NUM_NOTE_BLOCKS_PTR: MemoryAddress = 9;
+
+/// The memory address at which the maximum number of cycles a single note script may consume is
+/// stored.
+pub const NOTE_CYCLE_BUDGET_PTR: MemoryAddress = 10;
+
+/// The memory address at which the clock cycle at which the current note script started is
+/// stored.
+pub const NOTE_CYCLE_START_PTR: MemoryAddress = 11;
+
+/// The memory address at which the flag enabling the merging of created notes with the same
+/// metadata and recipient is stored.
+pub const MERGE_CREATED_NOTES_PTR: MemoryAddress = 12;
+
+/// The memory address at which the index of the fee asset is stored; 0 for the native fee asset,
+/// otherwise the index of the accepted fee asset plus one.
+pub const FEE_ASSET_IDX_PTR: MemoryAddress = 13;
+
+/// The memory address at which the fee asset deducted from the account vault is stored.
+pub const FEE_ASSET_PTR: MemoryAddress = 14;
+
 // GLOBAL INPUTS
 // ------------------------------------------------------------------------------------------------
 
@@ -63,6 +114,12 @@ pub const INIT_NONCE_PTR: MemoryAddress = 104;
 /// The memory address at which the transaction script mast root is store
 pub const TX_SCRIPT_ROOT_PTR: MemoryAddress = 105;
 
+/// The memory address at which the transaction script args are stored.
+pub const TX_SCRIPT_ARGS_PTR: MemoryAddress = 106;
+
+/// The memory address at which the root of the selected transaction script entrypoint is stored.
+pub const TX_SCRIPT_ENTRYPOINT_PTR: MemoryAddress = 107;
+
 // BLOCK DATA
 // ------------------------------------------------------------------------------------------------
 
@@ -99,8 +156,63 @@ pub const PROTOCOL_VERSION_IDX: DataIndex = 1;
 /// The index of the timestamp within the block metadata
 pub const TIMESTAMP_IDX: DataIndex = 2;
 
+/// The memory address at which the commitment to the kernel config is stored
+pub const KERNEL_CONFIG_HASH_PTR: MemoryAddress = 207;
+
 /// The memory address at which the note root is stored
-pub const NOTE_ROOT_PTR: MemoryAddress = 207;
+pub const NOTE_ROOT_PTR: MemoryAddress = 208;
+
+// KERNEL CONFIG
+// ------------------------------------------------------------------------------------------------
+
+/// The memory address at which the kernel config section begins
+pub const KERNEL_CONFIG_PTR: MemoryAddress = 209;
+
+/// The memory address at which the kernel parameters are stored
+pub const KERNEL_PARAMS_PTR: MemoryAddress = 209;
+
+/// The index of the kernel version within the kernel parameters
+pub const KERNEL_VERSION_IDX: DataIndex = 0;
+
+/// The index of the kernel features within the kernel parameters
+pub const KERNEL_FEATURES_IDX: DataIndex = 1;
+
+/// The index of the base fee within the kernel parameters
+pub const BASE_FEE_IDX: DataIndex = 2;
+
+/// The index of the fee per note within the kernel parameters
+pub const FEE_PER_NOTE_IDX: DataIndex = 3;
+
+/// The memory address at which the kernel limits are stored
+pub const KERNEL_LIMITS_PTR: MemoryAddress = 210;
+
+/// The index of the maximum number of input notes within the kernel limits
+pub const MAX_INPUT_NOTES_IDX: DataIndex = 0;
+
+/// The index of the maximum number of output notes within the kernel limits
+pub const MAX_OUTPUT_NOTES_IDX: DataIndex = 1;
+
+/// The index of the maximum number of log entries within the kernel limits
+pub const MAX_LOG_ENTRIES_IDX: DataIndex = 2;
+
+/// The memory address at which the fee assets of the kernel config are stored
+pub const FEE_ASSETS_PTR: MemoryAddress = 211;
+
+/// The index of the ID of the native fee faucet within the fee assets
+pub const NATIVE_FEE_FAUCET_ID_IDX: DataIndex = 0;
+
+/// The index of the number of accepted fee assets within the fee assets
+pub const NUM_ACCEPTED_FEE_ASSETS_IDX: DataIndex = 1;
+
+/// The memory address at which the root of the accepted fee assets tree is stored
+pub const ACCEPTED_FEE_ASSETS_ROOT_PTR: MemoryAddress = 212;
+
+// FEE ORACLE DATA
+// ------------------------------------------------------------------------------------------------
+
+/// The memory address at which the data of the oracle of the accepted fee asset is stored while
+/// the fee is converted in the epilogue
+pub const FEE_ORACLE_DATA_PTR: MemoryAddress = 220;
 
 // CHAIN DATA
 // ------------------------------------------------------------------------------------------------
@@ -172,6 +284,13 @@ pub const ACCT_NEW_CODE_ROOT_PTR: MemoryAddress =
 /// The memory address at which the account storage slot type data beings
 pub const ACCT_STORAGE_SLOT_TYPE_DATA_OFFSET: MemoryAddress = 405;
 
+// ACCOUNT PROCEDURE GUARDS
+// ------------------------------------------------------------------------------------------------
+
+/// The memory address at which the account procedure guard flags begin. The flag of the procedure
+/// with index i in the account code Merkle tree is stored at this offset + i.
+pub const ACCT_PROC_GUARDS_SECTION_OFFSET: MemoryOffset = 500;
+
 // NOTES DATA
 // ================================================================================================
 
@@ -190,6 +309,9 @@ pub const NOTE_MEM_SIZE: MemoryAddress = 512;
 // └─────────┴───────────┴───────────┴─────┴───────────┴─────────┴────────┴────────┴─────┴────────┘
 //  1_048_576  1_048_577   1_048_578        1_048_576+n      1_064_960   +512    +1024  +512n
 //
+// The padding also holds the note roots of the blocks authenticated for the consumed notes,
+// starting at memory offset 1_056_768 (see NOTE_BLOCK_ROOTS_SECTION_OFFSET).
+//
 // Each nullifier occupies a single word. A data section for each note consists of exactly 512
 // words and is laid out like so:
 //
@@ -211,6 +333,10 @@ pub const CONSUMED_NOTE_DATA_SECTION_OFFSET: MemoryAddress = 1_064_960;
 /// The memory address at which the number of consumed notes is stored.
 pub const CONSUMED_NOTE_NUM_PTR: MemoryAddress = CONSUMED_NOTE_SECTION_OFFSET;
 
+/// The memory address at which the note roots of the blocks authenticated for the consumed notes
+/// begin. The note root of the authenticated block with index i is stored at this offset + i.
+pub const NOTE_BLOCK_ROOTS_SECTION_OFFSET: MemoryOffset = 1_056_768;
+
 /// The offsets at which data of a consumed note is stored relative to the start of its data segment.
 pub const CONSUMED_NOTE_ID_OFFSET: MemoryOffset = 0;
 pub const CONSUMED_NOTE_SERIAL_NUM_OFFSET: MemoryOffset = 1;
@@ -223,6 +349,18 @@ pub const CONSUMED_NOTE_NUM_INPUTS_OFFSET: MemoryOffset = 7;
 pub const CONSUMED_NOTE_NUM_ASSETS_OFFSET: MemoryOffset = 8;
 pub const CONSUMED_NOTE_ASSETS_OFFSET: MemoryOffset = 9;
 
+/// The offset at which the inputs of a consumed note are stored when read by the kernel, relative
+/// to the start of its data segment. The inputs are padded to the next multiple of 8 elements and
+/// follow the maximum number of assets a note can carry.
+pub const CONSUMED_NOTE_INPUTS_OFFSET: MemoryOffset = 265;
+
+/// The positions of the note metadata fields within the note metadata word. The metadata word is
+/// laid out as [tag, sender, note_type, aux].
+pub const NOTE_METADATA_TAG_IDX: usize = 0;
+pub const NOTE_METADATA_SENDER_IDX: usize = 1;
+pub const NOTE_METADATA_NOTE_TYPE_IDX: usize = 2;
+pub const NOTE_METADATA_AUX_IDX: usize = 3;
+
 // OUTPUT NOTES DATA
 // ------------------------------------------------------------------------------------------------
 // Output notes section contains data of all notes produced by a transaction. The section starts at
@@ -257,3 +395,179 @@ pub const CREATED_NOTE_RECIPIENT_OFFSET: MemoryOffset = 2;
 pub const CREATED_NOTE_ASSET_HASH_OFFSET: MemoryOffset = 3;
 pub const CREATED_NOTE_NUM_ASSETS_OFFSET: MemoryOffset = 4;
 pub const CREATED_NOTE_ASSETS_OFFSET: MemoryOffset = 5;
+
+// MEMORY LAYOUT
+// ================================================================================================
+
+/// A group of constants describing a section of the transaction kernel memory.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MemoryLayoutSection {
+    /// The name of the section.
+    pub name: &'static str,
+    /// The names and values of the constants describing the section.
+    pub constants: &'static [(&'static str, u64)],
+}
+
+/// All constants defined in this module, grouped by the section of the kernel memory they describe.
+///
+/// This allows tooling to inspect the memory layout of the transaction kernel without parsing
+/// the kernel sources. Constants added to this module must also be added here.
+pub const MEMORY_LAYOUT: &[MemoryLayoutSection] = &[
+    MemoryLayoutSection {
+        name: "reserved account storage slots",
+        constants: &[
+            ("FAUCET_STORAGE_DATA_SLOT", FAUCET_STORAGE_DATA_SLOT as u64),
+            ("OUTPUT_NOTE_HOOK_STORAGE_SLOT", OUTPUT_NOTE_HOOK_STORAGE_SLOT as u64),
+            ("AUTH_SCHEME_STORAGE_SLOT", AUTH_SCHEME_STORAGE_SLOT as u64),
+            ("SLOT_TYPES_COMMITMENT_STORAGE_SLOT", SLOT_TYPES_COMMITMENT_STORAGE_SLOT as u64),
+        ],
+    },
+    MemoryLayoutSection {
+        name: "bookkeeping",
+        constants: &[
+            ("TX_VAULT_ROOT_PTR", TX_VAULT_ROOT_PTR as u64),
+            ("CURRENT_CONSUMED_NOTE_PTR", CURRENT_CONSUMED_NOTE_PTR as u64),
+            ("NUM_CREATED_NOTES_PTR", NUM_CREATED_NOTES_PTR as u64),
+            ("INPUT_VAULT_ROOT_PTR", INPUT_VAULT_ROOT_PTR as u64),
+            ("OUTPUT_VAULT_ROOT_PTR", OUTPUT_VAULT_ROOT_PTR as u64),
+            ("ACCT_PROC_CALL_DEPTH_PTR", ACCT_PROC_CALL_DEPTH_PTR as u64),
+            ("TX_LOG_COMMITMENT_PTR", TX_LOG_COMMITMENT_PTR as u64),
+            ("NUM_TX_LOG_ENTRIES_PTR", NUM_TX_LOG_ENTRIES_PTR as u64),
+            ("NOTE_ARGS_COMMITMENT_PTR", NOTE_ARGS_COMMITMENT_PTR as u64),
+            ("NUM_NOTE_BLOCKS_PTR", NUM_NOTE_BLOCKS_PTR as u64),
+            ("NOTE_CYCLE_BUDGET_PTR", NOTE_CYCLE_BUDGET_PTR as u64),
+            ("NOTE_CYCLE_START_PTR", NOTE_CYCLE_START_PTR as u64),
+            ("MERGE_CREATED_NOTES_PTR", MERGE_CREATED_NOTES_PTR as u64),
+            ("FEE_ASSET_IDX_PTR", FEE_ASSET_IDX_PTR as u64),
+            ("FEE_ASSET_PTR", FEE_ASSET_PTR as u64),
+        ],
+    },
+    MemoryLayoutSection {
+        name: "global inputs",
+        constants: &[
+            ("GLOBAL_INPUTS_SECTION_OFFSET", GLOBAL_INPUTS_SECTION_OFFSET as u64),
+            ("BLK_HASH_PTR", BLK_HASH_PTR as u64),
+            ("ACCT_ID_PTR", ACCT_ID_PTR as u64),
+            ("INIT_ACCT_HASH_PTR", INIT_ACCT_HASH_PTR as u64),
+            ("NULLIFIER_COM_PTR", NULLIFIER_COM_PTR as u64),
+            ("INIT_NONCE_PTR", INIT_NONCE_PTR as u64),
+            ("TX_SCRIPT_ROOT_PTR", TX_SCRIPT_ROOT_PTR as u64),
+            ("TX_SCRIPT_ARGS_PTR", TX_SCRIPT_ARGS_PTR as u64),
+            ("TX_SCRIPT_ENTRYPOINT_PTR", TX_SCRIPT_ENTRYPOINT_PTR as u64),
+        ],
+    },
+    MemoryLayoutSection {
+        name: "block data",
+        constants: &[
+            ("BLOCK_DATA_SECTION_OFFSET", BLOCK_DATA_SECTION_OFFSET as u64),
+            ("PREV_BLOCK_HASH_PTR", PREV_BLOCK_HASH_PTR as u64),
+            ("CHAIN_ROOT_PTR", CHAIN_ROOT_PTR as u64),
+            ("ACCT_DB_ROOT_PTR", ACCT_DB_ROOT_PTR as u64),
+            ("NULLIFIER_DB_ROOT_PTR", NULLIFIER_DB_ROOT_PTR as u64),
+            ("BATCH_ROOT_PTR", BATCH_ROOT_PTR as u64),
+            ("PROOF_HASH_PTR", PROOF_HASH_PTR as u64),
+            ("BLOCK_METADATA_PTR", BLOCK_METADATA_PTR as u64),
+            ("BLOCK_NUMBER_IDX", BLOCK_NUMBER_IDX as u64),
+            ("PROTOCOL_VERSION_IDX", PROTOCOL_VERSION_IDX as u64),
+            ("TIMESTAMP_IDX", TIMESTAMP_IDX as u64),
+            ("KERNEL_CONFIG_HASH_PTR", KERNEL_CONFIG_HASH_PTR as u64),
+            ("NOTE_ROOT_PTR", NOTE_ROOT_PTR as u64),
+        ],
+    },
+    MemoryLayoutSection {
+        name: "kernel config",
+        constants: &[
+            ("KERNEL_CONFIG_PTR", KERNEL_CONFIG_PTR as u64),
+            ("KERNEL_PARAMS_PTR", KERNEL_PARAMS_PTR as u64),
+            ("KERNEL_VERSION_IDX", KERNEL_VERSION_IDX as u64),
+            ("KERNEL_FEATURES_IDX", KERNEL_FEATURES_IDX as u64),
+            ("BASE_FEE_IDX", BASE_FEE_IDX as u64),
+            ("FEE_PER_NOTE_IDX", FEE_PER_NOTE_IDX as u64),
+            ("KERNEL_LIMITS_PTR", KERNEL_LIMITS_PTR as u64),
+            ("MAX_INPUT_NOTES_IDX", MAX_INPUT_NOTES_IDX as u64),
+            ("MAX_OUTPUT_NOTES_IDX", MAX_OUTPUT_NOTES_IDX as u64),
+            ("MAX_LOG_ENTRIES_IDX", MAX_LOG_ENTRIES_IDX as u64),
+            ("FEE_ASSETS_PTR", FEE_ASSETS_PTR as u64),
+            ("NATIVE_FEE_FAUCET_ID_IDX", NATIVE_FEE_FAUCET_ID_IDX as u64),
+            ("NUM_ACCEPTED_FEE_ASSETS_IDX", NUM_ACCEPTED_FEE_ASSETS_IDX as u64),
+            ("ACCEPTED_FEE_ASSETS_ROOT_PTR", ACCEPTED_FEE_ASSETS_ROOT_PTR as u64),
+        ],
+    },
+    MemoryLayoutSection {
+        name: "fee oracle data",
+        constants: &[("FEE_ORACLE_DATA_PTR", FEE_ORACLE_DATA_PTR as u64)],
+    },
+    MemoryLayoutSection {
+        name: "chain data",
+        constants: &[
+            ("CHAIN_MMR_PTR", CHAIN_MMR_PTR as u64),
+            ("CHAIN_MMR_NUM_LEAVES_PTR", CHAIN_MMR_NUM_LEAVES_PTR as u64),
+            ("CHAIN_MMR_PEAKS_PTR", CHAIN_MMR_PEAKS_PTR as u64),
+        ],
+    },
+    MemoryLayoutSection {
+        name: "account data",
+        constants: &[
+            ("ACCT_DATA_MEM_SIZE", ACCT_DATA_MEM_SIZE as u64),
+            ("ACCT_DATA_SECTION_OFFSET", ACCT_DATA_SECTION_OFFSET as u64),
+            ("ACCT_ID_AND_NONCE_OFFSET", ACCT_ID_AND_NONCE_OFFSET as u64),
+            ("ACCT_ID_IDX", ACCT_ID_IDX as u64),
+            ("ACCT_NONCE_IDX", ACCT_NONCE_IDX as u64),
+            ("ACCT_ID_AND_NONCE_PTR", ACCT_ID_AND_NONCE_PTR as u64),
+            ("ACCT_VAULT_ROOT_OFFSET", ACCT_VAULT_ROOT_OFFSET as u64),
+            ("ACCT_VAULT_ROOT_PTR", ACCT_VAULT_ROOT_PTR as u64),
+            ("ACCT_STORAGE_ROOT_OFFSET", ACCT_STORAGE_ROOT_OFFSET as u64),
+            ("ACCT_STORAGE_ROOT_PTR", ACCT_STORAGE_ROOT_PTR as u64),
+            ("ACCT_CODE_ROOT_OFFSET", ACCT_CODE_ROOT_OFFSET as u64),
+            ("ACCT_CODE_ROOT_PTR", ACCT_CODE_ROOT_PTR as u64),
+            ("ACCT_NEW_CODE_ROOT_OFFSET", ACCT_NEW_CODE_ROOT_OFFSET as u64),
+            ("ACCT_NEW_CODE_ROOT_PTR", ACCT_NEW_CODE_ROOT_PTR as u64),
+            ("ACCT_STORAGE_SLOT_TYPE_DATA_OFFSET", ACCT_STORAGE_SLOT_TYPE_DATA_OFFSET as u64),
+        ],
+    },
+    MemoryLayoutSection {
+        name: "account procedure guards",
+        constants: &[("ACCT_PROC_GUARDS_SECTION_OFFSET", ACCT_PROC_GUARDS_SECTION_OFFSET as u64)],
+    },
+    MemoryLayoutSection {
+        name: "notes data",
+        constants: &[("NOTE_MEM_SIZE", NOTE_MEM_SIZE as u64)],
+    },
+    MemoryLayoutSection {
+        name: "input notes data",
+        constants: &[
+            ("CONSUMED_NOTE_SECTION_OFFSET", CONSUMED_NOTE_SECTION_OFFSET as u64),
+            ("CONSUMED_NOTE_DATA_SECTION_OFFSET", CONSUMED_NOTE_DATA_SECTION_OFFSET as u64),
+            ("CONSUMED_NOTE_NUM_PTR", CONSUMED_NOTE_NUM_PTR as u64),
+            ("NOTE_BLOCK_ROOTS_SECTION_OFFSET", NOTE_BLOCK_ROOTS_SECTION_OFFSET as u64),
+            ("CONSUMED_NOTE_ID_OFFSET", CONSUMED_NOTE_ID_OFFSET as u64),
+            ("CONSUMED_NOTE_SERIAL_NUM_OFFSET", CONSUMED_NOTE_SERIAL_NUM_OFFSET as u64),
+            ("CONSUMED_NOTE_SCRIPT_ROOT_OFFSET", CONSUMED_NOTE_SCRIPT_ROOT_OFFSET as u64),
+            ("CONSUMED_NOTE_INPUTS_HASH_OFFSET", CONSUMED_NOTE_INPUTS_HASH_OFFSET as u64),
+            ("CONSUMED_NOTE_ASSETS_HASH_OFFSET", CONSUMED_NOTE_ASSETS_HASH_OFFSET as u64),
+            ("CONSUMED_NOTE_METADATA_OFFSET", CONSUMED_NOTE_METADATA_OFFSET as u64),
+            ("CONSUMED_NOTE_ARGS_OFFSET", CONSUMED_NOTE_ARGS_OFFSET as u64),
+            ("CONSUMED_NOTE_NUM_INPUTS_OFFSET", CONSUMED_NOTE_NUM_INPUTS_OFFSET as u64),
+            ("CONSUMED_NOTE_NUM_ASSETS_OFFSET", CONSUMED_NOTE_NUM_ASSETS_OFFSET as u64),
+            ("CONSUMED_NOTE_ASSETS_OFFSET", CONSUMED_NOTE_ASSETS_OFFSET as u64),
+            ("CONSUMED_NOTE_INPUTS_OFFSET", CONSUMED_NOTE_INPUTS_OFFSET as u64),
+            ("NOTE_METADATA_TAG_IDX", NOTE_METADATA_TAG_IDX as u64),
+            ("NOTE_METADATA_SENDER_IDX", NOTE_METADATA_SENDER_IDX as u64),
+            ("NOTE_METADATA_NOTE_TYPE_IDX", NOTE_METADATA_NOTE_TYPE_IDX as u64),
+            ("NOTE_METADATA_AUX_IDX", NOTE_METADATA_AUX_IDX as u64),
+        ],
+    },
+    MemoryLayoutSection {
+        name: "output notes data",
+        constants: &[
+            ("CREATED_NOTE_SECTION_OFFSET", CREATED_NOTE_SECTION_OFFSET as u64),
+            ("CREATED_NOTE_CORE_DATA_SIZE", CREATED_NOTE_CORE_DATA_SIZE as u64),
+            ("CREATED_NOTE_ID_OFFSET", CREATED_NOTE_ID_OFFSET as u64),
+            ("CREATED_NOTE_METADATA_OFFSET", CREATED_NOTE_METADATA_OFFSET as u64),
+            ("CREATED_NOTE_RECIPIENT_OFFSET", CREATED_NOTE_RECIPIENT_OFFSET as u64),
+            ("CREATED_NOTE_ASSET_HASH_OFFSET", CREATED_NOTE_ASSET_HASH_OFFSET as u64),
+            ("CREATED_NOTE_NUM_ASSETS_OFFSET", CREATED_NOTE_NUM_ASSETS_OFFSET as u64),
+            ("CREATED_NOTE_ASSETS_OFFSET", CREATED_NOTE_ASSETS_OFFSET as u64),
+        ],
+    },
+];
@@ -2,8 +2,8 @@ use alloc::{string::ToString, vec::Vec};
 
 use miden_objects::{
     accounts::AccountId,
-    assembly::{Assembler, AssemblyContext, ProgramAst},
-    transaction::{OutputNote, OutputNotes, TransactionOutputs},
+    assembly::{Assembler, AssemblyContext, Library, LibraryNamespace, ProgramAst},
+    transaction::{OutputNote, OutputNotes, TransactionOutputs, TxLog, TxLogEntry},
     utils::{group_slice_elements, serde::DeserializationError},
     vm::{AdviceMap, ProgramInfo, StackInputs, StackOutputs},
     Digest, Felt, TransactionOutputError, Word,
@@ -14,20 +14,25 @@ use super::MidenLib;
 
 pub mod memory;
 
+mod abi;
+pub use abi::{KernelAbi, KernelEvent, KernelProcedure, KERNEL_ABI};
+
 mod events;
-pub use events::TransactionEvent;
+pub use events::{TransactionEvent, TransactionTrace};
 
 mod inputs;
 pub use inputs::ToTransactionKernelInputs;
 
 mod outputs;
 pub use outputs::{
-    parse_final_account_stub, FINAL_ACCOUNT_HASH_WORD_IDX, OUTPUT_NOTES_COMMITMENT_WORD_IDX,
-    TX_SCRIPT_ROOT_WORD_IDX,
+    parse_final_account_stub, TransactionKernelOutputs, FINAL_ACCOUNT_HASH_WORD_IDX,
+    OUTPUT_NOTES_COMMITMENT_WORD_IDX, TX_LOG_COMMITMENT_WORD_IDX, TX_SCRIPT_ROOT_WORD_IDX,
 };
 
 mod errors;
-pub use errors::{TransactionEventParsingError, TransactionKernelError};
+pub use errors::{
+    KernelError, TransactionEventParsingError, TransactionKernelError, TransactionTraceParsingError,
+};
 
 // TRANSACTION KERNEL
 // ================================================================================================
@@ -43,6 +48,12 @@ impl TransactionKernel {
         include_str!("../../asm/kernels/transaction/api.masm")
     }
 
+    /// Returns a structured description of the procedures exported by the transaction kernel, the
+    /// events emitted by it, and the layout of its memory.
+    pub fn abi() -> &'static KernelAbi {
+        &KERNEL_ABI
+    }
+
     /// Returns an AST of the transaction kernel executable program.
     ///
     /// # Errors
@@ -83,6 +94,13 @@ impl TransactionKernel {
             .expect("kernel must be well formed")
     }
 
+    /// Returns the namespaces of the libraries loaded into the transaction kernel assembler.
+    ///
+    /// These namespaces are reserved, and thus cannot be used by user-defined libraries.
+    pub fn library_namespaces() -> Vec<LibraryNamespace> {
+        vec![MidenLib::default().root_ns().clone(), StdLibrary::default().root_ns().clone()]
+    }
+
     // STACK INPUTS / OUTPUTS
     // --------------------------------------------------------------------------------------------
 
@@ -116,39 +134,22 @@ impl TransactionKernel {
             .expect("Invalid stack input")
     }
 
+    /// Returns the stack outputs which the transaction kernel produces for the provided data.
+    ///
+    /// See [TransactionKernelOutputs] for the layout of the output stack.
     pub fn build_output_stack(
         final_acct_hash: Digest,
         output_notes_hash: Digest,
         tx_script_root: Option<Digest>,
+        tx_log_commitment: Digest,
     ) -> StackOutputs {
-        let mut outputs: Vec<Felt> = Vec::with_capacity(9);
-        outputs.extend(final_acct_hash);
-        outputs.extend(output_notes_hash);
-        outputs.extend(tx_script_root.unwrap_or_default());
-        outputs.reverse();
-        StackOutputs::new(outputs, Vec::new())
-            .map_err(|e| e.to_string())
-            .expect("Invalid stack output")
-    }
-
-    /// Extracts transaction output data from the provided stack outputs.
-    ///
-    /// The data on the stack is expected to be arranged as follows:
-    ///
-    /// Stack: [TXSR, CNC, FAH]
-    ///
-    /// Where:
-    /// - TXSR is the transaction script root.
-    /// - CNC is the commitment to the notes created by the transaction.
-    /// - FAH is the final account hash of the account that the transaction is being
-    ///   executed against.
-    pub fn parse_output_stack(stack: &StackOutputs) -> (Digest, Digest, Digest) {
-        // TODO: use constants
-        let tx_script_root = stack.get_stack_word(0).expect("first word missing").into();
-        let output_notes_hash = stack.get_stack_word(4).expect("second word missing").into();
-        let final_account_hash = stack.get_stack_word(8).expect("third word missing").into();
-
-        (final_account_hash, output_notes_hash, tx_script_root)
+        TransactionKernelOutputs::new(
+            final_acct_hash,
+            output_notes_hash,
+            tx_script_root,
+            tx_log_commitment,
+        )
+        .to_stack_outputs()
     }
 
     // TRANSACTION OUTPUT PARSER
@@ -156,24 +157,21 @@ impl TransactionKernel {
 
     /// Returns [TransactionOutputs] constructed from the provided output stack and advice map.
     ///
-    /// The output stack is expected to be arrange as follows:
-    ///
-    /// Stack: [TXSR, CNC, FAH]
-    ///
-    /// Where:
-    /// - TXSR is the transaction script root.
-    /// - CNC is the commitment to the notes created by the transaction.
-    /// - FAH is the final account hash of the account that the transaction is being
-    ///   executed against.
+    /// The output stack is parsed into [TransactionKernelOutputs], which describes its layout.
     ///
     /// The actual data describing the new account state and output notes is expected to be located
-    /// in the provided advice map under keys CNC and FAH.
+    /// in the provided advice map under keys CNC and FAH. The provided log entries are expected to
+    /// be consistent with TLC.
     pub fn from_transaction_parts(
         stack: &StackOutputs,
         adv_map: &AdviceMap,
         output_notes: Vec<OutputNote>,
+        log_entries: Vec<TxLogEntry>,
     ) -> Result<TransactionOutputs, TransactionOutputError> {
-        let (final_acct_hash, output_notes_hash, _tx_script_root) = Self::parse_output_stack(stack);
+        let kernel_outputs = TransactionKernelOutputs::parse(stack)?;
+        let final_acct_hash = kernel_outputs.final_account_hash();
+        let output_notes_hash = kernel_outputs.output_notes_commitment();
+        let tx_log_commitment = kernel_outputs.tx_log_commitment();
 
         // parse final account state
         let final_account_data: &[Word] = group_slice_elements(
@@ -193,6 +191,15 @@ impl TransactionKernel {
             ));
         }
 
-        Ok(TransactionOutputs { account, output_notes })
+        // validate log entries
+        let log = TxLog::new(log_entries)?;
+        if tx_log_commitment != log.commitment() {
+            return Err(TransactionOutputError::TxLogCommitmentInconsistent(
+                tx_log_commitment,
+                log.commitment(),
+            ));
+        }
+
+        Ok(TransactionOutputs { account, output_notes, log })
     }
 }
@@ -1,6 +1,9 @@
+use alloc::vec::Vec;
+
 use miden_objects::{
     accounts::{AccountId, AccountStub},
-    AccountError, Word,
+    vm::StackOutputs,
+    AccountError, Digest, Felt, TransactionOutputError, Word, WORD_SIZE,
 };
 
 use super::memory::{
@@ -20,6 +23,128 @@ pub const OUTPUT_NOTES_COMMITMENT_WORD_IDX: usize = 1;
 /// The index of the word at which the final account hash is stored on the output stack.
 pub const FINAL_ACCOUNT_HASH_WORD_IDX: usize = 2;
 
+/// The index of the word at which the transaction log commitment is stored on the output stack.
+pub const TX_LOG_COMMITMENT_WORD_IDX: usize = 3;
+
+/// The number of elements which the transaction kernel leaves on the output stack.
+const NUM_OUTPUT_STACK_ELEMENTS: usize = 16;
+
+// TRANSACTION KERNEL OUTPUTS
+// ================================================================================================
+
+/// Typed representation of the stack outputs of the transaction kernel.
+///
+/// The transaction kernel leaves the following data on the stack once it completes:
+///
+/// Stack: [TXSR, CNC, FAH, TLC]
+///
+/// Where:
+/// - TXSR is the transaction script root, or [ZERO; 4] if the transaction has no script.
+/// - CNC is the commitment to the notes created by the transaction.
+/// - FAH is the final account hash of the account that the transaction is being
+///   executed against.
+/// - TLC is the commitment to the log entries recorded by the transaction.
+///
+/// Note that the current version of the kernel does not output a transaction expiration.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransactionKernelOutputs {
+    tx_script_root: Digest,
+    output_notes_commitment: Digest,
+    final_account_hash: Digest,
+    tx_log_commitment: Digest,
+}
+
+impl TransactionKernelOutputs {
+    /// Returns new [TransactionKernelOutputs] instantiated from the provided parameters.
+    pub fn new(
+        final_account_hash: Digest,
+        output_notes_commitment: Digest,
+        tx_script_root: Option<Digest>,
+        tx_log_commitment: Digest,
+    ) -> Self {
+        Self {
+            tx_script_root: tx_script_root.unwrap_or_default(),
+            output_notes_commitment,
+            final_account_hash,
+            tx_log_commitment,
+        }
+    }
+
+    /// Parses the provided stack outputs of the transaction kernel.
+    ///
+    /// # Errors
+    /// Returns an error if the stack contains more elements than the transaction kernel outputs,
+    /// i.e., if the stack overflow table is not empty.
+    pub fn parse(stack: &StackOutputs) -> Result<Self, TransactionOutputError> {
+        if stack.has_overflow() || stack.stack().len() != NUM_OUTPUT_STACK_ELEMENTS {
+            return Err(TransactionOutputError::OutputStackInvalidLength {
+                expected: NUM_OUTPUT_STACK_ELEMENTS,
+                actual: stack.stack().len(),
+            });
+        }
+
+        Ok(Self {
+            tx_script_root: get_output_word(stack, TX_SCRIPT_ROOT_WORD_IDX),
+            output_notes_commitment: get_output_word(stack, OUTPUT_NOTES_COMMITMENT_WORD_IDX),
+            final_account_hash: get_output_word(stack, FINAL_ACCOUNT_HASH_WORD_IDX),
+            tx_log_commitment: get_output_word(stack, TX_LOG_COMMITMENT_WORD_IDX),
+        })
+    }
+
+    /// Returns the root of the transaction script, or None if the transaction was executed
+    /// without a script.
+    pub fn tx_script_root(&self) -> Option<Digest> {
+        if self.tx_script_root == Digest::default() {
+            None
+        } else {
+            Some(self.tx_script_root)
+        }
+    }
+
+    /// Returns the commitment to the notes created by the transaction.
+    pub fn output_notes_commitment(&self) -> Digest {
+        self.output_notes_commitment
+    }
+
+    /// Returns the hash of the account state after the transaction.
+    pub fn final_account_hash(&self) -> Digest {
+        self.final_account_hash
+    }
+
+    /// Returns the commitment to the log entries recorded by the transaction.
+    pub fn tx_log_commitment(&self) -> Digest {
+        self.tx_log_commitment
+    }
+
+    /// Returns the [StackOutputs] which the transaction kernel is expected to produce for these
+    /// outputs.
+    pub fn to_stack_outputs(&self) -> StackOutputs {
+        let mut outputs: Vec<Felt> = Vec::with_capacity(NUM_OUTPUT_STACK_ELEMENTS);
+        outputs.extend(self.tx_log_commitment);
+        outputs.extend(self.final_account_hash);
+        outputs.extend(self.output_notes_commitment);
+        outputs.extend(self.tx_script_root);
+        outputs.reverse();
+        StackOutputs::new(outputs, Vec::new()).expect("kernel outputs fit into the stack top")
+    }
+}
+
+impl TryFrom<&StackOutputs> for TransactionKernelOutputs {
+    type Error = TransactionOutputError;
+
+    fn try_from(stack: &StackOutputs) -> Result<Self, Self::Error> {
+        Self::parse(stack)
+    }
+}
+
+/// Returns the word at the specified word index of the output stack.
+fn get_output_word(stack: &StackOutputs, word_idx: usize) -> Digest {
+    stack
+        .get_stack_word(word_idx * WORD_SIZE)
+        .expect("output stack contains 16 elements")
+        .into()
+}
+
 // ACCOUNT STUB EXTRACTOR
 // ================================================================================================
 
@@ -27,7 +152,10 @@ pub const FINAL_ACCOUNT_HASH_WORD_IDX: usize = 2;
 /// Returns a tuple of account ID, vault root, storage root, code root, and nonce.
 pub fn parse_final_account_stub(elements: &[Word]) -> Result<AccountStub, AccountError> {
     if elements.len() != ACCT_DATA_MEM_SIZE {
-        return Err(AccountError::StubDataIncorrectLength(elements.len(), ACCT_DATA_MEM_SIZE));
+        return Err(AccountError::StubDataIncorrectLength {
+            expected: ACCT_DATA_MEM_SIZE,
+            actual: elements.len(),
+        });
     }
 
     let id = AccountId::try_from(elements[ACCT_ID_AND_NONCE_OFFSET as usize][ACCT_ID_IDX])?;
@@ -0,0 +1,149 @@
+use alloc::{collections::BTreeMap, string::String, sync::Arc, vec::Vec};
+
+use miden_objects::{
+    accounts::AccountDelta, crypto::auth::AuthSecretKey, notes::NoteEnvelope,
+    transaction::OutputNote, Digest, Felt, Word,
+};
+
+// SIGNATURE REQUEST
+// ================================================================================================
+
+/// A request to sign a message with one of the keys held by a [BasicAuthenticator], made by the
+/// authentication procedure of an account while executing a transaction.
+///
+/// Besides the message, the request describes the effects of the transaction up to the point at
+/// which the signature was requested, so that the request can be presented to the user for
+/// approval.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SignatureRequest {
+    pub_key: Word,
+    message: Word,
+    account_delta: AccountDelta,
+    output_notes: Vec<NoteEnvelope>,
+}
+
+impl SignatureRequest {
+    /// Returns a new [SignatureRequest] for signing the message with the specified public key.
+    pub fn new(
+        pub_key: Word,
+        message: Word,
+        account_delta: AccountDelta,
+        output_notes: &[OutputNote],
+    ) -> Self {
+        Self {
+            pub_key,
+            message,
+            account_delta,
+            output_notes: output_notes.iter().map(NoteEnvelope::from).collect(),
+        }
+    }
+
+    /// Returns the public key with which the message is to be signed.
+    pub fn pub_key(&self) -> Word {
+        self.pub_key
+    }
+
+    /// Returns the message to be signed.
+    pub fn message(&self) -> Word {
+        self.message
+    }
+
+    /// Returns the changes made to the account by the transaction so far.
+    pub fn account_delta(&self) -> &AccountDelta {
+        &self.account_delta
+    }
+
+    /// Returns the envelopes of the notes created by the transaction so far.
+    pub fn output_notes(&self) -> &[NoteEnvelope] {
+        &self.output_notes
+    }
+}
+
+// BASIC AUTHENTICATOR
+// ================================================================================================
+
+/// A callback which approves or denies a [SignatureRequest], returning the reason for the denial
+/// as an error.
+///
+/// The callback is invoked synchronously during transaction execution, and thus execution is
+/// suspended until the callback returns. Applications which collect approvals asynchronously
+/// (e.g., via a user interface running on another thread) can forward the request over a channel
+/// and block on the response.
+pub type SignatureRequestHandler =
+    Arc<dyn Fn(&SignatureRequest) -> Result<(), String> + Send + Sync>;
+
+/// An authenticator which signs the messages requested by the authentication procedures of
+/// accounts with a set of secret keys.
+///
+/// The keys are provided to the transaction kernel only when a signature with the corresponding
+/// public key is requested, and only after the request has been approved by the handler set via
+/// [BasicAuthenticator::on_signature_request()], if any. Requests for keys which are not held by
+/// the authenticator are served from the advice inputs of the transaction, as usual.
+///
+/// Since the keys are not part of the transaction witness, the same authenticator must also be
+/// provided to the transaction prover. Signature request handlers are not invoked while proving,
+/// as the requests have already been approved when the transaction was executed.
+#[derive(Clone, Default)]
+pub struct BasicAuthenticator {
+    keys: BTreeMap<Digest, Vec<Felt>>,
+    signature_request_handler: Option<SignatureRequestHandler>,
+}
+
+impl BasicAuthenticator {
+    /// Returns a new [BasicAuthenticator] holding the specified secret keys.
+    pub fn new(keys: &[AuthSecretKey]) -> Self {
+        let keys = keys
+            .iter()
+            .map(|key| {
+                let (pub_key, key_data) = key.to_advice_map_entry();
+                (pub_key.into(), key_data)
+            })
+            .collect();
+
+        Self { keys, signature_request_handler: None }
+    }
+
+    /// Sets the handler which approves or denies every signature request before the signature is
+    /// produced.
+    ///
+    /// Transactions in which a signature request is denied fail to execute.
+    pub fn on_signature_request<F>(mut self, handler: F) -> Self
+    where
+        F: Fn(&SignatureRequest) -> Result<(), String> + Send + Sync + 'static,
+    {
+        self.signature_request_handler = Some(Arc::new(handler));
+        self
+    }
+
+    /// Returns true if this authenticator holds the secret key for the specified public key.
+    pub fn contains_key(&self, pub_key: Word) -> bool {
+        self.keys.contains_key(&Digest::from(pub_key))
+    }
+
+    /// Returns the number of secret keys held by this authenticator.
+    pub fn num_keys(&self) -> usize {
+        self.keys.len()
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Passes the request to the signature request handler, and returns the advice map entry of
+    /// the requested secret key if the request was approved and the key is held by this
+    /// authenticator.
+    pub(crate) fn authorize(&self, request: &SignatureRequest) -> Result<Option<&[Felt]>, String> {
+        if let Some(handler) = &self.signature_request_handler {
+            handler(request)?;
+        }
+
+        Ok(self.keys.get(&Digest::from(request.pub_key)).map(Vec::as_slice))
+    }
+
+    /// Returns a copy of this authenticator without the signature request handler.
+    pub(crate) fn without_signature_request_handler(&self) -> Self {
+        Self {
+            keys: self.keys.clone(),
+            signature_request_handler: None,
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use alloc::string::String;
+use core::{fmt::Write, marker::PhantomData};
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+use miden_objects::utils::serde::{Deserializable, Serializable};
+
+use super::Cache;
+
+// FILE CACHE
+// ================================================================================================
+
+/// A [Cache] which persists values in a directory of the file system, and thus retains them
+/// across restarts.
+///
+/// Every value is stored in a separate file named after the hex encoding of the serialized key.
+/// Files are replaced atomically, so that a value is never read while it is being written.
+/// Failures to read or write a file are not reported; unreadable or corrupted files are treated as
+/// missing values, and values which could not be written are dropped.
+#[derive(Debug, Clone)]
+pub struct FileCache<K, V> {
+    dir: PathBuf,
+    _entries: PhantomData<fn(K) -> V>,
+}
+
+impl<K, V> FileCache<K, V> {
+    /// Returns a new [FileCache] which persists values in the specified directory.
+    ///
+    /// The directory is created if it does not exist yet. Values persisted in the directory by a
+    /// previous [FileCache] are retained.
+    ///
+    /// # Errors
+    /// Returns an error if the directory could not be created.
+    pub fn new(dir: impl AsRef<Path>) -> std::io::Result<Self> {
+        fs::create_dir_all(dir.as_ref())?;
+        Ok(Self {
+            dir: dir.as_ref().to_path_buf(),
+            _entries: PhantomData,
+        })
+    }
+
+    /// Returns the directory in which this cache persists values.
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+}
+
+impl<K: Serializable, V: Serializable + Deserializable> FileCache<K, V> {
+    /// Returns the path of the file in which the value for the specified key is persisted.
+    fn value_path(&self, key: &K) -> PathBuf {
+        let file_name = key.to_bytes().iter().fold(String::new(), |mut name, byte| {
+            let _ = write!(name, "{byte:02x}");
+            name
+        });
+        self.dir.join(file_name)
+    }
+}
+
+impl<K: Serializable, V: Serializable + Deserializable> Cache<K, V> for FileCache<K, V> {
+    fn get(&self, key: &K) -> Option<V> {
+        let bytes = fs::read(self.value_path(key)).ok()?;
+        V::read_from_bytes(&bytes).ok()
+    }
+
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        let prev_value = self.get(&key);
+
+        // write the value into a temporary file first, and then move it into place; this makes
+        // sure that concurrent readers see either the previous or the new value
+        let path = self.value_path(&key);
+        let tmp_path = path.with_extension("tmp");
+        if fs::write(&tmp_path, value.to_bytes()).is_err() || fs::rename(&tmp_path, &path).is_err()
+        {
+            // the value could not be persisted; make sure the previous value is not returned
+            // for the key anymore
+            let _ = fs::remove_file(&tmp_path);
+            let _ = fs::remove_file(&path);
+        }
+
+        prev_value
+    }
+}
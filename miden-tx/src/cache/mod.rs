@@ -0,0 +1,65 @@
+use alloc::collections::BTreeMap;
+
+#[cfg(feature = "std")]
+mod file;
+#[cfg(feature = "std")]
+pub use file::FileCache;
+
+// CACHE
+// ================================================================================================
+
+/// A key-value store used by the [TransactionCompiler](crate::TransactionCompiler) to cache data
+/// derived from compiled code (e.g., the MAST roots of account procedures).
+///
+/// Caches are best effort: a value missing from a cache is re-derived by the compiler, and thus
+/// an implementation may drop values at any time (e.g., if it fails to persist them).
+pub trait Cache<K, V> {
+    /// Returns the value associated with the specified key, if any.
+    fn get(&self, key: &K) -> Option<V>;
+
+    /// Associates the specified value with the specified key, and returns the value previously
+    /// associated with the key, if any.
+    fn insert(&mut self, key: K, value: V) -> Option<V>;
+}
+
+// IN-MEMORY CACHE
+// ================================================================================================
+
+/// A [Cache] which keeps all values in memory, and thus does not retain them across restarts.
+#[derive(Debug, Clone)]
+pub struct InMemoryCache<K, V> {
+    entries: BTreeMap<K, V>,
+}
+
+impl<K, V> InMemoryCache<K, V> {
+    /// Returns a new empty [InMemoryCache].
+    pub fn new() -> Self {
+        Self { entries: BTreeMap::new() }
+    }
+
+    /// Returns the number of values in this cache.
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if this cache does not contain any values.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+impl<K, V> Default for InMemoryCache<K, V> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: Ord, V: Clone> Cache<K, V> for InMemoryCache<K, V> {
+    fn get(&self, key: &K) -> Option<V> {
+        self.entries.get(key).cloned()
+    }
+
+    fn insert(&mut self, key: K, value: V) -> Option<V> {
+        self.entries.insert(key, value)
+    }
+}
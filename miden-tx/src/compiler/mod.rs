@@ -1,14 +1,14 @@
-use alloc::{collections::BTreeMap, vec::Vec};
+use alloc::{boxed::Box, collections::BTreeSet, format, string::String, vec::Vec};
 
 use miden_objects::{
-    assembly::{Assembler, AssemblyContext, ModuleAst, ProgramAst},
-    transaction::{InputNotes, TransactionScript},
+    assembly::{Assembler, AssemblyContext, Library, LibraryNamespace, ModuleAst, ProgramAst},
+    transaction::{InputNotes, PersistedTransaction, TransactionScript},
     Felt, NoteError, TransactionScriptError, Word,
 };
 
 use super::{
-    AccountCode, AccountId, CodeBlock, Digest, NoteScript, Program, TransactionCompilerError,
-    TransactionKernel,
+    AccountCode, AccountId, Cache, CodeBlock, Digest, InMemoryCache, NoteScript, Program,
+    TransactionCompilerError, TransactionKernel,
 };
 
 #[cfg(test)]
@@ -25,10 +25,18 @@ mod tests;
 ///
 /// In addition to transaction compilation, transaction compiler provides methods which can be
 /// used to compile Miden account code and note scripts.
+///
+/// Additional MASM libraries can be made available to account code, note scripts, and transaction
+/// scripts via [TransactionCompiler::with_library()].
+///
+/// The interfaces of loaded accounts are kept in an in-memory [Cache] by default. A persistent
+/// cache (e.g., a [FileCache](crate::cache::FileCache)) can be provided via
+/// [TransactionCompiler::with_account_interface_cache()].
 pub struct TransactionCompiler {
     assembler: Assembler,
-    account_procedures: BTreeMap<AccountId, Vec<Digest>>,
+    account_procedures: Box<dyn Cache<AccountId, Vec<Digest>> + Send>,
     kernel_main: CodeBlock,
+    library_namespaces: BTreeSet<LibraryNamespace>,
 }
 
 impl TransactionCompiler {
@@ -46,9 +54,51 @@ impl TransactionCompiler {
 
         TransactionCompiler {
             assembler,
-            account_procedures: BTreeMap::default(),
+            account_procedures: Box::new(InMemoryCache::new()),
             kernel_main,
+            library_namespaces: TransactionKernel::library_namespaces().into_iter().collect(),
+        }
+    }
+
+    /// Makes the procedures of the provided library available to account code, note scripts, and
+    /// transaction scripts compiled by this [TransactionCompiler].
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The namespace of the library is already used by the libraries of the transaction kernel
+    ///   (i.e., `miden` and `std`) or by a previously added library.
+    /// - The modules of the library could not be loaded into the assembler.
+    pub fn with_library<L>(mut self, library: &L) -> Result<Self, TransactionCompilerError>
+    where
+        L: Library,
+    {
+        let namespace = library.root_ns();
+        if self.library_namespaces.contains(namespace) {
+            return Err(TransactionCompilerError::LibraryNamespaceConflict(namespace.clone()));
         }
+
+        self.assembler = self
+            .assembler
+            .with_library(library)
+            .map_err(TransactionCompilerError::LoadLibraryFailed)?;
+        self.library_namespaces.insert(namespace.clone());
+
+        Ok(self)
+    }
+
+    /// Sets the [Cache] in which the interfaces of loaded accounts (i.e., the MAST roots of their
+    /// procedures) are kept.
+    ///
+    /// Account interfaces which are already in the provided cache (e.g., because they were
+    /// persisted by a previous instance of the compiler) can be used to verify note scripts and
+    /// transaction scripts against the respective accounts without loading the accounts first.
+    /// Account interfaces kept in the previous cache are discarded.
+    pub fn with_account_interface_cache<C>(mut self, cache: C) -> Self
+    where
+        C: Cache<AccountId, Vec<Digest>> + Send + 'static,
+    {
+        self.account_procedures = Box::new(cache);
+        self
     }
 
     /// Puts the [TransactionCompiler] into debug mode.
@@ -124,13 +174,8 @@ impl TransactionCompiler {
         T: IntoIterator<Item = (Word, Vec<Felt>)>,
     {
         let (tx_script, code_block) =
-            TransactionScript::new(tx_script_ast, tx_script_inputs, &self.assembler).map_err(
-                |e| match e {
-                    TransactionScriptError::ScriptCompilationError(asm_error) => {
-                        TransactionCompilerError::CompileTxScriptFailed(asm_error)
-                    },
-                },
-            )?;
+            TransactionScript::new(tx_script_ast, tx_script_inputs, &self.assembler)
+                .map_err(tx_script_error)?;
         for target in target_account_proc.into_iter() {
             verify_program_account_compatibility(
                 &code_block,
@@ -141,6 +186,37 @@ impl TransactionCompiler {
         Ok(tx_script)
     }
 
+    /// Constructs a [TransactionScript] with multiple entrypoints by compiling the provided module
+    /// and checking the compatibility of each of the entrypoints with the target account
+    /// interfaces.
+    ///
+    /// The entrypoints are the procedures exported by the module, see
+    /// [TransactionScript::with_entrypoints()].
+    pub fn compile_tx_script_with_entrypoints<T>(
+        &self,
+        tx_script_ast: &ModuleAst,
+        tx_script_inputs: T,
+        target_account_proc: Vec<ScriptTarget>,
+    ) -> Result<TransactionScript, TransactionCompilerError>
+    where
+        T: IntoIterator<Item = (Word, Vec<Felt>)>,
+    {
+        let (tx_script, code_blocks) =
+            TransactionScript::with_entrypoints(tx_script_ast, tx_script_inputs, &self.assembler)
+                .map_err(tx_script_error)?;
+        for target in target_account_proc.into_iter() {
+            let target_interface = self.get_target_interface(target)?;
+            for code_block in code_blocks.iter() {
+                verify_program_account_compatibility(
+                    code_block,
+                    &target_interface,
+                    ScriptType::TransactionScript,
+                )?;
+            }
+        }
+        Ok(tx_script)
+    }
+
     // TRANSACTION PROGRAM BUILDER
     // --------------------------------------------------------------------------------------------
     /// Compiles a transaction which executes the provided notes and an optional tx script against
@@ -158,7 +234,6 @@ impl TransactionCompiler {
         let target_account_interface = self
             .account_procedures
             .get(&account_id)
-            .cloned()
             .ok_or(TransactionCompilerError::AccountInterfaceNotFound(account_id))?;
 
         // Transaction must contain at least one input note or a transaction script
@@ -169,6 +244,10 @@ impl TransactionCompiler {
         // Create the [AssemblyContext] for compilation of notes scripts and the transaction script
         let mut assembly_context = AssemblyContext::for_program(None);
 
+        // Add the account procedures to the [AssemblyContext]; this allows the transaction kernel
+        // to invoke the output note hook of the account via `dyncall`
+        self.compile_account_procedures(&target_account_interface, &mut assembly_context)?;
+
         // Compile note scripts
         let note_script_programs =
             self.compile_notes(&target_account_interface, notes, &mut assembly_context)?;
@@ -210,6 +289,41 @@ impl TransactionCompiler {
         Ok(program)
     }
 
+    /// Compiles the program of the provided [PersistedTransaction], such that the transaction can
+    /// be restored via [PersistedTransaction::into_witness()] or
+    /// [PersistedTransaction::into_executed_transaction()].
+    ///
+    /// The code of the account against which the transaction was executed is loaded into this
+    /// compiler. Libraries used by the account code, the note scripts, or the transaction script
+    /// must have been added to this compiler via [TransactionCompiler::with_library()].
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The account code, any of the note scripts, or the transaction script cannot be compiled.
+    /// - The hash of the compiled program differs from the hash of the program of the persisted
+    ///   transaction.
+    pub fn compile_persisted_transaction(
+        &mut self,
+        tx: &PersistedTransaction,
+    ) -> Result<Program, TransactionCompilerError> {
+        let account = tx.tx_inputs().account();
+        self.load_account(account.id(), account.code().module().clone())?;
+
+        let program = self.compile_transaction(
+            account.id(),
+            tx.tx_inputs().input_notes(),
+            tx.tx_args().tx_script().map(|tx_script| tx_script.code()),
+        )?;
+        if program.hash() != tx.program_hash() {
+            return Err(TransactionCompilerError::PersistedProgramMismatch {
+                expected: tx.program_hash(),
+                actual: program.hash(),
+            });
+        }
+
+        Ok(program)
+    }
+
     // HELPER METHODS
     // --------------------------------------------------------------------------------------------
 
@@ -226,9 +340,13 @@ impl TransactionCompiler {
 
         // Create and verify note programs. Note programs are verified against the target account.
         for recorded_note in notes.iter() {
+            let note_script = recorded_note.note().script();
+            let note_code = note_script
+                .code()
+                .ok_or(TransactionCompilerError::NoteScriptCodeMissing(note_script.hash()))?;
             let note_program = self
                 .assembler
-                .compile_in_context(recorded_note.note().script().code(), assembly_context)
+                .compile_in_context(note_code, assembly_context)
                 .map_err(TransactionCompilerError::CompileNoteScriptFailed)?;
             verify_program_account_compatibility(
                 &note_program,
@@ -262,6 +380,41 @@ impl TransactionCompiler {
         Ok(tx_script_code_block)
     }
 
+    /// Adds the account procedures with the specified roots, together with the procedures they
+    /// invoke, to the callset of the provided [AssemblyContext].
+    ///
+    /// Only procedures compiled by this compiler (e.g., via [TransactionCompiler::load_account()])
+    /// are added; procedures of accounts loaded via
+    /// [TransactionCompiler::load_account_interface()] are skipped.
+    fn compile_account_procedures(
+        &self,
+        procedures: &[Digest],
+        assembly_context: &mut AssemblyContext,
+    ) -> Result<(), TransactionCompilerError> {
+        // determine which procedures were compiled by this compiler; calls to all other procedures
+        // are registered as phantom calls, and thus are not part of the resulting callset
+        let program = build_call_program(procedures.iter());
+        let mut probe_context =
+            AssemblyContext::for_program(Some(&program)).with_phantom_calls(true);
+        self.assembler
+            .compile_in_context(&program, &mut probe_context)
+            .map_err(TransactionCompilerError::BuildCodeBlockTableFailed)?;
+        let cb_table = self
+            .assembler
+            .build_cb_table(probe_context)
+            .map_err(TransactionCompilerError::BuildCodeBlockTableFailed)?;
+
+        let mut compiled_procedures =
+            procedures.iter().filter(|procedure| cb_table.has(**procedure)).peekable();
+        if compiled_procedures.peek().is_some() {
+            self.assembler
+                .compile_in_context(&build_call_program(compiled_procedures), assembly_context)
+                .map_err(TransactionCompilerError::BuildCodeBlockTableFailed)?;
+        }
+
+        Ok(())
+    }
+
     /// Returns the account interface associated with the provided [ScriptTarget].
     ///
     /// # Errors
@@ -275,7 +428,6 @@ impl TransactionCompiler {
             ScriptTarget::AccountId(id) => self
                 .account_procedures
                 .get(&id)
-                .cloned()
                 .ok_or(TransactionCompilerError::AccountInterfaceNotFound(id)),
             ScriptTarget::Procedures(procs) => Ok(procs),
         }
@@ -291,6 +443,24 @@ impl Default for TransactionCompiler {
 // TRANSACTION COMPILER HELPERS
 // ------------------------------------------------------------------------------------------------
 
+/// Converts a [TransactionScriptError] into a [TransactionCompilerError].
+fn tx_script_error(err: TransactionScriptError) -> TransactionCompilerError {
+    match err {
+        TransactionScriptError::ScriptCompilationError(asm_error) => {
+            TransactionCompilerError::CompileTxScriptFailed(asm_error)
+        },
+        err => TransactionCompilerError::TxScriptError(err),
+    }
+}
+
+/// Returns a program which calls the procedures with the specified roots.
+fn build_call_program<'a>(procedures: impl Iterator<Item = &'a Digest>) -> ProgramAst {
+    let calls: Vec<String> =
+        procedures.map(|procedure| format!("call.{}", procedure.to_hex())).collect();
+    ProgramAst::parse(&format!("begin {} end", calls.join(" ")))
+        .expect("program calling account procedures is well formed")
+}
+
 /// Verifies that the provided program is compatible with the target account interface.
 ///
 /// This is achieved by checking that at least one execution branch in the program is compatible
@@ -355,7 +525,8 @@ fn recursively_collect_call_branches(code_block: &CodeBlock, branches: &mut Vec<
             recursively_collect_call_branches(block.body(), branches);
         },
         CodeBlock::Call(block) => {
-            if block.is_syscall() {
+            // the targets of dynamic calls are not known statically, and thus cannot be checked
+            if block.is_syscall() || block.fn_hash() == CodeBlock::new_dyn().hash() {
                 return;
             }
 
@@ -6,6 +6,7 @@ use miden_objects::{
         ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2, ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
         ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN_2, ACCOUNT_ID_SENDER,
     },
+    assembly::{LibraryNamespace, LibraryPath, MaslLibrary, Module, Version},
     assets::{Asset, FungibleAsset},
     notes::{
         Note, NoteAssets, NoteInclusionProof, NoteInputs, NoteMetadata, NoteRecipient, NoteType,
@@ -14,7 +15,11 @@ use miden_objects::{
     Felt, Word, ZERO,
 };
 
-use super::{AccountId, ModuleAst, ProgramAst, ScriptTarget, TransactionCompiler};
+use super::{
+    AccountId, Digest, ModuleAst, ProgramAst, ScriptTarget, TransactionCompiler,
+    TransactionCompilerError,
+};
+use crate::cache::{Cache, FileCache};
 
 // CONSTANTS
 // ================================================================================================
@@ -49,6 +54,14 @@ export.additional_procedure_2
 end
 ";
 
+// Procedures of an additional library:
+const LIBRARY_MATH_MASM: &str = "\
+export.add_three
+    push.3
+    add
+end
+";
+
 // TESTS
 // ================================================================================================
 
@@ -135,6 +148,54 @@ fn test_compile_valid_note_script() {
     }
 }
 
+#[test]
+fn test_account_interface_cache_is_persisted() {
+    let cache_dir = tempfile::tempdir().unwrap();
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+    let account_id_2 =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN_2).unwrap();
+
+    // the interface of the loaded account is persisted in the cache
+    let mut tx_compiler = TransactionCompiler::new()
+        .with_account_interface_cache(FileCache::new(cache_dir.path()).unwrap());
+    let account_code_ast = ModuleAst::parse(ACCOUNT_CODE_MASM).unwrap();
+    let account_code = tx_compiler.load_account(account_id, account_code_ast).unwrap();
+
+    let cache = FileCache::<AccountId, Vec<Digest>>::new(cache_dir.path()).unwrap();
+    assert_eq!(cache.get(&account_id), Some(account_code.procedures().to_vec()));
+    assert_eq!(cache.get(&account_id_2), None);
+
+    // a new compiler using the same cache can verify note scripts against the account without
+    // loading the account first; the account code is compiled via another account here to make
+    // its procedures available to note scripts
+    let note_script_ast = ProgramAst::parse(&format!("begin call.{ACCT_PROC_1} end")).unwrap();
+    let mut tx_compiler = TransactionCompiler::new();
+    tx_compiler
+        .load_account(account_id_2, ModuleAst::parse(ACCOUNT_CODE_MASM).unwrap())
+        .unwrap();
+    let result = tx_compiler
+        .compile_note_script(note_script_ast.clone(), vec![ScriptTarget::AccountId(account_id)]);
+    assert!(matches!(result, Err(TransactionCompilerError::AccountInterfaceNotFound(_))));
+
+    let mut tx_compiler = tx_compiler.with_account_interface_cache(cache);
+    tx_compiler
+        .load_account(account_id_2, ModuleAst::parse(ACCOUNT_CODE_MASM).unwrap())
+        .unwrap();
+    tx_compiler
+        .compile_note_script(note_script_ast, vec![ScriptTarget::AccountId(account_id)])
+        .unwrap();
+
+    // corrupted values are treated as missing
+    let mut cache = FileCache::<AccountId, Vec<Digest>>::new(cache_dir.path()).unwrap();
+    for entry in std::fs::read_dir(cache_dir.path()).unwrap() {
+        std::fs::write(entry.unwrap().path(), [0xff]).unwrap();
+    }
+    assert_eq!(cache.get(&account_id), None);
+    assert_eq!(cache.insert(account_id, account_code.procedures().to_vec()), None);
+    assert_eq!(cache.get(&account_id), Some(account_code.procedures().to_vec()));
+}
+
 fn mock_consumed_notes(
     tx_compiler: &mut TransactionCompiler,
     target_account: AccountId,
@@ -207,9 +268,70 @@ fn test_transaction_compilation_succeeds() {
     assert!(res.is_ok());
 }
 
+#[test]
+fn test_compile_with_library() {
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+    let note_script_src = "
+        use.dapp::math
+
+        begin
+            push.1 exec.math::add_three drop
+        end";
+
+    // without the library the note script cannot be compiled
+    let mut tx_compiler = TransactionCompiler::new();
+    tx_compiler
+        .load_account(account_id, ModuleAst::parse(ACCOUNT_CODE_MASM).unwrap())
+        .unwrap();
+    let note_script_ast = ProgramAst::parse(note_script_src).unwrap();
+    assert!(matches!(
+        tx_compiler.compile_note_script(note_script_ast, vec![]),
+        Err(TransactionCompilerError::CompileNoteScriptFailed(_))
+    ));
+
+    // the library is available to note scripts, tx scripts, and account code
+    let mut tx_compiler = TransactionCompiler::new().with_library(&mock_library("dapp")).unwrap();
+    let account_code_src = format!(
+        "use.dapp::math\n{ACCOUNT_CODE_MASM}\nexport.add_three\n    exec.math::add_three\nend\n"
+    );
+    tx_compiler
+        .load_account(account_id, ModuleAst::parse(&account_code_src).unwrap())
+        .unwrap();
+
+    let note_script_ast = ProgramAst::parse(note_script_src).unwrap();
+    tx_compiler
+        .compile_note_script(note_script_ast, vec![ScriptTarget::AccountId(account_id)])
+        .unwrap();
+
+    let tx_script_ast = ProgramAst::parse(note_script_src).unwrap();
+    tx_compiler
+        .compile_tx_script(tx_script_ast, vec![], vec![ScriptTarget::AccountId(account_id)])
+        .unwrap();
+
+    // libraries cannot use the namespaces of the kernel libraries or of other libraries
+    for namespace in ["miden", "std", "dapp"] {
+        let result = TransactionCompiler::new()
+            .with_library(&mock_library("dapp"))
+            .unwrap()
+            .with_library(&mock_library(namespace));
+        assert!(matches!(
+            result,
+            Err(TransactionCompilerError::LibraryNamespaceConflict(ns)) if ns.as_str() == namespace
+        ));
+    }
+}
+
 // HELPERS
 // ================================================================================================
 
+fn mock_library(namespace: &str) -> MaslLibrary {
+    let namespace = LibraryNamespace::new(namespace).unwrap();
+    let path = LibraryPath::new(format!("{}::math", namespace.as_str())).unwrap();
+    let module = Module::new(path, ModuleAst::parse(LIBRARY_MATH_MASM).unwrap());
+    MaslLibrary::new(namespace, Version::default(), false, vec![module], vec![]).unwrap()
+}
+
 fn hex_to_bytes(hex: &str) -> Vec<u8> {
     (2..hex.len())
         .step_by(2)
@@ -1,9 +1,14 @@
 use alloc::string::String;
 use core::fmt::{self, Display};
 
+use miden_lib::transaction::KernelError;
 use miden_objects::{
-    assembly::AssemblyError, notes::NoteId, Felt, NoteError, ProvenTransactionError,
-    TransactionInputError, TransactionOutputError,
+    assembly::{AssemblyError, LibraryNamespace},
+    notes::NoteId,
+    transaction::PostCondition,
+    utils::serde::DeserializationError,
+    AssetError, ChainMmrError, Felt, NoteError, ProvenTransactionError, TransactionInputError,
+    TransactionOutputError, TransactionScriptError,
 };
 use miden_verifier::VerificationError;
 
@@ -18,10 +23,15 @@ pub enum TransactionCompilerError {
     BuildCodeBlockTableFailed(AssemblyError),
     CompileNoteScriptFailed(AssemblyError),
     CompileTxScriptFailed(AssemblyError),
+    LibraryNamespaceConflict(LibraryNamespace),
     LoadAccountFailed(AccountError),
+    LoadLibraryFailed(AssemblyError),
     NoteIncompatibleWithAccountInterface(Digest),
+    NoteScriptCodeMissing(Digest),
     NoteScriptError(NoteError),
     NoTransactionDriver,
+    PersistedProgramMismatch { expected: Digest, actual: Digest },
+    TxScriptError(TransactionScriptError),
     TxScriptIncompatibleWithAccountInterface(Digest),
 }
 
@@ -32,17 +42,42 @@ impl fmt::Display for TransactionCompilerError {
 }
 
 #[cfg(feature = "std")]
-impl std::error::Error for TransactionCompilerError {}
+impl std::error::Error for TransactionCompilerError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::BuildCodeBlockTableFailed(err)
+            | Self::CompileNoteScriptFailed(err)
+            | Self::CompileTxScriptFailed(err)
+            | Self::LoadLibraryFailed(err) => Some(err),
+            Self::LoadAccountFailed(err) => Some(err),
+            Self::NoteScriptError(err) => Some(err),
+            _ => None,
+        }
+    }
+}
 
 // TRANSACTION EXECUTOR ERROR
 // ================================================================================================
 
+/// Errors which can occur when executing transactions.
+///
+/// Every variant has a stable numeric code (see [TransactionExecutorError::code()]) in the range
+/// 3001..=3999. Codes of existing variants never change, and codes of removed variants are never
+/// reused. More details about execution failures caused by the transaction kernel can be obtained
+/// via [TransactionExecutorError::kernel_error()].
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TransactionExecutorError {
     CompileNoteScriptFailed(TransactionCompilerError),
     CompileTransactionScriptFailed(TransactionCompilerError),
     CompileTransactionFailed(TransactionCompilerError),
-    ExecuteTransactionProgramFailed(ExecutionError),
+    DuplicateInputNote {
+        note_id: NoteId,
+    },
+    ExecutionFailure {
+        error: ExecutionError,
+        kernel_error: Option<KernelError>,
+        note_index: Option<usize>,
+    },
     FetchAccountCodeFailed(DataStoreError),
     FetchTransactionInputsFailed(DataStoreError),
     InconsistentAccountId {
@@ -53,18 +88,146 @@ pub enum TransactionExecutorError {
         expected: Option<Felt>,
         actual: Option<Felt>,
     },
+    IncompleteNoteGroup {
+        missing_note_id: NoteId,
+    },
+    InclusionDeadlineExpired {
+        deadline: u32,
+        block_ref: u32,
+    },
+    InvalidFeeConversionWitness {
+        faucet_id: AccountId,
+    },
     InvalidTransactionOutput(TransactionOutputError),
     LoadAccountFailed(TransactionCompilerError),
+    LoadLibraryFailed(TransactionCompilerError),
+    MissingTxScriptEntrypoint,
+    NoteScriptCodeMissing {
+        note_id: NoteId,
+        script_root: Digest,
+    },
+    PostConditionViolated {
+        post_condition: PostCondition,
+    },
+    UnacceptedFeeAsset {
+        faucet_id: AccountId,
+    },
+    UnknownNoteScript {
+        note_id: NoteId,
+        script_root: Digest,
+    },
+    UnsatisfiedNoteDependency {
+        note_id: NoteId,
+        dependency: NoteId,
+    },
+}
+
+impl TransactionExecutorError {
+    /// Returns a [TransactionExecutorError::ExecutionFailure] for the provided execution error.
+    ///
+    /// If the execution failed due to a failed assertion with an error code defined by the
+    /// transaction kernel or the Miden library, the corresponding [KernelError] is attached.
+    /// `note_index` is the index of the input note which was being processed when the execution
+    /// failed, if any.
+    pub fn execution_failure(error: ExecutionError, note_index: Option<usize>) -> Self {
+        let kernel_error = match error {
+            ExecutionError::FailedAssertion { err_code, .. } => KernelError::from_code(err_code),
+            _ => None,
+        };
+        Self::ExecutionFailure { error, kernel_error, note_index }
+    }
+
+    /// Returns the [KernelError] which caused the transaction execution to fail, if any.
+    pub fn kernel_error(&self) -> Option<KernelError> {
+        match self {
+            Self::ExecutionFailure { kernel_error, .. } => *kernel_error,
+            _ => None,
+        }
+    }
+
+    /// Returns the index of the input note which was being processed when the transaction
+    /// execution failed, or None if the execution did not fail while processing an input note.
+    ///
+    /// The note is known only if the traces emitted by the kernel are enabled in the execution
+    /// options of the executor, which is the case by default.
+    pub fn failed_note_index(&self) -> Option<usize> {
+        match self {
+            Self::ExecutionFailure { note_index, .. } => *note_index,
+            _ => None,
+        }
+    }
+
+    /// Returns the stable numeric code of this error.
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::CompileNoteScriptFailed(_) => 3001,
+            Self::CompileTransactionScriptFailed(_) => 3002,
+            Self::CompileTransactionFailed(_) => 3003,
+            Self::ExecutionFailure { .. } => 3004,
+            Self::FetchAccountCodeFailed(_) => 3005,
+            Self::FetchTransactionInputsFailed(_) => 3006,
+            Self::InconsistentAccountId { .. } => 3007,
+            Self::InconsistentAccountNonceDelta { .. } => 3008,
+            Self::InvalidTransactionOutput(_) => 3009,
+            Self::LoadAccountFailed(_) => 3010,
+            Self::UnknownNoteScript { .. } => 3011,
+            Self::LoadLibraryFailed(_) => 3012,
+            Self::UnsatisfiedNoteDependency { .. } => 3013,
+            Self::IncompleteNoteGroup { .. } => 3014,
+            Self::InclusionDeadlineExpired { .. } => 3015,
+            Self::DuplicateInputNote { .. } => 3016,
+            Self::MissingTxScriptEntrypoint => 3017,
+            Self::NoteScriptCodeMissing { .. } => 3018,
+            Self::PostConditionViolated { .. } => 3019,
+            Self::UnacceptedFeeAsset { .. } => 3020,
+            Self::InvalidFeeConversionWitness { .. } => 3021,
+        }
+    }
+
+    /// Returns true if the operation which failed with this error could succeed if retried.
+    ///
+    /// Only failures to fetch data from the data store can be retryable (see
+    /// [DataStoreError::is_retryable()]); transaction execution is deterministic, and thus all
+    /// other errors would occur again.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::FetchAccountCodeFailed(err) | Self::FetchTransactionInputsFailed(err) => {
+                err.is_retryable()
+            },
+            _ => false,
+        }
+    }
 }
 
 impl fmt::Display for TransactionExecutorError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        write!(f, "{:?}", self)
+        match self {
+            Self::ExecutionFailure { kernel_error: Some(kernel_error), .. } => {
+                write!(f, "transaction execution failed: {kernel_error}")
+            },
+            _ => write!(f, "{:?}", self),
+        }
     }
 }
 
 #[cfg(feature = "std")]
-impl std::error::Error for TransactionExecutorError {}
+impl std::error::Error for TransactionExecutorError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::CompileNoteScriptFailed(err)
+            | Self::CompileTransactionScriptFailed(err)
+            | Self::CompileTransactionFailed(err)
+            | Self::LoadAccountFailed(err)
+            | Self::LoadLibraryFailed(err) => Some(err),
+            Self::ExecutionFailure { error, .. } => Some(error),
+            Self::FetchAccountCodeFailed(err) | Self::FetchTransactionInputsFailed(err) => {
+                Some(err)
+            },
+            Self::InvalidTransactionOutput(err) => Some(err),
+            _ => None,
+        }
+    }
+}
 
 // TRANSACTION PROVER ERROR
 // ================================================================================================
@@ -75,6 +238,10 @@ pub enum TransactionProverError {
     InvalidAccountDelta(AccountError),
     InvalidTransactionOutput(TransactionOutputError),
     ProvenTransactionError(ProvenTransactionError),
+    NoteDeliveryMetadataMismatch(NoteId),
+    NoteDeliveryNoteNotFound(NoteId),
+    NoteEncryptionFailed(NoteId, String),
+    NoteEncryptorMissing(NoteId),
 }
 
 impl Display for TransactionProverError {
@@ -92,6 +259,18 @@ impl Display for TransactionProverError {
             TransactionProverError::ProvenTransactionError(inner) => {
                 write!(f, "Building proven transaction error: {}", inner)
             },
+            TransactionProverError::NoteDeliveryMetadataMismatch(note_id) => {
+                write!(f, "Metadata of output note {} does not match the delivered note", note_id)
+            },
+            TransactionProverError::NoteDeliveryNoteNotFound(note_id) => {
+                write!(f, "Output note {} with delivery instruction not found", note_id)
+            },
+            TransactionProverError::NoteEncryptionFailed(note_id, err) => {
+                write!(f, "Encrypting output note {} failed: {}", note_id, err)
+            },
+            TransactionProverError::NoteEncryptorMissing(note_id) => {
+                write!(f, "No note encryptor set to encrypt output note {}", note_id)
+            },
         }
     }
 }
@@ -106,6 +285,8 @@ impl std::error::Error for TransactionProverError {}
 pub enum TransactionVerifierError {
     TransactionVerificationFailed(VerificationError),
     InsufficientProofSecurityLevel(u32, u32),
+    InvalidProof(DeserializationError),
+    InvalidPublicInputs(DeserializationError),
 }
 
 impl fmt::Display for TransactionVerifierError {
@@ -117,19 +298,108 @@ impl fmt::Display for TransactionVerifierError {
 #[cfg(feature = "std")]
 impl std::error::Error for TransactionVerifierError {}
 
+// NOTE SCANNER ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NoteScannerError {
+    InvalidNoteInclusionProof(NoteId),
+    InvalidNoteIndex(NoteId, u64),
+    NoteDetailsMismatch(NoteId),
+}
+
+impl fmt::Display for NoteScannerError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NoteScannerError {}
+
+// ADVICE INJECTOR ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AdviceInjectorError {
+    DuplicateEventId(u32),
+    ReservedEventId(u32),
+}
+
+impl fmt::Display for AdviceInjectorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AdviceInjectorError {}
+
+// RECIPE ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RecipeError {
+    CreateAssetFailed(AssetError),
+    CreateNoteFailed(NoteError),
+    ExecuteTransactionFailed(TransactionExecutorError),
+    MintRecipientsMismatch { num_items: usize, num_recipients: usize },
+    NoAssets,
+    NoConsumableNotes,
+}
+
+impl fmt::Display for RecipeError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for RecipeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::CreateAssetFailed(err) => Some(err),
+            Self::CreateNoteFailed(err) => Some(err),
+            Self::ExecuteTransactionFailed(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
 // DATA STORE ERROR
 // ================================================================================================
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum DataStoreError {
     AccountNotFound(AccountId),
+    AccountSeedNotFound(AccountId),
     BlockNotFound(u32),
+    InvalidBlockPath {
+        block_num: u32,
+        block_ref: u32,
+    },
+    InvalidChainMmr(ChainMmrError),
     InvalidTransactionInput(TransactionInputError),
     InternalError(String),
     NoteAlreadyConsumed(NoteId),
+    NoteCreatedAfterReferenceBlock {
+        note_id: NoteId,
+        block_num: u32,
+        block_ref: u32,
+    },
     NoteNotFound(NoteId),
 }
 
+impl DataStoreError {
+    /// Returns true if the request which failed with this error could succeed if retried.
+    ///
+    /// Internal errors of the data store (e.g., a failure to connect to the underlying database)
+    /// are assumed to be transient, while all other errors are caused by invalid requests.
+    pub fn is_retryable(&self) -> bool {
+        matches!(self, Self::InternalError(_))
+    }
+}
+
 impl fmt::Display for DataStoreError {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{:?}", self)
@@ -137,4 +407,12 @@ impl fmt::Display for DataStoreError {
 }
 
 #[cfg(feature = "std")]
-impl std::error::Error for DataStoreError {}
+impl std::error::Error for DataStoreError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::InvalidChainMmr(err) => Some(err),
+            Self::InvalidTransactionInput(err) => Some(err),
+            _ => None,
+        }
+    }
+}
@@ -1,5 +1,11 @@
 use miden_objects::{
-    accounts::AccountId, assembly::ModuleAst, notes::NoteId, transaction::TransactionInputs,
+    accounts::{Account, AccountId},
+    assembly::ModuleAst,
+    block::KernelConfig,
+    crypto::merkle::{MerklePath, MmrPeaks},
+    notes::NoteId,
+    transaction::{InputNote, TransactionInputs},
+    BlockHeader, Digest, Word,
 };
 
 use crate::DataStoreError;
@@ -17,6 +23,10 @@ pub trait DataStore {
     /// recorded in the chain. In general, it is recommended that bock_ref corresponds to the
     /// latest block available in the data store.
     ///
+    /// Data stores which can provide the individual pieces of the transaction inputs (see
+    /// [WitnessStore]) can assemble the inputs via
+    /// [TransactionInputsBuilder](super::TransactionInputsBuilder).
+    ///
     /// # Errors
     /// Returns an error if:
     /// - The account with the specified ID could not be found in the data store.
@@ -35,3 +45,56 @@ pub trait DataStore {
     /// Returns the account code [ModuleAst] associated with the specified [AccountId].
     fn get_account_code(&self, account_id: AccountId) -> Result<ModuleAst, DataStoreError>;
 }
+
+// WITNESS STORE TRAIT
+// ================================================================================================
+
+/// The [WitnessStore] trait defines the interface used by
+/// [TransactionInputsBuilder](super::TransactionInputsBuilder) to fetch the individual pieces of
+/// the data required for transaction execution.
+pub trait WitnessStore {
+    /// Returns the account with the specified ID, together with its seed if the account is new.
+    ///
+    /// # Errors
+    /// Returns an error if the account could not be found in the store.
+    fn get_account(&self, account_id: AccountId)
+        -> Result<(Account, Option<Word>), DataStoreError>;
+
+    /// Returns the header of the block with the specified number.
+    ///
+    /// # Errors
+    /// Returns an error if the block could not be found in the store.
+    fn get_block_header(&self, block_num: u32) -> Result<BlockHeader, DataStoreError>;
+
+    /// Returns the peaks of the chain MMR committed to by the block with the specified number,
+    /// i.e., the peaks of the MMR of all blocks preceding the block.
+    ///
+    /// # Errors
+    /// Returns an error if the block could not be found in the store.
+    fn get_chain_peaks(&self, block_ref: u32) -> Result<MmrPeaks, DataStoreError>;
+
+    /// Returns the authentication path of the block with the specified number in the chain MMR
+    /// committed to by the block with number `block_ref`.
+    ///
+    /// # Errors
+    /// Returns an error if either of the blocks could not be found in the store.
+    fn get_block_path(&self, block_num: u32, block_ref: u32) -> Result<MerklePath, DataStoreError>;
+
+    /// Returns the note with the specified ID, together with the proof of its inclusion in the
+    /// block in which it was created.
+    ///
+    /// # Errors
+    /// Returns an error if the note could not be found in the store or was already consumed.
+    fn get_input_note(&self, note_id: NoteId) -> Result<InputNote, DataStoreError>;
+
+    /// Returns the kernel config with the specified commitment.
+    ///
+    /// By default, the default kernel config is returned, which is the config committed to by all
+    /// blocks which do not specify a kernel config.
+    ///
+    /// # Errors
+    /// Returns an error if the kernel config could not be found in the store.
+    fn get_kernel_config(&self, _commitment: Digest) -> Result<KernelConfig, DataStoreError> {
+        Ok(KernelConfig::default())
+    }
+}
@@ -0,0 +1,125 @@
+use alloc::{collections::BTreeSet, vec::Vec};
+
+use miden_objects::{
+    crypto::merkle::PartialMmr,
+    transaction::{ChainMmr, InputNotes, TransactionInputs},
+};
+
+use super::{AccountId, NoteId, WitnessStore};
+use crate::DataStoreError;
+
+// TRANSACTION INPUTS BUILDER
+// ================================================================================================
+
+/// A builder of [TransactionInputs] which fetches all data required to execute a transaction from
+/// a [WitnessStore].
+///
+/// Given the ID of the account, the IDs of the input notes, and the number of the reference block,
+/// the builder fetches:
+/// - The account, together with its seed if the account is new.
+/// - The header of the reference block and the kernel config committed to by it.
+/// - The input notes together with the proofs of their inclusion in the blocks in which they were
+///   created.
+/// - The headers of these blocks, and their authentication paths in the chain MMR committed to by
+///   the reference block.
+///
+/// The fetched data is validated as it is assembled, so that a store which provides inconsistent
+/// data results in an error pointing to the missing or invalid piece.
+#[derive(Debug, Clone)]
+pub struct TransactionInputsBuilder {
+    account_id: AccountId,
+    block_ref: u32,
+    notes: Vec<NoteId>,
+}
+
+impl TransactionInputsBuilder {
+    /// Returns a new [TransactionInputsBuilder] for a transaction executed against the specified
+    /// account, using the specified block as the reference block.
+    pub fn new(account_id: AccountId, block_ref: u32) -> Self {
+        Self { account_id, block_ref, notes: Vec::new() }
+    }
+
+    /// Adds the note with the specified ID to the input notes of the transaction.
+    pub fn with_note(mut self, note_id: NoteId) -> Self {
+        self.notes.push(note_id);
+        self
+    }
+
+    /// Adds the notes with the specified IDs to the input notes of the transaction, in the
+    /// specified order.
+    pub fn with_notes<I: IntoIterator<Item = NoteId>>(mut self, note_ids: I) -> Self {
+        self.notes.extend(note_ids);
+        self
+    }
+
+    /// Fetches the data required to execute the transaction from the provided store, and returns
+    /// the assembled [TransactionInputs].
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - Any piece of data could not be fetched from the store.
+    /// - The account is new, but the store did not provide its seed.
+    /// - Any of the notes was created after the reference block.
+    /// - The authentication path of any of the blocks in which the notes were created does not
+    ///   lead to the chain MMR committed to by the reference block.
+    /// - The fetched data does not form valid transaction inputs (e.g., the same note is
+    ///   specified more than once, or a note is not included in the block it claims).
+    pub fn build<S: WitnessStore + ?Sized>(
+        self,
+        store: &S,
+    ) -> Result<TransactionInputs, DataStoreError> {
+        let (account, account_seed) = store.get_account(self.account_id)?;
+        if account.is_new() && account_seed.is_none() {
+            return Err(DataStoreError::AccountSeedNotFound(self.account_id));
+        }
+
+        let block_header = store.get_block_header(self.block_ref)?;
+        let kernel_config = store.get_kernel_config(block_header.kernel_config_hash())?;
+
+        // fetch the input notes, and collect the blocks in which they were created; notes created
+        // in the reference block are authenticated against its header directly
+        let mut input_notes = Vec::with_capacity(self.notes.len());
+        let mut note_blocks = BTreeSet::new();
+        for note_id in self.notes {
+            let input_note = store.get_input_note(note_id)?;
+            let block_num = input_note.origin().block_num;
+            if block_num > self.block_ref {
+                return Err(DataStoreError::NoteCreatedAfterReferenceBlock {
+                    note_id,
+                    block_num,
+                    block_ref: self.block_ref,
+                });
+            }
+            if block_num != self.block_ref {
+                note_blocks.insert(block_num);
+            }
+            input_notes.push(input_note);
+        }
+
+        // build the partial chain MMR tracking the blocks in which the notes were created
+        let mut mmr = PartialMmr::from_peaks(store.get_chain_peaks(self.block_ref)?);
+        let mut blocks = Vec::with_capacity(note_blocks.len());
+        for block_num in note_blocks {
+            let header = store.get_block_header(block_num)?;
+            let path = store.get_block_path(block_num, self.block_ref)?;
+            mmr.track(block_num as usize, header.hash(), &path).map_err(|_| {
+                DataStoreError::InvalidBlockPath { block_num, block_ref: self.block_ref }
+            })?;
+            blocks.push(header);
+        }
+        let block_chain = ChainMmr::new(mmr, blocks).map_err(DataStoreError::InvalidChainMmr)?;
+
+        let input_notes =
+            InputNotes::new(input_notes).map_err(DataStoreError::InvalidTransactionInput)?;
+
+        TransactionInputs::new(
+            account,
+            account_seed,
+            block_header,
+            kernel_config,
+            block_chain,
+            input_notes,
+        )
+        .map_err(DataStoreError::InvalidTransactionInput)
+    }
+}
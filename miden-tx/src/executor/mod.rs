@@ -1,22 +1,30 @@
-use alloc::vec::Vec;
+use alloc::{collections::BTreeSet, vec::Vec};
 
 use miden_lib::transaction::{ToTransactionKernelInputs, TransactionKernel};
 use miden_objects::{
-    assembly::ProgramAst,
-    transaction::{TransactionArgs, TransactionInputs, TransactionScript},
-    vm::{Program, StackOutputs},
+    assembly::{Library, ModuleAst, ProgramAst},
+    crypto::merkle::MerkleStore,
+    notes::ScriptRegistry,
+    transaction::{
+        FeeEstimate, FeePayment, InputNotes, TransactionArgs, TransactionInputs, TransactionScript,
+        UnusedAdviceInputs,
+    },
+    vm::{AdviceInputs, Program, StackOutputs},
     Felt, Word, ZERO,
 };
 use vm_processor::ExecutionOptions;
 
 use super::{
-    AccountCode, AccountId, Digest, ExecutedTransaction, NoteId, NoteScript, PreparedTransaction,
-    RecAdviceProvider, ScriptTarget, TransactionCompiler, TransactionExecutorError,
-    TransactionHost,
+    AccountCode, AccountId, AdviceInjectors, BasicAuthenticator, Cache, Digest,
+    ExecutedTransaction, NoteId, NoteScript, PreparedTransaction, RecAdviceProvider, ScriptTarget,
+    TransactionCompiler, TransactionExecutorError, TransactionHost,
 };
 
 mod data;
-pub use data::DataStore;
+pub use data::{DataStore, WitnessStore};
+
+mod inputs_builder;
+pub use inputs_builder::TransactionInputsBuilder;
 
 // TRANSACTION EXECUTOR
 // ================================================================================================
@@ -38,6 +46,12 @@ pub struct TransactionExecutor<D: DataStore> {
     data_store: D,
     compiler: TransactionCompiler,
     exec_options: ExecutionOptions,
+    script_registry: Option<ScriptRegistry>,
+    advice_injectors: AdviceInjectors,
+    authenticator: Option<BasicAuthenticator>,
+    collect_unused_advice: bool,
+    note_cycle_budget: Option<u32>,
+    merge_output_notes: bool,
 }
 
 impl<D: DataStore> TransactionExecutor<D> {
@@ -48,10 +62,106 @@ impl<D: DataStore> TransactionExecutor<D> {
         Self {
             data_store,
             compiler: TransactionCompiler::new(),
-            exec_options: ExecutionOptions::default(),
+            exec_options: default_exec_options(),
+            script_registry: None,
+            advice_injectors: AdviceInjectors::default(),
+            authenticator: None,
+            collect_unused_advice: false,
+            note_cycle_budget: None,
+            merge_output_notes: false,
         }
     }
 
+    /// Sets the application-defined [AdviceInjectors] which are invoked when the corresponding
+    /// events are emitted during transaction execution.
+    ///
+    /// The same advice injectors must be provided to the prover of the executed transactions.
+    pub fn with_advice_injectors(mut self, advice_injectors: AdviceInjectors) -> Self {
+        self.advice_injectors = advice_injectors;
+        self
+    }
+
+    /// Sets the [BasicAuthenticator] which approves the signatures requested by the executed
+    /// transactions and provides the secret keys for them.
+    ///
+    /// The same authenticator must be provided to the prover of the executed transactions.
+    pub fn with_authenticator(mut self, authenticator: BasicAuthenticator) -> Self {
+        self.authenticator = Some(authenticator);
+        self
+    }
+
+    /// Restricts the [TransactionExecutor] to consuming notes with scripts which are contained in
+    /// the specified [ScriptRegistry].
+    ///
+    /// Transactions consuming a note with a script which is not in the registry are rejected
+    /// before being executed.
+    pub fn with_script_registry(mut self, script_registry: ScriptRegistry) -> Self {
+        self.script_registry = Some(script_registry);
+        self
+    }
+
+    /// Makes the procedures of the provided library available to account code, note scripts, and
+    /// transaction scripts compiled by this [TransactionExecutor].
+    ///
+    /// # Errors
+    /// Returns an error if the library could not be added to the underlying
+    /// [TransactionCompiler] (see [TransactionCompiler::with_library()]).
+    pub fn with_library<L>(mut self, library: &L) -> Result<Self, TransactionExecutorError>
+    where
+        L: Library,
+    {
+        self.compiler = self
+            .compiler
+            .with_library(library)
+            .map_err(TransactionExecutorError::LoadLibraryFailed)?;
+        Ok(self)
+    }
+
+    /// Sets the [Cache] in which the interfaces of accounts loaded into this [TransactionExecutor]
+    /// are kept (see [TransactionCompiler::with_account_interface_cache()]).
+    pub fn with_account_interface_cache<C>(mut self, cache: C) -> Self
+    where
+        C: Cache<AccountId, Vec<Digest>> + Send + 'static,
+    {
+        self.compiler = self.compiler.with_account_interface_cache(cache);
+        self
+    }
+
+    /// Enables or disables the collection of unused advice inputs.
+    ///
+    /// When enabled, the executor records which advice map entries provided via the transaction
+    /// arguments and which Merkle store nodes were never requested while executing a transaction,
+    /// and reports them via [ExecutedTransaction::unused_advice()]. This is meant as a diagnostic
+    /// tool for trimming transaction inputs, and incurs additional overhead during execution.
+    pub fn with_unused_advice_collection(mut self, enabled: bool) -> Self {
+        self.collect_unused_advice = enabled;
+        self
+    }
+
+    /// Sets the maximum number of cycles the script of a single input note may consume in the
+    /// transactions executed by this [TransactionExecutor].
+    ///
+    /// The budget is enforced by the kernel, and transactions in which any note script exceeds it
+    /// fail with the `NoteCycleBudgetExceeded` kernel error. If the transaction arguments specify
+    /// a budget as well, the lower of the two budgets applies. A budget of zero is treated as no
+    /// budget.
+    pub fn with_note_cycle_budget(mut self, note_cycle_budget: u32) -> Self {
+        self.note_cycle_budget = (note_cycle_budget != 0).then_some(note_cycle_budget);
+        self
+    }
+
+    /// Enables or disables the merging of output notes in the transactions executed by this
+    /// [TransactionExecutor].
+    ///
+    /// When enabled, notes created with the same metadata and recipient are merged by the kernel
+    /// into a single note carrying the combined assets (see
+    /// [TransactionArgs::set_merge_output_notes()]). Merging can also be enabled for individual
+    /// transactions via their transaction arguments.
+    pub fn with_output_note_merging(mut self, enabled: bool) -> Self {
+        self.merge_output_notes = enabled;
+        self
+    }
+
     /// Puts the [TransactionExecutor] into debug mode.
     ///
     /// When transaction executor is in debug mode, all transaction-related code (note scripts,
@@ -76,6 +186,15 @@ impl<D: DataStore> TransactionExecutor<D> {
         self
     }
 
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the [ScriptRegistry] restricting the notes which can be consumed by this executor,
+    /// if any.
+    pub fn script_registry(&self) -> Option<&ScriptRegistry> {
+        self.script_registry.as_ref()
+    }
+
     // STATE MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -145,6 +264,26 @@ impl<D: DataStore> TransactionExecutor<D> {
             .map_err(TransactionExecutorError::CompileTransactionScriptFailed)
     }
 
+    /// Compiles the provided module into a [TransactionScript] with multiple entrypoints and
+    /// checks (to the extent possible) that each of the entrypoints can be executed against all
+    /// accounts with the specified interfaces.
+    ///
+    /// The entrypoints are the procedures exported by the module, and the entrypoint to execute
+    /// is selected via [TransactionArgs::set_tx_script_entrypoint()].
+    pub fn compile_tx_script_with_entrypoints<T>(
+        &self,
+        tx_script_ast: &ModuleAst,
+        inputs: T,
+        target_account_procs: Vec<ScriptTarget>,
+    ) -> Result<TransactionScript, TransactionExecutorError>
+    where
+        T: IntoIterator<Item = (Word, Vec<Felt>)>,
+    {
+        self.compiler
+            .compile_tx_script_with_entrypoints(tx_script_ast, inputs, target_account_procs)
+            .map_err(TransactionExecutorError::CompileTransactionScriptFailed)
+    }
+
     // TRANSACTION EXECUTION
     // --------------------------------------------------------------------------------------------
 
@@ -157,9 +296,26 @@ impl<D: DataStore> TransactionExecutor<D> {
     ///
     /// # Errors:
     /// Returns an error if:
+    /// - If the same note is specified more than once.
+    /// - If the transaction script has multiple entrypoints, but none of them is selected.
     /// - If required data can not be fetched from the [DataStore].
+    /// - If any of the notes has a script which was instantiated from its MAST root only.
+    /// - If a script registry is set and any of the notes has a script which is not in it.
+    /// - If the input notes violate the note dependencies or note groups declared in the
+    ///   transaction arguments.
+    /// - If the inclusion deadline declared in the transaction arguments is not after the
+    ///   reference block.
+    /// - If the fee is paid in an asset which is not accepted by the kernel config, or the
+    ///   conversion witness of the asset cannot be verified against the reference block.
     /// - If the transaction program can not be compiled.
-    /// - If the transaction program can not be executed.
+    /// - If the transaction program can not be executed, e.g., because a note script exceeded
+    ///   the per-note cycle budget, or the account vault does not hold enough of the fee asset.
+    /// - If any of the post-conditions declared in the transaction arguments is violated by the
+    ///   executed transaction.
+    ///
+    /// If the collection of unused advice inputs is enabled, the returned transaction also
+    /// reports the advice inputs which were never requested during execution. The number of cycles
+    /// consumed by each note script is reported via [ExecutedTransaction::measurements()].
     pub fn execute_transaction(
         &self,
         account_id: AccountId,
@@ -167,11 +323,31 @@ impl<D: DataStore> TransactionExecutor<D> {
         notes: &[NoteId],
         tx_args: TransactionArgs,
     ) -> Result<ExecutedTransaction, TransactionExecutorError> {
+        #[cfg(feature = "tracing")]
+        let span = tracing::info_span!(
+            "execute_transaction",
+            %account_id,
+            block_ref,
+            note_ids = ?notes,
+            cycles = tracing::field::Empty,
+        )
+        .entered();
+
         let transaction = self.prepare_transaction(account_id, block_ref, notes, tx_args)?;
 
         let (stack_inputs, advice_inputs) = transaction.get_kernel_inputs();
+        let provided_advice = self
+            .collect_unused_advice
+            .then(|| ProvidedAdvice::new(transaction.tx_args(), &advice_inputs));
         let advice_recorder: RecAdviceProvider = advice_inputs.into();
-        let mut host = TransactionHost::new(transaction.account().into(), advice_recorder);
+        let mut host = TransactionHost::with_advice_injectors(
+            transaction.account().into(),
+            advice_recorder,
+            self.advice_injectors.clone(),
+        );
+        if let Some(authenticator) = &self.authenticator {
+            host = host.with_authenticator(authenticator.clone());
+        }
 
         let result = vm_processor::execute(
             transaction.program(),
@@ -179,17 +355,74 @@ impl<D: DataStore> TransactionExecutor<D> {
             &mut host,
             self.exec_options,
         )
-        .map_err(TransactionExecutorError::ExecuteTransactionProgramFailed)?;
+        .map_err(|error| {
+            let note_index = host.current_note().and_then(|note_id| {
+                transaction.input_notes().iter().position(|note| note.id() == note_id)
+            });
+            TransactionExecutorError::execution_failure(error, note_index)
+        })?;
+
+        #[cfg(feature = "tracing")]
+        span.record("cycles", result.trace_len_summary().main_trace_len());
 
         let (tx_program, tx_inputs, tx_args) = transaction.into_parts();
 
-        build_executed_transaction(
+        let executed_tx = build_executed_transaction(
             tx_program,
             tx_args,
             tx_inputs,
             result.stack_outputs().clone(),
             host,
-        )
+        )?;
+
+        // reject the transaction before it is proven if its effects are not the expected ones
+        if let Some(post_condition) = executed_tx
+            .tx_args()
+            .post_conditions()
+            .iter()
+            .find(|post_condition| !post_condition.is_satisfied_by(&executed_tx))
+        {
+            return Err(TransactionExecutorError::PostConditionViolated {
+                post_condition: *post_condition,
+            });
+        }
+
+        Ok(match provided_advice {
+            Some(provided_advice) => {
+                let unused_advice = provided_advice.into_unused(executed_tx.advice_witness());
+                executed_tx.with_unused_advice(unused_advice)
+            },
+            None => executed_tx,
+        })
+    }
+
+    /// Estimates the fee of a transaction which executes the provided notes and an optional tx
+    /// script against the specified account.
+    ///
+    /// The transaction is executed in order to determine the number of notes it creates, but no
+    /// proof is generated for it. The fee is computed under the fee parameters of the kernel
+    /// config in effect for the reference block, and includes the priority fee declared in the
+    /// inclusion hints of the transaction arguments. The required fee is also deducted from the
+    /// account vault by the executed transaction, so the account must be able to pay it.
+    ///
+    /// # Errors:
+    /// Returns an error if the transaction could not be executed (see
+    /// [TransactionExecutor::execute_transaction()]).
+    pub fn estimate_fee(
+        &self,
+        account_id: AccountId,
+        block_ref: u32,
+        notes: &[NoteId],
+        tx_args: TransactionArgs,
+    ) -> Result<FeeEstimate, TransactionExecutorError> {
+        let executed_tx = self.execute_transaction(account_id, block_ref, notes, tx_args)?;
+
+        Ok(FeeEstimate::new(
+            executed_tx.tx_inputs().kernel_config(),
+            executed_tx.input_notes().num_notes(),
+            executed_tx.output_notes().num_notes(),
+            executed_tx.tx_args().inclusion_hints().priority_fee(),
+        ))
     }
 
     // HELPER METHODS
@@ -201,20 +434,107 @@ impl<D: DataStore> TransactionExecutor<D> {
     ///
     /// # Errors:
     /// Returns an error if:
+    /// - If the same note is specified more than once.
+    /// - If the transaction script has multiple entrypoints, but none of them is selected.
     /// - If required data can not be fetched from the [DataStore].
+    /// - If any of the notes has a script which was instantiated from its MAST root only.
+    /// - If a script registry is set and any of the notes has a script which is not in it.
+    /// - If the input notes violate the note dependencies or note groups declared in the
+    ///   transaction arguments.
+    /// - If the inclusion deadline declared in the transaction arguments is not after the
+    ///   reference block.
+    /// - If the fee is paid in an asset which is not accepted by the kernel config, or the
+    ///   conversion witness of the asset cannot be verified against the reference block.
     /// - If the transaction can not be compiled.
     fn prepare_transaction(
         &self,
         account_id: AccountId,
         block_ref: u32,
         notes: &[NoteId],
-        tx_args: TransactionArgs,
+        mut tx_args: TransactionArgs,
     ) -> Result<PreparedTransaction, TransactionExecutorError> {
+        // the budget of the executor caps the budget requested by the transaction arguments
+        if let Some(budget) = self.note_cycle_budget {
+            let budget = tx_args.note_cycle_budget().map_or(budget, |args| args.min(budget));
+            tx_args.set_note_cycle_budget(budget);
+        }
+
+        if self.merge_output_notes {
+            tx_args.set_merge_output_notes(true);
+        }
+
+        // a note consumed more than once would be rejected by the kernel; fail early with a
+        // dedicated error instead
+        let mut unique_notes = BTreeSet::new();
+        if let Some(note_id) = notes.iter().find(|note_id| !unique_notes.insert(**note_id)) {
+            return Err(TransactionExecutorError::DuplicateInputNote { note_id: *note_id });
+        }
+
+        if let Some(tx_script) = tx_args.tx_script() {
+            if !tx_script.entrypoints().is_empty() && tx_args.tx_script_entrypoint().is_none() {
+                return Err(TransactionExecutorError::MissingTxScriptEntrypoint);
+            }
+        }
+
         let tx_inputs = self
             .data_store
             .get_transaction_inputs(account_id, block_ref, notes)
             .map_err(TransactionExecutorError::FetchTransactionInputsFailed)?;
 
+        // notes whose scripts were instantiated from their MAST roots only cannot be executed
+        if let Some(input_note) =
+            tx_inputs.input_notes().iter().find(|note| note.note().script().is_root_only())
+        {
+            return Err(TransactionExecutorError::NoteScriptCodeMissing {
+                note_id: input_note.id(),
+                script_root: input_note.note().script().hash(),
+            });
+        }
+
+        if let Some(script_registry) = &self.script_registry {
+            for input_note in tx_inputs.input_notes().iter() {
+                let script_root = input_note.note().script().hash();
+                if !script_registry.contains(&script_root) {
+                    return Err(TransactionExecutorError::UnknownNoteScript {
+                        note_id: input_note.id(),
+                        script_root,
+                    });
+                }
+            }
+        }
+
+        validate_note_dependencies(tx_inputs.input_notes(), &tx_args)?;
+
+        // the transaction can be included at the earliest into the block following the reference
+        // block
+        if let Some(deadline) = tx_args.inclusion_hints().deadline() {
+            if deadline <= block_ref {
+                return Err(TransactionExecutorError::InclusionDeadlineExpired {
+                    deadline,
+                    block_ref,
+                });
+            }
+        }
+
+        // a fee paid in an asset which is not accepted, or with a conversion rate which cannot be
+        // verified against the reference block, would be rejected by the kernel; fail early with
+        // a dedicated error instead
+        if let FeePayment::Converted { faucet_id, witness } = tx_args.fee_payment() {
+            let (_, accepted_asset) =
+                tx_inputs.kernel_config().fees().find_accepted_asset(*faucet_id).ok_or(
+                    TransactionExecutorError::UnacceptedFeeAsset { faucet_id: *faucet_id },
+                )?;
+
+            if !witness.verify(accepted_asset, tx_inputs.block_header().account_root()) {
+                return Err(TransactionExecutorError::InvalidFeeConversionWitness {
+                    faucet_id: *faucet_id,
+                });
+            }
+        }
+
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!("compile_transaction", %account_id).entered();
+
         let tx_program = self
             .compiler
             .compile_transaction(
@@ -228,9 +548,105 @@ impl<D: DataStore> TransactionExecutor<D> {
     }
 }
 
+// PROVIDED ADVICE
+// ================================================================================================
+
+/// Advice inputs provided to the VM before executing a transaction.
+///
+/// Used to determine which of the provided advice inputs were never requested during execution.
+struct ProvidedAdvice {
+    map_keys: BTreeSet<Digest>,
+    merkle_nodes: BTreeSet<Digest>,
+}
+
+impl ProvidedAdvice {
+    /// Records the keys of the advice map entries provided via the transaction arguments, and the
+    /// inner nodes of the Merkle store of the kernel advice inputs.
+    ///
+    /// Nodes of empty subtrees are always present in a Merkle store, and thus are not recorded.
+    fn new(tx_args: &TransactionArgs, advice_inputs: &AdviceInputs) -> Self {
+        let map_keys = tx_args.advice_map().clone().into_iter().map(|(key, _)| key).collect();
+
+        let empty_store: MerkleStore = MerkleStore::new();
+        let empty_nodes = empty_store.inner_nodes().map(|node| node.value).collect::<BTreeSet<_>>();
+        let merkle_nodes = advice_inputs
+            .merkle_store()
+            .inner_nodes()
+            .map(|node| node.value)
+            .filter(|node| !empty_nodes.contains(node))
+            .collect();
+
+        Self { map_keys, merkle_nodes }
+    }
+
+    /// Returns the provided advice inputs which are not contained in the specified advice
+    /// witness, i.e., which were never requested during execution.
+    fn into_unused(self, advice_witness: &AdviceInputs) -> UnusedAdviceInputs {
+        let map_keys = self
+            .map_keys
+            .into_iter()
+            .filter(|key| advice_witness.mapped_values(key).is_none())
+            .collect();
+
+        let requested_nodes = advice_witness
+            .merkle_store()
+            .inner_nodes()
+            .map(|node| node.value)
+            .collect::<BTreeSet<_>>();
+        let merkle_nodes = self.merkle_nodes.difference(&requested_nodes).copied().collect();
+
+        UnusedAdviceInputs::new(map_keys, merkle_nodes)
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
+/// Returns the default execution options of a [TransactionExecutor].
+///
+/// Tracing is enabled in the execution options, so that the host can count the cycles consumed by
+/// every note script. If the `tracing` feature is enabled, the host also enters a span for every
+/// stage of the transaction kernel.
+fn default_exec_options() -> ExecutionOptions {
+    ExecutionOptions::default().with_tracing()
+}
+
+/// Checks that the input notes satisfy the note dependencies and note groups declared in the
+/// provided transaction arguments.
+///
+/// The kernel processes input notes in the order in which they are committed to, and the
+/// transaction fails if any of the note scripts fails. Thus, a note is processed only if all of
+/// its dependencies precede it in the input notes, and all notes of a group are consumed together
+/// if they are all among the input notes.
+fn validate_note_dependencies(
+    input_notes: &InputNotes,
+    tx_args: &TransactionArgs,
+) -> Result<(), TransactionExecutorError> {
+    let mut processed_notes = BTreeSet::new();
+    for input_note in input_notes.iter() {
+        let note_id = input_note.id();
+        if let Some(&dependency) =
+            tx_args.note_dependencies(note_id).find(|id| !processed_notes.contains(*id))
+        {
+            return Err(TransactionExecutorError::UnsatisfiedNoteDependency {
+                note_id,
+                dependency,
+            });
+        }
+        processed_notes.insert(note_id);
+    }
+
+    for group in tx_args.note_groups() {
+        if group.iter().any(|id| processed_notes.contains(id)) {
+            if let Some(&missing_note_id) = group.iter().find(|id| !processed_notes.contains(*id)) {
+                return Err(TransactionExecutorError::IncompleteNoteGroup { missing_note_id });
+            }
+        }
+    }
+
+    Ok(())
+}
+
 /// Creates a new [ExecutedTransaction] from the provided data.
 fn build_executed_transaction(
     program: Program,
@@ -239,13 +655,19 @@ fn build_executed_transaction(
     stack_outputs: StackOutputs,
     host: TransactionHost<RecAdviceProvider>,
 ) -> Result<ExecutedTransaction, TransactionExecutorError> {
-    let (advice_recorder, account_delta, output_notes) = host.into_parts();
+    let measurements = host.measurements();
+    let access_set = host.access_set();
+    let (advice_recorder, account_delta, output_notes, log_entries) = host.into_parts();
 
     let (advice_witness, _, map, _store) = advice_recorder.finalize();
 
-    let tx_outputs =
-        TransactionKernel::from_transaction_parts(&stack_outputs, &map.into(), output_notes)
-            .map_err(TransactionExecutorError::InvalidTransactionOutput)?;
+    let tx_outputs = TransactionKernel::from_transaction_parts(
+        &stack_outputs,
+        &map.into(),
+        output_notes,
+        log_entries,
+    )
+    .map_err(TransactionExecutorError::InvalidTransactionOutput)?;
     let final_account = &tx_outputs.account;
 
     let initial_account = tx_inputs.account();
@@ -280,5 +702,7 @@ fn build_executed_transaction(
         account_delta,
         tx_args,
         advice_witness,
-    ))
+    )
+    .with_measurements(measurements)
+    .with_access_set(access_set))
 }
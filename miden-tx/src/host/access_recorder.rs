@@ -0,0 +1,82 @@
+use alloc::collections::BTreeSet;
+
+use miden_lib::transaction::memory::{ACCT_STORAGE_ROOT_PTR, ACCT_VAULT_ROOT_PTR};
+use miden_objects::{accounts::AccountStorage, transaction::AccessSet, Digest, Word};
+
+use super::{AdviceInjector, ContextId, ProcessState};
+
+// ACCESS RECORDER
+// ================================================================================================
+
+/// Records the account storage slots and vault keys accessed while executing a transaction, based
+/// on the advice requested by the kernel.
+///
+/// The kernel reads and updates storage slots by requesting the Merkle paths to the slots in the
+/// storage tree, and assets by requesting the leaves of the vault Sparse Merkle Tree. A request is
+/// attributed to the account only if it is made against the current storage or vault root of the
+/// account, as stored in the kernel memory.
+#[derive(Default)]
+pub struct AccessRecorder {
+    storage_slots: BTreeSet<u8>,
+    vault_keys: BTreeSet<Digest>,
+}
+
+impl AccessRecorder {
+    /// Records the storage slot or vault key accessed by the specified advice request, if any.
+    pub fn on_advice_injector<S: ProcessState>(&mut self, process: &S, injector: &AdviceInjector) {
+        match injector {
+            // Expected stack state: [depth, index, ROOT, ...]
+            AdviceInjector::MerkleNodeToStack | AdviceInjector::UpdateMerkleNode => {
+                let depth = process.get_stack_item(0).as_int();
+                let index = process.get_stack_item(1).as_int();
+                let root = [
+                    process.get_stack_item(5),
+                    process.get_stack_item(4),
+                    process.get_stack_item(3),
+                    process.get_stack_item(2),
+                ];
+
+                // array items are read via the slot of the array first, and so array roots can
+                // be ignored here
+                if depth == AccountStorage::STORAGE_TREE_DEPTH as u64
+                    && is_memory_word(process, ACCT_STORAGE_ROOT_PTR, root)
+                {
+                    self.storage_slots.insert(index as u8);
+                }
+            },
+            // Expected stack state: [KEY, ROOT, ...]
+            AdviceInjector::SmtGet | AdviceInjector::SmtPeek => {
+                self.on_vault_request(process, process.get_stack_word(0), process.get_stack_word(1))
+            },
+            // Expected stack state: [VALUE, KEY, ROOT, ...]
+            AdviceInjector::SmtSet => {
+                self.on_vault_request(process, process.get_stack_word(1), process.get_stack_word(2))
+            },
+            _ => (),
+        }
+    }
+
+    /// Returns the storage slots and vault keys accessed so far.
+    pub fn access_set(&self) -> AccessSet {
+        AccessSet::new(self.storage_slots.clone(), self.vault_keys.clone())
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Records the provided key if the request is made against the vault root of the account.
+    fn on_vault_request<S: ProcessState>(&mut self, process: &S, key: Word, root: Word) {
+        if is_memory_word(process, ACCT_VAULT_ROOT_PTR, root) {
+            self.vault_keys.insert(key.into());
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns true if the word stored at the specified address of the root context memory is equal
+/// to the provided word.
+fn is_memory_word<S: ProcessState>(process: &S, addr: u32, word: Word) -> bool {
+    process.get_mem_value(ContextId::root(), addr) == Some(word)
+}
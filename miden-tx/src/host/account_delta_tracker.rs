@@ -75,9 +75,12 @@ impl AccountDeltaTracker {
 ///
 /// The delta tracker is composed of:
 /// - A map which records the latest states for the updated storage slots.
+/// - A map which records the latest states for the updated items of storage arrays, keyed by
+///   (slot index, item index).
 #[derive(Default, Debug, Clone, PartialEq, Eq)]
 pub struct AccountStorageDeltaTracker {
     slot_updates: BTreeMap<u8, Word>,
+    array_item_updates: BTreeMap<(u8, u64), Word>,
 }
 
 impl AccountStorageDeltaTracker {
@@ -95,13 +98,28 @@ impl AccountStorageDeltaTracker {
             }
         }
 
-        AccountStorageDelta { cleared_items, updated_items }
+        let updated_array_items = self
+            .array_item_updates
+            .into_iter()
+            .map(|((slot_idx, item_idx), value)| (slot_idx, item_idx, value))
+            .collect();
+
+        AccountStorageDelta {
+            cleared_items,
+            updated_items,
+            updated_array_items,
+        }
     }
 
     /// Tracks a slot change
     pub fn slot_update(&mut self, slot_index: u8, new_slot_value: [Felt; 4]) {
         self.slot_updates.insert(slot_index, new_slot_value);
     }
+
+    /// Tracks a change of an item of a storage array
+    pub fn array_item_update(&mut self, slot_index: u8, item_index: u64, new_value: [Felt; 4]) {
+        self.array_item_updates.insert((slot_index, item_index), new_value);
+    }
 }
 
 // ACCOUNT VAULT DELTA TRACKER
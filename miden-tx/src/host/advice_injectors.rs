@@ -0,0 +1,74 @@
+use alloc::{collections::BTreeMap, string::String, sync::Arc, vec::Vec};
+
+use miden_lib::transaction::TransactionEvent;
+use vm_processor::Felt;
+
+use crate::AdviceInjectorError;
+
+// ADVICE INJECTORS
+// ================================================================================================
+
+/// A callback which provides advice to a transaction in response to an application-defined event.
+///
+/// The callback receives the state of the operand stack at the time the event was emitted, and
+/// returns the values to be pushed onto the advice stack. The values are pushed such that the
+/// first returned value ends up at the top of the advice stack.
+pub type AdviceInjectorFn = Arc<dyn Fn(&[Felt]) -> Result<Vec<Felt>, String> + Send + Sync>;
+
+/// A set of application-defined advice injectors keyed by event ID.
+///
+/// Advice injectors allow scripts executed within a transaction to request custom advice (e.g.,
+/// oracle data or off-chain Merkle paths) via the `emit.<event_id>` instruction. Event IDs
+/// reserved by the transaction kernel (i.e., IDs with the upper 16 bits set to
+/// [TransactionEvent::EVENT_ID_PREFIX]) cannot be used for advice injectors.
+///
+/// Since advice injected during transaction execution is not recorded in the transaction witness,
+/// the same advice injectors must also be provided to the transaction prover.
+#[derive(Clone, Default)]
+pub struct AdviceInjectors {
+    injectors: BTreeMap<u32, AdviceInjectorFn>,
+}
+
+impl AdviceInjectors {
+    /// Returns a new empty set of [AdviceInjectors].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the provided advice injector to be invoked whenever an event with the specified
+    /// ID is emitted.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The event ID is reserved by the transaction kernel.
+    /// - An advice injector has already been registered for the event ID.
+    pub fn register<F>(&mut self, event_id: u32, injector: F) -> Result<(), AdviceInjectorError>
+    where
+        F: Fn(&[Felt]) -> Result<Vec<Felt>, String> + Send + Sync + 'static,
+    {
+        if event_id >> 16 == TransactionEvent::EVENT_ID_PREFIX as u32 {
+            return Err(AdviceInjectorError::ReservedEventId(event_id));
+        }
+        if self.injectors.contains_key(&event_id) {
+            return Err(AdviceInjectorError::DuplicateEventId(event_id));
+        }
+
+        self.injectors.insert(event_id, Arc::new(injector));
+        Ok(())
+    }
+
+    /// Returns the advice injector registered for the specified event ID, if any.
+    pub fn get(&self, event_id: u32) -> Option<&AdviceInjectorFn> {
+        self.injectors.get(&event_id)
+    }
+
+    /// Returns the number of registered advice injectors.
+    pub fn num_injectors(&self) -> usize {
+        self.injectors.len()
+    }
+
+    /// Returns true if no advice injectors have been registered.
+    pub fn is_empty(&self) -> bool {
+        self.injectors.is_empty()
+    }
+}
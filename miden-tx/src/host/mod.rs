@@ -4,13 +4,10 @@ use miden_lib::transaction::{
     memory::ACCT_STORAGE_ROOT_PTR, TransactionEvent, TransactionKernelError,
 };
 use miden_objects::{
-    accounts::{AccountDelta, AccountId, AccountStorage, AccountStub},
+    accounts::{AccountDelta, AccountStorage, AccountStub},
     assets::Asset,
-    notes::{
-        Note, NoteAssets, NoteEnvelope, NoteId, NoteInputs, NoteMetadata, NoteRecipient,
-        NoteScript, NoteTag, NoteType,
-    },
-    transaction::OutputNote,
+    notes::{NoteAssets, NoteId, NoteInputs, NoteMetadata, NoteRecipient, NoteScript, NoteType},
+    transaction::{AccessSet, OutputNote, TransactionMeasurements, TxLogEntry},
     Digest,
 };
 use vm_processor::{
@@ -18,12 +15,31 @@ use vm_processor::{
     ExecutionError, Felt, Host, HostResponse, ProcessState,
 };
 
+use crate::auth::{BasicAuthenticator, SignatureRequest};
+
+mod access_recorder;
+use access_recorder::AccessRecorder;
+
 mod account_delta_tracker;
 use account_delta_tracker::AccountDeltaTracker;
 
 mod account_procs;
 use account_procs::AccountProcedureIndexMap;
 
+mod advice_injectors;
+pub use advice_injectors::{AdviceInjectorFn, AdviceInjectors};
+
+mod note_builder;
+use note_builder::OutputNoteBuilder;
+
+mod note_cycle_counter;
+use note_cycle_counter::NoteCycleCounter;
+
+#[cfg(feature = "tracing")]
+mod stage_tracer;
+#[cfg(feature = "tracing")]
+use stage_tracer::KernelStageTracer;
+
 // CONSTANTS
 // ================================================================================================
 
@@ -44,30 +60,112 @@ pub struct TransactionHost<A> {
     /// A map for the account's procedures.
     acct_procedure_index_map: AccountProcedureIndexMap,
 
-    /// The list of notes created while executing a transaction.
-    output_notes: Vec<OutputNote>,
+    /// The list of notes created while executing a transaction, in the order of their creation.
+    output_notes: Vec<OutputNoteBuilder>,
+
+    /// A map from the memory pointers at which the kernel stores the created notes to the indexes
+    /// of the notes in the list of created notes.
+    output_note_ptrs: BTreeMap<u64, usize>,
+
+    /// The list of log entries emitted while executing a transaction.
+    log_entries: Vec<TxLogEntry>,
+
+    /// Application-defined advice injectors which are invoked on events not handled by the
+    /// transaction kernel.
+    advice_injectors: AdviceInjectors,
+
+    /// Counts the cycles consumed by the script of every input note.
+    note_cycle_counter: NoteCycleCounter,
+
+    /// Records the account storage slots and vault keys accessed by the transaction.
+    access_recorder: AccessRecorder,
+
+    /// Authenticator which provides the secret keys for the signatures requested by the
+    /// transaction, if any.
+    authenticator: Option<BasicAuthenticator>,
+
+    /// Enters a span for every stage of the transaction kernel.
+    #[cfg(feature = "tracing")]
+    stage_tracer: KernelStageTracer,
 }
 
 impl<A: AdviceProvider> TransactionHost<A> {
     /// Returns a new [TransactionHost] instance with the provided [AdviceProvider].
     pub fn new(account: AccountStub, adv_provider: A) -> Self {
+        Self::with_advice_injectors(account, adv_provider, AdviceInjectors::default())
+    }
+
+    /// Returns a new [TransactionHost] instance with the provided [AdviceProvider] and
+    /// application-defined [AdviceInjectors].
+    pub fn with_advice_injectors(
+        account: AccountStub,
+        adv_provider: A,
+        advice_injectors: AdviceInjectors,
+    ) -> Self {
         let proc_index_map = AccountProcedureIndexMap::new(account.code_root(), &adv_provider);
         Self {
             adv_provider,
             account_delta: AccountDeltaTracker::new(&account),
             acct_procedure_index_map: proc_index_map,
             output_notes: Vec::new(),
+            output_note_ptrs: BTreeMap::new(),
+            log_entries: Vec::new(),
+            advice_injectors,
+            note_cycle_counter: NoteCycleCounter::default(),
+            access_recorder: AccessRecorder::default(),
+            authenticator: None,
+            #[cfg(feature = "tracing")]
+            stage_tracer: KernelStageTracer::default(),
         }
     }
 
-    /// Consumes `self` and returns the advice provider and account vault delta.
-    pub fn into_parts(self) -> (A, AccountDelta, Vec<OutputNote>) {
-        (self.adv_provider, self.account_delta.into_delta(), self.output_notes)
+    /// Sets the [BasicAuthenticator] which approves the signatures requested by the transaction
+    /// and provides the secret keys for them.
+    pub fn with_authenticator(mut self, authenticator: BasicAuthenticator) -> Self {
+        self.authenticator = Some(authenticator);
+        self
+    }
+
+    /// Consumes `self` and returns the advice provider, account vault delta, output notes and
+    /// transaction log entries.
+    pub fn into_parts(self) -> (A, AccountDelta, Vec<OutputNote>, Vec<TxLogEntry>) {
+        (
+            self.adv_provider,
+            self.account_delta.into_delta(),
+            self.output_notes.into_iter().map(OutputNoteBuilder::build).collect(),
+            self.log_entries,
+        )
+    }
+
+    /// Returns the number of cycles consumed by the script of every input note processed so far.
+    ///
+    /// The cycles are counted only if the traces emitted by the kernel are enabled in the options
+    /// of the executing VM.
+    pub fn measurements(&self) -> TransactionMeasurements {
+        self.note_cycle_counter.measurements()
+    }
+
+    /// Returns the ID of the input note which is currently being processed by the kernel, if any.
+    ///
+    /// The note is known only if the traces emitted by the kernel are enabled in the options of
+    /// the executing VM.
+    pub fn current_note(&self) -> Option<NoteId> {
+        self.note_cycle_counter.processed_note()
+    }
+
+    /// Returns the account storage slots and vault keys accessed by the transaction so far.
+    pub fn access_set(&self) -> AccessSet {
+        self.access_recorder.access_set()
     }
 
     // EVENT HANDLERS
     // --------------------------------------------------------------------------------------------
 
+    /// Extracts the data of the created note from the process state, and starts building the
+    /// note. The note is validated immediately, so that malformed notes are rejected at the point
+    /// of their creation.
+    ///
+    /// Expected stack state: [aux, note_type, sender_acct_id, tag, note_ptr, ASSET, RECIPIENT, ...]
     fn on_note_created<S: ProcessState>(
         &mut self,
         process: &S,
@@ -76,23 +174,20 @@ impl<A: AdviceProvider> TransactionHost<A> {
 
         // Stack:
         // # => [aux, note_type, sender_acct_id, tag, note_ptr, ASSET, RECIPIENT]
-        let aux = stack[0];
-        let note_type =
-            NoteType::try_from(stack[1]).map_err(TransactionKernelError::MalformedNoteType)?;
-        let sender =
-            AccountId::try_from(stack[2]).map_err(TransactionKernelError::MalformedAccountId)?;
-        let tag = NoteTag::try_from(stack[3])
-            .map_err(|_| TransactionKernelError::MalformedTag(stack[3]))?;
+        let metadata = NoteMetadata::try_from([stack[3], stack[2], stack[1], stack[0]])
+            .map_err(TransactionKernelError::MalformedNoteMetadata)?;
         let asset = Asset::try_from([stack[8], stack[7], stack[6], stack[5]])
             .map_err(TransactionKernelError::MalformedAsset)?;
+        let note_ptr = stack[4];
         let recipient = Digest::new([stack[12], stack[11], stack[10], stack[9]]);
         let vault =
             NoteAssets::new(vec![asset]).map_err(TransactionKernelError::MalformedNoteType)?;
 
-        let metadata = NoteMetadata::new(sender, note_type, tag, aux)
-            .map_err(TransactionKernelError::MalformedNoteMetadata)?;
+        if self.output_note_ptrs.contains_key(&note_ptr.as_int()) {
+            return Err(TransactionKernelError::DuplicateCreatedNotePtr(note_ptr));
+        }
 
-        let note = if metadata.note_type() == NoteType::Public {
+        let recipient_details = if metadata.note_type() == NoteType::Public {
             let data = self.adv_provider.get_mapped_values(&recipient).ok_or(
                 TransactionKernelError::MissingNoteDetails(metadata, vault.clone(), recipient),
             )?;
@@ -111,17 +206,48 @@ impl<A: AdviceProvider> TransactionHost<A> {
 
             let script = NoteScript::try_from(script_data)
                 .map_err(|_| TransactionKernelError::MalformedNoteScript(script_data.to_vec()))?;
-            let recipient = NoteRecipient::new(serial_num, script, inputs);
-            OutputNote::Public(Note::new(vault, metadata, recipient))
+            Some(NoteRecipient::new(serial_num, script, inputs))
         } else {
-            let note_id = NoteId::new(recipient, vault.commitment());
-            OutputNote::Private(
-                NoteEnvelope::new(note_id, metadata).expect("NoteType checked above"),
-            )
+            None
         };
 
-        self.output_notes.push(note);
+        let note_builder = OutputNoteBuilder::new(metadata, recipient, recipient_details, vault)?;
+        self.output_note_ptrs.insert(note_ptr.as_int(), self.output_notes.len());
+        self.output_notes.push(note_builder);
+
+        Ok(())
+    }
+
+    /// Extracts the asset and the pointer to the note from the process state and adds the asset to
+    /// the corresponding output note.
+    ///
+    /// Expected stack state: [ASSET, note_ptr, ...]
+    fn on_note_asset_added<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<(), TransactionKernelError> {
+        let stack = process.get_stack_state();
+
+        let asset = Asset::try_from([stack[3], stack[2], stack[1], stack[0]])
+            .map_err(TransactionKernelError::MalformedAsset)?;
+        let note_ptr = stack[4];
+        let note_idx = *self
+            .output_note_ptrs
+            .get(&note_ptr.as_int())
+            .ok_or(TransactionKernelError::InvalidCreatedNotePtr(note_ptr))?;
+
+        self.output_notes[note_idx].add_asset(asset)
+    }
 
+    /// Extracts the log entry from the process state and appends it to the list of log entries
+    /// emitted by the transaction.
+    ///
+    /// Expected stack state: [ENTRY, ...]
+    fn on_tx_log_entry<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<(), TransactionKernelError> {
+        self.log_entries.push(TxLogEntry::new(process.get_stack_word(0)));
         Ok(())
     }
 
@@ -193,6 +319,38 @@ impl<A: AdviceProvider> TransactionHost<A> {
         Ok(())
     }
 
+    /// Extracts information about the array item being updated from the process state and records
+    /// the update in the storage delta tracker.
+    ///
+    /// Expected stack state: [slot_index, item_index, V', ...]
+    pub fn on_account_storage_set_array_item<S: ProcessState>(
+        &mut self,
+        process: &S,
+    ) -> Result<(), TransactionKernelError> {
+        // get slot index from the stack and make sure it is valid
+        let slot_index = process.get_stack_item(0);
+        if slot_index.as_int() as usize >= AccountStorage::NUM_STORAGE_SLOTS {
+            return Err(TransactionKernelError::InvalidStorageSlotIndex(slot_index.as_int()));
+        }
+
+        // get the index of the array item and the value to which it is being updated
+        let item_index = process.get_stack_item(1);
+        let new_item_value = [
+            process.get_stack_item(5),
+            process.get_stack_item(4),
+            process.get_stack_item(3),
+            process.get_stack_item(2),
+        ];
+
+        self.account_delta.storage_tracker().array_item_update(
+            slot_index.as_int() as u8,
+            item_index.as_int(),
+            new_item_value,
+        );
+
+        Ok(())
+    }
+
     // ACCOUNT VAULT UPDATE HANDLERS
     // --------------------------------------------------------------------------------------------
 
@@ -225,6 +383,62 @@ impl<A: AdviceProvider> TransactionHost<A> {
         self.account_delta.vault_tracker().remove_asset(asset);
         Ok(())
     }
+
+    // ADVICE INJECTOR HANDLERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Invokes the provided advice injector with the current state of the operand stack and pushes
+    /// the returned values onto the advice stack.
+    fn on_advice_injector_event<S: ProcessState>(
+        &mut self,
+        process: &S,
+        event_id: u32,
+        injector: &AdviceInjectorFn,
+    ) -> Result<(), ExecutionError> {
+        let values = injector(&process.get_stack_state()).map_err(|err| {
+            ExecutionError::EventError(format!(
+                "advice injector for event {event_id} failed: {err}"
+            ))
+        })?;
+
+        for value in values.into_iter().rev() {
+            self.adv_provider.push_stack(AdviceSource::Value(value))?;
+        }
+
+        Ok(())
+    }
+
+    /// Passes the signature request to the authenticator, and inserts the requested secret key
+    /// into the advice map if the authenticator holds it.
+    ///
+    /// Expected stack state: [PUB_KEY, MESSAGE, ...]
+    fn on_signature_request<S: ProcessState>(&mut self, process: &S) -> Result<(), ExecutionError> {
+        let Some(authenticator) = &self.authenticator else {
+            return Ok(());
+        };
+
+        let output_notes = self
+            .output_notes
+            .iter()
+            .cloned()
+            .map(OutputNoteBuilder::build)
+            .collect::<Vec<_>>();
+        let request = SignatureRequest::new(
+            process.get_stack_word(0),
+            process.get_stack_word(1),
+            self.account_delta.clone().into_delta(),
+            &output_notes,
+        );
+
+        let key_data = authenticator.authorize(&request).map_err(|err| {
+            ExecutionError::EventError(format!("signature request denied: {err}"))
+        })?;
+        if let Some(key_data) = key_data {
+            self.adv_provider.insert_into_map(request.pub_key(), key_data.to_vec())?;
+        }
+
+        Ok(())
+    }
 }
 
 impl<A: AdviceProvider> Host for TransactionHost<A> {
@@ -241,14 +455,38 @@ impl<A: AdviceProvider> Host for TransactionHost<A> {
         process: &S,
         injector: AdviceInjector,
     ) -> Result<HostResponse, ExecutionError> {
+        #[cfg(feature = "tracing")]
+        let _span = matches!(injector, AdviceInjector::SigToStack { .. })
+            .then(|| tracing::info_span!("signature_request", cycle = process.clk()).entered());
+
+        self.access_recorder.on_advice_injector(process, &injector);
+        if matches!(injector, AdviceInjector::SigToStack { .. }) {
+            self.on_signature_request(process)?;
+        }
         self.adv_provider.set_advice(process, &injector)
     }
 
+    fn on_trace<S: ProcessState>(
+        &mut self,
+        process: &S,
+        trace_id: u32,
+    ) -> Result<HostResponse, ExecutionError> {
+        self.note_cycle_counter.on_trace(process, trace_id);
+        #[cfg(feature = "tracing")]
+        self.stage_tracer.on_trace(process, trace_id);
+        Ok(HostResponse::None)
+    }
+
     fn on_event<S: ProcessState>(
         &mut self,
         process: &S,
         event_id: u32,
     ) -> Result<HostResponse, ExecutionError> {
+        if let Some(injector) = self.advice_injectors.get(event_id).cloned() {
+            self.on_advice_injector_event(process, event_id, &injector)?;
+            return Ok(HostResponse::None);
+        }
+
         let event = TransactionEvent::try_from(event_id)
             .map_err(|err| ExecutionError::EventError(err.to_string()))?;
 
@@ -269,6 +507,11 @@ impl<A: AdviceProvider> Host for TransactionHost<A> {
                 self.on_account_push_procedure_index(process)
             },
             TransactionEvent::NoteCreated => self.on_note_created(process),
+            TransactionEvent::AccountStorageSetArrayItem => {
+                self.on_account_storage_set_array_item(process)
+            },
+            TransactionEvent::TxLogEntry => self.on_tx_log_entry(process),
+            TransactionEvent::NoteAssetAdded => self.on_note_asset_added(process),
         }
         .map_err(|err| ExecutionError::EventError(err.to_string()))?;
 
@@ -0,0 +1,105 @@
+use alloc::vec::Vec;
+
+use miden_lib::transaction::TransactionKernelError;
+use miden_objects::{
+    assets::Asset,
+    notes::{Note, NoteAssets, NoteEnvelope, NoteId, NoteMetadata, NoteRecipient, NoteType},
+    transaction::OutputNote,
+    Digest, NoteError,
+};
+
+// OUTPUT NOTE BUILDER
+// ================================================================================================
+
+/// Keeps track of the data of a note created during transaction execution.
+///
+/// A note is created with a single asset, and additional assets can be added to the note until
+/// the end of the transaction, either explicitly or by merging notes created with the same
+/// metadata and recipient into it. The data of the note is validated as soon as it is received from
+/// the kernel, and thus the resulting [OutputNote] can always be built once the transaction has
+/// been executed.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct OutputNoteBuilder {
+    metadata: NoteMetadata,
+    recipient_digest: Digest,
+    recipient: Option<NoteRecipient>,
+    assets: NoteAssets,
+}
+
+impl OutputNoteBuilder {
+    /// Returns a new [OutputNoteBuilder] instantiated from the specified parameters.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The recipient details are provided for a note which is not public, or are missing for a
+    ///   public note.
+    /// - The recipient details do not hash to the recipient the note was created with.
+    pub fn new(
+        metadata: NoteMetadata,
+        recipient_digest: Digest,
+        recipient: Option<NoteRecipient>,
+        assets: NoteAssets,
+    ) -> Result<Self, TransactionKernelError> {
+        match (&recipient, metadata.note_type()) {
+            (Some(recipient), NoteType::Public) => {
+                if recipient.digest() != recipient_digest {
+                    return Err(TransactionKernelError::InconsistentNoteRecipient {
+                        expected: recipient_digest,
+                        actual: recipient.digest(),
+                    });
+                }
+            },
+            (None, NoteType::OffChain) => (),
+            (_, note_type) => {
+                let err = NoteError::InvalidNoteType(note_type);
+                return Err(TransactionKernelError::MalformedNoteType(err));
+            },
+        }
+
+        Ok(Self {
+            metadata,
+            recipient_digest,
+            recipient,
+            assets,
+        })
+    }
+
+    /// Adds the specified asset to the assets of the note.
+    ///
+    /// A fungible asset issued by the same faucet as a fungible asset of the note is combined with
+    /// that asset in place, as done by the kernel when merging created notes; any other asset is
+    /// appended to the assets of the note. The kernel rejects fungible assets issued by the same
+    /// faucet when notes are not merged, and so they never reach the builder in that case.
+    ///
+    /// # Errors
+    /// Returns an error if the note already contains the maximum number of assets or the same
+    /// non-fungible asset, or if the combined amount of a fungible asset is too large.
+    pub fn add_asset(&mut self, asset: Asset) -> Result<(), TransactionKernelError> {
+        let mut assets: Vec<Asset> = self.assets.iter().copied().collect();
+        match (assets.iter_mut().find(|note_asset| note_asset.is_same(&asset)), asset) {
+            (Some(Asset::Fungible(note_asset)), Asset::Fungible(asset)) => {
+                *note_asset =
+                    note_asset.add(asset).map_err(TransactionKernelError::MalformedAsset)?;
+            },
+            _ => assets.push(asset),
+        }
+        self.assets =
+            NoteAssets::new(assets).map_err(TransactionKernelError::FailedToAddAssetToNote)?;
+
+        Ok(())
+    }
+
+    /// Consumes `self` and returns the resulting [OutputNote].
+    pub fn build(self) -> OutputNote {
+        match self.recipient {
+            Some(recipient) => OutputNote::Public(Note::new(self.assets, self.metadata, recipient)),
+            None => {
+                let note_id = NoteId::new(self.recipient_digest, self.assets.commitment());
+                OutputNote::Private(
+                    NoteEnvelope::new(note_id, self.metadata)
+                        .expect("note type validated on creation"),
+                )
+            },
+        }
+    }
+}
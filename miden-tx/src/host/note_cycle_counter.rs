@@ -0,0 +1,66 @@
+use alloc::vec::Vec;
+
+use miden_lib::transaction::{
+    memory::{CONSUMED_NOTE_ID_OFFSET, CURRENT_CONSUMED_NOTE_PTR},
+    TransactionTrace,
+};
+use miden_objects::{notes::NoteId, transaction::TransactionMeasurements};
+
+use super::{ContextId, ProcessState};
+
+// NOTE CYCLE COUNTER
+// ================================================================================================
+
+/// Counts the number of cycles consumed by the script of every input note, based on the traces
+/// emitted by the kernel.
+///
+/// The counter also keeps track of the note which is currently being processed by the kernel,
+/// i.e., the note whose script is being executed or whose execution is being finalized.
+#[derive(Default)]
+pub struct NoteCycleCounter {
+    current_note: Option<(NoteId, u32)>,
+    processed_note: Option<NoteId>,
+    note_cycles: Vec<(NoteId, u32)>,
+}
+
+impl NoteCycleCounter {
+    /// Records the start of the execution of a note script, or the number of cycles consumed by
+    /// the note script if the specified trace marks the end of its execution.
+    ///
+    /// All other traces are ignored.
+    pub fn on_trace<S: ProcessState>(&mut self, process: &S, trace_id: u32) {
+        match TransactionTrace::try_from(trace_id) {
+            Ok(TransactionTrace::NoteExecutionStart) => {
+                self.processed_note = current_note_id(process);
+                self.current_note = self.processed_note.map(|note_id| (note_id, process.clk()));
+            },
+            Ok(TransactionTrace::NoteExecutionEnd) => {
+                if let Some((note_id, start_clk)) = self.current_note.take() {
+                    self.note_cycles.push((note_id, process.clk() - start_clk));
+                }
+            },
+            Ok(TransactionTrace::NotesProcessingEnd) => self.processed_note = None,
+            _ => (),
+        }
+    }
+
+    /// Returns the ID of the note which is currently being processed, if any.
+    pub fn processed_note(&self) -> Option<NoteId> {
+        self.processed_note
+    }
+
+    /// Returns the measurements of the notes whose execution was observed.
+    pub fn measurements(&self) -> TransactionMeasurements {
+        TransactionMeasurements::new(self.note_cycles.clone())
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the ID of the note which is currently being consumed, read from the kernel memory.
+pub fn current_note_id<S: ProcessState>(process: &S) -> Option<NoteId> {
+    let note_ptr = process.get_mem_value(ContextId::root(), CURRENT_CONSUMED_NOTE_PTR)?[0];
+    let note_id_ptr = note_ptr.as_int() as u32 + CONSUMED_NOTE_ID_OFFSET;
+    process.get_mem_value(ContextId::root(), note_id_ptr).map(NoteId::from)
+}
@@ -0,0 +1,58 @@
+use alloc::vec::Vec;
+
+use miden_lib::transaction::TransactionTrace;
+use tracing::{field::Empty, info_span, span::EnteredSpan};
+
+use super::{note_cycle_counter::current_note_id, ProcessState};
+
+// KERNEL STAGE TRACER
+// ================================================================================================
+
+/// Enters a [tracing] span for every stage of the transaction kernel (i.e., the prologue, the
+/// processing of the consumed notes and of every single note, the processing of the transaction
+/// script, and the epilogue), based on the traces emitted by the kernel.
+///
+/// Every span records the number of cycles spent in the stage when the stage ends.
+#[derive(Default)]
+pub struct KernelStageTracer {
+    open_stages: Vec<(EnteredSpan, u32)>,
+}
+
+impl KernelStageTracer {
+    /// Enters a span if the specified trace marks the start of a kernel stage, and exits the span
+    /// of the innermost open stage if it marks the end of a stage.
+    ///
+    /// Traces which were not emitted by the transaction kernel are ignored.
+    pub fn on_trace<S: ProcessState>(&mut self, process: &S, trace_id: u32) {
+        let Ok(trace) = TransactionTrace::try_from(trace_id) else {
+            return;
+        };
+
+        let span = match trace {
+            TransactionTrace::PrologueStart => info_span!("prologue", cycles = Empty),
+            TransactionTrace::NotesProcessingStart => {
+                info_span!("notes_processing", cycles = Empty)
+            },
+            TransactionTrace::NoteExecutionStart => match current_note_id(process) {
+                Some(note_id) => info_span!("note_execution", %note_id, cycles = Empty),
+                None => info_span!("note_execution", cycles = Empty),
+            },
+            TransactionTrace::TxScriptProcessingStart => {
+                info_span!("tx_script_processing", cycles = Empty)
+            },
+            TransactionTrace::EpilogueStart => info_span!("epilogue", cycles = Empty),
+            TransactionTrace::PrologueEnd
+            | TransactionTrace::NotesProcessingEnd
+            | TransactionTrace::NoteExecutionEnd
+            | TransactionTrace::TxScriptProcessingEnd
+            | TransactionTrace::EpilogueEnd => {
+                if let Some((span, start_clk)) = self.open_stages.pop() {
+                    span.record("cycles", process.clk() - start_clk);
+                }
+                return;
+            },
+        };
+
+        self.open_stages.push((span.entered(), process.clk()));
+    }
+}
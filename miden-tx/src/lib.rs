@@ -17,25 +17,36 @@ use miden_objects::{
 };
 use vm_processor::{ExecutionError, RecAdviceProvider};
 
+mod auth;
+pub use auth::{BasicAuthenticator, SignatureRequest, SignatureRequestHandler};
+
+pub mod cache;
+pub use cache::{Cache, InMemoryCache};
+
 mod compiler;
 pub use compiler::{ScriptTarget, TransactionCompiler};
 
 mod executor;
-pub use executor::{DataStore, TransactionExecutor};
+pub use executor::{DataStore, TransactionExecutor, TransactionInputsBuilder, WitnessStore};
 
 pub mod host;
-pub use host::TransactionHost;
+pub use host::{AdviceInjectors, TransactionHost};
 
 mod prover;
-pub use prover::{ProvingOptions, TransactionProver};
+pub use prover::{NoteEncryptorFn, ProvingOptions, TransactionProver};
 
 mod verifier;
-pub use verifier::TransactionVerifier;
+pub use verifier::{verify_transaction, TransactionVerifier};
+
+mod scanner;
+pub use scanner::{BlockNote, DiscoveredNote, NoteScanner};
+
+pub mod recipes;
 
 mod error;
 pub use error::{
-    DataStoreError, TransactionCompilerError, TransactionExecutorError, TransactionProverError,
-    TransactionVerifierError,
+    AdviceInjectorError, DataStoreError, NoteScannerError, RecipeError, TransactionCompilerError,
+    TransactionExecutorError, TransactionProverError, TransactionVerifierError,
 };
 
 #[cfg(test)]
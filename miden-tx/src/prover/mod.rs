@@ -1,15 +1,24 @@
+use alloc::{string::String, sync::Arc, vec::Vec};
+
 use miden_lib::transaction::{ToTransactionKernelInputs, TransactionKernel};
 use miden_objects::{
-    notes::Nullifier,
+    notes::{Note, Nullifier},
     transaction::{
-        AccountDetails, InputNotes, ProvenTransaction, ProvenTransactionBuilder, TransactionWitness,
+        AccountDetails, DeliveryReport, InputNotes, NoteDelivery, NoteDeliveryInstruction,
+        OutputNote, OutputNotes, ProvenTransaction, ProvenTransactionBuilder, TransactionArgs,
+        TransactionWitness,
     },
+    Word,
 };
 use miden_prover::prove;
 pub use miden_prover::ProvingOptions;
 use vm_processor::MemAdviceProvider;
 
-use super::{TransactionHost, TransactionProverError};
+use super::{AdviceInjectors, BasicAuthenticator, TransactionHost, TransactionProverError};
+
+/// A callback which encrypts the details of an output note to the specified public key, used to
+/// deliver notes with the [NoteDeliveryInstruction::Encrypt] instruction.
+pub type NoteEncryptorFn = Arc<dyn Fn(Word, &Note) -> Result<Vec<u8>, String> + Send + Sync>;
 
 /// Transaction prover is a stateless component which is responsible for proving transactions.
 ///
@@ -17,6 +26,9 @@ use super::{TransactionHost, TransactionProverError};
 /// or anything that can be converted into a [TransactionWitness], and returns a [ProvenTransaction].
 pub struct TransactionProver {
     proof_options: ProvingOptions,
+    advice_injectors: AdviceInjectors,
+    authenticator: Option<BasicAuthenticator>,
+    note_encryptor: Option<NoteEncryptorFn>,
 }
 
 impl TransactionProver {
@@ -24,7 +36,43 @@ impl TransactionProver {
     // --------------------------------------------------------------------------------------------
     /// Creates a new [TransactionProver] instance.
     pub fn new(proof_options: ProvingOptions) -> Self {
-        Self { proof_options }
+        Self {
+            proof_options,
+            advice_injectors: AdviceInjectors::default(),
+            authenticator: None,
+            note_encryptor: None,
+        }
+    }
+
+    /// Sets the application-defined [AdviceInjectors] which are invoked when the corresponding
+    /// events are emitted during transaction proving.
+    ///
+    /// These must be the same advice injectors which were used to execute the transaction, as
+    /// advice provided by the injectors is not part of the transaction witness.
+    pub fn with_advice_injectors(mut self, advice_injectors: AdviceInjectors) -> Self {
+        self.advice_injectors = advice_injectors;
+        self
+    }
+
+    /// Sets the [BasicAuthenticator] which provides the secret keys for the signatures requested
+    /// by the proven transactions.
+    ///
+    /// This must be the same authenticator which was used to execute the transaction, as the
+    /// secret keys held by the authenticator are not part of the transaction witness. Signature
+    /// request handlers of the authenticator are not invoked while proving.
+    pub fn with_authenticator(mut self, authenticator: BasicAuthenticator) -> Self {
+        self.authenticator = Some(authenticator);
+        self
+    }
+
+    /// Sets the callback which encrypts the details of output notes with the
+    /// [NoteDeliveryInstruction::Encrypt] delivery instruction.
+    pub fn with_note_encryptor<F>(mut self, note_encryptor: F) -> Self
+    where
+        F: Fn(Word, &Note) -> Result<Vec<u8>, String> + Send + Sync + 'static,
+    {
+        self.note_encryptor = Some(Arc::new(note_encryptor));
+        self
     }
 
     // TRANSACTION PROVER
@@ -32,16 +80,49 @@ impl TransactionProver {
 
     /// Proves the provided transaction and returns a [ProvenTransaction].
     ///
+    /// Output notes with a [NoteDeliveryInstruction] are included in the proven transaction as
+    /// specified by their instructions (see [TransactionProver::prove_and_deliver()]).
+    ///
     /// # Errors
     /// - If the consumed note data in the transaction witness is corrupt.
     /// - If the transaction program cannot be proven.
     /// - If the transaction result is corrupt.
+    /// - If the output notes cannot be delivered as specified by their delivery instructions.
     pub fn prove_transaction<T: Into<TransactionWitness>>(
         &self,
         transaction: T,
     ) -> Result<ProvenTransaction, TransactionProverError> {
+        self.prove_and_deliver(transaction).map(|(proven_tx, _)| proven_tx)
+    }
+
+    /// Proves the provided transaction, and delivers its output notes as specified by their
+    /// [NoteDeliveryInstruction]s. Returns the [ProvenTransaction] together with a
+    /// [DeliveryReport] on the output notes with a delivery instruction.
+    ///
+    /// Notes without a delivery instruction are included in the proven transaction as they were
+    /// output by the transaction.
+    ///
+    /// # Errors
+    /// - If the consumed note data in the transaction witness is corrupt.
+    /// - If the transaction program cannot be proven.
+    /// - If the transaction result is corrupt.
+    /// - If a delivery instruction refers to a note which was not created by the transaction, or
+    ///   the metadata of the note differs from the metadata of the note created by the transaction.
+    /// - If a note must be encrypted but no note encryptor was set, or the encryption fails.
+    pub fn prove_and_deliver<T: Into<TransactionWitness>>(
+        &self,
+        transaction: T,
+    ) -> Result<(ProvenTransaction, DeliveryReport), TransactionProverError> {
         let tx_witness: TransactionWitness = transaction.into();
 
+        #[cfg(feature = "tracing")]
+        let _span = {
+            let input_notes = tx_witness.tx_inputs().input_notes();
+            let note_ids = input_notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+            let account_id = tx_witness.account().id();
+            tracing::info_span!("prove_transaction", %account_id, ?note_ids).entered()
+        };
+
         // extract required data from the transaction witness
         let (stack_inputs, advice_inputs) = tx_witness.get_kernel_inputs();
 
@@ -52,27 +133,51 @@ impl TransactionProver {
         let tx_script_root = tx_witness.tx_args().tx_script().map(|script| *script.hash());
 
         let advice_provider: MemAdviceProvider = advice_inputs.into();
-        let mut host = TransactionHost::new(tx_witness.account().into(), advice_provider);
+        let mut host = TransactionHost::with_advice_injectors(
+            tx_witness.account().into(),
+            advice_provider,
+            self.advice_injectors.clone(),
+        );
+        if let Some(authenticator) = &self.authenticator {
+            host = host.with_authenticator(authenticator.without_signature_request_handler());
+        }
+        #[cfg(feature = "tracing")]
+        let prove_span = tracing::info_span!("prove_program").entered();
+
         let (stack_outputs, proof) =
             prove(tx_witness.program(), stack_inputs, &mut host, self.proof_options.clone())
                 .map_err(TransactionProverError::ProveTransactionProgramFailed)?;
 
+        #[cfg(feature = "tracing")]
+        drop(prove_span);
+
         // extract transaction outputs and process transaction data
-        let (advice_provider, account_delta, output_notes) = host.into_parts();
+        let (advice_provider, account_delta, output_notes, log_entries) = host.into_parts();
         let (_, map, _) = advice_provider.into_parts();
-        let tx_outputs =
-            TransactionKernel::from_transaction_parts(&stack_outputs, &map.into(), output_notes)
-                .map_err(TransactionProverError::InvalidTransactionOutput)?;
+        let tx_outputs = TransactionKernel::from_transaction_parts(
+            &stack_outputs,
+            &map.into(),
+            output_notes,
+            log_entries,
+        )
+        .map_err(TransactionProverError::InvalidTransactionOutput)?;
+        let (output_notes, delivery_report) =
+            self.deliver_output_notes(&tx_outputs.output_notes, tx_witness.tx_args())?;
+
+        #[cfg(feature = "tracing")]
+        let _span = tracing::info_span!("build_proven_transaction").entered();
 
         let builder = ProvenTransactionBuilder::new(
             account_id,
-            tx_witness.account().proof_init_hash(),
+            tx_witness.tx_inputs().account_init_hash(),
             tx_outputs.account.hash(),
             block_hash,
             proof,
         )
         .add_input_notes(input_notes)
-        .add_output_notes(tx_outputs.output_notes.iter().cloned());
+        .add_output_notes(output_notes)
+        .add_log_entries(tx_outputs.log.iter().copied())
+        .inclusion_hints(tx_witness.tx_args().inclusion_hints());
 
         let builder = match tx_script_root {
             Some(tx_script_root) => builder.tx_script_root(tx_script_root),
@@ -82,7 +187,7 @@ impl TransactionProver {
         let builder = match account_id.is_on_chain() {
             true => {
                 let account_details = if tx_witness.account().is_new() {
-                    let mut account = tx_witness.account().clone();
+                    let mut account = tx_witness.account().fork();
                     account
                         .apply_delta(&account_delta)
                         .map_err(TransactionProverError::InvalidAccountDelta)?;
@@ -97,6 +202,58 @@ impl TransactionProver {
             false => builder,
         };
 
-        builder.build().map_err(TransactionProverError::ProvenTransactionError)
+        let proven_tx = builder.build().map_err(TransactionProverError::ProvenTransactionError)?;
+        Ok((proven_tx, delivery_report))
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Applies the delivery instructions of the transaction arguments to the output notes, and
+    /// returns the output notes to be included in the proven transaction together with the
+    /// resulting [DeliveryReport].
+    fn deliver_output_notes(
+        &self,
+        output_notes: &OutputNotes,
+        tx_args: &TransactionArgs,
+    ) -> Result<(Vec<OutputNote>, DeliveryReport), TransactionProverError> {
+        for (note, _) in tx_args.note_delivery_instructions() {
+            if !output_notes.iter().any(|output_note| output_note.id() == note.id()) {
+                return Err(TransactionProverError::NoteDeliveryNoteNotFound(note.id()));
+            }
+        }
+
+        let mut delivery_report = DeliveryReport::new();
+        let mut delivered_notes = Vec::with_capacity(output_notes.num_notes());
+        for output_note in output_notes.iter() {
+            let note_id = output_note.id();
+            let Some((note, instruction)) = tx_args.note_delivery(note_id) else {
+                delivered_notes.push(output_note.clone());
+                continue;
+            };
+            if note.metadata() != output_note.metadata() {
+                return Err(TransactionProverError::NoteDeliveryMetadataMismatch(note_id));
+            }
+
+            let delivery = match instruction {
+                NoteDeliveryInstruction::Publish => NoteDelivery::Published,
+                NoteDeliveryInstruction::Encrypt { pub_key } => {
+                    let note_encryptor = self
+                        .note_encryptor
+                        .as_ref()
+                        .ok_or(TransactionProverError::NoteEncryptorMissing(note_id))?;
+                    let ciphertext = note_encryptor(pub_key, note).map_err(|err| {
+                        TransactionProverError::NoteEncryptionFailed(note_id, err)
+                    })?;
+                    NoteDelivery::Encrypted { pub_key, ciphertext }
+                },
+                NoteDeliveryInstruction::Export => NoteDelivery::Exported(note.clone()),
+            };
+
+            delivered_notes.push(instruction.apply(note));
+            delivery_report.insert(note_id, delivery);
+        }
+
+        Ok((delivered_notes, delivery_report))
     }
 }
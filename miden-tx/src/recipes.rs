@@ -0,0 +1,470 @@
+use alloc::{string::String, vec::Vec};
+
+use miden_lib::notes::create_p2id_note;
+use miden_objects::{
+    accounts::AccountId,
+    assembly::ProgramAst,
+    assets::{Asset, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails},
+    crypto::rand::{FeltRng, RpoRandomCoin},
+    notes::{Note, NoteId, NoteType},
+    transaction::{ExecutedTransaction, OutputNote, TransactionArgs},
+    Word, MAX_OUTPUT_NOTES_PER_TX,
+};
+
+use super::{DataStore, DiscoveredNote, RecipeError, TransactionExecutor};
+
+pub use miden_objects::crypto::auth::AuthSecretKey;
+
+// AUTH PROCEDURES
+// ================================================================================================
+
+/// Returns the name of the procedure of `miden::contracts::auth::basic` which authenticates
+/// transactions using the specified key.
+fn auth_procedure(auth: &AuthSecretKey) -> &'static str {
+    match auth {
+        AuthSecretKey::RpoFalcon512(_) => "auth_tx_rpo_falcon512",
+    }
+}
+
+/// Returns the name of the procedure of `miden::contracts::auth::basic` which authenticates
+/// keep-alive transactions using the specified key.
+fn keep_alive_procedure(auth: &AuthSecretKey) -> &'static str {
+    match auth {
+        AuthSecretKey::RpoFalcon512(_) => "keep_alive_rpo_falcon512",
+    }
+}
+
+// RECIPES
+// ================================================================================================
+
+/// Sends the specified assets from the specified account to the target account in a single P2ID
+/// note, and returns the executed transaction together with the created note.
+///
+/// The sender account must expose the procedures of the basic wallet (i.e.,
+/// `miden::contracts::wallets::basic`) and authenticate transactions using the provided key. The
+/// transaction does not consume any notes, and is executed against the block with the specified
+/// number.
+///
+/// # Errors
+/// Returns an error if:
+/// - No assets were specified, or the P2ID note could not be created from the specified assets.
+/// - The transaction could not be executed, e.g., because the sender account does not contain
+///   the specified assets.
+#[allow(clippy::too_many_arguments)]
+pub fn send_assets<D: DataStore, R: FeltRng>(
+    executor: &mut TransactionExecutor<D>,
+    auth: &AuthSecretKey,
+    block_ref: u32,
+    sender: AccountId,
+    target: AccountId,
+    assets: Vec<Asset>,
+    note_type: NoteType,
+    rng: R,
+) -> Result<(ExecutedTransaction, Vec<Note>), RecipeError> {
+    let (first_asset, other_assets) = assets.split_first().ok_or(RecipeError::NoAssets)?;
+    let note = create_p2id_note(sender, target, assets.clone(), note_type, rng)
+        .map_err(RecipeError::CreateNoteFailed)?;
+
+    // the first asset is sent via a new note, and all other assets are moved into the same note
+    let move_assets = other_assets
+        .iter()
+        .map(|asset| {
+            format!(
+                "
+            push.{asset}
+            call.wallet::move_asset_to_note dropw",
+                asset = format_word((*asset).into())
+            )
+        })
+        .collect::<Vec<String>>()
+        .join("");
+
+    let tx_script_src = format!(
+        "
+        use.miden::contracts::auth::basic->auth_tx
+        use.miden::contracts::wallets::basic->wallet
+
+        begin
+            push.{recipient}
+            push.{note_type}
+            push.{tag}
+            push.{asset}
+            call.wallet::send_asset
+            {move_assets}
+            drop dropw dropw drop
+
+            call.auth_tx::{auth_procedure}
+        end
+        ",
+        recipient = format_word(note.recipient_digest().into()),
+        note_type = note_type as u8,
+        tag = note.metadata().tag().inner(),
+        asset = format_word((*first_asset).into()),
+        auth_procedure = auth_procedure(auth),
+    );
+
+    execute_recipe(executor, auth, block_ref, sender, &[], &tx_script_src, &[note])
+}
+
+/// Consumes all notes among the provided discovered notes which can be consumed by the specified
+/// account, and returns the executed transaction together with the notes created by it whose
+/// details are known.
+///
+/// A discovered note can be consumed if its details are known, it was created in a block not
+/// later than the block with the specified number, and it has not expired by that block. The
+/// notes are consumed in the order in which they were provided, and must be available in the
+/// data store of the executor.
+///
+/// The account must authenticate transactions using the provided key.
+///
+/// # Errors
+/// Returns an error if:
+/// - None of the discovered notes can be consumed.
+/// - The transaction could not be executed, e.g., because the script of one of the notes failed.
+pub fn consume_available_notes<D, I>(
+    executor: &mut TransactionExecutor<D>,
+    auth: &AuthSecretKey,
+    block_ref: u32,
+    account_id: AccountId,
+    notes: I,
+) -> Result<(ExecutedTransaction, Vec<Note>), RecipeError>
+where
+    D: DataStore,
+    I: IntoIterator<Item = DiscoveredNote>,
+{
+    let note_ids: Vec<NoteId> = notes
+        .into_iter()
+        .filter_map(DiscoveredNote::into_input_note)
+        .filter(|note| {
+            note.proof().origin().block_num <= block_ref
+                && !note.note().metadata().is_expired(block_ref)
+        })
+        .map(|note| note.id())
+        .collect();
+    if note_ids.is_empty() {
+        return Err(RecipeError::NoConsumableNotes);
+    }
+
+    let tx_script_src = format!(
+        "
+        use.miden::contracts::auth::basic->auth_tx
+
+        begin
+            call.auth_tx::{auth_procedure}
+        end
+        ",
+        auth_procedure = auth_procedure(auth),
+    );
+
+    execute_recipe(executor, auth, block_ref, account_id, &note_ids, &tx_script_src, &[])
+}
+
+/// Mints the specified amount of the fungible asset issued by the specified faucet to the target
+/// account via a P2ID note, and returns the executed transaction together with the created note.
+///
+/// The faucet account must expose the procedures of the basic fungible faucet (i.e.,
+/// `miden::contracts::faucets::basic_fungible`) and authenticate transactions using the provided
+/// key.
+///
+/// # Errors
+/// Returns an error if:
+/// - The asset or the P2ID note could not be created, e.g., because the specified account is not
+///   a fungible faucet.
+/// - The transaction could not be executed, e.g., because minting the asset would exceed the
+///   maximum supply of the faucet.
+#[allow(clippy::too_many_arguments)]
+pub fn mint<D: DataStore, R: FeltRng>(
+    executor: &mut TransactionExecutor<D>,
+    auth: &AuthSecretKey,
+    block_ref: u32,
+    faucet_id: AccountId,
+    target: AccountId,
+    amount: u64,
+    note_type: NoteType,
+    rng: R,
+) -> Result<(ExecutedTransaction, Vec<Note>), RecipeError> {
+    let asset = FungibleAsset::new(faucet_id, amount).map_err(RecipeError::CreateAssetFailed)?;
+    let note = create_p2id_note(faucet_id, target, vec![asset.into()], note_type, rng)
+        .map_err(RecipeError::CreateNoteFailed)?;
+
+    let tx_script_src = format!(
+        "
+        use.miden::contracts::auth::basic->auth_tx
+        use.miden::contracts::faucets::basic_fungible->faucet
+
+        begin
+            push.{recipient}
+            push.{note_type}
+            push.{tag}
+            push.{amount}
+            call.faucet::distribute
+
+            call.auth_tx::{auth_procedure}
+            dropw dropw
+        end
+        ",
+        recipient = format_word(note.recipient_digest().into()),
+        note_type = note_type as u8,
+        tag = note.metadata().tag().inner(),
+        auth_procedure = auth_procedure(auth),
+    );
+
+    execute_recipe(executor, auth, block_ref, faucet_id, &[], &tx_script_src, &[note])
+}
+
+/// Executes the specified transaction minting non-fungible assets, and returns the executed
+/// transaction together with the created notes.
+///
+/// The faucet account must expose the procedures of the basic non-fungible faucet (i.e.,
+/// `miden::contracts::faucets::basic_non_fungible`) and authenticate transactions using the
+/// provided key, which must be the key the transaction was built for.
+///
+/// # Errors
+/// Returns an error if the transaction could not be executed, e.g., because one of the assets has
+/// already been minted.
+pub fn mint_nfts<D: DataStore>(
+    executor: &mut TransactionExecutor<D>,
+    auth: &AuthSecretKey,
+    block_ref: u32,
+    mint_tx: &NftMintTransaction,
+) -> Result<(ExecutedTransaction, Vec<Note>), RecipeError> {
+    execute_recipe(
+        executor,
+        auth,
+        block_ref,
+        mint_tx.faucet_id,
+        &[],
+        &mint_tx.tx_script_src,
+        &mint_tx.notes,
+    )
+}
+
+/// Increments the nonce of the specified account without changing its state otherwise, and
+/// returns the executed keep-alive transaction.
+///
+/// The transaction neither consumes nor creates notes, and thus results in the smallest possible
+/// proof. It can be used to signal that an account is still active, or to pay fees without
+/// performing any other action. The account must expose the keep-alive authentication procedure
+/// of `miden::contracts::auth::basic` (e.g., `keep_alive_rpo_falcon512`) and authenticate
+/// transactions using the provided key.
+///
+/// # Errors
+/// Returns an error if the transaction could not be executed, e.g., because the account does not
+/// expose the keep-alive authentication procedure.
+pub fn keep_alive<D: DataStore>(
+    executor: &mut TransactionExecutor<D>,
+    auth: &AuthSecretKey,
+    block_ref: u32,
+    account_id: AccountId,
+) -> Result<ExecutedTransaction, RecipeError> {
+    let tx_script_src = format!(
+        "
+        use.miden::contracts::auth::basic->auth_tx
+
+        begin
+            call.auth_tx::{keep_alive_procedure}
+        end
+        ",
+        keep_alive_procedure = keep_alive_procedure(auth),
+    );
+
+    execute_recipe(executor, auth, block_ref, account_id, &[], &tx_script_src, &[])
+        .map(|(executed_transaction, _)| executed_transaction)
+}
+
+// NON-FUNGIBLE ASSET MINTING
+// ================================================================================================
+
+/// The data of a non-fungible token, from which the non-fungible asset representing the token is
+/// derived (see [NonFungibleAssetDetails]).
+pub type TokenData = Vec<u8>;
+
+/// The maximum number of non-fungible assets minted by a single transaction built via
+/// [build_nft_mint_tx()], i.e., the maximum number of notes a transaction can create.
+pub const MAX_NFT_MINTS_PER_TX: usize = MAX_OUTPUT_NOTES_PER_TX;
+
+/// A transaction minting non-fungible assets to their recipients, built via
+/// [build_nft_mint_tx()] and executed via [mint_nfts()].
+#[derive(Debug, Clone)]
+pub struct NftMintTransaction {
+    faucet_id: AccountId,
+    tx_script_src: String,
+    notes: Vec<Note>,
+}
+
+impl NftMintTransaction {
+    /// Returns the ID of the faucet minting the assets.
+    pub fn faucet_id(&self) -> AccountId {
+        self.faucet_id
+    }
+
+    /// Returns the code of the transaction script which mints the assets and creates the notes.
+    pub fn tx_script_code(&self) -> ProgramAst {
+        ProgramAst::parse(&self.tx_script_src).expect("recipe transaction script is invalid")
+    }
+
+    /// Returns the P2ID notes created by the transaction, one for each minted asset.
+    pub fn notes(&self) -> &[Note] {
+        &self.notes
+    }
+}
+
+/// Builds the transactions minting a non-fungible asset from each of the specified token data
+/// and sending it to the recipient at the same position via a P2ID note.
+///
+/// A single transaction mints at most [MAX_NFT_MINTS_PER_TX] assets; larger collections are split
+/// into multiple transactions, in the order of the provided items. Since every transaction
+/// changes the state of the faucet, the transactions must be executed one after the other, each
+/// against the state of the faucet resulting from the previous one.
+///
+/// The faucet account must expose the procedures of the basic non-fungible faucet (i.e.,
+/// `miden::contracts::faucets::basic_non_fungible`) and authenticate transactions using the
+/// provided key. The serial numbers of the notes are drawn from the provided random generator.
+///
+/// # Errors
+/// Returns an error if:
+/// - No items were specified.
+/// - The number of items differs from the number of recipients.
+/// - Any of the assets or notes could not be created, e.g., because the specified account is not
+///   a non-fungible faucet.
+pub fn build_nft_mint_tx<R: FeltRng>(
+    auth: &AuthSecretKey,
+    faucet_id: AccountId,
+    items: Vec<TokenData>,
+    recipients: Vec<AccountId>,
+    note_type: NoteType,
+    mut rng: R,
+) -> Result<Vec<NftMintTransaction>, RecipeError> {
+    if items.is_empty() {
+        return Err(RecipeError::NoAssets);
+    }
+    if items.len() != recipients.len() {
+        return Err(RecipeError::MintRecipientsMismatch {
+            num_items: items.len(),
+            num_recipients: recipients.len(),
+        });
+    }
+
+    let mut mints = Vec::with_capacity(items.len());
+    for (data, recipient) in items.into_iter().zip(recipients) {
+        let details = NonFungibleAssetDetails::new(faucet_id, data)
+            .map_err(RecipeError::CreateAssetFailed)?;
+        let asset: Asset =
+            NonFungibleAsset::new(&details).map_err(RecipeError::CreateAssetFailed)?.into();
+
+        // every note gets its own generator, so that the notes have distinct serial numbers
+        let note_rng = RpoRandomCoin::new(rng.draw_word());
+        let note = create_p2id_note(faucet_id, recipient, vec![asset], note_type, note_rng)
+            .map_err(RecipeError::CreateNoteFailed)?;
+        mints.push((asset, note));
+    }
+
+    let mint_txs = mints
+        .chunks(MAX_NFT_MINTS_PER_TX)
+        .map(|chunk| {
+            // every call to `distribute` leaves 10 elements on the stack, which are dropped
+            let distribute_assets = chunk
+                .iter()
+                .map(|(asset, note)| {
+                    format!(
+                        "
+            push.{recipient}
+            push.{note_type}
+            push.{tag}
+            push.{asset}
+            call.faucet::distribute
+            drop dropw dropw drop",
+                        recipient = format_word(note.recipient_digest().into()),
+                        note_type = note_type as u8,
+                        tag = note.metadata().tag().inner(),
+                        asset = format_word((*asset).into()),
+                    )
+                })
+                .collect::<Vec<String>>()
+                .join("");
+
+            let tx_script_src = format!(
+                "
+        use.miden::contracts::auth::basic->auth_tx
+        use.miden::contracts::faucets::basic_non_fungible->faucet
+
+        begin
+            {distribute_assets}
+
+            call.auth_tx::{auth_procedure}
+        end
+        ",
+                auth_procedure = auth_procedure(auth),
+            );
+
+            NftMintTransaction {
+                faucet_id,
+                tx_script_src,
+                notes: chunk.iter().map(|(_, note)| note.clone()).collect(),
+            }
+        })
+        .collect();
+
+    Ok(mint_txs)
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Compiles the provided transaction script and executes it against the specified account in a
+/// transaction which consumes the specified notes and is expected to create the specified notes.
+///
+/// Returns the executed transaction together with the created notes whose details are known.
+fn execute_recipe<D: DataStore>(
+    executor: &mut TransactionExecutor<D>,
+    auth: &AuthSecretKey,
+    block_ref: u32,
+    account_id: AccountId,
+    note_ids: &[NoteId],
+    tx_script_src: &str,
+    expected_output_notes: &[Note],
+) -> Result<(ExecutedTransaction, Vec<Note>), RecipeError> {
+    executor
+        .load_account(account_id)
+        .map_err(RecipeError::ExecuteTransactionFailed)?;
+
+    // the recipes generate scripts only from valid words and numbers, and thus they always parse
+    let tx_script_ast =
+        ProgramAst::parse(tx_script_src).expect("recipe transaction script is invalid");
+    let tx_script = executor
+        .compile_tx_script(tx_script_ast, vec![auth.to_advice_map_entry()], vec![])
+        .map_err(RecipeError::ExecuteTransactionFailed)?;
+
+    let mut tx_args = TransactionArgs::with_tx_script(tx_script);
+    tx_args.extend_expected_output_notes(expected_output_notes.iter().cloned());
+
+    let executed_transaction = executor
+        .execute_transaction(account_id, block_ref, note_ids, tx_args)
+        .map_err(RecipeError::ExecuteTransactionFailed)?;
+
+    // details of private notes are not included in the transaction outputs, and thus are taken
+    // from the expected output notes
+    let created_notes = executed_transaction
+        .output_notes()
+        .iter()
+        .filter_map(|note| match note {
+            OutputNote::Public(note) => Some(note.clone()),
+            OutputNote::Private(envelope) => {
+                expected_output_notes.iter().find(|note| note.id() == envelope.id()).cloned()
+            },
+        })
+        .collect();
+
+    Ok((executed_transaction, created_notes))
+}
+
+/// Formats the provided word as the immediate value of a `push` instruction.
+fn format_word(word: Word) -> String {
+    format!(
+        "{}.{}.{}.{}",
+        word[0].as_int(),
+        word[1].as_int(),
+        word[2].as_int(),
+        word[3].as_int()
+    )
+}
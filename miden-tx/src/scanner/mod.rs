@@ -0,0 +1,296 @@
+use alloc::{collections::BTreeMap, sync::Arc, vec::Vec};
+
+use miden_objects::{
+    accounts::AccountId,
+    crypto::merkle::MerklePath,
+    notes::{
+        Note, NoteEnvelope, NoteExecutionMode, NoteId, NoteInclusionProof, NoteRelevance, NoteTag,
+        Relevance, RelevanceReason, TagSubscription,
+    },
+    transaction::InputNote,
+    BlockHeader, Hasher, NoteError, Word,
+};
+
+use super::NoteScannerError;
+
+// BLOCK NOTE
+// ================================================================================================
+
+/// A note created in a block, as received from the network.
+///
+/// Every block note contains the header of the note (i.e., its ID and metadata), the index of the
+/// note in the note tree of the block, and the Merkle path authenticating the note against the
+/// note root of the block. Details of the note are provided only for public notes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockNote {
+    note_index: u64,
+    envelope: NoteEnvelope,
+    details: Option<Note>,
+    note_path: MerklePath,
+}
+
+impl BlockNote {
+    /// Returns a new [BlockNote] instantiated from the specified parameters.
+    pub fn new(
+        note_index: u64,
+        envelope: NoteEnvelope,
+        details: Option<Note>,
+        note_path: MerklePath,
+    ) -> Self {
+        Self { note_index, envelope, details, note_path }
+    }
+
+    /// Returns the ID of the note.
+    pub fn id(&self) -> NoteId {
+        self.envelope.id()
+    }
+
+    /// Returns the header of the note.
+    pub fn envelope(&self) -> &NoteEnvelope {
+        &self.envelope
+    }
+
+    /// Returns the details of the note, if they were provided.
+    pub fn details(&self) -> Option<&Note> {
+        self.details.as_ref()
+    }
+
+    /// Returns the index of the note in the note tree of the block.
+    pub fn note_index(&self) -> u64 {
+        self.note_index
+    }
+
+    /// Returns the Merkle path to the note in the note tree of the block.
+    pub fn note_path(&self) -> &MerklePath {
+        &self.note_path
+    }
+}
+
+// DISCOVERED NOTE
+// ================================================================================================
+
+/// A note relevant to the scanning wallet, together with the proof of its inclusion in a block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DiscoveredNote {
+    /// A note whose details are known, and which can thus be consumed by a transaction.
+    Consumable(InputNote),
+
+    /// A note matching one of the tracked tags whose details are not known, e.g., a private note
+    /// whose details were not yet received from its sender.
+    DetailsUnknown(NoteEnvelope, NoteInclusionProof),
+}
+
+impl DiscoveredNote {
+    /// Returns the ID of the note.
+    pub fn id(&self) -> NoteId {
+        match self {
+            Self::Consumable(note) => note.id(),
+            Self::DetailsUnknown(envelope, _) => envelope.id(),
+        }
+    }
+
+    /// Returns the inclusion proof of the note.
+    pub fn proof(&self) -> &NoteInclusionProof {
+        match self {
+            Self::Consumable(note) => note.proof(),
+            Self::DetailsUnknown(_, proof) => proof,
+        }
+    }
+
+    /// Returns the discovered note as an [InputNote] if the details of the note are known.
+    pub fn into_input_note(self) -> Option<InputNote> {
+        match self {
+            Self::Consumable(note) => Some(note),
+            Self::DetailsUnknown(..) => None,
+        }
+    }
+}
+
+// NOTE SCANNER
+// ================================================================================================
+
+/// Scans the notes created in a sequence of blocks for notes which are relevant to a wallet.
+///
+/// A note is considered relevant if:
+/// - Its ID is the ID of one of the tracked notes, i.e., notes whose details were received by the
+///   wallet out of band (see [NoteScanner::track_note()]).
+/// - Its tag is one of the tracked tags (see [NoteScanner::track_tag()] and
+///   [NoteScanner::track_account()]).
+/// - It is relevant according to any of the added relevance checks, e.g., a [ViewKey] (see
+///   [NoteScanner::add_relevance_check()]).
+///
+/// The scanner itself implements [NoteRelevance], and thus the same checks can be applied to notes
+/// outside of the scanned blocks.
+///
+/// Every relevant note is authenticated against the note root of the block in which it was
+/// created, and the details of the note, if any, are checked against the note header. Thus, the
+/// discovered notes are ready to be stored as [InputNote]s.
+///
+/// [ViewKey]: miden_objects::notes::ViewKey
+#[derive(Debug, Clone, Default)]
+pub struct NoteScanner {
+    tags: TagSubscription,
+    notes: BTreeMap<NoteId, Note>,
+    relevance_checks: Vec<Arc<dyn NoteRelevance>>,
+}
+
+impl NoteScanner {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [NoteScanner] which does not track any tags or notes.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Adds the specified tag to the set of tracked tags.
+    pub fn track_tag(&mut self, tag: NoteTag) {
+        self.tags.add_tag(tag);
+    }
+
+    /// Adds the tag used for notes targeting the specified account for local execution to the set
+    /// of tracked tags.
+    ///
+    /// # Errors
+    /// Returns an error if the tag cannot be derived from the account ID.
+    pub fn track_account(&mut self, account_id: AccountId) -> Result<(), NoteError> {
+        self.track_tag(NoteTag::from_account_id(account_id, NoteExecutionMode::Local)?);
+        Ok(())
+    }
+
+    /// Adds the specified note to the set of tracked notes.
+    ///
+    /// Once a note with the same ID is found in a block, the provided details are used to build
+    /// the discovered note, regardless of the tag of the note.
+    pub fn track_note(&mut self, note: Note) {
+        self.notes.insert(note.id(), note);
+    }
+
+    /// Adds the specified relevance check, so that notes which are relevant according to it are
+    /// also considered relevant by this scanner.
+    pub fn add_relevance_check<R: NoteRelevance + 'static>(&mut self, relevance_check: R) {
+        self.relevance_checks.push(Arc::new(relevance_check));
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns true if the specified tag is tracked by this scanner.
+    pub fn is_tracked_tag(&self, tag: &NoteTag) -> bool {
+        self.tags.contains(*tag)
+    }
+
+    /// Returns true if the note with the specified ID is tracked by this scanner.
+    pub fn is_tracked_note(&self, note_id: &NoteId) -> bool {
+        self.notes.contains_key(note_id)
+    }
+
+    // SCANNING
+    // --------------------------------------------------------------------------------------------
+
+    /// Scans the notes of the provided blocks and returns an iterator over the relevant notes.
+    ///
+    /// Blocks are processed lazily, and thus the scanner can be used on a stream of blocks
+    /// received from the network.
+    ///
+    /// # Errors
+    /// The iterator yields an error if any of the relevant notes is invalid (see
+    /// [NoteScanner::scan_block()]).
+    pub fn scan<'a, I>(
+        &'a self,
+        blocks: I,
+    ) -> impl Iterator<Item = Result<DiscoveredNote, NoteScannerError>> + 'a
+    where
+        I: IntoIterator<Item = (BlockHeader, Vec<BlockNote>)>,
+        I::IntoIter: 'a,
+    {
+        blocks.into_iter().flat_map(move |(block_header, notes)| {
+            notes
+                .into_iter()
+                .filter_map(move |note| self.scan_note(&block_header, note).transpose())
+        })
+    }
+
+    /// Scans the notes created in the specified block and returns the relevant notes.
+    ///
+    /// # Errors
+    /// Returns an error if for any of the relevant notes:
+    /// - The details of the note do not match the note header.
+    /// - The index of the note is not a valid index in the note tree.
+    /// - The note cannot be authenticated against the note root of the block.
+    pub fn scan_block(
+        &self,
+        block_header: &BlockHeader,
+        notes: Vec<BlockNote>,
+    ) -> Result<Vec<DiscoveredNote>, NoteScannerError> {
+        notes
+            .into_iter()
+            .filter_map(|note| self.scan_note(block_header, note).transpose())
+            .collect()
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the discovered note if the specified note is relevant, or None otherwise.
+    fn scan_note(
+        &self,
+        block_header: &BlockHeader,
+        note: BlockNote,
+    ) -> Result<Option<DiscoveredNote>, NoteScannerError> {
+        let BlockNote { note_index, envelope, details, note_path } = note;
+        let note_id = envelope.id();
+
+        // details provided by the block take precedence over the tracked ones, but in both cases
+        // they must match the authenticated note header
+        let details = details.or_else(|| self.notes.get(&note_id).cloned());
+        if !self.check_relevance(&envelope, details.as_ref()).is_relevant() {
+            return Ok(None);
+        }
+
+        if let Some(note) = details.as_ref() {
+            if note.id() != note_id || note.metadata() != envelope.metadata() {
+                return Err(NoteScannerError::NoteDetailsMismatch(note_id));
+            }
+        }
+
+        let proof = NoteInclusionProof::new(
+            block_header.block_num(),
+            block_header.sub_hash(),
+            block_header.note_root(),
+            note_index,
+            note_path,
+        )
+        .map_err(|_| NoteScannerError::InvalidNoteIndex(note_id, note_index))?;
+
+        // authenticate the note against the note root of the block
+        let auth_hash = Hasher::merge(&[note_id.inner(), Word::from(envelope.metadata()).into()]);
+        if !proof.note_path().verify(note_index, auth_hash, &block_header.note_root()) {
+            return Err(NoteScannerError::InvalidNoteInclusionProof(note_id));
+        }
+
+        Ok(Some(match details {
+            Some(note) => DiscoveredNote::Consumable(InputNote::new(note, proof)),
+            None => DiscoveredNote::DetailsUnknown(envelope, proof),
+        }))
+    }
+}
+
+/// A note is relevant if it is tracked by the scanner, if its tag is tracked by the scanner, or if
+/// it is relevant according to any of the added relevance checks, checked in this order.
+impl NoteRelevance for NoteScanner {
+    fn check_relevance(&self, envelope: &NoteEnvelope, details: Option<&Note>) -> Relevance {
+        if self.is_tracked_note(&envelope.id()) {
+            return Relevance::Relevant { reason: RelevanceReason::TrackedNote };
+        }
+
+        core::iter::once(&self.tags as &dyn NoteRelevance)
+            .chain(self.relevance_checks.iter().map(|check| check.as_ref()))
+            .map(|check| check.check_relevance(envelope, details))
+            .find(Relevance::is_relevant)
+            .unwrap_or(Relevance::NotRelevant)
+    }
+}
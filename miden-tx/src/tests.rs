@@ -1,30 +1,47 @@
 use alloc::vec::Vec;
 
-use miden_lib::transaction::{ToTransactionKernelInputs, TransactionKernel};
+use miden_lib::{
+    notes::{create_p2id_note, standard_script_registry},
+    transaction::{KernelError, ToTransactionKernelInputs, TransactionEvent, TransactionKernel},
+};
 use miden_objects::{
     accounts::{
         Account, AccountCode, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
         ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2, ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
+        ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN, ACCOUNT_ID_SENDER,
     },
     assembly::{Assembler, ModuleAst, ProgramAst},
-    assets::{Asset, FungibleAsset},
-    block::BlockHeader,
-    notes::{NoteId, NoteType},
+    assets::{Asset, AssetVault, FungibleAsset, PartialVault},
+    block::{BlockHeader, FeeParameters, KernelConfig, KernelLimits},
+    crypto::{
+        merkle::{LeafIndex, MerklePath, MmrPeaks, SimpleSmt},
+        rand::RpoRandomCoin,
+    },
+    notes::{
+        Note, NoteAssets, NoteEnvelope, NoteId, NoteRecipient, NoteRelevance, NoteScript, NoteType,
+        Nullifier, RelevanceReason, ScriptInfo, ScriptRootSet,
+    },
     transaction::{
-        ChainMmr, InputNote, InputNotes, ProvenTransaction, TransactionArgs, TransactionWitness,
+        ChainMmr, Counterparty, FeeConversionWitness, FeeEstimate, FeePayment, InclusionHints,
+        InputNote, InputNotes, OutputNote, OutputNotes, PersistedTransaction, PostCondition,
+        ProvenTransaction, ProvenTransactionHeader, TransactionArgs, TransactionId,
+        TransactionWitness, TxLogEntry, TxSummary,
     },
-    Felt, Word,
+    Digest, Felt, PersistedTransactionError, ProvenTransactionError, TransactionScriptError, Word,
+    NOTE_TREE_DEPTH, ONE, TX_KERNEL_VERSION, ZERO,
 };
 use miden_prover::ProvingOptions;
 use mock::{
     constants::{non_fungible_asset, FUNGIBLE_ASSET_AMOUNT, MIN_PROOF_SECURITY_LEVEL},
     mock::{
         account::{
-            MockAccountType, ACCOUNT_INCR_NONCE_MAST_ROOT, ACCOUNT_SET_CODE_MAST_ROOT,
-            ACCOUNT_SET_ITEM_MAST_ROOT, STORAGE_INDEX_0,
+            MockAccountType, ACCOUNT_INCR_NONCE_MAST_ROOT, ACCOUNT_SET_ARRAY_ITEM_MAST_ROOT,
+            ACCOUNT_SET_CODE_MAST_ROOT, ACCOUNT_SET_ITEM_MAST_ROOT, STORAGE_INDEX_0,
+            STORAGE_INDEX_2,
         },
+        block::mock_block_header,
         notes::AssetPreservationStatus,
-        transaction::mock_inputs,
+        transaction::{mock_inputs, mock_inputs_with_kernel_config},
     },
     utils::prepare_word,
 };
@@ -34,8 +51,11 @@ use vm_processor::{
 };
 
 use super::{
-    AccountId, DataStore, DataStoreError, TransactionExecutor, TransactionHost, TransactionInputs,
-    TransactionProver, TransactionVerifier,
+    verify_transaction, AccountId, AdviceInjectorError, AdviceInjectors, BlockNote, DataStore,
+    DataStoreError, DiscoveredNote, NoteScanner, NoteScannerError, TransactionCompiler,
+    TransactionExecutor, TransactionExecutorError, TransactionHost, TransactionInputs,
+    TransactionInputsBuilder, TransactionProver, TransactionVerifier, TransactionVerifierError,
+    WitnessStore,
 };
 
 // TESTS
@@ -65,17 +85,19 @@ fn transaction_executor_witness() {
         vm_processor::execute(tx_witness.program(), stack_inputs, &mut host, Default::default())
             .unwrap();
 
-    let (advice_provider, _, output_notes) = host.into_parts();
+    let (advice_provider, _, output_notes, log_entries) = host.into_parts();
     let (_, map, _) = advice_provider.into_parts();
     let tx_outputs = TransactionKernel::from_transaction_parts(
         result.stack_outputs(),
         &map.into(),
         output_notes,
+        log_entries,
     )
     .unwrap();
 
     assert_eq!(executed_transaction.final_account().hash(), tx_outputs.account.hash());
     assert_eq!(executed_transaction.output_notes(), &tx_outputs.output_notes);
+    assert_eq!(executed_transaction.log(), &tx_outputs.log);
 }
 
 #[test]
@@ -96,6 +118,8 @@ fn executed_transaction_account_delta() {
 
     // updated storage
     let updated_slot_value = [Felt::new(7), Felt::new(9), Felt::new(11), Felt::new(13)];
+    let updated_array_item_index = 5;
+    let updated_array_item_value = [Felt::new(3), Felt::new(5), Felt::new(7), Felt::new(9)];
 
     // removed assets
     let removed_asset_1 = Asset::Fungible(
@@ -131,6 +155,14 @@ fn executed_transaction_account_delta() {
             # => [R', V]
         end
 
+        proc.set_array_item
+            push.0.0 movdn.7 movdn.7
+            # => [index, item_index, V', 0, 0]
+
+            call.{ACCOUNT_SET_ARRAY_ITEM_MAST_ROOT}
+            # => [V, 0, 0, 0, 0]
+        end
+
         proc.set_code
             call.{ACCOUNT_SET_CODE_MAST_ROOT}
             # => [0, 0, 0, 0]
@@ -164,6 +196,16 @@ fn executed_transaction_account_delta() {
             exec.set_item dropw dropw
             # => []
 
+            ## Update account storage array item
+            ## ------------------------------------------------------------------------------------
+            # push the new value, the item index and the index of the array slot onto the stack
+            push.{UPDATED_ARRAY_ITEM_VALUE}.{UPDATED_ARRAY_ITEM_INDEX}.{STORAGE_INDEX_2}
+            # => [idx, item_idx, 9, 7, 5, 3]
+
+            # update the array item
+            exec.set_array_item dropw dropw
+            # => []
+
             ## Send some assets from the account vault
             ## ------------------------------------------------------------------------------------
             # partially deplete fungible asset balance
@@ -203,6 +245,8 @@ fn executed_transaction_account_delta() {
     ",
         NEW_ACCOUNT_ROOT = prepare_word(&new_acct_code.root()),
         UPDATED_SLOT_VALUE = prepare_word(&Word::from(updated_slot_value)),
+        UPDATED_ARRAY_ITEM_VALUE = prepare_word(&updated_array_item_value),
+        UPDATED_ARRAY_ITEM_INDEX = updated_array_item_index,
         REMOVED_ASSET_1 = prepare_word(&Word::from(removed_asset_1)),
         REMOVED_ASSET_2 = prepare_word(&Word::from(removed_asset_2)),
         REMOVED_ASSET_3 = prepare_word(&Word::from(removed_asset_3)),
@@ -237,6 +281,10 @@ fn executed_transaction_account_delta() {
         executed_transaction.account_delta().storage().updated_items[0].1,
         updated_slot_value
     );
+    assert_eq!(
+        executed_transaction.account_delta().storage().updated_array_items,
+        vec![(STORAGE_INDEX_2, updated_array_item_index, updated_array_item_value)]
+    );
 
     // vault delta
     // --------------------------------------------------------------------------------------------
@@ -285,19 +333,83 @@ fn prove_witness_and_verify() {
     let block_ref = data_store.block_header.block_num();
     let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
 
-    let executed_transaction = executor
-        .execute_transaction(account_id, block_ref, &note_ids, data_store.tx_args().clone())
-        .unwrap();
+    // the inclusion hints are carried over to the proven transaction
+    let inclusion_hints = InclusionHints::new(Some(block_ref + 10), 100);
+    let mut tx_args = data_store.tx_args().clone();
+    tx_args.set_inclusion_hints(inclusion_hints);
+
+    let executed_transaction =
+        executor.execute_transaction(account_id, block_ref, &note_ids, tx_args).unwrap();
 
     let proof_options = ProvingOptions::default();
     let prover = TransactionProver::new(proof_options);
-    let proven_transaction = prover.prove_transaction(executed_transaction).unwrap();
+    let proven_transaction = prover.prove_transaction(executed_transaction.clone()).unwrap();
+
+    // the proven transaction matches the executed transaction, but not a transaction which
+    // additionally updates the account storage
+    assert_eq!(proven_transaction.validate_against(&executed_transaction), Ok(()));
+
+    let tx_script_code = ProgramAst::parse(&format!(
+        "
+        begin
+            push.0.0.0 push.91.92.93.94 push.{STORAGE_INDEX_0}
+            call.{ACCOUNT_SET_ITEM_MAST_ROOT} dropw dropw
+            push.1 call.{ACCOUNT_INCR_NONCE_MAST_ROOT} drop
+        end
+        "
+    ))
+    .unwrap();
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+    let tx_args =
+        TransactionArgs::new(Some(tx_script), None, data_store.tx_args.advice_map().clone());
+    let other_transaction =
+        executor.execute_transaction(account_id, block_ref, &note_ids, tx_args).unwrap();
+    assert!(matches!(
+        proven_transaction.validate_against(&other_transaction),
+        Err(ProvenTransactionError::AccountFinalHashMismatch(..))
+    ));
 
     let serialised_transaction = proven_transaction.to_bytes();
     let proven_transaction = ProvenTransaction::read_from_bytes(&serialised_transaction).unwrap();
+    assert_eq!(proven_transaction.inclusion_hints(), inclusion_hints);
+
+    // the header can be decoded from the serialized transaction without reading the proof
+    let header = ProvenTransactionHeader::read_from_bytes(&serialised_transaction).unwrap();
+    assert_eq!(&header, proven_transaction.header());
+    assert_eq!(header.id(), proven_transaction.id());
+
+    // the transaction ID can be recomputed from the public components of the transaction
+    let input_nullifiers: Vec<_> = header.input_notes().iter().copied().collect();
+    let tx_id = TransactionId::compute(
+        header.initial_account_hash(),
+        header.final_account_hash(),
+        &input_nullifiers,
+        header.output_notes().commitment(),
+        header.block_ref(),
+    );
+    assert_eq!(tx_id, proven_transaction.id());
+    assert_eq!(tx_id, TransactionId::from(&executed_transaction));
+
+    let (header, proof) = proven_transaction.split_proof();
+    let proven_transaction = ProvenTransaction::from_parts(header.clone(), proof);
 
     let verifier = TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL);
     assert!(verifier.verify(proven_transaction).is_ok());
+
+    // the proof can be verified statelessly from the two parts of the serialized transaction
+    let public_inputs_bytes = header.to_bytes();
+    let proof_bytes = &serialised_transaction[public_inputs_bytes.len()..];
+    let tx_id =
+        verify_transaction(proof_bytes, &public_inputs_bytes, MIN_PROOF_SECURITY_LEVEL).unwrap();
+    assert_eq!(tx_id, header.id());
+    assert!(matches!(
+        verify_transaction(proof_bytes, &public_inputs_bytes, u32::MAX),
+        Err(TransactionVerifierError::InsufficientProofSecurityLevel(..))
+    ));
+    assert!(matches!(
+        verify_transaction(&[], &public_inputs_bytes, MIN_PROOF_SECURITY_LEVEL),
+        Err(TransactionVerifierError::InvalidProof(_))
+    ));
 }
 
 // TEST TRANSACTION SCRIPT
@@ -353,74 +465,1426 @@ fn test_tx_script() {
     );
 }
 
-// MOCK DATA STORE
-// ================================================================================================
+#[test]
+fn test_tx_script_args() {
+    let data_store = MockDataStore::default();
+    let mut executor = TransactionExecutor::new(data_store.clone());
 
-#[derive(Clone)]
-struct MockDataStore {
-    pub account: Account,
-    pub block_header: BlockHeader,
-    pub block_chain: ChainMmr,
-    pub notes: Vec<InputNote>,
-    pub tx_args: TransactionArgs,
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let tx_script_args = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+    let tx_script_source = format!(
+        "
+    begin
+        # drop the tx script root
+        dropw
+
+        # assert that the tx script args were put onto the stack
+        push.{args} assert_eqw
+    end
+",
+        args = prepare_word(&tx_script_args)
+    );
+    let tx_script_code = ProgramAst::parse(&tx_script_source).unwrap();
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+    let mut tx_args =
+        TransactionArgs::new(Some(tx_script), None, data_store.tx_args.advice_map().clone());
+    tx_args.set_tx_script_args(tx_script_args);
+
+    let executed_transaction =
+        executor.execute_transaction(account_id, block_ref, &note_ids, tx_args);
+
+    assert!(
+        executed_transaction.is_ok(),
+        "Transaction execution failed {:?}",
+        executed_transaction,
+    );
 }
 
-impl MockDataStore {
-    pub fn new(asset_preservation: AssetPreservationStatus) -> Self {
-        let (tx_inputs, tx_args) =
-            mock_inputs(MockAccountType::StandardExisting, asset_preservation);
-        let (account, _, block_header, block_chain, notes) = tx_inputs.into_parts();
+#[test]
+fn test_tx_script_entrypoints() {
+    let data_store = MockDataStore::default();
+    let mut executor = TransactionExecutor::new(data_store.clone());
 
-        Self {
-            account,
-            block_header,
-            block_chain,
-            notes: notes.into_vec(),
-            tx_args,
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let tx_script_args = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+    let tx_script_source = format!(
+        "
+    proc.assert_args
+        push.{args} assert_eqw
+    end
+
+    export.check_args
+        # drop the entrypoint root
+        dropw
+
+        # assert that the tx script args were put onto the stack
+        exec.assert_args
+    end
+
+    export.fail
+        push.0 assert
+    end
+",
+        args = prepare_word(&tx_script_args)
+    );
+    let tx_script_code = ModuleAst::parse(&tx_script_source).unwrap();
+    let tx_script = executor
+        .compile_tx_script_with_entrypoints(&tx_script_code, vec![], vec![])
+        .unwrap();
+    let entrypoints = tx_script.entrypoints().to_vec();
+    assert_eq!(entrypoints.len(), 2);
+
+    let mut tx_args =
+        TransactionArgs::new(Some(tx_script), None, data_store.tx_args.advice_map().clone());
+    tx_args.set_tx_script_args(tx_script_args);
+
+    // an entrypoint must be selected
+    let err = executor
+        .execute_transaction(account_id, block_ref, &note_ids, tx_args.clone())
+        .unwrap_err();
+    assert_eq!(err, TransactionExecutorError::MissingTxScriptEntrypoint);
+
+    // only the roots of the entrypoints of the script can be selected
+    let unknown_root = *tx_args.tx_script().unwrap().hash();
+    assert_eq!(
+        tx_args.set_tx_script_entrypoint(unknown_root),
+        Err(TransactionScriptError::UnknownEntrypoint(unknown_root))
+    );
+
+    tx_args.set_tx_script_entrypoint(entrypoints[0]).unwrap();
+    let executed_transaction =
+        executor.execute_transaction(account_id, block_ref, &note_ids, tx_args.clone());
+    assert!(
+        executed_transaction.is_ok(),
+        "Transaction execution failed {:?}",
+        executed_transaction,
+    );
+
+    tx_args.set_tx_script_entrypoint(entrypoints[1]).unwrap();
+    let executed_transaction =
+        executor.execute_transaction(account_id, block_ref, &note_ids, tx_args);
+    assert!(matches!(
+        executed_transaction,
+        Err(TransactionExecutorError::ExecutionFailure { .. })
+    ));
+
+    // the transaction script is executed after all notes, so no note caused the failure
+    assert_eq!(executed_transaction.unwrap_err().failed_note_index(), None);
+}
+
+#[test]
+fn test_script_registry() {
+    let data_store = MockDataStore::default();
+    let account_id = data_store.account.id();
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    // the mock notes do not use any of the standard scripts
+    let mut registry = standard_script_registry().unwrap();
+    let mut executor =
+        TransactionExecutor::new(data_store.clone()).with_script_registry(registry.clone());
+    executor.load_account(account_id).unwrap();
+
+    let err = executor
+        .execute_transaction(account_id, block_ref, &note_ids, data_store.tx_args.clone())
+        .unwrap_err();
+    assert_eq!(
+        err,
+        TransactionExecutorError::UnknownNoteScript {
+            note_id: data_store.notes[0].id(),
+            script_root: data_store.notes[0].note().script().hash(),
         }
-    }
+    );
 
-    fn tx_args(&self) -> &TransactionArgs {
-        &self.tx_args
+    // once the scripts of the notes are registered, the transaction can be executed
+    for (idx, note) in data_store.notes.iter().enumerate() {
+        let info = ScriptInfo::new(format!("mock_{idx}"), 1).unwrap();
+        registry.register(note.note().script().hash(), info).unwrap();
     }
+    let mut executor = TransactionExecutor::new(data_store.clone()).with_script_registry(registry);
+    executor.load_account(account_id).unwrap();
+
+    executor
+        .execute_transaction(account_id, block_ref, &note_ids, data_store.tx_args.clone())
+        .unwrap();
 }
 
-impl Default for MockDataStore {
-    fn default() -> Self {
-        Self::new(AssetPreservationStatus::Preserved)
-    }
+#[test]
+fn test_note_dependencies() {
+    let data_store = MockDataStore::default();
+    let account_id = data_store.account.id();
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(account_id).unwrap();
+
+    // the first note cannot depend on the second note, as it is processed before it
+    let mut tx_args = data_store.tx_args.clone();
+    tx_args.add_note_dependency(note_ids[0], note_ids[1]);
+    let err = executor
+        .execute_transaction(account_id, block_ref, &note_ids, tx_args)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        TransactionExecutorError::UnsatisfiedNoteDependency {
+            note_id: note_ids[0],
+            dependency: note_ids[1],
+        }
+    );
+
+    // all notes of a group must be consumed together
+    let missing_note_id = NoteId::new(Digest::default(), Digest::default());
+    let mut tx_args = data_store.tx_args.clone();
+    tx_args.add_note_group([note_ids[0], missing_note_id]);
+    let err = executor
+        .execute_transaction(account_id, block_ref, &note_ids, tx_args)
+        .unwrap_err();
+    assert_eq!(err, TransactionExecutorError::IncompleteNoteGroup { missing_note_id });
+
+    // the transaction can be executed once the constraints are satisfied by the input notes
+    let mut tx_args = data_store.tx_args.clone();
+    tx_args.add_note_dependency(note_ids[1], note_ids[0]);
+    tx_args.add_note_group(note_ids.iter().copied());
+    tx_args.add_note_group([missing_note_id]);
+    executor.execute_transaction(account_id, block_ref, &note_ids, tx_args).unwrap();
 }
 
-impl DataStore for MockDataStore {
-    fn get_transaction_inputs(
-        &self,
-        account_id: AccountId,
-        block_num: u32,
-        notes: &[NoteId],
-    ) -> Result<TransactionInputs, DataStoreError> {
-        assert_eq!(account_id, self.account.id());
-        assert_eq!(block_num, self.block_header.block_num());
-        assert_eq!(notes.len(), self.notes.len());
+#[test]
+fn test_duplicate_input_note() {
+    let data_store = MockDataStore::default();
+    let account_id = data_store.account.id();
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
 
-        let notes = self
-            .notes
-            .iter()
-            .filter(|note| notes.contains(&note.id()))
-            .cloned()
-            .collect::<Vec<_>>();
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(account_id).unwrap();
 
-        Ok(TransactionInputs::new(
-            self.account.clone(),
-            None,
-            self.block_header,
-            self.block_chain.clone(),
-            InputNotes::new(notes).unwrap(),
-        )
-        .unwrap())
-    }
+    let duplicate_notes = [note_ids[0], note_ids[1], note_ids[0]];
+    let err = executor
+        .execute_transaction(account_id, block_ref, &duplicate_notes, data_store.tx_args.clone())
+        .unwrap_err();
+    assert_eq!(err, TransactionExecutorError::DuplicateInputNote { note_id: note_ids[0] });
+}
 
-    fn get_account_code(&self, account_id: AccountId) -> Result<ModuleAst, DataStoreError> {
-        assert_eq!(account_id, self.account.id());
-        Ok(self.account.code().module().clone())
+#[test]
+fn test_root_only_note_script() {
+    let mut data_store = MockDataStore::default();
+    let account_id = data_store.account.id();
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    // replacing the script of a note with its MAST root does not change the ID of the note
+    let input_note = data_store.notes[0].clone();
+    let note = input_note.note();
+    let script = NoteScript::from_root(note.script().hash());
+    let recipient = NoteRecipient::new(note.serial_num(), script, note.inputs().clone());
+    let root_only_note = Note::new(note.assets().clone(), *note.metadata(), recipient);
+    assert_eq!(root_only_note.id(), note.id());
+    data_store.notes[0] = InputNote::new(root_only_note, input_note.proof().clone());
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(account_id).unwrap();
+
+    let err = executor
+        .execute_transaction(account_id, block_ref, &note_ids, data_store.tx_args.clone())
+        .unwrap_err();
+    assert_eq!(
+        err,
+        TransactionExecutorError::NoteScriptCodeMissing {
+            note_id: note_ids[0],
+            script_root: note.script().hash(),
+        }
+    );
+}
+
+#[test]
+fn test_persisted_transaction() {
+    let data_store = MockDataStore::default();
+    let mut executor = TransactionExecutor::new(data_store.clone());
+
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+    let mut tx_args = data_store.tx_args().clone();
+    tx_args.set_inclusion_hints(InclusionHints::new(Some(block_ref + 10), 100));
+
+    let executed_transaction =
+        executor.execute_transaction(account_id, block_ref, &note_ids, tx_args).unwrap();
+
+    // persist the executed transaction, and restore it with a program compiled by a new compiler,
+    // as if the transaction was restored by another process
+    let bytes = PersistedTransaction::from(executed_transaction.clone()).to_bytes();
+    let persisted_tx = PersistedTransaction::from_bytes(&bytes).unwrap();
+    assert_eq!(persisted_tx.tx_inputs(), executed_transaction.tx_inputs());
+    assert_eq!(persisted_tx.account_delta(), Some(executed_transaction.account_delta()));
+
+    // persisted transactions can also be read via the deserialization traits
+    let deserialized_tx = PersistedTransaction::read_from_bytes(&bytes).unwrap();
+    assert_eq!(deserialized_tx.to_bytes(), bytes);
+
+    let mut compiler = TransactionCompiler::new();
+    let program = compiler.compile_persisted_transaction(&persisted_tx).unwrap();
+    let restored_tx = persisted_tx.clone().into_executed_transaction(program.clone()).unwrap();
+    assert_eq!(restored_tx.id(), executed_transaction.id());
+    assert_eq!(restored_tx.output_notes(), executed_transaction.output_notes());
+
+    // the restored witness can be proven
+    let prover = TransactionProver::new(ProvingOptions::default());
+    let proven_transaction =
+        prover.prove_transaction(persisted_tx.into_witness(program).unwrap()).unwrap();
+    assert_eq!(proven_transaction.validate_against(&executed_transaction), Ok(()));
+    assert_eq!(
+        proven_transaction.inclusion_hints(),
+        InclusionHints::new(Some(block_ref + 10), 100)
+    );
+
+    let verifier = TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL);
+    assert!(verifier.verify(proven_transaction).is_ok());
+
+    // the outputs of a transaction persisted from its witness are not known
+    let witness = TransactionWitness::from(executed_transaction.clone());
+    let persisted_witness =
+        PersistedTransaction::from_bytes(&PersistedTransaction::from(witness).to_bytes()).unwrap();
+    assert_eq!(persisted_witness.account_delta(), None);
+    let program = compiler.compile_persisted_transaction(&persisted_witness).unwrap();
+    assert_eq!(
+        persisted_witness.into_executed_transaction(program).unwrap_err(),
+        PersistedTransactionError::ExecutionOutputsMissing
+    );
+
+    // the program must be the program of the persisted transaction
+    let tx_script = ProgramAst::parse("begin push.1 drop end").unwrap();
+    let other_program = compiler
+        .compile_transaction(account_id, &InputNotes::default(), Some(&tx_script))
+        .unwrap();
+    let persisted_tx = PersistedTransaction::from_bytes(&bytes).unwrap();
+    assert_eq!(
+        persisted_tx
+            .clone()
+            .into_executed_transaction(other_program.clone())
+            .unwrap_err(),
+        PersistedTransactionError::ProgramHashMismatch {
+            expected: executed_transaction.program().hash(),
+            actual: other_program.hash(),
+        }
+    );
+
+    // corrupted bytes, bytes of other format versions, and other data are rejected
+    let mut corrupted = bytes.clone();
+    *corrupted.last_mut().unwrap() ^= 1;
+    assert!(matches!(
+        PersistedTransaction::from_bytes(&corrupted),
+        Err(PersistedTransactionError::BodyCommitmentMismatch { .. })
+    ));
+    assert!(PersistedTransaction::read_from_bytes(&corrupted).is_err());
+
+    let mut other_version = bytes.clone();
+    other_version[4] = PersistedTransaction::FORMAT_VERSION + 1;
+    assert_eq!(
+        PersistedTransaction::from_bytes(&other_version).unwrap_err(),
+        PersistedTransactionError::UnsupportedFormatVersion {
+            supported: PersistedTransaction::FORMAT_VERSION,
+            actual: PersistedTransaction::FORMAT_VERSION + 1,
+        }
+    );
+
+    assert_eq!(
+        PersistedTransaction::from_bytes(&bytes[1..]).unwrap_err(),
+        PersistedTransactionError::InvalidMagic
+    );
+    assert_eq!(
+        PersistedTransaction::from_bytes(&bytes[..10]).unwrap_err(),
+        PersistedTransactionError::MissingHeader(10)
+    );
+}
+
+#[test]
+fn test_partial_vault() {
+    let data_store = MockDataStore::default();
+    let account_id = data_store.account.id();
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(account_id).unwrap();
+    let expected_tx = executor
+        .execute_transaction(account_id, block_ref, &note_ids, data_store.tx_args.clone())
+        .unwrap();
+
+    // track only the assets added by the input notes and provide the account without its vault
+    let vault_keys = data_store
+        .notes
+        .iter()
+        .flat_map(|note| note.note().assets().iter())
+        .map(|asset| asset.vault_key());
+    let mut partial_vault = PartialVault::from_vault(data_store.account.vault(), vault_keys);
+
+    let account = &data_store.account;
+    let mut partial_data_store = data_store.clone();
+    partial_data_store.account = Account::new(
+        account.id(),
+        AssetVault::default(),
+        account.storage().clone(),
+        account.code().clone(),
+        account.nonce(),
+    );
+    partial_data_store.partial_vault = Some(partial_vault.clone());
+
+    let mut executor = TransactionExecutor::new(partial_data_store);
+    executor.load_account(account_id).unwrap();
+    let executed_tx = executor
+        .execute_transaction(account_id, block_ref, &note_ids, data_store.tx_args.clone())
+        .unwrap();
+    assert_eq!(executed_tx.id(), expected_tx.id());
+    assert_eq!(executed_tx.final_account().hash(), expected_tx.final_account().hash());
+
+    // the partial vault is kept consistent with the account by applying the account delta
+    partial_vault.apply_delta(executed_tx.account_delta().vault()).unwrap();
+    assert_eq!(partial_vault.commitment(), executed_tx.final_account().vault_root());
+}
+
+#[test]
+fn test_minimize_inputs() {
+    let data_store = MockDataStore::default();
+    let account_id = data_store.account.id();
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(account_id).unwrap();
+    let expected_tx = executor
+        .execute_transaction(account_id, block_ref, &note_ids, data_store.tx_args.clone())
+        .unwrap();
+
+    // the assets of the input notes are added to the vault of the account
+    let access_set = expected_tx.access_set();
+    assert!(data_store
+        .notes
+        .iter()
+        .flat_map(|note| note.note().assets().iter())
+        .all(|asset| access_set.vault_keys().contains(&Digest::from(asset.vault_key()))));
+
+    // the trimmed inputs track only the accessed vault keys and storage arrays
+    let tx_inputs = expected_tx.tx_inputs().minimize(access_set);
+    let partial_vault = tx_inputs.partial_vault().unwrap();
+    assert!(access_set.vault_keys().iter().all(|&key| partial_vault.is_tracked(key.into())));
+    assert!(tx_inputs.account().vault().assets().next().is_none());
+    assert!(tx_inputs
+        .account()
+        .storage()
+        .arrays()
+        .keys()
+        .all(|index| access_set.storage_slots().contains(index)));
+    assert_eq!(tx_inputs.account_init_hash(), expected_tx.tx_inputs().account_init_hash());
+
+    // the transaction can be re-executed against the trimmed inputs
+    let minimal_data_store = MockDataStore::from_inputs(tx_inputs, data_store.tx_args.clone());
+    let mut executor = TransactionExecutor::new(minimal_data_store);
+    executor.load_account(account_id).unwrap();
+    let executed_tx = executor
+        .execute_transaction(account_id, block_ref, &note_ids, data_store.tx_args.clone())
+        .unwrap();
+    assert_eq!(executed_tx.id(), expected_tx.id());
+    assert_eq!(executed_tx.final_account().hash(), expected_tx.final_account().hash());
+    assert_eq!(executed_tx.access_set(), access_set);
+}
+
+#[test]
+fn test_inclusion_deadline() {
+    let data_store = MockDataStore::default();
+    let account_id = data_store.account.id();
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(account_id).unwrap();
+
+    // the transaction cannot be included into the reference block
+    let mut tx_args = data_store.tx_args.clone();
+    tx_args.set_inclusion_hints(InclusionHints::new(Some(block_ref), 10));
+    let err = executor
+        .execute_transaction(account_id, block_ref, &note_ids, tx_args)
+        .unwrap_err();
+    assert_eq!(
+        err,
+        TransactionExecutorError::InclusionDeadlineExpired { deadline: block_ref, block_ref }
+    );
+
+    // but it can be included into the next block
+    let inclusion_hints = InclusionHints::new(Some(block_ref + 1), 10);
+    assert!(!inclusion_hints.is_expired(block_ref + 1));
+    assert!(inclusion_hints.is_expired(block_ref + 2));
+
+    let mut tx_args = data_store.tx_args.clone();
+    tx_args.set_inclusion_hints(inclusion_hints);
+    let executed_transaction =
+        executor.execute_transaction(account_id, block_ref, &note_ids, tx_args).unwrap();
+    assert_eq!(executed_transaction.tx_args().inclusion_hints(), inclusion_hints);
+}
+
+#[test]
+fn test_post_conditions() {
+    let data_store = MockDataStore::default();
+    let account_id = data_store.account.id();
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(account_id).unwrap();
+    let executed_tx = executor
+        .execute_transaction(account_id, block_ref, &note_ids, data_store.tx_args.clone())
+        .unwrap();
+
+    let num_output_notes = executed_tx.output_notes().num_notes();
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let slot_value: Word = data_store.account.storage().get_item(STORAGE_INDEX_0).into();
+
+    // the transaction is executed if all post-conditions hold
+    let satisfied_conditions = [
+        PostCondition::NumOutputNotes(num_output_notes),
+        PostCondition::MinAssetBalance { faucet_id, amount: 0 },
+        PostCondition::StorageSlotEquals {
+            index: STORAGE_INDEX_0,
+            value: slot_value,
+        },
+    ];
+    let tx_args = satisfied_conditions
+        .into_iter()
+        .fold(data_store.tx_args.clone(), |tx_args, condition| {
+            tx_args.with_post_condition(condition)
+        });
+    executor.execute_transaction(account_id, block_ref, &note_ids, tx_args).unwrap();
+
+    // and rejected if any of them is violated
+    let violated_conditions = [
+        PostCondition::NumOutputNotes(num_output_notes + 1),
+        PostCondition::MinAssetBalance { faucet_id, amount: u64::MAX },
+        PostCondition::StorageSlotEquals { index: STORAGE_INDEX_0, value: [ONE; 4] },
+    ];
+    for post_condition in violated_conditions {
+        let tx_args = data_store.tx_args.clone().with_post_condition(post_condition);
+        let err = executor
+            .execute_transaction(account_id, block_ref, &note_ids, tx_args)
+            .unwrap_err();
+        assert_eq!(err, TransactionExecutorError::PostConditionViolated { post_condition });
+    }
+}
+
+#[test]
+fn test_collect_unused_advice() {
+    let data_store = MockDataStore::default();
+    let account_id = data_store.account.id();
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let used_key = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+    let unused_key = [Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)];
+    let tx_script_source = format!(
+        "
+    begin
+        push.{used_key}
+        adv.push_mapval
+        dropw
+        adv_push.4
+        dropw
+    end
+",
+        used_key = prepare_word(&used_key)
+    );
+    let tx_script_code = ProgramAst::parse(&tx_script_source).unwrap();
+    let tx_script_inputs = vec![(used_key, vec![ZERO; 4]), (unused_key, vec![ZERO; 4])];
+
+    // unused advice inputs are not collected by default
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(account_id).unwrap();
+    let tx_script = executor.compile_tx_script(tx_script_code, tx_script_inputs, vec![]).unwrap();
+    let tx_args =
+        TransactionArgs::new(Some(tx_script), None, data_store.tx_args.advice_map().clone());
+    let executed_transaction = executor
+        .execute_transaction(account_id, block_ref, &note_ids, tx_args.clone())
+        .unwrap();
+    assert!(executed_transaction.unused_advice().is_none());
+
+    let mut executor =
+        TransactionExecutor::new(data_store.clone()).with_unused_advice_collection(true);
+    executor.load_account(account_id).unwrap();
+    let executed_transaction =
+        executor.execute_transaction(account_id, block_ref, &note_ids, tx_args).unwrap();
+    let unused_advice = executed_transaction.unused_advice().unwrap();
+
+    assert!(unused_advice.map_keys().contains(&Digest::from(unused_key)));
+    assert!(!unused_advice.map_keys().contains(&Digest::from(used_key)));
+
+    // none of the unused Merkle nodes is part of the advice witness
+    let advice_witness = executed_transaction.advice_witness();
+    assert!(!unused_advice.merkle_nodes().is_empty());
+    assert!(advice_witness
+        .merkle_store()
+        .inner_nodes()
+        .all(|node| !unused_advice.merkle_nodes().contains(&node.value)));
+}
+
+#[test]
+fn test_note_cycle_budget() {
+    let data_store = MockDataStore::default();
+    let account_id = data_store.account.id();
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    // the cycles consumed by every note script are measured during execution
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(account_id).unwrap();
+    let executed_transaction = executor
+        .execute_transaction(account_id, block_ref, &note_ids, data_store.tx_args.clone())
+        .unwrap();
+    let measurements = executed_transaction.measurements();
+    let measured_notes = measurements.note_cycles().iter().map(|(id, _)| *id).collect::<Vec<_>>();
+    assert_eq!(measured_notes, note_ids);
+    assert!(note_ids.iter().all(|id| measurements.cycles_of_note(*id).unwrap() > 0));
+    let max_note_cycles = measurements.max_note_cycles();
+
+    // a budget which covers the most expensive note script does not affect execution
+    let mut executor =
+        TransactionExecutor::new(data_store.clone()).with_note_cycle_budget(max_note_cycles + 100);
+    executor.load_account(account_id).unwrap();
+    let executed_transaction = executor
+        .execute_transaction(account_id, block_ref, &note_ids, data_store.tx_args.clone())
+        .unwrap();
+    assert_eq!(executed_transaction.tx_args().note_cycle_budget(), Some(max_note_cycles + 100));
+
+    // the kernel aborts the transaction if a note script exceeds the budget
+    let mut executor =
+        TransactionExecutor::new(data_store.clone()).with_note_cycle_budget(max_note_cycles / 2);
+    executor.load_account(account_id).unwrap();
+    let err = executor
+        .execute_transaction(account_id, block_ref, &note_ids, data_store.tx_args.clone())
+        .unwrap_err();
+    assert_eq!(err.kernel_error(), Some(KernelError::NoteCycleBudgetExceeded));
+
+    // the failure is attributed to the first note whose script exceeds the budget
+    let first_failing_note = note_ids
+        .iter()
+        .position(|id| measurements.cycles_of_note(*id).unwrap() > max_note_cycles / 2);
+    assert_eq!(err.failed_note_index(), first_failing_note);
+
+    // the budget of the executor caps the budget requested by the transaction arguments
+    let mut tx_args = data_store.tx_args.clone();
+    tx_args.set_note_cycle_budget(max_note_cycles + 100);
+    let err = executor
+        .execute_transaction(account_id, block_ref, &note_ids, tx_args)
+        .unwrap_err();
+    assert_eq!(err.kernel_error(), Some(KernelError::NoteCycleBudgetExceeded));
+}
+
+#[test]
+fn test_merge_output_notes() {
+    let data_store = MockDataStore::new(AssetPreservationStatus::PreservedWithAccountVaultDelta);
+    let account_id = data_store.account.id();
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let faucet_id_1 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let faucet_id_2 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2).unwrap();
+    let asset =
+        |faucet_id, amount| -> Asset { FungibleAsset::new(faucet_id, amount).unwrap().into() };
+    let recipient = Digest::from([ZERO, ONE, Felt::new(2), Felt::new(3)]);
+
+    // send three assets with the same tag and one asset with a different tag to the same recipient
+    let sent_assets = [
+        (999, asset(faucet_id_1, FUNGIBLE_ASSET_AMOUNT / 4)),
+        (999, asset(faucet_id_2, FUNGIBLE_ASSET_AMOUNT / 2)),
+        (999, asset(faucet_id_1, FUNGIBLE_ASSET_AMOUNT / 4)),
+        (998, asset(faucet_id_2, FUNGIBLE_ASSET_AMOUNT / 2)),
+    ];
+    let send_assets = sent_assets
+        .iter()
+        .map(|(tag, asset)| {
+            format!(
+                "
+            push.{recipient}
+            push.{OFFCHAIN}
+            push.{tag}
+            push.{asset}
+            call.wallet::send_asset dropw dropw drop drop
+            ",
+                recipient = prepare_word(&recipient),
+                OFFCHAIN = NoteType::OffChain as u8,
+                asset = prepare_word(&Word::from(*asset)),
+            )
+        })
+        .collect::<String>();
+    let tx_script = format!(
+        "
+        use.miden::contracts::wallets::basic->wallet
+
+        begin
+            {send_assets}
+            push.1 call.{ACCOUNT_INCR_NONCE_MAST_ROOT} drop
+        end
+        "
+    );
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(account_id).unwrap();
+    let tx_script = executor
+        .compile_tx_script(ProgramAst::parse(&tx_script).unwrap(), vec![], vec![])
+        .unwrap();
+    let tx_args =
+        TransactionArgs::new(Some(tx_script), None, data_store.tx_args.advice_map().clone());
+
+    // without merging, a note is created for every asset sent
+    let executed_transaction = executor
+        .execute_transaction(account_id, block_ref, &note_ids, tx_args.clone())
+        .unwrap();
+    assert_eq!(executed_transaction.output_notes().num_notes(), sent_assets.len());
+
+    // with merging, the assets sent with the same tag are combined into a single note
+    let mut executor = TransactionExecutor::new(data_store.clone()).with_output_note_merging(true);
+    executor.load_account(account_id).unwrap();
+    let executed_transaction =
+        executor.execute_transaction(account_id, block_ref, &note_ids, tx_args).unwrap();
+    assert!(executed_transaction.tx_args().merge_output_notes());
+
+    let merged_assets = NoteAssets::new(vec![
+        asset(faucet_id_1, FUNGIBLE_ASSET_AMOUNT / 2),
+        asset(faucet_id_2, FUNGIBLE_ASSET_AMOUNT / 2),
+    ])
+    .unwrap();
+    let single_assets =
+        NoteAssets::new(vec![asset(faucet_id_2, FUNGIBLE_ASSET_AMOUNT / 2)]).unwrap();
+    let output_notes = executed_transaction.output_notes();
+    assert_eq!(output_notes.num_notes(), 2);
+    assert_eq!(
+        output_notes.get_note(0).id(),
+        NoteId::new(recipient, merged_assets.commitment())
+    );
+    assert_eq!(
+        output_notes.get_note(1).id(),
+        NoteId::new(recipient, single_assets.commitment())
+    );
+}
+
+#[test]
+fn test_tx_script_emit_event() {
+    let mut data_store = MockDataStore::default();
+
+    // log entries can only be recorded by account procedures, so we replace the code of the mock
+    // account with code which exposes a procedure recording the provided entry
+    let account_code_src = "
+    use.miden::tx
+
+    export.emit_log_entry
+        exec.tx::emit_event
+    end
+    ";
+    let account_code_ast = ModuleAst::parse(account_code_src).unwrap();
+    let account_code = AccountCode::new(account_code_ast, &TransactionKernel::assembler()).unwrap();
+    let emit_log_entry_root = account_code.procedures()[0].to_hex();
+
+    let account = data_store.account.clone();
+    data_store.account = Account::new(
+        account.id(),
+        account.vault().clone(),
+        account.storage().clone(),
+        account_code,
+        account.nonce(),
+    );
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let entry_1 = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+    let entry_2 = [Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)];
+    let tx_script_source = format!(
+        "
+    begin
+        push.{entry_1}
+        call.{emit_log_entry_root}
+
+        push.{entry_2}
+        call.{emit_log_entry_root}
+    end
+",
+        entry_1 = prepare_word(&entry_1),
+        entry_2 = prepare_word(&entry_2)
+    );
+    let tx_script_code = ProgramAst::parse(&tx_script_source).unwrap();
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+    let tx_args =
+        TransactionArgs::new(Some(tx_script), None, data_store.tx_args.advice_map().clone());
+
+    let executed_transaction =
+        executor.execute_transaction(account_id, block_ref, &note_ids, tx_args).unwrap();
+
+    let log = executed_transaction.log();
+    assert_eq!(log.num_entries(), 2);
+    assert_eq!(log.get_entry(0), &TxLogEntry::new(entry_1));
+    assert_eq!(log.get_entry(1), &TxLogEntry::new(entry_2));
+
+    // recording an entry directly from the transaction script is not allowed
+    let tx_script_source = format!(
+        "
+    use.miden::tx
+
+    begin
+        push.{entry_1}
+        exec.tx::emit_event
+    end
+",
+        entry_1 = prepare_word(&entry_1)
+    );
+    let tx_script_code = ProgramAst::parse(&tx_script_source).unwrap();
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+    let tx_args =
+        TransactionArgs::new(Some(tx_script), None, data_store.tx_args.advice_map().clone());
+
+    let result = executor.execute_transaction(account_id, block_ref, &note_ids, tx_args);
+    assert!(matches!(result, Err(TransactionExecutorError::ExecutionFailure { .. })));
+}
+
+#[test]
+fn test_guarded_account_procedure() {
+    let mut data_store = MockDataStore::default();
+
+    // replace the code of the mock account with code exposing a guarded procedure which invokes
+    // the procedure with the root provided via the stack
+    let account_code_src = "
+    export.invoke_guarded
+        dyncall
+        dropw
+    end
+
+    export.noop
+        push.0 drop
+    end
+    ";
+    let account_code_ast = ModuleAst::parse(account_code_src).unwrap();
+    let assembler = TransactionKernel::assembler();
+    let account_code = AccountCode::with_guarded_procedures(
+        account_code_ast.clone(),
+        &["invoke_guarded"],
+        &assembler,
+    )
+    .unwrap();
+    assert_eq!(account_code.is_procedure_guarded(0), Some(true));
+    assert_eq!(account_code.is_procedure_guarded(1), Some(false));
+    assert_eq!(account_code.is_procedure_guarded(2), None);
+
+    // the guard is derived from the module, and thus it survives serialization
+    let deserialized_code = AccountCode::read_from_bytes(&account_code.to_bytes()).unwrap();
+    assert_eq!(
+        deserialized_code.guarded_procedures().collect::<Vec<_>>(),
+        [account_code.procedures()[0]]
+    );
+
+    // the guard is a part of the code of the procedure
+    let unguarded_code = AccountCode::new(account_code_ast, &assembler).unwrap();
+    assert_ne!(account_code.procedures()[0], unguarded_code.procedures()[0]);
+    assert_eq!(account_code.procedures()[1], unguarded_code.procedures()[1]);
+
+    let invoke_guarded_root = account_code.procedures()[0];
+    let noop_root = account_code.procedures()[1];
+
+    let account = data_store.account.clone();
+    data_store.account = Account::new(
+        account.id(),
+        account.vault().clone(),
+        account.storage().clone(),
+        account_code,
+        account.nonce(),
+    );
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let execute = |executor: &mut TransactionExecutor<MockDataStore>, invoked_root: Digest| {
+        let tx_script_source = format!(
+            "
+        begin
+            push.{invoked_root}
+            call.{invoke_guarded_root}
+            dropw
+        end
+        ",
+            invoked_root = prepare_word(&invoked_root),
+            invoke_guarded_root = invoke_guarded_root.to_hex(),
+        );
+        let tx_script_code = ProgramAst::parse(&tx_script_source).unwrap();
+        let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+        let tx_args =
+            TransactionArgs::new(Some(tx_script), None, data_store.tx_args.advice_map().clone());
+
+        executor.execute_transaction(account_id, block_ref, &note_ids, tx_args)
+    };
+
+    // the guarded procedure can invoke other account procedures
+    assert!(execute(&mut executor, noop_root).is_ok());
+
+    // but it cannot be re-entered while it is being executed
+    let result = execute(&mut executor, invoke_guarded_root);
+    assert_eq!(result.unwrap_err().kernel_error(), Some(KernelError::AccountProcReentrantCall));
+}
+
+#[test]
+fn test_tx_script_advice_injector() {
+    const ORACLE_EVENT: u32 = 1000;
+
+    let data_store = MockDataStore::default();
+
+    let mut advice_injectors = AdviceInjectors::new();
+    advice_injectors
+        .register(ORACLE_EVENT, |stack| Ok(vec![stack[0] + stack[0], Felt::new(7)]))
+        .unwrap();
+    assert_eq!(
+        advice_injectors.register(ORACLE_EVENT, |_| Ok(vec![])),
+        Err(AdviceInjectorError::DuplicateEventId(ORACLE_EVENT))
+    );
+    let kernel_event = TransactionEvent::TxLogEntry as u32;
+    assert_eq!(
+        advice_injectors.register(kernel_event, |_| Ok(vec![])),
+        Err(AdviceInjectorError::ReservedEventId(kernel_event))
+    );
+
+    let mut executor =
+        TransactionExecutor::new(data_store.clone()).with_advice_injectors(advice_injectors);
+
+    let account_id = data_store.account.id();
+    executor.load_account(account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let tx_script_source = format!(
+        "
+    begin
+        push.5
+        emit.{ORACLE_EVENT}
+        drop
+        adv_push.2
+        push.7 assert_eq
+        push.10 assert_eq
+    end
+"
+    );
+    let tx_script_code = ProgramAst::parse(&tx_script_source).unwrap();
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+    let tx_args =
+        TransactionArgs::new(Some(tx_script), None, data_store.tx_args.advice_map().clone());
+
+    executor
+        .execute_transaction(account_id, block_ref, &note_ids, tx_args.clone())
+        .unwrap();
+
+    // without the advice injector the event cannot be handled
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(account_id).unwrap();
+    let result = executor.execute_transaction(account_id, block_ref, &note_ids, tx_args);
+    assert!(matches!(result, Err(TransactionExecutorError::ExecutionFailure { .. })));
+}
+
+// TEST NOTE SCANNER
+// ================================================================================================
+
+#[test]
+fn test_note_scanner() {
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let target = AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+    let rng = |seed| RpoRandomCoin::new([Felt::new(seed), ZERO, ZERO, ZERO]);
+
+    // notes 0 and 1 target the scanning account, note 2 is irrelevant, and the details of note 3
+    // are known to the scanning account in advance
+    let notes = [
+        create_p2id_note(sender, target, vec![asset], NoteType::Public, rng(1)).unwrap(),
+        create_p2id_note(sender, target, vec![asset], NoteType::OffChain, rng(2)).unwrap(),
+        create_p2id_note(target, sender, vec![asset], NoteType::Public, rng(3)).unwrap(),
+        create_p2id_note(target, sender, vec![asset], NoteType::OffChain, rng(4)).unwrap(),
+    ];
+    assert_ne!(notes[0].metadata().tag(), notes[2].metadata().tag());
+
+    let note_tree = SimpleSmt::<NOTE_TREE_DEPTH>::with_leaves(
+        notes
+            .iter()
+            .enumerate()
+            .map(|(idx, note)| (idx as u64, note.authentication_hash().into())),
+    )
+    .unwrap();
+    let block_header = mock_block_header(5, None, Some(note_tree.root()), &[]);
+
+    let block_note = |idx: usize, path_idx: u64, details: Option<Note>| {
+        let path = note_tree.open(&LeafIndex::new(path_idx).unwrap()).path;
+        BlockNote::new(idx as u64, NoteEnvelope::from(&notes[idx]), details, path)
+    };
+    let block_notes = vec![
+        block_note(0, 0, Some(notes[0].clone())),
+        block_note(1, 1, None),
+        block_note(2, 2, Some(notes[2].clone())),
+        block_note(3, 3, None),
+    ];
+
+    let mut scanner = NoteScanner::new();
+    scanner.track_account(target).unwrap();
+    scanner.track_note(notes[3].clone());
+
+    let discovered = scanner
+        .scan([(block_header, block_notes)])
+        .collect::<Result<Vec<_>, _>>()
+        .unwrap();
+
+    assert_eq!(
+        discovered.iter().map(DiscoveredNote::id).collect::<Vec<_>>(),
+        vec![notes[0].id(), notes[1].id(), notes[3].id()]
+    );
+    assert!(matches!(discovered[1], DiscoveredNote::DetailsUnknown(..)));
+    assert!(discovered.iter().all(|note| note.proof().origin().block_num == 5));
+
+    let input_note = discovered[2].clone().into_input_note().unwrap();
+    assert_eq!(input_note.note(), &notes[3]);
+    assert_eq!(input_note.proof().note_root(), note_tree.root());
+
+    // relevant notes with invalid inclusion proofs or details are rejected
+    assert_eq!(
+        scanner.scan_block(&block_header, vec![block_note(0, 2, None)]),
+        Err(NoteScannerError::InvalidNoteInclusionProof(notes[0].id()))
+    );
+    assert_eq!(
+        scanner.scan_block(&block_header, vec![block_note(1, 1, Some(notes[0].clone()))]),
+        Err(NoteScannerError::NoteDetailsMismatch(notes[1].id()))
+    );
+
+    // irrelevant notes are not checked
+    assert_eq!(scanner.scan_block(&block_header, vec![block_note(2, 0, None)]), Ok(vec![]));
+
+    // notes can also be discovered via additional relevance checks, e.g., by their scripts
+    let script_root = notes[2].script().hash();
+    let mut scanner = NoteScanner::new();
+    scanner.add_relevance_check(ScriptRootSet::new([script_root]));
+    assert_eq!(
+        scanner
+            .check_relevance(&NoteEnvelope::from(&notes[2]), Some(&notes[2]))
+            .reason(),
+        Some(RelevanceReason::ScriptRootMatch(script_root))
+    );
+
+    // the script of a note is only known if its details are known
+    let discovered = scanner
+        .scan_block(
+            &block_header,
+            vec![block_note(2, 2, Some(notes[2].clone())), block_note(3, 3, None)],
+        )
+        .unwrap();
+    assert_eq!(
+        discovered.iter().map(DiscoveredNote::id).collect::<Vec<_>>(),
+        vec![notes[2].id()]
+    );
+}
+
+// NOTE LOOKUPS
+// ================================================================================================
+
+#[test]
+fn test_note_lookups() {
+    let (tx_inputs, _) =
+        mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
+    let input_notes = tx_inputs.input_notes();
+    for note in input_notes.iter() {
+        assert_eq!(input_notes.find_by_recipient(&note.recipient_digest()), Some(note));
+    }
+    assert_eq!(input_notes.find_by_recipient(&Digest::default()), None);
+
+    // recipients are not known once the notes are reduced to nullifiers
+    let nullifiers = InputNotes::<Nullifier>::from(input_notes.clone());
+    let recipient = input_notes.get_note(0).recipient_digest();
+    assert_eq!(nullifiers.find_by_recipient(&recipient), None);
+
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let target = AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+    let rng = |seed| RpoRandomCoin::new([Felt::new(seed), ZERO, ZERO, ZERO]);
+
+    let public = create_p2id_note(sender, target, vec![asset], NoteType::Public, rng(1)).unwrap();
+    let private =
+        create_p2id_note(sender, target, vec![asset], NoteType::OffChain, rng(2)).unwrap();
+    let output_notes = OutputNotes::new(vec![
+        OutputNote::from(public.clone()),
+        OutputNote::from(NoteEnvelope::from(&private)),
+    ])
+    .unwrap();
+
+    assert_eq!(output_notes.find_by_id(&public.id()), Some(output_notes.get_note(0)));
+    assert_eq!(output_notes.find_by_id(&private.id()), Some(output_notes.get_note(1)));
+    assert_eq!(output_notes.find_by_id(&input_notes.get_note(0).id()), None);
+
+    // only the recipients of public notes are known
+    assert_eq!(output_notes.get_note(0).recipient_digest(), Some(public.recipient_digest()));
+    assert_eq!(output_notes.get_note(1).recipient_digest(), None);
+    assert_eq!(
+        output_notes.find_by_recipient(&public.recipient_digest()),
+        Some(output_notes.get_note(0))
+    );
+    assert_eq!(output_notes.find_by_recipient(&private.recipient_digest()), None);
+}
+
+#[test]
+fn test_estimate_fee() {
+    let fee_faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let kernel_config = KernelConfig::new(
+        TX_KERNEL_VERSION,
+        KernelConfig::ALL_FEATURES,
+        FeeParameters::new(20, 1).with_native_asset(fee_faucet_id),
+        KernelLimits::default(),
+    )
+    .unwrap();
+    let data_store = MockDataStore::with_kernel_config(kernel_config);
+    let account_id = data_store.account.id();
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(account_id).unwrap();
+
+    // paying the fee changes the account vault, and so the nonce must be incremented
+    let tx_script_code = ProgramAst::parse(&format!(
+        "
+        begin
+            push.1 call.{ACCOUNT_INCR_NONCE_MAST_ROOT} drop
+        end
+        "
+    ))
+    .unwrap();
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![], vec![]).unwrap();
+    let mut tx_args =
+        TransactionArgs::new(Some(tx_script), None, data_store.tx_args.advice_map().clone());
+    tx_args.set_inclusion_hints(InclusionHints::new(None, 5));
+    let estimate = executor
+        .estimate_fee(account_id, block_ref, &note_ids, tx_args.clone())
+        .unwrap();
+
+    // the estimate matches the fee of the executed transaction
+    let executed_transaction =
+        executor.execute_transaction(account_id, block_ref, &note_ids, tx_args).unwrap();
+    let num_notes = executed_transaction.input_notes().num_notes()
+        + executed_transaction.output_notes().num_notes();
+    assert_eq!(estimate.num_input_notes(), data_store.notes.len());
+    assert_eq!(estimate.num_output_notes(), executed_transaction.output_notes().num_notes());
+    assert_eq!(estimate.required_fee(), 20 + num_notes as u64);
+    assert_eq!(estimate.total(), estimate.required_fee() + 5);
+
+    // the required fee is deducted from the account vault in the native fee asset
+    let fee = Asset::from(FungibleAsset::new(fee_faucet_id, estimate.required_fee()).unwrap());
+    assert_eq!(executed_transaction.account_delta().vault().removed_assets, vec![fee]);
+}
+
+#[test]
+fn test_unaccepted_fee_asset() {
+    let data_store = MockDataStore::default();
+    let account_id = data_store.account.id();
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(account_id).unwrap();
+
+    // the default kernel config does not accept any fee asset besides the native one
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2).unwrap();
+    let witness =
+        FeeConversionWitness::from_account(&data_store.account, MerklePath::new(vec![]), 0);
+    let mut tx_args = data_store.tx_args.clone();
+    tx_args.set_fee_payment(FeePayment::converted(faucet_id, witness));
+    let err = executor
+        .execute_transaction(account_id, block_ref, &note_ids, tx_args)
+        .unwrap_err();
+    assert_eq!(err, TransactionExecutorError::UnacceptedFeeAsset { faucet_id });
+}
+
+#[test]
+fn test_describe() {
+    let data_store = MockDataStore::default();
+    let account_id = data_store.account.id();
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(account_id).unwrap();
+    let executed_transaction = executor
+        .execute_transaction(account_id, block_ref, &note_ids, data_store.tx_args.clone())
+        .unwrap();
+
+    let summary = executed_transaction.describe(&standard_script_registry().unwrap());
+    assert_eq!(summary.account_id(), account_id);
+
+    // all mock notes were created by the same sender, and the fungible assets of every faucet are
+    // merged into a single asset
+    let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    assert_eq!(summary.assets_in().len(), 1);
+    assert_eq!(summary.assets_in()[0].counterparty(), Counterparty::Sender(sender));
+    let total_in = |faucet_id: AccountId| -> u64 {
+        data_store
+            .notes
+            .iter()
+            .flat_map(|note| note.note().assets().iter())
+            .filter_map(|asset| match asset {
+                Asset::Fungible(asset) if asset.faucet_id() == faucet_id => Some(asset.amount()),
+                _ => None,
+            })
+            .sum()
+    };
+    for asset in summary.assets_in()[0].assets() {
+        if let Asset::Fungible(asset) = asset {
+            assert_eq!(asset.amount(), total_in(asset.faucet_id()));
+        }
+    }
+
+    // the assets out and the created notes match the output notes of the transaction
+    let output_notes = executed_transaction.output_notes();
+    assert_eq!(summary.created_notes().len(), output_notes.num_notes());
+    for (created_note, output_note) in summary.created_notes().iter().zip(output_notes.iter()) {
+        assert_eq!(created_note.id(), output_note.id());
+        assert_eq!(
+            created_note.assets(),
+            output_note
+                .assets()
+                .map(|assets| assets.iter().copied().collect::<Vec<_>>())
+                .as_deref()
+        );
+        // the mock note scripts are not standard scripts
+        assert_eq!(created_note.script(), None);
+    }
+    for transfer in summary.assets_out() {
+        let Counterparty::Recipient(recipient) = transfer.counterparty() else {
+            panic!("assets out must be sent to a recipient");
+        };
+        assert!(output_notes.find_by_recipient(&recipient).is_some());
+    }
+
+    // the vault and storage changes and the fee match the transaction
+    let delta = executed_transaction.account_delta();
+    assert_eq!(summary.received(), delta.vault().added_assets.as_slice());
+    assert_eq!(summary.sent(), delta.vault().removed_assets.as_slice());
+    assert_eq!(
+        summary.storage_changes().len(),
+        delta.storage().cleared_items.len()
+            + delta.storage().updated_items.len()
+            + delta.storage().updated_array_items.len()
+    );
+    let fee = FeeEstimate::new(
+        &data_store.kernel_config,
+        executed_transaction.input_notes().num_notes(),
+        output_notes.num_notes(),
+        data_store.tx_args.inclusion_hints().priority_fee(),
+    );
+    assert_eq!(summary.fee(), fee.total());
+
+    // the summary can be serialized for rendering on another device
+    assert_eq!(summary, TxSummary::read_from_bytes(&summary.to_bytes()).unwrap());
+}
+
+#[test]
+fn test_transaction_inputs_builder() {
+    let data_store = MockDataStore::default();
+    let account_id = data_store.account.id();
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    // the inputs assembled from the individual pieces match the inputs of the data store
+    let tx_inputs = TransactionInputsBuilder::new(account_id, block_ref)
+        .with_notes(note_ids.iter().copied())
+        .build(&data_store)
+        .unwrap();
+    let expected = data_store.get_transaction_inputs(account_id, block_ref, &note_ids).unwrap();
+    assert_eq!(tx_inputs.account(), expected.account());
+    assert_eq!(tx_inputs.block_header(), expected.block_header());
+    assert_eq!(tx_inputs.kernel_config(), expected.kernel_config());
+    assert_eq!(tx_inputs.input_notes(), expected.input_notes());
+    assert_eq!(tx_inputs.block_chain().peaks(), expected.block_chain().peaks());
+    for note in tx_inputs.input_notes().iter() {
+        let block_num = note.origin().block_num;
+        assert!(block_num == block_ref || tx_inputs.block_chain().contains_block(block_num));
+    }
+
+    // a note unknown to the store is reported as missing
+    let unknown_note_id = NoteId::new(Digest::default(), Digest::default());
+    let result = TransactionInputsBuilder::new(account_id, block_ref)
+        .with_notes(note_ids.iter().copied())
+        .with_note(unknown_note_id)
+        .build(&data_store);
+    assert_eq!(result, Err(DataStoreError::NoteNotFound(unknown_note_id)));
+
+    // the same note cannot be consumed twice
+    let result = TransactionInputsBuilder::new(account_id, block_ref)
+        .with_note(note_ids[0])
+        .with_note(note_ids[0])
+        .build(&data_store);
+    assert!(matches!(result, Err(DataStoreError::InvalidTransactionInput(_))));
+}
+
+// MOCK DATA STORE
+// ================================================================================================
+
+#[derive(Clone)]
+struct MockDataStore {
+    pub account: Account,
+    pub block_header: BlockHeader,
+    pub block_chain: ChainMmr,
+    pub notes: Vec<InputNote>,
+    pub tx_args: TransactionArgs,
+    pub partial_vault: Option<PartialVault>,
+    pub kernel_config: KernelConfig,
+}
+
+impl MockDataStore {
+    pub fn new(asset_preservation: AssetPreservationStatus) -> Self {
+        let (tx_inputs, tx_args) =
+            mock_inputs(MockAccountType::StandardExisting, asset_preservation);
+        Self::from_inputs(tx_inputs, tx_args)
+    }
+
+    pub fn with_kernel_config(kernel_config: KernelConfig) -> Self {
+        let (tx_inputs, tx_args) = mock_inputs_with_kernel_config(
+            MockAccountType::StandardExisting,
+            AssetPreservationStatus::Preserved,
+            kernel_config,
+        );
+        Self::from_inputs(tx_inputs, tx_args)
+    }
+
+    fn from_inputs(tx_inputs: TransactionInputs, tx_args: TransactionArgs) -> Self {
+        let partial_vault = tx_inputs.partial_vault().cloned();
+        let (account, _, block_header, kernel_config, block_chain, notes) = tx_inputs.into_parts();
+
+        Self {
+            account,
+            block_header,
+            block_chain,
+            notes: notes.into_vec(),
+            tx_args,
+            partial_vault,
+            kernel_config,
+        }
+    }
+
+    fn tx_args(&self) -> &TransactionArgs {
+        &self.tx_args
+    }
+}
+
+impl Default for MockDataStore {
+    fn default() -> Self {
+        Self::new(AssetPreservationStatus::Preserved)
+    }
+}
+
+impl DataStore for MockDataStore {
+    fn get_transaction_inputs(
+        &self,
+        account_id: AccountId,
+        block_num: u32,
+        notes: &[NoteId],
+    ) -> Result<TransactionInputs, DataStoreError> {
+        assert_eq!(account_id, self.account.id());
+        assert_eq!(block_num, self.block_header.block_num());
+        assert_eq!(notes.len(), self.notes.len());
+
+        let notes = self
+            .notes
+            .iter()
+            .filter(|note| notes.contains(&note.id()))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let tx_inputs = TransactionInputs::new(
+            self.account.clone(),
+            None,
+            self.block_header,
+            self.kernel_config.clone(),
+            self.block_chain.clone(),
+            InputNotes::new(notes).unwrap(),
+        )
+        .unwrap();
+
+        match &self.partial_vault {
+            Some(partial_vault) => Ok(tx_inputs.with_partial_vault(partial_vault.clone()).unwrap()),
+            None => Ok(tx_inputs),
+        }
+    }
+
+    fn get_account_code(&self, account_id: AccountId) -> Result<ModuleAst, DataStoreError> {
+        assert_eq!(account_id, self.account.id());
+        Ok(self.account.code().module().clone())
+    }
+}
+
+impl WitnessStore for MockDataStore {
+    fn get_account(
+        &self,
+        account_id: AccountId,
+    ) -> Result<(Account, Option<Word>), DataStoreError> {
+        if account_id != self.account.id() {
+            return Err(DataStoreError::AccountNotFound(account_id));
+        }
+        Ok((self.account.clone(), None))
+    }
+
+    fn get_block_header(&self, block_num: u32) -> Result<BlockHeader, DataStoreError> {
+        if block_num == self.block_header.block_num() {
+            return Ok(self.block_header);
+        }
+        self.block_chain
+            .get_block(block_num)
+            .copied()
+            .ok_or(DataStoreError::BlockNotFound(block_num))
+    }
+
+    fn get_chain_peaks(&self, block_ref: u32) -> Result<MmrPeaks, DataStoreError> {
+        if block_ref != self.block_header.block_num() {
+            return Err(DataStoreError::BlockNotFound(block_ref));
+        }
+        Ok(self.block_chain.peaks())
+    }
+
+    fn get_block_path(&self, block_num: u32, block_ref: u32) -> Result<MerklePath, DataStoreError> {
+        if block_ref != self.block_header.block_num() {
+            return Err(DataStoreError::BlockNotFound(block_ref));
+        }
+        self.block_chain
+            .get_block_path(block_num)
+            .ok_or(DataStoreError::BlockNotFound(block_num))
+    }
+
+    fn get_input_note(&self, note_id: NoteId) -> Result<InputNote, DataStoreError> {
+        self.notes
+            .iter()
+            .find(|note| note.id() == note_id)
+            .cloned()
+            .ok_or(DataStoreError::NoteNotFound(note_id))
+    }
+
+    fn get_kernel_config(&self, _commitment: Digest) -> Result<KernelConfig, DataStoreError> {
+        Ok(self.kernel_config.clone())
     }
 }
@@ -1,6 +1,10 @@
 use miden_lib::transaction::TransactionKernel;
-use miden_objects::{transaction::ProvenTransaction, vm::ProgramInfo};
-use miden_verifier::verify;
+use miden_objects::{
+    transaction::{ProvenTransaction, ProvenTransactionHeader, TransactionId},
+    utils::serde::Deserializable,
+    vm::ProgramInfo,
+};
+use miden_verifier::{verify, ExecutionProof};
 
 use super::TransactionVerifierError;
 
@@ -31,27 +35,38 @@ impl TransactionVerifier {
     /// - Transaction verification fails.
     /// - The security level of the verified proof is insufficient.
     pub fn verify(&self, transaction: ProvenTransaction) -> Result<(), TransactionVerifierError> {
+        let (header, proof) = transaction.split_proof();
+        self.verify_proof(&header, proof)
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Verifies the provided proof against the public inputs and outputs of the transaction
+    /// described by the provided header.
+    fn verify_proof(
+        &self,
+        header: &ProvenTransactionHeader,
+        proof: ExecutionProof,
+    ) -> Result<(), TransactionVerifierError> {
         // build stack inputs and outputs
         let stack_inputs = TransactionKernel::build_input_stack(
-            transaction.account_id(),
-            transaction.initial_account_hash(),
-            transaction.input_notes().commitment(),
-            transaction.block_ref(),
+            header.account_id(),
+            header.initial_account_hash(),
+            header.input_notes().commitment(),
+            header.block_ref(),
         );
         let stack_outputs = TransactionKernel::build_output_stack(
-            transaction.final_account_hash(),
-            transaction.output_notes().commitment(),
-            transaction.tx_script_root(),
+            header.final_account_hash(),
+            header.output_notes().commitment(),
+            header.tx_script_root(),
+            header.log().commitment(),
         );
 
         // verify transaction proof
-        let proof_security_level = verify(
-            self.tx_program_info.clone(),
-            stack_inputs,
-            stack_outputs,
-            transaction.proof().clone(),
-        )
-        .map_err(TransactionVerifierError::TransactionVerificationFailed)?;
+        let proof_security_level =
+            verify(self.tx_program_info.clone(), stack_inputs, stack_outputs, proof)
+                .map_err(TransactionVerifierError::TransactionVerificationFailed)?;
 
         // check security level
         if proof_security_level < self.proof_security_level {
@@ -64,3 +79,35 @@ impl TransactionVerifier {
         Ok(())
     }
 }
+
+// STATELESS VERIFICATION
+// ================================================================================================
+
+/// Verifies a transaction proof against the public inputs of the transaction and returns the ID
+/// of the verified transaction.
+///
+/// The `proof_bytes` must be a serialized [ExecutionProof], and the `public_inputs_bytes` a
+/// serialized [ProvenTransactionHeader], i.e., the two parts of a serialized [ProvenTransaction]
+/// as returned by [ProvenTransaction::split_proof()]. The function does not depend on any state
+/// and compiles to `wasm32` without the standard library, and thus can be embedded into light
+/// clients and browser applications.
+///
+/// # Errors
+/// Returns an error if:
+/// - The proof or the public inputs cannot be deserialized.
+/// - Transaction verification fails.
+/// - The security level of the verified proof is lower than `min_security`.
+pub fn verify_transaction(
+    proof_bytes: &[u8],
+    public_inputs_bytes: &[u8],
+    min_security: u32,
+) -> Result<TransactionId, TransactionVerifierError> {
+    let proof = ExecutionProof::read_from_bytes(proof_bytes)
+        .map_err(TransactionVerifierError::InvalidProof)?;
+    let header = ProvenTransactionHeader::read_from_bytes(public_inputs_bytes)
+        .map_err(TransactionVerifierError::InvalidPublicInputs)?;
+
+    TransactionVerifier::new(min_security).verify_proof(&header, proof)?;
+
+    Ok(header.id())
+}
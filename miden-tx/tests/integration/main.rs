@@ -1,3 +1,4 @@
+mod recipes;
 mod scripts;
 mod wallet;
 
@@ -16,7 +17,7 @@ use miden_objects::{
         ChainMmr, ExecutedTransaction, InputNote, InputNotes, OutputNote, ProvenTransaction,
         TransactionArgs, TransactionInputs,
     },
-    BlockHeader, Felt, Word, ZERO,
+    BlockHeader, Felt, KernelConfig, Word, ZERO,
 };
 use miden_prover::ProvingOptions;
 use miden_tx::{
@@ -49,7 +50,7 @@ impl MockDataStore {
     pub fn new() -> Self {
         let (tx_inputs, tx_args) =
             mock_inputs(MockAccountType::StandardExisting, AssetPreservationStatus::Preserved);
-        let (account, _, block_header, block_chain, notes) = tx_inputs.into_parts();
+        let (account, _, block_header, _, block_chain, notes) = tx_inputs.into_parts();
         Self {
             account,
             block_header,
@@ -118,6 +119,7 @@ impl DataStore for MockDataStore {
             self.account.clone(),
             None,
             self.block_header,
+            KernelConfig::default(),
             self.block_chain.clone(),
             InputNotes::new(notes).unwrap(),
         )
@@ -0,0 +1,342 @@
+use miden_lib::{
+    accounts::faucets::create_basic_non_fungible_faucet, notes::create_p2id_note,
+    transaction::KernelError, AuthScheme,
+};
+use miden_objects::{
+    accounts::{
+        Account, AccountId, AccountStorageType, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+        ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1, ACCOUNT_ID_OFF_CHAIN_SENDER,
+        ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
+    },
+    assembly::ProgramAst,
+    assets::{Asset, AssetVault, FungibleAsset},
+    crypto::{dsa::rpo_falcon512::SecretKey, rand::RpoRandomCoin},
+    notes::{NoteCreationReceipt, NoteMetadata, NoteType},
+    transaction::TransactionArgs,
+    utils::{Deserializable, Serializable},
+    Felt, NoteError, Word, ZERO,
+};
+use miden_tx::{
+    recipes::{
+        build_nft_mint_tx, consume_available_notes, keep_alive, mint, mint_nfts, send_assets,
+        AuthSecretKey,
+    },
+    DiscoveredNote, RecipeError, TransactionExecutor,
+};
+use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+
+use crate::{
+    get_account_with_default_account_code, get_new_key_pair_with_advice_map,
+    scripts::faucet::get_faucet_account_with_max_supply_and_total_issuance, MockDataStore,
+};
+
+// RECIPE TESTS
+// ================================================================================================
+
+#[test]
+fn recipes_send_and_consume_assets() {
+    let secret_key = SecretKey::with_rng(&mut ChaCha20Rng::from_seed([0_u8; 32]));
+    let pub_key: Word = secret_key.public_key().into();
+    let auth = AuthSecretKey::RpoFalcon512(secret_key);
+
+    let assets: Vec<Asset> = vec![
+        FungibleAsset::new(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN.try_into().unwrap(), 100)
+            .unwrap()
+            .into(),
+        FungibleAsset::new(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1.try_into().unwrap(), 50)
+            .unwrap()
+            .into(),
+    ];
+
+    let sender_id = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+    let sender = with_assets_and_nonce(
+        &get_account_with_default_account_code(sender_id, pub_key, None),
+        &assets,
+        1,
+    );
+    let target_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let target = get_account_with_default_account_code(target_id, pub_key, None);
+
+    // SEND ASSETS
+    // --------------------------------------------------------------------------------------------
+    let data_store = MockDataStore::with_existing(Some(sender.clone()), Some(vec![]));
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    let block_ref = data_store.block_header.block_num();
+
+    let rng = RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]);
+    let (executed_transaction, created_notes) = send_assets(
+        &mut executor,
+        &auth,
+        block_ref,
+        sender_id,
+        target_id,
+        assets.clone(),
+        NoteType::Public,
+        rng,
+    )
+    .unwrap();
+
+    let expected_note =
+        create_p2id_note(sender_id, target_id, assets.clone(), NoteType::Public, rng).unwrap();
+    assert_eq!(created_notes, vec![expected_note]);
+    assert_eq!(
+        executed_transaction.final_account().hash(),
+        with_assets_and_nonce(&sender, &[], 2).hash()
+    );
+
+    let err = send_assets(
+        &mut executor,
+        &auth,
+        block_ref,
+        sender_id,
+        target_id,
+        vec![],
+        NoteType::Public,
+        rng,
+    )
+    .unwrap_err();
+    assert_eq!(err, RecipeError::NoAssets);
+
+    // CONSUME AVAILABLE NOTES
+    // --------------------------------------------------------------------------------------------
+    let data_store = MockDataStore::with_existing(Some(target.clone()), Some(created_notes));
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    let block_ref = data_store.block_header.block_num();
+
+    let err = consume_available_notes(&mut executor, &auth, block_ref, target_id, []).unwrap_err();
+    assert_eq!(err, RecipeError::NoConsumableNotes);
+
+    let discovered_notes = data_store.notes.iter().cloned().map(DiscoveredNote::Consumable);
+    let (executed_transaction, created_notes) =
+        consume_available_notes(&mut executor, &auth, block_ref, target_id, discovered_notes)
+            .unwrap();
+
+    assert!(created_notes.is_empty());
+    assert_eq!(
+        executed_transaction.final_account().hash(),
+        with_assets_and_nonce(&target, &assets, 2).hash()
+    );
+}
+
+#[test]
+fn recipes_mint() {
+    let secret_key = SecretKey::with_rng(&mut ChaCha20Rng::from_seed([0_u8; 32]));
+    let pub_key: Word = secret_key.public_key().into();
+    let auth = AuthSecretKey::RpoFalcon512(secret_key);
+
+    let faucet = get_faucet_account_with_max_supply_and_total_issuance(pub_key, 200, None);
+    let target_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+
+    let data_store = MockDataStore::with_existing(Some(faucet.clone()), Some(vec![]));
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    let block_ref = data_store.block_header.block_num();
+
+    let rng = RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]);
+    let (_, created_notes) = mint(
+        &mut executor,
+        &auth,
+        block_ref,
+        faucet.id(),
+        target_id,
+        100,
+        NoteType::OffChain,
+        rng,
+    )
+    .unwrap();
+
+    let asset = FungibleAsset::new(faucet.id(), 100).unwrap();
+    let expected_note =
+        create_p2id_note(faucet.id(), target_id, vec![asset.into()], NoteType::OffChain, rng)
+            .unwrap();
+    assert_eq!(created_notes, vec![expected_note]);
+
+    // minting more than the maximum supply fails
+    let err = mint(
+        &mut executor,
+        &auth,
+        block_ref,
+        faucet.id(),
+        target_id,
+        250,
+        NoteType::OffChain,
+        rng,
+    )
+    .unwrap_err();
+    assert!(matches!(err, RecipeError::ExecuteTransactionFailed(_)));
+}
+
+#[test]
+fn recipes_mint_nfts() {
+    let secret_key = SecretKey::with_rng(&mut ChaCha20Rng::from_seed([0_u8; 32]));
+    let auth_scheme = AuthScheme::RpoFalcon512 { pub_key: secret_key.public_key() };
+    let auth = AuthSecretKey::RpoFalcon512(secret_key);
+
+    let (faucet, _) =
+        create_basic_non_fungible_faucet([0; 32], AccountStorageType::OffChain, auth_scheme)
+            .unwrap();
+    let faucet = with_assets_and_nonce(&faucet, &[], 1);
+    let target_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let other_target_id = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+
+    let items = vec![vec![1, 2, 3], vec![4, 5, 6]];
+    let rng = RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]);
+
+    // every item needs a recipient
+    let err = build_nft_mint_tx(
+        &auth,
+        faucet.id(),
+        items.clone(),
+        vec![target_id],
+        NoteType::Public,
+        rng,
+    )
+    .unwrap_err();
+    assert_eq!(err, RecipeError::MintRecipientsMismatch { num_items: 2, num_recipients: 1 });
+
+    // a small collection is minted in a single transaction
+    let mint_txs = build_nft_mint_tx(
+        &auth,
+        faucet.id(),
+        items,
+        vec![target_id, other_target_id],
+        NoteType::Public,
+        rng,
+    )
+    .unwrap();
+    assert_eq!(mint_txs.len(), 1);
+    assert_eq!(mint_txs[0].notes().len(), 2);
+    assert_ne!(mint_txs[0].notes()[0].serial_num(), mint_txs[0].notes()[1].serial_num());
+
+    let data_store = MockDataStore::with_existing(Some(faucet.clone()), Some(vec![]));
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    let block_ref = data_store.block_header.block_num();
+
+    let (executed_transaction, created_notes) =
+        mint_nfts(&mut executor, &auth, block_ref, &mint_txs[0]).unwrap();
+    assert_eq!(created_notes, mint_txs[0].notes());
+    assert_eq!(executed_transaction.account_delta().nonce(), Some(Felt::new(2)));
+}
+
+#[test]
+fn recipes_keep_alive() {
+    let secret_key = SecretKey::with_rng(&mut ChaCha20Rng::from_seed([0_u8; 32]));
+    let pub_key: Word = secret_key.public_key().into();
+    let auth = AuthSecretKey::RpoFalcon512(secret_key);
+
+    let asset: Asset =
+        FungibleAsset::new(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN.try_into().unwrap(), 100)
+            .unwrap()
+            .into();
+    let account_id = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+    let account = get_account_with_default_account_code(account_id, pub_key, Some(asset));
+
+    let data_store = MockDataStore::with_existing(Some(account.clone()), Some(vec![]));
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    let block_ref = data_store.block_header.block_num();
+
+    // the keep-alive transaction only increments the nonce of the account
+    let executed_transaction = keep_alive(&mut executor, &auth, block_ref, account_id).unwrap();
+    assert!(executed_transaction.input_notes().is_empty());
+    assert!(executed_transaction.output_notes().is_empty());
+
+    let account_delta = executed_transaction.account_delta();
+    assert!(account_delta.storage().is_empty());
+    assert!(account_delta.vault().is_empty());
+    assert_eq!(account_delta.nonce(), Some(Felt::new(2)));
+    assert_eq!(
+        executed_transaction.final_account().hash(),
+        with_assets_and_nonce(&account, &[asset], 2).hash()
+    );
+
+    // keep-alive transactions cannot consume notes
+    let target_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let target = get_account_with_default_account_code(target_id, pub_key, None);
+    let rng = RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]);
+    let note =
+        create_p2id_note(account_id, target_id, vec![asset], NoteType::OffChain, rng).unwrap();
+
+    let data_store = MockDataStore::with_existing(Some(target.clone()), Some(vec![note.clone()]));
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(target_id).unwrap();
+
+    let tx_script_code = ProgramAst::parse(
+        "
+        use.miden::contracts::auth::basic->auth_tx
+
+        begin
+            call.auth_tx::keep_alive_rpo_falcon512
+        end
+        ",
+    )
+    .unwrap();
+    let tx_script = executor
+        .compile_tx_script(tx_script_code, vec![get_new_key_pair_with_advice_map()], vec![])
+        .unwrap();
+    let err = executor
+        .execute_transaction(
+            target_id,
+            block_ref,
+            &[note.id()],
+            TransactionArgs::with_tx_script(tx_script),
+        )
+        .unwrap_err();
+    assert_eq!(err.kernel_error(), Some(KernelError::AccountKeepAliveWithNotes));
+}
+
+#[test]
+fn recipes_note_creation_receipt() {
+    let secret_key = SecretKey::with_rng(&mut ChaCha20Rng::from_seed([0_u8; 32]));
+    let pub_key: Word = secret_key.public_key().into();
+    let auth = AuthSecretKey::RpoFalcon512(secret_key);
+
+    let sender_id = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+    let sender = get_account_with_default_account_code(sender_id, pub_key, None);
+    let target_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+
+    let asset = FungibleAsset::new(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN.try_into().unwrap(), 100)
+        .unwrap()
+        .into();
+    let mut rng = RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]);
+    let note =
+        create_p2id_note(sender_id, target_id, vec![asset], NoteType::OffChain, rng).unwrap();
+
+    let receipt = auth.sign_note_creation(note.id(), *note.metadata(), &mut rng);
+    assert_eq!(receipt.sender(), sender_id);
+
+    // the receipt is valid against the public key stored in the account storage of the sender
+    let sender_pub_key: Word = sender.storage().get_item(0).into();
+    assert!(receipt.verify(sender_pub_key).is_ok());
+
+    // the receipt is not valid against other public keys
+    let other_key = SecretKey::with_rng(&mut ChaCha20Rng::from_seed([1_u8; 32]));
+    let err = receipt.verify(other_key.public_key().into()).unwrap_err();
+    assert_eq!(err, NoteError::InvalidNoteCreationReceipt(note.id()));
+
+    // the receipt does not verify for a note with different metadata
+    let metadata =
+        NoteMetadata::new(target_id, NoteType::OffChain, note.metadata().tag(), ZERO).unwrap();
+    let forged_receipt = NoteCreationReceipt::new(note.id(), metadata, receipt.signature().clone());
+    assert!(forged_receipt.verify(sender_pub_key).is_err());
+
+    // the receipt survives serialization
+    let bytes = receipt.to_bytes();
+    assert_eq!(NoteCreationReceipt::read_from_bytes(&bytes).unwrap(), receipt);
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn with_assets_and_nonce(account: &Account, assets: &[Asset], nonce: u64) -> Account {
+    Account::new(
+        account.id(),
+        AssetVault::new(assets).unwrap(),
+        account.storage().clone(),
+        account.code().clone(),
+        Felt::new(nonce),
+    )
+}
@@ -0,0 +1,213 @@
+use miden_lib::{
+    accounts::wallets::{build_allowlist_update_script, create_allowlist_wallet},
+    transaction::{KernelError, TransactionKernel},
+    AuthScheme,
+};
+use miden_objects::{
+    accounts::{
+        Account, AccountId, AccountStorageType, AccountType, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+        ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2, ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
+        ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN, ACCOUNT_ID_SENDER,
+    },
+    assembly::ProgramAst,
+    assets::{Asset, AssetVault, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails},
+    crypto::dsa::rpo_falcon512::PublicKey,
+    notes::{Note, NoteAssets, NoteInputs, NoteMetadata, NoteRecipient, NoteScript, NoteType},
+    transaction::{ExecutedTransaction, TransactionArgs},
+    Felt, Word, ONE, ZERO,
+};
+use miden_tx::{TransactionExecutor, TransactionExecutorError};
+use mock::mock::account::DEFAULT_AUTH_SCRIPT;
+
+use crate::{get_new_key_pair_with_advice_map, prove_and_verify_transaction, MockDataStore};
+
+// ALLOWLIST TESTS
+// ================================================================================================
+// We want to test the asset allowlist component, which only allows an account to receive assets
+// issued by faucets on an allowlist stored in the account storage.
+
+#[test]
+fn prove_allowlist_receive_asset() {
+    let (fungible_asset, non_fungible_asset) = get_assets();
+    let account = get_allowlist_account(&[
+        AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap(),
+        AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap(),
+    ]);
+    let note = get_note_with_assets(vec![fungible_asset, non_fungible_asset]);
+
+    let executed_transaction = execute_with_notes(account.clone(), vec![note])
+        .expect("Transaction consuming allowed assets failed");
+
+    // Prove, serialize/deserialize and verify the transaction
+    assert!(prove_and_verify_transaction(executed_transaction.clone()).is_ok());
+
+    let account_after = Account::new(
+        account.id(),
+        AssetVault::new(&[fungible_asset, non_fungible_asset]).unwrap(),
+        account.storage().clone(),
+        account.code().clone(),
+        Felt::new(2),
+    );
+    assert_eq!(executed_transaction.final_account().hash(), account_after.hash());
+}
+
+#[test]
+fn allowlist_rejects_assets_from_other_faucets() {
+    let (fungible_asset, non_fungible_asset) = get_assets();
+    let faucet_id_2 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2).unwrap();
+    let account =
+        get_allowlist_account(&[AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap()]);
+
+    // a non-fungible asset from a faucet which is not on the allowlist is rejected
+    let note = get_note_with_assets(vec![fungible_asset, non_fungible_asset]);
+    let executed_transaction = execute_with_notes(account.clone(), vec![note]);
+    assert_eq!(
+        executed_transaction.unwrap_err().kernel_error(),
+        Some(KernelError::AllowlistFaucetNotAllowed)
+    );
+
+    // and so is a fungible asset
+    let note = get_note_with_assets(vec![FungibleAsset::new(faucet_id_2, 10).unwrap().into()]);
+    let executed_transaction = execute_with_notes(account, vec![note]);
+    assert_eq!(
+        executed_transaction.unwrap_err().kernel_error(),
+        Some(KernelError::AllowlistFaucetNotAllowed)
+    );
+}
+
+#[test]
+fn allowlist_update_via_tx_script() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let faucet_id_2 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2).unwrap();
+    let (pub_key, keypair_felts) = get_new_key_pair_with_advice_map();
+    let mut account = get_allowlist_account(&[faucet_id]);
+
+    let data_store = MockDataStore::with_existing(Some(account.clone()), Some(vec![]));
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(account.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+
+    let auth_scheme = AuthScheme::RpoFalcon512 { pub_key: PublicKey::new(pub_key) };
+    let tx_script_code =
+        build_allowlist_update_script(&[faucet_id_2], &[faucet_id], auth_scheme).unwrap();
+    let tx_script = executor
+        .compile_tx_script(tx_script_code, vec![(pub_key, keypair_felts)], vec![])
+        .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+
+    let executed_transaction =
+        executor.execute_transaction(account.id(), block_ref, &[], tx_args).unwrap();
+
+    // the allowlist was updated
+    account.apply_delta(executed_transaction.account_delta()).unwrap();
+    assert_eq!(executed_transaction.final_account().hash(), account.hash());
+    assert_eq!(account.storage().get_array_item(1, faucet_id.into()), Ok([ZERO; 4]));
+    assert_eq!(
+        account.storage().get_array_item(1, faucet_id_2.into()),
+        Ok([ONE, ZERO, ZERO, ZERO])
+    );
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn get_assets() -> (Asset, Asset) {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+
+    let faucet_id_2 = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let non_fungible_asset: Asset = NonFungibleAsset::new(
+        &NonFungibleAssetDetails::new(faucet_id_2, vec![1, 2, 3, 4]).unwrap(),
+    )
+    .unwrap()
+    .into();
+
+    (fungible_asset, non_fungible_asset)
+}
+
+fn get_allowlist_account(allowed_faucets: &[AccountId]) -> Account {
+    let (pub_key, _) = get_new_key_pair_with_advice_map();
+    let auth_scheme = AuthScheme::RpoFalcon512 { pub_key: PublicKey::new(pub_key) };
+    let (account, _) = create_allowlist_wallet(
+        [0; 32],
+        auth_scheme,
+        allowed_faucets,
+        AccountType::RegularAccountUpdatableCode,
+        AccountStorageType::OffChain,
+    )
+    .unwrap();
+
+    Account::new(
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap(),
+        AssetVault::new(&[]).unwrap(),
+        account.storage().clone(),
+        account.code().clone(),
+        ONE,
+    )
+}
+
+/// Returns a note which adds all of its assets to the consuming account via the allowlist
+/// component.
+fn get_note_with_assets(assets: Vec<Asset>) -> Note {
+    let note_script_ast = ProgramAst::parse(
+        "
+        use.miden::note
+        use.miden::contracts::compliance::allowlist
+
+        begin
+            dropw
+            push.0 exec.note::get_assets
+            # => [num_of_assets, ptr]
+
+            dup.1 add padw movup.5 dup dup.6 neq
+            # => [latch, ptr, 0, 0, 0, 0, end_ptr]
+
+            while.true
+                dup movdn.5 mem_loadw call.allowlist::receive_asset
+                # => [ASSET, ptr, end_ptr]
+
+                movup.4 add.1 dup dup.6 neq
+            end
+
+            drop dropw drop
+        end
+        ",
+    )
+    .unwrap();
+    let (note_script, _) =
+        NoteScript::new(note_script_ast, &TransactionKernel::assembler()).unwrap();
+
+    let sender_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    const SERIAL_NUM: Word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+
+    let vault = NoteAssets::new(assets).unwrap();
+    let metadata = NoteMetadata::new(sender_id, NoteType::Public, 1.into(), ZERO).unwrap();
+    let inputs = NoteInputs::new(vec![]).unwrap();
+    let recipient = NoteRecipient::new(SERIAL_NUM, note_script, inputs);
+
+    Note::new(vault, metadata, recipient)
+}
+
+fn execute_with_notes(
+    account: Account,
+    notes: Vec<Note>,
+) -> Result<ExecutedTransaction, TransactionExecutorError> {
+    let (pub_key, keypair_felts) = get_new_key_pair_with_advice_map();
+    let data_store = MockDataStore::with_existing(Some(account.clone()), Some(notes));
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(account.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let tx_script_code = ProgramAst::parse(DEFAULT_AUTH_SCRIPT).unwrap();
+    let tx_script = executor
+        .compile_tx_script(tx_script_code, vec![(pub_key, keypair_felts)], vec![])
+        .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+
+    executor.execute_transaction(account.id(), block_ref, &note_ids, tx_args)
+}
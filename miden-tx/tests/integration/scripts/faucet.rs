@@ -1,19 +1,23 @@
 use miden_lib::{
-    accounts::faucets::create_basic_fungible_faucet,
-    transaction::{memory::FAUCET_STORAGE_DATA_SLOT, TransactionKernel},
+    accounts::faucets::{create_basic_fungible_faucet, create_burn_tx_script_code, FaucetIssuance},
+    notes::{create_p2id_note, create_voucher_note, utils::build_p2id_recipient},
+    transaction::{memory::FAUCET_STORAGE_DATA_SLOT, KernelError, TransactionKernel},
     AuthScheme,
 };
 use miden_objects::{
     accounts::{
-        Account, AccountCode, AccountId, AccountStorage, AccountStorageType, SlotItem, StorageSlot,
-        ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN,
+        Account, AccountCode, AccountId, AccountStorage, AccountStorageType, AccountStub, SlotItem,
+        StorageSlot, ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN, ACCOUNT_ID_SENDER,
     },
     assembly::{ModuleAst, ProgramAst},
     assets::{Asset, AssetVault, FungibleAsset, TokenSymbol},
-    crypto::dsa::rpo_falcon512::SecretKey,
-    notes::{NoteAssets, NoteId, NoteMetadata, NoteType},
+    crypto::{
+        dsa::rpo_falcon512::{PublicKey, SecretKey},
+        rand::RpoRandomCoin,
+    },
+    notes::{Note, NoteAssets, NoteExecutionMode, NoteId, NoteMetadata, NoteTag, NoteType},
     transaction::TransactionArgs,
-    Felt, Word, ZERO,
+    AccountError, Felt, Word, ZERO,
 };
 use miden_tx::TransactionExecutor;
 use mock::utils::prepare_word;
@@ -154,7 +158,11 @@ fn faucet_contract_mint_fungible_asset_fails_exceeds_max_supply() {
     let executed_transaction =
         executor.execute_transaction(faucet_account.id(), block_ref, &note_ids, tx_args);
 
-    assert!(executed_transaction.is_err());
+    let err = executed_transaction.unwrap_err();
+    assert_eq!(err.kernel_error(), Some(KernelError::BasicFungibleMaxSupplyOverflow));
+    assert_eq!(err.code(), 3004);
+    assert!(!err.is_retryable());
+    assert!(std::error::Error::source(&err).is_some());
 }
 
 // TESTS BURN FUNGIBLE ASSET
@@ -221,6 +229,205 @@ fn prove_faucet_contract_burn_fungible_asset_succeeds() {
     assert_eq!(executed_transaction.input_notes().get_note(0).id(), note.id());
 }
 
+#[test]
+fn prove_faucet_burn_fungible_asset_via_tx_script_succeeds() {
+    let (faucet_pub_key, faucet_keypair_felts) = get_new_key_pair_with_advice_map();
+    let faucet_account =
+        get_faucet_account_with_max_supply_and_total_issuance(faucet_pub_key, 200, Some(100));
+
+    let fungible_asset = FungibleAsset::new(faucet_account.id(), 60).unwrap();
+
+    // the note script leaves the asset in the input vault so that it can be burned by the
+    // transaction script
+    let note_script = ProgramAst::parse("begin dropw end").unwrap();
+    let note = get_note_with_fungible_asset_and_script(fungible_asset, note_script);
+
+    // CONSTRUCT AND EXECUTE TX (Success)
+    // --------------------------------------------------------------------------------------------
+    let data_store =
+        MockDataStore::with_existing(Some(faucet_account.clone()), Some(vec![note.clone()]));
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(faucet_account.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let auth_scheme = AuthScheme::RpoFalcon512 { pub_key: PublicKey::new(faucet_pub_key) };
+    let tx_script_code =
+        create_burn_tx_script_code(&[fungible_asset.into()], &auth_scheme).unwrap();
+    let tx_script = executor
+        .compile_tx_script(tx_script_code, vec![(faucet_pub_key, faucet_keypair_felts)], vec![])
+        .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+
+    let executed_transaction = executor
+        .execute_transaction(faucet_account.id(), block_ref, &note_ids, tx_args)
+        .unwrap();
+
+    assert!(prove_and_verify_transaction(executed_transaction.clone()).is_ok());
+
+    // check that the total issuance of the faucet was reduced by the burned amount
+    assert_eq!(executed_transaction.account_delta().nonce(), Some(Felt::new(2)));
+    assert_eq!(
+        executed_transaction.account_delta().storage().updated_items,
+        vec![(FAUCET_STORAGE_DATA_SLOT, [ZERO, ZERO, ZERO, Felt::new(40)])]
+    );
+    assert_eq!(executed_transaction.output_notes().num_notes(), 0);
+}
+
+#[test]
+fn faucet_burn_fungible_asset_via_tx_script_fails_without_input_asset() {
+    let (faucet_pub_key, faucet_keypair_felts) = get_new_key_pair_with_advice_map();
+    let faucet_account =
+        get_faucet_account_with_max_supply_and_total_issuance(faucet_pub_key, 200, Some(100));
+
+    // the asset to be burned is not provided to the transaction via a note
+    let fungible_asset = FungibleAsset::new(faucet_account.id(), 60).unwrap();
+
+    // CONSTRUCT AND EXECUTE TX (Failure)
+    // --------------------------------------------------------------------------------------------
+    let data_store = MockDataStore::with_existing(Some(faucet_account.clone()), Some(vec![]));
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(faucet_account.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let auth_scheme = AuthScheme::RpoFalcon512 { pub_key: PublicKey::new(faucet_pub_key) };
+    let tx_script_code =
+        create_burn_tx_script_code(&[fungible_asset.into()], &auth_scheme).unwrap();
+    let tx_script = executor
+        .compile_tx_script(tx_script_code, vec![(faucet_pub_key, faucet_keypair_felts)], vec![])
+        .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+
+    let executed_transaction =
+        executor.execute_transaction(faucet_account.id(), block_ref, &note_ids, tx_args);
+
+    assert!(executed_transaction.is_err());
+}
+
+// TESTS REDEEM VOUCHER
+// ================================================================================================
+
+#[test]
+fn prove_faucet_contract_redeem_voucher_succeeds() {
+    let (faucet_pub_key, _faucet_keypair_felts) = get_new_key_pair_with_advice_map();
+    let faucet_account =
+        get_faucet_account_with_max_supply_and_total_issuance(faucet_pub_key, 200, Some(100));
+
+    // the voucher is issued by the faucet for the target account
+    let target_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let (voucher, payout_serial_num) = create_voucher_note(
+        faucet_account.id(),
+        target_account_id,
+        50,
+        NoteType::OffChain,
+        RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+
+    // CONSTRUCT AND EXECUTE TX (Success)
+    // --------------------------------------------------------------------------------------------
+    let data_store =
+        MockDataStore::with_existing(Some(faucet_account.clone()), Some(vec![voucher.clone()]));
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(faucet_account.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    // no signature of the faucet owner is required to redeem the voucher
+    let executed_transaction = executor
+        .execute_transaction(faucet_account.id(), block_ref, &note_ids, TransactionArgs::default())
+        .unwrap();
+
+    // Prove, serialize/deserialize and verify the transaction
+    assert!(prove_and_verify_transaction(executed_transaction.clone()).is_ok());
+
+    // check that the faucet minted the amount specified by the voucher
+    assert_eq!(executed_transaction.account_delta().nonce(), Some(Felt::new(2)));
+    assert_eq!(
+        executed_transaction.account_delta().storage().updated_items,
+        vec![(FAUCET_STORAGE_DATA_SLOT, [ZERO, ZERO, ZERO, Felt::new(150)])]
+    );
+
+    // check that the minted asset was sent to the target account
+    assert_eq!(executed_transaction.output_notes().num_notes(), 1);
+
+    let fungible_asset: Asset = FungibleAsset::new(faucet_account.id(), 50).unwrap().into();
+    let recipient = build_p2id_recipient(target_account_id, payout_serial_num).unwrap();
+    let assets = NoteAssets::new(vec![fungible_asset]).unwrap();
+    let tag = NoteTag::from_account_id(target_account_id, NoteExecutionMode::Local).unwrap();
+
+    let created_note = executed_transaction.output_notes().get_note(0);
+    assert_eq!(created_note.id(), NoteId::new(recipient, assets.commitment()));
+    assert_eq!(
+        created_note.metadata(),
+        &NoteMetadata::new(faucet_account.id(), NoteType::OffChain, tag, ZERO).unwrap()
+    );
+
+    // the created note is a regular P2ID note for the target account
+    let p2id_note = create_p2id_note(
+        faucet_account.id(),
+        target_account_id,
+        vec![fungible_asset],
+        NoteType::OffChain,
+        RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+    assert_eq!(p2id_note.serial_num(), payout_serial_num);
+    assert_eq!(created_note.id(), p2id_note.id());
+}
+
+#[test]
+fn faucet_contract_redeem_voucher_fails_if_not_issued_by_faucet() {
+    let (faucet_pub_key, _faucet_keypair_felts) = get_new_key_pair_with_advice_map();
+    let faucet_account =
+        get_faucet_account_with_max_supply_and_total_issuance(faucet_pub_key, 200, Some(100));
+
+    // the voucher is issued by an account other than the faucet
+    let target_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let (voucher, _) = create_voucher_note(
+        faucet_account.id(),
+        target_account_id,
+        50,
+        NoteType::OffChain,
+        RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+    let metadata =
+        NoteMetadata::new(target_account_id, NoteType::OffChain, voucher.metadata().tag(), ZERO)
+            .unwrap();
+    let voucher = Note::new(voucher.assets().clone(), metadata, voucher.recipient().clone());
+
+    // CONSTRUCT AND EXECUTE TX (Failure)
+    // --------------------------------------------------------------------------------------------
+    let data_store =
+        MockDataStore::with_existing(Some(faucet_account.clone()), Some(vec![voucher]));
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(faucet_account.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let executed_transaction = executor.execute_transaction(
+        faucet_account.id(),
+        block_ref,
+        &note_ids,
+        TransactionArgs::default(),
+    );
+
+    assert_eq!(
+        executed_transaction.unwrap_err().kernel_error(),
+        Some(KernelError::VoucherNotIssuedByFaucet)
+    );
+}
+
 // TESTS FUNGIBLE CONTRACT CONSTRUCTION
 // ================================================================================================
 
@@ -275,7 +482,93 @@ fn faucet_contract_creation() {
     assert_eq!(faucet_account.code(), &exp_faucet_account_code);
 }
 
-fn get_faucet_account_with_max_supply_and_total_issuance(
+#[test]
+fn faucet_issuance_tracks_minted_supply() {
+    let (faucet_pub_key, faucet_keypair_felts) = get_new_key_pair_with_advice_map();
+    let faucet_account =
+        get_faucet_account_with_max_supply_and_total_issuance(faucet_pub_key, 200, Some(50));
+
+    let issuance = FaucetIssuance::from_account(&faucet_account).unwrap();
+    assert_eq!(issuance.faucet_id(), faucet_account.id());
+    assert_eq!(issuance.max_supply(), 200);
+    assert_eq!(issuance.total_issuance(), 50);
+    assert_eq!(issuance.remaining_supply(), 150);
+
+    // CONSTRUCT AND EXECUTE TX (Success)
+    // --------------------------------------------------------------------------------------------
+    let data_store = MockDataStore::with_existing(Some(faucet_account.clone()), Some(vec![]));
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(faucet_account.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let recipient = [Felt::new(0), Felt::new(1), Felt::new(2), Felt::new(3)];
+
+    let tx_script_code = ProgramAst::parse(
+        format!(
+            "
+            use.miden::contracts::faucets::basic_fungible->faucet
+            use.miden::contracts::auth::basic->auth_tx
+
+            begin
+                push.{recipient}
+                push.{note_type}
+                push.4
+                push.100
+                call.faucet::distribute
+
+                call.auth_tx::auth_tx_rpo_falcon512
+                dropw dropw
+            end
+            ",
+            note_type = NoteType::OffChain as u8,
+            recipient = prepare_word(&recipient),
+        )
+        .as_str(),
+    )
+    .unwrap();
+    let tx_script = executor
+        .compile_tx_script(tx_script_code, vec![(faucet_pub_key, faucet_keypair_felts)], vec![])
+        .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+
+    let executed_transaction = executor
+        .execute_transaction(faucet_account.id(), block_ref, &[], tx_args)
+        .unwrap();
+
+    // CHECK ISSUANCE OF THE UPDATED FAUCET
+    // --------------------------------------------------------------------------------------------
+    let mut final_account = faucet_account.clone();
+    final_account.apply_delta(executed_transaction.account_delta()).unwrap();
+
+    let mut expected_issuance = issuance;
+    expected_issuance.mint(100).unwrap();
+    assert_eq!(FaucetIssuance::from_account(&final_account).unwrap(), expected_issuance);
+    assert_eq!(expected_issuance.remaining_supply(), 50);
+
+    // minting or burning more than allowed fails and leaves the issuance unchanged
+    assert!(expected_issuance.mint(51).is_err());
+    assert!(expected_issuance.burn(151).is_err());
+    assert_eq!(expected_issuance.total_issuance(), 150);
+
+    // the issuance can be proven against the public state of the faucet
+    let proof = FaucetIssuance::prove(&final_account).unwrap();
+    assert_eq!(proof.verify(executed_transaction.final_account()), Ok(expected_issuance));
+
+    // the proof is not valid against an outdated state of the faucet, as the path of the metadata
+    // slot contains the previous value of the issuance slot
+    assert_eq!(
+        proof.verify(&AccountStub::from(executed_transaction.initial_account())),
+        Err(AccountError::StorageSlotInvalidProof(FaucetIssuance::METADATA_SLOT))
+    );
+
+    // writing the issuance state into the storage of the faucet updates the issuance slot
+    let mut storage = faucet_account.storage().clone();
+    expected_issuance.write_into_storage(&mut storage).unwrap();
+    assert_eq!(storage.root(), final_account.storage().root());
+}
+
+pub fn get_faucet_account_with_max_supply_and_total_issuance(
     public_key: Word,
     max_supply: u64,
     total_issuance: Option<u64>,
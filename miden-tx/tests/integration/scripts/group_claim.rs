@@ -0,0 +1,114 @@
+use miden_lib::notes::create_group_claim_note;
+use miden_objects::{
+    accounts::{
+        Account, AccountId, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+        ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+        ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN, ACCOUNT_ID_SENDER,
+    },
+    assembly::ProgramAst,
+    assets::{Asset, AssetVault, FungibleAsset},
+    crypto::rand::RpoRandomCoin,
+    notes::{AccountGroup, NoteType},
+    transaction::TransactionArgs,
+    Felt,
+};
+use miden_tx::TransactionExecutor;
+use mock::mock::account::DEFAULT_AUTH_SCRIPT;
+
+use crate::{
+    get_account_with_default_account_code, get_new_key_pair_with_advice_map,
+    prove_and_verify_transaction, MockDataStore,
+};
+
+// GROUP CLAIM TESTS
+// ================================================================================================
+// We test the group claim script. So we create a note that can be consumed by any member of a
+// group of accounts, as long as the member proves its membership in the group.
+
+#[test]
+fn prove_group_claim_script() {
+    // Create assets
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+
+    // Create sender and target account
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let (target_pub_key, target_sk_pk_felt) = get_new_key_pair_with_advice_map();
+    let target_account =
+        get_account_with_default_account_code(target_account_id, target_pub_key, None);
+
+    // Create the group, of which the target account is the second member
+    let other_member_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+    let group = AccountGroup::new(vec![other_member_id, target_account_id]).unwrap();
+
+    // Create the note
+    let note = create_group_claim_note(
+        sender_account_id,
+        &group,
+        vec![fungible_asset],
+        RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+    assert_eq!(note.metadata().note_type(), NoteType::Public);
+    assert_eq!(note.metadata().tag(), group.tag());
+
+    // CONSTRUCT AND EXECUTE TX (Success)
+    // --------------------------------------------------------------------------------------------
+    let data_store =
+        MockDataStore::with_existing(Some(target_account.clone()), Some(vec![note.clone()]));
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(target_account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let tx_script_code = ProgramAst::parse(DEFAULT_AUTH_SCRIPT).unwrap();
+    let tx_script = executor
+        .compile_tx_script(tx_script_code, vec![(target_pub_key, target_sk_pk_felt)], vec![])
+        .unwrap();
+
+    // Provide the membership of the target account in the group
+    let membership = group.membership(target_account_id).unwrap();
+    let mut tx_args = TransactionArgs::with_tx_script(tx_script.clone());
+    tx_args.add_note_args(note.id(), membership.note_args());
+    tx_args.extend_merkle_store(membership.inner_nodes());
+
+    // Execute the transaction and get the witness
+    let executed_transaction = executor
+        .execute_transaction(target_account_id, block_ref, &note_ids, tx_args)
+        .unwrap();
+
+    // Prove, serialize/deserialize and verify the transaction
+    assert!(prove_and_verify_transaction(executed_transaction.clone()).is_ok());
+
+    // vault delta
+    let target_account_after: Account = Account::new(
+        target_account.id(),
+        AssetVault::new(&[fungible_asset]).unwrap(),
+        target_account.storage().clone(),
+        target_account.code().clone(),
+        Felt::new(2),
+    );
+    assert_eq!(executed_transaction.final_account().hash(), target_account_after.hash());
+
+    // CONSTRUCT AND EXECUTE TX (Failure)
+    // --------------------------------------------------------------------------------------------
+    // The target account tries to consume the note with the membership of another member, we
+    // expect an error
+
+    let membership = group.membership(other_member_id).unwrap();
+    let mut tx_args = TransactionArgs::with_tx_script(tx_script);
+    tx_args.add_note_args(note.id(), membership.note_args());
+    tx_args.extend_merkle_store(membership.inner_nodes());
+
+    let executed_transaction_2 =
+        executor.execute_transaction(target_account_id, block_ref, &note_ids, tx_args);
+
+    // Check that we got the expected result - TransactionExecutorError
+    assert!(executed_transaction_2.is_err());
+}
@@ -0,0 +1,202 @@
+use miden_lib::{
+    accounts::wallets::{
+        build_key_activation_script, build_key_announcement_script, build_key_cancellation_script,
+        create_key_rotation_wallet,
+    },
+    transaction::KernelError,
+    AuthScheme,
+};
+use miden_objects::{
+    accounts::{
+        Account, AccountId, AccountStorageType, AccountType,
+        ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
+    },
+    assembly::ProgramAst,
+    assets::AssetVault,
+    crypto::{
+        dsa::rpo_falcon512::{PublicKey, SecretKey},
+        utils::Serializable,
+    },
+    transaction::{ExecutedTransaction, TransactionArgs},
+    Felt, Word, ONE, ZERO,
+};
+use miden_tx::{TransactionExecutor, TransactionExecutorError};
+use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+
+use crate::{get_new_key_pair_with_advice_map, prove_and_verify_transaction, MockDataStore};
+
+// KEY ROTATION TESTS
+// ================================================================================================
+// We want to test the time-locked key rotation component, which requires a new key to be announced
+// and activated only once the rotation delay has elapsed, while the current key can cancel the
+// announcement. The reference block of the mock chain is block 4.
+
+#[test]
+fn prove_key_announcement() {
+    let (old_key, old_keypair_felts) = get_new_key_pair_with_advice_map();
+    let (new_key, _) = get_new_key_pair_with_seed([1; 32]);
+    let account = get_key_rotation_account(old_key, 10, None);
+
+    let tx_script_code =
+        build_key_announcement_script(auth_scheme(new_key), auth_scheme(old_key)).unwrap();
+    let executed_transaction =
+        execute(&account, tx_script_code, (old_key, old_keypair_felts)).unwrap();
+
+    // Prove, serialize/deserialize and verify the transaction
+    assert!(prove_and_verify_transaction(executed_transaction.clone()).is_ok());
+
+    // the new key and the reference block were recorded, while the public key is unchanged
+    assert_eq!(executed_transaction.account_delta().nonce(), Some(Felt::new(2)));
+    assert_eq!(
+        executed_transaction.account_delta().storage().updated_items,
+        vec![(2, new_key), (3, [Felt::new(4), ZERO, ZERO, ZERO])]
+    );
+}
+
+#[test]
+fn key_announcement_fails_if_key_already_announced() {
+    let (old_key, old_keypair_felts) = get_new_key_pair_with_advice_map();
+    let (new_key, _) = get_new_key_pair_with_seed([1; 32]);
+    let account = get_key_rotation_account(old_key, 10, Some((new_key, 0)));
+
+    // an attacker holding the current key cannot replace the announced key
+    let (attacker_key, _) = get_new_key_pair_with_seed([2; 32]);
+    let tx_script_code =
+        build_key_announcement_script(auth_scheme(attacker_key), auth_scheme(old_key)).unwrap();
+    let result = execute(&account, tx_script_code, (old_key, old_keypair_felts));
+    assert_eq!(
+        result.unwrap_err().kernel_error(),
+        Some(KernelError::KeyRotationAlreadyAnnounced)
+    );
+}
+
+#[test]
+fn key_activation_fails_before_rotation_delay() {
+    let (old_key, _) = get_new_key_pair_with_advice_map();
+    let (new_key, new_keypair_felts) = get_new_key_pair_with_seed([1; 32]);
+    let account = get_key_rotation_account(old_key, 10, Some((new_key, 0)));
+
+    let tx_script_code = build_key_activation_script(auth_scheme(new_key)).unwrap();
+    let result = execute(&account, tx_script_code, (new_key, new_keypair_felts));
+    assert_eq!(
+        result.unwrap_err().kernel_error(),
+        Some(KernelError::KeyRotationDelayNotElapsed)
+    );
+}
+
+#[test]
+fn prove_key_activation() {
+    let (old_key, old_keypair_felts) = get_new_key_pair_with_advice_map();
+    let (new_key, new_keypair_felts) = get_new_key_pair_with_seed([1; 32]);
+    let account = get_key_rotation_account(old_key, 4, Some((new_key, 0)));
+
+    // the activation must be signed by the new key
+    let tx_script_code = build_key_activation_script(auth_scheme(new_key)).unwrap();
+    assert!(execute(&account, tx_script_code.clone(), (old_key, old_keypair_felts)).is_err());
+
+    let executed_transaction =
+        execute(&account, tx_script_code, (new_key, new_keypair_felts)).unwrap();
+
+    // Prove, serialize/deserialize and verify the transaction
+    assert!(prove_and_verify_transaction(executed_transaction.clone()).is_ok());
+
+    // the public key was replaced and the announcement was cleared
+    let mut updated_items = executed_transaction.account_delta().storage().updated_items.clone();
+    updated_items.sort_by_key(|(index, _)| *index);
+    assert_eq!(updated_items, vec![(0, new_key), (2, [ZERO; 4]), (3, [ZERO; 4])]);
+}
+
+#[test]
+fn key_cancellation_clears_announcement() {
+    let (old_key, old_keypair_felts) = get_new_key_pair_with_advice_map();
+    let (new_key, new_keypair_felts) = get_new_key_pair_with_seed([1; 32]);
+    let mut account = get_key_rotation_account(old_key, 4, Some((new_key, 0)));
+
+    let tx_script_code = build_key_cancellation_script(auth_scheme(old_key)).unwrap();
+    let executed_transaction =
+        execute(&account, tx_script_code.clone(), (old_key, old_keypair_felts.clone())).unwrap();
+
+    account.apply_delta(executed_transaction.account_delta()).unwrap();
+    assert_eq!(executed_transaction.final_account().hash(), account.hash());
+    assert_eq!(Word::from(account.storage().get_item(2)), [ZERO; 4]);
+
+    // the cancelled key can no longer be activated, and there is nothing left to cancel
+    let activation_code = build_key_activation_script(auth_scheme(new_key)).unwrap();
+    let result = execute(&account, activation_code, (new_key, new_keypair_felts));
+    assert_eq!(result.unwrap_err().kernel_error(), Some(KernelError::KeyRotationNotAnnounced));
+
+    let result = execute(&account, tx_script_code, (old_key, old_keypair_felts));
+    assert_eq!(result.unwrap_err().kernel_error(), Some(KernelError::KeyRotationNotAnnounced));
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a key rotation wallet with the specified public key and rotation delay. If a pending
+/// key is provided, it is recorded as announced at the specified block.
+fn get_key_rotation_account(
+    pub_key: Word,
+    rotation_delay: u32,
+    pending_key: Option<(Word, u32)>,
+) -> Account {
+    let (account, _) = create_key_rotation_wallet(
+        [0; 32],
+        auth_scheme(pub_key),
+        rotation_delay,
+        AccountType::RegularAccountUpdatableCode,
+        AccountStorageType::OffChain,
+    )
+    .unwrap();
+
+    let mut storage = account.storage().clone();
+    if let Some((pending_key, announced_at)) = pending_key {
+        storage.set_item(2, pending_key).unwrap();
+        storage.set_item(3, [Felt::from(announced_at), ZERO, ZERO, ZERO]).unwrap();
+        storage.commit();
+    }
+
+    Account::new(
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap(),
+        AssetVault::new(&[]).unwrap(),
+        storage,
+        account.code().clone(),
+        ONE,
+    )
+}
+
+fn auth_scheme(pub_key: Word) -> AuthScheme {
+    AuthScheme::RpoFalcon512 { pub_key: PublicKey::new(pub_key) }
+}
+
+/// Returns a key pair derived from the specified seed, in the same format as
+/// [get_new_key_pair_with_advice_map()].
+fn get_new_key_pair_with_seed(seed: [u8; 32]) -> (Word, Vec<Felt>) {
+    let mut rng = ChaCha20Rng::from_seed(seed);
+
+    let sec_key = SecretKey::with_rng(&mut rng);
+    let pub_key: Word = sec_key.public_key().into();
+    let mut pk_sk_bytes = sec_key.to_bytes();
+    pk_sk_bytes.append(&mut pub_key.to_bytes());
+
+    (pub_key, pk_sk_bytes.iter().map(|byte| Felt::new(*byte as u64)).collect())
+}
+
+/// Executes a transaction against the specified account which executes the specified transaction
+/// script, with the provided key pair made available via the advice map.
+fn execute(
+    account: &Account,
+    tx_script_code: ProgramAst,
+    key_pair: (Word, Vec<Felt>),
+) -> Result<ExecutedTransaction, TransactionExecutorError> {
+    let data_store = MockDataStore::with_existing(Some(account.clone()), Some(vec![]));
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(account.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![key_pair], vec![]).unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+
+    executor.execute_transaction(account.id(), block_ref, &[], tx_args)
+}
@@ -1,4 +1,16 @@
-mod faucet;
+mod allowlist;
+mod basket_swap;
+pub mod faucet;
+mod group_claim;
+mod key_rotation;
+mod note_utils;
+mod output_note_policy;
 mod p2id;
 mod p2idr;
+mod p2idt;
+mod p2pk;
+mod partial_swap;
+mod rate_limit;
+mod recovery;
 mod swap;
+mod tx_script_allowlist;
@@ -0,0 +1,133 @@
+use miden_lib::notes::create_p2id_note;
+use miden_objects::{
+    accounts::{
+        Account, AccountId, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+        ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
+        ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN, ACCOUNT_ID_SENDER,
+    },
+    assembly::ProgramAst,
+    assets::{Asset, AssetVault, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails},
+    crypto::rand::RpoRandomCoin,
+    notes::{NoteEnvelope, NoteType},
+    transaction::TransactionArgs,
+    Felt, Word,
+};
+use miden_tx::TransactionExecutor;
+use mock::{mock::account::DEFAULT_AUTH_SCRIPT, utils::prepare_word};
+
+use crate::{
+    get_account_with_default_account_code, get_new_key_pair_with_advice_map,
+    get_note_with_fungible_asset_and_script, prove_and_verify_transaction, MockDataStore,
+};
+
+// NOTE UTILS TESTS
+// ================================================================================================
+// We want to test that a custom note script built from the procedures of the `miden::note_utils`
+// module can restrict its consumer, receive its assets and pay the sender back via a P2ID note.
+
+#[test]
+fn prove_note_utils_payback_script() {
+    // Create assets
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset = FungibleAsset::new(faucet_id, 100).unwrap();
+
+    let faucet_id_2 = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let non_fungible_asset: Asset = NonFungibleAsset::new(
+        &NonFungibleAssetDetails::new(faucet_id_2, vec![1, 2, 3, 4]).unwrap(),
+    )
+    .unwrap()
+    .into();
+
+    // Create sender and target account
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let (target_pub_key, target_sk_felt) = get_new_key_pair_with_advice_map();
+    let target_account = get_account_with_default_account_code(
+        target_account_id,
+        target_pub_key,
+        Some(non_fungible_asset),
+    );
+
+    // The P2ID note the target account is expected to create for the sender
+    let expected_note = create_p2id_note(
+        target_account_id,
+        sender_account_id,
+        vec![non_fungible_asset],
+        NoteType::OffChain,
+        RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+
+    // Create the note paying the sender back from the consuming account
+    let note_script = format!(
+        "
+        use.miden::note_utils
+
+        begin
+            dropw
+
+            push.{target_account_id} exec.note_utils::assert_consumer_is
+            exec.note_utils::add_note_assets_to_account
+
+            push.{note_type}
+            push.{serial_num}
+            push.{asset}
+            exec.note_utils::create_p2id_payback drop
+        end
+        ",
+        target_account_id = Felt::from(target_account_id),
+        note_type = Felt::from(NoteType::OffChain),
+        serial_num = prepare_word(&expected_note.serial_num()),
+        asset = prepare_word(&Word::from(non_fungible_asset)),
+    );
+    let note = get_note_with_fungible_asset_and_script(
+        fungible_asset,
+        ProgramAst::parse(&note_script).unwrap(),
+    );
+
+    // CONSTRUCT AND EXECUTE TX (Success)
+    // --------------------------------------------------------------------------------------------
+    let data_store =
+        MockDataStore::with_existing(Some(target_account.clone()), Some(vec![note.clone()]));
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(target_account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let tx_script_code = ProgramAst::parse(DEFAULT_AUTH_SCRIPT).unwrap();
+    let tx_script = executor
+        .compile_tx_script(tx_script_code, vec![(target_pub_key, target_sk_felt)], vec![])
+        .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+
+    let executed_transaction = executor
+        .execute_transaction(target_account_id, block_ref, &note_ids, tx_args)
+        .expect("Transaction consuming note utils note failed");
+
+    // Prove, serialize/deserialize and verify the transaction
+    assert!(prove_and_verify_transaction(executed_transaction.clone()).is_ok());
+
+    // Check that the target account has received the asset from the note and paid back the
+    // requested asset
+    let target_account_after = Account::new(
+        target_account_id,
+        AssetVault::new(&[fungible_asset.into()]).unwrap(),
+        target_account.storage().clone(),
+        target_account.code().clone(),
+        Felt::new(2),
+    );
+    assert_eq!(executed_transaction.final_account().hash(), target_account_after.hash());
+
+    // Check that the created note is the expected P2ID note
+    assert_eq!(executed_transaction.output_notes().num_notes(), 1);
+
+    let created_note = executed_transaction.output_notes().get_note(0);
+    assert_eq!(
+        NoteEnvelope::from(created_note),
+        NoteEnvelope::new(expected_note.id(), *expected_note.metadata()).unwrap()
+    );
+}
@@ -0,0 +1,330 @@
+use miden_lib::{
+    accounts::wallets::create_script_allowlist_wallet,
+    notes::create_p2id_note,
+    transaction::{KernelError, TransactionKernel},
+    AuthScheme,
+};
+use miden_objects::{
+    accounts::{
+        Account, AccountCode, AccountId, AccountStorage, AccountStorageType, AccountType, SlotItem,
+        StorageSlot, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+        ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+        ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
+    },
+    assembly::{ModuleAst, ProgramAst},
+    assets::{Asset, AssetVault, FungibleAsset},
+    crypto::{dsa::rpo_falcon512::PublicKey, rand::RpoRandomCoin},
+    notes::{Note, NoteType},
+    transaction::{ExecutedTransaction, TransactionArgs},
+    Digest, Felt, Word, ONE, ZERO,
+};
+use miden_tx::{TransactionExecutor, TransactionExecutorError};
+use mock::procedures::prepare_word;
+
+use crate::{get_new_key_pair_with_advice_map, prove_and_verify_transaction, MockDataStore};
+
+// OUTPUT NOTE POLICY TESTS
+// ================================================================================================
+// We want to test the output note hook of accounts, which is invoked by the transaction kernel for
+// each note created by a transaction and can reject the note.
+
+#[test]
+fn prove_script_allowlist_wallet_send_asset() {
+    let note = get_p2id_note();
+    let (account, pub_key, keypair_felts) = get_script_allowlist_account(&[note.script().hash()]);
+
+    let executed_transaction = send_note(&account, pub_key, keypair_felts, &note, true, vec![])
+        .expect("Transaction creating a note with an allowed script failed");
+
+    // Prove, serialize/deserialize and verify the transaction
+    assert!(prove_and_verify_transaction(executed_transaction.clone()).is_ok());
+
+    assert_eq!(executed_transaction.output_notes().num_notes(), 1);
+    assert_eq!(executed_transaction.output_notes().get_note(0).id(), note.id());
+}
+
+#[test]
+fn script_allowlist_wallet_rejects_unknown_script() {
+    let note = get_p2id_note();
+    let unknown_script = Digest::new([ONE, Felt::new(2), Felt::new(3), Felt::new(4)]);
+    let (account, pub_key, keypair_felts) = get_script_allowlist_account(&[unknown_script]);
+
+    let executed_transaction = send_note(&account, pub_key, keypair_felts, &note, true, vec![]);
+    assert_eq!(
+        executed_transaction.unwrap_err().kernel_error(),
+        Some(KernelError::OutputNoteScriptNotAllowed)
+    );
+}
+
+#[test]
+fn script_allowlist_wallet_requires_recipient_details() {
+    let note = get_p2id_note();
+    let (account, pub_key, keypair_felts) = get_script_allowlist_account(&[note.script().hash()]);
+
+    // the recipient details are not provided
+    let executed_transaction =
+        send_note(&account, pub_key, keypair_felts.clone(), &note, false, vec![]);
+    assert!(executed_transaction.is_err());
+
+    // the provided recipient details do not match the recipient
+    let mut recipient_details = note.recipient().to_elements();
+    recipient_details[11] += ONE;
+    let advice = vec![(note.recipient().digest().into(), recipient_details)];
+    let executed_transaction = send_note(&account, pub_key, keypair_felts, &note, false, advice);
+    assert_eq!(
+        executed_transaction.unwrap_err().kernel_error(),
+        Some(KernelError::OutputNoteRecipientMismatch)
+    );
+}
+
+#[test]
+fn allow_all_output_note_hook() {
+    let note = get_p2id_note();
+    let account_code = ModuleAst::parse(
+        "
+        use.miden::contracts::wallets::basic->wallet
+        use.miden::contracts::policies::allow_all
+        use.miden::contracts::auth::basic
+
+        export.wallet::receive_asset
+        export.wallet::send_asset
+        export.allow_all::auth_output_note
+        export.basic::auth_tx_rpo_falcon512
+        ",
+    )
+    .unwrap();
+    let account_code = AccountCode::new(account_code, &TransactionKernel::assembler()).unwrap();
+    let output_note_hook = ModuleAst::parse(
+        "
+        use.miden::contracts::policies::allow_all
+
+        export.allow_all::auth_output_note
+        ",
+    )
+    .unwrap();
+    let output_note_hook = AccountCode::new(output_note_hook, &TransactionKernel::assembler())
+        .unwrap()
+        .procedures()[0];
+
+    // notes are created without providing any details of their recipients
+    let (account, pub_key, keypair_felts) =
+        get_account_with_output_note_hook(account_code.clone(), output_note_hook.into());
+    send_note(&account, pub_key, keypair_felts, &note, false, vec![])
+        .expect("Transaction creating a note via the allow all hook failed");
+
+    // the output note hook must be a procedure of the account
+    let (account, pub_key, keypair_felts) =
+        get_account_with_output_note_hook(account_code, [ONE, ZERO, ZERO, ZERO]);
+    assert!(send_note(&account, pub_key, keypair_felts, &note, false, vec![]).is_err());
+}
+
+#[test]
+fn output_note_hook_inspects_notes_created_by_the_hook() {
+    let note = get_p2id_note();
+    let hook_note_tag = 7;
+    assert_ne!(note.metadata().tag().inner(), hook_note_tag);
+
+    // the hook creates a note with its own tag for every note it inspects which was not created by
+    // the hook itself; `on_hook_note` is executed for the notes created by the hook
+    let hook_proc = |on_hook_note: &str| {
+        format!(
+            "
+            export.create_hook_note
+                # drop the procedure root and the recipient of the inspected note
+                dropw dropw
+                # => [aux, note_type, sender, tag, ...]
+
+                drop drop drop push.{hook_note_tag} eq
+                # => [is_hook_note, ...]
+
+                if.true
+                    {on_hook_note}
+                else
+                    push.{recipient}
+                    push.{note_type}
+                    push.{hook_note_tag}
+                    push.{asset}
+                    # => [ASSET, tag, note_type, RECIPIENT, ...]
+
+                    exec.wallet::send_asset
+                    # => [note_ptr, 0, 0, 0, 0, 0, 0, 0, 0, 0, ...]
+
+                    drop dropw dropw drop
+                end
+            end
+            ",
+            recipient = prepare_word(&[ONE, Felt::new(2), Felt::new(3), Felt::new(4)]),
+            note_type = NoteType::OffChain as u8,
+            asset = prepare_word(&get_asset(1).into()),
+        )
+    };
+
+    let build_account = |on_hook_note: &str| {
+        let hook_module = ModuleAst::parse(&format!(
+            "use.miden::contracts::wallets::basic->wallet {}",
+            hook_proc(on_hook_note)
+        ))
+        .unwrap();
+        let output_note_hook = AccountCode::new(hook_module, &TransactionKernel::assembler())
+            .unwrap()
+            .procedures()[0];
+
+        let account_code = ModuleAst::parse(&format!(
+            "
+            use.miden::contracts::wallets::basic->wallet
+            use.miden::contracts::auth::basic
+
+            export.wallet::receive_asset
+            export.wallet::send_asset
+            export.basic::auth_tx_rpo_falcon512
+
+            {}
+            ",
+            hook_proc(on_hook_note)
+        ))
+        .unwrap();
+        let account_code = AccountCode::new(account_code, &TransactionKernel::assembler()).unwrap();
+        assert!(account_code.has_procedure(output_note_hook));
+
+        get_account_with_output_note_hook(account_code, output_note_hook.into())
+    };
+
+    // the note created by the hook is passed to the hook as well
+    let (account, pub_key, keypair_felts) = build_account("push.0 drop");
+    let executed_transaction = send_note(&account, pub_key, keypair_felts, &note, false, vec![])
+        .expect("Transaction creating a note via the hook failed");
+    assert_eq!(executed_transaction.output_notes().num_notes(), 2);
+    assert_eq!(
+        executed_transaction.output_notes().get_note(1).metadata().tag().inner(),
+        hook_note_tag
+    );
+
+    // thus, the hook can reject the notes which it creates
+    let (account, pub_key, keypair_felts) = build_account("push.0 assert");
+    assert!(send_note(&account, pub_key, keypair_felts, &note, false, vec![]).is_err());
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a script allowlist wallet holding 100 units of a fungible asset, together with the key
+/// pair of the account owner.
+fn get_script_allowlist_account(allowed_scripts: &[Digest]) -> (Account, Word, Vec<Felt>) {
+    let (pub_key, keypair_felts) = get_new_key_pair_with_advice_map();
+    let auth_scheme = AuthScheme::RpoFalcon512 { pub_key: PublicKey::new(pub_key) };
+    let (account, _) = create_script_allowlist_wallet(
+        [0; 32],
+        auth_scheme,
+        allowed_scripts,
+        AccountType::RegularAccountUpdatableCode,
+        AccountStorageType::OffChain,
+    )
+    .unwrap();
+
+    let account = Account::new(
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap(),
+        AssetVault::new(&[get_asset(100)]).unwrap(),
+        account.storage().clone(),
+        account.code().clone(),
+        ONE,
+    );
+
+    (account, pub_key, keypair_felts)
+}
+
+/// Returns an account with the specified code and output note hook holding 100 units of a
+/// fungible asset, together with the key pair of the account owner.
+fn get_account_with_output_note_hook(
+    account_code: AccountCode,
+    output_note_hook: Word,
+) -> (Account, Word, Vec<Felt>) {
+    let (pub_key, keypair_felts) = get_new_key_pair_with_advice_map();
+    let account_storage = AccountStorage::new(vec![SlotItem {
+        index: 0,
+        slot: StorageSlot::new_value(pub_key),
+    }])
+    .unwrap()
+    .with_output_note_hook(output_note_hook);
+
+    let account = Account::new(
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap(),
+        AssetVault::new(&[get_asset(100)]).unwrap(),
+        account_storage,
+        account_code,
+        ONE,
+    );
+
+    (account, pub_key, keypair_felts)
+}
+
+fn get_asset(amount: u64) -> Asset {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    FungibleAsset::new(faucet_id, amount).unwrap().into()
+}
+
+/// Returns a P2ID note carrying 10 units of the fungible asset, sent by the account used in these
+/// tests.
+fn get_p2id_note() -> Note {
+    create_p2id_note(
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap(),
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap(),
+        vec![get_asset(10)],
+        NoteType::OffChain,
+        RpoRandomCoin::new([ONE, Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap()
+}
+
+/// Executes a transaction against the specified account which creates the specified note.
+///
+/// If `expect_note` is true, the details of the note are provided to the transaction via
+/// [TransactionArgs::add_expected_output_note()]. Additional entries of the advice map can be
+/// provided via `advice`.
+fn send_note(
+    account: &Account,
+    pub_key: Word,
+    keypair_felts: Vec<Felt>,
+    note: &Note,
+    expect_note: bool,
+    advice: Vec<(Word, Vec<Felt>)>,
+) -> Result<ExecutedTransaction, TransactionExecutorError> {
+    let data_store = MockDataStore::with_existing(Some(account.clone()), Some(vec![]));
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(account.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+
+    let tx_script_code = ProgramAst::parse(&format!(
+        "
+        use.miden::contracts::auth::basic->auth_tx
+        use.miden::contracts::wallets::basic->wallet
+
+        begin
+            push.{recipient}
+            push.{note_type}
+            push.{tag}
+            push.{asset}
+            call.wallet::send_asset
+            drop drop dropw dropw
+            call.auth_tx::auth_tx_rpo_falcon512
+        end
+        ",
+        recipient = prepare_word(&note.recipient().digest().into()),
+        note_type = note.metadata().note_type() as u8,
+        tag = note.metadata().tag(),
+        asset = prepare_word(&get_asset(10).into()),
+    ))
+    .unwrap();
+
+    let mut tx_script_inputs = vec![(pub_key, keypair_felts)];
+    tx_script_inputs.extend(advice);
+    let tx_script = executor.compile_tx_script(tx_script_code, tx_script_inputs, vec![]).unwrap();
+
+    let mut tx_args = TransactionArgs::with_tx_script(tx_script);
+    if expect_note {
+        tx_args.add_expected_output_note(note);
+    }
+
+    executor.execute_transaction(account.id(), block_ref, &[], tx_args)
+}
@@ -1,3 +1,5 @@
+use std::sync::{Arc, Mutex};
+
 use miden_lib::{notes::create_p2id_note, transaction::TransactionKernel};
 use miden_objects::{
     accounts::{
@@ -8,13 +10,15 @@ use miden_objects::{
     },
     assembly::ProgramAst,
     assets::{Asset, AssetVault, FungibleAsset},
-    crypto::rand::RpoRandomCoin,
+    crypto::{auth::AuthSecretKey, dsa::rpo_falcon512::SecretKey, rand::RpoRandomCoin},
     notes::{NoteScript, NoteType},
     transaction::TransactionArgs,
     Felt,
 };
-use miden_tx::TransactionExecutor;
-use mock::mock::account::DEFAULT_AUTH_SCRIPT;
+use miden_prover::ProvingOptions;
+use miden_tx::{BasicAuthenticator, TransactionExecutor, TransactionProver, TransactionVerifier};
+use mock::{constants::MIN_PROOF_SECURITY_LEVEL, mock::account::DEFAULT_AUTH_SCRIPT};
+use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
 
 use crate::{
     get_account_with_default_account_code, get_new_key_pair_with_advice_map,
@@ -133,6 +137,93 @@ fn prove_p2id_script() {
     assert!(executed_transaction_2.is_err());
 }
 
+/// We test consuming a P2ID note with the secret key of the target account held by a
+/// [BasicAuthenticator], which approves or denies the signature request based on the account
+/// delta.
+#[test]
+fn prove_p2id_script_with_authenticator() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let secret_key =
+        AuthSecretKey::RpoFalcon512(SecretKey::with_rng(&mut ChaCha20Rng::from_seed([0_u8; 32])));
+    let target_pub_key = secret_key.public_key().to_word();
+    let target_account =
+        get_account_with_default_account_code(target_account_id, target_pub_key, None);
+
+    let note = create_p2id_note(
+        sender_account_id,
+        target_account_id,
+        vec![fungible_asset],
+        NoteType::Public,
+        RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+
+    let data_store = MockDataStore::with_existing(Some(target_account.clone()), Some(vec![note]));
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    // the secret key is provided by the authenticator rather than the transaction arguments
+    let authenticator = BasicAuthenticator::new(&[secret_key]);
+    assert!(authenticator.contains_key(target_pub_key));
+
+    let execute = |authenticator: BasicAuthenticator| {
+        let mut executor =
+            TransactionExecutor::new(data_store.clone()).with_authenticator(authenticator);
+        executor.load_account(target_account_id).unwrap();
+        let tx_script = executor
+            .compile_tx_script(ProgramAst::parse(DEFAULT_AUTH_SCRIPT).unwrap(), vec![], vec![])
+            .unwrap();
+        executor.execute_transaction(
+            target_account_id,
+            block_ref,
+            &note_ids,
+            TransactionArgs::with_tx_script(tx_script),
+        )
+    };
+
+    // APPROVED SIGNATURE REQUEST
+    // --------------------------------------------------------------------------------------------
+    let requests = Arc::new(Mutex::new(Vec::new()));
+    let approving_authenticator = authenticator.clone().on_signature_request({
+        let requests = requests.clone();
+        move |request| {
+            requests.lock().unwrap().push(request.clone());
+            if request.account_delta().vault().removed_assets.is_empty() {
+                Ok(())
+            } else {
+                Err("transaction removes assets from the account".to_string())
+            }
+        }
+    });
+    let executed_transaction = execute(approving_authenticator.clone()).unwrap();
+
+    let requests = requests.lock().unwrap();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].pub_key(), target_pub_key);
+    assert_eq!(requests[0].account_delta().vault().added_assets, vec![fungible_asset]);
+    assert!(requests[0].output_notes().is_empty());
+
+    // the prover must be provided with the same authenticator, but does not request approval
+    let proven_transaction = TransactionProver::new(ProvingOptions::default())
+        .with_authenticator(approving_authenticator)
+        .prove_transaction(executed_transaction)
+        .unwrap();
+    assert_eq!(requests.len(), 1);
+    let verifier = TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL);
+    assert!(verifier.verify(proven_transaction).is_ok());
+
+    // DENIED SIGNATURE REQUEST
+    // --------------------------------------------------------------------------------------------
+    let denying_authenticator = authenticator
+        .on_signature_request(|_| Err("signature request rejected by the user".to_string()));
+    assert!(execute(denying_authenticator).is_err());
+}
+
 /// We test the Pay to script with 2 assets to test the loop inside the script.
 /// So we create a note containing two assets that can only be consumed by the target account.
 #[test]
@@ -0,0 +1,134 @@
+use miden_lib::{notes::create_p2id_timelocked_note, transaction::KernelError};
+use miden_objects::{
+    accounts::{
+        Account, AccountId, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+        ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN,
+        ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN_2, ACCOUNT_ID_SENDER,
+    },
+    assembly::ProgramAst,
+    assets::{Asset, AssetVault, FungibleAsset},
+    crypto::rand::RpoRandomCoin,
+    notes::{Note, NoteType},
+    transaction::{ExecutedTransaction, TransactionArgs},
+    Felt, Word,
+};
+use miden_tx::{TransactionExecutor, TransactionExecutorError};
+use mock::mock::account::DEFAULT_AUTH_SCRIPT;
+
+use crate::{
+    get_account_with_default_account_code, get_new_key_pair_with_advice_map,
+    prove_and_verify_transaction, MockDataStore,
+};
+
+// P2IDT TESTS
+// ================================================================================================
+// We want to test the Pay to ID Time-locked script, which only allows the target account to
+// consume the note once the reference block of the transaction is at or after the release block
+// height of the note. The reference block of the mock chain is block 4.
+
+#[test]
+fn prove_p2idt_script() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let (target_pub_key, target_keypair_felt) = get_new_key_pair_with_advice_map();
+    let target_account =
+        get_account_with_default_account_code(target_account_id, target_pub_key, None);
+
+    // the note is released at the reference block
+    let note = create_timelocked_note(target_account_id, fungible_asset, 4);
+    let executed_transaction =
+        execute(&target_account, &note, (target_pub_key, target_keypair_felt)).unwrap();
+
+    // Prove, serialize/deserialize and verify the transaction
+    assert!(prove_and_verify_transaction(executed_transaction.clone()).is_ok());
+
+    // the target account received the funds and the nonce increased by 1
+    let target_account_after = Account::new(
+        target_account_id,
+        AssetVault::new(&[fungible_asset]).unwrap(),
+        target_account.storage().clone(),
+        target_account.code().clone(),
+        Felt::new(2),
+    );
+    assert_eq!(executed_transaction.final_account().hash(), target_account_after.hash());
+}
+
+#[test]
+fn p2idt_script_fails_before_release_height() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let (target_pub_key, target_keypair_felt) = get_new_key_pair_with_advice_map();
+    let target_account =
+        get_account_with_default_account_code(target_account_id, target_pub_key, None);
+
+    let note = create_timelocked_note(target_account_id, fungible_asset, 5);
+    let result = execute(&target_account, &note, (target_pub_key, target_keypair_felt));
+    assert_eq!(
+        result.unwrap_err().kernel_error(),
+        Some(KernelError::P2idtReleaseHeightNotReached)
+    );
+}
+
+#[test]
+fn p2idt_script_fails_for_other_accounts() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let note = create_timelocked_note(target_account_id, fungible_asset, 3);
+
+    // neither the sender nor any other account can consume a released note
+    for account_id in [ACCOUNT_ID_SENDER, ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN_2] {
+        let account_id = AccountId::try_from(account_id).unwrap();
+        let (pub_key, keypair_felt) = get_new_key_pair_with_advice_map();
+        let account = get_account_with_default_account_code(account_id, pub_key, None);
+
+        let result = execute(&account, &note, (pub_key, keypair_felt));
+        assert_eq!(result.unwrap_err().kernel_error(), Some(KernelError::P2idtTargetAcctMismatch));
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a P2IDT note sent by the sender account to the specified target account, which is
+/// released at the specified block height.
+fn create_timelocked_note(target: AccountId, asset: Asset, release_height: u32) -> Note {
+    create_p2id_timelocked_note(
+        AccountId::try_from(ACCOUNT_ID_SENDER).unwrap(),
+        target,
+        vec![asset],
+        NoteType::Public,
+        release_height,
+        RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap()
+}
+
+/// Executes a transaction against the specified account which consumes the provided note, with
+/// the provided key pair made available via the advice map.
+fn execute(
+    account: &Account,
+    note: &Note,
+    key_pair: (Word, Vec<Felt>),
+) -> Result<ExecutedTransaction, TransactionExecutorError> {
+    let data_store = MockDataStore::with_existing(Some(account.clone()), Some(vec![note.clone()]));
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(account.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+
+    let tx_script_code = ProgramAst::parse(DEFAULT_AUTH_SCRIPT).unwrap();
+    let tx_script = executor.compile_tx_script(tx_script_code, vec![key_pair], vec![]).unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+
+    executor.execute_transaction(account.id(), block_ref, &[note.id()], tx_args)
+}
@@ -0,0 +1,129 @@
+use miden_lib::notes::create_p2pk_note;
+use miden_objects::{
+    accounts::{
+        Account, AccountId, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+        ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN, ACCOUNT_ID_SENDER,
+    },
+    assembly::ProgramAst,
+    assets::{Asset, AssetVault, FungibleAsset},
+    crypto::{dsa::rpo_falcon512::SecretKey, rand::RpoRandomCoin, utils::Serializable},
+    notes::NoteType,
+    transaction::TransactionArgs,
+    Felt, Word,
+};
+use miden_tx::TransactionExecutor;
+use mock::mock::account::DEFAULT_AUTH_SCRIPT;
+use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
+
+use crate::{
+    get_account_with_default_account_code, get_new_key_pair_with_advice_map,
+    prove_and_verify_transaction, MockDataStore,
+};
+
+// P2PK TESTS
+// ================================================================================================
+// We test the Pay to Public Key script. So we create a note that can be consumed by any account,
+// as long as the executor can sign the transaction with the key specified by the note.
+
+#[test]
+fn prove_p2pk_script() {
+    // Create assets
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+
+    // Create the key pair of the note owner, which is not tied to any account
+    let (owner_pub_key, owner_sk_pk_felt) = get_key_pair_with_advice_map([1_u8; 32]);
+
+    // Create sender and target account
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let (target_pub_key, target_sk_pk_felt) = get_new_key_pair_with_advice_map();
+    let target_account =
+        get_account_with_default_account_code(target_account_id, target_pub_key, None);
+
+    // Create the note
+    let note = create_p2pk_note(
+        sender_account_id,
+        owner_pub_key,
+        vec![fungible_asset],
+        NoteType::Public,
+        RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+    assert_eq!(note.inputs().values(), owner_pub_key.as_slice());
+
+    // CONSTRUCT AND EXECUTE TX (Success)
+    // --------------------------------------------------------------------------------------------
+    let data_store =
+        MockDataStore::with_existing(Some(target_account.clone()), Some(vec![note.clone()]));
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(target_account_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let tx_script_code = ProgramAst::parse(DEFAULT_AUTH_SCRIPT).unwrap();
+
+    let tx_script = executor
+        .compile_tx_script(
+            tx_script_code.clone(),
+            vec![(target_pub_key, target_sk_pk_felt.clone()), (owner_pub_key, owner_sk_pk_felt)],
+            vec![],
+        )
+        .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+
+    // Execute the transaction and get the witness
+    let executed_transaction = executor
+        .execute_transaction(target_account_id, block_ref, &note_ids, tx_args)
+        .unwrap();
+
+    // Prove, serialize/deserialize and verify the transaction
+    assert!(prove_and_verify_transaction(executed_transaction.clone()).is_ok());
+
+    // vault delta
+    let target_account_after: Account = Account::new(
+        target_account.id(),
+        AssetVault::new(&[fungible_asset]).unwrap(),
+        target_account.storage().clone(),
+        target_account.code().clone(),
+        Felt::new(2),
+    );
+    assert_eq!(executed_transaction.final_account().hash(), target_account_after.hash());
+
+    // CONSTRUCT AND EXECUTE TX (Failure)
+    // --------------------------------------------------------------------------------------------
+    // The account tries to consume the note without being able to sign with the owner's key, we
+    // expect an error
+
+    let tx_script = executor
+        .compile_tx_script(tx_script_code, vec![(target_pub_key, target_sk_pk_felt)], vec![])
+        .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+
+    let executed_transaction_2 =
+        executor.execute_transaction(target_account_id, block_ref, &note_ids, tx_args);
+
+    // Check that we got the expected result - TransactionExecutorError
+    assert!(executed_transaction_2.is_err());
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a key pair generated from the specified seed, and the secret key in the form in which
+/// it is provided to the advice map.
+fn get_key_pair_with_advice_map(seed: [u8; 32]) -> (Word, Vec<Felt>) {
+    let mut rng = ChaCha20Rng::from_seed(seed);
+
+    let sec_key = SecretKey::with_rng(&mut rng);
+    let pub_key: Word = sec_key.public_key().into();
+    let mut pk_sk_bytes = sec_key.to_bytes();
+    pk_sk_bytes.append(&mut pub_key.to_bytes());
+    let pk_sk_felts = pk_sk_bytes.iter().map(|a| Felt::new(*a as u64)).collect::<Vec<Felt>>();
+
+    (pub_key, pk_sk_felts)
+}
@@ -0,0 +1,195 @@
+use miden_lib::notes::{create_partial_swap_note, swap::PartialSwapFill};
+use miden_objects::{
+    accounts::{
+        Account, AccountId, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+        ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2, ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
+        ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN, ACCOUNT_ID_SENDER,
+    },
+    assembly::ProgramAst,
+    assets::{Asset, AssetVault, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails},
+    crypto::rand::RpoRandomCoin,
+    notes::{Note, NoteEnvelope, NoteType},
+    transaction::{ExecutedTransaction, TransactionArgs},
+    Felt, NoteError,
+};
+use miden_tx::TransactionExecutor;
+use mock::mock::account::DEFAULT_AUTH_SCRIPT;
+
+use crate::{
+    get_account_with_default_account_code, get_new_key_pair_with_advice_map,
+    prove_and_verify_transaction, MockDataStore,
+};
+
+/// Executes a transaction in which an account holding the specified assets fills the provided
+/// SWAPP note, and returns the account together with the transaction.
+fn execute_partial_swap(
+    note: &Note,
+    taker_assets: &[Asset],
+    requested_fill: u64,
+) -> (Account, PartialSwapFill, ExecutedTransaction) {
+    let taker_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let (taker_pub_key, taker_sk_felt) = get_new_key_pair_with_advice_map();
+    let taker = get_account_with_default_account_code(taker_id, taker_pub_key, None);
+    let taker = Account::new(
+        taker.id(),
+        AssetVault::new(taker_assets).unwrap(),
+        taker.storage().clone(),
+        taker.code().clone(),
+        taker.nonce(),
+    );
+
+    let fill = PartialSwapFill::new(note, taker_id, requested_fill).unwrap();
+
+    let data_store = MockDataStore::with_existing(Some(taker.clone()), Some(vec![note.clone()]));
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(taker_id).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let tx_script_code = ProgramAst::parse(DEFAULT_AUTH_SCRIPT).unwrap();
+    let tx_script = executor
+        .compile_tx_script(tx_script_code, vec![(taker_pub_key, taker_sk_felt)], vec![])
+        .unwrap();
+    let mut tx_args = TransactionArgs::with_tx_script(tx_script);
+    fill.extend_tx_args(&mut tx_args);
+
+    let executed_transaction = executor
+        .execute_transaction(taker_id, block_ref, &[note.id()], tx_args)
+        .expect("Transaction consuming partial swap note failed");
+
+    (taker, fill, executed_transaction)
+}
+
+#[test]
+fn prove_partial_swap_script() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let faucet_id_2 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2).unwrap();
+    let offered_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+    let requested_asset: Asset = FungibleAsset::new(faucet_id_2, 50).unwrap().into();
+
+    let maker_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let (note, payback_serial_num) = create_partial_swap_note(
+        maker_id,
+        offered_asset,
+        requested_asset,
+        NoteType::OffChain,
+        RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+
+    // FILL A PART OF THE OFFER
+    // --------------------------------------------------------------------------------------------
+    let taker_assets: [Asset; 1] = [FungibleAsset::new(faucet_id_2, 40).unwrap().into()];
+    let (taker, fill, executed_transaction) = execute_partial_swap(&note, &taker_assets, 20);
+    assert!(prove_and_verify_transaction(executed_transaction.clone()).is_ok());
+
+    // the taker received 40 units of the offered asset for 20 units of the requested asset
+    assert_eq!(fill.offered_fill(), 40);
+    assert!(!fill.is_complete());
+    let taker_after = Account::new(
+        taker.id(),
+        AssetVault::new(&[
+            FungibleAsset::new(faucet_id, 40).unwrap().into(),
+            FungibleAsset::new(faucet_id_2, 20).unwrap().into(),
+        ])
+        .unwrap(),
+        taker.storage().clone(),
+        taker.code().clone(),
+        Felt::new(2),
+    );
+    assert_eq!(executed_transaction.final_account().hash(), taker_after.hash());
+
+    // the payback note and the remainder note have been created
+    let output_notes = executed_transaction.output_notes();
+    assert_eq!(output_notes.num_notes(), 2);
+    assert_eq!(fill.payback_note().serial_num(), payback_serial_num);
+    assert_eq!(NoteEnvelope::from(output_notes.get_note(0)), fill.payback_note().into());
+
+    let remainder = fill.remainder_note().unwrap();
+    assert_eq!(NoteEnvelope::from(output_notes.get_note(1)), remainder.into());
+    let remainder_asset: Asset = FungibleAsset::new(faucet_id, 60).unwrap().into();
+    assert_eq!(remainder.assets().iter().as_slice(), &[remainder_asset]);
+
+    // FILL THE REMAINDER OF THE OFFER
+    // --------------------------------------------------------------------------------------------
+    let taker_assets: [Asset; 1] = [FungibleAsset::new(faucet_id_2, 30).unwrap().into()];
+    let (taker, fill, executed_transaction) = execute_partial_swap(remainder, &taker_assets, 30);
+    assert!(prove_and_verify_transaction(executed_transaction.clone()).is_ok());
+
+    // the taker received the whole remainder, and only the payback note has been created
+    assert!(fill.is_complete());
+    let taker_after = Account::new(
+        taker.id(),
+        AssetVault::new(&[FungibleAsset::new(faucet_id, 60).unwrap().into()]).unwrap(),
+        taker.storage().clone(),
+        taker.code().clone(),
+        Felt::new(2),
+    );
+    assert_eq!(executed_transaction.final_account().hash(), taker_after.hash());
+
+    let output_notes = executed_transaction.output_notes();
+    assert_eq!(output_notes.num_notes(), 1);
+    assert_eq!(NoteEnvelope::from(output_notes.get_note(0)), fill.payback_note().into());
+}
+
+#[test]
+fn partial_swap_rejects_invalid_offers_and_fills() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let faucet_id_2 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2).unwrap();
+    let non_fungible_faucet_id =
+        AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let maker_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let rng = || RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]);
+
+    let offered_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+    let non_fungible_asset: Asset = NonFungibleAsset::new(
+        &NonFungibleAssetDetails::new(non_fungible_faucet_id, vec![1, 2, 3, 4]).unwrap(),
+    )
+    .unwrap()
+    .into();
+
+    // only fungible assets can be partially swapped
+    let result = create_partial_swap_note(
+        maker_id,
+        offered_asset,
+        non_fungible_asset,
+        NoteType::OffChain,
+        rng(),
+    );
+    assert_eq!(result.err(), Some(NoteError::InvalidPartialSwapAsset(non_fungible_asset)));
+
+    // the amounts must be small enough for the prices to be compared in the script
+    let large_asset: Asset = FungibleAsset::new(faucet_id_2, 1 << 40).unwrap().into();
+    let large_offered_asset: Asset = FungibleAsset::new(faucet_id, 1 << 23).unwrap().into();
+    let result = create_partial_swap_note(
+        maker_id,
+        large_offered_asset,
+        large_asset,
+        NoteType::OffChain,
+        rng(),
+    );
+    assert_eq!(
+        result.err(),
+        Some(NoteError::PartialSwapAmountsTooLarge {
+            offered_amount: 1 << 23,
+            requested_amount: 1 << 40,
+        })
+    );
+
+    // fills must be non-zero and must not exceed the requested amount
+    let requested_asset: Asset = FungibleAsset::new(faucet_id_2, 50).unwrap().into();
+    let (note, _) = create_partial_swap_note(
+        maker_id,
+        offered_asset,
+        requested_asset,
+        NoteType::OffChain,
+        rng(),
+    )
+    .unwrap();
+    for requested_fill in [0, 51] {
+        assert_eq!(
+            PartialSwapFill::new(&note, maker_id, requested_fill),
+            Err(NoteError::InvalidPartialSwapFill { requested_fill, requested_amount: 50 })
+        );
+    }
+}
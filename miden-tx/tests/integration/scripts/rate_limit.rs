@@ -0,0 +1,194 @@
+use miden_lib::{
+    accounts::wallets::{create_rate_limited_wallet, NoteRateLimit},
+    transaction::KernelError,
+    AuthScheme,
+};
+use miden_objects::{
+    accounts::{
+        Account, AccountId, AccountStorage, AccountStorageType, AccountType, SlotItem, StorageSlot,
+        ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
+    },
+    assembly::ProgramAst,
+    assets::{Asset, AssetVault, FungibleAsset},
+    crypto::dsa::rpo_falcon512::PublicKey,
+    notes::NoteType,
+    transaction::{ExecutedTransaction, TransactionArgs},
+    Felt, Word, ONE, ZERO,
+};
+use miden_tx::{TransactionExecutor, TransactionExecutorError};
+use mock::procedures::prepare_word;
+
+use crate::{get_new_key_pair_with_advice_map, prove_and_verify_transaction, MockDataStore};
+
+// RATE LIMIT TESTS
+// ================================================================================================
+// We want to test the note rate limit component, which limits the number of notes an account can
+// create within a window of a configured number of blocks. The reference block of the mock chain
+// is block 4.
+
+#[test]
+fn prove_rate_limited_wallet_send_asset() {
+    // the window opened at block 0 is still open at block 4
+    let rate_limit = NoteRateLimit::new(2, 10).unwrap();
+    let (account, pub_key, keypair_felts) = get_rate_limited_account(rate_limit, [ZERO; 4]);
+
+    let executed_transaction = send_notes(&account, pub_key, keypair_felts, 2)
+        .expect("Transaction creating notes within the rate limit failed");
+
+    // Prove, serialize/deserialize and verify the transaction
+    assert!(prove_and_verify_transaction(executed_transaction.clone()).is_ok());
+
+    assert_eq!(executed_transaction.output_notes().num_notes(), 2);
+    assert_eq!(get_window(account, &executed_transaction), [ZERO, Felt::new(2), ZERO, ZERO]);
+}
+
+#[test]
+fn rate_limited_wallet_fails_when_limit_exceeded() {
+    let rate_limit = NoteRateLimit::new(2, 10).unwrap();
+
+    // creating more notes than allowed within a single transaction fails
+    let (account, pub_key, keypair_felts) = get_rate_limited_account(rate_limit, [ZERO; 4]);
+    let executed_transaction = send_notes(&account, pub_key, keypair_felts, 3);
+    assert_eq!(
+        executed_transaction.unwrap_err().kernel_error(),
+        Some(KernelError::RateLimitExceeded)
+    );
+
+    // and so does creating a note once the limit was reached by previous transactions
+    let window = [ZERO, Felt::new(2), ZERO, ZERO];
+    let (account, pub_key, keypair_felts) = get_rate_limited_account(rate_limit, window);
+    let executed_transaction = send_notes(&account, pub_key, keypair_felts, 1);
+    assert_eq!(
+        executed_transaction.unwrap_err().kernel_error(),
+        Some(KernelError::RateLimitExceeded)
+    );
+}
+
+#[test]
+fn rate_limited_wallet_opens_new_window() {
+    // the window opened at block 0 expires at block 4, so the limit reached within it no longer
+    // applies and a new window is opened at block 4
+    let rate_limit = NoteRateLimit::new(2, 4).unwrap();
+    let window = [ZERO, Felt::new(2), ZERO, ZERO];
+    let (account, pub_key, keypair_felts) = get_rate_limited_account(rate_limit, window);
+
+    let executed_transaction = send_notes(&account, pub_key, keypair_felts, 1).unwrap();
+    assert_eq!(get_window(account, &executed_transaction), [Felt::new(4), ONE, ZERO, ZERO]);
+}
+
+#[test]
+fn note_rate_limit_rejects_invalid_config() {
+    assert!(NoteRateLimit::new(0, 10).is_err());
+    assert!(NoteRateLimit::new(10, 0).is_err());
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a rate limited wallet holding 100 units of a fungible asset, with the state of the
+/// current window set to `window`, together with the key pair of the account owner.
+fn get_rate_limited_account(rate_limit: NoteRateLimit, window: Word) -> (Account, Word, Vec<Felt>) {
+    let (pub_key, keypair_felts) = get_new_key_pair_with_advice_map();
+    let auth_scheme = AuthScheme::RpoFalcon512 { pub_key: PublicKey::new(pub_key) };
+    let (account, _) = create_rate_limited_wallet(
+        [0; 32],
+        auth_scheme,
+        rate_limit,
+        AccountType::RegularAccountUpdatableCode,
+        AccountStorageType::OffChain,
+    )
+    .unwrap();
+
+    let account_storage = AccountStorage::new(vec![
+        SlotItem {
+            index: 0,
+            slot: StorageSlot::new_value(pub_key),
+        },
+        SlotItem {
+            index: 1,
+            slot: StorageSlot::new_value(rate_limit.into()),
+        },
+        SlotItem {
+            index: 2,
+            slot: StorageSlot::new_value(window),
+        },
+    ])
+    .unwrap();
+
+    let account = Account::new(
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap(),
+        AssetVault::new(&[get_asset(100)]).unwrap(),
+        account_storage,
+        account.code().clone(),
+        ONE,
+    );
+
+    (account, pub_key, keypair_felts)
+}
+
+/// Returns the state of the current window of the account after the specified transaction.
+fn get_window(mut account: Account, executed_transaction: &ExecutedTransaction) -> Word {
+    account.apply_delta(executed_transaction.account_delta()).unwrap();
+    assert_eq!(executed_transaction.final_account().hash(), account.hash());
+
+    account.storage().get_item(2).into()
+}
+
+fn get_asset(amount: u64) -> Asset {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    FungibleAsset::new(faucet_id, amount).unwrap().into()
+}
+
+/// Executes a transaction against the specified account which creates `num_notes` notes, each
+/// carrying 10 units of the fungible asset of the account.
+fn send_notes(
+    account: &Account,
+    pub_key: Word,
+    keypair_felts: Vec<Felt>,
+    num_notes: u64,
+) -> Result<ExecutedTransaction, TransactionExecutorError> {
+    let data_store = MockDataStore::with_existing(Some(account.clone()), Some(vec![]));
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(account.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+
+    let mut send_notes = String::new();
+    for i in 0..num_notes {
+        let recipient = [Felt::new(i), ONE, Felt::new(2), Felt::new(3)];
+        send_notes.push_str(&format!(
+            "
+            push.{recipient}
+            push.{note_type}
+            push.{tag}
+            push.{asset}
+            call.rate_limit::send_asset
+            drop drop dropw dropw
+            ",
+            recipient = prepare_word(&recipient),
+            note_type = NoteType::OffChain as u8,
+            tag = 4,
+            asset = prepare_word(&get_asset(10).into()),
+        ));
+    }
+
+    let tx_script_code = ProgramAst::parse(&format!(
+        "
+        use.miden::contracts::auth::basic->auth_tx
+        use.miden::contracts::limits::rate_limit
+
+        begin
+            {send_notes}
+            call.auth_tx::auth_tx_rpo_falcon512
+        end
+        "
+    ))
+    .unwrap();
+    let tx_script = executor
+        .compile_tx_script(tx_script_code, vec![(pub_key, keypair_felts)], vec![])
+        .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+
+    executor.execute_transaction(account.id(), block_ref, &[], tx_args)
+}
@@ -0,0 +1,244 @@
+use miden_lib::{
+    accounts::wallets::create_recoverable_wallet,
+    notes::{create_recovery_note, utils::build_p2id_recipient},
+    transaction::KernelError,
+    AuthScheme,
+};
+use miden_objects::{
+    accounts::{
+        Account, AccountId, AccountStorageType, AccountType, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+        ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
+        ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
+        ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN, ACCOUNT_ID_SENDER,
+    },
+    assembly::ProgramAst,
+    assets::{Asset, AssetVault, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails},
+    crypto::{dsa::rpo_falcon512::PublicKey, rand::RpoRandomCoin},
+    notes::{
+        Note, NoteAssets, NoteEnvelope, NoteExecutionMode, NoteId, NoteMetadata, NoteTag, NoteType,
+    },
+    transaction::{ExecutedTransaction, TransactionArgs},
+    Felt, Word, ONE, ZERO,
+};
+use miden_tx::{TransactionExecutor, TransactionExecutorError};
+
+use crate::{get_new_key_pair_with_advice_map, prove_and_verify_transaction, MockDataStore};
+
+// RECOVERY TESTS
+// ================================================================================================
+// We want to test the inactivity recovery component, which allows the heir of an account to
+// sweep the assets out of the account once the account has been inactive for the configured
+// number of blocks. The reference block of the mock chain is block 4 and the account has not
+// recorded any activity yet, i.e., its last activity height is 0.
+
+#[test]
+fn prove_recovery_script() {
+    let (fungible_asset, non_fungible_asset) = get_assets();
+    let heir_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let (pub_key, _) = get_new_key_pair_with_advice_map();
+    let account =
+        get_recoverable_account(pub_key, heir_id, 4, vec![fungible_asset, non_fungible_asset]);
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+
+    // the note sweeps the non-fungible asset out of the account and carries a fungible asset
+    let heir_asset: Asset = FungibleAsset::new(faucet_id, 10).unwrap().into();
+    let (note, sweep_serial_nums) = create_recovery_note(
+        heir_id,
+        account.id(),
+        vec![heir_asset],
+        vec![non_fungible_asset],
+        NoteType::OffChain,
+        RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+
+    // CONSTRUCT AND EXECUTE TX (Success)
+    // --------------------------------------------------------------------------------------------
+    let data_store = MockDataStore::with_existing(Some(account.clone()), Some(vec![note]));
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(account.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let executed_transaction = executor
+        .execute_transaction(account.id(), block_ref, &note_ids, TransactionArgs::default())
+        .expect("Transaction consuming recovery note failed");
+
+    // Prove, serialize/deserialize and verify the transaction
+    assert!(prove_and_verify_transaction(executed_transaction.clone()).is_ok());
+
+    // the account received the asset of the note and lost the swept asset
+    let account_after = Account::new(
+        account.id(),
+        AssetVault::new(&[FungibleAsset::new(faucet_id, 110).unwrap().into()]).unwrap(),
+        account.storage().clone(),
+        account.code().clone(),
+        Felt::new(2),
+    );
+    assert_eq!(executed_transaction.final_account().hash(), account_after.hash());
+
+    // the swept asset was sent to the heir
+    assert_eq!(executed_transaction.output_notes().num_notes(), 1);
+
+    let recipient = build_p2id_recipient(heir_id, sweep_serial_nums[0]).unwrap();
+    let tag = NoteTag::from_account_id(heir_id, NoteExecutionMode::Local).unwrap();
+    let note_metadata = NoteMetadata::new(account.id(), NoteType::OffChain, tag, ZERO).unwrap();
+    let assets = NoteAssets::new(vec![non_fungible_asset]).unwrap();
+    let note_id = NoteId::new(recipient, assets.commitment());
+
+    let created_note = executed_transaction.output_notes().get_note(0);
+    assert_eq!(
+        NoteEnvelope::from(created_note),
+        NoteEnvelope::new(note_id, note_metadata).unwrap()
+    );
+}
+
+#[test]
+fn recovery_script_fails_before_inactivity_period() {
+    let (fungible_asset, non_fungible_asset) = get_assets();
+    let heir_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let (pub_key, _) = get_new_key_pair_with_advice_map();
+    let account = get_recoverable_account(pub_key, heir_id, 5, vec![fungible_asset]);
+
+    let (note, _) = create_recovery_note(
+        heir_id,
+        account.id(),
+        vec![non_fungible_asset],
+        vec![fungible_asset],
+        NoteType::OffChain,
+        RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+
+    let executed_transaction = execute_with_notes(account, vec![note]);
+    assert_eq!(
+        executed_transaction.unwrap_err().kernel_error(),
+        Some(KernelError::RecoveryInactivityPeriodNotElapsed)
+    );
+}
+
+#[test]
+fn recovery_script_fails_for_sender_other_than_heir() {
+    let (fungible_asset, non_fungible_asset) = get_assets();
+    let heir_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let (pub_key, _) = get_new_key_pair_with_advice_map();
+    let account = get_recoverable_account(pub_key, heir_id, 4, vec![fungible_asset]);
+
+    let malicious_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+    let (note, _) = create_recovery_note(
+        malicious_id,
+        account.id(),
+        vec![non_fungible_asset],
+        vec![fungible_asset],
+        NoteType::OffChain,
+        RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+
+    let executed_transaction = execute_with_notes(account, vec![note]);
+    assert_eq!(
+        executed_transaction.unwrap_err().kernel_error(),
+        Some(KernelError::RecoverySenderIsNotHeir)
+    );
+}
+
+#[test]
+fn recoverable_wallet_records_owner_activity() {
+    let (fungible_asset, _) = get_assets();
+    let heir_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let (pub_key, keypair_felts) = get_new_key_pair_with_advice_map();
+    let account = get_recoverable_account(pub_key, heir_id, 4, vec![fungible_asset]);
+
+    let data_store = MockDataStore::with_existing(Some(account.clone()), Some(vec![]));
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(account.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+
+    let tx_script_code = ProgramAst::parse(
+        "
+        use.miden::contracts::recovery::inactivity
+
+        begin
+            call.inactivity::auth_tx_rpo_falcon512
+        end
+        ",
+    )
+    .unwrap();
+    let tx_script = executor
+        .compile_tx_script(tx_script_code, vec![(pub_key, keypair_felts)], vec![])
+        .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+
+    let executed_transaction =
+        executor.execute_transaction(account.id(), block_ref, &[], tx_args).unwrap();
+
+    // the reference block of the transaction was recorded as the last activity height
+    assert_eq!(executed_transaction.account_delta().nonce(), Some(Felt::new(2)));
+    assert_eq!(
+        executed_transaction.account_delta().storage().updated_items,
+        vec![(2, [Felt::from(block_ref), ZERO, ZERO, ZERO])]
+    );
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn get_assets() -> (Asset, Asset) {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+
+    let faucet_id_2 = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let non_fungible_asset: Asset = NonFungibleAsset::new(
+        &NonFungibleAssetDetails::new(faucet_id_2, vec![1, 2, 3, 4]).unwrap(),
+    )
+    .unwrap()
+    .into();
+
+    (fungible_asset, non_fungible_asset)
+}
+
+fn get_recoverable_account(
+    pub_key: Word,
+    heir: AccountId,
+    inactivity_period: u32,
+    assets: Vec<Asset>,
+) -> Account {
+    let auth_scheme = AuthScheme::RpoFalcon512 { pub_key: PublicKey::new(pub_key) };
+    let (account, _) = create_recoverable_wallet(
+        [0; 32],
+        auth_scheme,
+        heir,
+        inactivity_period,
+        AccountType::RegularAccountUpdatableCode,
+        AccountStorageType::OffChain,
+    )
+    .unwrap();
+
+    Account::new(
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap(),
+        AssetVault::new(&assets).unwrap(),
+        account.storage().clone(),
+        account.code().clone(),
+        ONE,
+    )
+}
+
+fn execute_with_notes(
+    account: Account,
+    notes: Vec<Note>,
+) -> Result<ExecutedTransaction, TransactionExecutorError> {
+    let data_store = MockDataStore::with_existing(Some(account.clone()), Some(notes));
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(account.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    executor.execute_transaction(account.id(), block_ref, &note_ids, TransactionArgs::default())
+}
@@ -1,8 +1,12 @@
-use miden_lib::notes::{create_swap_note, utils::build_p2id_recipient};
+use miden_lib::notes::{
+    create_p2id_note, create_swap_note,
+    swap::{SwapFill, SwapOffer, SwapPrice},
+    utils::build_p2id_recipient,
+};
 use miden_objects::{
     accounts::{
         Account, AccountId, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
-        ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
+        ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2, ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
         ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN, ACCOUNT_ID_SENDER,
     },
     assembly::ProgramAst,
@@ -10,7 +14,7 @@ use miden_objects::{
     crypto::rand::RpoRandomCoin,
     notes::{NoteAssets, NoteEnvelope, NoteExecutionMode, NoteId, NoteMetadata, NoteTag, NoteType},
     transaction::TransactionArgs,
-    Felt, ZERO,
+    Felt, NoteError, ZERO,
 };
 use miden_tx::TransactionExecutor;
 use mock::mock::account::DEFAULT_AUTH_SCRIPT;
@@ -108,3 +112,142 @@ fn prove_swap_script() {
         NoteEnvelope::new(note_id, note_metadata).unwrap()
     );
 }
+
+#[test]
+fn swap_fill_request() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset: Asset = FungibleAsset::new(faucet_id, 100).unwrap().into();
+
+    let faucet_id_2 = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let non_fungible_asset: Asset = NonFungibleAsset::new(
+        &NonFungibleAssetDetails::new(faucet_id_2, vec![1, 2, 3, 4]).unwrap(),
+    )
+    .unwrap()
+    .into();
+
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let (target_pub_key, target_sk_felt) = get_new_key_pair_with_advice_map();
+    let target_account = get_account_with_default_account_code(
+        target_account_id,
+        target_pub_key,
+        Some(non_fungible_asset),
+    );
+
+    let (note, repay_serial_num) = create_swap_note(
+        sender_account_id,
+        fungible_asset,
+        non_fungible_asset,
+        NoteType::Public,
+        RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+
+    // public SWAP notes are tagged for the swap use case of the pair of assets
+    assert_eq!(note.metadata().tag(), NoteTag::for_swap(&fungible_asset, &non_fungible_asset));
+    assert!(note.metadata().tag().is_swap());
+
+    // the offer is parsed from the SWAP note
+    let offer = SwapOffer::from_note(&note).unwrap();
+    assert_eq!(offer.note_id(), note.id());
+    assert_eq!(offer.maker(), sender_account_id);
+    assert_eq!(offer.offered_asset(), fungible_asset);
+    assert_eq!(offer.requested_asset(), non_fungible_asset);
+    assert_eq!(
+        offer.payback_recipient(),
+        build_p2id_recipient(sender_account_id, repay_serial_num).unwrap()
+    );
+    assert_eq!(
+        offer.payback_tag(),
+        NoteTag::from_account_id(sender_account_id, NoteExecutionMode::Local).unwrap()
+    );
+    assert_eq!(offer.price(), None);
+
+    // notes with other scripts are rejected
+    let p2id_note = create_p2id_note(
+        sender_account_id,
+        target_account_id,
+        vec![fungible_asset],
+        NoteType::Public,
+        RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+    assert!(matches!(
+        SwapOffer::from_note(&p2id_note),
+        Err(NoteError::NoteScriptMismatch { .. })
+    ));
+
+    // fill the offer
+    let data_store =
+        MockDataStore::with_existing(Some(target_account.clone()), Some(vec![note.clone()]));
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(target_account_id).unwrap();
+
+    let tx_script_code = ProgramAst::parse(DEFAULT_AUTH_SCRIPT).unwrap();
+    let tx_script = executor
+        .compile_tx_script(tx_script_code, vec![(target_pub_key, target_sk_felt)], vec![])
+        .unwrap();
+    let request = SwapFill::new(target_account_id)
+        .with_offer(offer)
+        .with_tx_script(tx_script)
+        .build()
+        .unwrap();
+    assert_eq!(request.note_ids(), [note.id()]);
+
+    let block_ref = data_store.block_header.block_num();
+    let executed_transaction = executor
+        .execute_transaction(
+            target_account_id,
+            block_ref,
+            request.note_ids(),
+            request.tx_args().clone(),
+        )
+        .expect("Transaction filling the swap offer failed");
+
+    assert!(request.is_fulfilled_by(&executed_transaction));
+    assert_eq!(
+        NoteEnvelope::from(executed_transaction.output_notes().get_note(0)),
+        request.payback_notes()[0]
+    );
+}
+
+#[test]
+fn swap_price() {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let faucet_id_2 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2).unwrap();
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+    let offer = |offered: Asset, requested: Asset| {
+        let (note, _) = create_swap_note(
+            sender_account_id,
+            offered,
+            requested,
+            NoteType::Public,
+            RpoRandomCoin::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]),
+        )
+        .unwrap();
+        SwapOffer::from_note(&note).unwrap()
+    };
+    let asset =
+        |faucet_id, amount| -> Asset { FungibleAsset::new(faucet_id, amount).unwrap().into() };
+
+    // asks for 2 units of the second asset per unit of the first asset
+    let ask = offer(asset(faucet_id, 50), asset(faucet_id_2, 100));
+    // offers 1.5 units of the second asset per unit of the first asset
+    let bid = offer(asset(faucet_id_2, 150), asset(faucet_id, 100));
+
+    let price = ask.price().unwrap();
+    assert_eq!(price, SwapPrice::new(4, 2).unwrap());
+    assert!(price > SwapPrice::new(3, 2).unwrap());
+    assert_eq!(price.quote(7), 14);
+    assert_eq!(bid.price().unwrap().quote(1), 1);
+    assert_eq!(SwapPrice::new(1, 0), None);
+
+    // the offers cross only if each offered asset covers the asset requested by the other offer
+    assert!(!ask.crosses(&bid));
+    let bid = offer(asset(faucet_id_2, 100), asset(faucet_id, 50));
+    assert!(ask.crosses(&bid));
+    assert!(bid.crosses(&ask));
+    assert!(!ask.crosses(&ask));
+}
@@ -0,0 +1,187 @@
+use miden_lib::{
+    accounts::wallets::{
+        build_tx_script_allowlist_update_script, create_tx_script_allowlist_wallet,
+    },
+    notes::create_p2id_note,
+    transaction::TransactionKernel,
+    AuthScheme,
+};
+use miden_objects::{
+    accounts::{
+        Account, AccountId, AccountStorageType, AccountType, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+        ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+        ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
+    },
+    assembly::ProgramAst,
+    assets::{Asset, AssetVault, FungibleAsset},
+    crypto::{dsa::rpo_falcon512::PublicKey, rand::RpoRandomCoin},
+    notes::{Note, NoteType},
+    transaction::{ExecutedTransaction, TransactionArgs, TransactionScript},
+    Digest, Felt, Word, ONE, ZERO,
+};
+use miden_tx::{TransactionExecutor, TransactionExecutorError};
+use mock::procedures::prepare_word;
+
+use crate::{get_new_key_pair_with_advice_map, prove_and_verify_transaction, MockDataStore};
+
+// TX SCRIPT ALLOWLIST TESTS
+// ================================================================================================
+// We want to test the transaction script allowlist authentication component, which approves
+// transactions executing a transaction script on an allowlist without a signature.
+
+#[test]
+fn prove_tx_script_allowlist_pre_approved_script() {
+    let (account, ..) = get_tx_script_allowlist_account(&[bot_script_root()]);
+
+    // the transaction is executed without the key of the account owner
+    let executed_transaction = execute(&account, vec![], bot_script_code(), None)
+        .expect("Transaction executing a pre-approved script failed");
+
+    // Prove, serialize/deserialize and verify the transaction
+    assert!(prove_and_verify_transaction(executed_transaction.clone()).is_ok());
+
+    assert_eq!(executed_transaction.output_notes().num_notes(), 1);
+    assert_eq!(executed_transaction.final_account().nonce(), Felt::new(2));
+}
+
+#[test]
+fn tx_script_allowlist_requires_signature_for_other_scripts() {
+    let other_script = Digest::new([ONE, Felt::new(2), Felt::new(3), Felt::new(4)]);
+    let (account, pub_key, keypair_felts) = get_tx_script_allowlist_account(&[other_script]);
+
+    assert!(execute(&account, vec![], bot_script_code(), None).is_err());
+    execute(&account, vec![], bot_script_code(), Some((pub_key, keypair_felts)))
+        .expect("Transaction authenticated by the account owner failed");
+}
+
+#[test]
+fn tx_script_allowlist_requires_signature_when_consuming_notes() {
+    let (account, pub_key, keypair_felts) = get_tx_script_allowlist_account(&[bot_script_root()]);
+    let note = create_p2id_note(
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap(),
+        account.id(),
+        vec![get_asset(5)],
+        NoteType::OffChain,
+        RpoRandomCoin::new([ONE, Felt::new(2), Felt::new(3), Felt::new(4)]),
+    )
+    .unwrap();
+
+    // the scripts of consumed notes could invoke the procedures of the account
+    assert!(execute(&account, vec![note.clone()], bot_script_code(), None).is_err());
+    execute(&account, vec![note], bot_script_code(), Some((pub_key, keypair_felts)))
+        .expect("Transaction authenticated by the account owner failed");
+}
+
+#[test]
+fn tx_script_allowlist_update_via_tx_script() {
+    let other_script = Digest::new([ONE, Felt::new(2), Felt::new(3), Felt::new(4)]);
+    let (mut account, pub_key, keypair_felts) = get_tx_script_allowlist_account(&[other_script]);
+
+    let auth_scheme = AuthScheme::RpoFalcon512 { pub_key: PublicKey::new(pub_key) };
+    let tx_script_code =
+        build_tx_script_allowlist_update_script(&[bot_script_root()], &[other_script], auth_scheme)
+            .unwrap();
+    let executed_transaction =
+        execute(&account, vec![], tx_script_code, Some((pub_key, keypair_felts))).unwrap();
+
+    // the allowlist was updated
+    account.apply_delta(executed_transaction.account_delta()).unwrap();
+    assert_eq!(executed_transaction.final_account().hash(), account.hash());
+    assert_eq!(account.storage().get_array_item(1, other_script[3].as_int()), Ok([ZERO; 4]));
+    assert_eq!(
+        account.storage().get_array_item(1, bot_script_root()[3].as_int()),
+        Ok(bot_script_root().into())
+    );
+
+    // and the bot script can be executed without a signature
+    execute(&account, vec![], bot_script_code(), None)
+        .expect("Transaction executing a pre-approved script failed");
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a transaction script allowlist wallet holding 100 units of a fungible asset, together
+/// with the key pair of the account owner.
+fn get_tx_script_allowlist_account(allowed_tx_scripts: &[Digest]) -> (Account, Word, Vec<Felt>) {
+    let (pub_key, keypair_felts) = get_new_key_pair_with_advice_map();
+    let auth_scheme = AuthScheme::RpoFalcon512 { pub_key: PublicKey::new(pub_key) };
+    let (account, _) = create_tx_script_allowlist_wallet(
+        [0; 32],
+        auth_scheme,
+        allowed_tx_scripts,
+        AccountType::RegularAccountUpdatableCode,
+        AccountStorageType::OffChain,
+    )
+    .unwrap();
+
+    let account = Account::new(
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap(),
+        AssetVault::new(&[get_asset(100)]).unwrap(),
+        account.storage().clone(),
+        account.code().clone(),
+        ONE,
+    );
+
+    (account, pub_key, keypair_felts)
+}
+
+fn get_asset(amount: u64) -> Asset {
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    FungibleAsset::new(faucet_id, amount).unwrap().into()
+}
+
+/// Returns the code of a transaction script which sends 10 units of the fungible asset to a fixed
+/// recipient, as executed by a bot operating the account.
+fn bot_script_code() -> ProgramAst {
+    let recipient = [Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)];
+    ProgramAst::parse(&format!(
+        "
+        use.miden::contracts::auth::tx_script_allowlist
+        use.miden::contracts::wallets::basic->wallet
+
+        begin
+            push.{recipient}
+            push.{note_type}
+            push.0
+            push.{asset}
+            call.wallet::send_asset
+            drop drop dropw dropw
+            call.tx_script_allowlist::auth_tx_rpo_falcon512
+        end
+        ",
+        recipient = prepare_word(&recipient),
+        note_type = NoteType::OffChain as u8,
+        asset = prepare_word(&get_asset(10).into()),
+    ))
+    .unwrap()
+}
+
+fn bot_script_root() -> Digest {
+    let (tx_script, _) =
+        TransactionScript::new(bot_script_code(), vec![], &TransactionKernel::assembler()).unwrap();
+    tx_script.hash()
+}
+
+/// Executes a transaction against the specified account which consumes the specified notes and
+/// executes the specified transaction script. If a key pair is provided, it is made available to
+/// the transaction via the advice map.
+fn execute(
+    account: &Account,
+    notes: Vec<Note>,
+    tx_script_code: ProgramAst,
+    key_pair: Option<(Word, Vec<Felt>)>,
+) -> Result<ExecutedTransaction, TransactionExecutorError> {
+    let data_store = MockDataStore::with_existing(Some(account.clone()), Some(notes));
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(account.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let tx_script = executor.compile_tx_script(tx_script_code, key_pair, vec![]).unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+
+    executor.execute_transaction(account.id(), block_ref, &note_ids, tx_args)
+}
@@ -1,19 +1,39 @@
-use miden_lib::{accounts::wallets::create_basic_wallet, AuthScheme};
+use miden_lib::{
+    accounts::{
+        faucets::create_basic_fungible_faucet,
+        interface::{AccountInterface, KnownInterface},
+        wallets::{create_basic_wallet, BalanceSheet},
+    },
+    create_auth_scheme_migration_tx_script_code,
+    notes::{create_p2id_note, create_p2idr_note, standard_script_registry},
+    AuthScheme, AuthSchemeVersion,
+};
 use miden_objects::{
     accounts::{
-        Account, AccountId, AccountStorage, SlotItem, StorageSlot,
-        ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_OFF_CHAIN_SENDER,
+        Account, AccountId, AccountStorage, AccountStorageType, AccountType, SlotItem, StorageSlot,
+        ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1,
+        ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_OFF_CHAIN_SENDER,
         ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
     },
     assembly::ProgramAst,
-    assets::{Asset, AssetVault, FungibleAsset},
-    crypto::dsa::rpo_falcon512::SecretKey,
-    notes::NoteType,
-    transaction::TransactionArgs,
-    Felt, Word, ONE, ZERO,
+    assets::{Asset, AssetVault, FungibleAsset, TokenSymbol},
+    crypto::{
+        dsa::rpo_falcon512::{PublicKey, SecretKey},
+        merkle::MerklePath,
+        rand::RpoRandomCoin,
+    },
+    notes::{Note, NoteInclusionProof, NoteType, ScriptInfo},
+    transaction::{InputNote, NoteDelivery, NoteDeliveryInstruction, OutputNote, TransactionArgs},
+    utils::serde::{Deserializable, Serializable},
+    Digest, Felt, Word, ONE, ZERO,
+};
+use miden_prover::ProvingOptions;
+use miden_tx::{TransactionExecutor, TransactionProver, TransactionVerifier};
+use mock::{
+    constants::{non_fungible_asset, non_fungible_asset_2, MIN_PROOF_SECURITY_LEVEL},
+    mock::account::DEFAULT_AUTH_SCRIPT,
+    utils::prepare_word,
 };
-use miden_tx::TransactionExecutor;
-use mock::{mock::account::DEFAULT_AUTH_SCRIPT, utils::prepare_word};
 use rand_chacha::{rand_core::SeedableRng, ChaCha20Rng};
 
 use crate::{
@@ -182,6 +202,242 @@ fn prove_send_asset_via_wallet() {
     assert_eq!(executed_transaction.final_account().hash(), sender_account_after.hash());
 }
 
+#[test]
+/// Testing the delivery of notes sent via the basic Miden wallet
+fn prove_send_asset_with_note_delivery() {
+    let faucet_id_1 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset_1: Asset = FungibleAsset::new(faucet_id_1, 100).unwrap().into();
+
+    let sender_account_id = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let (sender_pub_key, sender_keypair_felt) = get_new_key_pair_with_advice_map();
+    let sender_account = get_account_with_default_account_code(
+        sender_account_id,
+        sender_pub_key,
+        fungible_asset_1.into(),
+    );
+
+    // one note is published, one is exported, and one is encrypted to the public key of the target
+    let rng = RpoRandomCoin::new([ONE; 4]);
+    let [published_note, exported_note, encrypted_note] = [10, 40, 50].map(|amount| {
+        let asset = FungibleAsset::new(faucet_id_1, amount).unwrap().into();
+        create_p2id_note(sender_account_id, target_account_id, vec![asset], NoteType::OffChain, rng)
+            .unwrap()
+    });
+    let target_pub_key: Word = [ONE, Felt::new(2), Felt::new(3), Felt::new(4)];
+
+    let data_store = MockDataStore::with_existing(Some(sender_account.clone()), Some(vec![]));
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(sender_account.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+
+    let send_notes = [&published_note, &exported_note, &encrypted_note]
+        .map(|note| {
+            format!(
+                "
+            push.{recipient}
+            push.{note_type}
+            push.{tag}
+            push.{asset}
+            call.wallet::send_asset
+            drop drop dropw dropw",
+                recipient = prepare_word(&note.recipient().digest().into()),
+                note_type = NoteType::OffChain as u8,
+                tag = note.metadata().tag(),
+                asset = prepare_word(&note.assets().iter().next().unwrap().into())
+            )
+        })
+        .join("");
+    let tx_script_code = ProgramAst::parse(&format!(
+        "
+        use.miden::contracts::auth::basic->auth_tx
+        use.miden::contracts::wallets::basic->wallet
+
+        begin
+            {send_notes}
+            call.auth_tx::auth_tx_rpo_falcon512
+        end
+        "
+    ))
+    .unwrap();
+    let tx_script = executor
+        .compile_tx_script(tx_script_code, vec![(sender_pub_key, sender_keypair_felt)], vec![])
+        .unwrap();
+    let mut tx_args = TransactionArgs::with_tx_script(tx_script);
+    tx_args.set_note_delivery(&published_note, NoteDeliveryInstruction::Publish);
+    tx_args.set_note_delivery(&exported_note, NoteDeliveryInstruction::Export);
+    tx_args.set_note_delivery(
+        &encrypted_note,
+        NoteDeliveryInstruction::Encrypt { pub_key: target_pub_key },
+    );
+
+    let executed_transaction = executor
+        .execute_transaction(sender_account.id(), block_ref, &[], tx_args)
+        .unwrap();
+
+    // the details of the notes are not known to the transaction, as the notes are off-chain
+    assert!(executed_transaction
+        .output_notes()
+        .iter()
+        .all(|note| matches!(note, OutputNote::Private(_))));
+
+    // the "encryption" of the test prepends the public key to the serialized note details
+    let prover = TransactionProver::new(ProvingOptions::default()).with_note_encryptor(
+        |pub_key: Word, note: &Note| {
+            let mut ciphertext = pub_key.to_bytes();
+            ciphertext.append(&mut note.to_bytes());
+            Ok(ciphertext)
+        },
+    );
+    let (proven_transaction, delivery_report) =
+        prover.prove_and_deliver(executed_transaction).unwrap();
+
+    // only the details of the published note are included in the proven transaction
+    let output_notes = proven_transaction.output_notes();
+    assert_eq!(output_notes.get_note(0), &OutputNote::Public(published_note.clone()));
+    assert!(matches!(output_notes.get_note(1), OutputNote::Private(_)));
+    assert!(matches!(output_notes.get_note(2), OutputNote::Private(_)));
+
+    assert_eq!(delivery_report.num_notes(), 3);
+    assert_eq!(delivery_report.get(published_note.id()), Some(&NoteDelivery::Published));
+    assert_eq!(
+        delivery_report.get(exported_note.id()),
+        Some(&NoteDelivery::Exported(exported_note.clone()))
+    );
+    let mut expected_ciphertext = target_pub_key.to_bytes();
+    expected_ciphertext.append(&mut encrypted_note.to_bytes());
+    assert_eq!(
+        delivery_report.get(encrypted_note.id()),
+        Some(&NoteDelivery::Encrypted {
+            pub_key: target_pub_key,
+            ciphertext: expected_ciphertext
+        })
+    );
+
+    let verifier = TransactionVerifier::new(MIN_PROOF_SECURITY_LEVEL);
+    assert!(verifier.verify(proven_transaction).is_ok());
+}
+
+#[test]
+/// Testing the vault introspection procedures from a note script and a transaction script
+fn wallet_vault_introspection() {
+    let faucet_id_1 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let fungible_asset_1 = FungibleAsset::new(faucet_id_1, 100).unwrap();
+    let non_fungible_asset = non_fungible_asset(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN);
+    let missing_non_fungible_asset = non_fungible_asset_2(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN);
+
+    let target_account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let (target_pub_key, target_keypair_felt) = get_new_key_pair_with_advice_map();
+    let target_account = get_account_with_default_account_code(
+        target_account_id,
+        target_pub_key,
+        Some(non_fungible_asset),
+    );
+
+    // Create the note which checks the vault after receiving its asset
+    let note_script_ast = ProgramAst::parse(
+        format!(
+            "
+    use.miden::account
+    use.miden::note
+    use.miden::contracts::wallets::basic->wallet
+
+    begin
+        # add the asset
+        dropw
+        exec.note::get_assets drop
+        mem_loadw
+        call.wallet::receive_asset
+        dropw
+
+        # the received asset must be reflected in the vault balance
+        push.{faucet_id_1}
+        exec.account::get_balance
+        push.{amount} assert_eq
+
+        # the account must already hold the non-fungible asset
+        push.{non_fungible_asset}
+        exec.account::has_non_fungible_asset
+        assert
+    end
+    ",
+            faucet_id_1 = faucet_id_1,
+            amount = fungible_asset_1.amount(),
+            non_fungible_asset = prepare_word(&non_fungible_asset.into()),
+        )
+        .as_str(),
+    )
+    .unwrap();
+
+    let note = get_note_with_fungible_asset_and_script(fungible_asset_1, note_script_ast);
+
+    // CONSTRUCT AND EXECUTE TX (Success)
+    // --------------------------------------------------------------------------------------------
+    let data_store = MockDataStore::with_existing(Some(target_account.clone()), Some(vec![note]));
+
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(target_account.id()).unwrap();
+
+    let block_ref = data_store.block_header.block_num();
+    let note_ids = data_store.notes.iter().map(|note| note.id()).collect::<Vec<_>>();
+
+    let tx_script_code = ProgramAst::parse(
+        format!(
+            "
+        use.miden::account
+        use.miden::contracts::auth::basic->auth_tx
+
+        begin
+            # the asset received by the note must be reflected in the vault balance
+            push.{faucet_id_1}
+            exec.account::get_balance
+            push.{amount} assert_eq
+
+            # the balance of an asset which is not in the vault must be zero
+            push.{faucet_id_2}
+            exec.account::get_balance
+            assertz
+
+            # only the non-fungible asset held by the account must be found in the vault
+            push.{non_fungible_asset}
+            exec.account::has_non_fungible_asset
+            assert
+
+            push.{missing_non_fungible_asset}
+            exec.account::has_non_fungible_asset
+            assertz
+
+            call.auth_tx::auth_tx_rpo_falcon512
+        end
+        ",
+            faucet_id_1 = faucet_id_1,
+            amount = fungible_asset_1.amount(),
+            faucet_id_2 = ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1,
+            non_fungible_asset = prepare_word(&non_fungible_asset.into()),
+            missing_non_fungible_asset = prepare_word(&missing_non_fungible_asset.into()),
+        )
+        .as_str(),
+    )
+    .unwrap();
+    let tx_script = executor
+        .compile_tx_script(tx_script_code, vec![(target_pub_key, target_keypair_felt)], vec![])
+        .unwrap();
+    let tx_args: TransactionArgs = TransactionArgs::with_tx_script(tx_script);
+
+    let executed_transaction =
+        executor.execute_transaction(target_account.id(), block_ref, &note_ids, tx_args);
+
+    assert!(
+        executed_transaction.is_ok(),
+        "Transaction execution failed {:?}",
+        executed_transaction,
+    );
+}
+
 #[cfg(not(target_arch = "wasm32"))]
 #[test]
 fn wallet_creation() {
@@ -219,3 +475,181 @@ fn wallet_creation() {
     let pub_key_word: Word = pub_key.into();
     assert_eq!(wallet.storage().get_item(0).as_elements(), pub_key_word);
 }
+
+#[test]
+fn wallet_and_faucet_interface_detection() {
+    let (pub_key, _) = get_new_key_pair_with_advice_map();
+    let (wallet, _) = create_basic_wallet(
+        [0; 32],
+        AuthScheme::RpoFalcon512 { pub_key: PublicKey::new(pub_key) },
+        AccountType::RegularAccountImmutableCode,
+        AccountStorageType::OffChain,
+    )
+    .unwrap();
+    let (faucet, _) = create_basic_fungible_faucet(
+        [1; 32],
+        TokenSymbol::new("POL").unwrap(),
+        2,
+        Felt::new(100),
+        AccountStorageType::OffChain,
+        AuthScheme::RpoFalcon512 { pub_key: PublicKey::new(pub_key) },
+    )
+    .unwrap();
+
+    assert_eq!(
+        AccountInterface::detect(wallet.code()),
+        [KnownInterface::BasicWallet, KnownInterface::RpoFalcon512Auth]
+    );
+    assert_eq!(
+        AccountInterface::detect(faucet.code()),
+        [KnownInterface::BasicFungibleFaucet, KnownInterface::RpoFalcon512Auth]
+    );
+
+    // wallets can receive P2ID notes, and faucets can consume vouchers
+    let registry = standard_script_registry().unwrap();
+    let script_info = |name: &str| {
+        let root = registry.find(name, 1).unwrap();
+        registry.get(&root).unwrap().clone()
+    };
+    let wallet_interface = AccountInterface::new(wallet.code());
+    let faucet_interface = AccountInterface::new(faucet.code());
+
+    assert!(wallet_interface.supports_note_script(&script_info("P2ID")));
+    assert!(!faucet_interface.supports_note_script(&script_info("P2ID")));
+    assert!(wallet_interface.supports_note_script(&script_info("P2IDT")));
+    assert!(faucet_interface.supports_note_script(&script_info("VOUCHER")));
+    assert!(!wallet_interface.supports_note_script(&script_info("VOUCHER")));
+
+    // requirements of unknown scripts are unknown
+    let unknown_script = ScriptInfo::new("P2ID", 2).unwrap();
+    assert!(!wallet_interface.supports_note_script(&unknown_script));
+}
+
+#[test]
+fn wallet_auth_scheme_migration() {
+    let (pub_key, keypair_felts) = get_new_key_pair_with_advice_map();
+    let auth_scheme = AuthScheme::RpoFalcon512 { pub_key: PublicKey::new(pub_key) };
+    let (wallet, _) = create_basic_wallet(
+        [0; 32],
+        auth_scheme,
+        AccountType::RegularAccountUpdatableCode,
+        AccountStorageType::OffChain,
+    )
+    .unwrap();
+    let mut account = Account::new(
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap(),
+        AssetVault::new(&[]).unwrap(),
+        wallet.storage().clone(),
+        wallet.code().clone(),
+        ONE,
+    );
+
+    // the auth scheme of the wallet can be detected from its storage
+    let current = AuthScheme::detect(&account).unwrap();
+    assert_eq!(current.version(), AuthSchemeVersion::RpoFalcon512V1);
+    let AuthScheme::RpoFalcon512 { pub_key: detected_pub_key } = current;
+    assert_eq!(Word::from(detected_pub_key), pub_key);
+
+    // rotate the key of the wallet via a migration transaction authenticated with the current key
+    let new_pub_key = SecretKey::with_rng(&mut ChaCha20Rng::from_seed([1; 32])).public_key();
+    let target = AuthScheme::RpoFalcon512 { pub_key: new_pub_key };
+
+    let data_store = MockDataStore::with_existing(Some(account.clone()), Some(vec![]));
+    let mut executor = TransactionExecutor::new(data_store.clone());
+    executor.load_account(account.id()).unwrap();
+    let block_ref = data_store.block_header.block_num();
+
+    let tx_script_code = create_auth_scheme_migration_tx_script_code(&current, &target).unwrap();
+    let tx_script = executor
+        .compile_tx_script(tx_script_code, vec![(pub_key, keypair_felts)], vec![])
+        .unwrap();
+    let tx_args = TransactionArgs::with_tx_script(tx_script);
+
+    let executed_transaction =
+        executor.execute_transaction(account.id(), block_ref, &[], tx_args).unwrap();
+
+    account.apply_delta(executed_transaction.account_delta()).unwrap();
+    assert_eq!(executed_transaction.final_account().hash(), account.hash());
+
+    let AuthScheme::RpoFalcon512 { pub_key: migrated_pub_key } =
+        AuthScheme::detect(&account).unwrap();
+    assert_eq!(migrated_pub_key, new_pub_key);
+}
+
+#[test]
+fn wallet_balance_sheet() {
+    let account_id =
+        AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN).unwrap();
+    let sender_id = AccountId::try_from(ACCOUNT_ID_OFF_CHAIN_SENDER).unwrap();
+    let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    let faucet_id_1 = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1).unwrap();
+
+    let asset = |faucet_id, amount| Asset::from(FungibleAsset::new(faucet_id, amount).unwrap());
+    let input_note = |note: Note| {
+        let proof = NoteInclusionProof::new(
+            0,
+            Digest::default(),
+            Digest::default(),
+            0,
+            MerklePath::new(vec![]),
+        )
+        .unwrap();
+        InputNote::new(note, proof)
+    };
+    let rng = RpoRandomCoin::new([ONE; 4]);
+
+    let (pub_key, _) = get_new_key_pair_with_advice_map();
+    let account =
+        get_account_with_default_account_code(account_id, pub_key, Some(asset(faucet_id, 100)));
+
+    let unspent_notes = [
+        // incoming notes which can be consumed by the account
+        create_p2id_note(
+            sender_id,
+            account_id,
+            vec![asset(faucet_id, 20)],
+            NoteType::OffChain,
+            rng,
+        )
+        .unwrap(),
+        create_p2idr_note(
+            sender_id,
+            account_id,
+            vec![
+                asset(faucet_id_1, 5),
+                non_fungible_asset(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN),
+            ],
+            NoteType::OffChain,
+            10,
+            rng,
+        )
+        .unwrap(),
+        // outgoing note sent by the account
+        create_p2id_note(
+            account_id,
+            sender_id,
+            vec![asset(faucet_id, 30)],
+            NoteType::OffChain,
+            rng,
+        )
+        .unwrap(),
+        // note which neither targets the account nor was sent by it
+        create_p2id_note(sender_id, sender_id, vec![asset(faucet_id, 40)], NoteType::OffChain, rng)
+            .unwrap(),
+    ]
+    .map(input_note);
+
+    let sheet = BalanceSheet::compute(&account, &unspent_notes);
+    let non_fungible_faucet_id =
+        AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+    assert_eq!(sheet.on_account().get(&faucet_id), Some(&100));
+    assert_eq!(sheet.incoming_claimable().get(&faucet_id), Some(&20));
+    assert_eq!(sheet.incoming_claimable().get(&faucet_id_1), Some(&5));
+    assert_eq!(sheet.incoming_claimable().get(&non_fungible_faucet_id), Some(&1));
+    assert_eq!(sheet.locked_in_outgoing().get(&faucet_id), Some(&30));
+    assert_eq!(sheet.available_balance(faucet_id), 120);
+    assert_eq!(sheet.available_balance(faucet_id_1), 5);
+
+    let bytes = sheet.to_bytes();
+    assert_eq!(BalanceSheet::read_from_bytes(&bytes).unwrap(), sheet);
+}
@@ -122,7 +122,7 @@ impl<T: Rng> AccountBuilder<T> {
         }
 
         self.account_id_builder.code(&self.code);
-        self.account_id_builder.storage_root(storage.root());
+        self.account_id_builder.storage_root(storage.commit());
         let account_id = self.account_id_builder.with_seed(seed)?;
         let account_code =
             str_to_account_code(&self.code).map_err(AccountBuilderError::AccountError)?;
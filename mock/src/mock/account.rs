@@ -2,7 +2,7 @@ use miden_lib::transaction::memory::FAUCET_STORAGE_DATA_SLOT;
 use miden_objects::{
     accounts::{
         get_account_seed_single, Account, AccountCode, AccountId, AccountStorage,
-        AccountStorageType, AccountType, SlotItem, StorageSlot,
+        AccountStorageType, AccountType, SlotItem, StorageArray, StorageSlot,
         ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1,
         ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2, ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
         ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN,
@@ -29,6 +29,10 @@ pub const STORAGE_INDEX_0: u8 = 20;
 pub const STORAGE_VALUE_0: Word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
 pub const STORAGE_INDEX_1: u8 = 30;
 pub const STORAGE_VALUE_1: Word = [Felt::new(5), Felt::new(6), Felt::new(7), Felt::new(8)];
+pub const STORAGE_INDEX_2: u8 = 40;
+pub const STORAGE_ARRAY_DEPTH_2: u8 = 4;
+pub const STORAGE_ARRAY_ITEM_INDEX_2: u64 = 3;
+pub const STORAGE_ARRAY_VALUE_2: Word = [Felt::new(9), Felt::new(10), Felt::new(11), Felt::new(12)];
 
 pub fn storage_item_0() -> SlotItem {
     SlotItem {
@@ -44,6 +48,18 @@ pub fn storage_item_1() -> SlotItem {
     }
 }
 
+pub fn storage_array_2() -> StorageArray {
+    StorageArray::new(STORAGE_ARRAY_DEPTH_2, [(STORAGE_ARRAY_ITEM_INDEX_2, STORAGE_ARRAY_VALUE_2)])
+        .unwrap()
+}
+
+pub fn storage_item_2() -> SlotItem {
+    SlotItem {
+        index: STORAGE_INDEX_2,
+        slot: StorageSlot::new_array(*storage_array_2().root(), STORAGE_ARRAY_DEPTH_2),
+    }
+}
+
 /// Creates an [AssetVault] with 4 assets.
 ///
 /// The ids of the assets added to the vault are defined by the following constants:
@@ -73,20 +89,25 @@ fn mock_account_vault() -> AssetVault {
 
 pub fn mock_account_storage() -> AccountStorage {
     // create account storage
-    AccountStorage::new(vec![storage_item_0(), storage_item_1()]).unwrap()
+    AccountStorage::with_arrays(
+        vec![storage_item_0(), storage_item_1(), storage_item_2()],
+        [(STORAGE_INDEX_2, storage_array_2())].into(),
+    )
+    .unwrap()
 }
 
 // The MAST root of the default account's interface. Use these constants to interact with the
 // account's procedures.
-const MASTS: [&str; 8] = [
-    "0xe06a83054c72efc7e32698c4fc6037620cde834c9841afb038a5d39889e502b6",
-    "0xd0260c15a64e796833eb2987d4072ac2ea824b3ce4a54a1e693bada6e82f71dd",
-    "0xd765111e22479256e87a57eaf3a27479d19cc876c9a715ee6c262e0a0d47a2ac",
-    "0x17b326d5403115afccc0727efa72bd929bfdc7bbf284c7c28a7aadade5d4cc9d",
-    "0x73c14f65d2bab6f52eafc4397e104b3ab22a470f6b5cbc86d4aa4d3978c8b7d4",
-    "0xef07641ea1aa8fe85d8f854d29bf729b92251e1433244892138fd9ca898a5a22",
+const MASTS: [&str; 9] = [
+    "0x955a57abb1c959241fdba5c05575615b6c6f339ddac977b9f7a100a42c4be3cd",
+    "0x9cc69c19083cc37c43c5c33bbfce720701f51c98667bb0fa0608be558abe5200",
+    "0xb5962756a58cab5edba1e34853f9dc1f9321f8585e824ec3b03342de9813bdb4",
+    "0x024cef29bd0c4d84c3e634343fdcb7bcd1440cbf4d7635e31116e95ced4f0914",
+    "0xcfa622681768220c9d2f55ded0f4fdb4dc86a7b885c470c7a5e9cd9a25f83a7a",
+    "0x7a3916404d531c2de7fbdab159920a45c2637c3af3a4d6fcf320de2288201a4d",
     "0xff06b90f849c4b262cbfbea67042c4ea017ea0e9c558848a951d44b23370bec5",
     "0x8ef0092134469a1330e3c468f57c7f085ce611645d09cc7516c786fefc71d794",
+    "0x48867f7ad861121256df386017b8730f0f218298d3a262d53a362b49457cc7db",
 ];
 pub const ACCOUNT_RECEIVE_ASSET_MAST_ROOT: &str = MASTS[0];
 pub const ACCOUNT_SEND_ASSET_MAST_ROOT: &str = MASTS[1];
@@ -96,6 +117,7 @@ pub const ACCOUNT_SET_CODE_MAST_ROOT: &str = MASTS[4];
 pub const ACCOUNT_CREATE_NOTE_MAST_ROOT: &str = MASTS[5];
 pub const ACCOUNT_ACCOUNT_PROCEDURE_1_MAST_ROOT: &str = MASTS[6];
 pub const ACCOUNT_ACCOUNT_PROCEDURE_2_MAST_ROOT: &str = MASTS[7];
+pub const ACCOUNT_SET_ARRAY_ITEM_MAST_ROOT: &str = MASTS[8];
 
 // ACCOUNT ASSEMBLY CODE
 // ================================================================================================
@@ -106,7 +128,10 @@ pub const DEFAULT_ACCOUNT_CODE: &str = "
 
     export.basic_wallet::receive_asset
     export.basic_wallet::send_asset
+    export.basic_wallet::move_asset_to_note
     export.basic_eoa::auth_tx_rpo_falcon512
+    export.basic_eoa::keep_alive_rpo_falcon512
+    export.basic_eoa::set_auth_scheme
 ";
 
 pub const DEFAULT_AUTH_SCRIPT: &str = "
@@ -172,6 +197,15 @@ pub fn mock_account_code(assembler: &Assembler) -> AccountCode {
                 push.2.1
                 sub
             end
+
+            # acct proc 8
+            export.set_array_item
+                push.0.0 movdn.7 movdn.7
+                # => [index, item_index, V', 0, 0, 0, 0]
+
+                exec.account::set_array_item
+                # => [V, 0, 0, 0, 0]
+            end
             ";
     let account_module_ast = ModuleAst::parse(account_code).unwrap();
     let code = AccountCode::new(account_module_ast, assembler).unwrap();
@@ -193,8 +227,9 @@ pub fn mock_account_code(assembler: &Assembler) -> AccountCode {
         code.procedures()[5].to_hex(),
         code.procedures()[6].to_hex(),
         code.procedures()[7].to_hex(),
+        code.procedures()[8].to_hex(),
     ];
-    assert!(current == MASTS, "const MASTS: [&str; 8] = {:?};", current);
+    assert!(current == MASTS, "const MASTS: [&str; 9] = {:?};", current);
 
     code
 }
@@ -7,7 +7,7 @@ use miden_objects::{
     crypto::merkle::{LeafIndex, Mmr, PartialMmr, SimpleSmt, Smt},
     notes::{Note, NoteInclusionProof},
     transaction::{ChainMmr, InputNote},
-    BlockHeader, Digest, Felt, Word, ACCOUNT_TREE_DEPTH, NOTE_TREE_DEPTH, ZERO,
+    BlockHeader, Digest, Felt, KernelConfig, Word, ACCOUNT_TREE_DEPTH, NOTE_TREE_DEPTH, ZERO,
 };
 use rand::{Rng, SeedableRng};
 
@@ -462,18 +462,19 @@ impl<R: Rng + SeedableRng> MockChain<R> {
         let batch_root = Digest::default();
         let proof_hash = Digest::default();
 
-        let header = BlockHeader::new(
-            prev_hash,
-            block_num,
-            chain_root,
-            account_root,
-            nullifier_root,
-            note_root,
-            batch_root,
-            proof_hash,
-            version,
-            timestamp,
-        );
+        let header = BlockHeader::builder()
+            .prev_hash(prev_hash)
+            .block_num(block_num)
+            .chain_root(chain_root)
+            .account_root(account_root)
+            .nullifier_root(nullifier_root)
+            .note_root(note_root)
+            .batch_root(batch_root)
+            .proof_hash(proof_hash)
+            .version(version)
+            .timestamp(timestamp)
+            .kernel_config_hash(KernelConfig::default().commitment())
+            .build();
 
         self.blocks.push(header);
         self.chain.add(header.hash());
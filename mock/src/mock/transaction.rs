@@ -2,12 +2,13 @@ use alloc::vec::Vec;
 
 use miden_objects::{
     accounts::{Account, AccountDelta, ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN},
+    crypto::merkle::Smt,
     notes::Note,
     transaction::{
         ChainMmr, ExecutedTransaction, InputNote, InputNotes, OutputNote, OutputNotes,
-        TransactionArgs, TransactionInputs, TransactionOutputs,
+        TransactionArgs, TransactionInputs, TransactionOutputs, TxLog,
     },
-    BlockHeader, Felt, FieldElement, ZERO,
+    BlockHeader, Felt, FieldElement, KernelConfig, ZERO,
 };
 use vm_processor::{AdviceInputs, Operation, Program, Word};
 
@@ -29,6 +30,121 @@ pub fn mock_inputs(
     mock_inputs_with_account_seed(account_type, asset_preservation, None)
 }
 
+pub fn mock_inputs_with_kernel_config(
+    account_type: MockAccountType,
+    asset_preservation: AssetPreservationStatus,
+    kernel_config: KernelConfig,
+) -> (TransactionInputs, TransactionArgs) {
+    let (tx_inputs, tx_args) = mock_inputs(account_type, asset_preservation);
+    let (account, account_seed, header, _, chain_mmr, input_notes) = tx_inputs.into_parts();
+
+    // rebuild the reference block header so that it commits to the provided kernel config
+    let block_header = BlockHeader::new(
+        header.prev_hash(),
+        header.block_num(),
+        header.chain_root(),
+        header.account_root(),
+        header.nullifier_root(),
+        header.note_root(),
+        header.batch_root(),
+        header.proof_hash(),
+        header.version(),
+        header.timestamp(),
+        kernel_config.commitment(),
+    );
+
+    let tx_inputs = TransactionInputs::new(
+        account,
+        account_seed,
+        block_header,
+        kernel_config,
+        chain_mmr,
+        input_notes,
+    )
+    .unwrap();
+
+    (tx_inputs, tx_args)
+}
+
+/// Returns mock transaction inputs with a reference block header which commits to the root of the
+/// provided nullifier tree.
+///
+/// Nullifier proofs are not attached to the returned inputs.
+pub fn mock_inputs_with_nullifier_tree(
+    account_type: MockAccountType,
+    asset_preservation: AssetPreservationStatus,
+    nullifier_tree: &Smt,
+) -> (TransactionInputs, TransactionArgs) {
+    let (tx_inputs, tx_args) = mock_inputs(account_type, asset_preservation);
+    let (account, account_seed, header, kernel_config, chain_mmr, input_notes) =
+        tx_inputs.into_parts();
+
+    // rebuild the reference block header so that it commits to the provided nullifier tree
+    let block_header = BlockHeader::new(
+        header.prev_hash(),
+        header.block_num(),
+        header.chain_root(),
+        header.account_root(),
+        nullifier_tree.root(),
+        header.note_root(),
+        header.batch_root(),
+        header.proof_hash(),
+        header.version(),
+        header.timestamp(),
+        header.kernel_config_hash(),
+    );
+
+    let tx_inputs = TransactionInputs::new(
+        account,
+        account_seed,
+        block_header,
+        kernel_config,
+        chain_mmr,
+        input_notes,
+    )
+    .unwrap();
+
+    (tx_inputs, tx_args)
+}
+
+/// Returns mock transaction inputs in which all input notes expire at the specified block.
+///
+/// The reference block of the returned inputs is block 4.
+pub fn mock_inputs_with_note_expiration(
+    account_type: MockAccountType,
+    asset_preservation: AssetPreservationStatus,
+    expiration: u32,
+) -> (TransactionInputs, TransactionArgs) {
+    let (tx_inputs, tx_args) = mock_inputs(account_type, asset_preservation);
+    let (account, account_seed, _, kernel_config, _, input_notes) = tx_inputs.into_parts();
+
+    let input_notes = input_notes
+        .iter()
+        .map(|input_note| {
+            let note = input_note.note();
+            let metadata = note.metadata().with_expiration(expiration).unwrap();
+            Note::new(note.assets().clone(), metadata, note.recipient().clone())
+        })
+        .collect();
+
+    // rebuild the chain data so that the block headers commit to the updated notes
+    let (chain_mmr, recorded_notes) = mock_chain_data(input_notes);
+    let block_header =
+        mock_block_header(4, Some(chain_mmr.peaks().hash_peaks()), None, &[account.clone()]);
+
+    let tx_inputs = TransactionInputs::new(
+        account,
+        account_seed,
+        block_header,
+        kernel_config,
+        chain_mmr,
+        InputNotes::new(recorded_notes).unwrap(),
+    )
+    .unwrap();
+
+    (tx_inputs, tx_args)
+}
+
 pub fn mock_inputs_with_account_seed(
     account_type: MockAccountType,
     asset_preservation: AssetPreservationStatus,
@@ -59,9 +175,15 @@ pub fn mock_inputs_with_account_seed(
         mock_block_header(4, Some(chain_mmr.peaks().hash_peaks()), None, &[account.clone()]);
 
     let input_notes = InputNotes::new(recorded_notes).unwrap();
-    let tx_inputs =
-        TransactionInputs::new(account, account_seed, block_header, chain_mmr, input_notes)
-            .unwrap();
+    let tx_inputs = TransactionInputs::new(
+        account,
+        account_seed,
+        block_header,
+        KernelConfig::default(),
+        chain_mmr,
+        input_notes,
+    )
+    .unwrap();
 
     let output_notes = output_notes.into_iter().filter_map(|n| match n {
         OutputNote::Public(note) => Some(note),
@@ -140,6 +262,7 @@ pub fn mock_executed_tx(asset_preservation: AssetPreservationStatus) -> Executed
         initial_account,
         None,
         block_header,
+        KernelConfig::default(),
         block_chain,
         InputNotes::new(input_notes).unwrap(),
     )
@@ -155,6 +278,7 @@ pub fn mock_executed_tx(asset_preservation: AssetPreservationStatus) -> Executed
     let tx_outputs = TransactionOutputs {
         account: final_account.into(),
         output_notes: OutputNotes::new(output_notes).unwrap(),
+        log: TxLog::default(),
     };
 
     let program = build_dummy_tx_program();
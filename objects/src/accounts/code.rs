@@ -1,6 +1,6 @@
-use alloc::vec::Vec;
+use alloc::{string::ToString, vec::Vec};
 
-use assembly::ast::AstSerdeOptions;
+use assembly::ast::{AstSerdeOptions, CodeBody, Node, ProgramAst};
 
 use super::{
     AccountError, Assembler, AssemblyContext, ByteReader, ByteWriter, Deserializable,
@@ -17,6 +17,11 @@ const MODULE_SERDE_OPTIONS: AstSerdeOptions = AstSerdeOptions::new(false);
 /// The depth of the Merkle tree that is used to commit to the account's public interface.
 pub const PROCEDURE_TREE_DEPTH: u8 = 8;
 
+/// The kernel procedures which mark the invoking account procedure as being executed and as no
+/// longer being executed respectively.
+const GUARD_ENTER_KERNEL_PROC: &str = "account_procedure_enter";
+const GUARD_EXIT_KERNEL_PROC: &str = "account_procedure_exit";
+
 // ACCOUNT CODE
 // ================================================================================================
 
@@ -25,10 +30,16 @@ pub const PROCEDURE_TREE_DEPTH: u8 = 8;
 /// Account's public interface consists of a set of account procedures, each procedure being a Miden
 /// VM program. Thus, MAST root of each procedure commits to the underlying program. We commit to
 /// the entire account interface by building a simple Merkle tree out of all procedure MAST roots.
+///
+/// Procedures can be guarded against re-entrance (see [AccountCode::with_guarded_procedures()]).
+/// The body of a guarded procedure is wrapped by the kernel procedures which track the execution
+/// of guarded procedures, and thus the guard is part of the procedure's MAST root. Whether a
+/// procedure is guarded is derived from its body in the module, and thus it is not serialized.
 #[derive(Debug, Clone)]
 pub struct AccountCode {
     module: ModuleAst,
     procedures: Vec<Digest>,
+    guarded: Vec<bool>,
     procedure_tree: SimpleSmt<PROCEDURE_TREE_DEPTH>,
 }
 
@@ -51,6 +62,8 @@ impl AccountCode {
     /// - Compilation of the provided module fails.
     /// - The number of procedures exported from the provided module is smaller than 1 or greater
     ///   than 256.
+    /// - The number of compiled procedures does not match the number of procedures exported from
+    ///   the module.
     pub fn new(module: ModuleAst, assembler: &Assembler) -> Result<Self, AccountError> {
         // compile the module and make sure the number of exported procedures is within the limit
         let procedures = assembler
@@ -67,13 +80,66 @@ impl AccountCode {
             });
         }
 
+        let guarded = guarded_procedure_flags(&module);
+        if guarded.len() != procedures.len() {
+            return Err(AccountError::AccountCodeProcedureCountMismatch {
+                expected: guarded.len(),
+                actual: procedures.len(),
+            });
+        }
+
         Ok(Self {
             procedure_tree: build_procedure_tree(&procedures),
             module,
+            guarded,
             procedures,
         })
     }
 
+    /// Returns a new definition of an account's interface compiled from the specified source code,
+    /// in which the procedures with the specified names are guarded against re-entrance.
+    ///
+    /// The body of every guarded procedure is wrapped by calls to the transaction kernel which
+    /// mark the procedure as being executed when it is entered, and as no longer being executed
+    /// when it is exited. Thus, the kernel panics if a guarded procedure is re-entered, e.g., via a
+    /// note script invoked while the procedure is being executed, or if the maximum depth of
+    /// nested guarded procedure calls is exceeded. Guarded procedures must not invoke
+    /// `miden::account::enter_procedure` and `miden::account::exit_procedure` themselves.
+    ///
+    /// The provided assembler must be instantiated with the transaction kernel.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - Any of the specified procedures is not a local procedure exported by the module.
+    /// - Compilation of the provided module fails.
+    /// - The number of procedures exported from the provided module is smaller than 1 or greater
+    ///   than 256.
+    pub fn with_guarded_procedures(
+        module: ModuleAst,
+        guarded_procedures: &[&str],
+        assembler: &Assembler,
+    ) -> Result<Self, AccountError> {
+        let mut procs = module.procs().to_vec();
+        if let Some(name) = guarded_procedures.iter().find(|name| {
+            !procs.iter().any(|proc| proc.is_export && proc.name.to_string() == **name)
+        }) {
+            return Err(AccountError::AccountCodeProcedureNotExported(name.to_string()));
+        }
+
+        for proc in procs.iter_mut().filter(|proc| proc.is_export) {
+            if guarded_procedures.contains(&proc.name.to_string().as_str()) {
+                proc.body = build_guarded_body(&proc.body);
+            }
+        }
+
+        let guarded_module =
+            ModuleAst::new(procs, module.reexported_procs().to_vec(), module.docs().cloned())
+                .map_err(|err| AccountError::AccountCodeAssemblerError(err.into()))?
+                .with_import_info(module.import_info().clone());
+
+        Self::new(guarded_module, assembler)
+    }
+
     /// Returns a new definition of an account's interface instantiated from the provided
     /// module and list of procedure digests.
     ///
@@ -81,13 +147,21 @@ impl AccountCode {
     /// the compilation of the provided module, but this is not checked.
     ///
     /// # Panics
-    /// Panics if the number of procedures is smaller than 1 or greater than 256.
+    /// Panics if:
+    /// - The number of procedures is smaller than 1 or greater than 256.
+    /// - The number of procedures does not match the number of procedures exported from the
+    ///   module.
     pub fn from_parts(module: ModuleAst, procedures: Vec<Digest>) -> Self {
         assert!(!procedures.is_empty(), "no account procedures");
         assert!(procedures.len() <= Self::MAX_NUM_PROCEDURES, "too many account procedures");
+
+        let guarded = guarded_procedure_flags(&module);
+        assert_eq!(guarded.len(), procedures.len(), "procedures do not match module exports");
+
         Self {
             procedure_tree: build_procedure_tree(&procedures),
             module,
+            guarded,
             procedures,
         }
     }
@@ -138,6 +212,21 @@ impl AccountCode {
     pub fn get_procedure_index_by_root(&self, root: Digest) -> Option<usize> {
         self.procedures.iter().position(|r| r == &root)
     }
+
+    /// Returns true if the procedure with the specified index is guarded against re-entrance, or
+    /// None if the index is out of bounds.
+    pub fn is_procedure_guarded(&self, index: usize) -> Option<bool> {
+        self.guarded.get(index).copied()
+    }
+
+    /// Returns the digests of the procedures which are guarded against re-entrance.
+    pub fn guarded_procedures(&self) -> impl Iterator<Item = Digest> + '_ {
+        self.procedures
+            .iter()
+            .zip(self.guarded.iter())
+            .filter(|(_, is_guarded)| **is_guarded)
+            .map(|(root, _)| *root)
+    }
 }
 
 // EQUALITY
@@ -173,6 +262,13 @@ impl Deserializable for AccountCode {
         let num_procedures = (source.read_u8()? as usize) + 1;
         let procedures = source.read_many::<Digest>(num_procedures)?;
 
+        let num_exports = guarded_procedure_flags(&module).len();
+        if num_exports != num_procedures {
+            return Err(DeserializationError::InvalidValue(format!(
+                "module exports {num_exports} procedures, but {num_procedures} roots were provided"
+            )));
+        }
+
         Ok(Self::from_parts(module, procedures))
     }
 }
@@ -180,6 +276,48 @@ impl Deserializable for AccountCode {
 // HELPER FUNCTIONS
 // ================================================================================================
 
+/// Returns the nodes which call the specified kernel procedure from a guarded procedure.
+fn build_guard_nodes(kernel_proc: &str) -> Vec<Node> {
+    let source = format!("begin padw syscall.{kernel_proc} dropw end");
+    let program = ProgramAst::parse(&source).expect("invalid guard source");
+    program.body().nodes().to_vec()
+}
+
+/// Returns the body of a guarded procedure, i.e., the provided body preceded by a call to the
+/// kernel procedure which marks the procedure as being executed, and followed by a call to the
+/// kernel procedure which marks the procedure as no longer being executed.
+fn build_guarded_body(body: &CodeBody) -> CodeBody {
+    let nodes: Vec<Node> = build_guard_nodes(GUARD_ENTER_KERNEL_PROC)
+        .into_iter()
+        .chain(body.nodes().iter().cloned())
+        .chain(build_guard_nodes(GUARD_EXIT_KERNEL_PROC))
+        .collect();
+
+    CodeBody::new(nodes)
+}
+
+/// Returns a flag for every procedure exported from the provided module, which is true if the
+/// body of the procedure is wrapped by the calls to the kernel procedures which mark the procedure
+/// as being executed and as no longer being executed.
+///
+/// The flags are ordered in the same way as the roots returned by the assembler for the module,
+/// i.e., re-exported procedures first, followed by local exported procedures in the order in which
+/// they are declared in the module. Re-exported procedures are never guarded.
+fn guarded_procedure_flags(module: &ModuleAst) -> Vec<bool> {
+    let enter = build_guard_nodes(GUARD_ENTER_KERNEL_PROC);
+    let exit = build_guard_nodes(GUARD_EXIT_KERNEL_PROC);
+
+    let reexported = module.reexported_procs().iter().map(|_| false);
+    let local = module.procs().iter().filter(|proc| proc.is_export).map(|proc| {
+        let nodes = proc.body.nodes();
+        nodes.len() >= enter.len() + exit.len()
+            && nodes.starts_with(&enter)
+            && nodes.ends_with(&exit)
+    });
+
+    reexported.chain(local).collect()
+}
+
 fn build_procedure_tree(procedures: &[Digest]) -> SimpleSmt<PROCEDURE_TREE_DEPTH> {
     // order the procedure digests to achieve a reproducible tree
     let procedures = {
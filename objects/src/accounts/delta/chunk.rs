@@ -0,0 +1,135 @@
+use alloc::vec::Vec;
+
+use super::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable};
+use crate::{Digest, Hasher, MAX_ACCOUNT_UPDATE_CHUNK_SIZE};
+
+// ACCOUNT UPDATE CHUNK
+// ================================================================================================
+
+/// A chunk of the serialized [AccountUpdateDetails](super::AccountUpdateDetails) of an account.
+///
+/// Updates which exceed the size limits of the node (e.g., updates of public accounts with large
+/// storage changes) can be split into a sequence of chunks (see
+/// [AccountUpdateDetails::into_chunks()](super::AccountUpdateDetails::into_chunks)), which are
+/// published separately and reassembled into the original update (see
+/// [AccountUpdateDetails::from_chunks()](super::AccountUpdateDetails::from_chunks)).
+///
+/// Every chunk records the final hash of the account after the update, its position in the
+/// sequence, and a continuation commitment to the chunks following it. The commitment to a chunk
+/// is computed as:
+///
+/// > hash(hash(payload), continuation)
+///
+/// where the continuation of the last chunk is the empty digest. Thus, the commitment to the first
+/// chunk commits to the whole sequence, and a missing, reordered or modified chunk is detected
+/// during reassembly.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountUpdateChunk {
+    final_hash: Digest,
+    index: u32,
+    num_chunks: u32,
+    payload: Vec<u8>,
+    continuation: Digest,
+}
+
+impl AccountUpdateChunk {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [AccountUpdateChunk] instantiated from the provided parameters.
+    ///
+    /// The caller is responsible for ensuring that the index is smaller than the number of chunks,
+    /// and that the payload does not exceed [MAX_ACCOUNT_UPDATE_CHUNK_SIZE].
+    pub(super) fn new(
+        final_hash: Digest,
+        index: u32,
+        num_chunks: u32,
+        payload: Vec<u8>,
+        continuation: Digest,
+    ) -> Self {
+        Self {
+            final_hash,
+            index,
+            num_chunks,
+            payload,
+            continuation,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the hash of the account state after the update this chunk is part of.
+    pub fn final_hash(&self) -> Digest {
+        self.final_hash
+    }
+
+    /// Returns the position of this chunk in the sequence of chunks of the update.
+    pub fn index(&self) -> u32 {
+        self.index
+    }
+
+    /// Returns the number of chunks the update was split into.
+    pub fn num_chunks(&self) -> u32 {
+        self.num_chunks
+    }
+
+    /// Returns true if this is the last chunk of the update.
+    pub fn is_last(&self) -> bool {
+        self.index + 1 == self.num_chunks
+    }
+
+    /// Returns the bytes of the serialized update carried by this chunk.
+    pub fn payload(&self) -> &[u8] {
+        &self.payload
+    }
+
+    /// Returns the commitment to the chunks following this chunk, or the empty digest if this is
+    /// the last chunk.
+    pub fn continuation(&self) -> Digest {
+        self.continuation
+    }
+
+    /// Returns the commitment to this chunk and all chunks following it.
+    pub fn commitment(&self) -> Digest {
+        Hasher::merge(&[Hasher::hash(&self.payload), self.continuation])
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for AccountUpdateChunk {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.final_hash.write_into(target);
+        target.write_u32(self.index);
+        target.write_u32(self.num_chunks);
+        target.write_u32(self.payload.len() as u32);
+        target.write_bytes(&self.payload);
+        self.continuation.write_into(target);
+    }
+}
+
+impl Deserializable for AccountUpdateChunk {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let final_hash = Digest::read_from(source)?;
+        let index = source.read_u32()?;
+        let num_chunks = source.read_u32()?;
+        if index >= num_chunks {
+            return Err(DeserializationError::InvalidValue(format!(
+                "chunk index {index} is not smaller than the number of chunks {num_chunks}"
+            )));
+        }
+
+        let payload_len = source.read_u32()? as usize;
+        if payload_len > MAX_ACCOUNT_UPDATE_CHUNK_SIZE {
+            return Err(DeserializationError::InvalidValue(format!(
+                "chunk payload of {payload_len} bytes exceeds the maximum chunk size"
+            )));
+        }
+        let payload = source.read_vec(payload_len)?;
+        let continuation = Digest::read_from(source)?;
+
+        Ok(Self::new(final_hash, index, num_chunks, payload, continuation))
+    }
+}
@@ -1,12 +1,18 @@
-use alloc::string::ToString;
+use alloc::{string::ToString, vec::Vec};
 
 use super::{
     ByteReader, ByteWriter, Deserializable, DeserializationError, Felt, Serializable, Word, ZERO,
 };
-use crate::{assets::Asset, AccountDeltaError};
+use crate::{
+    assets::Asset, transaction::AccountDetails, AccountDeltaError, Digest,
+    MAX_ACCOUNT_UPDATE_CHUNK_SIZE,
+};
+
+mod chunk;
+pub use chunk::AccountUpdateChunk;
 
 mod storage;
-pub use storage::AccountStorageDelta;
+pub use storage::{AccountStorageDelta, AccountStorageDeltaProof, StorageSlotProof};
 
 mod vault;
 pub use vault::AccountVaultDelta;
@@ -104,6 +110,173 @@ impl Deserializable for AccountDelta {
     }
 }
 
+// ACCOUNT UPDATE DETAILS
+// ================================================================================================
+
+/// Details of a single on-chain update of an account.
+///
+/// The update consists of the account details recorded for the transaction which updated the
+/// account, i.e., the full state of a newly created account or the delta of an existing account,
+/// and of the hash of the account state after the update.
+///
+/// A sequence of updates can be folded into the current state of the account via
+/// [Account::reconstruct()](crate::accounts::Account::reconstruct).
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct AccountUpdateDetails {
+    final_hash: Digest,
+    details: AccountDetails,
+}
+
+impl AccountUpdateDetails {
+    /// Returns new [AccountUpdateDetails] instantiated from the provided account details and the
+    /// hash of the account state after the update.
+    pub fn new(final_hash: Digest, details: AccountDetails) -> Self {
+        Self { final_hash, details }
+    }
+
+    /// Returns the hash of the account state after the update.
+    pub fn final_hash(&self) -> Digest {
+        self.final_hash
+    }
+
+    /// Returns the account details of the update.
+    pub fn details(&self) -> &AccountDetails {
+        &self.details
+    }
+
+    /// Returns the size of this update in bytes when serialized.
+    pub fn size(&self) -> usize {
+        self.to_bytes().len()
+    }
+
+    // CHUNKING
+    // --------------------------------------------------------------------------------------------
+
+    /// Splits this update into a sequence of [AccountUpdateChunk]s whose payloads do not exceed
+    /// the specified size.
+    ///
+    /// Updates which fit into a single chunk result in a single chunk. The chunks can be
+    /// reassembled into this update via [AccountUpdateDetails::from_chunks()].
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The chunk size is 0 or greater than [MAX_ACCOUNT_UPDATE_CHUNK_SIZE].
+    /// - The update would have to be split into more than [u32::MAX] chunks.
+    pub fn into_chunks(
+        self,
+        max_chunk_size: usize,
+    ) -> Result<Vec<AccountUpdateChunk>, AccountDeltaError> {
+        if max_chunk_size == 0 || max_chunk_size > MAX_ACCOUNT_UPDATE_CHUNK_SIZE {
+            return Err(AccountDeltaError::UpdateChunkSizeInvalid {
+                size: max_chunk_size,
+                max: MAX_ACCOUNT_UPDATE_CHUNK_SIZE,
+            });
+        }
+
+        let bytes = self.to_bytes();
+        let num_chunks = u32::try_from(bytes.len().div_ceil(max_chunk_size))
+            .map_err(|_| AccountDeltaError::UpdateTooLarge { size: bytes.len() })?;
+
+        // the continuation commitments are computed from the last chunk to the first one
+        let mut continuation = Digest::default();
+        let mut chunks: Vec<AccountUpdateChunk> = bytes
+            .chunks(max_chunk_size)
+            .enumerate()
+            .rev()
+            .map(|(index, payload)| {
+                let chunk = AccountUpdateChunk::new(
+                    self.final_hash,
+                    index as u32,
+                    num_chunks,
+                    payload.to_vec(),
+                    continuation,
+                );
+                continuation = chunk.commitment();
+                chunk
+            })
+            .collect();
+        chunks.reverse();
+
+        Ok(chunks)
+    }
+
+    /// Reassembles an update from the provided sequence of chunks, as produced by
+    /// [AccountUpdateDetails::into_chunks()].
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - No chunks were provided.
+    /// - The chunks are not provided in order, or any of the chunks is missing.
+    /// - The chunks record different final account hashes.
+    /// - The continuation commitment of any of the chunks does not match the chunks following it.
+    /// - The reassembled payload is not a valid update, or its final account hash does not match
+    ///   the final account hash recorded in the chunks.
+    pub fn from_chunks(chunks: &[AccountUpdateChunk]) -> Result<Self, AccountDeltaError> {
+        let first_chunk = chunks.first().ok_or(AccountDeltaError::UpdateChunksEmpty)?;
+        let final_hash = first_chunk.final_hash();
+        let num_chunks = first_chunk.num_chunks();
+        if chunks.len() != num_chunks as usize {
+            return Err(AccountDeltaError::UpdateChunkCountMismatch {
+                expected: num_chunks,
+                actual: chunks.len() as u32,
+            });
+        }
+
+        // verify the chunks from the last to the first one, so that the continuation commitment of
+        // every chunk can be checked against the commitment to the chunk following it
+        let mut continuation = Digest::default();
+        for (index, chunk) in chunks.iter().enumerate().rev() {
+            let index = index as u32;
+            if chunk.index() != index {
+                return Err(AccountDeltaError::UpdateChunkIndexMismatch {
+                    expected: index,
+                    actual: chunk.index(),
+                });
+            }
+            if chunk.num_chunks() != num_chunks {
+                return Err(AccountDeltaError::UpdateChunkCountMismatch {
+                    expected: num_chunks,
+                    actual: chunk.num_chunks(),
+                });
+            }
+            if chunk.final_hash() != final_hash {
+                return Err(AccountDeltaError::UpdateChunkFinalHashMismatch { index });
+            }
+            if chunk.continuation() != continuation {
+                return Err(AccountDeltaError::UpdateChunkContinuationMismatch { index });
+            }
+            continuation = chunk.commitment();
+        }
+
+        let bytes: Vec<u8> = chunks.iter().flat_map(|chunk| chunk.payload()).copied().collect();
+        let update = Self::read_from_bytes(&bytes)
+            .map_err(|err| AccountDeltaError::UpdateChunksMalformed(err.to_string()))?;
+        if update.final_hash() != final_hash {
+            return Err(AccountDeltaError::UpdateChunksMalformed(
+                "final hash of the update does not match the final hash of the chunks".to_string(),
+            ));
+        }
+
+        Ok(update)
+    }
+}
+
+impl Serializable for AccountUpdateDetails {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.final_hash.write_into(target);
+        self.details.write_into(target);
+    }
+}
+
+impl Deserializable for AccountUpdateDetails {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let final_hash = Digest::read_from(source)?;
+        let details = AccountDetails::read_from(source)?;
+
+        Ok(Self { final_hash, details })
+    }
+}
+
 // HELPER FUNCTIONS
 // ================================================================================================
 
@@ -147,8 +320,14 @@ fn validate_nonce(
 
 #[cfg(test)]
 mod tests {
-    use super::{AccountDelta, AccountStorageDelta, AccountVaultDelta};
-    use crate::{ONE, ZERO};
+    use super::{
+        AccountDelta, AccountDetails, AccountStorageDelta, AccountUpdateChunk,
+        AccountUpdateDetails, AccountVaultDelta,
+    };
+    use crate::{
+        utils::serde::{Deserializable, Serializable},
+        AccountDeltaError, Digest, Felt, MAX_ACCOUNT_UPDATE_CHUNK_SIZE, ONE, ZERO,
+    };
 
     #[test]
     fn account_delta_nonce_validation() {
@@ -156,6 +335,7 @@ mod tests {
         let storage_delta = AccountStorageDelta {
             cleared_items: vec![],
             updated_items: vec![],
+            updated_array_items: vec![],
         };
 
         let vault_delta = AccountVaultDelta {
@@ -170,10 +350,81 @@ mod tests {
         let storage_delta = AccountStorageDelta {
             cleared_items: vec![1],
             updated_items: vec![],
+            updated_array_items: vec![],
         };
 
         assert!(AccountDelta::new(storage_delta.clone(), vault_delta.clone(), None).is_err());
         assert!(AccountDelta::new(storage_delta.clone(), vault_delta.clone(), Some(ZERO)).is_err());
         assert!(AccountDelta::new(storage_delta.clone(), vault_delta.clone(), Some(ONE)).is_ok());
     }
+
+    #[test]
+    fn account_update_chunking() {
+        let storage_delta = AccountStorageDelta {
+            cleared_items: vec![],
+            updated_items: vec![],
+            updated_array_items: (0..1000)
+                .map(|index| (1, index, [Felt::new(index), ONE, ZERO, ZERO]))
+                .collect(),
+        };
+        let delta =
+            AccountDelta::new(storage_delta, AccountVaultDelta::default(), Some(ONE)).unwrap();
+        let final_hash = Digest::new([ONE, ONE, ZERO, ZERO]);
+        let update = AccountUpdateDetails::new(final_hash, AccountDetails::Delta(delta));
+
+        // an update which fits into a single chunk is not split
+        let chunks = update.clone().into_chunks(MAX_ACCOUNT_UPDATE_CHUNK_SIZE).unwrap();
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(AccountUpdateDetails::from_chunks(&chunks).unwrap(), update);
+
+        let chunks = update.clone().into_chunks(1024).unwrap();
+        assert_eq!(chunks.len(), update.size().div_ceil(1024));
+        assert!(chunks.iter().all(|chunk| chunk.payload().len() <= 1024));
+        assert!(chunks.last().unwrap().is_last());
+        assert_eq!(AccountUpdateDetails::from_chunks(&chunks).unwrap(), update);
+
+        // chunks survive serialization
+        let bytes = chunks[1].to_bytes();
+        assert_eq!(AccountUpdateChunk::read_from_bytes(&bytes).unwrap(), chunks[1]);
+
+        // missing, reordered and modified chunks are detected
+        assert_eq!(
+            AccountUpdateDetails::from_chunks(&chunks[1..]),
+            Err(AccountDeltaError::UpdateChunkCountMismatch {
+                expected: chunks.len() as u32,
+                actual: chunks.len() as u32 - 1
+            })
+        );
+
+        let mut reordered = chunks.clone();
+        reordered.swap(1, 2);
+        assert_eq!(
+            AccountUpdateDetails::from_chunks(&reordered),
+            Err(AccountDeltaError::UpdateChunkIndexMismatch { expected: 2, actual: 1 })
+        );
+
+        let mut modified = chunks.clone();
+        let mut payload = chunks[2].payload().to_vec();
+        payload[0] ^= 1;
+        modified[2] = AccountUpdateChunk::new(
+            final_hash,
+            2,
+            chunks[2].num_chunks(),
+            payload,
+            chunks[2].continuation(),
+        );
+        assert_eq!(
+            AccountUpdateDetails::from_chunks(&modified),
+            Err(AccountDeltaError::UpdateChunkContinuationMismatch { index: 1 })
+        );
+
+        assert_eq!(
+            AccountUpdateDetails::from_chunks(&[]),
+            Err(AccountDeltaError::UpdateChunksEmpty)
+        );
+        assert!(matches!(
+            update.into_chunks(MAX_ACCOUNT_UPDATE_CHUNK_SIZE + 1),
+            Err(AccountDeltaError::UpdateChunkSizeInvalid { .. })
+        ));
+    }
 }
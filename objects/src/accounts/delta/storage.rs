@@ -4,6 +4,11 @@ use super::{
     AccountDeltaError, ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable,
     Word,
 };
+use crate::{
+    accounts::AccountStorage,
+    crypto::merkle::{LeafIndex, MerklePath},
+    AccountError, Digest,
+};
 
 // CONSTANTS
 // ================================================================================================
@@ -17,10 +22,13 @@ const MAX_MUTABLE_STORAGE_SLOT_IDX: u8 = 254;
 ///
 /// The differences are represented as follows:
 /// - item updates: represented by `cleared_items` and `updated_items` field.
+/// - array item updates: represented by `updated_array_items` field as (slot index, item index,
+///   new value) tuples.
 #[derive(Clone, Debug, Default, PartialEq, Eq)]
 pub struct AccountStorageDelta {
     pub cleared_items: Vec<u8>,
     pub updated_items: Vec<(u8, Word)>,
+    pub updated_array_items: Vec<(u8, u64, Word)>,
 }
 
 impl AccountStorageDelta {
@@ -29,11 +37,14 @@ impl AccountStorageDelta {
     /// # Errors
     /// Returns an error if:
     /// - The number of cleared or updated items is greater than 255.
+    /// - The number of updated array items is greater than 65535.
     /// - Any of cleared or updated items are at slot 255 (i.e., immutable slot).
     /// - Any of the cleared or updated items is referenced more than once (e.g., updated twice).
+    /// - Any of the updated array items is referenced more than once.
     pub fn validate(&self) -> Result<(), AccountDeltaError> {
         let num_cleared_items = self.cleared_items.len();
         let num_updated_items = self.updated_items.len();
+        let num_updated_array_items = self.updated_array_items.len();
 
         if num_cleared_items > u8::MAX as usize {
             return Err(AccountDeltaError::TooManyClearedStorageItems {
@@ -45,6 +56,11 @@ impl AccountStorageDelta {
                 actual: num_updated_items,
                 max: u8::MAX as usize,
             });
+        } else if num_updated_array_items > u16::MAX as usize {
+            return Err(AccountDeltaError::TooManyUpdatedStorageItems {
+                actual: num_updated_array_items,
+                max: u16::MAX as usize,
+            });
         }
 
         // make sure cleared items vector does not contain errors
@@ -73,15 +89,175 @@ impl AccountStorageDelta {
             }
         }
 
+        // make sure updated array items vector does not contain errors
+        for (pos, (idx, item_idx, _)) in self.updated_array_items.iter().enumerate() {
+            if *idx > MAX_MUTABLE_STORAGE_SLOT_IDX {
+                return Err(AccountDeltaError::ImmutableStorageSlot(*idx as usize));
+            }
+
+            if self.updated_array_items[..pos].iter().any(|x| x.0 == *idx && x.1 == *item_idx) {
+                return Err(AccountDeltaError::DuplicateStorageItemUpdate(*idx as usize));
+            }
+        }
+
         Ok(())
     }
 
     /// Returns true if storage delta contains no updates.
     pub fn is_empty(&self) -> bool {
-        self.cleared_items.is_empty() && self.updated_items.is_empty()
+        self.cleared_items.is_empty()
+            && self.updated_items.is_empty()
+            && self.updated_array_items.is_empty()
+    }
+
+    /// Returns Merkle openings of all storage slots touched by this delta when it is applied to
+    /// the provided storage.
+    ///
+    /// The returned proof can be verified against the initial and the final storage roots via
+    /// [AccountStorageDeltaProof::verify()].
+    ///
+    /// # Errors
+    /// Returns an error if this delta cannot be applied to the provided storage.
+    pub fn to_proofs(
+        &self,
+        storage: &AccountStorage,
+    ) -> Result<AccountStorageDeltaProof, AccountError> {
+        let mut final_storage = storage.clone();
+        final_storage.apply_delta(self)?;
+
+        let mut touched_slots: Vec<u8> = self
+            .cleared_items
+            .iter()
+            .copied()
+            .chain(self.updated_items.iter().map(|(idx, _)| *idx))
+            .chain(self.updated_array_items.iter().map(|(idx, ..)| *idx))
+            .collect();
+        touched_slots.sort_unstable();
+        touched_slots.dedup();
+
+        // open every slot against the storage tree in which all preceding slots were updated,
+        // starting from the tree which includes uncommitted updates of the initial storage
+        let mut initial_storage = storage.clone();
+        initial_storage.commit();
+        let mut slots_tree = initial_storage.slots().clone();
+        let mut slots = Vec::with_capacity(touched_slots.len());
+        for index in touched_slots {
+            let leaf_index =
+                LeafIndex::new(index as u64).expect("index is u8 - index within range");
+            let initial_value: Word = storage.get_item(index).into();
+            let final_value: Word = final_storage.get_item(index).into();
+
+            let path = slots_tree.open(&leaf_index).path;
+            slots_tree.insert(leaf_index, final_value);
+
+            slots.push(StorageSlotProof { index, initial_value, final_value, path });
+        }
+
+        Ok(AccountStorageDeltaProof { slots })
+    }
+}
+
+// ACCOUNT STORAGE DELTA PROOF
+// ================================================================================================
+
+/// Merkle openings of a single storage slot touched by an [AccountStorageDelta].
+///
+/// The initial and the final values of the slot are opened against the same Merkle path, as
+/// updating a single slot does not change the siblings of the slot in the storage tree.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StorageSlotProof {
+    index: u8,
+    initial_value: Word,
+    final_value: Word,
+    path: MerklePath,
+}
+
+impl StorageSlotProof {
+    /// Returns the index of the storage slot.
+    pub fn index(&self) -> u8 {
+        self.index
+    }
+
+    /// Returns the value of the slot before the update.
+    pub fn initial_value(&self) -> Word {
+        self.initial_value
+    }
+
+    /// Returns the value of the slot after the update.
+    pub fn final_value(&self) -> Word {
+        self.final_value
+    }
+
+    /// Returns the Merkle path of the slot.
+    pub fn path(&self) -> &MerklePath {
+        &self.path
     }
 }
 
+/// Merkle openings of all storage slots touched by an [AccountStorageDelta].
+///
+/// The slots are updated one at a time in ascending order of their indexes, and the openings of
+/// every slot are made against the storage root obtained after updating all preceding slots. This
+/// allows verifying the transition between the initial and the final storage roots without having
+/// access to the full account storage, and guarantees that no other slots were modified.
+///
+/// For updated array items, only the storage slot holding the array root is opened.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AccountStorageDeltaProof {
+    slots: Vec<StorageSlotProof>,
+}
+
+impl AccountStorageDeltaProof {
+    /// Returns the openings of the touched storage slots in ascending order of slot indexes.
+    pub fn slots(&self) -> &[StorageSlotProof] {
+        &self.slots
+    }
+
+    /// Verifies that the openings of this proof transition the storage from the specified
+    /// initial root to the specified final root.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The slots are not in strictly ascending order.
+    /// - The opening of the initial value of any slot is invalid.
+    /// - The storage root obtained after applying all slot updates is different from the final
+    ///   root.
+    pub fn verify(
+        &self,
+        initial_root: Digest,
+        final_root: Digest,
+    ) -> Result<(), AccountDeltaError> {
+        let mut root = initial_root;
+        for (pos, slot) in self.slots.iter().enumerate() {
+            if pos > 0 && self.slots[pos - 1].index >= slot.index {
+                return Err(AccountDeltaError::InvalidStorageSlotProof(slot.index));
+            }
+
+            let index = slot.index as u64;
+            if !slot.path.verify(index, slot.initial_value.into(), &root) {
+                return Err(AccountDeltaError::InvalidStorageSlotProof(slot.index));
+            }
+
+            root = slot
+                .path
+                .compute_root(index, slot.final_value.into())
+                .map_err(|_| AccountDeltaError::InvalidStorageSlotProof(slot.index))?;
+        }
+
+        if root != final_root {
+            return Err(AccountDeltaError::StorageRootMismatch {
+                expected: final_root,
+                actual: root,
+            });
+        }
+
+        Ok(())
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
 impl Serializable for AccountStorageDelta {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
         assert!(self.cleared_items.len() <= u8::MAX as usize, "too many cleared storage items");
@@ -96,6 +272,17 @@ impl Serializable for AccountStorageDelta {
             idx.write_into(target);
             value.write_into(target);
         }
+
+        assert!(
+            self.updated_array_items.len() <= u16::MAX as usize,
+            "too many updated storage array items"
+        );
+        target.write_u16(self.updated_array_items.len() as u16);
+        for (idx, item_idx, value) in self.updated_array_items.iter() {
+            idx.write_into(target);
+            item_idx.write_into(target);
+            value.write_into(target);
+        }
     }
 }
 
@@ -155,7 +342,74 @@ impl Deserializable for AccountStorageDelta {
             updated_items.push((idx, value));
         }
 
-        Ok(Self { cleared_items, updated_items })
+        // deserialize and validate updated array items
+        let num_updated_array_items = source.read_u16()? as usize;
+        let mut updated_array_items: Vec<(u8, u64, Word)> =
+            Vec::with_capacity(num_updated_array_items);
+        for _ in 0..num_updated_array_items {
+            let idx = source.read_u8()?;
+            let item_idx = source.read_u64()?;
+            let value = Word::read_from(source)?;
+
+            // make sure index is valid
+            if idx > MAX_MUTABLE_STORAGE_SLOT_IDX {
+                return Err(DeserializationError::InvalidValue(
+                    "immutable storage array item updated".to_string(),
+                ));
+            }
+
+            // make sure the same array item hasn't been updated before
+            if updated_array_items.iter().any(|x| x.0 == idx && x.1 == item_idx) {
+                return Err(DeserializationError::InvalidValue(
+                    "storage array item updated more than once".to_string(),
+                ));
+            }
+
+            updated_array_items.push((idx, item_idx, value));
+        }
+
+        Ok(Self {
+            cleared_items,
+            updated_items,
+            updated_array_items,
+        })
+    }
+}
+
+impl Serializable for StorageSlotProof {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.index.write_into(target);
+        self.initial_value.write_into(target);
+        self.final_value.write_into(target);
+        self.path.write_into(target);
+    }
+}
+
+impl Deserializable for StorageSlotProof {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let index = source.read_u8()?;
+        let initial_value = Word::read_from(source)?;
+        let final_value = Word::read_from(source)?;
+        let path = MerklePath::read_from(source)?;
+
+        Ok(Self { index, initial_value, final_value, path })
+    }
+}
+
+impl Serializable for AccountStorageDeltaProof {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        assert!(self.slots.len() <= u8::MAX as usize, "too many storage slot proofs");
+        target.write_u8(self.slots.len() as u8);
+        target.write_many(&self.slots);
+    }
+}
+
+impl Deserializable for AccountStorageDeltaProof {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let num_slots = source.read_u8()? as usize;
+        let slots = source.read_many::<StorageSlotProof>(num_slots)?;
+
+        Ok(Self { slots })
     }
 }
 
@@ -164,14 +418,20 @@ impl Deserializable for AccountStorageDelta {
 
 #[cfg(test)]
 mod tests {
-    use super::{AccountStorageDelta, Deserializable, Serializable};
-    use crate::{ONE, ZERO};
+    use alloc::{collections::BTreeMap, vec::Vec};
+
+    use super::{AccountStorageDelta, AccountStorageDeltaProof, Deserializable, Serializable};
+    use crate::{
+        accounts::{AccountStorage, SlotItem, StorageArray, StorageSlot},
+        AccountDeltaError, ONE, ZERO,
+    };
 
     #[test]
     fn account_storage_delta_validation() {
         let delta = AccountStorageDelta {
             cleared_items: vec![1, 2, 3],
             updated_items: vec![(4, [ONE, ONE, ONE, ONE]), (5, [ONE, ONE, ONE, ZERO])],
+            updated_array_items: vec![],
         };
         assert!(delta.validate().is_ok());
 
@@ -182,6 +442,7 @@ mod tests {
         let delta = AccountStorageDelta {
             cleared_items: vec![1, 2, 255],
             updated_items: vec![],
+            updated_array_items: vec![],
         };
         assert!(delta.validate().is_err());
 
@@ -192,6 +453,7 @@ mod tests {
         let delta = AccountStorageDelta {
             cleared_items: vec![1, 2, 1],
             updated_items: vec![],
+            updated_array_items: vec![],
         };
         assert!(delta.validate().is_err());
 
@@ -202,6 +464,7 @@ mod tests {
         let delta = AccountStorageDelta {
             cleared_items: vec![],
             updated_items: vec![(4, [ONE, ONE, ONE, ONE]), (255, [ONE, ONE, ONE, ZERO])],
+            updated_array_items: vec![],
         };
         assert!(delta.validate().is_err());
 
@@ -216,6 +479,7 @@ mod tests {
                 (5, [ONE, ONE, ONE, ZERO]),
                 (4, [ONE, ONE, ZERO, ZERO]),
             ],
+            updated_array_items: vec![],
         };
         assert!(delta.validate().is_err());
 
@@ -226,10 +490,101 @@ mod tests {
         let delta = AccountStorageDelta {
             cleared_items: vec![1, 2, 3],
             updated_items: vec![(2, [ONE, ONE, ONE, ONE]), (5, [ONE, ONE, ONE, ZERO])],
+            updated_array_items: vec![],
         };
         assert!(delta.validate().is_err());
 
         let bytes = delta.to_bytes();
         assert!(AccountStorageDelta::read_from_bytes(&bytes).is_err());
+
+        // array item updates
+        let delta = AccountStorageDelta {
+            cleared_items: vec![],
+            updated_items: vec![],
+            updated_array_items: vec![
+                (4, 0, [ONE, ONE, ONE, ONE]),
+                (4, 7, [ONE, ZERO, ZERO, ZERO]),
+            ],
+        };
+        assert!(delta.validate().is_ok());
+
+        let bytes = delta.to_bytes();
+        assert_eq!(AccountStorageDelta::read_from_bytes(&bytes), Ok(delta));
+
+        // duplicate in updated array items
+        let delta = AccountStorageDelta {
+            cleared_items: vec![],
+            updated_items: vec![],
+            updated_array_items: vec![
+                (4, 7, [ONE, ONE, ONE, ONE]),
+                (4, 7, [ONE, ZERO, ZERO, ZERO]),
+            ],
+        };
+        assert!(delta.validate().is_err());
+
+        let bytes = delta.to_bytes();
+        assert!(AccountStorageDelta::read_from_bytes(&bytes).is_err());
+    }
+
+    #[test]
+    fn account_storage_delta_proofs() {
+        let array = StorageArray::new(2, [(1, [ONE, ONE, ONE, ONE])]).unwrap();
+        let storage = AccountStorage::with_arrays(
+            vec![
+                SlotItem {
+                    index: 0,
+                    slot: StorageSlot::new_value([ONE, ZERO, ZERO, ZERO]),
+                },
+                SlotItem {
+                    index: 1,
+                    slot: StorageSlot::new_value([ONE, ONE, ZERO, ZERO]),
+                },
+                SlotItem {
+                    index: 3,
+                    slot: StorageSlot::new_array(*array.root(), 2),
+                },
+            ],
+            BTreeMap::from([(3, array)]),
+        )
+        .unwrap();
+
+        let delta = AccountStorageDelta {
+            cleared_items: vec![1],
+            updated_items: vec![(7, [ONE, ONE, ONE, ZERO]), (0, [ZERO, ZERO, ZERO, ONE])],
+            updated_array_items: vec![(3, 2, [ONE, ZERO, ONE, ZERO])],
+        };
+        let proof = delta.to_proofs(&storage).unwrap();
+        assert_eq!(
+            proof.slots().iter().map(|slot| slot.index()).collect::<Vec<_>>(),
+            vec![0, 1, 3, 7]
+        );
+
+        let mut final_storage = storage.clone();
+        final_storage.apply_delta(&delta).unwrap();
+        assert!(proof.verify(storage.root(), final_storage.root()).is_ok());
+
+        let bytes = proof.to_bytes();
+        assert_eq!(AccountStorageDeltaProof::read_from_bytes(&bytes).unwrap(), proof);
+
+        // the proof does not verify against other storage roots
+        assert_eq!(
+            proof.verify(storage.root(), storage.root()),
+            Err(AccountDeltaError::StorageRootMismatch {
+                expected: storage.root(),
+                actual: final_storage.root()
+            })
+        );
+        assert_eq!(
+            proof.verify(final_storage.root(), final_storage.root()),
+            Err(AccountDeltaError::InvalidStorageSlotProof(0))
+        );
+
+        // a delta which cannot be applied to the storage cannot be proven
+        let delta = AccountStorageDelta {
+            cleared_items: vec![],
+            updated_items: vec![(3, [ONE, ONE, ONE, ZERO])],
+            updated_array_items: vec![],
+        };
+        assert!(delta.to_proofs(&storage).is_err());
     }
 }
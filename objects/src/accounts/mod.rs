@@ -1,6 +1,9 @@
+use alloc::sync::Arc;
+
 use crate::{
     assembly::{Assembler, AssemblyContext, ModuleAst},
     assets::AssetVault,
+    transaction::AccountDetails,
     utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
     AccountError, Digest, Felt, Hasher, Word, ZERO,
 };
@@ -15,13 +18,18 @@ mod code;
 pub use code::AccountCode;
 
 pub mod delta;
-pub use delta::{AccountDelta, AccountStorageDelta, AccountVaultDelta};
+pub use delta::{
+    AccountDelta, AccountStorageDelta, AccountStorageDeltaProof, AccountUpdateChunk,
+    AccountUpdateDetails, AccountVaultDelta, StorageSlotProof,
+};
 
 mod seed;
 pub use seed::{get_account_seed, get_account_seed_single};
 
 mod storage;
-pub use storage::{AccountStorage, SlotItem, StorageSlot, StorageSlotType};
+pub use storage::{
+    verify_storage_item, AccountStorage, SlotItem, StorageArray, StorageSlot, StorageSlotType,
+};
 
 mod stub;
 pub use stub::AccountStub;
@@ -47,12 +55,16 @@ pub use data::{AccountData, AuthData};
 /// Out of the above components account ID is always immutable (once defined it can never be
 /// changed). Other components may be mutated throughout the lifetime of the account. However,
 /// account state can be changed only by invoking one of account interface methods.
+///
+/// The vault, storage, and code of an account are reference-counted and copied on write. Thus,
+/// cloning an account (e.g., via [Account::fork()]) is cheap, and mutating a clone copies only the
+/// components which are actually modified.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Account {
     id: AccountId,
-    vault: AssetVault,
-    storage: AccountStorage,
-    code: AccountCode,
+    vault: Arc<AssetVault>,
+    storage: Arc<AccountStorage>,
+    code: Arc<AccountCode>,
     nonce: Felt,
 }
 
@@ -61,14 +73,23 @@ impl Account {
     // --------------------------------------------------------------------------------------------
     /// Creates and returns a new account initialized with the specified ID, vault, storage, code,
     /// and nonce.
+    ///
+    /// Any uncommitted updates of the storage are committed.
     pub fn new(
         id: AccountId,
         vault: AssetVault,
-        storage: AccountStorage,
+        mut storage: AccountStorage,
         code: AccountCode,
         nonce: Felt,
     ) -> Self {
-        Self { id, vault, storage, code, nonce }
+        storage.commit();
+        Self {
+            id,
+            vault: Arc::new(vault),
+            storage: Arc::new(storage),
+            code: Arc::new(code),
+            nonce,
+        }
     }
 
     // PUBLIC ACCESSORS
@@ -168,17 +189,22 @@ impl Account {
     ///   nonce.
     pub fn apply_delta(&mut self, delta: &AccountDelta) -> Result<(), AccountError> {
         // update vault; we don't check vault delta validity here because AccountDelta can contain
-        // only valid vault deltas
-        for &asset in delta.vault().added_assets.iter() {
-            self.vault.add_asset(asset).map_err(AccountError::AssetVaultUpdateError)?;
-        }
+        // only valid vault deltas. the vault is copied only if it is shared and actually updated
+        if !delta.vault().is_empty() {
+            let vault = Arc::make_mut(&mut self.vault);
+            for &asset in delta.vault().added_assets.iter() {
+                vault.add_asset(asset).map_err(AccountError::AssetVaultUpdateError)?;
+            }
 
-        for &asset in delta.vault().removed_assets.iter() {
-            self.vault.remove_asset(asset).map_err(AccountError::AssetVaultUpdateError)?;
+            for &asset in delta.vault().removed_assets.iter() {
+                vault.remove_asset(asset).map_err(AccountError::AssetVaultUpdateError)?;
+            }
         }
 
         // update storage
-        self.storage.apply_delta(delta.storage())?;
+        if !delta.storage().is_empty() {
+            Arc::make_mut(&mut self.storage).apply_delta(delta.storage())?;
+        }
 
         // update nonce
         if let Some(nonce) = delta.nonce() {
@@ -188,6 +214,51 @@ impl Account {
         Ok(())
     }
 
+    /// Reconstructs the current state of an account by applying the provided sequence of on-chain
+    /// updates to the specified initial state of the account.
+    ///
+    /// The updates are applied in the provided order, and the hash of the account state after
+    /// each update is checked against the final hash recorded for that update.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - Any of the updates contains the full state of a different account.
+    /// - Any of the deltas cannot be applied to the account state preceding it.
+    /// - The hash of the account state after any of the updates does not match the final hash
+    ///   recorded for that update.
+    pub fn reconstruct(
+        genesis_state: Account,
+        updates: &[AccountUpdateDetails],
+    ) -> Result<Account, AccountError> {
+        let mut account = genesis_state;
+        for (index, update) in updates.iter().enumerate() {
+            match update.details() {
+                AccountDetails::Full(state) => {
+                    if state.id() != account.id() {
+                        return Err(AccountError::AccountUpdateIdMismatch {
+                            index,
+                            expected: account.id(),
+                            actual: state.id(),
+                        });
+                    }
+                    account = state.clone();
+                },
+                AccountDetails::Delta(delta) => account.apply_delta(delta)?,
+            }
+
+            let hash = account.hash();
+            if hash != update.final_hash() {
+                return Err(AccountError::AccountUpdateHashMismatch {
+                    index,
+                    expected: update.final_hash(),
+                    actual: hash,
+                });
+            }
+        }
+
+        Ok(account)
+    }
+
     /// Sets the nonce of this account to the specified nonce value.
     ///
     /// # Errors
@@ -207,13 +278,39 @@ impl Account {
         Ok(())
     }
 
+    // SPECULATIVE EXECUTION
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a fork of this account, i.e., a copy of the account which shares the vault,
+    /// storage, and code with this account until they are modified.
+    ///
+    /// Forks allow speculative updates of the account state (e.g., when simulating a chain of
+    /// transactions) without copying the state upfront. A fork can be either discarded, or
+    /// committed back to this account via [Account::commit()].
+    pub fn fork(&self) -> Self {
+        self.clone()
+    }
+
+    /// Replaces the state of this account with the state of the provided fork of this account.
+    ///
+    /// # Errors
+    /// Returns an error if the fork belongs to a different account.
+    pub fn commit(&mut self, fork: Account) -> Result<(), AccountError> {
+        if fork.id != self.id {
+            return Err(AccountError::AccountForkIdMismatch { expected: self.id, actual: fork.id });
+        }
+
+        *self = fork;
+        Ok(())
+    }
+
     // TEST HELPERS
     // --------------------------------------------------------------------------------------------
 
     #[cfg(test)]
     /// Returns a mutable reference to the vault of this account.
     pub fn vault_mut(&mut self) -> &mut AssetVault {
-        &mut self.vault
+        Arc::make_mut(&mut self.vault)
     }
 }
 
@@ -294,7 +391,8 @@ pub use testing::*;
 #[cfg(any(feature = "testing", test))]
 mod testing {
     use super::{
-        AccountStorageType, AccountType, ACCOUNT_STORAGE_MASK_SHIFT, ACCOUNT_TYPE_MASK_SHIFT,
+        AccountId, AccountStorageType, AccountType, Felt, ACCOUNT_STORAGE_MASK_SHIFT,
+        ACCOUNT_TYPE_MASK_SHIFT,
     };
 
     const fn account_id(account_type: AccountType, storage: AccountStorageType, rest: u64) -> u64 {
@@ -307,67 +405,131 @@ mod testing {
         id
     }
 
-    // REGULAR ACCOUNTS - OFF-CHAIN
-    pub const ACCOUNT_ID_SENDER: u64 = account_id(
-        AccountType::RegularAccountImmutableCode,
-        AccountStorageType::OffChain,
-        0b0001_1111,
-    );
-    pub const ACCOUNT_ID_OFF_CHAIN_SENDER: u64 = account_id(
+    // ACCOUNT ID ALLOCATOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Deterministically allocates valid account IDs of a given account type and storage type for
+    /// testing purposes.
+    ///
+    /// The ID at index `i` is built as follows:
+    /// - The top 4 bits encode the storage type and the account type.
+    /// - The next 56 bits encode `i + 1`.
+    /// - The lowest 4 bits are set to ones.
+    ///
+    /// Thus, every ID has at least [AccountId::MIN_ACCOUNT_ONES] ones, and the IDs of different
+    /// indexes, account types and storage types never collide. No seed is associated with the
+    /// allocated IDs, and so they can be used only for accounts which are not created via a
+    /// transaction.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub struct AccountIdAllocator {
+        account_type: AccountType,
+        storage_type: AccountStorageType,
+        next_index: u64,
+    }
+
+    impl AccountIdAllocator {
+        /// The maximum index for which an account ID can be allocated.
+        pub const MAX_INDEX: u64 = (1 << 56) - 2;
+
+        /// Returns a new [AccountIdAllocator] for IDs of the specified account and storage types.
+        pub const fn new(account_type: AccountType, storage_type: AccountStorageType) -> Self {
+            Self {
+                account_type,
+                storage_type,
+                next_index: 0,
+            }
+        }
+
+        /// Returns the integer representation of the account ID at the specified index.
+        ///
+        /// # Panics
+        /// Panics if the index is greater than [AccountIdAllocator::MAX_INDEX].
+        pub const fn id_at(&self, index: u64) -> u64 {
+            assert!(index <= Self::MAX_INDEX, "account ID index is too large");
+            account_id(self.account_type, self.storage_type, ((index + 1) << 4) | 0b1111)
+        }
+
+        /// Returns the account ID at the specified index.
+        ///
+        /// # Panics
+        /// Panics if the index is greater than [AccountIdAllocator::MAX_INDEX].
+        pub fn account_id_at(&self, index: u64) -> AccountId {
+            AccountId::new_unchecked(Felt::new(self.id_at(index)))
+        }
+    }
+
+    impl Iterator for AccountIdAllocator {
+        type Item = AccountId;
+
+        /// Returns the account ID at the next unallocated index.
+        fn next(&mut self) -> Option<AccountId> {
+            if self.next_index > Self::MAX_INDEX {
+                return None;
+            }
+
+            let account_id = self.account_id_at(self.next_index);
+            self.next_index += 1;
+            Some(account_id)
+        }
+    }
+
+    const REGULAR_IMMUTABLE_OFF_CHAIN: AccountIdAllocator = AccountIdAllocator::new(
         AccountType::RegularAccountImmutableCode,
         AccountStorageType::OffChain,
-        0b0010_1111,
     );
-    pub const ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN: u64 = account_id(
+    const REGULAR_UPDATABLE_OFF_CHAIN: AccountIdAllocator = AccountIdAllocator::new(
         AccountType::RegularAccountUpdatableCode,
         AccountStorageType::OffChain,
-        0b0011_1111,
-    );
-    // REGULAR ACCOUNTS - ON-CHAIN
-    pub const ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN: u64 = account_id(
-        AccountType::RegularAccountImmutableCode,
-        AccountStorageType::OnChain,
-        0b0001_1111,
     );
-    pub const ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN_2: u64 = account_id(
+    const REGULAR_IMMUTABLE_ON_CHAIN: AccountIdAllocator = AccountIdAllocator::new(
         AccountType::RegularAccountImmutableCode,
         AccountStorageType::OnChain,
-        0b0010_1111,
-    );
-    pub const ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN: u64 = account_id(
-        AccountType::RegularAccountUpdatableCode,
-        AccountStorageType::OnChain,
-        0b0011_1111,
     );
-    pub const ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN_2: u64 = account_id(
+    const REGULAR_UPDATABLE_ON_CHAIN: AccountIdAllocator = AccountIdAllocator::new(
         AccountType::RegularAccountUpdatableCode,
         AccountStorageType::OnChain,
-        0b0100_1111,
     );
+    const FUNGIBLE_FAUCET_OFF_CHAIN: AccountIdAllocator =
+        AccountIdAllocator::new(AccountType::FungibleFaucet, AccountStorageType::OffChain);
+    const FUNGIBLE_FAUCET_ON_CHAIN: AccountIdAllocator =
+        AccountIdAllocator::new(AccountType::FungibleFaucet, AccountStorageType::OnChain);
+    const NON_FUNGIBLE_FAUCET_OFF_CHAIN: AccountIdAllocator =
+        AccountIdAllocator::new(AccountType::NonFungibleFaucet, AccountStorageType::OffChain);
+    const NON_FUNGIBLE_FAUCET_ON_CHAIN: AccountIdAllocator =
+        AccountIdAllocator::new(AccountType::NonFungibleFaucet, AccountStorageType::OnChain);
+
+    // REGULAR ACCOUNTS - OFF-CHAIN
+    pub const ACCOUNT_ID_SENDER: u64 = REGULAR_IMMUTABLE_OFF_CHAIN.id_at(0);
+    pub const ACCOUNT_ID_OFF_CHAIN_SENDER: u64 = REGULAR_IMMUTABLE_OFF_CHAIN.id_at(1);
+    pub const ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_OFF_CHAIN: u64 =
+        REGULAR_UPDATABLE_OFF_CHAIN.id_at(2);
+    // REGULAR ACCOUNTS - ON-CHAIN
+    pub const ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN: u64 =
+        REGULAR_IMMUTABLE_ON_CHAIN.id_at(0);
+    pub const ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN_2: u64 =
+        REGULAR_IMMUTABLE_ON_CHAIN.id_at(1);
+    pub const ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN: u64 =
+        REGULAR_UPDATABLE_ON_CHAIN.id_at(2);
+    pub const ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN_2: u64 =
+        REGULAR_UPDATABLE_ON_CHAIN.id_at(3);
 
     // FUNGIBLE TOKENS - OFF-CHAIN
-    pub const ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN: u64 =
-        account_id(AccountType::FungibleFaucet, AccountStorageType::OffChain, 0b0001_1111);
+    pub const ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN: u64 = FUNGIBLE_FAUCET_OFF_CHAIN.id_at(0);
     // FUNGIBLE TOKENS - ON-CHAIN
-    pub const ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN: u64 =
-        account_id(AccountType::FungibleFaucet, AccountStorageType::OnChain, 0b0001_1111);
-    pub const ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1: u64 =
-        account_id(AccountType::FungibleFaucet, AccountStorageType::OnChain, 0b0010_1111);
-    pub const ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2: u64 =
-        account_id(AccountType::FungibleFaucet, AccountStorageType::OnChain, 0b0011_1111);
-    pub const ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_3: u64 =
-        account_id(AccountType::FungibleFaucet, AccountStorageType::OnChain, 0b0100_1111);
+    pub const ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN: u64 = FUNGIBLE_FAUCET_ON_CHAIN.id_at(0);
+    pub const ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1: u64 = FUNGIBLE_FAUCET_ON_CHAIN.id_at(1);
+    pub const ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2: u64 = FUNGIBLE_FAUCET_ON_CHAIN.id_at(2);
+    pub const ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_3: u64 = FUNGIBLE_FAUCET_ON_CHAIN.id_at(3);
 
     // NON-FUNGIBLE TOKENS - OFF-CHAIN
     pub const ACCOUNT_ID_INSUFFICIENT_ONES: u64 =
         account_id(AccountType::NonFungibleFaucet, AccountStorageType::OffChain, 0b0000_0000); // invalid
     pub const ACCOUNT_ID_NON_FUNGIBLE_FAUCET_OFF_CHAIN: u64 =
-        account_id(AccountType::NonFungibleFaucet, AccountStorageType::OffChain, 0b0001_1111);
+        NON_FUNGIBLE_FAUCET_OFF_CHAIN.id_at(0);
     // NON-FUNGIBLE TOKENS - ON-CHAIN
-    pub const ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN: u64 =
-        account_id(AccountType::NonFungibleFaucet, AccountStorageType::OnChain, 0b0010_1111);
+    pub const ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN: u64 = NON_FUNGIBLE_FAUCET_ON_CHAIN.id_at(1);
     pub const ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN_1: u64 =
-        account_id(AccountType::NonFungibleFaucet, AccountStorageType::OnChain, 0b0011_1111);
+        NON_FUNGIBLE_FAUCET_ON_CHAIN.id_at(2);
 
     #[test]
     fn test_account_id() {
@@ -387,6 +549,48 @@ mod testing {
             }
         }
     }
+
+    #[test]
+    fn test_account_id_allocator() {
+        use alloc::collections::BTreeSet;
+
+        let mut allocated = BTreeSet::new();
+        for account_type in [
+            AccountType::RegularAccountImmutableCode,
+            AccountType::RegularAccountUpdatableCode,
+            AccountType::NonFungibleFaucet,
+            AccountType::FungibleFaucet,
+        ] {
+            for storage_type in [AccountStorageType::OnChain, AccountStorageType::OffChain] {
+                let allocator = AccountIdAllocator::new(account_type, storage_type);
+                for (index, id) in allocator.take(20).enumerate() {
+                    // allocated IDs are valid, unique, and deterministic
+                    let acc = AccountId::try_from(u64::from(id)).unwrap();
+                    assert_eq!(acc.account_type(), account_type);
+                    assert_eq!(acc.storage_type(), storage_type);
+                    assert_eq!(allocator.account_id_at(index as u64), id);
+                    assert!(allocated.insert(u64::from(id)));
+                }
+
+                let last = allocator.account_id_at(AccountIdAllocator::MAX_INDEX);
+                assert!(AccountId::try_from(u64::from(last)).is_ok());
+            }
+        }
+
+        // testing constants are produced by the allocator
+        assert_eq!(
+            ACCOUNT_ID_SENDER,
+            account_id(
+                AccountType::RegularAccountImmutableCode,
+                AccountStorageType::OffChain,
+                0b0001_1111
+            )
+        );
+        assert_eq!(
+            ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN_1,
+            account_id(AccountType::NonFungibleFaucet, AccountStorageType::OnChain, 0b0011_1111)
+        );
+    }
 }
 
 // TESTS
@@ -394,15 +598,19 @@ mod testing {
 
 #[cfg(test)]
 mod tests {
-    use alloc::vec::Vec;
+    use alloc::{sync::Arc, vec::Vec};
 
     use super::{
-        Account, AccountCode, AccountDelta, AccountId, AccountStorage, AccountStorageDelta,
-        AccountVaultDelta, Assembler, Felt, ModuleAst, SlotItem, StorageSlot, StorageSlotType,
-        Word, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2,
+        Account, AccountCode, AccountDelta, AccountDetails, AccountId, AccountStorage,
+        AccountStorageDelta, AccountUpdateDetails, AccountVaultDelta, Assembler, Digest, Felt,
+        ModuleAst, SlotItem, StorageSlot, StorageSlotType, Word,
+        ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2,
         ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
     };
-    use crate::assets::{Asset, AssetVault, FungibleAsset};
+    use crate::{
+        assets::{Asset, AssetVault, FungibleAsset},
+        AccountError,
+    };
 
     fn build_account(assets: Vec<Asset>, nonce: Felt, storage_items: Vec<Word>) -> Account {
         // build account code
@@ -446,6 +654,7 @@ mod tests {
         let storage_delta = AccountStorageDelta {
             cleared_items: vec![0],
             updated_items: vec![(1, word)],
+            updated_array_items: vec![],
         };
 
         let vault_delta = AccountVaultDelta { added_assets, removed_assets };
@@ -485,6 +694,80 @@ mod tests {
         assert_eq!(account, final_account);
     }
 
+    #[test]
+    fn account_reconstruct_from_updates() {
+        let (asset_0, asset_1) = build_assets();
+        let word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        let genesis = build_account(vec![asset_0], Felt::new(1), vec![word]);
+
+        // the first update is recorded as a delta, and the second one as the full state
+        let delta = build_account_delta(vec![asset_1], vec![asset_0], Felt::new(2));
+        let state_1 = build_account(vec![asset_1], Felt::new(2), vec![Word::default(), word]);
+        let state_2 = build_account(vec![asset_0, asset_1], Felt::new(3), vec![word]);
+        let updates = vec![
+            AccountUpdateDetails::new(state_1.hash(), AccountDetails::Delta(delta.clone())),
+            AccountUpdateDetails::new(state_2.hash(), AccountDetails::Full(state_2.clone())),
+        ];
+
+        assert_eq!(Account::reconstruct(genesis.clone(), &updates).unwrap(), state_2);
+        assert_eq!(Account::reconstruct(genesis.clone(), &updates[..1]).unwrap(), state_1);
+        assert_eq!(Account::reconstruct(genesis.clone(), &[]).unwrap(), genesis);
+
+        // the hash of every intermediate state is verified
+        let updates = vec![
+            AccountUpdateDetails::new(Digest::default(), AccountDetails::Delta(delta)),
+            AccountUpdateDetails::new(state_2.hash(), AccountDetails::Full(state_2)),
+        ];
+        assert_eq!(
+            Account::reconstruct(genesis, &updates),
+            Err(AccountError::AccountUpdateHashMismatch {
+                index: 0,
+                expected: Digest::default(),
+                actual: state_1.hash(),
+            })
+        );
+    }
+
+    #[test]
+    fn account_fork_and_commit() {
+        let (asset_0, asset_1) = build_assets();
+        let word = [Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)];
+        let mut account = build_account(vec![asset_0], Felt::new(1), vec![word]);
+
+        // a fork shares the state of the account until the state is modified
+        let mut fork = account.fork();
+        assert!(Arc::ptr_eq(&fork.vault, &account.vault));
+        assert!(Arc::ptr_eq(&fork.storage, &account.storage));
+
+        // updating the vault of the fork copies only the vault
+        let vault_delta = AccountVaultDelta {
+            added_assets: vec![asset_1],
+            removed_assets: vec![],
+        };
+        let delta =
+            AccountDelta::new(AccountStorageDelta::default(), vault_delta, Some(Felt::new(2)))
+                .unwrap();
+        fork.apply_delta(&delta).unwrap();
+        assert!(!Arc::ptr_eq(&fork.vault, &account.vault));
+        assert!(Arc::ptr_eq(&fork.storage, &account.storage));
+        assert_eq!(account, build_account(vec![asset_0], Felt::new(1), vec![word]));
+
+        // a discarded fork leaves the account unchanged, and a committed fork replaces its state
+        let expected = build_account(vec![asset_0, asset_1], Felt::new(2), vec![word]);
+        assert_eq!(fork, expected);
+        account.commit(fork).unwrap();
+        assert_eq!(account, expected);
+
+        // forks of other accounts cannot be committed
+        let other_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let mut other = account.fork();
+        other.id = other_id;
+        assert_eq!(
+            account.commit(other),
+            Err(AccountError::AccountForkIdMismatch { expected: account.id(), actual: other_id })
+        );
+    }
+
     #[test]
     #[should_panic]
     fn valid_account_delta_with_unchanged_nonce() {
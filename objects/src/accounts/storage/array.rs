@@ -0,0 +1,235 @@
+use alloc::{collections::BTreeMap, string::ToString, vec::Vec};
+
+use super::{
+    AccountError, ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, Hasher,
+    Serializable, Word,
+};
+use crate::crypto::merkle::{EmptySubtreeRoots, InnerNodeInfo};
+
+// CONSTANTS
+// ================================================================================================
+
+/// The minimum depth of a storage array.
+const MIN_ARRAY_DEPTH: u8 = 2;
+
+/// The maximum depth of a storage array.
+const MAX_ARRAY_DEPTH: u8 = 64;
+
+/// The value of an item which has not been set.
+const EMPTY_ITEM: Word = [crate::ZERO; 4];
+
+// STORAGE ARRAY
+// ================================================================================================
+
+/// A fixed-size array of words stored in an account storage slot.
+///
+/// The array has a capacity of 2^depth items, and the storage slot holding the array contains the
+/// root of a sparse Merkle tree of the specified depth built from the array items. Items which
+/// have not been set are equal to [ZERO; 4].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StorageArray {
+    depth: u8,
+    items: BTreeMap<u64, Word>,
+}
+
+impl StorageArray {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [StorageArray] with capacity 2^depth instantiated with the provided items.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The depth is smaller than 2 or greater than 64.
+    /// - Any of the item indexes is greater than or equal to 2^depth.
+    pub fn new<T>(depth: u8, items: T) -> Result<Self, AccountError>
+    where
+        T: IntoIterator<Item = (u64, Word)>,
+    {
+        if !(MIN_ARRAY_DEPTH..=MAX_ARRAY_DEPTH).contains(&depth) {
+            return Err(AccountError::StorageArrayInvalidDepth(depth));
+        }
+
+        let mut array = Self { depth, items: BTreeMap::new() };
+        for (index, value) in items {
+            array.set(index, value)?;
+        }
+
+        Ok(array)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the depth of this array; the capacity of the array is 2^depth.
+    pub fn depth(&self) -> u8 {
+        self.depth
+    }
+
+    /// Returns the item at the specified index, or [ZERO; 4] if the item has not been set.
+    pub fn get(&self, index: u64) -> Word {
+        self.items.get(&index).copied().unwrap_or(EMPTY_ITEM)
+    }
+
+    /// Returns an iterator over the (index, value) pairs of all non-empty items in this array.
+    pub fn items(&self) -> impl Iterator<Item = (&u64, &Word)> {
+        self.items.iter()
+    }
+
+    /// Returns the root of the sparse Merkle tree built from the items of this array.
+    pub fn root(&self) -> Digest {
+        self.compute_tree(|_| ())
+    }
+
+    /// Returns an iterator over the inner nodes of the sparse Merkle tree built from the items of
+    /// this array.
+    ///
+    /// Nodes which are roots of empty subtrees are not included.
+    pub fn inner_nodes(&self) -> impl Iterator<Item = InnerNodeInfo> {
+        let mut nodes = Vec::new();
+        self.compute_tree(|node| nodes.push(node));
+        nodes.into_iter()
+    }
+
+    // DATA MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Sets the item at the specified index and returns the previous value of the item.
+    ///
+    /// # Errors
+    /// Returns an error if the index is greater than or equal to 2^depth.
+    pub fn set(&mut self, index: u64, value: Word) -> Result<Word, AccountError> {
+        if self.depth < MAX_ARRAY_DEPTH && index >> self.depth != 0 {
+            return Err(AccountError::StorageArrayIndexOutOfBounds { index, depth: self.depth });
+        }
+
+        let prev_value = if value == EMPTY_ITEM {
+            self.items.remove(&index)
+        } else {
+            self.items.insert(index, value)
+        };
+
+        Ok(prev_value.unwrap_or(EMPTY_ITEM))
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Computes the root of the sparse Merkle tree built from the items of this array, invoking
+    /// the provided callback for every inner node which is not a root of an empty subtree.
+    fn compute_tree(&self, mut on_node: impl FnMut(InnerNodeInfo)) -> Digest {
+        let mut nodes: BTreeMap<u64, Digest> =
+            self.items.iter().map(|(&index, &value)| (index, value.into())).collect();
+
+        for node_depth in (0..self.depth).rev() {
+            let empty_child = *EmptySubtreeRoots::entry(self.depth, node_depth + 1);
+
+            let mut parents = BTreeMap::new();
+            for &index in nodes.keys() {
+                let parent_index = index >> 1;
+                if parents.contains_key(&parent_index) {
+                    continue;
+                }
+
+                let left = nodes.get(&(parent_index << 1)).copied().unwrap_or(empty_child);
+                let right = nodes.get(&((parent_index << 1) + 1)).copied().unwrap_or(empty_child);
+                let value = Hasher::merge(&[left, right]);
+
+                on_node(InnerNodeInfo { value, left, right });
+                parents.insert(parent_index, value);
+            }
+            nodes = parents;
+        }
+
+        nodes
+            .get(&0)
+            .copied()
+            .unwrap_or_else(|| *EmptySubtreeRoots::entry(self.depth, 0))
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for StorageArray {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(self.depth);
+        target.write_u64(self.items.len() as u64);
+        for (index, value) in self.items.iter() {
+            target.write_u64(*index);
+            target.write(value);
+        }
+    }
+}
+
+impl Deserializable for StorageArray {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let depth = source.read_u8()?;
+        let num_items = source.read_u64()?;
+
+        let mut items = Vec::new();
+        for _ in 0..num_items {
+            let index = source.read_u64()?;
+            let value: Word = source.read()?;
+            items.push((index, value));
+        }
+
+        Self::new(depth, items).map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{Deserializable, Serializable, StorageArray};
+    use crate::{
+        crypto::merkle::{DefaultMerkleStore, LeafIndex, SimpleSmt},
+        Felt, ONE, ZERO,
+    };
+
+    #[test]
+    fn storage_array_root_matches_simple_smt() {
+        let items = [
+            (0, [ONE, ZERO, ZERO, ZERO]),
+            (5, [ONE, ONE, ZERO, ZERO]),
+            (15, [Felt::new(7), ONE, ONE, ONE]),
+        ];
+
+        let array = StorageArray::new(4, items).unwrap();
+        let smt = SimpleSmt::<4>::with_leaves(items).unwrap();
+        assert_eq!(array.root(), smt.root());
+
+        // the inner nodes must be sufficient to open every item
+        let mut store = DefaultMerkleStore::default();
+        store.extend(array.inner_nodes());
+        for (index, value) in items {
+            let node_index = LeafIndex::<4>::new(index).unwrap().into();
+            assert_eq!(store.get_node(array.root(), node_index).unwrap(), value.into());
+        }
+
+        // clearing an item removes it from the array
+        let mut array = array;
+        array.set(5, [ZERO; 4]).unwrap();
+        assert_eq!(array.items().count(), 2);
+        let mut smt = smt;
+        smt.insert(LeafIndex::new(5).unwrap(), [ZERO; 4]);
+        assert_eq!(array.root(), smt.root());
+    }
+
+    #[test]
+    fn storage_array_fails_on_invalid_input() {
+        assert!(StorageArray::new(1, []).is_err());
+        assert!(StorageArray::new(65, []).is_err());
+        assert!(StorageArray::new(4, [(16, [ONE; 4])]).is_err());
+        assert!(StorageArray::new(64, [(u64::MAX, [ONE; 4])]).is_ok());
+    }
+
+    #[test]
+    fn storage_array_serialization() {
+        let array = StorageArray::new(8, [(3, [ONE; 4]), (200, [ONE, ZERO, ONE, ZERO])]).unwrap();
+        let bytes = array.to_bytes();
+        assert_eq!(array, StorageArray::read_from_bytes(&bytes).unwrap());
+    }
+}
@@ -4,7 +4,10 @@ use super::{
     AccountError, AccountStorageDelta, ByteReader, ByteWriter, Deserializable,
     DeserializationError, Digest, Felt, Hasher, Serializable, Word,
 };
-use crate::crypto::merkle::{LeafIndex, NodeIndex, SimpleSmt};
+use crate::crypto::merkle::{LeafIndex, MerklePath, NodeIndex, SimpleSmt};
+
+mod array;
+pub use array::StorageArray;
 
 mod slot;
 pub use slot::StorageSlotType;
@@ -88,11 +91,25 @@ impl StorageSlot {
 /// - Map: a key-value map where keys are words and values contain up to 256 words.
 ///
 /// Storage slots are stored in a simple Sparse Merkle Tree of depth 8. Slot 255 is always reserved
-/// and contains information about slot types of all other slots.
-#[derive(Debug, Clone, PartialEq, Eq)]
+/// and contains information about slot types of all other slots. Slots 252 and 253 are reserved for
+/// the version of the authentication scheme and the root of the output note hook of the account
+/// respectively; these slots can be set only via [AccountStorage::with_auth_scheme_version()] and
+/// [AccountStorage::with_output_note_hook()].
+///
+/// The contents of array slots with value arity 0 can be tracked alongside the slots as
+/// [StorageArray]s. For such slots, the slot value is the root of the array.
+///
+/// Updates to storage slots are not applied to the storage tree immediately. Instead, updated
+/// slots are tracked in a set of dirty slots, and are applied to the tree only when the storage
+/// is committed (see [AccountStorage::commit()]). Thus, a slot which is updated several times
+/// between commits is hashed into the tree only once. Storage is committed automatically when
+/// it is placed into an account and after a delta is applied to it.
+#[derive(Debug, Clone)]
 pub struct AccountStorage {
     slots: SimpleSmt<STORAGE_TREE_DEPTH>,
     layout: Vec<StorageSlotType>,
+    arrays: BTreeMap<u8, StorageArray>,
+    dirty_slots: BTreeMap<u8, Word>,
 }
 
 impl AccountStorage {
@@ -108,10 +125,33 @@ impl AccountStorage {
     /// The storage slot at which the layout commitment is stored.
     pub const SLOT_LAYOUT_COMMITMENT_INDEX: u8 = 255;
 
+    /// The storage slot at which the root of the output note hook of the account is stored.
+    pub const OUTPUT_NOTE_HOOK_SLOT_INDEX: u8 = 253;
+
+    /// The storage slot at which the version of the authentication scheme of the account is
+    /// stored.
+    pub const AUTH_SCHEME_SLOT_INDEX: u8 = 252;
+
     // CONSTRUCTOR
     // --------------------------------------------------------------------------------------------
     /// Returns a new instance of account storage initialized with the provided items.
     pub fn new(items: Vec<SlotItem>) -> Result<AccountStorage, AccountError> {
+        Self::with_arrays(items, BTreeMap::new())
+    }
+
+    /// Returns a new instance of account storage initialized with the provided items and the
+    /// contents of the provided arrays.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - Any of the items is placed into a reserved slot or is duplicated.
+    /// - Any of the arrays is placed into a slot which is not an array slot of the same depth and
+    ///   value arity 0.
+    /// - The value of any slot holding an array is not equal to the root of that array.
+    pub fn with_arrays(
+        items: Vec<SlotItem>,
+        arrays: BTreeMap<u8, StorageArray>,
+    ) -> Result<AccountStorage, AccountError> {
         // initialize storage layout
         let mut layout = vec![StorageSlotType::default(); Self::NUM_STORAGE_SLOTS];
 
@@ -123,7 +163,7 @@ impl AccountStorage {
         let mut entries = items
             .into_iter()
             .map(|item| {
-                if item.index == Self::SLOT_LAYOUT_COMMITMENT_INDEX {
+                if Self::is_reserved_slot(item.index) {
                     return Err(AccountError::StorageSlotIsReserved(item.index));
                 }
 
@@ -142,27 +182,117 @@ impl AccountStorage {
         let slots = SimpleSmt::<STORAGE_TREE_DEPTH>::with_leaves(entries)
             .map_err(AccountError::DuplicateStorageItems)?;
 
-        Ok(Self { slots, layout })
+        // make sure the arrays are consistent with the slots they are placed into
+        for (&index, array) in arrays.iter() {
+            match layout[index as usize] {
+                StorageSlotType::Array { depth, value_arity: 0 } if depth == array.depth() => {},
+                slot_type => return Err(AccountError::StorageSlotNotArraySlot(index, slot_type)),
+            }
+
+            let item_index =
+                LeafIndex::new(index as u64).expect("index is u8 - index within range");
+            if slots.get_leaf(&item_index) != *array.root() {
+                return Err(AccountError::StorageArrayRootMismatch(index));
+            }
+        }
+
+        Ok(Self {
+            slots,
+            layout,
+            arrays,
+            dirty_slots: BTreeMap::new(),
+        })
+    }
+
+    /// Returns this storage with the version of the authentication scheme set to the provided
+    /// value.
+    ///
+    /// The version is stored in the reserved slot [AccountStorage::AUTH_SCHEME_SLOT_INDEX], and the
+    /// storage is committed.
+    pub fn with_auth_scheme_version(self, version: Word) -> Self {
+        self.with_reserved_item(Self::AUTH_SCHEME_SLOT_INDEX, version)
+    }
+
+    /// Returns this storage with the root of the output note hook set to the provided value.
+    ///
+    /// The root is stored in the reserved slot [AccountStorage::OUTPUT_NOTE_HOOK_SLOT_INDEX], and
+    /// the storage is committed. Once set, the hook is invoked for every note created by a
+    /// transaction against the account, and thus, the root must be the root of a procedure of the
+    /// account.
+    pub fn with_output_note_hook(self, hook_root: Word) -> Self {
+        self.with_reserved_item(Self::OUTPUT_NOTE_HOOK_SLOT_INDEX, hook_root)
     }
 
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
     /// Returns a commitment to this storage.
+    ///
+    /// If the storage has uncommitted updates, the root is computed from the committed storage
+    /// tree and the dirty slots, hashing every affected node of the tree once. To avoid repeating
+    /// this computation, the storage should be committed first (see [AccountStorage::commit()]).
     pub fn root(&self) -> Digest {
-        self.slots.root()
+        if self.dirty_slots.is_empty() {
+            return self.slots.root();
+        }
+
+        // compute the nodes affected by the dirty slots level by level, starting from the leaves
+        let mut nodes: BTreeMap<u64, Digest> = self
+            .dirty_slots
+            .iter()
+            .map(|(&index, &value)| (index as u64, value.into()))
+            .collect();
+        for depth in (0..Self::STORAGE_TREE_DEPTH).rev() {
+            let mut parents = BTreeMap::new();
+            for &index in nodes.keys() {
+                let parent = index >> 1;
+                if parents.contains_key(&parent) {
+                    continue;
+                }
+
+                let get_child = |child: u64| match nodes.get(&child) {
+                    Some(node) => *node,
+                    None => self.get_node(depth + 1, child),
+                };
+                let node = Hasher::merge(&[get_child(parent << 1), get_child((parent << 1) | 1)]);
+                parents.insert(parent, node);
+            }
+            nodes = parents;
+        }
+
+        nodes[&0]
     }
 
     /// Returns an item from the storage at the specified index.
     ///
     /// If the item is not present in the storage, [ZERO; 4] is returned.
     pub fn get_item(&self, index: u8) -> Digest {
-        let item_index = NodeIndex::new(Self::STORAGE_TREE_DEPTH, index as u64)
-            .expect("index is u8 - index within range");
-        self.slots.get_node(item_index).expect("index is u8 - index within range")
+        match self.dirty_slots.get(&index) {
+            Some(value) => (*value).into(),
+            None => self.get_node(Self::STORAGE_TREE_DEPTH, index as u64),
+        }
+    }
+
+    /// Returns true if all updates to this storage were applied to the storage tree.
+    pub fn is_committed(&self) -> bool {
+        self.dirty_slots.is_empty()
+    }
+
+    /// Returns an item of the array stored at the specified slot index.
+    ///
+    /// # Errors
+    /// Returns an error if the contents of an array are not tracked for the specified slot.
+    pub fn get_array_item(&self, index: u8, item_index: u64) -> Result<Word, AccountError> {
+        match self.arrays.get(&index) {
+            Some(array) => Ok(array.get(item_index)),
+            None => Err(AccountError::StorageSlotNotArraySlot(index, self.layout[index as usize])),
+        }
     }
 
     /// Returns a reference to the Sparse Merkle Tree that backs the storage slots.
+    ///
+    /// The tree reflects only the committed state of the storage, i.e., updates made since the
+    /// last call to [AccountStorage::commit()] are not included.
     pub fn slots(&self) -> &SimpleSmt<STORAGE_TREE_DEPTH> {
         &self.slots
     }
@@ -177,10 +307,29 @@ impl AccountStorage {
         Hasher::hash_elements(&self.layout.iter().map(Felt::from).collect::<Vec<_>>())
     }
 
+    /// Returns the arrays tracked by this storage keyed by their slot index.
+    pub fn arrays(&self) -> &BTreeMap<u8, StorageArray> {
+        &self.arrays
+    }
+
     // DATA MUTATORS
     // --------------------------------------------------------------------------------------------
 
-    /// Applies the provided delta to this account storage.
+    /// Applies all updates made since the last commit to the storage tree and returns the new
+    /// root of the storage.
+    ///
+    /// Every dirty slot is written into the tree once, regardless of how many times it was
+    /// updated, and slots which were set back to their committed values are skipped.
+    pub fn commit(&mut self) -> Digest {
+        for (index, value) in core::mem::take(&mut self.dirty_slots) {
+            let index = LeafIndex::new(index as u64).expect("index is u8 - index within range");
+            self.slots.insert(index, value);
+        }
+
+        self.slots.root()
+    }
+
+    /// Applies the provided delta to this account storage and commits the storage.
     ///
     /// This method assumes that the delta has been validated by the calling method and so, no
     /// additional validation of delta is performed.
@@ -198,14 +347,27 @@ impl AccountStorage {
             self.set_item(slot_idx, slot_value)?;
         }
 
+        for &(slot_idx, item_idx, item_value) in delta.updated_array_items.iter() {
+            self.set_array_item(slot_idx, item_idx, item_value)?;
+        }
+
+        self.commit();
         Ok(())
     }
 
-    /// Sets an item from the storage at the specified index.
+    /// Sets an item from the storage at the specified index and returns the previous value of the
+    /// item.
+    ///
+    /// The update is applied to the storage tree on the next commit (see
+    /// [AccountStorage::commit()]).
+    ///
+    /// The auth scheme and output note hook slots can be updated via this method, since the
+    /// transaction kernel allows account procedures to update them, and such updates are applied
+    /// to the storage via account deltas.
     ///
     /// # Errors
     /// Returns an error if:
-    /// - The index specifies a reserved storage slot.
+    /// - The index specifies the layout commitment slot.
     /// - The update violates storage layout constraints.
     pub fn set_item(&mut self, index: u8, value: Word) -> Result<Word, AccountError> {
         // layout commitment slot cannot be updated
@@ -227,13 +389,108 @@ impl AccountStorage {
             slot_type => Err(AccountError::StorageSlotNotValueSlot(index, slot_type))?,
         }
 
-        // update the slot and return
-        let index = LeafIndex::new(index as u64).expect("index is u8 - index within range");
-        let slot_value = self.slots.insert(index, value);
-        Ok(slot_value)
+        // mark the slot as dirty and return the previous value
+        let prev_value = self.get_item(index);
+        self.dirty_slots.insert(index, value);
+        Ok(prev_value.into())
+    }
+
+    /// Sets an item of the array stored at the specified slot index and returns the previous
+    /// value of the item.
+    ///
+    /// The value of the slot is updated to the new root of the array.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The contents of an array are not tracked for the specified slot.
+    /// - The item index is outside of the bounds of the array.
+    pub fn set_array_item(
+        &mut self,
+        index: u8,
+        item_index: u64,
+        value: Word,
+    ) -> Result<Word, AccountError> {
+        let array = self
+            .arrays
+            .get_mut(&index)
+            .ok_or(AccountError::StorageSlotNotArraySlot(index, self.layout[index as usize]))?;
+
+        let prev_value = array.set(item_index, value)?;
+
+        // update the slot with the new array root
+        self.dirty_slots.insert(index, *array.root());
+
+        Ok(prev_value)
+    }
+
+    /// Stops tracking the contents of the arrays stored at the slots for which the provided
+    /// predicate returns false.
+    ///
+    /// The values of the array slots, and thus the root of the storage, are not affected.
+    pub fn retain_arrays<F>(&mut self, mut f: F)
+    where
+        F: FnMut(u8) -> bool,
+    {
+        self.arrays.retain(|&index, _| f(index));
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns true if the slot with the specified index is reserved and cannot be initialized
+    /// with an arbitrary item.
+    fn is_reserved_slot(index: u8) -> bool {
+        index == Self::SLOT_LAYOUT_COMMITMENT_INDEX
+            || index == Self::OUTPUT_NOTE_HOOK_SLOT_INDEX
+            || index == Self::AUTH_SCHEME_SLOT_INDEX
+    }
+
+    /// Sets the value of the specified reserved slot and commits the storage.
+    ///
+    /// Reserved slots always have the default slot type, and thus, the value can be set without
+    /// checking the storage layout.
+    fn with_reserved_item(mut self, index: u8, value: Word) -> Self {
+        debug_assert!(self.layout[index as usize].is_default());
+        self.dirty_slots.insert(index, value);
+        self.commit();
+        self
+    }
+
+    /// Returns the node of the committed storage tree at the specified depth and index.
+    fn get_node(&self, depth: u8, index: u64) -> Digest {
+        let node_index = NodeIndex::new(depth, index).expect("node index is within the tree");
+        self.slots.get_node(node_index).expect("node index is within the tree")
     }
 }
 
+impl PartialEq for AccountStorage {
+    fn eq(&self, other: &Self) -> bool {
+        self.layout == other.layout && self.arrays == other.arrays && self.root() == other.root()
+    }
+}
+
+impl Eq for AccountStorage {}
+
+// STORAGE PROOFS
+// ================================================================================================
+
+/// Returns true if the provided Merkle path shows that the storage with the specified root holds
+/// the specified value in the slot with the specified index.
+///
+/// The slots are the leaves of the storage tree in the same way as in the transaction kernel, i.e.,
+/// the leaf at the index of a slot is the value of the slot (for map and array slots, the root of
+/// the map or array). The path must therefore have a depth of [STORAGE_TREE_DEPTH].
+///
+/// This allows verifying claims about the storage of an account without instantiating the storage.
+pub fn verify_storage_item(
+    storage_root: Digest,
+    index: u8,
+    value: Word,
+    proof: &MerklePath,
+) -> bool {
+    proof.depth() == STORAGE_TREE_DEPTH && proof.verify(index as u64, value.into(), &storage_root)
+}
+
 // SERIALIZATION
 // ================================================================================================
 
@@ -255,22 +512,32 @@ impl Serializable for AccountStorage {
         }
 
         // serialize slot values; we serialize only non-empty values and also skip slot 255 as info
-        // for this slot was already serialized as a part of serializing slot type info above
-        let filled_slots = self
-            .slots
-            .leaves()
-            .filter(|(idx, &value)| {
+        // for this slot was already serialized as a part of serializing slot type info above.
+        // uncommitted values of dirty slots take precedence over the committed values.
+        let mut slot_values: BTreeMap<u8, Word> =
+            self.slots.leaves().map(|(idx, &value)| (idx as u8, value)).collect();
+        slot_values.extend(self.dirty_slots.iter().map(|(&idx, &value)| (idx, value)));
+        let filled_slots = slot_values
+            .into_iter()
+            .filter(|&(idx, value)| {
                 // TODO: consider checking empty values for complex types as well
                 value != SimpleSmt::<STORAGE_TREE_DEPTH>::EMPTY_VALUE
-                    && *idx as u8 != AccountStorage::SLOT_LAYOUT_COMMITMENT_INDEX
+                    && idx != AccountStorage::SLOT_LAYOUT_COMMITMENT_INDEX
             })
             .collect::<Vec<_>>();
 
         target.write_u8(filled_slots.len() as u8);
-        for (idx, &value) in filled_slots {
-            target.write_u8(idx as u8);
+        for (idx, value) in filled_slots {
+            target.write_u8(idx);
             target.write(value);
         }
+
+        // serialize array contents
+        target.write_u8(self.arrays.len() as u8);
+        for (&idx, array) in self.arrays.iter() {
+            target.write_u8(idx);
+            target.write(array);
+        }
     }
 }
 
@@ -286,20 +553,45 @@ impl Deserializable for AccountStorage {
             complex_types.insert(idx, slot_type);
         }
 
-        // read filled slots and build a vector of slot items
+        // read filled slots and build a vector of slot items; values of the reserved slots are
+        // set separately as they cannot be provided as slot items
         let mut items: Vec<SlotItem> = Vec::new();
+        let mut reserved_items: Vec<(u8, Word)> = Vec::new();
         let num_filled_slots = source.read_u8()?;
         for _ in 0..num_filled_slots {
             let index = source.read_u8()?;
             let value: Word = source.read()?;
             let slot_type = complex_types.remove(&index).unwrap_or_default();
+            if AccountStorage::is_reserved_slot(index) {
+                if !slot_type.is_default() {
+                    return Err(DeserializationError::InvalidValue(format!(
+                        "reserved storage slot {index} must have the default slot type"
+                    )));
+                }
+                reserved_items.push((index, value));
+                continue;
+            }
             items.push(SlotItem {
                 index,
                 slot: StorageSlot { slot_type, value },
             });
         }
 
-        Self::new(items).map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+        // read array contents
+        let mut arrays = BTreeMap::new();
+        let num_arrays = source.read_u8()?;
+        for _ in 0..num_arrays {
+            let index = source.read_u8()?;
+            let array: StorageArray = source.read()?;
+            arrays.insert(index, array);
+        }
+
+        let storage = Self::with_arrays(items, arrays)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+
+        Ok(reserved_items
+            .into_iter()
+            .fold(storage, |storage, (index, value)| storage.with_reserved_item(index, value)))
     }
 }
 
@@ -308,12 +600,13 @@ impl Deserializable for AccountStorage {
 
 #[cfg(test)]
 mod tests {
-    use alloc::vec::Vec;
+    use alloc::{collections::BTreeMap, vec::Vec};
 
     use super::{
-        AccountStorage, Deserializable, Serializable, SlotItem, StorageSlot, StorageSlotType,
+        verify_storage_item, AccountStorage, Deserializable, LeafIndex, MerklePath, Serializable,
+        SlotItem, StorageArray, StorageSlot, StorageSlotType, STORAGE_TREE_DEPTH,
     };
-    use crate::{ONE, ZERO};
+    use crate::{Felt, ONE, ZERO};
 
     #[test]
     fn account_storage_serialization() {
@@ -375,4 +668,159 @@ mod tests {
         let bytes = storage.to_bytes();
         assert_eq!(storage, AccountStorage::read_from_bytes(&bytes).unwrap());
     }
+
+    #[test]
+    fn account_storage_lazy_commit() {
+        let items = (0..10_u8)
+            .map(|index| SlotItem {
+                index,
+                slot: StorageSlot::new_value([Felt::from(index), ZERO, ZERO, ZERO]),
+            })
+            .collect::<Vec<_>>();
+        let mut storage = AccountStorage::new(items.clone()).unwrap();
+        let initial_root = storage.root();
+
+        // updates are tracked in the dirty set and are reflected by the accessors
+        assert_eq!(storage.set_item(1, [ONE; 4]).unwrap(), [ONE, ZERO, ZERO, ZERO]);
+        assert_eq!(storage.set_item(1, [ONE, ONE, ZERO, ZERO]).unwrap(), [ONE; 4]);
+        storage.set_item(7, [ZERO; 4]).unwrap();
+        storage.set_item(200, [ONE; 4]).unwrap();
+        assert!(!storage.is_committed());
+        assert_eq!(storage.slots().root(), initial_root);
+        assert_eq!(storage.get_item(1), [ONE, ONE, ZERO, ZERO].into());
+
+        // the root of the uncommitted storage is the same as the root of the rebuilt storage
+        let mut expected_items = items;
+        expected_items[1].slot.value = [ONE, ONE, ZERO, ZERO];
+        expected_items[7].slot.value = [ZERO; 4];
+        expected_items.push(SlotItem {
+            index: 200,
+            slot: StorageSlot::new_value([ONE; 4]),
+        });
+        let expected = AccountStorage::new(expected_items).unwrap();
+        assert_eq!(storage.root(), expected.root());
+        assert_eq!(storage, expected);
+
+        let bytes = storage.to_bytes();
+        assert_eq!(AccountStorage::read_from_bytes(&bytes).unwrap(), expected);
+
+        // committing applies the updates to the storage tree
+        assert_eq!(storage.commit(), expected.root());
+        assert!(storage.is_committed());
+        assert_eq!(storage.slots().root(), expected.root());
+    }
+
+    #[test]
+    fn account_storage_reserved_slots() {
+        // reserved slots cannot be initialized with slot items
+        for index in [
+            AccountStorage::AUTH_SCHEME_SLOT_INDEX,
+            AccountStorage::OUTPUT_NOTE_HOOK_SLOT_INDEX,
+            AccountStorage::SLOT_LAYOUT_COMMITMENT_INDEX,
+        ] {
+            let item = SlotItem {
+                index,
+                slot: StorageSlot::new_value([ONE; 4]),
+            };
+            assert!(AccountStorage::new(vec![item]).is_err());
+        }
+
+        // reserved slots can be set explicitly and survive serialization
+        let item = SlotItem {
+            index: 0,
+            slot: StorageSlot::new_value([ONE; 4]),
+        };
+        let storage = AccountStorage::new(vec![item])
+            .unwrap()
+            .with_auth_scheme_version([ONE, ZERO, ZERO, ZERO])
+            .with_output_note_hook([ONE, ONE, ZERO, ZERO]);
+        assert!(storage.is_committed());
+        assert_eq!(
+            storage.get_item(AccountStorage::AUTH_SCHEME_SLOT_INDEX),
+            [ONE, ZERO, ZERO, ZERO].into()
+        );
+        assert_eq!(
+            storage.get_item(AccountStorage::OUTPUT_NOTE_HOOK_SLOT_INDEX),
+            [ONE, ONE, ZERO, ZERO].into()
+        );
+
+        let bytes = storage.to_bytes();
+        assert_eq!(storage, AccountStorage::read_from_bytes(&bytes).unwrap());
+    }
+
+    #[test]
+    fn account_storage_arrays() {
+        let array = StorageArray::new(4, [(3, [ONE, ONE, ONE, ONE])]).unwrap();
+        let array_item = SlotItem {
+            index: 1,
+            slot: StorageSlot::new_array(*array.root(), 4),
+        };
+
+        let mut storage = AccountStorage::with_arrays(
+            vec![array_item.clone()],
+            BTreeMap::from([(1, array.clone())]),
+        )
+        .unwrap();
+        assert_eq!(storage.get_array_item(1, 3).unwrap(), [ONE, ONE, ONE, ONE]);
+        assert_eq!(storage.get_array_item(1, 4).unwrap(), [ZERO; 4]);
+
+        let bytes = storage.to_bytes();
+        assert_eq!(storage, AccountStorage::read_from_bytes(&bytes).unwrap());
+
+        // updating an array item updates the slot value
+        let prev_value = storage.set_array_item(1, 4, [ONE, ZERO, ZERO, ZERO]).unwrap();
+        assert_eq!(prev_value, [ZERO; 4]);
+        assert_eq!(storage.get_array_item(1, 4).unwrap(), [ONE, ZERO, ZERO, ZERO]);
+        assert_eq!(storage.get_item(1), storage.arrays()[&1].root());
+        assert!(storage.set_array_item(1, 16, [ONE; 4]).is_err());
+        assert!(storage.set_array_item(2, 0, [ONE; 4]).is_err());
+
+        // dropping the contents of an array does not affect the storage root
+        let root = storage.commit();
+        storage.retain_arrays(|index| index != 1);
+        assert!(storage.arrays().is_empty());
+        assert!(storage.get_array_item(1, 4).is_err());
+        assert_eq!(storage.root(), root);
+
+        // array root must match the slot value
+        let mismatched_item = SlotItem {
+            index: 1,
+            slot: StorageSlot::new_array([ONE; 4], 4),
+        };
+        assert!(AccountStorage::with_arrays(
+            vec![mismatched_item],
+            BTreeMap::from([(1, array.clone())])
+        )
+        .is_err());
+
+        // array depth must match the slot type
+        let mismatched_item = SlotItem {
+            index: 1,
+            slot: StorageSlot::new_array(*array.root(), 5),
+        };
+        assert!(AccountStorage::with_arrays(vec![mismatched_item], BTreeMap::from([(1, array)]))
+            .is_err());
+    }
+
+    #[test]
+    fn test_verify_storage_item() {
+        let value = [ONE, ZERO, ONE, ZERO];
+        let storage = AccountStorage::new(vec![SlotItem {
+            index: 2,
+            slot: StorageSlot::new_value(value),
+        }])
+        .unwrap();
+        let root = storage.root();
+        let path = storage.slots().open(&LeafIndex::new(2).unwrap()).path;
+
+        assert!(verify_storage_item(root, 2, value, &path));
+        assert!(!verify_storage_item(root, 2, [ONE; 4], &path));
+        assert!(!verify_storage_item(root, 3, value, &path));
+
+        // a path to an inner node of the tree is not a valid opening of a slot
+        let inner_path = MerklePath::new(path[1..].to_vec());
+        let inner_node = storage.get_node(STORAGE_TREE_DEPTH - 1, 1);
+        assert!(inner_path.verify(1, inner_node, &root));
+        assert!(!verify_storage_item(root, 1, *inner_node, &inner_path));
+    }
 }
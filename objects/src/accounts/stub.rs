@@ -1,4 +1,7 @@
 use super::{hash_account, Account, AccountId, Digest, Felt};
+use crate::utils::serde::{
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable,
+};
 
 // ACCOUNT STUB
 // ================================================================================================
@@ -95,3 +98,28 @@ impl From<&Account> for AccountStub {
         }
     }
 }
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for AccountStub {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.id.write_into(target);
+        self.nonce.write_into(target);
+        self.vault_root.write_into(target);
+        self.storage_root.write_into(target);
+        self.code_root.write_into(target);
+    }
+}
+
+impl Deserializable for AccountStub {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let id = AccountId::read_from(source)?;
+        let nonce = Felt::read_from(source)?;
+        let vault_root = Digest::read_from(source)?;
+        let storage_root = Digest::read_from(source)?;
+        let code_root = Digest::read_from(source)?;
+
+        Ok(Self::new(id, nonce, vault_root, storage_root, code_root))
+    }
+}
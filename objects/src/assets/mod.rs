@@ -16,7 +16,10 @@ mod token_symbol;
 pub use token_symbol::TokenSymbol;
 
 mod vault;
-pub use vault::AssetVault;
+pub use vault::{verify_vault_item, AssetVault};
+
+mod partial_vault;
+pub use partial_vault::PartialVault;
 
 // ASSET
 // ================================================================================================
@@ -229,7 +232,10 @@ mod tests {
         Word,
     };
 
-    use super::{Asset, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails};
+    use super::{
+        verify_vault_item, Asset, AssetVault, FungibleAsset, NonFungibleAsset,
+        NonFungibleAssetDetails,
+    };
     use crate::accounts::{
         AccountId, ACCOUNT_ID_FUNGIBLE_FAUCET_OFF_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
         ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2,
@@ -291,4 +297,26 @@ mod tests {
             assert_eq!(non_fungible_asset, Asset::new_unchecked(Word::from(non_fungible_asset)));
         }
     }
+
+    #[test]
+    fn test_verify_vault_item() {
+        let fungible_faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let fungible_asset: Asset = FungibleAsset::new(fungible_faucet_id, 10).unwrap().into();
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let details = NonFungibleAssetDetails::new(faucet_id, vec![1, 2, 3]).unwrap();
+        let non_fungible_asset: Asset = NonFungibleAsset::new(&details).unwrap().into();
+
+        let vault = AssetVault::new(&[fungible_asset, non_fungible_asset]).unwrap();
+        let root = vault.commitment();
+        for asset in [fungible_asset, non_fungible_asset] {
+            let proof = vault.asset_tree().open(&asset.vault_key().into());
+            assert!(verify_vault_item(root, asset, &proof));
+        }
+
+        // the proof of a fungible asset shows the exact balance of the vault
+        let proof = vault.asset_tree().open(&fungible_asset.vault_key().into());
+        let other_amount = FungibleAsset::new(fungible_faucet_id, 5).unwrap().into();
+        assert!(!verify_vault_item(root, other_amount, &proof));
+        assert!(!verify_vault_item(AssetVault::default().commitment(), fungible_asset, &proof));
+    }
 }
@@ -0,0 +1,392 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use super::{AccountId, AccountType, Asset, AssetVault, FungibleAsset, Word, ZERO};
+use crate::{
+    accounts::AccountVaultDelta,
+    crypto::merkle::{
+        InnerNodeInfo, LeafIndex, PartialMerkleTree, Smt, SmtLeaf, SmtProof, SMT_DEPTH,
+    },
+    AssetVaultError, Digest,
+};
+
+// PARTIAL VAULT
+// ================================================================================================
+
+/// A partial view of an [AssetVault] which tracks only a subset of the assets of the vault.
+///
+/// A partial vault consists of the root of the vault and openings of the Sparse Merkle tree leaves
+/// in which the tracked assets are stored (the tracked leaves may be empty). This is sufficient
+/// for executing a transaction which touches only the tracked assets, and thus an account with a
+/// large vault (e.g., a faucet issuing many non-fungible assets) can execute transactions without
+/// materializing its entire vault.
+///
+/// Assets are tracked by their vault key (see [Asset::vault_key()]). Reading or updating an asset
+/// which is not tracked results in an error.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PartialVault {
+    root: Digest,
+    partial_tree: PartialMerkleTree,
+    leaves: BTreeMap<u64, SmtLeaf>,
+}
+
+impl PartialVault {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [PartialVault] of the vault with the specified root, which tracks the assets
+    /// stored in the leaves opened by the provided proofs.
+    ///
+    /// # Errors
+    /// Returns an error if any of the proofs does not resolve to the specified root.
+    pub fn new<T>(root: Digest, openings: T) -> Result<Self, AssetVaultError>
+    where
+        T: IntoIterator<Item = SmtProof>,
+    {
+        let mut vault = Self {
+            root,
+            partial_tree: PartialMerkleTree::new(),
+            leaves: BTreeMap::new(),
+        };
+
+        for opening in openings {
+            let opening_root = opening.compute_root();
+            if opening_root != root {
+                return Err(AssetVaultError::InconsistentVaultOpening {
+                    expected: root,
+                    actual: opening_root,
+                });
+            }
+
+            let (path, leaf) = opening.into_parts();
+            let leaf_index = leaf.index().value();
+            vault
+                .partial_tree
+                .add_path(leaf_index, leaf.hash(), path)
+                .map_err(AssetVaultError::InvalidVaultOpening)?;
+            vault.leaves.insert(leaf_index, leaf);
+        }
+
+        Ok(vault)
+    }
+
+    /// Returns a new [PartialVault] of the provided vault which tracks the assets with the
+    /// specified vault keys.
+    pub fn from_vault<T>(vault: &AssetVault, vault_keys: T) -> Self
+    where
+        T: IntoIterator<Item = Word>,
+    {
+        let openings = vault_keys.into_iter().map(|key| vault.asset_tree().open(&key.into()));
+        Self::new(vault.commitment(), openings).expect("openings resolve to the vault root")
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a commitment to the underlying vault.
+    pub fn commitment(&self) -> Digest {
+        self.root
+    }
+
+    /// Returns true if the asset with the specified vault key is tracked by this partial vault.
+    pub fn is_tracked(&self, vault_key: Word) -> bool {
+        self.leaves.contains_key(&leaf_index(vault_key))
+    }
+
+    /// Returns true if the specified non-fungible asset is stored in the vault.
+    ///
+    /// # Errors
+    /// Returns an error if the asset is not a non-fungible asset or is not tracked.
+    pub fn has_non_fungible_asset(&self, asset: Asset) -> Result<bool, AssetVaultError> {
+        if asset.is_fungible() {
+            return Err(AssetVaultError::NotANonFungibleAsset(asset));
+        }
+
+        Ok(self.get_value(asset.vault_key())? != Smt::EMPTY_VALUE)
+    }
+
+    /// Returns the balance of the asset issued by the specified faucet. If the vault does not
+    /// contain such an asset, 0 is returned.
+    ///
+    /// # Errors
+    /// Returns an error if the specified ID is not an ID of a fungible asset faucet, or if the
+    /// asset issued by the faucet is not tracked.
+    pub fn get_balance(&self, faucet_id: AccountId) -> Result<u64, AssetVaultError> {
+        if !matches!(faucet_id.account_type(), AccountType::FungibleFaucet) {
+            return Err(AssetVaultError::NotAFungibleFaucetId(faucet_id));
+        }
+
+        match self.get_value([ZERO, ZERO, ZERO, faucet_id.into()])? {
+            asset if asset == Smt::EMPTY_VALUE => Ok(0),
+            asset => Ok(FungibleAsset::new_unchecked(asset).amount()),
+        }
+    }
+
+    /// Returns an iterator over the tracked assets stored in the vault.
+    pub fn assets(&self) -> impl Iterator<Item = Asset> + '_ {
+        self.leaves
+            .values()
+            .flat_map(|leaf| leaf.entries())
+            .map(|(_, asset)| Asset::new_unchecked(*asset))
+    }
+
+    /// Returns an iterator over the inner nodes of the Merkle paths of the tracked leaves.
+    pub fn inner_nodes(&self) -> impl Iterator<Item = InnerNodeInfo> + '_ {
+        self.partial_tree.inner_nodes()
+    }
+
+    /// Returns an iterator over the tracked leaves of the Sparse Merkle tree underlying the vault.
+    pub fn leaves(&self) -> impl Iterator<Item = &SmtLeaf> {
+        self.leaves.values()
+    }
+
+    /// Returns an iterator over the openings of the tracked leaves against the root of the vault.
+    ///
+    /// A partial vault can be reconstructed from its commitment and these openings via
+    /// [PartialVault::new()].
+    pub fn openings(&self) -> impl Iterator<Item = SmtProof> + '_ {
+        self.leaves.values().map(|leaf| {
+            let path = self
+                .partial_tree
+                .get_path(leaf.index().into())
+                .expect("leaf is tracked by the partial tree");
+            SmtProof::new(path, leaf.clone()).expect("path has the depth of the vault tree")
+        })
+    }
+
+    // PUBLIC MODIFIERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Applies the provided vault delta to this partial vault.
+    ///
+    /// # Errors
+    /// Returns an error if any of the assets in the delta is not tracked, or if the delta cannot
+    /// be applied to the tracked assets.
+    pub fn apply_delta(&mut self, delta: &AccountVaultDelta) -> Result<(), AssetVaultError> {
+        for &asset in delta.added_assets.iter() {
+            self.add_asset(asset)?;
+        }
+
+        for &asset in delta.removed_assets.iter() {
+            self.remove_asset(asset)?;
+        }
+
+        Ok(())
+    }
+
+    /// Add the specified asset to the vault.
+    ///
+    /// # Errors
+    /// - If the asset is not tracked.
+    /// - If the total value of two fungible assets is greater than or equal to 2^63.
+    /// - If the vault already contains the same non-fungible asset.
+    pub fn add_asset(&mut self, asset: Asset) -> Result<Asset, AssetVaultError> {
+        let current = self.get_value(asset.vault_key())?;
+        let new = match asset {
+            Asset::Fungible(asset) if current == Smt::EMPTY_VALUE => Asset::Fungible(asset),
+            Asset::Fungible(asset) => Asset::Fungible(
+                FungibleAsset::new_unchecked(current)
+                    .add(asset)
+                    .map_err(AssetVaultError::AddFungibleAssetBalanceError)?,
+            ),
+            Asset::NonFungible(asset) if current == Smt::EMPTY_VALUE => Asset::NonFungible(asset),
+            Asset::NonFungible(asset) => {
+                return Err(AssetVaultError::DuplicateNonFungibleAsset(asset))
+            },
+        };
+        self.set_value(new.vault_key(), new.into());
+
+        Ok(new)
+    }
+
+    /// Remove the specified asset from the vault.
+    ///
+    /// # Errors
+    /// - The asset is not tracked.
+    /// - The asset is not found in the vault.
+    /// - The amount of the fungible asset in the vault is less than the amount to be removed.
+    pub fn remove_asset(&mut self, asset: Asset) -> Result<Asset, AssetVaultError> {
+        let current = self.get_value(asset.vault_key())?;
+        let new = match asset {
+            Asset::Fungible(asset) if current == Smt::EMPTY_VALUE => {
+                return Err(AssetVaultError::FungibleAssetNotFound(asset))
+            },
+            Asset::Fungible(asset) => {
+                let mut current = FungibleAsset::new_unchecked(current);
+                current
+                    .sub(asset.amount())
+                    .map_err(AssetVaultError::SubtractFungibleAssetBalanceError)?;
+                match current.amount() {
+                    0 => Smt::EMPTY_VALUE,
+                    _ => current.into(),
+                }
+            },
+            Asset::NonFungible(asset) if current == Smt::EMPTY_VALUE => {
+                return Err(AssetVaultError::NonFungibleAssetNotFound(asset))
+            },
+            Asset::NonFungible(_) => Smt::EMPTY_VALUE,
+        };
+        self.set_value(asset.vault_key(), new);
+
+        Ok(asset)
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the value stored under the specified vault key.
+    ///
+    /// # Errors
+    /// Returns an error if the key is not tracked.
+    fn get_value(&self, vault_key: Word) -> Result<Word, AssetVaultError> {
+        let key: Digest = vault_key.into();
+        let leaf = self
+            .leaves
+            .get(&leaf_index(vault_key))
+            .ok_or(AssetVaultError::AssetNotTracked(key))?;
+
+        Ok(leaf
+            .entries()
+            .into_iter()
+            .find(|(entry_key, _)| *entry_key == key)
+            .map_or(Smt::EMPTY_VALUE, |(_, value)| *value))
+    }
+
+    /// Sets the value stored under the specified tracked vault key and updates the root of the
+    /// vault.
+    fn set_value(&mut self, vault_key: Word, value: Word) {
+        let key: Digest = vault_key.into();
+        let leaf_index = leaf_index(vault_key);
+        let leaf = self.leaves.get_mut(&leaf_index).expect("vault key is tracked");
+
+        let mut entries: Vec<(Digest, Word)> = leaf
+            .entries()
+            .into_iter()
+            .copied()
+            .filter(|(entry_key, _)| *entry_key != key)
+            .collect();
+        if value != Smt::EMPTY_VALUE {
+            entries.push((key, value));
+        }
+        // the entries of a leaf are sorted by key, starting with the most significant element
+        entries.sort_by_key(|(key, _)| {
+            [key[3].as_int(), key[2].as_int(), key[1].as_int(), key[0].as_int()]
+        });
+        *leaf = SmtLeaf::new(entries, leaf.index()).expect("all keys map to the same leaf");
+
+        self.partial_tree
+            .update_leaf(leaf_index, leaf.hash().into())
+            .expect("leaf is tracked by the partial tree");
+        self.root = self.partial_tree.root();
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Returns the index of the Sparse Merkle tree leaf in which the asset with the specified vault
+/// key is stored.
+fn leaf_index(vault_key: Word) -> u64 {
+    LeafIndex::<SMT_DEPTH>::from(vault_key).value()
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{PartialVault, Smt};
+    use crate::{
+        accounts::{
+            AccountId, AccountVaultDelta, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1, ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
+        },
+        assets::{Asset, AssetVault, FungibleAsset, NonFungibleAsset, NonFungibleAssetDetails},
+        AssetVaultError, Digest,
+    };
+
+    fn fungible_asset(faucet_id: u64, amount: u64) -> Asset {
+        FungibleAsset::new(faucet_id.try_into().unwrap(), amount).unwrap().into()
+    }
+
+    fn non_fungible_asset(data: u8) -> Asset {
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let details = NonFungibleAssetDetails::new(faucet_id, vec![data]).unwrap();
+        NonFungibleAsset::new(&details).unwrap().into()
+    }
+
+    #[test]
+    fn partial_vault_tracks_vault_updates() {
+        let fungible = fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, 100);
+        let untracked = fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1, 50);
+        let non_fungible = non_fungible_asset(1);
+        let new_non_fungible = non_fungible_asset(2);
+
+        let mut vault = AssetVault::new(&[fungible, untracked, non_fungible]).unwrap();
+        let mut partial_vault = PartialVault::from_vault(
+            &vault,
+            [fungible.vault_key(), non_fungible.vault_key(), new_non_fungible.vault_key()],
+        );
+        assert_eq!(partial_vault.commitment(), vault.commitment());
+        assert_eq!(partial_vault.assets().count(), 2);
+        assert!(!partial_vault.is_tracked(untracked.vault_key()));
+
+        let delta = AccountVaultDelta {
+            added_assets: vec![
+                fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, 20),
+                new_non_fungible,
+            ],
+            removed_assets: vec![non_fungible],
+        };
+        partial_vault.apply_delta(&delta).unwrap();
+        for &asset in delta.added_assets.iter() {
+            vault.add_asset(asset).unwrap();
+        }
+        for &asset in delta.removed_assets.iter() {
+            vault.remove_asset(asset).unwrap();
+        }
+
+        assert_eq!(partial_vault.commitment(), vault.commitment());
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        assert_eq!(partial_vault.get_balance(faucet_id), Ok(120));
+        assert_eq!(vault.get_balance(faucet_id), Ok(120));
+        assert_eq!(partial_vault.has_non_fungible_asset(non_fungible), Ok(false));
+        assert_eq!(partial_vault.has_non_fungible_asset(new_non_fungible), Ok(true));
+
+        // the updated partial vault can be reconstructed from its openings
+        let reconstructed =
+            PartialVault::new(partial_vault.commitment(), partial_vault.openings()).unwrap();
+        assert_eq!(reconstructed, partial_vault);
+    }
+
+    #[test]
+    fn partial_vault_rejects_untracked_assets() {
+        let fungible = fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, 100);
+        let untracked = fungible_asset(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1, 50);
+
+        let vault = AssetVault::new(&[fungible, untracked]).unwrap();
+        let mut partial_vault = PartialVault::from_vault(&vault, [fungible.vault_key()]);
+
+        let untracked_key = Digest::from(untracked.vault_key());
+        assert_eq!(
+            partial_vault
+                .get_balance(AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1).unwrap()),
+            Err(AssetVaultError::AssetNotTracked(untracked_key))
+        );
+        assert_eq!(
+            partial_vault.add_asset(untracked),
+            Err(AssetVaultError::AssetNotTracked(untracked_key))
+        );
+        assert_eq!(partial_vault.commitment(), vault.commitment());
+
+        // openings must resolve to the root of the vault
+        let opening = vault.asset_tree().open(&untracked_key);
+        let other_root = Smt::new().root();
+        assert_eq!(
+            PartialVault::new(other_root, [opening]),
+            Err(AssetVaultError::InconsistentVaultOpening {
+                expected: other_root,
+                actual: vault.commitment(),
+            })
+        );
+    }
+}
@@ -4,7 +4,10 @@ use super::{
     AccountId, AccountType, Asset, ByteReader, ByteWriter, Deserializable, DeserializationError,
     FungibleAsset, NonFungibleAsset, Serializable, ZERO,
 };
-use crate::{crypto::merkle::Smt, AssetVaultError, Digest};
+use crate::{
+    crypto::merkle::{Smt, SmtProof},
+    AssetVaultError, Digest,
+};
 
 // ASSET VAULT
 // ================================================================================================
@@ -220,6 +223,22 @@ impl AssetVault {
     }
 }
 
+// VAULT PROOFS
+// ================================================================================================
+
+/// Returns true if the provided proof shows that the vault with the specified root contains the
+/// specified asset.
+///
+/// The asset is looked up in the vault tree in the same way as in the transaction kernel, i.e., the
+/// key of the asset is its vault key (see [Asset::vault_key()]) and the value is the asset itself.
+/// Thus, for a fungible asset, the proof shows that the vault holds exactly the amount of the
+/// asset, rather than at least that amount.
+///
+/// This allows verifying claims about the vault of an account without instantiating the vault.
+pub fn verify_vault_item(vault_root: Digest, asset: Asset, proof: &SmtProof) -> bool {
+    proof.verify_membership(&asset.vault_key().into(), &asset.into(), &vault_root)
+}
+
 // SERIALIZATION
 // ================================================================================================
 
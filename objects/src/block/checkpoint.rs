@@ -0,0 +1,288 @@
+use alloc::string::ToString;
+
+use super::{BlockHeader, Digest};
+use crate::{
+    crypto::merkle::{MmrDelta, MmrPeaks, PartialMmr},
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    CheckpointError,
+};
+
+// CHECKPOINT
+// ================================================================================================
+
+/// A commitment to the state of the chain at a given block which can be used by light clients to
+/// sync with the chain without downloading all block headers.
+///
+/// A checkpoint includes the following fields:
+///
+/// - block_num is the number of the block at which the checkpoint was taken.
+/// - block_hash is the hash of the header of the checkpointed block.
+/// - chain_peaks are the peaks of the chain MMR committed to by the checkpointed block. The MMR
+///   contains all blocks preceding the checkpointed block (i.e., blocks 0 to block_num - 1).
+/// - account_root is a commitment to the account database at the checkpointed block.
+/// - nullifier_root is a commitment to the nullifier database at the checkpointed block.
+///
+/// Given an earlier checkpoint, a later block header can be verified against it using an
+/// [MmrDelta] which describes how the chain MMR evolved between the two blocks (see
+/// [Checkpoint::advance()]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Checkpoint {
+    block_num: u32,
+    block_hash: Digest,
+    chain_peaks: MmrPeaks,
+    account_root: Digest,
+    nullifier_root: Digest,
+}
+
+impl Checkpoint {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [Checkpoint] for the provided block header and chain MMR peaks.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The number of leaves in the provided MMR peaks is not equal to the block number of the
+    ///   provided header.
+    /// - The provided MMR peaks do not hash to the chain root of the provided header.
+    pub fn new(header: &BlockHeader, chain_peaks: MmrPeaks) -> Result<Self, CheckpointError> {
+        validate_chain_peaks(header, &chain_peaks)?;
+
+        Ok(Self {
+            block_num: header.block_num(),
+            block_hash: header.hash(),
+            chain_peaks,
+            account_root: header.account_root(),
+            nullifier_root: header.nullifier_root(),
+        })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of the block at which this checkpoint was taken.
+    pub fn block_num(&self) -> u32 {
+        self.block_num
+    }
+
+    /// Returns the hash of the header of the checkpointed block.
+    pub fn block_hash(&self) -> Digest {
+        self.block_hash
+    }
+
+    /// Returns the peaks of the chain MMR committed to by the checkpointed block.
+    pub fn chain_peaks(&self) -> &MmrPeaks {
+        &self.chain_peaks
+    }
+
+    /// Returns the commitment to the chain MMR committed to by the checkpointed block.
+    pub fn chain_root(&self) -> Digest {
+        self.chain_peaks.hash_peaks()
+    }
+
+    /// Returns the account database root at the checkpointed block.
+    pub fn account_root(&self) -> Digest {
+        self.account_root
+    }
+
+    /// Returns the nullifier database root at the checkpointed block.
+    pub fn nullifier_root(&self) -> Digest {
+        self.nullifier_root
+    }
+
+    // VERIFICATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Verifies the provided header of a later block against this checkpoint and returns a new
+    /// checkpoint for that block.
+    ///
+    /// The `delta` must describe the update of the chain MMR from `self.block_num() + 1` leaves
+    /// (i.e., the MMR which includes the checkpointed block) to `header.block_num()` leaves. Such
+    /// a delta can be obtained via `Mmr::get_delta()`. Because the checkpointed block is appended
+    /// to the MMR before the delta is applied, a successful verification also attests to the fact
+    /// that the checkpointed block is a part of the chain leading up to the provided header.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The block number of the provided header is not greater than the block number of this
+    ///   checkpoint.
+    /// - The provided delta cannot be applied to the chain MMR of this checkpoint.
+    /// - The chain MMR resulting from applying the delta is inconsistent with the provided header.
+    pub fn advance(
+        &self,
+        header: &BlockHeader,
+        delta: MmrDelta,
+    ) -> Result<Checkpoint, CheckpointError> {
+        if header.block_num() <= self.block_num {
+            return Err(CheckpointError::BlockNumNotIncreasing {
+                checkpoint: self.block_num,
+                header: header.block_num(),
+            });
+        }
+
+        let mut mmr = PartialMmr::from_peaks(self.chain_peaks.clone());
+        mmr.add(self.block_hash, false);
+        mmr.apply(delta)
+            .map_err(|err| CheckpointError::InvalidMmrDelta(err.to_string()))?;
+
+        Self::new(header, mmr.peaks())
+    }
+
+    /// Verifies the provided header of a later block against this checkpoint.
+    ///
+    /// This is equivalent to [Checkpoint::advance()] but discards the resulting checkpoint.
+    pub fn verify_header(
+        &self,
+        header: &BlockHeader,
+        delta: MmrDelta,
+    ) -> Result<(), CheckpointError> {
+        self.advance(header, delta).map(|_| ())
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Makes sure the provided MMR peaks are consistent with the chain root of the provided header.
+fn validate_chain_peaks(header: &BlockHeader, peaks: &MmrPeaks) -> Result<(), CheckpointError> {
+    if peaks.num_leaves() != header.block_num() as usize {
+        return Err(CheckpointError::ChainLengthMismatch {
+            expected: header.block_num(),
+            actual: peaks.num_leaves(),
+        });
+    }
+
+    if peaks.hash_peaks() != header.chain_root() {
+        return Err(CheckpointError::ChainRootMismatch {
+            expected: header.chain_root(),
+            actual: peaks.hash_peaks(),
+        });
+    }
+
+    Ok(())
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for Checkpoint {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.block_num.write_into(target);
+        self.block_hash.write_into(target);
+        target.write_u64(self.chain_peaks.num_leaves() as u64);
+        target.write_u8(self.chain_peaks.num_peaks() as u8);
+        target.write_many(self.chain_peaks.peaks());
+        self.account_root.write_into(target);
+        self.nullifier_root.write_into(target);
+    }
+}
+
+impl Deserializable for Checkpoint {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let block_num = source.read()?;
+        let block_hash = source.read()?;
+        let num_leaves = source.read_u64()? as usize;
+        let num_peaks = source.read_u8()? as usize;
+        let peaks = source.read_many::<Digest>(num_peaks)?;
+        let chain_peaks = MmrPeaks::new(num_leaves, peaks)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+        let account_root = source.read()?;
+        let nullifier_root = source.read()?;
+
+        Ok(Self {
+            block_num,
+            block_hash,
+            chain_peaks,
+            account_root,
+            nullifier_root,
+        })
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::Checkpoint;
+    use crate::{
+        crypto::merkle::Mmr,
+        utils::serde::{Deserializable, Serializable},
+        BlockHeader, CheckpointError, Felt, ZERO,
+    };
+
+    /// Builds a chain of `num_blocks` headers, where each header commits to the MMR of all the
+    /// preceding headers, and returns the headers together with the full MMR.
+    fn build_chain(num_blocks: u32) -> (Vec<BlockHeader>, Mmr) {
+        let mut mmr = Mmr::default();
+        let mut headers = Vec::new();
+        for block_num in 0..num_blocks {
+            let header = BlockHeader::builder()
+                .block_num(block_num)
+                .chain_root(mmr.peaks(mmr.forest()).unwrap().hash_peaks())
+                .account_root([Felt::from(block_num), ZERO, ZERO, ZERO].into())
+                .build();
+            mmr.add(header.hash());
+            headers.push(header);
+        }
+        (headers, mmr)
+    }
+
+    #[test]
+    fn test_checkpoint_new() {
+        let (headers, mmr) = build_chain(6);
+
+        let checkpoint = Checkpoint::new(&headers[4], mmr.peaks(4).unwrap()).unwrap();
+        assert_eq!(checkpoint.block_num(), 4);
+        assert_eq!(checkpoint.block_hash(), headers[4].hash());
+        assert_eq!(checkpoint.chain_root(), headers[4].chain_root());
+        assert_eq!(checkpoint.account_root(), headers[4].account_root());
+
+        // peaks of a different chain length are rejected
+        let result = Checkpoint::new(&headers[4], mmr.peaks(3).unwrap());
+        assert!(matches!(result, Err(CheckpointError::ChainLengthMismatch { .. })));
+    }
+
+    #[test]
+    fn test_checkpoint_advance() {
+        let (headers, mmr) = build_chain(12);
+
+        for (from, to) in [(0, 1), (2, 3), (3, 7), (5, 11), (7, 8)] {
+            let checkpoint = Checkpoint::new(&headers[from], mmr.peaks(from).unwrap()).unwrap();
+            let delta = mmr.get_delta(from + 1, to).unwrap();
+            let next = checkpoint.advance(&headers[to], delta).unwrap();
+            assert_eq!(next, Checkpoint::new(&headers[to], mmr.peaks(to).unwrap()).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_checkpoint_advance_invalid() {
+        let (headers, mmr) = build_chain(10);
+        let checkpoint = Checkpoint::new(&headers[3], mmr.peaks(3).unwrap()).unwrap();
+
+        // block number must increase
+        let delta = mmr.get_delta(3, 3).unwrap();
+        let result = checkpoint.verify_header(&headers[2], delta);
+        assert!(matches!(result, Err(CheckpointError::BlockNumNotIncreasing { .. })));
+
+        // delta must start from the MMR which includes the checkpointed block
+        let delta = mmr.get_delta(3, 9).unwrap();
+        assert!(checkpoint.verify_header(&headers[9], delta).is_err());
+
+        // delta must lead to the MMR committed to by the header
+        let delta = mmr.get_delta(4, 8).unwrap();
+        let result = checkpoint.verify_header(&headers[9], delta);
+        assert!(matches!(result, Err(CheckpointError::ChainLengthMismatch { .. })));
+    }
+
+    #[test]
+    fn test_checkpoint_serde() {
+        let (headers, mmr) = build_chain(7);
+        let checkpoint = Checkpoint::new(&headers[6], mmr.peaks(6).unwrap()).unwrap();
+
+        let bytes = checkpoint.to_bytes();
+        assert_eq!(checkpoint, Checkpoint::read_from_bytes(&bytes).unwrap());
+    }
+}
@@ -1,10 +1,15 @@
 use alloc::vec::Vec;
 
-use super::{Digest, Felt, Hasher, ZERO};
-use crate::utils::serde::{
-    ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable,
+use super::{BlockHeaderField, BlockHeaderOpening, Digest, Felt, Hasher, ZERO};
+use crate::{
+    crypto::merkle::{MmrPeaks, PartialMmr},
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    BlockHeaderError,
 };
 
+// BLOCK HEADER
+// ================================================================================================
+
 /// The header of a block. It contains metadata about the block, commitments to the current
 /// state of the chain and the hash of the proof that attests to the integrity of the chain.
 ///
@@ -20,6 +25,8 @@ use crate::utils::serde::{
 /// - proof_hash is a hash of a STARK proof attesting to the correct state transition.
 /// - version specifies the version of the protocol.
 /// - timestamp is the time when the block was created.
+/// - kernel_config_hash is a commitment to the [KernelConfig](super::KernelConfig) of the
+///   transaction kernel which must be used to execute transactions against this block.
 /// - sub_hash is a sequential hash of all fields except the note_root.
 /// - hash is a 2-to-1 hash of the sub_hash and the note_root.
 #[derive(Debug, Eq, PartialEq, Copy, Clone)]
@@ -35,11 +42,15 @@ pub struct BlockHeader {
     proof_hash: Digest,
     version: Felt,
     timestamp: Felt,
+    kernel_config_hash: Digest,
     sub_hash: Digest,
     hash: Digest,
 }
 
 impl BlockHeader {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
     /// Creates a new block header.
     #[allow(clippy::too_many_arguments)]
     pub fn new(
@@ -53,6 +64,7 @@ impl BlockHeader {
         proof_hash: Digest,
         version: Felt,
         timestamp: Felt,
+        kernel_config_hash: Digest,
     ) -> Self {
         // compute block sub hash
         let sub_hash = Self::compute_sub_hash(
@@ -65,6 +77,7 @@ impl BlockHeader {
             version,
             timestamp,
             block_num,
+            kernel_config_hash,
         );
 
         // The sub hash is merged with the note_root - hash(sub_hash, note_root) to produce the final
@@ -83,11 +96,19 @@ impl BlockHeader {
             proof_hash,
             version,
             timestamp,
+            kernel_config_hash,
             sub_hash,
             hash,
         }
     }
 
+    /// Returns a [BlockHeaderBuilder] used to build a [BlockHeader].
+    ///
+    /// All fields of the builder are initialized to zero.
+    pub fn builder() -> BlockHeaderBuilder {
+        BlockHeaderBuilder::default()
+    }
+
     // ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -154,6 +175,90 @@ impl BlockHeader {
         self.timestamp
     }
 
+    /// Returns the commitment to the config of the transaction kernel which must be used to
+    /// execute transactions against this block.
+    pub fn kernel_config_hash(&self) -> Digest {
+        self.kernel_config_hash
+    }
+
+    /// Returns an opening of the specified field of this header, which proves the value of the
+    /// field against the hash of this header without revealing the entire header (see
+    /// [BlockHeaderOpening]).
+    pub fn open(&self, field: BlockHeaderField) -> BlockHeaderOpening {
+        BlockHeaderOpening::new(self, field)
+    }
+
+    // VALIDATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Validates this header as the direct successor of the provided header.
+    ///
+    /// A header cannot be used to reconstruct the chain MMR it commits to, and thus the peaks of
+    /// the chain MMR committed to by `prev` (i.e., the MMR of blocks 0 to `prev.block_num() - 1`)
+    /// must be provided as well. The chain root of this header is expected to commit to the MMR
+    /// obtained by appending `prev` to these peaks.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The block number of this header does not directly follow the block number of `prev`.
+    /// - The previous hash of this header is not the hash of `prev`.
+    /// - The provided peaks are inconsistent with the chain root of `prev`.
+    /// - The chain root of this header is not the result of appending `prev` to the chain MMR.
+    /// - The timestamp of this header is smaller than the timestamp of `prev`.
+    pub fn validate_against(
+        &self,
+        prev: &BlockHeader,
+        prev_chain_peaks: &MmrPeaks,
+    ) -> Result<(), BlockHeaderError> {
+        let expected_block_num = prev.block_num + 1;
+        if self.block_num != expected_block_num {
+            return Err(BlockHeaderError::BlockNumNotSequential {
+                expected: expected_block_num,
+                actual: self.block_num,
+            });
+        }
+
+        if self.prev_hash != prev.hash {
+            return Err(BlockHeaderError::PrevHashMismatch {
+                expected: prev.hash,
+                actual: self.prev_hash,
+            });
+        }
+
+        if prev_chain_peaks.num_leaves() != prev.block_num as usize {
+            return Err(BlockHeaderError::ChainLengthMismatch {
+                expected: prev.block_num,
+                actual: prev_chain_peaks.num_leaves(),
+            });
+        }
+
+        if prev_chain_peaks.hash_peaks() != prev.chain_root {
+            return Err(BlockHeaderError::ChainRootMismatch {
+                expected: prev.chain_root,
+                actual: prev_chain_peaks.hash_peaks(),
+            });
+        }
+
+        let mut chain_mmr = PartialMmr::from_peaks(prev_chain_peaks.clone());
+        chain_mmr.add(prev.hash, false);
+        let chain_root = chain_mmr.peaks().hash_peaks();
+        if self.chain_root != chain_root {
+            return Err(BlockHeaderError::ChainRootMismatch {
+                expected: chain_root,
+                actual: self.chain_root,
+            });
+        }
+
+        if self.timestamp.as_int() < prev.timestamp.as_int() {
+            return Err(BlockHeaderError::TimestampDecreasing {
+                prev: prev.timestamp.as_int(),
+                actual: self.timestamp.as_int(),
+            });
+        }
+
+        Ok(())
+    }
+
     // HELPERS
     // --------------------------------------------------------------------------------------------
 
@@ -161,7 +266,7 @@ impl BlockHeader {
     ///
     /// The sub hash is computed as a sequential hash of the following fields:
     /// prev_hash, chain_root, account_root, nullifier_root, note_root, batch_root, proof_hash,
-    /// version, timestamp, block_num (all fields except the note_root).
+    /// version, timestamp, block_num, kernel_config_hash (all fields except the note_root).
     #[allow(clippy::too_many_arguments)]
     fn compute_sub_hash(
         prev_hash: Digest,
@@ -173,6 +278,7 @@ impl BlockHeader {
         version: Felt,
         timestamp: Felt,
         block_num: u32,
+        kernel_config_hash: Digest,
     ) -> Digest {
         let mut elements: Vec<Felt> = Vec::with_capacity(32);
         elements.extend_from_slice(prev_hash.as_elements());
@@ -182,11 +288,125 @@ impl BlockHeader {
         elements.extend_from_slice(batch_root.as_elements());
         elements.extend_from_slice(proof_hash.as_elements());
         elements.extend([block_num.into(), version, timestamp, ZERO]);
-        elements.resize(32, ZERO);
+        elements.extend_from_slice(kernel_config_hash.as_elements());
         Hasher::hash_elements(&elements)
     }
 }
 
+// BLOCK HEADER BUILDER
+// ================================================================================================
+
+/// Builder for a [BlockHeader].
+///
+/// Fields which are not set explicitly are set to zero. The sub hash and the hash of the header
+/// are computed by [BlockHeaderBuilder::build()].
+#[derive(Debug, Default, Clone, Copy)]
+pub struct BlockHeaderBuilder {
+    prev_hash: Digest,
+    block_num: u32,
+    chain_root: Digest,
+    account_root: Digest,
+    nullifier_root: Digest,
+    note_root: Digest,
+    batch_root: Digest,
+    proof_hash: Digest,
+    version: Felt,
+    timestamp: Felt,
+    kernel_config_hash: Digest,
+}
+
+impl BlockHeaderBuilder {
+    // MODIFIERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Sets the hash of the previous block header.
+    pub fn prev_hash(mut self, prev_hash: Digest) -> Self {
+        self.prev_hash = prev_hash;
+        self
+    }
+
+    /// Sets the block number.
+    pub fn block_num(mut self, block_num: u32) -> Self {
+        self.block_num = block_num;
+        self
+    }
+
+    /// Sets the chain root.
+    pub fn chain_root(mut self, chain_root: Digest) -> Self {
+        self.chain_root = chain_root;
+        self
+    }
+
+    /// Sets the account database root.
+    pub fn account_root(mut self, account_root: Digest) -> Self {
+        self.account_root = account_root;
+        self
+    }
+
+    /// Sets the nullifier database root.
+    pub fn nullifier_root(mut self, nullifier_root: Digest) -> Self {
+        self.nullifier_root = nullifier_root;
+        self
+    }
+
+    /// Sets the note root.
+    pub fn note_root(mut self, note_root: Digest) -> Self {
+        self.note_root = note_root;
+        self
+    }
+
+    /// Sets the batch root.
+    pub fn batch_root(mut self, batch_root: Digest) -> Self {
+        self.batch_root = batch_root;
+        self
+    }
+
+    /// Sets the proof hash.
+    pub fn proof_hash(mut self, proof_hash: Digest) -> Self {
+        self.proof_hash = proof_hash;
+        self
+    }
+
+    /// Sets the protocol version.
+    pub fn version(mut self, version: Felt) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Sets the timestamp at which the block was created.
+    pub fn timestamp(mut self, timestamp: Felt) -> Self {
+        self.timestamp = timestamp;
+        self
+    }
+
+    /// Sets the commitment to the config of the transaction kernel which must be used to execute
+    /// transactions against the block.
+    pub fn kernel_config_hash(mut self, kernel_config_hash: Digest) -> Self {
+        self.kernel_config_hash = kernel_config_hash;
+        self
+    }
+
+    // BUILDER
+    // --------------------------------------------------------------------------------------------
+
+    /// Builds the [BlockHeader].
+    pub fn build(self) -> BlockHeader {
+        BlockHeader::new(
+            self.prev_hash,
+            self.block_num,
+            self.chain_root,
+            self.account_root,
+            self.nullifier_root,
+            self.note_root,
+            self.batch_root,
+            self.proof_hash,
+            self.version,
+            self.timestamp,
+            self.kernel_config_hash,
+        )
+    }
+}
+
 #[cfg(feature = "testing")]
 mod mock {
     use alloc::vec::Vec;
@@ -194,7 +414,7 @@ mod mock {
     use winter_rand_utils as rand;
 
     use crate::{
-        accounts::Account, crypto::merkle::SimpleSmt, BlockHeader, Digest, Felt,
+        accounts::Account, crypto::merkle::SimpleSmt, BlockHeader, Digest, Felt, KernelConfig,
         ACCOUNT_TREE_DEPTH, ZERO,
     };
 
@@ -239,11 +459,15 @@ mod mock {
                 proof_hash,
                 ZERO,
                 rand::rand_value(),
+                KernelConfig::default().commitment(),
             )
         }
     }
 }
 
+// SERIALIZATION
+// ================================================================================================
+
 impl Serializable for BlockHeader {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
         let Self {
@@ -257,6 +481,7 @@ impl Serializable for BlockHeader {
             proof_hash,
             version,
             timestamp,
+            kernel_config_hash,
             sub_hash,
             hash,
         } = self;
@@ -271,6 +496,7 @@ impl Serializable for BlockHeader {
         proof_hash.write_into(target);
         version.write_into(target);
         timestamp.write_into(target);
+        kernel_config_hash.write_into(target);
         sub_hash.write_into(target);
         hash.write_into(target);
     }
@@ -278,19 +504,156 @@ impl Serializable for BlockHeader {
 
 impl Deserializable for BlockHeader {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
-        Ok(Self {
-            prev_hash: source.read()?,
-            block_num: source.read()?,
-            chain_root: source.read()?,
-            account_root: source.read()?,
-            nullifier_root: source.read()?,
-            note_root: source.read()?,
-            batch_root: source.read()?,
-            proof_hash: source.read()?,
-            version: source.read()?,
-            timestamp: source.read()?,
-            sub_hash: source.read()?,
-            hash: source.read()?,
-        })
+        let header = Self::new(
+            source.read()?,
+            source.read()?,
+            source.read()?,
+            source.read()?,
+            source.read()?,
+            source.read()?,
+            source.read()?,
+            source.read()?,
+            source.read()?,
+            source.read()?,
+            source.read()?,
+        );
+
+        let sub_hash: Digest = source.read()?;
+        let hash: Digest = source.read()?;
+        if sub_hash != header.sub_hash || hash != header.hash {
+            return Err(DeserializationError::InvalidValue(
+                "block header hash is inconsistent with the header fields".into(),
+            ));
+        }
+
+        Ok(header)
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::BlockHeader;
+    use crate::{
+        crypto::merkle::Mmr,
+        utils::serde::{Deserializable, Serializable},
+        BlockHeaderError, Digest, Felt, ZERO,
+    };
+
+    /// Builds a chain of `num_blocks` headers, where each header commits to the MMR of all the
+    /// preceding headers, and returns the headers together with the full MMR.
+    fn build_chain(num_blocks: u32) -> (Vec<BlockHeader>, Mmr) {
+        let mut mmr = Mmr::default();
+        let mut headers: Vec<BlockHeader> = Vec::new();
+        for block_num in 0..num_blocks {
+            let header = BlockHeader::builder()
+                .prev_hash(headers.last().map_or(Digest::default(), |header| header.hash()))
+                .block_num(block_num)
+                .chain_root(mmr.peaks(mmr.forest()).unwrap().hash_peaks())
+                .account_root([Felt::from(block_num), ZERO, ZERO, ZERO].into())
+                .timestamp(Felt::from(block_num * 10))
+                .build();
+            mmr.add(header.hash());
+            headers.push(header);
+        }
+        (headers, mmr)
+    }
+
+    #[test]
+    fn test_block_header_builder() {
+        let header = BlockHeader::builder()
+            .prev_hash([Felt::from(1_u32), ZERO, ZERO, ZERO].into())
+            .block_num(2)
+            .chain_root([Felt::from(3_u32), ZERO, ZERO, ZERO].into())
+            .account_root([Felt::from(4_u32), ZERO, ZERO, ZERO].into())
+            .nullifier_root([Felt::from(5_u32), ZERO, ZERO, ZERO].into())
+            .note_root([Felt::from(6_u32), ZERO, ZERO, ZERO].into())
+            .batch_root([Felt::from(7_u32), ZERO, ZERO, ZERO].into())
+            .proof_hash([Felt::from(8_u32), ZERO, ZERO, ZERO].into())
+            .version(Felt::from(9_u32))
+            .timestamp(Felt::from(10_u32))
+            .kernel_config_hash([Felt::from(11_u32), ZERO, ZERO, ZERO].into())
+            .build();
+
+        let expected = BlockHeader::new(
+            [Felt::from(1_u32), ZERO, ZERO, ZERO].into(),
+            2,
+            [Felt::from(3_u32), ZERO, ZERO, ZERO].into(),
+            [Felt::from(4_u32), ZERO, ZERO, ZERO].into(),
+            [Felt::from(5_u32), ZERO, ZERO, ZERO].into(),
+            [Felt::from(6_u32), ZERO, ZERO, ZERO].into(),
+            [Felt::from(7_u32), ZERO, ZERO, ZERO].into(),
+            [Felt::from(8_u32), ZERO, ZERO, ZERO].into(),
+            Felt::from(9_u32),
+            Felt::from(10_u32),
+            [Felt::from(11_u32), ZERO, ZERO, ZERO].into(),
+        );
+        assert_eq!(header, expected);
+    }
+
+    #[test]
+    fn test_block_header_validate_against() {
+        let (headers, mmr) = build_chain(8);
+
+        for block_num in 0..7 {
+            let prev_peaks = mmr.peaks(block_num).unwrap();
+            headers[block_num + 1]
+                .validate_against(&headers[block_num], &prev_peaks)
+                .unwrap();
+        }
+    }
+
+    #[test]
+    fn test_block_header_validate_against_invalid() {
+        let (headers, mmr) = build_chain(6);
+        let prev = headers[4];
+        let prev_peaks = mmr.peaks(4).unwrap();
+        let valid = BlockHeader::builder()
+            .prev_hash(prev.hash())
+            .block_num(5)
+            .chain_root(headers[5].chain_root())
+            .timestamp(prev.timestamp());
+        valid.build().validate_against(&prev, &prev_peaks).unwrap();
+
+        // block number must directly follow the previous block number
+        let result = valid.block_num(6).build().validate_against(&prev, &prev_peaks);
+        assert!(matches!(result, Err(BlockHeaderError::BlockNumNotSequential { .. })));
+
+        // previous hash must be the hash of the previous header
+        let result =
+            valid.prev_hash(headers[3].hash()).build().validate_against(&prev, &prev_peaks);
+        assert!(matches!(result, Err(BlockHeaderError::PrevHashMismatch { .. })));
+
+        // provided peaks must be the peaks of the chain MMR of the previous header
+        let result = valid.build().validate_against(&prev, &mmr.peaks(3).unwrap());
+        assert!(matches!(result, Err(BlockHeaderError::ChainLengthMismatch { .. })));
+
+        // chain root must be obtained by appending the previous header to the chain MMR
+        let result =
+            valid.chain_root(prev.chain_root()).build().validate_against(&prev, &prev_peaks);
+        assert!(matches!(result, Err(BlockHeaderError::ChainRootMismatch { .. })));
+
+        // timestamp must not decrease
+        let timestamp = prev.timestamp() - Felt::from(1_u32);
+        let result = valid.timestamp(timestamp).build().validate_against(&prev, &prev_peaks);
+        assert!(matches!(result, Err(BlockHeaderError::TimestampDecreasing { .. })));
+    }
+
+    #[test]
+    fn test_block_header_serde() {
+        let (headers, _) = build_chain(3);
+
+        let bytes = headers[2].to_bytes();
+        assert_eq!(headers[2], BlockHeader::read_from_bytes(&bytes).unwrap());
+
+        // headers with an inconsistent hash are rejected
+        let mut bytes = headers[2].to_bytes();
+        let len = bytes.len();
+        bytes[len - 32] ^= 1;
+        assert!(BlockHeader::read_from_bytes(&bytes).is_err());
     }
 }
@@ -0,0 +1,761 @@
+use alloc::{string::ToString, vec::Vec};
+
+use super::{Digest, Felt, Hasher, ZERO};
+use crate::{
+    accounts::{AccountId, AccountType},
+    crypto::merkle::SimpleSmt,
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    KernelConfigError, Word, ACCEPTED_FEE_ASSETS_TREE_DEPTH, MAX_ACCEPTED_FEE_ASSETS,
+    MAX_ACCOUNT_PROC_CALL_DEPTH, MAX_INPUT_NOTES_PER_TX, MAX_LOG_ENTRIES_PER_TX,
+    MAX_OUTPUT_NOTES_PER_TX, TX_KERNEL_VERSION,
+};
+
+// KERNEL CONFIG
+// ================================================================================================
+
+/// Protocol parameters of the transaction kernel which are in effect for transactions executed
+/// against a given block.
+///
+/// A kernel config includes the following fields:
+///
+/// - version is the version of the transaction kernel which must be used to execute transactions.
+/// - features is a bitmask of the optional kernel features which are enabled (see
+///   [KernelConfig::TX_LOG_FEATURE]).
+/// - fees are the fee parameters of the transaction kernel (see [FeeParameters]).
+/// - limits are the limits imposed on transactions by the transaction kernel (see
+///   [KernelLimits]).
+///
+/// The limits cannot exceed the hard limits of the protocol (e.g., [MAX_INPUT_NOTES_PER_TX]).
+/// The fee of a transaction is deducted from the vault of the account by the transaction kernel
+/// in the epilogue, either in the native fee asset or in one of the accepted fee assets.
+///
+/// The config is committed to by the block header via [KernelConfig::commitment()], and is
+/// provided to the transaction kernel as a part of the transaction inputs.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct KernelConfig {
+    version: u32,
+    features: u32,
+    fees: FeeParameters,
+    limits: KernelLimits,
+}
+
+impl KernelConfig {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// Feature flag which enables recording of entries in the transaction log.
+    pub const TX_LOG_FEATURE: u32 = 0b1;
+
+    /// Bitmask of all features supported by the transaction kernel.
+    pub const ALL_FEATURES: u32 = Self::TX_LOG_FEATURE;
+
+    /// The number of field elements needed to encode a kernel config.
+    pub const NUM_ELEMENTS: usize = 16;
+
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [KernelConfig] instantiated with the specified parameters.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - Any of the features is not supported by the transaction kernel.
+    /// - The maximum number of input notes is greater than [MAX_INPUT_NOTES_PER_TX].
+    /// - The maximum number of output notes is greater than [MAX_OUTPUT_NOTES_PER_TX].
+    /// - The maximum number of log entries is greater than [MAX_LOG_ENTRIES_PER_TX].
+    /// - The maximum depth of nested guarded account procedure calls is greater than
+    ///   [MAX_ACCOUNT_PROC_CALL_DEPTH].
+    /// - Fees are charged or alternative fee assets are accepted, but no native fee asset is set.
+    /// - Any of the fee assets is not issued by a fungible faucet.
+    /// - Any of the accepted fee assets is the native fee asset or is accepted more than once.
+    /// - The number of accepted fee assets is greater than [MAX_ACCEPTED_FEE_ASSETS].
+    pub fn new(
+        version: u32,
+        features: u32,
+        fees: FeeParameters,
+        limits: KernelLimits,
+    ) -> Result<Self, KernelConfigError> {
+        if features & !Self::ALL_FEATURES != 0 {
+            return Err(KernelConfigError::UnsupportedFeatures(features));
+        }
+
+        if limits.max_input_notes as usize > MAX_INPUT_NOTES_PER_TX {
+            return Err(KernelConfigError::TooManyInputNotes {
+                max: MAX_INPUT_NOTES_PER_TX,
+                actual: limits.max_input_notes as usize,
+            });
+        }
+
+        if limits.max_output_notes as usize > MAX_OUTPUT_NOTES_PER_TX {
+            return Err(KernelConfigError::TooManyOutputNotes {
+                max: MAX_OUTPUT_NOTES_PER_TX,
+                actual: limits.max_output_notes as usize,
+            });
+        }
+
+        if limits.max_log_entries as usize > MAX_LOG_ENTRIES_PER_TX {
+            return Err(KernelConfigError::TooManyLogEntries {
+                max: MAX_LOG_ENTRIES_PER_TX,
+                actual: limits.max_log_entries as usize,
+            });
+        }
+
+        if limits.max_account_proc_call_depth as usize > MAX_ACCOUNT_PROC_CALL_DEPTH {
+            return Err(KernelConfigError::TooDeepAccountProcCalls {
+                max: MAX_ACCOUNT_PROC_CALL_DEPTH,
+                actual: limits.max_account_proc_call_depth as usize,
+            });
+        }
+
+        let charges_fees = fees.base_fee != 0 || fees.fee_per_note != 0;
+        match fees.native_asset {
+            None if charges_fees || !fees.accepted_assets.is_empty() => {
+                return Err(KernelConfigError::MissingNativeFeeAsset);
+            },
+            Some(faucet_id) if faucet_id.account_type() != AccountType::FungibleFaucet => {
+                return Err(KernelConfigError::FeeAssetNotFungible(faucet_id));
+            },
+            _ => (),
+        }
+
+        if fees.accepted_assets.len() > MAX_ACCEPTED_FEE_ASSETS {
+            return Err(KernelConfigError::TooManyAcceptedFeeAssets {
+                max: MAX_ACCEPTED_FEE_ASSETS,
+                actual: fees.accepted_assets.len(),
+            });
+        }
+
+        for (idx, accepted_asset) in fees.accepted_assets.iter().enumerate() {
+            let faucet_id = accepted_asset.faucet_id;
+            if faucet_id.account_type() != AccountType::FungibleFaucet {
+                return Err(KernelConfigError::FeeAssetNotFungible(faucet_id));
+            }
+
+            if fees.native_asset == Some(faucet_id)
+                || fees.accepted_assets[..idx].iter().any(|asset| asset.faucet_id == faucet_id)
+            {
+                return Err(KernelConfigError::DuplicateFeeAsset(faucet_id));
+            }
+        }
+
+        Ok(Self { version, features, fees, limits })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the version of the transaction kernel.
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    /// Returns the bitmask of the enabled kernel features.
+    pub fn features(&self) -> u32 {
+        self.features
+    }
+
+    /// Returns true if all of the specified features are enabled.
+    pub fn has_features(&self, features: u32) -> bool {
+        self.features & features == features
+    }
+
+    /// Returns the fee parameters of this config.
+    pub fn fees(&self) -> &FeeParameters {
+        &self.fees
+    }
+
+    /// Returns the transaction limits of this config.
+    pub fn limits(&self) -> &KernelLimits {
+        &self.limits
+    }
+
+    /// Returns the fee charged for every transaction.
+    pub fn base_fee(&self) -> u32 {
+        self.fees.base_fee
+    }
+
+    /// Returns the fee charged for every note consumed or created by a transaction.
+    pub fn fee_per_note(&self) -> u32 {
+        self.fees.fee_per_note
+    }
+
+    /// Returns the maximum number of notes which can be consumed by a transaction.
+    pub fn max_input_notes(&self) -> u32 {
+        self.limits.max_input_notes
+    }
+
+    /// Returns the maximum number of notes which can be created by a transaction.
+    pub fn max_output_notes(&self) -> u32 {
+        self.limits.max_output_notes
+    }
+
+    /// Returns the maximum number of log entries which can be emitted by a transaction.
+    pub fn max_log_entries(&self) -> u32 {
+        self.limits.max_log_entries
+    }
+
+    /// Returns the maximum depth of nested guarded account procedure calls.
+    pub fn max_account_proc_call_depth(&self) -> u32 {
+        self.limits.max_account_proc_call_depth
+    }
+
+    /// Returns the tree committing to the accepted fee assets of this config.
+    ///
+    /// The leaf at index `i` of the tree is the word encoding the `i`-th accepted fee asset (see
+    /// [AcceptedFeeAsset::to_word()]); all other leaves are empty.
+    pub fn accepted_fee_assets_tree(&self) -> SimpleSmt<ACCEPTED_FEE_ASSETS_TREE_DEPTH> {
+        SimpleSmt::with_leaves(
+            self.fees
+                .accepted_assets
+                .iter()
+                .enumerate()
+                .map(|(idx, asset)| (idx as u64, asset.to_word())),
+        )
+        .expect("the number of accepted fee assets was validated")
+    }
+
+    /// Returns the elements encoding this config, i.e.:
+    ///
+    /// [version, features, base_fee, fee_per_note,
+    ///  max_input_notes, max_output_notes, max_log_entries, max_account_proc_call_depth,
+    ///  native_fee_faucet_id, num_accepted_fee_assets, 0, 0,
+    ///  ACCEPTED_FEE_ASSETS_ROOT]
+    ///
+    /// where native_fee_faucet_id is 0 if no native fee asset is set, and ACCEPTED_FEE_ASSETS_ROOT
+    /// is the root of [KernelConfig::accepted_fee_assets_tree()].
+    pub fn to_elements(&self) -> Vec<Felt> {
+        let mut elements = Vec::with_capacity(Self::NUM_ELEMENTS);
+        for word in self.to_words() {
+            elements.extend_from_slice(&word);
+        }
+        elements
+    }
+
+    /// Returns a commitment to this config, computed as a sequential hash of its elements.
+    pub fn commitment(&self) -> Digest {
+        Hasher::hash_elements(&self.to_elements())
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the four words encoding this config: the first one holds the version, features and
+    /// fees, the second one holds the transaction limits, and the last two ones hold the fee assets.
+    fn to_words(&self) -> [Word; 4] {
+        [
+            [
+                self.version.into(),
+                self.features.into(),
+                self.fees.base_fee.into(),
+                self.fees.fee_per_note.into(),
+            ],
+            [
+                self.limits.max_input_notes.into(),
+                self.limits.max_output_notes.into(),
+                self.limits.max_log_entries.into(),
+                self.limits.max_account_proc_call_depth.into(),
+            ],
+            [
+                self.fees.native_asset.map(Felt::from).unwrap_or(ZERO),
+                Felt::from(self.fees.accepted_assets.len() as u32),
+                ZERO,
+                ZERO,
+            ],
+            self.accepted_fee_assets_tree().root().into(),
+        ]
+    }
+}
+
+impl Default for KernelConfig {
+    /// Returns the config of the latest kernel version with all features enabled, no fees and the
+    /// hard limits of the protocol.
+    fn default() -> Self {
+        Self {
+            version: TX_KERNEL_VERSION,
+            features: Self::ALL_FEATURES,
+            fees: FeeParameters::default(),
+            limits: KernelLimits::default(),
+        }
+    }
+}
+
+// FEE PARAMETERS
+// ================================================================================================
+
+/// Fee parameters of the transaction kernel.
+///
+/// - base_fee is the fee charged for every transaction.
+/// - fee_per_note is the fee charged for every note consumed or created by a transaction.
+/// - native_asset is the ID of the fungible faucet issuing the asset in which fees are
+///   denominated.
+/// - accepted_assets are the assets in which fees can be paid in place of the native fee asset
+///   (see [AcceptedFeeAsset]).
+///
+/// The fee is charged by the transaction kernel in the native fee asset, unless the transaction
+/// selects one of the accepted fee assets. In that case, the fee is converted into the selected
+/// asset at the rate published by the oracle account of the asset.
+///
+/// The fee parameters are validated when they are used to instantiate a [KernelConfig].
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct FeeParameters {
+    base_fee: u32,
+    fee_per_note: u32,
+    native_asset: Option<AccountId>,
+    accepted_assets: Vec<AcceptedFeeAsset>,
+}
+
+impl FeeParameters {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns new [FeeParameters] instantiated with the specified base fee and fee per note.
+    ///
+    /// The returned parameters have neither a native fee asset nor accepted fee assets.
+    pub fn new(base_fee: u32, fee_per_note: u32) -> Self {
+        Self {
+            base_fee,
+            fee_per_note,
+            native_asset: None,
+            accepted_assets: Vec::new(),
+        }
+    }
+
+    // MODIFIERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Sets the ID of the fungible faucet issuing the native fee asset.
+    pub fn with_native_asset(mut self, faucet_id: AccountId) -> Self {
+        self.native_asset = Some(faucet_id);
+        self
+    }
+
+    /// Adds an asset in which fees can be paid in place of the native fee asset.
+    pub fn with_accepted_asset(mut self, accepted_asset: AcceptedFeeAsset) -> Self {
+        self.accepted_assets.push(accepted_asset);
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the fee charged for every transaction.
+    pub fn base_fee(&self) -> u32 {
+        self.base_fee
+    }
+
+    /// Returns the fee charged for every note consumed or created by a transaction.
+    pub fn fee_per_note(&self) -> u32 {
+        self.fee_per_note
+    }
+
+    /// Returns the ID of the fungible faucet issuing the native fee asset, if set.
+    pub fn native_asset(&self) -> Option<AccountId> {
+        self.native_asset
+    }
+
+    /// Returns the assets in which fees can be paid in place of the native fee asset.
+    pub fn accepted_assets(&self) -> &[AcceptedFeeAsset] {
+        &self.accepted_assets
+    }
+
+    /// Returns the index of the accepted fee asset issued by the specified faucet together with
+    /// the asset, or None if fees cannot be paid in the asset of the faucet.
+    pub fn find_accepted_asset(&self, faucet_id: AccountId) -> Option<(usize, &AcceptedFeeAsset)> {
+        self.accepted_assets
+            .iter()
+            .enumerate()
+            .find(|(_, asset)| asset.faucet_id == faucet_id)
+    }
+}
+
+// ACCEPTED FEE ASSET
+// ================================================================================================
+
+/// An asset in which the fee of a transaction can be paid in place of the native fee asset.
+///
+/// - faucet_id is the ID of the fungible faucet issuing the asset.
+/// - oracle_id is the ID of the account publishing the conversion rate of the asset.
+/// - rate_slot is the index of the storage slot of the oracle account holding the rate.
+///
+/// The conversion rate is the number of units of the asset charged per unit of the native fee
+/// asset, and is stored in the first element of the value of the rate slot. The transaction kernel
+/// reads the rate from the state of the oracle account as of the reference block of the
+/// transaction, see [FeeConversionWitness](crate::transaction::FeeConversionWitness).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct AcceptedFeeAsset {
+    faucet_id: AccountId,
+    oracle_id: AccountId,
+    rate_slot: u8,
+}
+
+impl AcceptedFeeAsset {
+    /// Returns a new [AcceptedFeeAsset] instantiated with the specified faucet, oracle account and
+    /// rate slot.
+    pub fn new(faucet_id: AccountId, oracle_id: AccountId, rate_slot: u8) -> Self {
+        Self { faucet_id, oracle_id, rate_slot }
+    }
+
+    /// Returns the ID of the fungible faucet issuing the asset.
+    pub fn faucet_id(&self) -> AccountId {
+        self.faucet_id
+    }
+
+    /// Returns the ID of the account publishing the conversion rate of the asset.
+    pub fn oracle_id(&self) -> AccountId {
+        self.oracle_id
+    }
+
+    /// Returns the index of the storage slot of the oracle account holding the conversion rate.
+    pub fn rate_slot(&self) -> u8 {
+        self.rate_slot
+    }
+
+    /// Returns the word encoding this asset, i.e., [faucet_id, oracle_id, rate_slot, 0].
+    pub fn to_word(&self) -> Word {
+        [self.faucet_id.into(), self.oracle_id.into(), self.rate_slot.into(), ZERO]
+    }
+}
+
+// KERNEL LIMITS
+// ================================================================================================
+
+/// Limits imposed on transactions by the transaction kernel.
+///
+/// - max_input_notes is the maximum number of notes which can be consumed by a transaction.
+/// - max_output_notes is the maximum number of notes which can be created by a transaction.
+/// - max_log_entries is the maximum number of log entries which can be emitted by a transaction.
+/// - max_account_proc_call_depth is the maximum depth of nested guarded account procedure calls.
+///
+/// The default limits are the hard limits of the protocol. Individual limits are lowered via the
+/// `with_*` methods, and they are validated against the hard limits when the limits are used to
+/// instantiate a [KernelConfig].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
+pub struct KernelLimits {
+    max_input_notes: u32,
+    max_output_notes: u32,
+    max_log_entries: u32,
+    max_account_proc_call_depth: u32,
+}
+
+impl KernelLimits {
+    // MODIFIERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Sets the maximum number of notes which can be consumed by a transaction.
+    pub fn with_max_input_notes(mut self, max_input_notes: u32) -> Self {
+        self.max_input_notes = max_input_notes;
+        self
+    }
+
+    /// Sets the maximum number of notes which can be created by a transaction.
+    pub fn with_max_output_notes(mut self, max_output_notes: u32) -> Self {
+        self.max_output_notes = max_output_notes;
+        self
+    }
+
+    /// Sets the maximum number of log entries which can be emitted by a transaction.
+    pub fn with_max_log_entries(mut self, max_log_entries: u32) -> Self {
+        self.max_log_entries = max_log_entries;
+        self
+    }
+
+    /// Sets the maximum depth of nested guarded account procedure calls.
+    pub fn with_max_account_proc_call_depth(mut self, max_account_proc_call_depth: u32) -> Self {
+        self.max_account_proc_call_depth = max_account_proc_call_depth;
+        self
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the maximum number of notes which can be consumed by a transaction.
+    pub fn max_input_notes(&self) -> u32 {
+        self.max_input_notes
+    }
+
+    /// Returns the maximum number of notes which can be created by a transaction.
+    pub fn max_output_notes(&self) -> u32 {
+        self.max_output_notes
+    }
+
+    /// Returns the maximum number of log entries which can be emitted by a transaction.
+    pub fn max_log_entries(&self) -> u32 {
+        self.max_log_entries
+    }
+
+    /// Returns the maximum depth of nested guarded account procedure calls.
+    pub fn max_account_proc_call_depth(&self) -> u32 {
+        self.max_account_proc_call_depth
+    }
+}
+
+impl Default for KernelLimits {
+    /// Returns the hard limits of the protocol.
+    fn default() -> Self {
+        Self {
+            max_input_notes: MAX_INPUT_NOTES_PER_TX as u32,
+            max_output_notes: MAX_OUTPUT_NOTES_PER_TX as u32,
+            max_log_entries: MAX_LOG_ENTRIES_PER_TX as u32,
+            max_account_proc_call_depth: MAX_ACCOUNT_PROC_CALL_DEPTH as u32,
+        }
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for KernelConfig {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u32(self.version);
+        target.write_u32(self.features);
+        self.fees.write_into(target);
+        self.limits.write_into(target);
+    }
+}
+
+impl Deserializable for KernelConfig {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let version = source.read_u32()?;
+        let features = source.read_u32()?;
+        let fees = FeeParameters::read_from(source)?;
+        let limits = KernelLimits::read_from(source)?;
+
+        Self::new(version, features, fees, limits)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+    }
+}
+
+impl Serializable for FeeParameters {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u32(self.base_fee);
+        target.write_u32(self.fee_per_note);
+        self.native_asset.write_into(target);
+        target.write_u16(self.accepted_assets.len() as u16);
+        target.write_many(&self.accepted_assets);
+    }
+}
+
+impl Deserializable for FeeParameters {
+    /// Reads the fee parameters without validating them, which is done when the parameters are
+    /// used to instantiate a [KernelConfig].
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let base_fee = source.read_u32()?;
+        let fee_per_note = source.read_u32()?;
+        let native_asset = <Option<AccountId>>::read_from(source)?;
+        let num_accepted_assets = source.read_u16()? as usize;
+        let accepted_assets = source.read_many::<AcceptedFeeAsset>(num_accepted_assets)?;
+
+        Ok(Self {
+            base_fee,
+            fee_per_note,
+            native_asset,
+            accepted_assets,
+        })
+    }
+}
+
+impl Serializable for AcceptedFeeAsset {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.faucet_id.write_into(target);
+        self.oracle_id.write_into(target);
+        target.write_u8(self.rate_slot);
+    }
+}
+
+impl Deserializable for AcceptedFeeAsset {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let faucet_id = AccountId::read_from(source)?;
+        let oracle_id = AccountId::read_from(source)?;
+        let rate_slot = source.read_u8()?;
+
+        Ok(Self::new(faucet_id, oracle_id, rate_slot))
+    }
+}
+
+impl Serializable for KernelLimits {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u32(self.max_input_notes);
+        target.write_u32(self.max_output_notes);
+        target.write_u32(self.max_log_entries);
+        target.write_u32(self.max_account_proc_call_depth);
+    }
+}
+
+impl Deserializable for KernelLimits {
+    /// Reads the limits without validating them against the hard limits of the protocol, which
+    /// is done when the limits are used to instantiate a [KernelConfig].
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let max_input_notes = source.read_u32()?;
+        let max_output_notes = source.read_u32()?;
+        let max_log_entries = source.read_u32()?;
+        let max_account_proc_call_depth = source.read_u32()?;
+
+        Ok(Self {
+            max_input_notes,
+            max_output_notes,
+            max_log_entries,
+            max_account_proc_call_depth,
+        })
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{AcceptedFeeAsset, FeeParameters, KernelConfig, KernelLimits};
+    use crate::{
+        accounts::{
+            AccountId, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1,
+            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2, ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN,
+            ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+        },
+        crypto::merkle::SimpleSmt,
+        utils::serde::{Deserializable, Serializable},
+        Hasher, KernelConfigError, ACCEPTED_FEE_ASSETS_TREE_DEPTH, MAX_ACCEPTED_FEE_ASSETS,
+        MAX_ACCOUNT_PROC_CALL_DEPTH, MAX_INPUT_NOTES_PER_TX, MAX_LOG_ENTRIES_PER_TX,
+        MAX_OUTPUT_NOTES_PER_TX,
+    };
+
+    #[test]
+    fn kernel_config_limits_are_validated() {
+        let fees = FeeParameters::default();
+
+        let limits =
+            KernelLimits::default().with_max_input_notes(MAX_INPUT_NOTES_PER_TX as u32 + 1);
+        let result = KernelConfig::new(1, 0, fees.clone(), limits);
+        assert!(matches!(result, Err(KernelConfigError::TooManyInputNotes { .. })));
+
+        let limits =
+            KernelLimits::default().with_max_output_notes(MAX_OUTPUT_NOTES_PER_TX as u32 + 1);
+        let result = KernelConfig::new(1, 0, fees.clone(), limits);
+        assert!(matches!(result, Err(KernelConfigError::TooManyOutputNotes { .. })));
+
+        let limits =
+            KernelLimits::default().with_max_log_entries(MAX_LOG_ENTRIES_PER_TX as u32 + 1);
+        let result = KernelConfig::new(1, 0, fees.clone(), limits);
+        assert!(matches!(result, Err(KernelConfigError::TooManyLogEntries { .. })));
+
+        let limits = KernelLimits::default()
+            .with_max_account_proc_call_depth(MAX_ACCOUNT_PROC_CALL_DEPTH as u32 + 1);
+        let result = KernelConfig::new(1, 0, fees.clone(), limits);
+        assert!(matches!(result, Err(KernelConfigError::TooDeepAccountProcCalls { .. })));
+
+        let result = KernelConfig::new(1, 0b10, fees, KernelLimits::default());
+        assert_eq!(result, Err(KernelConfigError::UnsupportedFeatures(0b10)));
+    }
+
+    #[test]
+    fn kernel_config_commitment_and_serialization() {
+        let limits = KernelLimits::default()
+            .with_max_input_notes(16)
+            .with_max_output_notes(32)
+            .with_max_log_entries(8)
+            .with_max_account_proc_call_depth(4);
+        let native_asset = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let accepted_asset = AcceptedFeeAsset::new(
+            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1.try_into().unwrap(),
+            ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN.try_into().unwrap(),
+            3,
+        );
+        let fees = FeeParameters::new(10, 2)
+            .with_native_asset(native_asset)
+            .with_accepted_asset(accepted_asset);
+        let config = KernelConfig::new(1, KernelConfig::TX_LOG_FEATURE, fees, limits).unwrap();
+        assert_eq!(config.max_output_notes(), 32);
+        assert_eq!(config.max_account_proc_call_depth(), 4);
+        assert!(config.has_features(KernelConfig::TX_LOG_FEATURE));
+        assert_eq!(config.commitment(), Hasher::hash_elements(&config.to_elements()));
+        assert_ne!(config.commitment(), KernelConfig::default().commitment());
+
+        // the fee assets are encoded by the last two words of the config
+        let elements = config.to_elements();
+        assert_eq!(elements.len(), KernelConfig::NUM_ELEMENTS);
+        assert_eq!(elements[8], native_asset.into());
+        assert_eq!(elements[9], 1u32.into());
+        let accepted_assets_tree = SimpleSmt::<ACCEPTED_FEE_ASSETS_TREE_DEPTH>::with_leaves([(
+            0,
+            accepted_asset.to_word(),
+        )])
+        .unwrap();
+        assert_eq!(config.accepted_fee_assets_tree().root(), accepted_assets_tree.root());
+        assert_eq!(elements[12..], accepted_assets_tree.root().as_elements()[..]);
+        assert_eq!(config.fees().find_accepted_asset(accepted_asset.faucet_id()).unwrap().0, 0);
+        assert_eq!(config.fees().find_accepted_asset(native_asset), None);
+
+        let bytes = config.to_bytes();
+        assert_eq!(config, KernelConfig::read_from_bytes(&bytes).unwrap());
+    }
+
+    #[test]
+    fn kernel_config_fee_assets_are_validated() {
+        let native_asset = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let oracle_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+        let accepted_asset = AcceptedFeeAsset::new(
+            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1.try_into().unwrap(),
+            oracle_id,
+            0,
+        );
+        let new_config =
+            |fees: FeeParameters| KernelConfig::new(1, 0, fees, KernelLimits::default());
+
+        // fees cannot be charged without a native fee asset
+        let result = new_config(FeeParameters::new(10, 0));
+        assert_eq!(result, Err(KernelConfigError::MissingNativeFeeAsset));
+        let result = new_config(FeeParameters::default().with_accepted_asset(accepted_asset));
+        assert_eq!(result, Err(KernelConfigError::MissingNativeFeeAsset));
+
+        // fee assets must be issued by fungible faucets
+        let non_fungible_faucet =
+            AccountId::try_from(ACCOUNT_ID_NON_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let result = new_config(FeeParameters::new(10, 0).with_native_asset(non_fungible_faucet));
+        assert_eq!(result, Err(KernelConfigError::FeeAssetNotFungible(non_fungible_faucet)));
+        let result = new_config(
+            FeeParameters::new(10, 0)
+                .with_native_asset(native_asset)
+                .with_accepted_asset(AcceptedFeeAsset::new(oracle_id, oracle_id, 0)),
+        );
+        assert_eq!(result, Err(KernelConfigError::FeeAssetNotFungible(oracle_id)));
+
+        // an asset can be accepted only once, and cannot be the native fee asset
+        let result = new_config(
+            FeeParameters::new(10, 0)
+                .with_native_asset(native_asset)
+                .with_accepted_asset(accepted_asset)
+                .with_accepted_asset(accepted_asset),
+        );
+        assert_eq!(result, Err(KernelConfigError::DuplicateFeeAsset(accepted_asset.faucet_id())));
+        let result = new_config(
+            FeeParameters::new(10, 0)
+                .with_native_asset(native_asset)
+                .with_accepted_asset(AcceptedFeeAsset::new(native_asset, oracle_id, 0)),
+        );
+        assert_eq!(result, Err(KernelConfigError::DuplicateFeeAsset(native_asset)));
+
+        // the number of accepted fee assets is limited
+        let fees = (0..=MAX_ACCEPTED_FEE_ASSETS)
+            .fold(FeeParameters::new(10, 0).with_native_asset(native_asset), |fees, _| {
+                fees.with_accepted_asset(accepted_asset)
+            });
+        assert!(matches!(
+            new_config(fees),
+            Err(KernelConfigError::TooManyAcceptedFeeAssets { .. })
+        ));
+
+        // a native fee asset can be set without charging fees
+        let fees = FeeParameters::default().with_native_asset(native_asset).with_accepted_asset(
+            AcceptedFeeAsset::new(
+                ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_2.try_into().unwrap(),
+                oracle_id,
+                0,
+            ),
+        );
+        assert!(new_config(fees).is_ok());
+    }
+}
@@ -1,6 +1,14 @@
 use super::{Digest, Felt, Hasher, ZERO};
 
+mod checkpoint;
+pub use checkpoint::Checkpoint;
 mod header;
-pub use header::BlockHeader;
+pub use header::{BlockHeader, BlockHeaderBuilder};
+mod kernel_config;
+pub use kernel_config::{AcceptedFeeAsset, FeeParameters, KernelConfig, KernelLimits};
 mod note_tree;
 pub use note_tree::BlockNoteTree;
+mod nullifier_checkpoint;
+pub use nullifier_checkpoint::{NullifierCheckpoint, NullifierStatus};
+mod opening;
+pub use opening::{BlockHeaderField, BlockHeaderOpening};
@@ -0,0 +1,476 @@
+use alloc::{collections::BTreeMap, string::ToString, vec::Vec};
+
+use super::{BlockHeader, Digest};
+use crate::{
+    crypto::merkle::{LeafIndex, MerklePath, Smt, SmtLeaf, SmtProof, SMT_DEPTH},
+    notes::Nullifier,
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    CheckpointError, Word,
+};
+
+// NULLIFIER STATUS
+// ================================================================================================
+
+/// The status of a nullifier in the nullifier database at a given block.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NullifierStatus {
+    /// The nullifier is not present in the nullifier database, i.e., the note has not been
+    /// consumed yet.
+    Unspent,
+    /// The nullifier was added to the nullifier database in the specified block.
+    Spent { block_num: u32 },
+}
+
+impl NullifierStatus {
+    /// Returns true if the nullifier has been spent.
+    pub fn is_spent(&self) -> bool {
+        matches!(self, Self::Spent { .. })
+    }
+}
+
+// NULLIFIER CHECKPOINT
+// ================================================================================================
+
+/// The spend status of a set of tracked nullifiers at a given block, together with the openings
+/// of the nullifier database which attest to it.
+///
+/// A nullifier checkpoint is meant to let wallets back up and restore the spend status of their
+/// notes without re-scanning the chain: the statuses can be verified against the nullifier root
+/// of the checkpointed block, and the block itself can be verified against a [Checkpoint] of the
+/// chain using the block hash.
+///
+/// The checkpoint is kept up to date by calling [NullifierCheckpoint::advance()] with the header
+/// of a later block and fresh openings of the tracked nullifiers. Openings are stored per leaf of
+/// the nullifier tree, and thus nullifiers which share a leaf share a single opening.
+///
+/// [Checkpoint]: super::Checkpoint
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NullifierCheckpoint {
+    block_num: u32,
+    block_hash: Digest,
+    nullifier_root: Digest,
+    nullifiers: BTreeMap<Nullifier, NullifierStatus>,
+    openings: BTreeMap<u64, SmtProof>,
+}
+
+impl NullifierCheckpoint {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [NullifierCheckpoint] for the provided block header which tracks the provided
+    /// nullifiers.
+    ///
+    /// Every nullifier must be accompanied by an opening of the nullifier tree of the provided
+    /// block at the leaf in which the nullifier is stored.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - Any of the openings does not resolve to the nullifier root of the provided header.
+    /// - Any of the openings is not an opening of the leaf in which its nullifier is stored.
+    pub fn new<I>(header: &BlockHeader, openings: I) -> Result<Self, CheckpointError>
+    where
+        I: IntoIterator<Item = (Nullifier, SmtProof)>,
+    {
+        let mut checkpoint = Self {
+            block_num: header.block_num(),
+            block_hash: header.hash(),
+            nullifier_root: header.nullifier_root(),
+            nullifiers: BTreeMap::new(),
+            openings: BTreeMap::new(),
+        };
+
+        for (nullifier, opening) in openings {
+            checkpoint.track(nullifier, opening)?;
+        }
+
+        Ok(checkpoint)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of the block at which this checkpoint was taken.
+    pub fn block_num(&self) -> u32 {
+        self.block_num
+    }
+
+    /// Returns the hash of the header of the checkpointed block.
+    pub fn block_hash(&self) -> Digest {
+        self.block_hash
+    }
+
+    /// Returns the nullifier database root at the checkpointed block.
+    pub fn nullifier_root(&self) -> Digest {
+        self.nullifier_root
+    }
+
+    /// Returns the status of the specified nullifier, or None if the nullifier is not tracked.
+    pub fn status(&self, nullifier: &Nullifier) -> Option<NullifierStatus> {
+        self.nullifiers.get(nullifier).copied()
+    }
+
+    /// Returns true if the specified nullifier is tracked and has been spent.
+    pub fn is_spent(&self, nullifier: &Nullifier) -> bool {
+        self.status(nullifier).is_some_and(|status| status.is_spent())
+    }
+
+    /// Returns an iterator over the tracked nullifiers and their statuses.
+    pub fn nullifiers(&self) -> impl Iterator<Item = (&Nullifier, &NullifierStatus)> {
+        self.nullifiers.iter()
+    }
+
+    /// Returns the number of tracked nullifiers.
+    pub fn num_nullifiers(&self) -> usize {
+        self.nullifiers.len()
+    }
+
+    /// Returns the opening of the nullifier tree which attests to the status of the specified
+    /// nullifier, or None if the nullifier is not tracked.
+    pub fn opening(&self, nullifier: &Nullifier) -> Option<&SmtProof> {
+        if !self.nullifiers.contains_key(nullifier) {
+            return None;
+        }
+        self.openings.get(&leaf_index(nullifier))
+    }
+
+    // PUBLIC MODIFIERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Starts tracking the specified nullifier using the provided opening of the nullifier tree
+    /// of the checkpointed block.
+    ///
+    /// # Errors
+    /// Returns an error if the opening does not resolve to the nullifier root of this checkpoint
+    /// or is not an opening of the leaf in which the nullifier is stored.
+    pub fn track(
+        &mut self,
+        nullifier: Nullifier,
+        opening: SmtProof,
+    ) -> Result<(), CheckpointError> {
+        let opening_root = opening.compute_root();
+        if opening_root != self.nullifier_root {
+            return Err(CheckpointError::InconsistentNullifierOpening {
+                expected: self.nullifier_root,
+                actual: opening_root,
+            });
+        }
+
+        let status = match opening.get(&nullifier.inner()) {
+            Some(value) => status_from_value(value),
+            None => return Err(CheckpointError::InvalidNullifierOpening(nullifier)),
+        };
+
+        self.nullifiers.insert(nullifier, status);
+        self.openings.insert(leaf_index(&nullifier), opening);
+
+        Ok(())
+    }
+
+    /// Stops tracking the specified nullifier and returns its last known status, or None if the
+    /// nullifier was not tracked.
+    pub fn untrack(&mut self, nullifier: &Nullifier) -> Option<NullifierStatus> {
+        let status = self.nullifiers.remove(nullifier)?;
+
+        let index = leaf_index(nullifier);
+        if !self.nullifiers.keys().any(|nullifier| leaf_index(nullifier) == index) {
+            self.openings.remove(&index);
+        }
+
+        Some(status)
+    }
+
+    // VERIFICATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new checkpoint of the tracked nullifiers for the provided header of a later
+    /// block.
+    ///
+    /// The `openings` must contain an opening of the nullifier tree of the provided block for
+    /// every leaf in which a tracked nullifier is stored, as served by the node while syncing.
+    /// Since nullifiers are never removed from the nullifier database, nullifiers which were spent
+    /// as of this checkpoint must be spent in the same block as of the new checkpoint.
+    ///
+    /// The provided header is not verified to be a part of the same chain as the checkpointed
+    /// block; this can be done via [Checkpoint::advance()](super::Checkpoint::advance()).
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The block number of the provided header is not greater than the block number of this
+    ///   checkpoint.
+    /// - An opening is missing for any of the tracked nullifiers.
+    /// - Any of the openings does not resolve to the nullifier root of the provided header.
+    /// - The status of any of the nullifiers spent as of this checkpoint has changed.
+    pub fn advance<I>(
+        &self,
+        header: &BlockHeader,
+        openings: I,
+    ) -> Result<NullifierCheckpoint, CheckpointError>
+    where
+        I: IntoIterator<Item = SmtProof>,
+    {
+        if header.block_num() <= self.block_num {
+            return Err(CheckpointError::BlockNumNotIncreasing {
+                checkpoint: self.block_num,
+                header: header.block_num(),
+            });
+        }
+
+        let openings: BTreeMap<u64, SmtProof> = openings
+            .into_iter()
+            .map(|opening| (opening.leaf().index().value(), opening))
+            .collect();
+
+        let mut tracked = Vec::with_capacity(self.nullifiers.len());
+        for &nullifier in self.nullifiers.keys() {
+            let opening = match openings.get(&leaf_index(&nullifier)) {
+                Some(opening) => opening.clone(),
+                None => return Err(CheckpointError::MissingNullifierOpening(nullifier)),
+            };
+            tracked.push((nullifier, opening));
+        }
+
+        let checkpoint = Self::new(header, tracked)?;
+        for (nullifier, &status) in self.nullifiers.iter() {
+            if status.is_spent() && checkpoint.status(nullifier) != Some(status) {
+                return Err(CheckpointError::NullifierStatusChanged(*nullifier));
+            }
+        }
+
+        Ok(checkpoint)
+    }
+
+    /// Returns the nullifiers which are spent as of this checkpoint but were not known to be spent
+    /// as of the provided earlier checkpoint, together with the numbers of the blocks in which they were spent.
+    pub fn spent_since<'a>(
+        &'a self,
+        earlier: &'a NullifierCheckpoint,
+    ) -> impl Iterator<Item = (Nullifier, u32)> + 'a {
+        self.nullifiers.iter().filter_map(|(nullifier, status)| match status {
+            NullifierStatus::Spent { block_num } if !earlier.is_spent(nullifier) => {
+                Some((*nullifier, *block_num))
+            },
+            _ => None,
+        })
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the index of the nullifier tree leaf in which the specified nullifier is stored.
+fn leaf_index(nullifier: &Nullifier) -> u64 {
+    LeafIndex::<SMT_DEPTH>::from(Word::from(nullifier)).value()
+}
+
+/// Returns the status of a nullifier stored in the nullifier tree under the specified value.
+///
+/// Spent nullifiers are stored in the tree as [block_num, 0, 0, 0].
+fn status_from_value(value: Word) -> NullifierStatus {
+    if value == Smt::EMPTY_VALUE {
+        NullifierStatus::Unspent
+    } else {
+        NullifierStatus::Spent { block_num: value[0].as_int() as u32 }
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for NullifierCheckpoint {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.block_num.write_into(target);
+        self.block_hash.write_into(target);
+        self.nullifier_root.write_into(target);
+
+        // the statuses of the nullifiers are not serialized, as they are recomputed from the
+        // openings when the checkpoint is deserialized
+        target.write_u32(self.nullifiers.len() as u32);
+        target.write_many(self.nullifiers.keys());
+
+        target.write_u32(self.openings.len() as u32);
+        for opening in self.openings.values() {
+            let (path, leaf) = opening.clone().into_parts();
+            let entries = leaf.entries();
+            target.write_u64(leaf.index().value());
+            target.write_u32(entries.len() as u32);
+            for (key, value) in entries {
+                key.write_into(target);
+                value.write_into(target);
+            }
+            path.write_into(target);
+        }
+    }
+}
+
+impl Deserializable for NullifierCheckpoint {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let block_num = source.read()?;
+        let block_hash = source.read()?;
+        let nullifier_root = source.read()?;
+
+        let num_nullifiers = source.read_u32()? as usize;
+        let nullifiers = source.read_many::<Nullifier>(num_nullifiers)?;
+
+        let num_openings = source.read_u32()? as usize;
+        let mut openings = BTreeMap::new();
+        for _ in 0..num_openings {
+            let index = LeafIndex::<SMT_DEPTH>::new_max_depth(source.read_u64()?);
+            let num_entries = source.read_u32()? as usize;
+            let mut entries = Vec::with_capacity(num_entries.min(u8::MAX as usize));
+            for _ in 0..num_entries {
+                entries.push((Digest::read_from(source)?, Word::read_from(source)?));
+            }
+            let leaf = SmtLeaf::new(entries, index)
+                .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+            let path = MerklePath::read_from(source)?;
+            let opening = SmtProof::new(path, leaf)
+                .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+            openings.insert(index.value(), opening);
+        }
+
+        let mut checkpoint = Self {
+            block_num,
+            block_hash,
+            nullifier_root,
+            nullifiers: BTreeMap::new(),
+            openings: BTreeMap::new(),
+        };
+        for nullifier in nullifiers {
+            let opening = openings.get(&leaf_index(&nullifier)).cloned().ok_or_else(|| {
+                DeserializationError::InvalidValue(format!("missing opening for {nullifier}"))
+            })?;
+            checkpoint
+                .track(nullifier, opening)
+                .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+        }
+
+        Ok(checkpoint)
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::{NullifierCheckpoint, NullifierStatus};
+    use crate::{
+        crypto::merkle::Smt,
+        notes::Nullifier,
+        utils::serde::{Deserializable, Serializable},
+        BlockHeader, CheckpointError, Digest, Felt, Word, ZERO,
+    };
+
+    fn nullifier(seed: u32) -> Nullifier {
+        // the most significant element determines the leaf of the nullifier tree
+        Nullifier::from(Digest::from([Felt::from(seed), ZERO, ZERO, Felt::from(seed)]))
+    }
+
+    fn header(block_num: u32, nullifier_tree: &Smt) -> BlockHeader {
+        BlockHeader::builder()
+            .block_num(block_num)
+            .nullifier_root(nullifier_tree.root())
+            .build()
+    }
+
+    fn spend(nullifier_tree: &mut Smt, nullifier: Nullifier, block_num: u32) {
+        let value: Word = [Felt::from(block_num), ZERO, ZERO, ZERO];
+        nullifier_tree.insert(nullifier.inner(), value);
+    }
+
+    fn checkpoint(
+        block_num: u32,
+        nullifier_tree: &Smt,
+        nullifiers: &[Nullifier],
+    ) -> NullifierCheckpoint {
+        let openings = nullifiers
+            .iter()
+            .map(|nullifier| (*nullifier, nullifier_tree.open(&nullifier.inner())));
+        NullifierCheckpoint::new(&header(block_num, nullifier_tree), openings).unwrap()
+    }
+
+    #[test]
+    fn test_nullifier_checkpoint_new() {
+        let [spent, unspent, other] = [nullifier(1), nullifier(3), nullifier(5)];
+        let mut tree = Smt::new();
+        spend(&mut tree, spent, 2);
+        spend(&mut tree, other, 3);
+
+        let checkpoint = checkpoint(4, &tree, &[spent, unspent]);
+        assert_eq!(checkpoint.num_nullifiers(), 2);
+        assert_eq!(checkpoint.status(&spent), Some(NullifierStatus::Spent { block_num: 2 }));
+        assert_eq!(checkpoint.status(&unspent), Some(NullifierStatus::Unspent));
+        assert_eq!(checkpoint.status(&other), None);
+        assert!(checkpoint.is_spent(&spent));
+
+        // openings must resolve to the nullifier root of the block
+        let result =
+            NullifierCheckpoint::new(&header(4, &Smt::new()), [(spent, tree.open(&spent.inner()))]);
+        assert!(matches!(result, Err(CheckpointError::InconsistentNullifierOpening { .. })));
+
+        // openings must open the leaf of the nullifier
+        let result =
+            NullifierCheckpoint::new(&header(4, &tree), [(unspent, tree.open(&spent.inner()))]);
+        assert_eq!(result, Err(CheckpointError::InvalidNullifierOpening(unspent)));
+    }
+
+    #[test]
+    fn test_nullifier_checkpoint_advance() {
+        let [first, second, third] = [nullifier(1), nullifier(3), nullifier(5)];
+        let mut tree = Smt::new();
+        spend(&mut tree, first, 1);
+        let checkpoint = checkpoint(2, &tree, &[first, second, third]);
+
+        spend(&mut tree, second, 3);
+        spend(&mut tree, nullifier(7), 4);
+        let openings = [first, second, third].map(|nullifier| tree.open(&nullifier.inner()));
+        let next = checkpoint.advance(&header(5, &tree), openings).unwrap();
+        assert_eq!(next, self::checkpoint(5, &tree, &[first, second, third]));
+        assert_eq!(next.status(&second), Some(NullifierStatus::Spent { block_num: 3 }));
+        assert_eq!(next.status(&third), Some(NullifierStatus::Unspent));
+        assert_eq!(next.spent_since(&checkpoint).collect::<Vec<_>>(), vec![(second, 3)]);
+
+        // block number must increase
+        let openings = [first, second, third].map(|nullifier| tree.open(&nullifier.inner()));
+        let result = next.advance(&header(5, &tree), openings);
+        assert!(matches!(result, Err(CheckpointError::BlockNumNotIncreasing { .. })));
+
+        // all tracked nullifiers must be opened
+        let openings = [first, second].map(|nullifier| tree.open(&nullifier.inner()));
+        let result = next.advance(&header(6, &tree), openings);
+        assert_eq!(result, Err(CheckpointError::MissingNullifierOpening(third)));
+
+        // spent nullifiers must stay spent
+        let mut other_tree = Smt::new();
+        spend(&mut other_tree, second, 3);
+        let openings = [first, second, third].map(|nullifier| other_tree.open(&nullifier.inner()));
+        let result = next.advance(&header(6, &other_tree), openings);
+        assert_eq!(result, Err(CheckpointError::NullifierStatusChanged(first)));
+    }
+
+    #[test]
+    fn test_nullifier_checkpoint_untrack() {
+        let [first, second] = [nullifier(1), nullifier(3)];
+        let mut tree = Smt::new();
+        spend(&mut tree, first, 1);
+        let mut checkpoint = checkpoint(2, &tree, &[first, second]);
+
+        assert_eq!(checkpoint.untrack(&first), Some(NullifierStatus::Spent { block_num: 1 }));
+        assert_eq!(checkpoint.untrack(&first), None);
+        assert!(checkpoint.opening(&first).is_none());
+        assert_eq!(checkpoint, self::checkpoint(2, &tree, &[second]));
+    }
+
+    #[test]
+    fn test_nullifier_checkpoint_serde() {
+        let [spent, unspent] = [nullifier(1), nullifier(3)];
+        let mut tree = Smt::new();
+        spend(&mut tree, spent, 6);
+        let checkpoint = checkpoint(7, &tree, &[spent, unspent]);
+
+        let bytes = checkpoint.to_bytes();
+        assert_eq!(checkpoint, NullifierCheckpoint::read_from_bytes(&bytes).unwrap());
+    }
+}
@@ -0,0 +1,336 @@
+use alloc::vec::Vec;
+
+use super::{BlockHeader, Digest, Felt, Hasher, ZERO};
+use crate::{
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    Word,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// The number of words absorbed into the sponge when computing the sub hash of a block header.
+const NUM_SUB_HASH_WORDS: usize = 8;
+
+/// The width of the state of the RPO sponge in field elements.
+const STATE_WIDTH: usize = 12;
+
+// BLOCK HEADER FIELD
+// ================================================================================================
+
+/// A field of a [BlockHeader] which can be opened against the hash of the header.
+///
+/// Block number, version and timestamp are committed to as a single word laid out as
+/// [block_num, version, timestamp, 0], and thus can only be opened together as
+/// [BlockHeaderField::Metadata].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum BlockHeaderField {
+    PrevHash = 0,
+    ChainRoot = 1,
+    AccountRoot = 2,
+    NullifierRoot = 3,
+    BatchRoot = 4,
+    ProofHash = 5,
+    Metadata = 6,
+    KernelConfigHash = 7,
+    NoteRoot = 8,
+}
+
+impl BlockHeaderField {
+    /// Returns the position of the field among the words absorbed into the sponge when computing
+    /// the sub hash, or None for the note root, which is not committed to by the sub hash.
+    fn sub_hash_position(&self) -> Option<usize> {
+        match self {
+            Self::NoteRoot => None,
+            field => Some(*field as usize),
+        }
+    }
+}
+
+impl TryFrom<u8> for BlockHeaderField {
+    type Error = DeserializationError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        Ok(match value {
+            0 => Self::PrevHash,
+            1 => Self::ChainRoot,
+            2 => Self::AccountRoot,
+            3 => Self::NullifierRoot,
+            4 => Self::BatchRoot,
+            5 => Self::ProofHash,
+            6 => Self::Metadata,
+            7 => Self::KernelConfigHash,
+            8 => Self::NoteRoot,
+            v => {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "Value {v} is not a valid BlockHeaderField"
+                )))
+            },
+        })
+    }
+}
+
+// BLOCK HEADER OPENING
+// ================================================================================================
+
+/// A proof that a field of a block header has a specific value, verifiable against the hash of
+/// the header.
+///
+/// The hash of a block header is computed as hash(sub_hash, note_root), where the sub hash is a
+/// sequential hash of all other fields (see [BlockHeader::sub_hash()]). An opening of the note
+/// root thus consists only of the sub hash. An opening of any other field consists of the state
+/// of the sponge before the field was absorbed, the other word absorbed together with the field,
+/// the words absorbed after the field, and the note root.
+///
+/// Hence, an opening does not reveal the fields absorbed before the opened field, but it does
+/// reveal the fields absorbed after it. The fields are absorbed in the order in which they are
+/// listed in [BlockHeaderField].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BlockHeaderOpening {
+    field: BlockHeaderField,
+    value: Word,
+    path: OpeningPath,
+}
+
+/// The data required to recompute the hash of a block header from an opened field.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum OpeningPath {
+    SubHash {
+        capacity: Word,
+        sibling: Word,
+        suffix: Vec<Word>,
+        note_root: Digest,
+    },
+    NoteRoot {
+        sub_hash: Digest,
+    },
+}
+
+impl BlockHeaderOpening {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns an opening of the specified field of the provided block header.
+    pub fn new(header: &BlockHeader, field: BlockHeaderField) -> Self {
+        let Some(position) = field.sub_hash_position() else {
+            return Self {
+                field,
+                value: header.note_root().into(),
+                path: OpeningPath::NoteRoot { sub_hash: header.sub_hash() },
+            };
+        };
+
+        let words = sub_hash_words(header);
+        let block_start = position - position % 2;
+        let state = absorb(Word::default(), &words[..block_start]);
+        let capacity = state[..4].try_into().expect("capacity is a word");
+
+        Self {
+            field,
+            value: words[position],
+            path: OpeningPath::SubHash {
+                capacity,
+                sibling: words[position ^ 1],
+                suffix: words[block_start + 2..].to_vec(),
+                note_root: header.note_root(),
+            },
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the opened field.
+    pub fn field(&self) -> BlockHeaderField {
+        self.field
+    }
+
+    /// Returns the value of the opened field.
+    pub fn value(&self) -> Word {
+        self.value
+    }
+
+    // VERIFICATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns true if this opening proves the value of the field against the specified block
+    /// header hash.
+    pub fn verify(&self, block_hash: Digest) -> bool {
+        let (sub_hash, note_root) = match &self.path {
+            OpeningPath::NoteRoot { sub_hash } => (*sub_hash, Digest::from(self.value)),
+            OpeningPath::SubHash { capacity, sibling, suffix, note_root } => {
+                let position =
+                    self.field.sub_hash_position().expect("note root is opened via the sub hash");
+                let block_start = position - position % 2;
+                if suffix.len() != NUM_SUB_HASH_WORDS - block_start - 2 {
+                    return false;
+                }
+
+                let mut words = Vec::with_capacity(2 + suffix.len());
+                if position % 2 == 0 {
+                    words.extend([self.value, *sibling]);
+                } else {
+                    words.extend([*sibling, self.value]);
+                }
+                words.extend_from_slice(suffix);
+
+                let state = absorb(*capacity, &words);
+                let sub_hash = Digest::new(state[4..8].try_into().expect("digest is a word"));
+                (sub_hash, *note_root)
+            },
+        };
+
+        Hasher::merge(&[sub_hash, note_root]) == block_hash
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for BlockHeaderOpening {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(self.field as u8);
+        self.value.write_into(target);
+        match &self.path {
+            OpeningPath::SubHash { capacity, sibling, suffix, note_root } => {
+                capacity.write_into(target);
+                sibling.write_into(target);
+                target.write_many(suffix);
+                note_root.write_into(target);
+            },
+            OpeningPath::NoteRoot { sub_hash } => sub_hash.write_into(target),
+        }
+    }
+}
+
+impl Deserializable for BlockHeaderOpening {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let field = BlockHeaderField::try_from(source.read_u8()?)?;
+        let value = Word::read_from(source)?;
+
+        let path = match field.sub_hash_position() {
+            Some(position) => {
+                let capacity = Word::read_from(source)?;
+                let sibling = Word::read_from(source)?;
+                let num_suffix_words = NUM_SUB_HASH_WORDS - (position - position % 2) - 2;
+                let suffix = source.read_many::<Word>(num_suffix_words)?;
+                let note_root = Digest::read_from(source)?;
+                OpeningPath::SubHash { capacity, sibling, suffix, note_root }
+            },
+            None => OpeningPath::NoteRoot { sub_hash: Digest::read_from(source)? },
+        };
+
+        Ok(Self { field, value, path })
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the words absorbed into the sponge when computing the sub hash of the header, in the
+/// order of absorption (see [BlockHeader::sub_hash()]).
+fn sub_hash_words(header: &BlockHeader) -> [Word; NUM_SUB_HASH_WORDS] {
+    [
+        header.prev_hash().into(),
+        header.chain_root().into(),
+        header.account_root().into(),
+        header.nullifier_root().into(),
+        header.batch_root().into(),
+        header.proof_hash().into(),
+        [header.block_num().into(), header.version(), header.timestamp(), ZERO],
+        header.kernel_config_hash().into(),
+    ]
+}
+
+/// Absorbs the specified words into an RPO sponge with the specified capacity two words at a
+/// time, and returns the resulting state.
+///
+/// This mirrors [Hasher::hash_elements()] for the sub hash of a block header: since the number of
+/// elements absorbed is a multiple of the rate of the sponge, no padding is applied and the
+/// initial capacity is zero. The rate of the state is overwritten by every absorbed block, and so
+/// only the capacity needs to be carried over between blocks.
+fn absorb(capacity: Word, words: &[Word]) -> [Felt; STATE_WIDTH] {
+    let mut state = [ZERO; STATE_WIDTH];
+    state[..4].copy_from_slice(&capacity);
+    for block in words.chunks(2) {
+        state[4..8].copy_from_slice(&block[0]);
+        state[8..12].copy_from_slice(&block[1]);
+        Hasher::apply_permutation(&mut state);
+    }
+    state
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{BlockHeaderField, BlockHeaderOpening};
+    use crate::{
+        utils::serde::{Deserializable, Serializable},
+        BlockHeader, Felt, Word, ZERO,
+    };
+
+    const ALL_FIELDS: [BlockHeaderField; 9] = [
+        BlockHeaderField::PrevHash,
+        BlockHeaderField::ChainRoot,
+        BlockHeaderField::AccountRoot,
+        BlockHeaderField::NullifierRoot,
+        BlockHeaderField::BatchRoot,
+        BlockHeaderField::ProofHash,
+        BlockHeaderField::Metadata,
+        BlockHeaderField::KernelConfigHash,
+        BlockHeaderField::NoteRoot,
+    ];
+
+    fn word(value: u32) -> Word {
+        [Felt::from(value), ZERO, ZERO, ZERO]
+    }
+
+    #[test]
+    fn test_block_header_opening() {
+        let header = BlockHeader::builder()
+            .prev_hash(word(1).into())
+            .block_num(2)
+            .chain_root(word(3).into())
+            .account_root(word(4).into())
+            .nullifier_root(word(5).into())
+            .note_root(word(6).into())
+            .batch_root(word(7).into())
+            .proof_hash(word(8).into())
+            .version(Felt::from(9_u32))
+            .timestamp(Felt::from(10_u32))
+            .kernel_config_hash(word(11).into())
+            .build();
+        let other_header = BlockHeader::builder().block_num(2).build();
+
+        let expected_values = [
+            word(1),
+            word(3),
+            word(4),
+            word(5),
+            word(7),
+            word(8),
+            [Felt::from(2_u32), Felt::from(9_u32), Felt::from(10_u32), ZERO],
+            word(11),
+            word(6),
+        ];
+
+        for (field, expected_value) in ALL_FIELDS.into_iter().zip(expected_values) {
+            let opening = header.open(field);
+            assert_eq!(opening.field(), field);
+            assert_eq!(opening.value(), expected_value);
+            assert!(opening.verify(header.hash()), "opening of {field:?} is invalid");
+            assert!(!opening.verify(other_header.hash()));
+
+            // an opening of a different value is rejected
+            let mut tampered = opening.clone();
+            tampered.value[0] += Felt::from(1_u32);
+            assert!(!tampered.verify(header.hash()));
+
+            let bytes = opening.to_bytes();
+            assert_eq!(opening, BlockHeaderOpening::read_from_bytes(&bytes).unwrap());
+        }
+    }
+}
@@ -4,6 +4,9 @@ pub const ACCOUNT_TREE_DEPTH: u8 = 64;
 /// The depth of the Merkle tree used to commit to notes produced in a block.
 pub const NOTE_TREE_DEPTH: u8 = 20;
 
+/// The depth of the Merkle tree used to commit to the accepted fee assets of a kernel config.
+pub const ACCEPTED_FEE_ASSETS_TREE_DEPTH: u8 = 4;
+
 /// The maximum number of assets that can be stored in a single note.
 pub const MAX_ASSETS_PER_NOTE: usize = 256;
 
@@ -19,6 +22,33 @@ pub const MAX_INPUT_NOTES_PER_TX: usize = 1023;
 /// The maximum number of new notes created by a single transaction.
 pub const MAX_OUTPUT_NOTES_PER_TX: usize = 4096;
 
+/// The maximum time-to-live of a public note, i.e., the maximum number of blocks during which the
+/// details of the note are kept by the nodes.
+pub const MAX_NOTE_TTL: u32 = (1 << 24) - 1;
+
+/// The maximum number of log entries which can be emitted by a single transaction.
+pub const MAX_LOG_ENTRIES_PER_TX: usize = 1024;
+
+/// The maximum size in bytes of the payload of a single chunk of an on-chain account update.
+///
+/// Account updates exceeding this size must be split into multiple chunks, see
+/// [AccountUpdateDetails::into_chunks()](crate::accounts::AccountUpdateDetails::into_chunks).
+pub const MAX_ACCOUNT_UPDATE_CHUNK_SIZE: usize = 64 * 1024;
+
+/// The maximum depth of nested guarded account procedure calls within a single transaction.
+///
+/// This is the upper bound for the depth defined by the kernel config (see
+/// [KernelConfig](crate::block::KernelConfig)), which is the depth enforced by the transaction
+/// kernel.
+pub const MAX_ACCOUNT_PROC_CALL_DEPTH: usize = 16;
+
+/// The maximum number of assets in which the fee of a transaction can be paid in place of the
+/// native fee asset (see [FeeParameters](crate::block::FeeParameters)).
+pub const MAX_ACCEPTED_FEE_ASSETS: usize = 1 << ACCEPTED_FEE_ASSETS_TREE_DEPTH;
+
+/// The latest version of the transaction kernel.
+pub const TX_KERNEL_VERSION: u32 = 9;
+
 /// The minimum proof security level used by the Miden prover & verifier.
 pub const MIN_PROOF_SECURITY_LEVEL: u32 = 96;
 
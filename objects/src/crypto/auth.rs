@@ -0,0 +1,239 @@
+use alloc::vec::Vec;
+
+use super::{
+    dsa::rpo_falcon512::{self, Signature},
+    rand::FeltRng,
+    utils::{Deserializable, Serializable},
+};
+use crate::{
+    notes::{NoteCreationReceipt, NoteId, NoteMetadata, ScriptAttestation, ScriptInfo},
+    AuthError, Digest, Felt, StarkField, Word,
+};
+
+// AUTH SECRET KEY
+// ================================================================================================
+
+/// Secret keys used to authenticate transactions against accounts which rely on one of the
+/// standard authentication schemes.
+pub enum AuthSecretKey {
+    /// A secret key of the RPO Falcon512 signature scheme (see
+    /// `miden::contracts::auth::basic::auth_tx_rpo_falcon512`).
+    RpoFalcon512(rpo_falcon512::SecretKey),
+}
+
+impl AuthSecretKey {
+    /// Returns the public key corresponding to this secret key.
+    pub fn public_key(&self) -> PublicKey {
+        PublicKey::from_secret(self)
+    }
+
+    /// Signs the specified message, and returns the signature in the format in which it is
+    /// provided to the VM via the advice provider (see [signature_to_advice()]).
+    pub fn sign<R: FeltRng>(&self, message: Word, rng: &mut R) -> Vec<Felt> {
+        match self {
+            Self::RpoFalcon512(secret_key) => {
+                signature_to_advice(&secret_key.sign_with_rng(message, rng))
+            },
+        }
+    }
+
+    /// Signs the commitment to the note with the specified ID and metadata, and returns the
+    /// resulting [NoteCreationReceipt].
+    ///
+    /// Receipts should only be issued for notes created by the account which authenticates
+    /// transactions using this key, as they are verified against the public key of the sender
+    /// of the note.
+    pub fn sign_note_creation<R: FeltRng>(
+        &self,
+        note_id: NoteId,
+        metadata: NoteMetadata,
+        rng: &mut R,
+    ) -> NoteCreationReceipt {
+        let commitment = NoteCreationReceipt::commitment(note_id, &metadata);
+        let signature = match self {
+            Self::RpoFalcon512(secret_key) => secret_key.sign_with_rng(commitment.into(), rng),
+        };
+
+        NoteCreationReceipt::new(note_id, metadata, signature)
+    }
+
+    /// Signs the commitment to the note script with the specified root, description, and hash of
+    /// its documentation, and returns the resulting [ScriptAttestation].
+    ///
+    /// The attestation is made by the publisher owning this key, and is recognized by registries
+    /// which trust the public key of the publisher.
+    pub fn sign_script_attestation<R: FeltRng>(
+        &self,
+        script_root: Digest,
+        info: ScriptInfo,
+        docs_hash: Digest,
+        rng: &mut R,
+    ) -> ScriptAttestation {
+        let commitment = ScriptAttestation::commitment(script_root, &info, docs_hash);
+        let signature = match self {
+            Self::RpoFalcon512(secret_key) => secret_key.sign_with_rng(commitment.into(), rng),
+        };
+
+        ScriptAttestation::new(script_root, info, docs_hash, self.public_key().to_word(), signature)
+    }
+
+    /// Returns the advice map entry from which the authentication procedures load this key.
+    ///
+    /// The entry is keyed by the public key, and contains the bytes of the secret key followed by
+    /// the bytes of the public key.
+    pub fn to_advice_map_entry(&self) -> (Word, Vec<Felt>) {
+        match self {
+            Self::RpoFalcon512(secret_key) => {
+                let pub_key: Word = secret_key.public_key().into();
+                let mut key_bytes = secret_key.to_bytes();
+                key_bytes.append(&mut pub_key.to_bytes());
+                let key_felts = key_bytes.into_iter().map(Felt::from).collect();
+                (pub_key, key_felts)
+            },
+        }
+    }
+}
+
+// PUBLIC KEY
+// ================================================================================================
+
+/// Public keys of the standard authentication schemes.
+///
+/// Public keys are stored in the account storage in the form returned by [PublicKey::to_word()],
+/// i.e., as a commitment to the key of the underlying signature scheme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PublicKey {
+    /// A public key of the RPO Falcon512 signature scheme.
+    RpoFalcon512(rpo_falcon512::PublicKey),
+}
+
+impl PublicKey {
+    /// Returns the public key corresponding to the specified secret key.
+    pub fn from_secret(secret_key: &AuthSecretKey) -> Self {
+        match secret_key {
+            AuthSecretKey::RpoFalcon512(secret_key) => Self::RpoFalcon512(secret_key.public_key()),
+        }
+    }
+
+    /// Returns the commitment to this key in the form in which it is stored in the account
+    /// storage.
+    pub fn to_word(&self) -> Word {
+        match self {
+            Self::RpoFalcon512(pub_key) => (*pub_key).into(),
+        }
+    }
+
+    /// Verifies the signature in advice format against this key (see [verify()]).
+    ///
+    /// # Errors
+    /// Returns an error if the signature could not be decoded, or is not valid for the specified
+    /// message and this key.
+    pub fn verify(&self, message: Word, signature_advice: &[Felt]) -> Result<(), AuthError> {
+        verify(self.to_word(), message, signature_advice)
+    }
+}
+
+impl From<PublicKey> for Word {
+    fn from(pub_key: PublicKey) -> Self {
+        pub_key.to_word()
+    }
+}
+
+// SIGNATURE VERIFICATION
+// ================================================================================================
+
+/// Returns the specified signature in the format in which it is provided to the VM via the
+/// advice provider.
+///
+/// The signature is serialized into bytes, and every byte is encoded as a single field element,
+/// i.e., in the same way as the keys in the advice map entries of the authentication procedures
+/// (see [AuthSecretKey::to_advice_map_entry()]).
+pub fn signature_to_advice(signature: &Signature) -> Vec<Felt> {
+    signature.to_bytes().into_iter().map(Felt::from).collect()
+}
+
+/// Verifies the signature in advice format (see [signature_to_advice()]) over the specified
+/// message against the specified public key.
+///
+/// The public key is expected in the form in which it is stored in the account storage, i.e., as
+/// a commitment to the RPO Falcon512 public key. Thus, components outside of the VM (e.g., nodes
+/// verifying note creation receipts, or the participants of a multisig) can verify the signatures
+/// exactly as they are consumed by the authentication procedures.
+///
+/// # Errors
+/// Returns an error if:
+/// - Any of the elements of the signature is not a byte, or the bytes are not a valid signature.
+/// - The signature is not valid for the specified message and public key.
+pub fn verify(pub_key: Word, message: Word, signature_advice: &[Felt]) -> Result<(), AuthError> {
+    let signature_bytes = signature_advice
+        .iter()
+        .map(|element| {
+            u8::try_from(element.as_int()).map_err(|_| AuthError::SignatureAdviceNotBytes(*element))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let signature = Signature::read_from_bytes(&signature_bytes)
+        .map_err(AuthError::SignatureDeserializationFailed)?;
+
+    if !signature.verify(message, pub_key) {
+        return Err(AuthError::InvalidSignature);
+    }
+
+    Ok(())
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{signature_to_advice, verify, AuthSecretKey, PublicKey};
+    use crate::{
+        crypto::{dsa::rpo_falcon512::SecretKey, rand::RpoRandomCoin},
+        AuthError, Felt, Word, ONE, ZERO,
+    };
+
+    #[test]
+    fn test_verify_signature_advice() {
+        let mut rng = RpoRandomCoin::new([ONE, ZERO, ZERO, ZERO]);
+        let secret_key = SecretKey::with_rng(&mut rng);
+        let expected_pub_key: Word = secret_key.public_key().into();
+        let auth = AuthSecretKey::RpoFalcon512(secret_key);
+
+        let pub_key = PublicKey::from_secret(&auth);
+        assert_eq!(pub_key.to_word(), expected_pub_key);
+        assert_eq!(auth.public_key(), pub_key);
+
+        let message = [ONE, Felt::new(2), Felt::new(3), Felt::new(4)];
+        let signature_advice = auth.sign(message, &mut rng);
+        assert!(verify(pub_key.to_word(), message, &signature_advice).is_ok());
+        assert!(pub_key.verify(message, &signature_advice).is_ok());
+
+        // the signature is not valid for a different message
+        let other_message = [ONE, ONE, ONE, ONE];
+        assert_eq!(
+            pub_key.verify(other_message, &signature_advice),
+            Err(AuthError::InvalidSignature)
+        );
+
+        // the signature is not valid for a different key
+        let other_auth = AuthSecretKey::RpoFalcon512(SecretKey::with_rng(&mut rng));
+        assert_eq!(
+            other_auth.public_key().verify(message, &signature_advice),
+            Err(AuthError::InvalidSignature)
+        );
+
+        // signatures which are not encoded as bytes are rejected
+        let mut malformed_advice = signature_advice.clone();
+        malformed_advice[0] = Felt::new(256);
+        assert_eq!(
+            pub_key.verify(message, &malformed_advice),
+            Err(AuthError::SignatureAdviceNotBytes(Felt::new(256)))
+        );
+
+        // the advice format of a signature is its serialization with a byte per element
+        let signature = match &auth {
+            AuthSecretKey::RpoFalcon512(secret_key) => secret_key.sign_with_rng(message, &mut rng),
+        };
+        assert!(pub_key.verify(message, &signature_to_advice(&signature)).is_ok());
+    }
+}
@@ -0,0 +1,3 @@
+pub use miden_crypto::{dsa, hash, merkle, rand, utils};
+
+pub mod auth;
@@ -0,0 +1,168 @@
+//! Canonical encodings of integer values and account IDs into field elements.
+//!
+//! Values which are passed to the VM (e.g., note inputs, storage values, and script arguments)
+//! must be encoded as field elements. Since the field modulus is smaller than 2^64, converting an
+//! arbitrary `u64` via [Felt::new()] silently reduces values which are not smaller than the
+//! modulus. The helpers in this module either reject such values, or split them into 32-bit limbs
+//! which can be processed by the `u64` and `u128` procedures of the standard library.
+//!
+//! Multi-limb values are encoded in little-endian order, i.e., the least significant limb comes
+//! first.
+
+use super::{accounts::AccountId, EncodingError, Felt, StarkField, Word};
+
+// CONSTANTS
+// ================================================================================================
+
+/// The largest absolute value of a signed integer which can be encoded into a single field
+/// element, i.e., (p - 1) / 2.
+pub const MAX_SIGNED_MAGNITUDE: u64 = (Felt::MODULUS - 1) / 2;
+
+// SINGLE ELEMENT ENCODINGS
+// ================================================================================================
+
+/// Returns the field element encoding the specified value.
+///
+/// # Errors
+/// Returns an error if the value is not smaller than the field modulus.
+pub fn felt_from_u64(value: u64) -> Result<Felt, EncodingError> {
+    if value >= Felt::MODULUS {
+        return Err(EncodingError::U64NotCanonical(value));
+    }
+
+    Ok(Felt::new(value))
+}
+
+/// Returns the `u32` value encoded by the specified field element.
+///
+/// # Errors
+/// Returns an error if the element is not smaller than 2^32.
+pub fn u32_from_felt(felt: Felt) -> Result<u32, EncodingError> {
+    u32::try_from(felt.as_int()).map_err(|_| EncodingError::FeltNotU32(felt))
+}
+
+/// Returns the field element encoding the specified signed value.
+///
+/// Non-negative values are encoded as themselves, and negative values are encoded as their
+/// additive inverses in the field, i.e., -x is encoded as p - x.
+///
+/// # Errors
+/// Returns an error if the absolute value is greater than [MAX_SIGNED_MAGNITUDE].
+pub fn felt_from_i64(value: i64) -> Result<Felt, EncodingError> {
+    let magnitude = value.unsigned_abs();
+    if magnitude > MAX_SIGNED_MAGNITUDE {
+        return Err(EncodingError::I64NotCanonical(value));
+    }
+
+    let felt = Felt::new(magnitude);
+    Ok(if value < 0 { -felt } else { felt })
+}
+
+/// Returns the signed value encoded by the specified field element.
+///
+/// Elements not greater than [MAX_SIGNED_MAGNITUDE] are decoded as non-negative values, and all
+/// other elements are decoded as negative values (see [felt_from_i64()]).
+pub fn i64_from_felt(felt: Felt) -> i64 {
+    let value = felt.as_int();
+    if value <= MAX_SIGNED_MAGNITUDE {
+        value as i64
+    } else {
+        -((Felt::MODULUS - value) as i64)
+    }
+}
+
+/// Returns the account ID encoded by the specified field element.
+///
+/// # Errors
+/// Returns an error if the element does not encode a valid account ID.
+pub fn account_id_from_felt(felt: Felt) -> Result<AccountId, EncodingError> {
+    AccountId::try_from(felt).map_err(EncodingError::InvalidAccountId)
+}
+
+// MULTI-LIMB ENCODINGS
+// ================================================================================================
+
+/// Splits the specified value into two 32-bit limbs as [lo, hi].
+pub fn split_u64(value: u64) -> [Felt; 2] {
+    [Felt::from(value as u32), Felt::from((value >> 32) as u32)]
+}
+
+/// Joins two 32-bit limbs [lo, hi] into a `u64` value.
+///
+/// # Errors
+/// Returns an error if any of the limbs is not smaller than 2^32.
+pub fn join_u64(limbs: [Felt; 2]) -> Result<u64, EncodingError> {
+    let lo = u32_from_felt(limbs[0])? as u64;
+    let hi = u32_from_felt(limbs[1])? as u64;
+
+    Ok((hi << 32) | lo)
+}
+
+/// Splits the specified value into four 32-bit limbs, starting from the least significant limb.
+pub fn split_u128(value: u128) -> Word {
+    let [l0, l1] = split_u64(value as u64);
+    let [l2, l3] = split_u64((value >> 64) as u64);
+
+    [l0, l1, l2, l3]
+}
+
+/// Joins four 32-bit limbs, starting from the least significant limb, into a `u128` value.
+///
+/// # Errors
+/// Returns an error if any of the limbs is not smaller than 2^32.
+pub fn join_u128(limbs: Word) -> Result<u128, EncodingError> {
+    let lo = join_u64([limbs[0], limbs[1]])? as u128;
+    let hi = join_u64([limbs[2], limbs[3]])? as u128;
+
+    Ok((hi << 64) | lo)
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        felt_from_i64, felt_from_u64, i64_from_felt, join_u128, join_u64, split_u128, split_u64,
+        u32_from_felt, EncodingError, Felt, StarkField, MAX_SIGNED_MAGNITUDE,
+    };
+
+    #[test]
+    fn encoding_unsigned() {
+        assert_eq!(felt_from_u64(Felt::MODULUS - 1), Ok(Felt::new(Felt::MODULUS - 1)));
+        assert_eq!(
+            felt_from_u64(Felt::MODULUS),
+            Err(EncodingError::U64NotCanonical(Felt::MODULUS))
+        );
+        assert_eq!(felt_from_u64(u64::MAX), Err(EncodingError::U64NotCanonical(u64::MAX)));
+
+        assert_eq!(u32_from_felt(Felt::from(u32::MAX)), Ok(u32::MAX));
+        let felt = Felt::new(u32::MAX as u64 + 1);
+        assert_eq!(u32_from_felt(felt), Err(EncodingError::FeltNotU32(felt)));
+
+        for value in [0, 1, u32::MAX as u64, Felt::MODULUS, u64::MAX] {
+            assert_eq!(join_u64(split_u64(value)), Ok(value));
+        }
+        for value in [0, u64::MAX as u128, u128::MAX - 1, u128::MAX] {
+            assert_eq!(join_u128(split_u128(value)), Ok(value));
+        }
+        assert_eq!(split_u64(u64::MAX - 1), [Felt::from(u32::MAX - 1), Felt::from(u32::MAX)]);
+
+        let limb = Felt::new(1 << 32);
+        assert_eq!(join_u64([Felt::from(1_u32), limb]), Err(EncodingError::FeltNotU32(limb)));
+    }
+
+    #[test]
+    fn encoding_signed() {
+        for value in [0, 1, -1, i32::MIN as i64, MAX_SIGNED_MAGNITUDE as i64] {
+            assert_eq!(i64_from_felt(felt_from_i64(value).unwrap()), value);
+        }
+        assert_eq!(felt_from_i64(-1), Ok(Felt::new(Felt::MODULUS - 1)));
+        assert_eq!(
+            felt_from_i64(-(MAX_SIGNED_MAGNITUDE as i64)),
+            Ok(Felt::new(MAX_SIGNED_MAGNITUDE + 1))
+        );
+        assert_eq!(felt_from_i64(i64::MIN), Err(EncodingError::I64NotCanonical(i64::MIN)));
+        assert_eq!(felt_from_i64(i64::MAX), Err(EncodingError::I64NotCanonical(i64::MAX)));
+    }
+}
@@ -8,33 +8,94 @@ use super::{
     accounts::{AccountId, StorageSlotType},
     assets::{Asset, FungibleAsset, NonFungibleAsset},
     crypto::merkle::MerkleError,
-    notes::NoteId,
-    Digest, Word,
+    notes::{NoteId, Nullifier},
+    transaction::{InclusionHints, TransactionId},
+    Digest, Felt, Word,
 };
 use crate::{accounts::AccountType, notes::NoteType};
 
 // ACCOUNT ERROR
 // ================================================================================================
 
+/// Errors which can occur when building or updating accounts.
+///
+/// Every variant has a stable numeric code (see [AccountError::code()]) in the range
+/// 1001..=1999. Codes of existing variants never change, and codes of removed variants are never
+/// reused, so they can be used to handle errors without relying on their string representation.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum AccountError {
     AccountCodeAssemblerError(AssemblyError),
     AccountCodeNoProcedures,
-    AccountCodeTooManyProcedures { max: usize, actual: usize },
+    AccountCodeProcedureCountMismatch {
+        expected: usize,
+        actual: usize,
+    },
+    AccountCodeProcedureNotDeclared(String),
+    AccountCodeProcedureNotExported(String),
+    AccountCodeTooManyProcedures {
+        max: usize,
+        actual: usize,
+    },
+    AccountForkIdMismatch {
+        expected: AccountId,
+        actual: AccountId,
+    },
     AccountIdInvalidFieldElement(String),
-    AccountIdTooFewOnes(u32, u32),
+    AccountIdNotFungibleFaucet(AccountId),
+    AccountIdTooFewOnes {
+        expected: u32,
+        actual: u32,
+    },
+    AccountUpdateHashMismatch {
+        index: usize,
+        expected: Digest,
+        actual: Digest,
+    },
+    AccountUpdateIdMismatch {
+        index: usize,
+        expected: AccountId,
+        actual: AccountId,
+    },
     AssetVaultUpdateError(AssetVaultError),
+    AuthSchemeNotVersioned,
+    AuthSchemeUnknownVersion(Word),
     DuplicateStorageItems(MerkleError),
     FungibleFaucetIdInvalidFirstBit,
+    FungibleFaucetInvalidIssuance(String),
     FungibleFaucetInvalidMetadata(String),
     HexParseError(String),
     InvalidAccountStorageType,
-    NonceNotMonotonicallyIncreasing { current: u64, new: u64 },
-    SeedDigestTooFewTrailingZeros { expected: u32, actual: u32 },
-    StorageSlotInvalidValueArity { slot: u8, expected: u8, actual: u8 },
+    NonceNotMonotonicallyIncreasing {
+        current: u64,
+        new: u64,
+    },
+    NoteRateLimitInvalidConfig {
+        max_notes: u32,
+        period: u32,
+    },
+    SeedDigestTooFewTrailingZeros {
+        expected: u32,
+        actual: u32,
+    },
+    StorageArrayIndexOutOfBounds {
+        index: u64,
+        depth: u8,
+    },
+    StorageArrayInvalidDepth(u8),
+    StorageArrayRootMismatch(u8),
+    StorageSlotInvalidProof(u8),
+    StorageSlotInvalidValueArity {
+        slot: u8,
+        expected: u8,
+        actual: u8,
+    },
     StorageSlotIsReserved(u8),
+    StorageSlotNotArraySlot(u8, StorageSlotType),
     StorageSlotNotValueSlot(u8, StorageSlotType),
-    StubDataIncorrectLength(usize, usize),
+    StubDataIncorrectLength {
+        expected: usize,
+        actual: usize,
+    },
 }
 
 impl AccountError {
@@ -43,7 +104,7 @@ impl AccountError {
     }
 
     pub fn account_id_too_few_ones(expected: u32, actual: u32) -> Self {
-        Self::AccountIdTooFewOnes(expected, actual)
+        Self::AccountIdTooFewOnes { expected, actual }
     }
 
     pub fn seed_digest_too_few_trailing_zeros(expected: u32, actual: u32) -> Self {
@@ -53,6 +114,51 @@ impl AccountError {
     pub fn fungible_faucet_id_invalid_first_bit() -> Self {
         Self::FungibleFaucetIdInvalidFirstBit
     }
+
+    /// Returns the stable numeric code of this error.
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::AccountCodeAssemblerError(_) => 1001,
+            Self::AccountCodeNoProcedures => 1002,
+            Self::AccountCodeTooManyProcedures { .. } => 1003,
+            Self::AccountIdInvalidFieldElement(_) => 1004,
+            Self::AccountIdTooFewOnes { .. } => 1005,
+            Self::AssetVaultUpdateError(_) => 1006,
+            Self::DuplicateStorageItems(_) => 1007,
+            Self::FungibleFaucetIdInvalidFirstBit => 1008,
+            Self::FungibleFaucetInvalidMetadata(_) => 1009,
+            Self::HexParseError(_) => 1010,
+            Self::InvalidAccountStorageType => 1011,
+            Self::NonceNotMonotonicallyIncreasing { .. } => 1012,
+            Self::SeedDigestTooFewTrailingZeros { .. } => 1013,
+            Self::StorageArrayIndexOutOfBounds { .. } => 1014,
+            Self::StorageArrayInvalidDepth(_) => 1015,
+            Self::StorageArrayRootMismatch(_) => 1016,
+            Self::StorageSlotInvalidValueArity { .. } => 1017,
+            Self::StorageSlotIsReserved(_) => 1018,
+            Self::StorageSlotNotArraySlot(..) => 1019,
+            Self::StorageSlotNotValueSlot(..) => 1020,
+            Self::StubDataIncorrectLength { .. } => 1021,
+            Self::AccountIdNotFungibleFaucet(_) => 1022,
+            Self::FungibleFaucetInvalidIssuance(_) => 1023,
+            Self::StorageSlotInvalidProof(_) => 1024,
+            Self::AccountUpdateHashMismatch { .. } => 1025,
+            Self::AccountUpdateIdMismatch { .. } => 1026,
+            Self::AccountCodeProcedureNotDeclared(_) => 1027,
+            Self::AccountCodeProcedureNotExported(_) => 1028,
+            Self::NoteRateLimitInvalidConfig { .. } => 1029,
+            Self::AuthSchemeNotVersioned => 1030,
+            Self::AuthSchemeUnknownVersion(_) => 1031,
+            Self::AccountCodeProcedureCountMismatch { .. } => 1032,
+        }
+    }
+
+    /// Returns true if the operation which failed with this error could succeed if retried.
+    ///
+    /// Account errors are caused by invalid account data and are never retryable.
+    pub fn is_retryable(&self) -> bool {
+        false
+    }
 }
 
 impl fmt::Display for AccountError {
@@ -62,7 +168,16 @@ impl fmt::Display for AccountError {
 }
 
 #[cfg(feature = "std")]
-impl std::error::Error for AccountError {}
+impl std::error::Error for AccountError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::AccountCodeAssemblerError(err) => Some(err),
+            Self::AssetVaultUpdateError(err) => Some(err),
+            Self::DuplicateStorageItems(err) => Some(err),
+            _ => None,
+        }
+    }
+}
 
 // ACCOUNT DELTA ERROR
 // ================================================================================================
@@ -73,10 +188,20 @@ pub enum AccountDeltaError {
     DuplicateVaultUpdate(Asset),
     InconsistentNonceUpdate(String),
     ImmutableStorageSlot(usize),
+    InvalidStorageSlotProof(u8),
+    StorageRootMismatch { expected: Digest, actual: Digest },
     TooManyAddedAsset { actual: usize, max: usize },
     TooManyClearedStorageItems { actual: usize, max: usize },
     TooManyRemovedAssets { actual: usize, max: usize },
     TooManyUpdatedStorageItems { actual: usize, max: usize },
+    UpdateChunkContinuationMismatch { index: u32 },
+    UpdateChunkCountMismatch { expected: u32, actual: u32 },
+    UpdateChunkFinalHashMismatch { index: u32 },
+    UpdateChunkIndexMismatch { expected: u32, actual: u32 },
+    UpdateChunkSizeInvalid { size: usize, max: usize },
+    UpdateChunksEmpty,
+    UpdateChunksMalformed(String),
+    UpdateTooLarge { size: usize },
 }
 
 #[cfg(feature = "std")]
@@ -168,9 +293,12 @@ impl std::error::Error for AssetError {}
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum AssetVaultError {
     AddFungibleAssetBalanceError(AssetError),
+    AssetNotTracked(Digest),
     DuplicateAsset(MerkleError),
     DuplicateNonFungibleAsset(NonFungibleAsset),
     FungibleAssetNotFound(FungibleAsset),
+    InconsistentVaultOpening { expected: Digest, actual: Digest },
+    InvalidVaultOpening(MerkleError),
     NotANonFungibleAsset(Asset),
     NotAFungibleFaucetId(AccountId),
     NonFungibleAssetNotFound(NonFungibleAsset),
@@ -184,39 +312,141 @@ impl fmt::Display for AssetVaultError {
 }
 
 #[cfg(feature = "std")]
-impl std::error::Error for AssetVaultError {}
+impl std::error::Error for AssetVaultError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::AddFungibleAssetBalanceError(err)
+            | Self::SubtractFungibleAssetBalanceError(err) => Some(err),
+            Self::DuplicateAsset(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+// AUTH ERROR
+// ================================================================================================
+
+/// Errors which can occur when verifying signatures outside of the VM (see [crate::crypto::auth]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AuthError {
+    InvalidSignature,
+    SignatureAdviceNotBytes(Felt),
+    SignatureDeserializationFailed(DeserializationError),
+}
+
+impl fmt::Display for AuthError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AuthError {}
 
 // NOTE ERROR
 // ================================================================================================
 
+/// Errors which can occur when building notes.
+///
+/// Every variant has a stable numeric code (see [NoteError::code()]) in the range 2001..=2999.
+/// Codes of existing variants never change, and codes of removed variants are never reused.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum NoteError {
-    DuplicateFungibleAsset(AccountId),
-    DuplicateNonFungibleAsset(NonFungibleAsset),
+    DuplicateFungibleAsset {
+        faucet_id: AccountId,
+    },
+    DuplicateNonFungibleAsset {
+        asset: NonFungibleAsset,
+    },
+    DuplicateGroupMember(AccountId),
+    DuplicateScriptRoot(Digest),
+    EmptyAccountGroup,
     EmptyAssetList,
-    InconsistentNoteTag(NoteType, u64),
+    /// The tag is inconsistent with the note type; `invalid_bits` contains the bits of the tag
+    /// which violate the rules of the note type (see [NoteTag::validate()](crate::notes::NoteTag)).
+    InconsistentNoteTag {
+        note_type: NoteType,
+        tag: u64,
+        invalid_bits: u64,
+    },
     InconsistentStubAssetHash(Digest, Digest),
     InconsistentStubId(NoteId, NoteId),
     InvalidAssetData(AssetError),
+    InvalidGroupId(u32),
+    InvalidInputEncoding(EncodingError),
     InvalidOriginIndex(String),
+    InvalidPartialSwapAsset(Asset),
+    InvalidPartialSwapFill {
+        requested_fill: u64,
+        requested_amount: u64,
+    },
+    InvalidNoteCreationReceipt(NoteId),
+    InvalidNoteExpiration(u32),
+    InvalidNoteTtl(u32),
+    InvalidNoteInclusionProof(NoteId),
+    InvalidNoteMetadata(NoteMetadataError),
+    InvalidScriptAttestation(Digest),
+    InvalidScriptName(String),
+    InvalidScriptPublisherName(String),
     InvalidStubDataLen(usize),
+    InvalidTagPrefixLength(u8),
     InvalidNoteSender(AccountError),
     InvalidNoteType(NoteType),
     InvalidNoteTypeValue(u64),
     NetworkExecutionRequiresOnChainAccount,
+    NetworkExecutionRequiresPublicNote(NoteType),
+    NoteBatchCommitmentMismatch {
+        expected: Digest,
+        actual: Digest,
+    },
+    NoteBlockMismatch {
+        note_id: NoteId,
+        block_num: u32,
+    },
     NoteDeserializationError(DeserializationError),
+    NoteScriptMismatch {
+        expected: Digest,
+        actual: Digest,
+    },
+    NoteTtlRequiresPublicNote(NoteType),
+    PartialSwapAmountsTooLarge {
+        offered_amount: u64,
+        requested_amount: u64,
+    },
+    ScriptAttestationMismatch(Digest),
     ScriptCompilationError(AssemblyError),
-    TooManyAssets(usize),
-    TooManyInputs(usize),
+    TooManyAssets {
+        max: usize,
+        actual: usize,
+    },
+    TooManyGroupMembers {
+        max: u64,
+        actual: usize,
+    },
+    TooManyInputs {
+        max: usize,
+        actual: usize,
+    },
+    UnexpectedNumNoteInputs {
+        expected: usize,
+        actual: usize,
+    },
+    UntrustedScriptPublisher(Digest),
+}
+
+impl From<NoteMetadataError> for NoteError {
+    fn from(err: NoteMetadataError) -> Self {
+        Self::InvalidNoteMetadata(err)
+    }
 }
 
 impl NoteError {
     pub fn duplicate_fungible_asset(faucet_id: AccountId) -> Self {
-        Self::DuplicateFungibleAsset(faucet_id)
+        Self::DuplicateFungibleAsset { faucet_id }
     }
 
     pub fn duplicate_non_fungible_asset(asset: NonFungibleAsset) -> Self {
-        Self::DuplicateNonFungibleAsset(asset)
+        Self::DuplicateNonFungibleAsset { asset }
     }
 
     pub fn empty_asset_list() -> Self {
@@ -227,12 +457,68 @@ impl NoteError {
         Self::InvalidOriginIndex(msg)
     }
 
-    pub fn too_many_assets(num_assets: usize) -> Self {
-        Self::TooManyAssets(num_assets)
+    pub fn too_many_assets(max: usize, actual: usize) -> Self {
+        Self::TooManyAssets { max, actual }
+    }
+
+    pub fn too_many_inputs(max: usize, actual: usize) -> Self {
+        Self::TooManyInputs { max, actual }
+    }
+
+    /// Returns the stable numeric code of this error.
+    pub fn code(&self) -> u32 {
+        match self {
+            Self::DuplicateFungibleAsset { .. } => 2001,
+            Self::DuplicateNonFungibleAsset { .. } => 2002,
+            Self::EmptyAssetList => 2003,
+            Self::InconsistentNoteTag { .. } => 2004,
+            Self::InconsistentStubAssetHash(..) => 2005,
+            Self::InconsistentStubId(..) => 2006,
+            Self::InvalidAssetData(_) => 2007,
+            Self::InvalidOriginIndex(_) => 2008,
+            Self::InvalidStubDataLen(_) => 2009,
+            Self::InvalidNoteSender(_) => 2010,
+            Self::InvalidNoteType(_) => 2011,
+            Self::InvalidNoteTypeValue(_) => 2012,
+            Self::NetworkExecutionRequiresOnChainAccount => 2013,
+            Self::NoteDeserializationError(_) => 2014,
+            Self::ScriptCompilationError(_) => 2015,
+            Self::TooManyAssets { .. } => 2016,
+            Self::TooManyInputs { .. } => 2017,
+            Self::DuplicateScriptRoot(_) => 2018,
+            Self::InvalidScriptName(_) => 2019,
+            Self::InvalidNoteExpiration(_) => 2020,
+            Self::InvalidTagPrefixLength(_) => 2021,
+            Self::InvalidNoteCreationReceipt(_) => 2022,
+            Self::InvalidInputEncoding(_) => 2023,
+            Self::InvalidNoteMetadata(_) => 2024,
+            Self::NoteBlockMismatch { .. } => 2025,
+            Self::InvalidNoteInclusionProof(_) => 2026,
+            Self::NoteBatchCommitmentMismatch { .. } => 2027,
+            Self::NetworkExecutionRequiresPublicNote(_) => 2028,
+            Self::InvalidNoteTtl(_) => 2029,
+            Self::NoteTtlRequiresPublicNote(_) => 2030,
+            Self::NoteScriptMismatch { .. } => 2031,
+            Self::UnexpectedNumNoteInputs { .. } => 2032,
+            Self::DuplicateGroupMember(_) => 2033,
+            Self::EmptyAccountGroup => 2034,
+            Self::InvalidGroupId(_) => 2035,
+            Self::TooManyGroupMembers { .. } => 2036,
+            Self::InvalidScriptAttestation(_) => 2037,
+            Self::InvalidScriptPublisherName(_) => 2038,
+            Self::ScriptAttestationMismatch(_) => 2039,
+            Self::UntrustedScriptPublisher(_) => 2040,
+            Self::InvalidPartialSwapAsset(_) => 2041,
+            Self::InvalidPartialSwapFill { .. } => 2042,
+            Self::PartialSwapAmountsTooLarge { .. } => 2043,
+        }
     }
 
-    pub fn too_many_inputs(num_inputs: usize) -> Self {
-        Self::TooManyInputs(num_inputs)
+    /// Returns true if the operation which failed with this error could succeed if retried.
+    ///
+    /// Note errors are caused by invalid note data and are never retryable.
+    pub fn is_retryable(&self) -> bool {
+        false
     }
 }
 
@@ -243,7 +529,90 @@ impl fmt::Display for NoteError {
 }
 
 #[cfg(feature = "std")]
-impl std::error::Error for NoteError {}
+impl std::error::Error for NoteError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::InvalidAssetData(err) => Some(err),
+            Self::InvalidInputEncoding(err) => Some(err),
+            Self::InvalidNoteMetadata(err) => Some(err),
+            Self::InvalidNoteSender(err) => Some(err),
+            Self::ScriptCompilationError(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+// NOTE METADATA ERROR
+// ================================================================================================
+
+/// Errors which can occur when decoding note metadata from a word (see
+/// [NoteMetadata](crate::notes::NoteMetadata)).
+///
+/// Each variant identifies the element of the metadata word which failed validation.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NoteMetadataError {
+    /// The tag is inconsistent with the note type; e.g., an off-chain note cannot be intended for
+    /// network execution. `invalid_bits` contains the bits of the tag which violate the rules of
+    /// the note type.
+    InconsistentTag {
+        note_type: NoteType,
+        tag: u32,
+        invalid_bits: u32,
+    },
+    /// The lower 32 bits of the note type element do not encode a valid note type.
+    InvalidNoteType(u64),
+    /// The sender element is not a valid account ID.
+    InvalidSender(AccountError),
+    /// The tag element does not fit into 32 bits.
+    TagNotU32(u64),
+    /// The note type element encodes a TTL for a note which is not public.
+    TtlRequiresPublicNote(NoteType),
+}
+
+impl fmt::Display for NoteMetadataError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for NoteMetadataError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::InvalidSender(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+// ENCODING ERROR
+// ================================================================================================
+
+/// Errors which can occur when encoding values into field elements or decoding them back (see
+/// [crate::encoding]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EncodingError {
+    FeltNotU32(Felt),
+    I64NotCanonical(i64),
+    InvalidAccountId(AccountError),
+    U64NotCanonical(u64),
+}
+
+impl fmt::Display for EncodingError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for EncodingError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Self::InvalidAccountId(err) => Some(err),
+            _ => None,
+        }
+    }
+}
 
 // CHAIN MMR ERROR
 // ================================================================================================
@@ -278,12 +647,108 @@ impl fmt::Display for ChainMmrError {
 #[cfg(feature = "std")]
 impl std::error::Error for ChainMmrError {}
 
+// BLOCK HEADER ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BlockHeaderError {
+    BlockNumNotSequential { expected: u32, actual: u32 },
+    ChainLengthMismatch { expected: u32, actual: usize },
+    ChainRootMismatch { expected: Digest, actual: Digest },
+    PrevHashMismatch { expected: Digest, actual: Digest },
+    TimestampDecreasing { prev: u64, actual: u64 },
+}
+
+impl fmt::Display for BlockHeaderError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BlockHeaderError {}
+
+// CHECKPOINT ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CheckpointError {
+    BlockNumNotIncreasing { checkpoint: u32, header: u32 },
+    ChainLengthMismatch { expected: u32, actual: usize },
+    ChainRootMismatch { expected: Digest, actual: Digest },
+    InconsistentNullifierOpening { expected: Digest, actual: Digest },
+    InvalidMmrDelta(String),
+    InvalidNullifierOpening(Nullifier),
+    MissingNullifierOpening(Nullifier),
+    NullifierStatusChanged(Nullifier),
+}
+
+impl fmt::Display for CheckpointError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for CheckpointError {}
+
+// PAYMENT PROOF ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PaymentProofError {
+    BlockNotBeforeReferenceBlock { block_num: u32, reference_block_num: u32 },
+    ChainLengthMismatch { expected: u32, actual: usize },
+    ChainRootMismatch { expected: Digest, actual: Digest },
+    InvalidChainPath(u32),
+    InvalidNoteInclusionProof(NoteError),
+    NoteIdMismatch { expected: NoteId, actual: NoteId },
+    ReferenceBlockMismatch { expected: Digest, actual: Digest },
+}
+
+impl fmt::Display for PaymentProofError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PaymentProofError {}
+
+// KERNEL CONFIG ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KernelConfigError {
+    DuplicateFeeAsset(AccountId),
+    FeeAssetNotFungible(AccountId),
+    MissingNativeFeeAsset,
+    TooDeepAccountProcCalls { max: usize, actual: usize },
+    TooManyAcceptedFeeAssets { max: usize, actual: usize },
+    TooManyInputNotes { max: usize, actual: usize },
+    TooManyLogEntries { max: usize, actual: usize },
+    TooManyOutputNotes { max: usize, actual: usize },
+    UnsupportedFeatures(u32),
+}
+
+impl fmt::Display for KernelConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for KernelConfigError {}
+
 // TRANSACTION SCRIPT ERROR
 // ================================================================================================
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum TransactionScriptError {
+    NoEntrypoints,
+    ReexportedEntrypoints,
     ScriptCompilationError(AssemblyError),
+    UnknownEntrypoint(Digest),
 }
 
 impl fmt::Display for TransactionScriptError {
@@ -306,9 +771,14 @@ pub enum TransactionInputError {
     InconsistentAccountSeed { expected: AccountId, actual: AccountId },
     InconsistentChainLength { expected: u32, actual: u32 },
     InconsistentChainRoot { expected: Digest, actual: Digest },
+    InconsistentKernelConfig { expected: Digest, actual: Digest },
+    InconsistentNumNullifierProofs { expected: usize, actual: usize },
+    InconsistentPartialVault { expected: Digest, actual: Digest },
+    InputNoteAlreadyConsumed(NoteId),
     InputNoteBlockNotInChainMmr(NoteId),
     InputNoteNotInBlock(NoteId, u32),
     InvalidAccountSeed(AccountError),
+    InvalidNullifierProof(NoteId),
     TooManyInputNotes { max: usize, actual: usize },
 }
 
@@ -332,7 +802,10 @@ pub enum TransactionOutputError {
     OutputNoteDataNotFound,
     OutputNoteDataInvalid(NoteError),
     OutputNotesCommitmentInconsistent(Digest, Digest),
+    OutputStackInvalidLength { expected: usize, actual: usize },
+    TooManyLogEntries { max: usize, actual: usize },
     TooManyOutputNotes { max: usize, actual: usize },
+    TxLogCommitmentInconsistent(Digest, Digest),
 }
 
 impl fmt::Display for TransactionOutputError {
@@ -344,25 +817,115 @@ impl fmt::Display for TransactionOutputError {
 #[cfg(feature = "std")]
 impl std::error::Error for TransactionOutputError {}
 
+// ACCOUNT UPDATE CHAIN ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AccountUpdateChainError {
+    AccountIdMismatch {
+        expected: AccountId,
+        actual: AccountId,
+    },
+    DuplicateTransaction(TransactionId),
+    Fork {
+        tx_id: TransactionId,
+        conflicting_tx_id: TransactionId,
+    },
+    Gap {
+        expected: Digest,
+        actual: Digest,
+    },
+    NonceNotIncreasing {
+        previous: Felt,
+        actual: Felt,
+    },
+}
+
+impl fmt::Display for AccountUpdateChainError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for AccountUpdateChainError {}
+
+// PERSISTED TRANSACTION ERROR
+// ================================================================================================
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PersistedTransactionError {
+    BodyCommitmentMismatch { expected: Digest, actual: Digest },
+    ExecutionOutputsMissing,
+    InvalidBody(DeserializationError),
+    InvalidMagic,
+    MissingHeader(usize),
+    ProgramHashMismatch { expected: Digest, actual: Digest },
+    UnsupportedFormatVersion { supported: u8, actual: u8 },
+}
+
+impl fmt::Display for PersistedTransactionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:?}", self)
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PersistedTransactionError {}
+
 // PROVEN TRANSACTION ERROR
 // ================================================================================================
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ProvenTransactionError {
+    AccountDeltaMismatch(AccountId),
     AccountFinalHashMismatch(Digest, Digest),
     AccountIdMismatch(AccountId, AccountId),
+    BlockRefMismatch {
+        expected: Digest,
+        actual: Digest,
+    },
+    InitialAccountHashMismatch {
+        expected: Digest,
+        actual: Digest,
+    },
+    InclusionHintsMismatch {
+        expected: InclusionHints,
+        actual: InclusionHints,
+    },
     InputNotesError(TransactionInputError),
+    InputNotesMismatch {
+        expected: Digest,
+        actual: Digest,
+    },
+    InvalidInclusionDeadline(u32),
     NoteDetailsForUnknownNotes(Vec<NoteId>),
     OffChainAccountWithDetails(AccountId),
     OnChainAccountMissingDetails(AccountId),
     NewOnChainAccountRequiresFullDetails(AccountId),
     ExistingOnChainAccountRequiresDeltaDetails(AccountId),
     OutputNotesError(TransactionOutputError),
+    OutputNotesMismatch {
+        expected: Digest,
+        actual: Digest,
+    },
+    TransactionIdMismatch {
+        expected: TransactionId,
+        actual: TransactionId,
+    },
+    TxLogError(TransactionOutputError),
 }
 
 impl fmt::Display for ProvenTransactionError {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
+            ProvenTransactionError::AccountDeltaMismatch(account_id) => {
+                write!(
+                    f,
+                    "Account delta of account {} does not match the executed transaction",
+                    account_id
+                )
+            },
             ProvenTransactionError::AccountFinalHashMismatch(account_final_hash, details_hash) => {
                 write!(f, "Proven transaction account_final_hash {} and account_details.hash must match {}.", account_final_hash, details_hash)
             },
@@ -373,9 +936,40 @@ impl fmt::Display for ProvenTransactionError {
                     tx_id, details_id,
                 )
             },
+            ProvenTransactionError::BlockRefMismatch { expected, actual } => {
+                write!(
+                    f,
+                    "Block reference {} does not match the expected block {}",
+                    actual, expected
+                )
+            },
+            ProvenTransactionError::InitialAccountHashMismatch { expected, actual } => {
+                write!(
+                    f,
+                    "Initial account hash {} does not match the expected hash {}",
+                    actual, expected
+                )
+            },
+            ProvenTransactionError::InclusionHintsMismatch { expected, actual } => {
+                write!(
+                    f,
+                    "Inclusion hints {:?} do not match the expected hints {:?}",
+                    actual, expected
+                )
+            },
             ProvenTransactionError::InputNotesError(inner) => {
                 write!(f, "Invalid input notes: {}", inner)
             },
+            ProvenTransactionError::InputNotesMismatch { expected, actual } => {
+                write!(
+                    f,
+                    "Input notes commitment {} does not match the expected commitment {}",
+                    actual, expected
+                )
+            },
+            ProvenTransactionError::InvalidInclusionDeadline(deadline) => {
+                write!(f, "Inclusion deadline {} can never be met", deadline)
+            },
             ProvenTransactionError::NoteDetailsForUnknownNotes(note_ids) => {
                 write!(f, "Note details for unknown note ids: {:?}", note_ids)
             },
@@ -388,12 +982,25 @@ impl fmt::Display for ProvenTransactionError {
             ProvenTransactionError::OutputNotesError(inner) => {
                 write!(f, "Invalid output notes: {}", inner)
             },
+            ProvenTransactionError::OutputNotesMismatch { expected, actual } => {
+                write!(
+                    f,
+                    "Output notes commitment {} does not match the expected commitment {}",
+                    actual, expected
+                )
+            },
+            ProvenTransactionError::TransactionIdMismatch { expected, actual } => {
+                write!(f, "Transaction ID {} does not match the expected ID {}", actual, expected)
+            },
             ProvenTransactionError::NewOnChainAccountRequiresFullDetails(account_id) => {
                 write!(f, "New on-chain account {} missing full details", account_id)
             },
             ProvenTransactionError::ExistingOnChainAccountRequiresDeltaDetails(account_id) => {
                 write!(f, "Existing on-chain account {} should only provide deltas", account_id)
             },
+            ProvenTransactionError::TxLogError(inner) => {
+                write!(f, "Invalid transaction log: {}", inner)
+            },
         }
     }
 }
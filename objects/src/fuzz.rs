@@ -0,0 +1,99 @@
+//! Entry points for fuzzing the deserializers of the core objects.
+//!
+//! Every entry point attempts to deserialize an object from arbitrary bytes. Inputs which are
+//! rejected by the deserializer are ignored. For accepted inputs, the entry points compute the
+//! commitments to the object and check that:
+//! - Serializing the object and deserializing it again results in an equal object.
+//! - The serialization of the object is stable, i.e., the re-deserialized object serializes into
+//!   the same bytes.
+//! - The commitments to the re-deserialized object are equal to the commitments to the original
+//!   object.
+//!
+//! The entry points panic if any of the invariants is violated, or if the object accepted by the
+//! deserializer cannot be committed to. They are meant to be invoked by the `cargo-fuzz` targets
+//! in the `fuzz` directory of the repository, and are available only with the `fuzzing` feature.
+
+use core::fmt::Debug;
+
+use crate::{
+    accounts::Account,
+    notes::Note,
+    transaction::ProvenTransaction,
+    utils::serde::{Deserializable, Serializable},
+};
+
+// FUZZ ENTRY POINTS
+// ================================================================================================
+
+/// Deserializes a [Note] from the provided bytes, and checks the invariants of the note.
+pub fn fuzz_note_roundtrip(bytes: &[u8]) {
+    let Ok(note) = Note::read_from_bytes(bytes) else {
+        return;
+    };
+
+    let commitments = |note: &Note| {
+        (
+            note.id(),
+            note.nullifier(),
+            note.recipient_digest(),
+            note.assets().commitment(),
+            note.authentication_hash(),
+        )
+    };
+    check_roundtrip(&note, commitments);
+}
+
+/// Deserializes a [ProvenTransaction] from the provided bytes, and checks the invariants of the
+/// transaction.
+pub fn fuzz_proven_tx(bytes: &[u8]) {
+    let Ok(tx) = ProvenTransaction::read_from_bytes(bytes) else {
+        return;
+    };
+
+    let commitments = |tx: &ProvenTransaction| {
+        (
+            tx.id(),
+            tx.input_notes().commitment(),
+            tx.output_notes().commitment(),
+            tx.log().commitment(),
+        )
+    };
+    check_roundtrip(&tx, commitments);
+}
+
+/// Deserializes an [Account] from the provided bytes, and checks the invariants of the account.
+pub fn fuzz_account(bytes: &[u8]) {
+    let Ok(account) = Account::read_from_bytes(bytes) else {
+        return;
+    };
+
+    let commitments = |account: &Account| {
+        (
+            account.hash(),
+            account.code().root(),
+            account.storage().root(),
+            account.vault().commitment(),
+        )
+    };
+    check_roundtrip(&account, commitments);
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Checks that the provided object survives a serialization roundtrip, and that the commitments
+/// computed by `commitments` are equal for the original and the re-deserialized object.
+fn check_roundtrip<T, C, F>(object: &T, commitments: F)
+where
+    T: Serializable + Deserializable + PartialEq + Debug,
+    C: PartialEq + Debug,
+    F: Fn(&T) -> C,
+{
+    let expected_commitments = commitments(object);
+
+    let bytes = object.to_bytes();
+    let decoded = T::read_from_bytes(&bytes).expect("serialized object failed to deserialize");
+    assert_eq!(&decoded, object, "object changed in serialization roundtrip");
+    assert_eq!(decoded.to_bytes(), bytes, "serialization of the object is not stable");
+    assert_eq!(commitments(&decoded), expected_commitments, "commitments to the object changed");
+}
@@ -0,0 +1,412 @@
+//! Human-readable renderings of the core objects.
+//!
+//! The [Inspect] trait renders an object as a tree of [InspectNode]s, which is displayed in a
+//! tree-style format with one line per node, e.g.:
+//!
+//! ```text
+//! Block 2
+//! ├── hash: 0x...
+//! ├── prev hash: 0x...
+//! └── timestamp: 0
+//! ```
+//!
+//! Renderings include the commitments to the objects and their components, as well as decoded
+//! metadata (e.g., note types and storage slot types). The format is stable, and is meant to be
+//! used for CLI `inspect` commands and debugging output.
+
+use alloc::{
+    string::{String, ToString},
+    vec::Vec,
+};
+use core::fmt;
+
+use crate::{
+    accounts::{Account, AccountCode, AccountDelta, AccountStorage},
+    assets::{Asset, AssetVault},
+    notes::{Note, NoteAssets, NoteMetadata, NoteRecipient},
+    transaction::{AccountDetails, OutputNote, ProvenTransaction},
+    BlockHeader, Digest, Word,
+};
+
+// INSPECT
+// ================================================================================================
+
+/// An object which can be rendered as a tree of [InspectNode]s.
+pub trait Inspect {
+    /// Returns the rendering of this object.
+    fn inspect(&self) -> InspectNode;
+}
+
+/// A node of a rendered object, consisting of a one-line label and a list of child nodes.
+///
+/// The [Display](fmt::Display) implementation renders the node and all of its descendants in a
+/// tree-style format.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InspectNode {
+    label: String,
+    children: Vec<InspectNode>,
+}
+
+impl InspectNode {
+    /// Returns a new [InspectNode] with the specified label and no children.
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            children: Vec::new(),
+        }
+    }
+
+    /// Returns a new [InspectNode] labeled as `<name>: <value>`.
+    pub fn field(name: &str, value: impl fmt::Display) -> Self {
+        Self::new(format!("{name}: {value}"))
+    }
+
+    /// Adds the provided node to the children of this node.
+    pub fn with_child(mut self, child: InspectNode) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// Adds a child node labeled as `<name>: <value>` to this node.
+    pub fn with_field(self, name: &str, value: impl fmt::Display) -> Self {
+        self.with_child(Self::field(name, value))
+    }
+
+    /// Returns the label of this node.
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// Returns the children of this node.
+    pub fn children(&self) -> &[InspectNode] {
+        &self.children
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Adds child nodes built from the provided items to this node.
+    fn with_children<T>(
+        mut self,
+        items: impl IntoIterator<Item = T>,
+        f: impl Fn(T) -> Self,
+    ) -> Self {
+        self.children.extend(items.into_iter().map(f));
+        self
+    }
+
+    /// Writes the children of this node, prefixing every line with the specified prefix.
+    fn fmt_children(&self, f: &mut fmt::Formatter<'_>, prefix: &str) -> fmt::Result {
+        for (index, child) in self.children.iter().enumerate() {
+            let is_last = index == self.children.len() - 1;
+            let (branch, indent) = if is_last {
+                ("└── ", "    ")
+            } else {
+                ("├── ", "│   ")
+            };
+            writeln!(f, "{prefix}{branch}{}", child.label)?;
+            child.fmt_children(f, &format!("{prefix}{indent}"))?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for InspectNode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{}", self.label)?;
+        self.fmt_children(f, "")
+    }
+}
+
+// NOTES
+// ================================================================================================
+
+impl Inspect for Note {
+    fn inspect(&self) -> InspectNode {
+        InspectNode::new(format!("Note {}", self.id()))
+            .with_field("nullifier", self.nullifier())
+            .with_child(inspect_metadata(self.metadata()))
+            .with_child(inspect_recipient(self.recipient()))
+            .with_child(inspect_note_assets(self.assets()))
+    }
+}
+
+fn inspect_metadata(metadata: &NoteMetadata) -> InspectNode {
+    let node = InspectNode::new("metadata")
+        .with_field("sender", metadata.sender())
+        .with_field("type", format!("{:?}", metadata.note_type()))
+        .with_field("tag", metadata.tag())
+        .with_field("aux", metadata.aux());
+
+    match metadata.expiration() {
+        Some(block_num) => node.with_field("expiration", block_num),
+        None => node,
+    }
+}
+
+fn inspect_recipient(recipient: &NoteRecipient) -> InspectNode {
+    let inputs = recipient.inputs();
+    let script = recipient.script();
+    let script_label = if script.is_root_only() {
+        "script (root only)"
+    } else {
+        "script"
+    };
+
+    InspectNode::field("recipient", hex(recipient.digest()))
+        .with_field("serial number", hex(recipient.serial_num()))
+        .with_field(script_label, hex(script.hash()))
+        .with_child(
+            InspectNode::field(
+                &format!("inputs ({})", inputs.num_values()),
+                hex(inputs.commitment()),
+            )
+            .with_children(inputs.values().iter().enumerate(), |(index, value)| {
+                InspectNode::field(&index.to_string(), value)
+            }),
+        )
+}
+
+fn inspect_note_assets(assets: &NoteAssets) -> InspectNode {
+    InspectNode::field(&format!("assets ({})", assets.num_assets()), hex(assets.commitment()))
+        .with_children(assets.iter(), |asset| inspect_asset(*asset))
+}
+
+// ACCOUNTS
+// ================================================================================================
+
+impl Inspect for Account {
+    fn inspect(&self) -> InspectNode {
+        InspectNode::new(format!("Account {}", self.id()))
+            .with_field("hash", hex(self.hash()))
+            .with_field("type", format!("{:?}", self.account_type()))
+            .with_field("storage type", format!("{:?}", self.id().storage_type()))
+            .with_field("nonce", self.nonce())
+            .with_child(inspect_code(self.code()))
+            .with_child(inspect_storage(self.storage()))
+            .with_child(inspect_vault(self.vault()))
+    }
+}
+
+fn inspect_code(code: &AccountCode) -> InspectNode {
+    InspectNode::field(&format!("code ({} procedures)", code.num_procedures()), hex(code.root()))
+        .with_children(code.procedures().iter().enumerate(), |(index, procedure)| {
+            InspectNode::field(&index.to_string(), hex(*procedure))
+        })
+}
+
+fn inspect_storage(storage: &AccountStorage) -> InspectNode {
+    // only the slots with a non-default type or value are rendered
+    let slots = storage.layout().iter().enumerate().filter_map(|(index, slot_type)| {
+        let value = storage.get_item(index as u8);
+        (*slot_type != Default::default() || value != Digest::default())
+            .then_some((index, slot_type, value))
+    });
+
+    InspectNode::field("storage", hex(storage.root())).with_children(
+        slots,
+        |(index, slot_type, value)| {
+            InspectNode::field(&format!("slot {index} ({slot_type:?})"), hex(value))
+        },
+    )
+}
+
+fn inspect_vault(vault: &AssetVault) -> InspectNode {
+    InspectNode::field("vault", hex(vault.commitment()))
+        .with_children(vault.assets(), inspect_asset)
+}
+
+impl Inspect for AccountDelta {
+    fn inspect(&self) -> InspectNode {
+        let nonce = match self.nonce() {
+            Some(nonce) => nonce.to_string(),
+            None => "unchanged".to_string(),
+        };
+        let storage = self.storage();
+        let vault = self.vault();
+
+        InspectNode::new("Account delta")
+            .with_field("nonce", nonce)
+            .with_child(
+                InspectNode::new("storage")
+                    .with_children(&storage.cleared_items, |index| {
+                        InspectNode::new(format!("{index}: cleared"))
+                    })
+                    .with_children(&storage.updated_items, |(index, value)| {
+                        InspectNode::field(&index.to_string(), hex(*value))
+                    })
+                    .with_children(&storage.updated_array_items, |(index, item_index, value)| {
+                        InspectNode::field(&format!("{index}[{item_index}]"), hex(*value))
+                    }),
+            )
+            .with_child(
+                InspectNode::new("vault")
+                    .with_children(&vault.added_assets, |asset| {
+                        let node = inspect_asset(*asset);
+                        InspectNode::new(format!("+ {}", node.label))
+                    })
+                    .with_children(&vault.removed_assets, |asset| {
+                        let node = inspect_asset(*asset);
+                        InspectNode::new(format!("- {}", node.label))
+                    }),
+            )
+    }
+}
+
+// ASSETS
+// ================================================================================================
+
+fn inspect_asset(asset: Asset) -> InspectNode {
+    match asset {
+        Asset::Fungible(asset) => {
+            InspectNode::new(format!("{} from faucet {}", asset.amount(), asset.faucet_id()))
+        },
+        Asset::NonFungible(asset) => InspectNode::new(format!(
+            "non-fungible {} from faucet {}",
+            hex(Word::from(asset)),
+            asset.faucet_id()
+        )),
+    }
+}
+
+// TRANSACTIONS
+// ================================================================================================
+
+impl Inspect for ProvenTransaction {
+    fn inspect(&self) -> InspectNode {
+        let account_details = match self.account_details() {
+            Some(AccountDetails::Full(account)) => {
+                InspectNode::new("account details (full)").with_child(account.inspect())
+            },
+            Some(AccountDetails::Delta(delta)) => {
+                InspectNode::new("account details (delta)").with_child(delta.inspect())
+            },
+            None => InspectNode::new("account details (none)"),
+        };
+        let tx_script_root = match self.tx_script_root() {
+            Some(root) => hex(root),
+            None => "none".to_string(),
+        };
+        let input_notes = self.input_notes();
+        let output_notes = self.output_notes();
+
+        InspectNode::new(format!("Transaction {}", self.id()))
+            .with_field("account", self.account_id())
+            .with_field("initial account hash", hex(self.initial_account_hash()))
+            .with_field("final account hash", hex(self.final_account_hash()))
+            .with_child(account_details)
+            .with_field("block ref", hex(self.block_ref()))
+            .with_field("tx script root", tx_script_root)
+            .with_child(
+                InspectNode::field(
+                    &format!("input notes ({})", input_notes.num_notes()),
+                    hex(input_notes.commitment()),
+                )
+                .with_children(input_notes.iter(), |nullifier| {
+                    InspectNode::field("nullifier", nullifier)
+                }),
+            )
+            .with_child(
+                InspectNode::field(
+                    &format!("output notes ({})", output_notes.num_notes()),
+                    hex(output_notes.commitment()),
+                )
+                .with_children(output_notes.iter(), inspect_output_note),
+            )
+            .with_child(
+                InspectNode::field(
+                    &format!("log ({} entries)", self.log().num_entries()),
+                    hex(self.log().commitment()),
+                )
+                .with_children(self.log().iter(), |entry| InspectNode::new(hex(entry.data()))),
+            )
+            .with_field("proof security level", self.proof().security_level())
+    }
+}
+
+fn inspect_output_note(note: &OutputNote) -> InspectNode {
+    let (label, node) = match note {
+        OutputNote::Public(note) => ("public", inspect_note_assets(note.assets())),
+        OutputNote::Private(_) => ("private", InspectNode::new("assets (hidden)")),
+    };
+
+    InspectNode::new(format!("{label} note {}", note.id()))
+        .with_child(inspect_metadata(note.metadata()))
+        .with_child(node)
+}
+
+// BLOCKS
+// ================================================================================================
+
+impl Inspect for BlockHeader {
+    fn inspect(&self) -> InspectNode {
+        InspectNode::new(format!("Block {}", self.block_num()))
+            .with_field("hash", hex(self.hash()))
+            .with_field("sub hash", hex(self.sub_hash()))
+            .with_field("prev hash", hex(self.prev_hash()))
+            .with_field("chain root", hex(self.chain_root()))
+            .with_field("account root", hex(self.account_root()))
+            .with_field("nullifier root", hex(self.nullifier_root()))
+            .with_field("note root", hex(self.note_root()))
+            .with_field("batch root", hex(self.batch_root()))
+            .with_field("proof hash", hex(self.proof_hash()))
+            .with_field("kernel config hash", hex(self.kernel_config_hash()))
+            .with_field("version", self.version())
+            .with_field("timestamp", self.timestamp())
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the hex encoding of the provided word or digest.
+fn hex(value: impl Into<Digest>) -> String {
+    value.into().to_hex()
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{Inspect, InspectNode};
+    use crate::{BlockHeader, Digest, Felt, ONE, ZERO};
+
+    #[test]
+    fn test_inspect_node_rendering() {
+        let node = InspectNode::new("root")
+            .with_child(InspectNode::new("a").with_field("b", 1).with_field("c", 2))
+            .with_child(InspectNode::new("d").with_child(InspectNode::new("e")));
+
+        let expected = "\
+root
+├── a
+│   ├── b: 1
+│   └── c: 2
+└── d
+    └── e
+";
+        assert_eq!(node.to_string(), expected);
+    }
+
+    #[test]
+    fn test_inspect_block_header() {
+        let header = BlockHeader::builder()
+            .block_num(2)
+            .prev_hash(Digest::from([ONE, ZERO, ZERO, ZERO]))
+            .timestamp(Felt::new(1000))
+            .build();
+
+        let node = header.inspect();
+        assert_eq!(node.label(), "Block 2");
+        assert_eq!(node.children()[0].label(), format!("hash: {}", header.hash().to_hex()));
+        assert_eq!(
+            node.children()[2].label(),
+            format!("prev hash: {}", header.prev_hash().to_hex())
+        );
+
+        let rendering = node.to_string();
+        assert_eq!(rendering.lines().count(), 13);
+        assert!(rendering.ends_with("└── timestamp: 1000\n"));
+    }
+}
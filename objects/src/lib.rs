@@ -10,19 +10,29 @@ pub mod accounts;
 pub mod assets;
 pub mod batches;
 pub mod block;
+pub mod crypto;
+pub mod encoding;
+pub mod inspect;
 pub mod notes;
 pub mod transaction;
 
+#[cfg(feature = "fuzzing")]
+pub mod fuzz;
+
 mod constants;
 mod errors;
 
 // RE-EXPORTS
 // ================================================================================================
 
-pub use block::BlockHeader;
+pub use block::{
+    AcceptedFeeAsset, BlockHeader, BlockHeaderBuilder, FeeParameters, KernelConfig, KernelLimits,
+};
 pub use constants::*;
 pub use errors::{
-    AccountDeltaError, AccountError, AssetError, AssetVaultError, ChainMmrError, NoteError,
+    AccountDeltaError, AccountError, AccountUpdateChainError, AssetError, AssetVaultError,
+    AuthError, BlockHeaderError, ChainMmrError, CheckpointError, EncodingError, KernelConfigError,
+    NoteError, NoteMetadataError, PaymentProofError, PersistedTransactionError,
     ProvenTransactionError, TransactionInputError, TransactionOutputError, TransactionScriptError,
 };
 pub use miden_crypto::hash::rpo::{Rpo256 as Hasher, RpoDigest as Digest};
@@ -32,14 +42,10 @@ pub mod assembly {
     pub use assembly::{
         ast::{AstSerdeOptions, ModuleAst, ProgramAst},
         Assembler, AssemblyContext, AssemblyError, Library, LibraryNamespace, LibraryPath,
-        MaslLibrary, Version,
+        MaslLibrary, Module, Version,
     };
 }
 
-pub mod crypto {
-    pub use miden_crypto::{dsa, hash, merkle, rand, utils};
-}
-
 pub mod utils {
     pub use miden_crypto::utils::{bytes_to_hex_string, collections, hex_to_bytes, HexParseError};
     pub use vm_core::utils::*;
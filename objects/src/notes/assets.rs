@@ -42,7 +42,7 @@ impl NoteAssets {
         if assets.is_empty() {
             return Err(NoteError::EmptyAssetList);
         } else if assets.len() > Self::MAX_NUM_ASSETS {
-            return Err(NoteError::too_many_assets(assets.len()));
+            return Err(NoteError::too_many_assets(Self::MAX_NUM_ASSETS, assets.len()));
         }
 
         // make sure all provided assets are unique
@@ -158,8 +158,22 @@ impl Serializable for NoteAssets {
 
 impl Deserializable for NoteAssets {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
-        let count = source.read_u8()? + 1;
-        let assets = source.read_many::<Asset>(count.into())?;
+        let count = source.read_u8()? as usize + 1;
+        let assets = source.read_many::<Asset>(count)?;
         Self::new(assets).map_err(|e| DeserializationError::InvalidValue(format!("{e:?}")))
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{Deserializable, NoteAssets};
+
+    #[test]
+    fn test_note_assets_max_count_deserialization() {
+        // a count byte of 255 denotes 256 assets, and must not overflow
+        assert!(NoteAssets::read_from_bytes(&[u8::MAX]).is_err());
+    }
+}
@@ -0,0 +1,138 @@
+use alloc::vec::Vec;
+
+use super::{
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, Felt, Hasher, NoteError,
+    ScriptInfo, Serializable, Word, ZERO,
+};
+use crate::crypto::dsa::rpo_falcon512::Signature;
+
+// SCRIPT ATTESTATION
+// ================================================================================================
+
+/// A signed statement of a script publisher that a note script with the specified root has the
+/// specified name, version, and documentation.
+///
+/// The publisher signs the commitment to the script (see [ScriptAttestation::commitment()]) with
+/// an RPO Falcon512 key. Wallets which trust the key of the publisher can verify the attestation
+/// and label the script accordingly (see
+/// [ScriptRegistry::attest()](super::ScriptRegistry::attest)), so that users can tell scripts
+/// attested by a known publisher apart from scripts which merely claim a well-known name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScriptAttestation {
+    script_root: Digest,
+    info: ScriptInfo,
+    docs_hash: Digest,
+    publisher: Word,
+    signature: Signature,
+}
+
+impl ScriptAttestation {
+    /// Returns a new [ScriptAttestation] of the script with the specified root, made by the
+    /// publisher with the specified public key.
+    ///
+    /// The public key is expected as a commitment to the RPO Falcon512 public key, and the
+    /// signature must be made over [ScriptAttestation::commitment()] of the script.
+    pub fn new(
+        script_root: Digest,
+        info: ScriptInfo,
+        docs_hash: Digest,
+        publisher: Word,
+        signature: Signature,
+    ) -> Self {
+        Self {
+            script_root,
+            info,
+            docs_hash,
+            publisher,
+            signature,
+        }
+    }
+
+    /// Returns the commitment to the script with the specified root, description, and hash of
+    /// the documentation, which is signed by the publisher of the script.
+    ///
+    /// The commitment is computed as hash(script_root, hash(name), [version, 0, 0, 0], docs_hash),
+    /// where the name is hashed as a sequence of bytes.
+    pub fn commitment(script_root: Digest, info: &ScriptInfo, docs_hash: Digest) -> Digest {
+        let name_hash = Hasher::hash(info.name().as_bytes());
+
+        let mut elements = Vec::with_capacity(16);
+        elements.extend_from_slice(script_root.as_elements());
+        elements.extend_from_slice(name_hash.as_elements());
+        elements.extend_from_slice(&[Felt::from(info.version()), ZERO, ZERO, ZERO]);
+        elements.extend_from_slice(docs_hash.as_elements());
+        Hasher::hash_elements(&elements)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the root of the attested script.
+    pub fn script_root(&self) -> Digest {
+        self.script_root
+    }
+
+    /// Returns the description of the attested script.
+    pub fn info(&self) -> &ScriptInfo {
+        &self.info
+    }
+
+    /// Returns the hash of the documentation of the attested script.
+    pub fn docs_hash(&self) -> Digest {
+        self.docs_hash
+    }
+
+    /// Returns the public key of the publisher which made this attestation.
+    pub fn publisher(&self) -> Word {
+        self.publisher
+    }
+
+    /// Returns the signature of the publisher over the commitment to the script.
+    pub fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    // VERIFICATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Verifies the signature of this attestation against the public key of its publisher.
+    ///
+    /// This does not establish whether the publisher is trusted; see
+    /// [ScriptRegistry::attest()](super::ScriptRegistry::attest).
+    ///
+    /// # Errors
+    /// Returns an error if the signature is not valid for the public key of the publisher.
+    pub fn verify(&self) -> Result<(), NoteError> {
+        let commitment = Self::commitment(self.script_root, &self.info, self.docs_hash);
+        if !self.signature.verify(commitment.into(), self.publisher) {
+            return Err(NoteError::InvalidScriptAttestation(self.script_root));
+        }
+
+        Ok(())
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for ScriptAttestation {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.script_root.write_into(target);
+        self.info.write_into(target);
+        self.docs_hash.write_into(target);
+        self.publisher.write_into(target);
+        self.signature.write_into(target);
+    }
+}
+
+impl Deserializable for ScriptAttestation {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let script_root = Digest::read_from(source)?;
+        let info = ScriptInfo::read_from(source)?;
+        let docs_hash = Digest::read_from(source)?;
+        let publisher = Word::read_from(source)?;
+        let signature = Signature::read_from(source)?;
+
+        Ok(Self::new(script_root, info, docs_hash, publisher, signature))
+    }
+}
@@ -0,0 +1,427 @@
+use alloc::{collections::BTreeMap, string::ToString, vec::Vec};
+
+use super::{
+    AccountId, ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, Felt, Hasher,
+    Note, NoteAssets, NoteError, NoteInputs, NoteMetadata, NoteRecipient, NoteScript, NoteTag,
+    NoteType, Serializable, Word, ZERO,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// Mask of the bits of the note flags which encode the note type.
+const NOTE_TYPE_MASK: u8 = 0b11;
+
+/// Note flag set if the aux field of the note is not zero.
+const HAS_AUX_FLAG: u8 = 0b100;
+
+/// Note flag set if the note expires.
+const HAS_EXPIRATION_FLAG: u8 = 0b1000;
+
+/// Note flag set if the note has a TTL.
+const HAS_TTL_FLAG: u8 = 0b10000;
+
+// COMPRESSED NOTE BATCH
+// ================================================================================================
+
+/// A compact encoding of a list of notes, meant to reduce the size of sync payloads containing
+/// many public notes.
+///
+/// The batch is compressed as follows:
+/// - Note scripts are stored once per script root, and notes refer to their script by index.
+/// - Senders are stored once per account, and notes refer to their sender by index.
+/// - Note tags are encoded as the difference to the tag of the preceding note, and the note type,
+///   the presence of the aux field, the presence of an expiration and the presence of a TTL are
+///   packed into a single byte.
+///
+/// The batch also contains a commitment to the IDs and metadata of the compressed notes, computed
+/// in the same way as the commitment to the output notes of a transaction. Decompressing a batch
+/// via [CompressedNoteBatch::decompress()] recomputes this commitment, so that a corrupted or
+/// tampered batch is detected.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CompressedNoteBatch {
+    commitment: Digest,
+    scripts: Vec<NoteScript>,
+    senders: Vec<AccountId>,
+    notes: Vec<CompressedNote>,
+}
+
+impl CompressedNoteBatch {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [CompressedNoteBatch] encoding the provided notes.
+    ///
+    /// The order of the notes is preserved by the batch.
+    pub fn new(notes: &[Note]) -> Self {
+        let commitment = compute_notes_commitment(notes.iter());
+
+        let mut scripts = Vec::new();
+        let mut script_indexes = BTreeMap::new();
+        let mut senders = Vec::new();
+        let mut sender_indexes = BTreeMap::new();
+
+        let notes = notes
+            .iter()
+            .map(|note| {
+                let script = note.script();
+                let script_idx = *script_indexes.entry(script.hash()).or_insert_with(|| {
+                    scripts.push(script.clone());
+                    scripts.len() - 1
+                });
+
+                let sender = note.metadata().sender();
+                let sender_idx = *sender_indexes.entry(sender).or_insert_with(|| {
+                    senders.push(sender);
+                    senders.len() - 1
+                });
+
+                CompressedNote {
+                    script_idx,
+                    sender_idx,
+                    note_type: note.metadata().note_type(),
+                    tag: note.metadata().tag().inner(),
+                    aux: note.metadata().aux(),
+                    expiration: note.metadata().expiration(),
+                    ttl: note.metadata().ttl(),
+                    inputs: note.inputs().clone(),
+                    serial_num: note.serial_num(),
+                    assets: note.assets().clone(),
+                }
+            })
+            .collect();
+
+        Self { commitment, scripts, senders, notes }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the commitment to the IDs and metadata of the notes in this batch.
+    pub fn commitment(&self) -> Digest {
+        self.commitment
+    }
+
+    /// Returns the number of notes in this batch.
+    pub fn num_notes(&self) -> usize {
+        self.notes.len()
+    }
+
+    /// Returns the number of distinct note scripts in this batch.
+    pub fn num_scripts(&self) -> usize {
+        self.scripts.len()
+    }
+
+    // DECOMPRESSION
+    // --------------------------------------------------------------------------------------------
+
+    /// Decompresses this batch into the list of notes it encodes.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The metadata of any of the notes is invalid.
+    /// - The commitment to the decompressed notes does not match the commitment of this batch.
+    pub fn decompress(&self) -> Result<Vec<Note>, NoteError> {
+        let notes = self
+            .notes
+            .iter()
+            .map(|note| {
+                let mut metadata = NoteMetadata::new(
+                    self.senders[note.sender_idx],
+                    note.note_type,
+                    NoteTag::from(note.tag),
+                    note.aux,
+                )?;
+                if let Some(expiration) = note.expiration {
+                    metadata = metadata.with_expiration(expiration)?;
+                }
+                if let Some(ttl) = note.ttl {
+                    metadata = metadata.with_ttl(ttl)?;
+                }
+
+                let recipient = NoteRecipient::new(
+                    note.serial_num,
+                    self.scripts[note.script_idx].clone(),
+                    note.inputs.clone(),
+                );
+
+                Ok(Note::new(note.assets.clone(), metadata, recipient))
+            })
+            .collect::<Result<Vec<_>, NoteError>>()?;
+
+        let commitment = compute_notes_commitment(notes.iter());
+        if commitment != self.commitment {
+            return Err(NoteError::NoteBatchCommitmentMismatch {
+                expected: self.commitment,
+                actual: commitment,
+            });
+        }
+
+        Ok(notes)
+    }
+}
+
+// COMPRESSED NOTE
+// ================================================================================================
+
+/// A note in a [CompressedNoteBatch], which refers to its script and sender by index.
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct CompressedNote {
+    script_idx: usize,
+    sender_idx: usize,
+    note_type: NoteType,
+    tag: u32,
+    aux: Felt,
+    expiration: Option<u32>,
+    ttl: Option<u32>,
+    inputs: NoteInputs,
+    serial_num: Word,
+    assets: NoteAssets,
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns a sequential hash of the (note_id, metadata) tuples of the provided notes.
+fn compute_notes_commitment<'a>(notes: impl Iterator<Item = &'a Note>) -> Digest {
+    let mut elements: Vec<Felt> = Vec::new();
+    for note in notes {
+        elements.extend_from_slice(note.id().as_elements());
+        elements.extend_from_slice(&Word::from(note.metadata()));
+    }
+
+    Hasher::hash_elements(&elements)
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for CompressedNoteBatch {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.commitment.write_into(target);
+
+        target.write_usize(self.scripts.len());
+        target.write_many(&self.scripts);
+        target.write_usize(self.senders.len());
+        target.write_many(&self.senders);
+
+        target.write_usize(self.notes.len());
+        let mut prev_tag = 0_u32;
+        for note in self.notes.iter() {
+            target.write_usize(note.script_idx);
+            target.write_usize(note.sender_idx);
+
+            let mut flags = note.note_type as u8;
+            if note.aux != ZERO {
+                flags |= HAS_AUX_FLAG;
+            }
+            if note.expiration.is_some() {
+                flags |= HAS_EXPIRATION_FLAG;
+            }
+            if note.ttl.is_some() {
+                flags |= HAS_TTL_FLAG;
+            }
+            target.write_u8(flags);
+
+            target.write_usize(note.tag.wrapping_sub(prev_tag) as usize);
+            prev_tag = note.tag;
+            if note.aux != ZERO {
+                note.aux.write_into(target);
+            }
+            if let Some(expiration) = note.expiration {
+                target.write_u32(expiration);
+            }
+            if let Some(ttl) = note.ttl {
+                target.write_u32(ttl);
+            }
+
+            note.inputs.write_into(target);
+            note.serial_num.write_into(target);
+            note.assets.write_into(target);
+        }
+    }
+}
+
+impl Deserializable for CompressedNoteBatch {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let commitment = Digest::read_from(source)?;
+
+        let num_scripts = source.read_usize()?;
+        let scripts = source.read_many::<NoteScript>(num_scripts)?;
+        let num_senders = source.read_usize()?;
+        let senders = source.read_many::<AccountId>(num_senders)?;
+
+        let num_notes = source.read_usize()?;
+        let mut notes = Vec::with_capacity(num_notes);
+        let mut prev_tag = 0_u32;
+        for _ in 0..num_notes {
+            let script_idx = source.read_usize()?;
+            if script_idx >= scripts.len() {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "script index {script_idx} out of bounds for {} scripts",
+                    scripts.len()
+                )));
+            }
+            let sender_idx = source.read_usize()?;
+            if sender_idx >= senders.len() {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "sender index {sender_idx} out of bounds for {} senders",
+                    senders.len()
+                )));
+            }
+
+            let flags = source.read_u8()?;
+            if flags & !(NOTE_TYPE_MASK | HAS_AUX_FLAG | HAS_EXPIRATION_FLAG | HAS_TTL_FLAG) != 0 {
+                return Err(DeserializationError::InvalidValue(format!(
+                    "invalid note flags {flags:#b}"
+                )));
+            }
+            let note_type = NoteType::try_from(flags & NOTE_TYPE_MASK)
+                .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+
+            let tag_delta = u32::try_from(source.read_usize()?).map_err(|_| {
+                DeserializationError::InvalidValue("tag delta does not fit into u32".to_string())
+            })?;
+            let tag = prev_tag.wrapping_add(tag_delta);
+            prev_tag = tag;
+            let aux = if flags & HAS_AUX_FLAG != 0 {
+                Felt::read_from(source)?
+            } else {
+                ZERO
+            };
+            let expiration = if flags & HAS_EXPIRATION_FLAG != 0 {
+                Some(source.read_u32()?)
+            } else {
+                None
+            };
+            let ttl = if flags & HAS_TTL_FLAG != 0 {
+                Some(source.read_u32()?)
+            } else {
+                None
+            };
+
+            notes.push(CompressedNote {
+                script_idx,
+                sender_idx,
+                note_type,
+                tag,
+                aux,
+                expiration,
+                ttl,
+                inputs: NoteInputs::read_from(source)?,
+                serial_num: Word::read_from(source)?,
+                assets: NoteAssets::read_from(source)?,
+            });
+        }
+
+        Ok(Self { commitment, scripts, senders, notes })
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use assembly::{ast::ProgramAst, Assembler};
+
+    use super::CompressedNoteBatch;
+    use crate::{
+        accounts::{
+            AccountId, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+            ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN, ACCOUNT_ID_SENDER,
+        },
+        assets::FungibleAsset,
+        notes::{
+            Note, NoteAssets, NoteInputs, NoteMetadata, NoteRecipient, NoteScript, NoteTag,
+            NoteType,
+        },
+        utils::serde::{Deserializable, Serializable},
+        Felt, NoteError, ONE, ZERO,
+    };
+
+    fn build_notes() -> Vec<Note> {
+        let assembler = Assembler::default();
+        let scripts = ["begin push.1 drop end", "begin push.2 drop end"]
+            .map(|code| NoteScript::new(ProgramAst::parse(code).unwrap(), &assembler).unwrap().0);
+        let senders = [ACCOUNT_ID_SENDER, ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN]
+            .map(|sender| AccountId::try_from(sender).unwrap());
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+
+        (0..10_u32)
+            .map(|i| {
+                let aux = if i % 3 == 0 { ZERO } else { Felt::from(i) };
+                let mut metadata = NoteMetadata::new(
+                    senders[(i % 2) as usize],
+                    NoteType::Public,
+                    NoteTag::from(100 - i),
+                    aux,
+                )
+                .unwrap();
+                if i % 4 == 0 {
+                    metadata = metadata.with_expiration(1000 + i).unwrap();
+                }
+                if i % 5 == 0 {
+                    metadata = metadata.with_ttl(500 + i).unwrap();
+                }
+
+                let asset = FungibleAsset::new(faucet_id, 10 + i as u64).unwrap();
+                let inputs = NoteInputs::new(vec![Felt::from(i)]).unwrap();
+                let serial_num = [Felt::from(i), ONE, ZERO, ZERO];
+                let recipient =
+                    NoteRecipient::new(serial_num, scripts[(i % 4 == 3) as usize].clone(), inputs);
+                Note::new(NoteAssets::new(vec![asset.into()]).unwrap(), metadata, recipient)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn compressed_note_batch_round_trip() {
+        let notes = build_notes();
+        let batch = CompressedNoteBatch::new(&notes);
+        assert_eq!(batch.num_notes(), notes.len());
+        assert_eq!(batch.num_scripts(), 2);
+        assert_eq!(batch.decompress().unwrap(), notes);
+
+        let bytes = batch.to_bytes();
+        let deserialized = CompressedNoteBatch::read_from_bytes(&bytes).unwrap();
+        assert_eq!(deserialized, batch);
+        assert_eq!(deserialized.decompress().unwrap(), notes);
+
+        // the batch is smaller than the serialized notes
+        assert!(bytes.len() < notes.to_bytes().len());
+
+        let empty = CompressedNoteBatch::new(&[]);
+        let empty = CompressedNoteBatch::read_from_bytes(&empty.to_bytes()).unwrap();
+        assert_eq!(empty.decompress().unwrap(), Vec::new());
+    }
+
+    #[test]
+    fn compressed_note_batch_detects_tampering() {
+        let notes = build_notes();
+        let batch = CompressedNoteBatch::new(&notes);
+
+        // the commitment must match the decompressed notes
+        let mut tampered = CompressedNoteBatch::new(&notes[1..]);
+        tampered.commitment = batch.commitment();
+        assert!(matches!(
+            tampered.decompress(),
+            Err(NoteError::NoteBatchCommitmentMismatch { .. })
+        ));
+
+        // and so must the metadata of the notes
+        let mut tampered = batch.clone();
+        tampered.notes[2].tag += 1;
+        assert!(matches!(
+            tampered.decompress(),
+            Err(NoteError::NoteBatchCommitmentMismatch { .. })
+        ));
+
+        // indexes out of bounds are rejected during deserialization
+        let mut tampered = batch.clone();
+        tampered.notes[0].script_idx = 2;
+        assert!(CompressedNoteBatch::read_from_bytes(&tampered.to_bytes()).is_err());
+    }
+}
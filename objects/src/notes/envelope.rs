@@ -54,6 +54,28 @@ impl From<NoteEnvelope> for [Word; 2] {
     }
 }
 
+impl TryFrom<[Word; 2]> for NoteEnvelope {
+    type Error = NoteError;
+
+    /// Decodes a note envelope from the note ID and the note metadata words, e.g., as stored in
+    /// the note tree of a block.
+    fn try_from(elements: [Word; 2]) -> Result<Self, Self::Error> {
+        let note_id = NoteId::from(elements[0]);
+        let note_metadata = NoteMetadata::try_from(elements[1])?;
+        Self::new(note_id, note_metadata)
+    }
+}
+
+impl TryFrom<[Felt; 8]> for NoteEnvelope {
+    type Error = NoteError;
+
+    fn try_from(elements: [Felt; 8]) -> Result<Self, Self::Error> {
+        let note_id = [elements[0], elements[1], elements[2], elements[3]];
+        let note_metadata = [elements[4], elements[5], elements[6], elements[7]];
+        Self::try_from([note_id, note_metadata])
+    }
+}
+
 impl From<NoteEnvelope> for [u8; 64] {
     fn from(note_envelope: NoteEnvelope) -> Self {
         (&note_envelope).into()
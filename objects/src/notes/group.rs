@@ -0,0 +1,260 @@
+use alloc::{collections::BTreeSet, vec::Vec};
+
+use super::{
+    AccountId, ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, Felt,
+    NoteError, NoteTag, Serializable, Word, ZERO,
+};
+use crate::crypto::merkle::{InnerNodeInfo, LeafIndex, MerklePath, SimpleSmt};
+
+// CONSTANTS
+// ================================================================================================
+
+/// The depth of the Merkle tree committing to the members of an [AccountGroup].
+pub const GROUP_TREE_DEPTH: u8 = 20;
+
+// ACCOUNT GROUP
+// ================================================================================================
+
+/// A set of accounts which can be targeted by a note as a whole, e.g., the members of a DAO.
+///
+/// A group is committed to by the root of a Merkle tree whose leaf at index `i` is
+/// [account_id, 0, 0, 0] for the `i`-th member of the group; all other leaves are empty. Notes
+/// targeting the group store the root in their inputs, and the consuming account proves its
+/// membership via a [GroupMembership].
+///
+/// Each group has a group ID derived from its root, which notes targeting the group can use as
+/// their tag (see [NoteTag::for_group()]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountGroup {
+    members: Vec<AccountId>,
+    tree: SimpleSmt<GROUP_TREE_DEPTH>,
+}
+
+impl AccountGroup {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [AccountGroup] with the specified members, in the specified order.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The group has no members.
+    /// - Any of the accounts is specified more than once.
+    /// - The group has more than 2^[GROUP_TREE_DEPTH] members.
+    pub fn new(members: Vec<AccountId>) -> Result<Self, NoteError> {
+        if members.is_empty() {
+            return Err(NoteError::EmptyAccountGroup);
+        }
+
+        let max_members = 1_u64 << GROUP_TREE_DEPTH;
+        if members.len() as u64 > max_members {
+            return Err(NoteError::TooManyGroupMembers { max: max_members, actual: members.len() });
+        }
+
+        let mut unique_members = BTreeSet::new();
+        if let Some(member) = members.iter().find(|member| !unique_members.insert(**member)) {
+            return Err(NoteError::DuplicateGroupMember(*member));
+        }
+
+        let leaves = members.iter().enumerate().map(|(idx, member)| (idx as u64, leaf(*member)));
+        let tree = SimpleSmt::with_leaves(leaves).expect("leaf indexes are unique and in bounds");
+
+        Ok(Self { members, tree })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the members of this group.
+    pub fn members(&self) -> &[AccountId] {
+        &self.members
+    }
+
+    /// Returns the root of the Merkle tree committing to the members of this group.
+    pub fn root(&self) -> Digest {
+        self.tree.root()
+    }
+
+    /// Returns the ID of this group, which is derived from the root of the group.
+    ///
+    /// The ID is between 1 and [NoteTag::MAX_GROUP_ID], as the ID 0 is reserved for the broadcast
+    /// tag. Since the ID space is small, distinct groups may share an ID; notes targeting a group
+    /// are therefore bound to the group by its root, while the ID only serves for note discovery.
+    pub fn group_id(&self) -> u32 {
+        (self.root()[0].as_int() % NoteTag::MAX_GROUP_ID as u64) as u32 + 1
+    }
+
+    /// Returns the tag of notes targeting this group.
+    pub fn tag(&self) -> NoteTag {
+        NoteTag::for_group(self.group_id()).expect("group ID is within bounds")
+    }
+
+    /// Returns the proof that the specified account is a member of this group, or None if the
+    /// account is not a member of this group.
+    pub fn membership(&self, account_id: AccountId) -> Option<GroupMembership> {
+        let index = self.members.iter().position(|member| *member == account_id)? as u64;
+        let leaf_index = LeafIndex::new(index).expect("member index is within bounds");
+
+        Some(GroupMembership {
+            account_id,
+            index,
+            path: self.tree.open(&leaf_index).path,
+        })
+    }
+}
+
+// GROUP MEMBERSHIP
+// ================================================================================================
+
+/// A proof that an account is a member of an [AccountGroup], verifiable against the root of the
+/// group.
+///
+/// To consume a note targeting the group, the consuming account provides its index in the group
+/// via the note args (see [GroupMembership::note_args()]) and the nodes of the proof via the
+/// Merkle store of the transaction (see [GroupMembership::inner_nodes()]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupMembership {
+    account_id: AccountId,
+    index: u64,
+    path: MerklePath,
+}
+
+impl GroupMembership {
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the ID of the account whose membership is proven.
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Returns the index of the account in the group.
+    pub fn index(&self) -> u64 {
+        self.index
+    }
+
+    /// Returns the note args which provide the index of the account to a note script verifying
+    /// the membership, i.e., [index, 0, 0, 0].
+    pub fn note_args(&self) -> Word {
+        [Felt::new(self.index), ZERO, ZERO, ZERO]
+    }
+
+    /// Returns the nodes of the Merkle tree of the group which are required to verify the
+    /// membership.
+    pub fn inner_nodes(&self) -> impl Iterator<Item = InnerNodeInfo> + '_ {
+        self.path
+            .inner_nodes(self.index, leaf(self.account_id).into())
+            .expect("index is within the depth of the path")
+    }
+
+    // VERIFICATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns true if this proof proves the membership of the account in the group with the
+    /// specified root.
+    pub fn verify(&self, group_root: Digest) -> bool {
+        self.path.depth() == GROUP_TREE_DEPTH
+            && self.path.verify(self.index, leaf(self.account_id).into(), &group_root)
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for GroupMembership {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.account_id.write_into(target);
+        target.write_u64(self.index);
+        self.path.write_into(target);
+    }
+}
+
+impl Deserializable for GroupMembership {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let account_id = AccountId::read_from(source)?;
+        let index = source.read_u64()?;
+        let path = MerklePath::read_from(source)?;
+
+        Ok(Self { account_id, index, path })
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the leaf of the group tree for the specified member.
+fn leaf(account_id: AccountId) -> Word {
+    [account_id.into(), ZERO, ZERO, ZERO]
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{AccountGroup, GroupMembership};
+    use crate::{
+        accounts::{
+            AccountId, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN,
+            ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN, ACCOUNT_ID_SENDER,
+        },
+        notes::{NoteTag, NoteType},
+        utils::serde::{Deserializable, Serializable},
+        NoteError,
+    };
+
+    #[test]
+    fn test_group_membership() {
+        let member_1 = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let member_2 =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+        let outsider = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+
+        let group = AccountGroup::new(vec![member_1, member_2]).unwrap();
+        let other_group = AccountGroup::new(vec![member_2, member_1]).unwrap();
+        assert_ne!(group.root(), other_group.root());
+
+        let membership = group.membership(member_2).unwrap();
+        assert_eq!(membership.index(), 1);
+        assert!(membership.verify(group.root()));
+        assert!(!membership.verify(other_group.root()));
+        assert!(group.membership(outsider).is_none());
+
+        let bytes = membership.to_bytes();
+        assert_eq!(membership, GroupMembership::read_from_bytes(&bytes).unwrap());
+
+        assert_eq!(AccountGroup::new(vec![]), Err(NoteError::EmptyAccountGroup));
+        assert_eq!(
+            AccountGroup::new(vec![member_1, member_2, member_1]),
+            Err(NoteError::DuplicateGroupMember(member_1))
+        );
+    }
+
+    #[test]
+    fn test_group_tags() {
+        let member = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let group = AccountGroup::new(vec![member]).unwrap();
+
+        let tag = group.tag();
+        assert_eq!(tag.group_id(), Some(group.group_id()));
+        assert!(!tag.is_single_target());
+        assert!(!tag.is_broadcast());
+        assert!(tag.validate(NoteType::Public).is_ok());
+        assert!(tag.validate(NoteType::OffChain).is_err());
+
+        let broadcast = NoteTag::broadcast();
+        assert!(broadcast.is_broadcast());
+        assert_eq!(broadcast.group_id(), None);
+        assert!(broadcast.validate(NoteType::Public).is_ok());
+
+        assert_eq!(NoteTag::for_group(0), Err(NoteError::InvalidGroupId(0)));
+        assert_eq!(
+            NoteTag::for_group(NoteTag::MAX_GROUP_ID + 1),
+            Err(NoteError::InvalidGroupId(NoteTag::MAX_GROUP_ID + 1))
+        );
+        assert_eq!(
+            NoteTag::for_group(NoteTag::MAX_GROUP_ID).unwrap().inner(),
+            u32::MAX - 0x4000_0000
+        );
+    }
+}
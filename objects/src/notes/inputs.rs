@@ -35,7 +35,7 @@ impl NoteInputs {
     /// Returns an error if the number of provided inputs is greater than 128.
     pub fn new(values: Vec<Felt>) -> Result<Self, NoteError> {
         if values.len() > Self::MAX_INPUTS_PER_NOTE {
-            return Err(NoteError::too_many_inputs(values.len()));
+            return Err(NoteError::too_many_inputs(Self::MAX_INPUTS_PER_NOTE, values.len()));
         }
 
         let hash = {
@@ -145,7 +145,7 @@ impl Deserializable for NoteInputs {
 mod tests {
     use miden_crypto::utils::Deserializable;
 
-    use super::{Felt, NoteInputs, Serializable};
+    use super::{Felt, NoteError, NoteInputs, Serializable, ZERO};
 
     #[test]
     fn test_input_ordering() {
@@ -158,6 +158,16 @@ mod tests {
         assert_eq!(&expected_ordering, &note_inputs.values);
     }
 
+    #[test]
+    fn test_too_many_inputs() {
+        let max = NoteInputs::MAX_INPUTS_PER_NOTE;
+        assert!(NoteInputs::new(vec![ZERO; max]).is_ok());
+        assert_eq!(
+            NoteInputs::new(vec![ZERO; max + 1]),
+            Err(NoteError::TooManyInputs { max, actual: max + 1 })
+        );
+    }
+
     #[test]
     fn test_input_serialization() {
         let inputs = vec![Felt::new(1), Felt::new(2), Felt::new(3)];
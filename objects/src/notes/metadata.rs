@@ -1,15 +1,22 @@
 use alloc::string::ToString;
 
 use super::{
-    AccountId, ByteReader, ByteWriter, Deserializable, DeserializationError, Felt, NoteError,
-    NoteTag, NoteType, Serializable, Word,
+    validate_note_emission, AccountId, ByteReader, ByteWriter, Deserializable,
+    DeserializationError, Felt, NoteError, NoteTag, NoteType, Serializable, Word,
 };
+use crate::{NoteMetadataError, MAX_NOTE_TTL};
 
 // CONSTANTS
 // ================================================================================================
 const NETWORK_EXECUTION: u8 = 0;
 const LOCAL_EXECUTION: u8 = 1;
 
+/// The bits of the note type element of an encoded metadata which contain the note type.
+const NOTE_TYPE_MASK: u64 = 0xff;
+
+/// The offset of the TTL in the note type element of an encoded metadata.
+const TTL_OFFSET: u64 = 8;
+
 /// Determines if a note is intended to be consumed by the network or not.
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -30,6 +37,22 @@ pub enum NoteExecutionMode {
 /// - For public notes, the second most significant bit of the tag must be 0.
 /// - For encrypted notes, two most significant bits of the tag must be 00.
 ///
+/// Additionally, the sender must be allowed to emit a note with the specified type and tag (see
+/// [validate_note_emission()](super::validate_note_emission)).
+///
+/// A note may optionally specify an expiration block height. Such a note cannot be consumed by
+/// transactions executed against a block with a number greater than the expiration block height,
+/// which allows the nodes to prune expired public notes.
+///
+/// A public note may also specify a time-to-live (TTL), i.e., the number of blocks following the
+/// block in which the note was created during which the nodes keep the details of the note. Once
+/// the TTL has elapsed, the nodes may prune the details of the note; the note can still be
+/// consumed by anyone who knows its details (see [NoteMetadata::is_prunable()]).
+///
+/// When converted into a [Word], the metadata is encoded as [tag, sender, note_type_and_exp, aux],
+/// where the lowest 8 bits of `note_type_and_exp` contain the note type, the following 24 bits
+/// contain the TTL (or 0 if the note has no TTL), and the upper 32 bits contain the expiration
+/// block height (or 0 if the note does not expire).
 #[derive(Clone, Copy, Debug, Eq, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct NoteMetadata {
@@ -44,21 +67,70 @@ pub struct NoteMetadata {
 
     /// An arbitrary user-defined value.
     aux: Felt,
+
+    /// The number of the last block in which the note can be consumed, if any.
+    expiration: Option<u32>,
+
+    /// The number of blocks during which the details of a public note are kept by the nodes, if
+    /// any.
+    ttl: Option<u32>,
 }
 
 impl NoteMetadata {
     /// Returns a new [NoteMetadata] instantiated with the specified parameters.
     ///
     /// # Errors
-    /// Returns an error if the note type and note tag are inconsistent.
+    /// Returns an error if the sender is not allowed to emit a note with the specified type and
+    /// tag (see [validate_note_emission()](super::validate_note_emission)).
     pub fn new(
         sender: AccountId,
         note_type: NoteType,
         tag: NoteTag,
         aux: Felt,
     ) -> Result<Self, NoteError> {
-        let tag = tag.validate(note_type)?;
-        Ok(Self { sender, note_type, tag, aux })
+        validate_note_emission(sender.storage_type(), note_type, tag)?;
+        Ok(Self {
+            sender,
+            note_type,
+            tag,
+            aux,
+            expiration: None,
+            ttl: None,
+        })
+    }
+
+    /// Returns a copy of this [NoteMetadata] with the expiration block height set to the specified
+    /// block number.
+    ///
+    /// # Errors
+    /// Returns an error if the expiration block number is 0 or [u32::MAX], as these values cannot
+    /// be encoded in the metadata.
+    pub fn with_expiration(mut self, block_num: u32) -> Result<Self, NoteError> {
+        if block_num == 0 || block_num == u32::MAX {
+            return Err(NoteError::InvalidNoteExpiration(block_num));
+        }
+
+        self.expiration = Some(block_num);
+        Ok(self)
+    }
+
+    /// Returns a copy of this [NoteMetadata] with the TTL set to the specified number of blocks.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The note is not public, as only the details of public notes are kept by the nodes.
+    /// - The TTL is 0 or greater than [MAX_NOTE_TTL], as these values cannot be encoded in the
+    ///   metadata.
+    pub fn with_ttl(mut self, ttl: u32) -> Result<Self, NoteError> {
+        if self.note_type != NoteType::Public {
+            return Err(NoteError::NoteTtlRequiresPublicNote(self.note_type));
+        }
+        if ttl == 0 || ttl > MAX_NOTE_TTL {
+            return Err(NoteError::InvalidNoteTtl(ttl));
+        }
+
+        self.ttl = Some(ttl);
+        Ok(self)
     }
 
     /// Returns the account which created the note.
@@ -80,6 +152,40 @@ impl NoteMetadata {
     pub fn aux(&self) -> Felt {
         self.aux
     }
+
+    /// Returns the number of the last block in which the note can be consumed, or None if the
+    /// note does not expire.
+    pub fn expiration(&self) -> Option<u32> {
+        self.expiration
+    }
+
+    /// Returns true if the note cannot be consumed by transactions executed against the block with
+    /// the specified number.
+    pub fn is_expired(&self, block_num: u32) -> bool {
+        self.expiration.is_some_and(|expiration| block_num > expiration)
+    }
+
+    /// Returns the number of blocks during which the details of the note are kept by the nodes,
+    /// or None if the note has no TTL.
+    pub fn ttl(&self) -> Option<u32> {
+        self.ttl
+    }
+
+    /// Returns true if the nodes may prune the details of the note, which was created in the
+    /// block with number `created_in`, once the block with the specified number has been produced.
+    ///
+    /// The details of a public note may be pruned if its TTL has elapsed, or if the note has
+    /// expired and thus can no longer be consumed. The details of other notes are not kept by the
+    /// nodes, and thus are never prunable.
+    pub fn is_prunable(&self, created_in: u32, block_num: u32) -> bool {
+        if self.note_type != NoteType::Public {
+            return false;
+        }
+
+        let ttl_elapsed =
+            self.ttl.is_some_and(|ttl| block_num as u64 > created_in as u64 + ttl as u64);
+        ttl_elapsed || self.is_expired(block_num)
+    }
 }
 
 impl From<NoteMetadata> for Word {
@@ -93,7 +199,11 @@ impl From<&NoteMetadata> for Word {
         let mut elements = Word::default();
         elements[0] = metadata.tag.inner().into();
         elements[1] = metadata.sender.into();
-        elements[2] = metadata.note_type.into();
+        elements[2] = Felt::new(
+            ((metadata.expiration.unwrap_or(0) as u64) << 32)
+                | ((metadata.ttl.unwrap_or(0) as u64) << TTL_OFFSET)
+                | metadata.note_type as u64,
+        );
         elements[3] = metadata.aux;
         elements
     }
@@ -102,13 +212,51 @@ impl From<&NoteMetadata> for Word {
 impl TryFrom<Word> for NoteMetadata {
     type Error = NoteError;
 
+    /// Decodes note metadata from the provided word.
+    ///
+    /// # Errors
+    /// Returns [NoteError::InvalidNoteMetadata] describing the invalid element if:
+    /// - The tag does not fit into 32 bits.
+    /// - The sender is not a valid account ID.
+    /// - The lowest 8 bits of the third element do not encode a valid note type.
+    /// - The third element encodes a TTL for a note which is not public.
+    /// - The tag is inconsistent with the note type.
     fn try_from(elements: Word) -> Result<Self, Self::Error> {
-        let sender = elements[1].try_into().map_err(NoteError::InvalidNoteSender)?;
-        let note_type = elements[2].try_into()?;
-        let tag: u64 = elements[0].into();
-        let tag: u32 =
-            tag.try_into().map_err(|_| NoteError::InconsistentNoteTag(note_type, tag))?;
-        Self::new(sender, note_type, tag.into(), elements[3])
+        let tag = elements[0].as_int();
+        let tag = u32::try_from(tag).map_err(|_| NoteMetadataError::TagNotU32(tag))?;
+
+        let sender = AccountId::try_from(elements[1]).map_err(NoteMetadataError::InvalidSender)?;
+
+        let note_type_and_exp = elements[2].as_int();
+        let note_type = NoteType::try_from((note_type_and_exp & NOTE_TYPE_MASK) as u32)
+            .map_err(|_| NoteMetadataError::InvalidNoteType(note_type_and_exp & NOTE_TYPE_MASK))?;
+        let ttl = match ((note_type_and_exp & u32::MAX as u64) >> TTL_OFFSET) as u32 {
+            0 => None,
+            _ if note_type != NoteType::Public => {
+                return Err(NoteMetadataError::TtlRequiresPublicNote(note_type).into())
+            },
+            ttl => Some(ttl),
+        };
+        // an expiration of u32::MAX cannot be encoded together with a valid note type, as the
+        // resulting value would not be smaller than the field modulus
+        let expiration = match (note_type_and_exp >> 32) as u32 {
+            0 => None,
+            block_num => Some(block_num),
+        };
+
+        let invalid_bits = tag & ((note_type as u32) << 30);
+        if invalid_bits != 0 {
+            return Err(NoteMetadataError::InconsistentTag { note_type, tag, invalid_bits }.into());
+        }
+
+        Ok(Self {
+            sender,
+            note_type,
+            tag: tag.into(),
+            aux: elements[3],
+            expiration,
+            ttl,
+        })
     }
 }
 
@@ -121,6 +269,8 @@ impl Serializable for NoteMetadata {
         self.note_type.write_into(target);
         self.tag.write_into(target);
         self.aux.write_into(target);
+        self.expiration.write_into(target);
+        self.ttl.write_into(target);
     }
 }
 
@@ -130,8 +280,168 @@ impl Deserializable for NoteMetadata {
         let note_type = NoteType::read_from(source)?;
         let tag = NoteTag::read_from(source)?;
         let aux = Felt::read_from(source)?;
+        let expiration = Option::<u32>::read_from(source)?;
+        let ttl = Option::<u32>::read_from(source)?;
+
+        let mut metadata = Self::new(sender, note_type, tag, aux)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+        if let Some(block_num) = expiration {
+            metadata = metadata
+                .with_expiration(block_num)
+                .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+        }
+        if let Some(ttl) = ttl {
+            metadata = metadata
+                .with_ttl(ttl)
+                .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+        }
+
+        Ok(metadata)
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{NoteMetadata, NoteTag, NoteType, Word};
+    use crate::{
+        accounts::{AccountId, ACCOUNT_ID_SENDER},
+        utils::serde::{Deserializable, Serializable},
+        Felt, NoteError, NoteMetadataError, MAX_NOTE_TTL, ONE,
+    };
+
+    #[test]
+    fn note_metadata_expiration() {
+        let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let metadata =
+            NoteMetadata::new(sender, NoteType::OffChain, NoteTag::from(0), ONE).unwrap();
+        assert_eq!(metadata.expiration(), None);
+        assert!(!metadata.is_expired(u32::MAX));
+
+        // metadata without expiration is encoded in the same way as before
+        let word = Word::from(metadata);
+        assert_eq!(word[2], NoteType::OffChain.into());
+
+        let expiring = metadata.with_expiration(10).unwrap();
+        assert!(!expiring.is_expired(10));
+        assert!(expiring.is_expired(11));
+        assert_eq!(NoteMetadata::try_from(Word::from(expiring)), Ok(expiring));
+        assert_eq!(NoteMetadata::read_from_bytes(&expiring.to_bytes()).unwrap(), expiring);
+
+        assert_eq!(metadata.with_expiration(0), Err(NoteError::InvalidNoteExpiration(0)));
+        assert_eq!(
+            metadata.with_expiration(u32::MAX),
+            Err(NoteError::InvalidNoteExpiration(u32::MAX))
+        );
+    }
+
+    #[test]
+    fn note_metadata_ttl() {
+        let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let metadata = NoteMetadata::new(sender, NoteType::Public, NoteTag::from(0), ONE).unwrap();
+        assert_eq!(metadata.ttl(), None);
+        assert!(!metadata.is_prunable(10, u32::MAX));
+
+        // the details of a public note may be pruned once its TTL has elapsed
+        let with_ttl = metadata.with_ttl(100).unwrap();
+        assert!(!with_ttl.is_prunable(10, 110));
+        assert!(with_ttl.is_prunable(10, 111));
+        assert!(!with_ttl.is_prunable(u32::MAX - 10, u32::MAX));
+        assert_eq!(NoteMetadata::try_from(Word::from(with_ttl)), Ok(with_ttl));
+        assert_eq!(NoteMetadata::read_from_bytes(&with_ttl.to_bytes()).unwrap(), with_ttl);
+
+        // the TTL is encoded next to the note type, and is independent of the expiration
+        let expiring = with_ttl.with_expiration(50).unwrap();
+        assert_eq!(Word::from(expiring)[2], Felt::new(50 << 32 | 100 << 8 | 1));
+        assert_eq!(NoteMetadata::try_from(Word::from(expiring)), Ok(expiring));
+        assert!(expiring.is_prunable(10, 51));
+
+        assert_eq!(metadata.with_ttl(0), Err(NoteError::InvalidNoteTtl(0)));
+        assert_eq!(
+            metadata.with_ttl(MAX_NOTE_TTL + 1),
+            Err(NoteError::InvalidNoteTtl(MAX_NOTE_TTL + 1))
+        );
+
+        // only public notes can have a TTL
+        let off_chain =
+            NoteMetadata::new(sender, NoteType::OffChain, NoteTag::from(0), ONE).unwrap();
+        assert_eq!(
+            off_chain.with_ttl(100),
+            Err(NoteError::NoteTtlRequiresPublicNote(NoteType::OffChain))
+        );
+        let mut word = Word::from(off_chain);
+        word[2] = Felt::new(100 << 8 | NoteType::OffChain as u64);
+        assert_eq!(
+            NoteMetadata::try_from(word),
+            Err(NoteError::InvalidNoteMetadata(NoteMetadataError::TtlRequiresPublicNote(
+                NoteType::OffChain
+            )))
+        );
+    }
+
+    #[test]
+    fn note_metadata_inconsistent_tag() {
+        let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+
+        // public notes require the second most significant bit of the tag to be 0
+        let tag = 0b01 << 30 | 1;
+        assert_eq!(
+            NoteMetadata::new(sender, NoteType::Public, NoteTag::from(tag), ONE),
+            Err(NoteError::InconsistentNoteTag {
+                note_type: NoteType::Public,
+                tag: tag as u64,
+                invalid_bits: 0b01 << 30,
+            })
+        );
+
+        // tags encoded in a metadata word must fit into 32 bits
+        let mut word = Word::from(
+            NoteMetadata::new(sender, NoteType::OffChain, NoteTag::from(1), ONE).unwrap(),
+        );
+        word[0] = Felt::new(1 << 40 | 1);
+        assert_eq!(
+            NoteMetadata::try_from(word),
+            Err(NoteError::InvalidNoteMetadata(NoteMetadataError::TagNotU32(1 << 40 | 1)))
+        );
+    }
+
+    #[test]
+    fn note_metadata_word_validation() {
+        let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let metadata = NoteMetadata::new(sender, NoteType::Public, NoteTag::from(7), ONE)
+            .unwrap()
+            .with_expiration(100)
+            .unwrap();
+        let word = Word::from(metadata);
+        assert_eq!(NoteMetadata::try_from(word), Ok(metadata));
+
+        // off-chain notes cannot be intended for network execution
+        let mut invalid = word;
+        invalid[0] = Felt::from(0b10 << 30 | 7_u32);
+        invalid[2] = Felt::new(NoteType::OffChain as u64);
+        assert_eq!(
+            NoteMetadata::try_from(invalid),
+            Err(NoteError::InvalidNoteMetadata(NoteMetadataError::InconsistentTag {
+                note_type: NoteType::OffChain,
+                tag: 0b10 << 30 | 7,
+                invalid_bits: 0b10 << 30,
+            }))
+        );
+
+        let mut invalid = word;
+        invalid[1] = Felt::new(u64::MAX >> 1);
+        assert!(matches!(
+            NoteMetadata::try_from(invalid),
+            Err(NoteError::InvalidNoteMetadata(NoteMetadataError::InvalidSender(_)))
+        ));
 
-        Self::new(sender, note_type, tag, aux)
-            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+        let mut invalid = word;
+        invalid[2] = Felt::new(100 << 32 | 4);
+        assert_eq!(
+            NoteMetadata::try_from(invalid),
+            Err(NoteError::InvalidNoteMetadata(NoteMetadataError::InvalidNoteType(4)))
+        );
     }
 }
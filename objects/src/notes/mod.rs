@@ -15,9 +15,18 @@ use crate::{
 mod assets;
 pub use assets::NoteAssets;
 
+mod attestation;
+pub use attestation::ScriptAttestation;
+
+mod batch;
+pub use batch::CompressedNoteBatch;
+
 mod envelope;
 pub use envelope::NoteEnvelope;
 
+mod group;
+pub use group::{AccountGroup, GroupMembership, GROUP_TREE_DEPTH};
+
 mod inputs;
 pub use inputs::NoteInputs;
 
@@ -39,12 +48,33 @@ pub use nullifier::Nullifier;
 mod origin;
 pub use origin::{NoteInclusionProof, NoteOrigin};
 
+mod payment_proof;
+pub use payment_proof::PaymentProof;
+
+mod policy;
+pub use policy::validate_note_emission;
+
+mod pruning;
+pub use pruning::prunable_notes;
+
+mod receipt;
+pub use receipt::NoteCreationReceipt;
+
 mod recipient;
 pub use recipient::NoteRecipient;
 
+mod relevance;
+pub use relevance::{NoteRelevance, Relevance, RelevanceReason, ScriptRootSet, ViewKey};
+
 mod script;
 pub use script::NoteScript;
 
+mod script_registry;
+pub use script_registry::{ScriptInfo, ScriptLabel, ScriptRegistry};
+
+mod tag_subscription;
+pub use tag_subscription::{TagFilter, TagPrefix, TagSubscription};
+
 // CONSTANTS
 // ================================================================================================
 
@@ -3,18 +3,56 @@ use core::{fmt, num::TryFromIntError};
 use miden_crypto::Felt;
 
 use super::{
-    AccountId, ByteReader, ByteWriter, Deserializable, DeserializationError, NoteError,
+    AccountId, Asset, ByteReader, ByteWriter, Deserializable, DeserializationError, NoteError,
     NoteExecutionMode, NoteType, Serializable,
 };
+use crate::accounts::ACCOUNT_TYPE_MASK_SHIFT;
+
+// CONSTANTS
+// ================================================================================================
+
+/// The four most significant bits of tags targeting a group of accounts, i.e., 0b1011.
+///
+/// The prefix lies within the space of local, multi-target tags (0b101), and so group tags can
+/// only be used with public notes.
+const GROUP_TAG_PREFIX: u32 = 0xb000_0000;
+
+/// The mask selecting the four most significant bits of a tag.
+const GROUP_TAG_PREFIX_MASK: u32 = 0xf000_0000;
+
+/// The four most significant bits of tags identifying a public use case, i.e., 0b1010.
+///
+/// Like group tags, use case tags lie within the space of local, multi-target tags (0b101), and so
+/// they can only be used with public notes.
+const USE_CASE_TAG_PREFIX: u32 = 0xa000_0000;
+
+/// The number of bits of a use case tag which carry the payload defined by the use case.
+const USE_CASE_PAYLOAD_BITS: u32 = 24;
+
+/// The number of bits of a swap tag derived from the ID of each of the faucets of the swapped
+/// assets.
+pub(super) const SWAP_FAUCET_ID_BITS: u32 = USE_CASE_PAYLOAD_BITS / 2;
 
 // NOTE TAG
 // ================================================================================================
 
-#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Ord, PartialOrd)]
 #[cfg_attr(feature = "serde", derive(serde::Deserialize, serde::Serialize))]
 pub struct NoteTag(u32);
 
 impl NoteTag {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// The largest ID of a group of accounts which can be targeted by a tag.
+    ///
+    /// Group tags carry the ID of the group in their 28 least significant bits; the ID 0 is
+    /// reserved for the broadcast tag.
+    pub const MAX_GROUP_ID: u32 = !GROUP_TAG_PREFIX_MASK;
+
+    /// The ID of the use case of notes swapping one asset for another (see [NoteTag::for_swap()]).
+    pub const SWAP_USE_CASE_ID: u8 = 0;
+
     // CONSTRUCTORS
     // --------------------------------------------------------------------------------------------
 
@@ -56,6 +94,67 @@ impl NoteTag {
         }
     }
 
+    /// Returns a new [NoteTag] targeting the group of accounts with the specified ID.
+    ///
+    /// The four most significant bits of the tag are set to 0b1011, and the remaining bits are set
+    /// to the group ID. Such notes are intended for local execution by any member of the group
+    /// (see [AccountGroup](super::AccountGroup)), and must be public.
+    ///
+    /// # Errors
+    /// Returns an error if the group ID is 0 (which is reserved for the broadcast tag) or greater
+    /// than [NoteTag::MAX_GROUP_ID].
+    pub fn for_group(group_id: u32) -> Result<Self, NoteError> {
+        if group_id == 0 || group_id > Self::MAX_GROUP_ID {
+            return Err(NoteError::InvalidGroupId(group_id));
+        }
+
+        Ok(Self(GROUP_TAG_PREFIX | group_id))
+    }
+
+    /// Returns a new [NoteTag] for a public note offering the `offered_asset` in exchange for the
+    /// `requested_asset`, e.g., a SWAP note.
+    ///
+    /// Tags of public use cases are structured as follows:
+    /// - The four most significant bits are set to 0b1010.
+    /// - The following 4 bits are set to the ID of the use case, i.e., [NoteTag::SWAP_USE_CASE_ID].
+    /// - The remaining 24 bits are the payload of the use case.
+    ///
+    /// For swaps, the payload consists of 12 bits derived from the ID of the faucet which issued
+    /// the offered asset, followed by 12 bits derived from the ID of the faucet which issued the
+    /// requested asset (see [NoteTag::for_swap_pair()]). Thus, the tag depends only on the pair of
+    /// swapped assets, and not on the amounts or the data of the assets.
+    ///
+    /// Such notes are intended for local execution by any account willing to make the swap, and
+    /// must be public.
+    pub fn for_swap(offered_asset: &Asset, requested_asset: &Asset) -> Self {
+        Self::for_swap_pair(asset_faucet_id(offered_asset), asset_faucet_id(requested_asset))
+    }
+
+    /// Returns the tag of public notes offering an asset issued by the `offered_faucet_id` in
+    /// exchange for an asset issued by the `requested_faucet_id`.
+    ///
+    /// Clients interested in such swaps can subscribe to the returned tag; to subscribe to all
+    /// swaps offering a given asset, see
+    /// [TagPrefix::for_swaps_offering()](super::TagPrefix::for_swaps_offering).
+    ///
+    /// Each faucet ID contributes the 12 bits following its 4 most significant bits, which encode
+    /// the storage mode and the type of the faucet. Thus, swaps of different pairs of assets may
+    /// share the same tag, and clients must check the assets of the notes they receive.
+    pub fn for_swap_pair(offered_faucet_id: AccountId, requested_faucet_id: AccountId) -> Self {
+        let payload = (swap_faucet_id_bits(offered_faucet_id) << SWAP_FAUCET_ID_BITS)
+            | swap_faucet_id_bits(requested_faucet_id);
+        use_case_tag(Self::SWAP_USE_CASE_ID, payload)
+    }
+
+    /// Returns the broadcast tag, i.e., the tag of notes intended for local execution by any
+    /// account.
+    ///
+    /// The broadcast tag is the group tag with the reserved group ID 0, and so the notes with this
+    /// tag must be public.
+    pub const fn broadcast() -> Self {
+        Self(GROUP_TAG_PREFIX)
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -85,6 +184,35 @@ impl NoteTag {
         }
     }
 
+    /// Returns true if this is the broadcast tag.
+    pub fn is_broadcast(&self) -> bool {
+        *self == Self::broadcast()
+    }
+
+    /// Returns the ID of the group of accounts targeted by this tag, or None if this is not a
+    /// group tag.
+    ///
+    /// The broadcast tag is not a group tag, i.e., this returns None for the broadcast tag.
+    pub fn group_id(&self) -> Option<u32> {
+        let group_id = self.0 & Self::MAX_GROUP_ID;
+        (self.0 & GROUP_TAG_PREFIX_MASK == GROUP_TAG_PREFIX && group_id != 0).then_some(group_id)
+    }
+
+    /// Returns true if this is the tag of a public note swapping one asset for another.
+    pub fn is_swap(&self) -> bool {
+        self.0 >> USE_CASE_PAYLOAD_BITS == use_case_tag_head(Self::SWAP_USE_CASE_ID)
+    }
+
+    /// Returns true if this is the tag of public notes offering an asset issued by the
+    /// `offered_faucet_id` in exchange for an asset issued by the `requested_faucet_id`.
+    pub fn matches_swap(
+        &self,
+        offered_faucet_id: AccountId,
+        requested_faucet_id: AccountId,
+    ) -> bool {
+        *self == Self::for_swap_pair(offered_faucet_id, requested_faucet_id)
+    }
+
     /// Returns the inner u32 value of this tag.
     pub fn inner(&self) -> u32 {
         self.0
@@ -101,9 +229,14 @@ impl NoteTag {
     /// - For public notes, the second most significant bit of the tag is 0.
     /// - For encrypted notes, two most significant bits of the tag is 00.
     pub fn validate(&self, note_type: NoteType) -> Result<Self, NoteError> {
-        let tag_mask = note_type as u32;
-        if (self.0 >> 30) & tag_mask != 0 {
-            Err(NoteError::InconsistentNoteTag(note_type, self.0 as u64))
+        let tag_mask = (note_type as u32) << 30;
+        let invalid_bits = self.0 & tag_mask;
+        if invalid_bits != 0 {
+            Err(NoteError::InconsistentNoteTag {
+                note_type,
+                tag: self.0 as u64,
+                invalid_bits: invalid_bits as u64,
+            })
         } else {
             Ok(*self)
         }
@@ -177,3 +310,36 @@ impl Deserializable for NoteTag {
         Ok(Self(tag))
     }
 }
+
+// HELPERS
+// ================================================================================================
+
+/// Returns the tag of the public use case with the specified ID carrying the specified payload,
+/// which must fit into 24 bits.
+fn use_case_tag(use_case_id: u8, payload: u32) -> NoteTag {
+    debug_assert!(payload >> USE_CASE_PAYLOAD_BITS == 0, "use case payload is too large");
+    NoteTag((use_case_tag_head(use_case_id) << USE_CASE_PAYLOAD_BITS) | payload)
+}
+
+/// Returns the 8 most significant bits of the tags of the public use case with the specified ID.
+///
+/// Use case IDs are 4 bits long, and so only the 4 least significant bits of the ID are used.
+const fn use_case_tag_head(use_case_id: u8) -> u32 {
+    (USE_CASE_TAG_PREFIX >> USE_CASE_PAYLOAD_BITS) | (use_case_id & 0xf) as u32
+}
+
+/// Returns the bits of the specified faucet ID which are included in swap tags, i.e., the 12 bits
+/// following the 4 most significant bits of the ID.
+fn swap_faucet_id_bits(faucet_id: AccountId) -> u32 {
+    let id: u64 = faucet_id.into();
+    ((id >> (ACCOUNT_TYPE_MASK_SHIFT - SWAP_FAUCET_ID_BITS as u64)) as u32)
+        & ((1 << SWAP_FAUCET_ID_BITS) - 1)
+}
+
+/// Returns the ID of the faucet which issued the specified asset.
+fn asset_faucet_id(asset: &Asset) -> AccountId {
+    match asset {
+        Asset::Fungible(asset) => asset.faucet_id(),
+        Asset::NonFungible(asset) => asset.faucet_id(),
+    }
+}
@@ -1,10 +1,13 @@
-use alloc::string::ToString;
+use alloc::{collections::BTreeMap, string::ToString, vec::Vec};
 
 use super::{
-    ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, NoteError, Serializable,
-    NOTE_TREE_DEPTH,
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, NoteEnvelope, NoteError,
+    Serializable, Word, NOTE_TREE_DEPTH,
+};
+use crate::{
+    crypto::merkle::{MerklePath, NodeIndex},
+    BlockHeader, Hasher,
 };
-use crate::crypto::merkle::{MerklePath, NodeIndex};
 
 /// Contains information about the origin of a note.
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -76,6 +79,124 @@ impl NoteInclusionProof {
     pub fn note_path(&self) -> &MerklePath {
         &self.note_path
     }
+
+    // VERIFICATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Verifies that the note with the specified envelope is included in the note tree of the
+    /// specified block.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - This proof was not created against the specified block.
+    /// - The note is not at the location of this proof in the note tree of the block.
+    pub fn verify(&self, note: &NoteEnvelope, block_header: &BlockHeader) -> Result<(), NoteError> {
+        self.verify_with_cache(note, block_header, &mut BTreeMap::new())
+    }
+
+    /// Verifies that each of the specified notes is included in the note tree of the specified
+    /// block, and returns the verification result for each note in the order of the inputs.
+    ///
+    /// Inner nodes of the note tree authenticated while verifying one proof are not hashed again
+    /// when verifying subsequent proofs; thus, verifying proofs of notes which are close to each
+    /// other in the note tree is considerably cheaper than verifying each proof individually.
+    ///
+    /// When the `concurrent` feature is enabled, the proofs are verified using multiple threads.
+    pub fn verify_batch(
+        proofs: &[(NoteEnvelope, &NoteInclusionProof)],
+        block_header: &BlockHeader,
+    ) -> Vec<Result<(), NoteError>> {
+        #[cfg(feature = "concurrent")]
+        {
+            let thread_count = std::thread::available_parallelism().map_or(1, |v| v.get());
+            let chunk_size = proofs.len().div_ceil(thread_count).max(1);
+
+            std::thread::scope(|scope| {
+                let handles: Vec<_> = proofs
+                    .chunks(chunk_size)
+                    .map(|chunk| scope.spawn(|| verify_chunk(chunk, block_header)))
+                    .collect();
+
+                handles
+                    .into_iter()
+                    .flat_map(|handle| handle.join().expect("verification thread panicked"))
+                    .collect()
+            })
+        }
+
+        #[cfg(not(feature = "concurrent"))]
+        verify_chunk(proofs, block_header)
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Verifies this proof for the specified note using the provided set of inner nodes which are
+    /// already known to be authenticated against the note root of the block.
+    ///
+    /// Hashing stops as soon as the path reaches one of the authenticated nodes. If the proof is
+    /// valid, all nodes computed or revealed by it are added to the set of authenticated nodes.
+    fn verify_with_cache(
+        &self,
+        note: &NoteEnvelope,
+        block_header: &BlockHeader,
+        authenticated: &mut BTreeMap<NodeIndex, Digest>,
+    ) -> Result<(), NoteError> {
+        let note_id = note.id();
+        if self.origin.block_num != block_header.block_num()
+            || self.sub_hash != block_header.sub_hash()
+            || self.note_root != block_header.note_root()
+        {
+            return Err(NoteError::NoteBlockMismatch {
+                note_id,
+                block_num: block_header.block_num(),
+            });
+        }
+
+        if self.note_path.depth() != NOTE_TREE_DEPTH
+            || self.origin.node_index.depth() != NOTE_TREE_DEPTH
+        {
+            return Err(NoteError::InvalidNoteInclusionProof(note_id));
+        }
+
+        let mut index = self.origin.node_index;
+        let mut node = Hasher::merge(&[note_id.inner(), Word::from(note.metadata()).into()]);
+        let mut computed = Vec::with_capacity(2 * self.note_path.len());
+        for &sibling in self.note_path.iter() {
+            if let Some(&known) = authenticated.get(&index) {
+                if known != node {
+                    return Err(NoteError::InvalidNoteInclusionProof(note_id));
+                }
+                authenticated.extend(computed);
+                return Ok(());
+            }
+
+            computed.push((index, node));
+            computed.push((index.sibling(), sibling));
+
+            node = Hasher::merge(&index.build_node(node, sibling));
+            index.move_up();
+        }
+
+        if node != block_header.note_root() {
+            return Err(NoteError::InvalidNoteInclusionProof(note_id));
+        }
+        authenticated.extend(computed);
+
+        Ok(())
+    }
+}
+
+/// Verifies the specified proofs sequentially, sharing authenticated inner nodes between them.
+fn verify_chunk(
+    proofs: &[(NoteEnvelope, &NoteInclusionProof)],
+    block_header: &BlockHeader,
+) -> Vec<Result<(), NoteError>> {
+    let mut authenticated = BTreeMap::new();
+    proofs
+        .iter()
+        .map(|(note, proof)| proof.verify_with_cache(note, block_header, &mut authenticated))
+        .collect()
 }
 
 // SERIALIZATION
@@ -116,3 +237,107 @@ impl Deserializable for NoteInclusionProof {
         Ok(Self { origin, sub_hash, note_root, note_path })
     }
 }
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::{NoteEnvelope, NoteInclusionProof, Word, NOTE_TREE_DEPTH};
+    use crate::{
+        accounts::{AccountId, ACCOUNT_ID_SENDER},
+        crypto::merkle::{LeafIndex, SimpleSmt},
+        notes::{NoteId, NoteMetadata, NoteTag, NoteType},
+        BlockHeader, Digest, Felt, Hasher, NoteError, ONE, ZERO,
+    };
+
+    #[test]
+    fn note_inclusion_proof_verify_batch() {
+        let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let metadata =
+            NoteMetadata::new(sender, NoteType::OffChain, NoteTag::from(0), ONE).unwrap();
+        let notes: Vec<NoteEnvelope> = (0..6u64)
+            .map(|i| {
+                let id =
+                    NoteId::new(Digest::from([Felt::new(i), ZERO, ZERO, ZERO]), Digest::default());
+                NoteEnvelope::new(id, metadata).unwrap()
+            })
+            .collect();
+
+        let auth_hash = |note: &NoteEnvelope| {
+            Hasher::merge(&[note.id().inner(), Word::from(note.metadata()).into()])
+        };
+        let note_tree = SimpleSmt::<NOTE_TREE_DEPTH>::with_leaves(
+            notes.iter().enumerate().map(|(idx, note)| (idx as u64, auth_hash(note).into())),
+        )
+        .unwrap();
+
+        let block_header = BlockHeader::new(
+            Digest::default(),
+            7,
+            Digest::default(),
+            Digest::default(),
+            Digest::default(),
+            note_tree.root(),
+            Digest::default(),
+            Digest::default(),
+            ZERO,
+            ZERO,
+            Digest::default(),
+        );
+        let proof = |idx: u64, block_header: &BlockHeader| {
+            let path = note_tree.open(&LeafIndex::new(idx).unwrap()).path;
+            NoteInclusionProof::new(
+                block_header.block_num(),
+                block_header.sub_hash(),
+                block_header.note_root(),
+                idx,
+                path,
+            )
+            .unwrap()
+        };
+
+        let proofs: Vec<NoteInclusionProof> =
+            (0..notes.len() as u64).map(|idx| proof(idx, &block_header)).collect();
+        for (note, proof) in notes.iter().zip(proofs.iter()) {
+            assert_eq!(proof.verify(note, &block_header), Ok(()));
+        }
+
+        // proofs at the wrong position and proofs from another block are rejected, without
+        // affecting the results of the remaining proofs
+        let other_header = BlockHeader::new(
+            Digest::default(),
+            8,
+            Digest::default(),
+            Digest::default(),
+            Digest::default(),
+            note_tree.root(),
+            Digest::default(),
+            Digest::default(),
+            ZERO,
+            ZERO,
+            Digest::default(),
+        );
+        let other_block_proof = proof(4, &other_header);
+
+        let mut batch: Vec<(NoteEnvelope, &NoteInclusionProof)> =
+            notes.iter().cloned().zip(proofs.iter()).collect();
+        batch[1].1 = &proofs[2];
+        batch[2].1 = &proofs[1];
+        batch[4].1 = &other_block_proof;
+
+        let results = NoteInclusionProof::verify_batch(&batch, &block_header);
+        assert_eq!(results.len(), notes.len());
+        for (idx, result) in results.into_iter().enumerate() {
+            let note_id = batch[idx].0.id();
+            let expected = match idx {
+                1 | 2 => Err(NoteError::InvalidNoteInclusionProof(note_id)),
+                4 => Err(NoteError::NoteBlockMismatch { note_id, block_num: 7 }),
+                _ => Ok(()),
+            };
+            assert_eq!(result, expected);
+        }
+    }
+}
@@ -0,0 +1,316 @@
+use alloc::string::ToString;
+
+use super::{
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, NoteAssets, NoteEnvelope,
+    NoteId, NoteInclusionProof, Serializable,
+};
+use crate::{
+    crypto::merkle::{MerklePath, MmrPeaks, PartialMmr},
+    BlockHeader, PaymentProofError,
+};
+
+// PAYMENT PROOF
+// ================================================================================================
+
+/// A proof that a note with the specified assets was paid to a given recipient, verifiable by a
+/// third party which trusts the hash of a single block.
+///
+/// A payment proof consists of:
+/// - The envelope of the note (i.e., its ID and metadata) and the assets carried by the note.
+/// - A proof of inclusion of the note in the note tree of the block in which it was created.
+/// - The header of the block in which the note was created.
+/// - The header of a later reference block, the peaks of the chain MMR committed to by the
+///   reference block, and the path to the block in which the note was created in that MMR.
+///
+/// The proof does not reveal the details of the recipient (e.g., the serial number of the note),
+/// and thus does not allow the verifier to consume the note.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaymentProof {
+    note: NoteEnvelope,
+    assets: NoteAssets,
+    inclusion_proof: NoteInclusionProof,
+    block_header: BlockHeader,
+    reference_block: BlockHeader,
+    chain_peaks: MmrPeaks,
+    chain_path: MerklePath,
+}
+
+impl PaymentProof {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [PaymentProof] for the specified note.
+    ///
+    /// The `chain_peaks` must be the peaks of the chain MMR committed to by the `reference_block`,
+    /// and the `chain_path` must be the path to the block in which the note was created in that
+    /// MMR. The proof is not validated; see [PaymentProof::verify()].
+    pub fn new(
+        note: NoteEnvelope,
+        assets: NoteAssets,
+        inclusion_proof: NoteInclusionProof,
+        block_header: BlockHeader,
+        reference_block: BlockHeader,
+        chain_peaks: MmrPeaks,
+        chain_path: MerklePath,
+    ) -> Self {
+        Self {
+            note,
+            assets,
+            inclusion_proof,
+            block_header,
+            reference_block,
+            chain_peaks,
+            chain_path,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the envelope of the paid note.
+    pub fn note(&self) -> &NoteEnvelope {
+        &self.note
+    }
+
+    /// Returns the assets carried by the paid note.
+    pub fn assets(&self) -> &NoteAssets {
+        &self.assets
+    }
+
+    /// Returns the header of the block in which the note was created.
+    pub fn block_header(&self) -> &BlockHeader {
+        &self.block_header
+    }
+
+    /// Returns the header of the block against which the proof is verified.
+    pub fn reference_block(&self) -> &BlockHeader {
+        &self.reference_block
+    }
+
+    // VERIFICATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Verifies that the note of this proof carries the assets of this proof, was created for the
+    /// specified recipient, and was included in a block which is a part of the chain leading up
+    /// to the block with the specified hash.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The ID of the note is not the ID of a note with the specified recipient and the assets of
+    ///   this proof.
+    /// - The note is not included in the block in which it was allegedly created.
+    /// - The hash of the reference block is not equal to the specified trusted block hash.
+    /// - The block in which the note was created is not earlier than the reference block.
+    /// - The chain MMR peaks are inconsistent with the reference block.
+    /// - The block in which the note was created is not at its position in the chain MMR.
+    pub fn verify(
+        &self,
+        expected_recipient: Digest,
+        trusted_block_hash: Digest,
+    ) -> Result<(), PaymentProofError> {
+        let expected_id = NoteId::new(expected_recipient, self.assets.commitment());
+        if self.note.id() != expected_id {
+            return Err(PaymentProofError::NoteIdMismatch {
+                expected: expected_id,
+                actual: self.note.id(),
+            });
+        }
+
+        self.inclusion_proof
+            .verify(&self.note, &self.block_header)
+            .map_err(PaymentProofError::InvalidNoteInclusionProof)?;
+
+        let reference_hash = self.reference_block.hash();
+        if reference_hash != trusted_block_hash {
+            return Err(PaymentProofError::ReferenceBlockMismatch {
+                expected: trusted_block_hash,
+                actual: reference_hash,
+            });
+        }
+
+        let block_num = self.block_header.block_num();
+        let reference_block_num = self.reference_block.block_num();
+        if block_num >= reference_block_num {
+            return Err(PaymentProofError::BlockNotBeforeReferenceBlock {
+                block_num,
+                reference_block_num,
+            });
+        }
+
+        if self.chain_peaks.num_leaves() != reference_block_num as usize {
+            return Err(PaymentProofError::ChainLengthMismatch {
+                expected: reference_block_num,
+                actual: self.chain_peaks.num_leaves(),
+            });
+        }
+
+        if self.chain_peaks.hash_peaks() != self.reference_block.chain_root() {
+            return Err(PaymentProofError::ChainRootMismatch {
+                expected: self.reference_block.chain_root(),
+                actual: self.chain_peaks.hash_peaks(),
+            });
+        }
+
+        // tracking a leaf in a partial MMR fails if the path does not lead to one of its peaks
+        let mut chain_mmr = PartialMmr::from_peaks(self.chain_peaks.clone());
+        chain_mmr
+            .track(block_num as usize, self.block_header.hash(), &self.chain_path)
+            .map_err(|_| PaymentProofError::InvalidChainPath(block_num))?;
+
+        Ok(())
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for PaymentProof {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.note.write_into(target);
+        self.assets.write_into(target);
+        self.inclusion_proof.write_into(target);
+        self.block_header.write_into(target);
+        self.reference_block.write_into(target);
+        target.write_u64(self.chain_peaks.num_leaves() as u64);
+        target.write_u8(self.chain_peaks.num_peaks() as u8);
+        target.write_many(self.chain_peaks.peaks());
+        self.chain_path.write_into(target);
+    }
+}
+
+impl Deserializable for PaymentProof {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let note = NoteEnvelope::read_from(source)?;
+        let assets = NoteAssets::read_from(source)?;
+        let inclusion_proof = NoteInclusionProof::read_from(source)?;
+        let block_header = BlockHeader::read_from(source)?;
+        let reference_block = BlockHeader::read_from(source)?;
+        let num_leaves = source.read_u64()? as usize;
+        let num_peaks = source.read_u8()? as usize;
+        let peaks = source.read_many::<Digest>(num_peaks)?;
+        let chain_peaks = MmrPeaks::new(num_leaves, peaks)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+        let chain_path = MerklePath::read_from(source)?;
+
+        Ok(Self {
+            note,
+            assets,
+            inclusion_proof,
+            block_header,
+            reference_block,
+            chain_peaks,
+            chain_path,
+        })
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::PaymentProof;
+    use crate::{
+        accounts::{AccountId, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_SENDER},
+        assets::FungibleAsset,
+        crypto::merkle::{LeafIndex, Mmr, SimpleSmt},
+        notes::{
+            NoteAssets, NoteEnvelope, NoteId, NoteInclusionProof, NoteMetadata, NoteTag, NoteType,
+        },
+        utils::serde::{Deserializable, Serializable},
+        BlockHeader, Digest, Felt, Hasher, PaymentProofError, Word, NOTE_TREE_DEPTH, ONE, ZERO,
+    };
+
+    const NOTE_BLOCK_NUM: u32 = 3;
+
+    /// Builds a chain of `num_blocks` headers, where the block [NOTE_BLOCK_NUM] contains the
+    /// specified note, and returns the headers together with the full chain MMR and the proof of
+    /// inclusion of the note.
+    fn build_chain(
+        num_blocks: u32,
+        note: &NoteEnvelope,
+    ) -> (Vec<BlockHeader>, Mmr, NoteInclusionProof) {
+        let auth_hash = Hasher::merge(&[note.id().inner(), Word::from(note.metadata()).into()]);
+        let note_tree = SimpleSmt::<NOTE_TREE_DEPTH>::with_leaves([(5, auth_hash.into())]).unwrap();
+
+        let mut mmr = Mmr::default();
+        let mut headers = Vec::new();
+        for block_num in 0..num_blocks {
+            let mut builder = BlockHeader::builder()
+                .block_num(block_num)
+                .chain_root(mmr.peaks(mmr.forest()).unwrap().hash_peaks());
+            if block_num == NOTE_BLOCK_NUM {
+                builder = builder.note_root(note_tree.root());
+            }
+            let header = builder.build();
+            mmr.add(header.hash());
+            headers.push(header);
+        }
+
+        let header = &headers[NOTE_BLOCK_NUM as usize];
+        let inclusion_proof = NoteInclusionProof::new(
+            NOTE_BLOCK_NUM,
+            header.sub_hash(),
+            header.note_root(),
+            5,
+            note_tree.open(&LeafIndex::new(5).unwrap()).path,
+        )
+        .unwrap();
+
+        (headers, mmr, inclusion_proof)
+    }
+
+    #[test]
+    fn test_payment_proof() {
+        let recipient = Digest::from([ONE, Felt::new(2), Felt::new(3), Felt::new(4)]);
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let assets =
+            NoteAssets::new(vec![FungibleAsset::new(faucet_id, 100).unwrap().into()]).unwrap();
+        let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let metadata =
+            NoteMetadata::new(sender, NoteType::OffChain, NoteTag::from(0), ZERO).unwrap();
+        let note =
+            NoteEnvelope::new(NoteId::new(recipient, assets.commitment()), metadata).unwrap();
+
+        let (headers, mmr, inclusion_proof) = build_chain(8, &note);
+        let proof = |reference_block_num: usize, path_block_num: usize| {
+            PaymentProof::new(
+                note,
+                assets.clone(),
+                inclusion_proof.clone(),
+                headers[NOTE_BLOCK_NUM as usize],
+                headers[reference_block_num],
+                mmr.peaks(reference_block_num).unwrap(),
+                mmr.open(path_block_num, reference_block_num).unwrap().merkle_path,
+            )
+        };
+
+        let valid_proof = proof(7, NOTE_BLOCK_NUM as usize);
+        assert_eq!(valid_proof.verify(recipient, headers[7].hash()), Ok(()));
+        assert_eq!(proof(4, NOTE_BLOCK_NUM as usize).verify(recipient, headers[4].hash()), Ok(()));
+
+        let bytes = valid_proof.to_bytes();
+        assert_eq!(valid_proof, PaymentProof::read_from_bytes(&bytes).unwrap());
+
+        // the note must have been paid to the expected recipient
+        let other_recipient = Digest::from([ZERO, Felt::new(2), Felt::new(3), Felt::new(4)]);
+        let result = valid_proof.verify(other_recipient, headers[7].hash());
+        assert!(matches!(result, Err(PaymentProofError::NoteIdMismatch { .. })));
+
+        // the proof must be made against the trusted block
+        let result = valid_proof.verify(recipient, headers[6].hash());
+        assert!(matches!(result, Err(PaymentProofError::ReferenceBlockMismatch { .. })));
+
+        // the path must lead from the block of the note to the chain MMR of the reference block
+        let result = proof(7, 2).verify(recipient, headers[7].hash());
+        assert_eq!(result, Err(PaymentProofError::InvalidChainPath(NOTE_BLOCK_NUM)));
+
+        // the reference block must be later than the block of the note
+        let mut invalid_proof = proof(7, NOTE_BLOCK_NUM as usize);
+        invalid_proof.reference_block = headers[NOTE_BLOCK_NUM as usize];
+        let result = invalid_proof.verify(recipient, headers[NOTE_BLOCK_NUM as usize].hash());
+        assert!(matches!(result, Err(PaymentProofError::BlockNotBeforeReferenceBlock { .. })));
+    }
+}
@@ -0,0 +1,106 @@
+use super::{NoteError, NoteExecutionMode, NoteTag, NoteType};
+use crate::accounts::AccountStorageType;
+
+// NOTE EMISSION POLICY
+// ================================================================================================
+
+/// Validates that an account with the specified storage mode can emit a note with the specified
+/// type and tag.
+///
+/// The rules are the same as the rules enforced by the transaction kernel when a note is created,
+/// and are checked in the following order:
+/// - Encrypted notes are not supported.
+/// - Notes intended for network execution (see [NoteTag::execution_mode()]) must be public, as
+///   the network cannot execute notes whose details it does not know.
+/// - Notes intended for network execution can only be emitted by on-chain accounts.
+/// - The tag must be consistent with the note type (see [NoteTag::validate()]).
+///
+/// Clients can use this function to validate notes before executing a transaction which creates
+/// them, and thus obtain the precise reason for which a note would be rejected by the kernel.
+///
+/// # Errors
+/// Returns an error if any of the rules above is violated:
+/// - [NoteError::InvalidNoteType] if the note is encrypted.
+/// - [NoteError::NetworkExecutionRequiresPublicNote] if a network note is not public.
+/// - [NoteError::NetworkExecutionRequiresOnChainAccount] if a network note is emitted by an
+///   off-chain account.
+/// - [NoteError::InconsistentNoteTag] if the tag is inconsistent with the note type.
+pub fn validate_note_emission(
+    account_storage_mode: AccountStorageType,
+    note_type: NoteType,
+    note_tag: NoteTag,
+) -> Result<(), NoteError> {
+    if note_type == NoteType::Encrypted {
+        return Err(NoteError::InvalidNoteType(note_type));
+    }
+
+    if note_tag.execution_mode() == NoteExecutionMode::Network {
+        if note_type != NoteType::Public {
+            return Err(NoteError::NetworkExecutionRequiresPublicNote(note_type));
+        }
+        if account_storage_mode != AccountStorageType::OnChain {
+            return Err(NoteError::NetworkExecutionRequiresOnChainAccount);
+        }
+    }
+
+    note_tag.validate(note_type)?;
+
+    Ok(())
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::validate_note_emission;
+    use crate::{
+        accounts::{AccountId, AccountStorageType, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN},
+        notes::{NoteExecutionMode, NoteTag, NoteType},
+        NoteError,
+    };
+
+    #[test]
+    fn test_note_emission_policy() {
+        use AccountStorageType::{OffChain, OnChain};
+
+        let target = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let local_tag = NoteTag::from_account_id(target, NoteExecutionMode::Local).unwrap();
+        let network_tag = NoteTag::from_account_id(target, NoteExecutionMode::Network).unwrap();
+
+        // local notes can be emitted by any account
+        for storage_mode in [OnChain, OffChain] {
+            for note_type in [NoteType::Public, NoteType::OffChain] {
+                assert_eq!(validate_note_emission(storage_mode, note_type, local_tag), Ok(()));
+            }
+        }
+
+        // encrypted notes are not supported
+        assert_eq!(
+            validate_note_emission(OnChain, NoteType::Encrypted, local_tag),
+            Err(NoteError::InvalidNoteType(NoteType::Encrypted))
+        );
+
+        // network notes must be public and emitted by on-chain accounts
+        assert_eq!(validate_note_emission(OnChain, NoteType::Public, network_tag), Ok(()));
+        assert_eq!(
+            validate_note_emission(OnChain, NoteType::OffChain, network_tag),
+            Err(NoteError::NetworkExecutionRequiresPublicNote(NoteType::OffChain))
+        );
+        assert_eq!(
+            validate_note_emission(OffChain, NoteType::Public, network_tag),
+            Err(NoteError::NetworkExecutionRequiresOnChainAccount)
+        );
+
+        // the tag must be consistent with the note type
+        let tag = 0b01 << 30 | 1;
+        assert_eq!(
+            validate_note_emission(OnChain, NoteType::Public, NoteTag::from(tag)),
+            Err(NoteError::InconsistentNoteTag {
+                note_type: NoteType::Public,
+                tag: tag as u64,
+                invalid_bits: 0b01 << 30,
+            })
+        );
+    }
+}
@@ -0,0 +1,67 @@
+use alloc::vec::Vec;
+
+use super::{NoteId, NoteMetadata};
+
+// NOTE PRUNING
+// ================================================================================================
+
+/// Returns the IDs of the notes whose details may be pruned by a node once the block with the
+/// specified number has been produced.
+///
+/// Every note is provided as a (created_in, note_id, metadata) tuple, where `created_in` is the
+/// number of the block in which the note was created. A note is prunable if it is public and
+/// either its TTL has elapsed or it has expired (see [NoteMetadata::is_prunable()]). The IDs are
+/// returned in the order in which the notes are provided.
+pub fn prunable_notes(
+    notes: impl IntoIterator<Item = (u32, NoteId, NoteMetadata)>,
+    block_num: u32,
+) -> Vec<NoteId> {
+    notes
+        .into_iter()
+        .filter(|(created_in, _, metadata)| metadata.is_prunable(*created_in, block_num))
+        .map(|(_, note_id, _)| note_id)
+        .collect()
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use super::prunable_notes;
+    use crate::{
+        accounts::{AccountId, ACCOUNT_ID_SENDER},
+        notes::{NoteId, NoteMetadata, NoteTag, NoteType},
+        Felt, ZERO,
+    };
+
+    #[test]
+    fn test_prunable_notes() {
+        let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let public = NoteMetadata::new(sender, NoteType::Public, NoteTag::from(0), ZERO).unwrap();
+        let off_chain =
+            NoteMetadata::new(sender, NoteType::OffChain, NoteTag::from(0), ZERO).unwrap();
+
+        let notes = [
+            (10, public),
+            (10, public.with_ttl(5).unwrap()),
+            (20, public.with_ttl(5).unwrap()),
+            (10, public.with_expiration(12).unwrap()),
+            (10, off_chain.with_expiration(12).unwrap()),
+        ]
+        .into_iter()
+        .enumerate()
+        .map(|(i, (created_in, metadata))| {
+            (created_in, NoteId::from([Felt::from(i as u32), ZERO, ZERO, ZERO]), metadata)
+        })
+        .collect::<Vec<_>>();
+        let note_ids =
+            |indexes: &[usize]| -> Vec<NoteId> { indexes.iter().map(|&i| notes[i].1).collect() };
+
+        assert_eq!(prunable_notes(notes.clone(), 15), note_ids(&[3]));
+        assert_eq!(prunable_notes(notes.clone(), 16), note_ids(&[1, 3]));
+        assert_eq!(prunable_notes(notes.clone(), 26), note_ids(&[1, 2, 3]));
+    }
+}
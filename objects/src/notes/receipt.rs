@@ -0,0 +1,103 @@
+use super::{
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, Hasher, NoteError,
+    NoteId, NoteMetadata, Serializable, Word,
+};
+use crate::{accounts::AccountId, crypto::dsa::rpo_falcon512::Signature};
+
+// NOTE CREATION RECEIPT
+// ================================================================================================
+
+/// A signed statement of an account that it created a note with the specified ID and metadata.
+///
+/// The sender of the note (see [NoteMetadata::sender()]) signs the commitment to the note (see
+/// [NoteCreationReceipt::commitment()]) with the secret key it uses to authenticate transactions.
+/// Receivers of the note can verify the receipt against the public key stored in the account
+/// storage of the sender, and thus obtain proof that the note was produced by the claimed sender
+/// without waiting for the note to be included in a block.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NoteCreationReceipt {
+    note_id: NoteId,
+    metadata: NoteMetadata,
+    signature: Signature,
+}
+
+impl NoteCreationReceipt {
+    /// Returns a new [NoteCreationReceipt] for the note with the specified ID and metadata.
+    ///
+    /// The signature must be made over [NoteCreationReceipt::commitment()] of the note.
+    pub fn new(note_id: NoteId, metadata: NoteMetadata, signature: Signature) -> Self {
+        Self { note_id, metadata, signature }
+    }
+
+    /// Returns the commitment to the note with the specified ID and metadata which is signed by
+    /// the sender of the note.
+    ///
+    /// The commitment is computed as hash(note_id, metadata), i.e., the same way as the leaves of
+    /// the note tree of a block.
+    pub fn commitment(note_id: NoteId, metadata: &NoteMetadata) -> Digest {
+        Hasher::merge(&[note_id.inner(), Word::from(metadata).into()])
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the ID of the created note.
+    pub fn note_id(&self) -> NoteId {
+        self.note_id
+    }
+
+    /// Returns the metadata of the created note.
+    pub fn metadata(&self) -> &NoteMetadata {
+        &self.metadata
+    }
+
+    /// Returns the ID of the account which created the note.
+    pub fn sender(&self) -> AccountId {
+        self.metadata.sender()
+    }
+
+    /// Returns the signature of the sender over the commitment to the note.
+    pub fn signature(&self) -> &Signature {
+        &self.signature
+    }
+
+    // VERIFICATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Verifies the signature of this receipt against the specified public key of the sender.
+    ///
+    /// The public key is expected in the form in which it is stored in the account storage of
+    /// the sender, i.e., as a commitment to the RPO Falcon512 public key.
+    ///
+    /// # Errors
+    /// Returns an error if the signature is not valid for the specified public key.
+    pub fn verify(&self, pub_key: Word) -> Result<(), NoteError> {
+        let commitment = Self::commitment(self.note_id, &self.metadata);
+        if !self.signature.verify(commitment.into(), pub_key) {
+            return Err(NoteError::InvalidNoteCreationReceipt(self.note_id));
+        }
+
+        Ok(())
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for NoteCreationReceipt {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.note_id.write_into(target);
+        self.metadata.write_into(target);
+        self.signature.write_into(target);
+    }
+}
+
+impl Deserializable for NoteCreationReceipt {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let note_id = NoteId::read_from(source)?;
+        let metadata = NoteMetadata::read_from(source)?;
+        let signature = Signature::read_from(source)?;
+
+        Ok(Self { note_id, metadata, signature })
+    }
+}
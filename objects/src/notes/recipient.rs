@@ -58,6 +58,30 @@ impl NoteRecipient {
         self.digest
     }
 
+    /// Returns the commitment to the provided serial number, computed as hash(serial_num, [0; 4]).
+    ///
+    /// This mirrors the `compute_serial_num_commitment` procedure of the `miden::note_utils`
+    /// module.
+    pub fn serial_num_commitment(serial_num: Word) -> Digest {
+        Hasher::merge(&[serial_num.into(), Digest::default()])
+    }
+
+    /// Returns the digest of a recipient computed from the commitment to its serial number (see
+    /// [NoteRecipient::serial_num_commitment()]), the root of its script, and the commitment to
+    /// its inputs.
+    ///
+    /// This allows computing the recipient of a note without knowing its serial number, and
+    /// mirrors the `compute_recipient` procedure of the `miden::note_utils` module, which note
+    /// scripts can use to compute the recipients of the notes they create.
+    pub fn digest_from_parts(
+        serial_num_commitment: Digest,
+        script_root: Digest,
+        inputs_hash: Digest,
+    ) -> Digest {
+        let merge_script = Hasher::merge(&[serial_num_commitment, script_root]);
+        Hasher::merge(&[merge_script, inputs_hash])
+    }
+
     /// Returns the recipient encoded as [Felt]s.
     pub fn to_elements(&self) -> Vec<Felt> {
         let mut result = Vec::with_capacity(12);
@@ -69,9 +93,8 @@ impl NoteRecipient {
 }
 
 fn compute_recipient_digest(serial_num: Word, script: &NoteScript, inputs: &NoteInputs) -> Digest {
-    let serial_num_hash = Hasher::merge(&[serial_num.into(), Digest::default()]);
-    let merge_script = Hasher::merge(&[serial_num_hash, script.hash()]);
-    Hasher::merge(&[merge_script, inputs.commitment()])
+    let serial_num_commitment = NoteRecipient::serial_num_commitment(serial_num);
+    NoteRecipient::digest_from_parts(serial_num_commitment, script.hash(), inputs.commitment())
 }
 
 // SERIALIZATION
@@ -0,0 +1,243 @@
+use alloc::collections::BTreeSet;
+use core::fmt::Debug;
+
+use super::{Digest, Felt, Hasher, Note, NoteEnvelope, NoteTag, TagFilter, TagSubscription, Word};
+use crate::{accounts::AccountId, ZERO};
+
+// NOTE RELEVANCE
+// ================================================================================================
+
+/// Decides whether a note is relevant to its owner, e.g., whether a note found in a block is
+/// addressed to the accounts of a wallet, or whether a note should be screened by a node which
+/// executes network notes.
+///
+/// Relevance is decided from the header of the note and, if they are known, its details. Wallets
+/// and nodes should rely on the same implementations, so that they agree on which notes are
+/// relevant to an account.
+pub trait NoteRelevance: Debug {
+    /// Returns the relevance of the note with the specified header and details.
+    ///
+    /// The details, if provided, are assumed to match the header of the note.
+    fn check_relevance(&self, envelope: &NoteEnvelope, details: Option<&Note>) -> Relevance;
+}
+
+/// The outcome of a [NoteRelevance] check.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Relevance {
+    /// The note is relevant for the specified reason.
+    Relevant { reason: RelevanceReason },
+    /// The note is not relevant.
+    NotRelevant,
+}
+
+impl Relevance {
+    /// Returns true if the note is relevant.
+    pub fn is_relevant(&self) -> bool {
+        matches!(self, Self::Relevant { .. })
+    }
+
+    /// Returns the reason for which the note is relevant, if it is.
+    pub fn reason(&self) -> Option<RelevanceReason> {
+        match self {
+            Self::Relevant { reason } => Some(*reason),
+            Self::NotRelevant => None,
+        }
+    }
+}
+
+/// The reason for which a note is relevant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum RelevanceReason {
+    /// The details of the note were received out of band, and the note is tracked by its ID.
+    TrackedNote,
+    /// The tag of the note matches one of the tags of interest.
+    TagMatch(NoteTag),
+    /// The script of the note is one of the scripts of interest.
+    ScriptRootMatch(Digest),
+    /// The auxiliary data of the note is the stealth marker derived from a view key (see
+    /// [ViewKey::stealth_marker()]).
+    StealthMatch,
+}
+
+// TAG MATCH
+// ================================================================================================
+
+/// A note is relevant if its tag is contained in the subscription.
+impl NoteRelevance for TagSubscription {
+    fn check_relevance(&self, envelope: &NoteEnvelope, _details: Option<&Note>) -> Relevance {
+        let tag = envelope.metadata().tag();
+        match self.contains(tag) {
+            true => Relevance::Relevant { reason: RelevanceReason::TagMatch(tag) },
+            false => Relevance::NotRelevant,
+        }
+    }
+}
+
+/// A note is relevant if its tag is contained in the filter.
+///
+/// As the filter admits false positives, nodes can screen notes with it, but clients should check
+/// the notes returned by a node against the subscription from which the filter was built.
+impl NoteRelevance for TagFilter {
+    fn check_relevance(&self, envelope: &NoteEnvelope, _details: Option<&Note>) -> Relevance {
+        let tag = envelope.metadata().tag();
+        match self.contains(tag) {
+            true => Relevance::Relevant { reason: RelevanceReason::TagMatch(tag) },
+            false => Relevance::NotRelevant,
+        }
+    }
+}
+
+// SCRIPT ROOT MATCH
+// ================================================================================================
+
+/// A set of note script roots of interest, e.g., the roots of the scripts a network account can
+/// consume.
+///
+/// A note is relevant if its details are known and its script is one of the scripts in the set.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScriptRootSet {
+    roots: BTreeSet<Digest>,
+}
+
+impl ScriptRootSet {
+    /// Returns a new [ScriptRootSet] containing the specified script roots.
+    pub fn new<I: IntoIterator<Item = Digest>>(roots: I) -> Self {
+        Self { roots: roots.into_iter().collect() }
+    }
+
+    /// Adds the specified script root to this set. Returns true if the root was not yet contained
+    /// in the set.
+    pub fn insert(&mut self, root: Digest) -> bool {
+        self.roots.insert(root)
+    }
+
+    /// Returns true if the specified script root is contained in this set.
+    pub fn contains(&self, root: &Digest) -> bool {
+        self.roots.contains(root)
+    }
+}
+
+impl NoteRelevance for ScriptRootSet {
+    fn check_relevance(&self, _envelope: &NoteEnvelope, details: Option<&Note>) -> Relevance {
+        match details.map(|note| note.script().hash()) {
+            Some(root) if self.contains(&root) => Relevance::Relevant {
+                reason: RelevanceReason::ScriptRootMatch(root),
+            },
+            _ => Relevance::NotRelevant,
+        }
+    }
+}
+
+// VIEW KEY
+// ================================================================================================
+
+/// A key which allows its owner to recognize stealth notes addressed to them.
+///
+/// The sender of a stealth note sets the auxiliary data of the note to the stealth marker derived
+/// from the view key of the recipient and the ID of the sender (see [ViewKey::stealth_marker()]),
+/// instead of using a tag which identifies the recipient. Thus, only the holders of the view key
+/// can recognize the notes addressed to its owner.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ViewKey(Word);
+
+impl ViewKey {
+    /// Returns a new [ViewKey] instantiated from the specified key material.
+    pub fn new(key: Word) -> Self {
+        Self(key)
+    }
+
+    /// Returns the stealth marker for notes sent by the specified sender to the owner of this key,
+    /// computed as:
+    ///
+    /// > hash(view_key, [sender, 0, 0, 0])[0]
+    pub fn stealth_marker(&self, sender: AccountId) -> Felt {
+        let sender = Digest::new([sender.into(), ZERO, ZERO, ZERO]);
+        Hasher::merge(&[Digest::new(self.0), sender]).as_elements()[0]
+    }
+}
+
+impl NoteRelevance for ViewKey {
+    fn check_relevance(&self, envelope: &NoteEnvelope, _details: Option<&Note>) -> Relevance {
+        let metadata = envelope.metadata();
+        match metadata.aux() == self.stealth_marker(metadata.sender()) {
+            true => Relevance::Relevant { reason: RelevanceReason::StealthMatch },
+            false => Relevance::NotRelevant,
+        }
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{
+        NoteRelevance, Relevance, RelevanceReason, ScriptRootSet, TagSubscription, ViewKey,
+    };
+    use crate::{
+        accounts::{AccountId, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_SENDER},
+        assets::FungibleAsset,
+        notes::{
+            Note, NoteAssets, NoteEnvelope, NoteInputs, NoteMetadata, NoteRecipient, NoteScript,
+            NoteTag, NoteType,
+        },
+        Digest, Felt, ONE, ZERO,
+    };
+
+    fn build_note(tag: u32, aux: Felt, script_root: Digest) -> Note {
+        let sender = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let metadata =
+            NoteMetadata::new(sender, NoteType::Public, NoteTag::from(tag), aux).unwrap();
+        let recipient = NoteRecipient::new(
+            [ONE, ZERO, ZERO, ZERO],
+            NoteScript::from_root(script_root),
+            NoteInputs::new(vec![]).unwrap(),
+        );
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let asset = FungibleAsset::new(faucet_id, 10).unwrap();
+        Note::new(NoteAssets::new(vec![asset.into()]).unwrap(), metadata, recipient)
+    }
+
+    #[test]
+    fn test_note_relevance() {
+        let root = Digest::new([ONE, ONE, ZERO, ZERO]);
+        let other_root = Digest::new([ONE, ZERO, ZERO, ONE]);
+        let note = build_note(100, ZERO, root);
+        let envelope = NoteEnvelope::from(&note);
+
+        // tag match
+        let mut subscription = TagSubscription::new();
+        assert_eq!(subscription.check_relevance(&envelope, None), Relevance::NotRelevant);
+        subscription.add_tag(NoteTag::from(100));
+        assert_eq!(
+            subscription.check_relevance(&envelope, None),
+            Relevance::Relevant {
+                reason: RelevanceReason::TagMatch(NoteTag::from(100))
+            }
+        );
+
+        // script root match requires the details of the note
+        let roots = ScriptRootSet::new([root]);
+        assert_eq!(roots.check_relevance(&envelope, None), Relevance::NotRelevant);
+        assert_eq!(
+            roots.check_relevance(&envelope, Some(&note)).reason(),
+            Some(RelevanceReason::ScriptRootMatch(root))
+        );
+        let other_roots = ScriptRootSet::new([other_root]);
+        assert!(!other_roots.check_relevance(&envelope, Some(&note)).is_relevant());
+
+        // stealth match
+        let view_key = ViewKey::new([ONE, Felt::new(2), Felt::new(3), Felt::new(4)]);
+        assert!(!view_key.check_relevance(&envelope, None).is_relevant());
+        let marker = view_key.stealth_marker(note.metadata().sender());
+        let stealth_note = build_note(100, marker, root);
+        assert_eq!(
+            view_key.check_relevance(&NoteEnvelope::from(&stealth_note), None).reason(),
+            Some(RelevanceReason::StealthMatch)
+        );
+        let other_key = ViewKey::new([ONE, ONE, ONE, ONE]);
+        assert!(!other_key
+            .check_relevance(&NoteEnvelope::from(&stealth_note), None)
+            .is_relevant());
+    }
+}
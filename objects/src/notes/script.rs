@@ -21,10 +21,14 @@ const CODE_SERDE_OPTIONS: AstSerdeOptions = AstSerdeOptions::new(true);
 ///
 /// A note's script represents a program which must be executed for a note to be consumed. As such
 /// it defines the rules and side effects of consuming a given note.
+///
+/// A note script may also be instantiated from its MAST root only (see [NoteScript::from_root()]).
+/// Such a script is sufficient to compute the recipient and the ID of a note, but a note with such
+/// a script cannot be executed.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct NoteScript {
     hash: Digest,
-    code: ProgramAst,
+    code: Option<ProgramAst>,
 }
 
 impl NoteScript {
@@ -40,7 +44,8 @@ impl NoteScript {
         let code_block = assembler
             .compile_in_context(&code, &mut AssemblyContext::for_program(Some(&code)))
             .map_err(NoteError::ScriptCompilationError)?;
-        Ok((Self { hash: code_block.hash(), code }, code_block))
+        let hash = code_block.hash();
+        Ok((Self { hash, code: Some(code) }, code_block))
     }
 
     /// Returns a new [NoteScript] instantiated from the provided components.
@@ -48,7 +53,15 @@ impl NoteScript {
     /// **Note**: this function assumes that the specified hash results from the compilation of the
     /// provided program, but this is not checked.
     pub fn from_parts(code: ProgramAst, hash: Digest) -> Self {
-        Self { code, hash }
+        Self { code: Some(code), hash }
+    }
+
+    /// Returns a new [NoteScript] instantiated from the provided MAST root only.
+    ///
+    /// The returned script can be used to compute the recipients and the IDs of notes, but notes
+    /// with this script cannot be executed as the code of the script is not known.
+    pub fn from_root(hash: Digest) -> Self {
+        Self { code: None, hash }
     }
 
     // PUBLIC ACCESSORS
@@ -59,18 +72,29 @@ impl NoteScript {
         self.hash
     }
 
-    /// Returns the AST of this note script.
-    pub fn code(&self) -> &ProgramAst {
-        &self.code
+    /// Returns the AST of this note script, or None if this script was instantiated from its MAST
+    /// root only.
+    pub fn code(&self) -> Option<&ProgramAst> {
+        self.code.as_ref()
+    }
+
+    /// Returns true if this script was instantiated from its MAST root only, and thus cannot be
+    /// executed.
+    pub fn is_root_only(&self) -> bool {
+        self.code.is_none()
     }
 }
 
 // CONVERSIONS INTO NOTE SCRIPT
 // ================================================================================================
 
+/// The encoding of a script instantiated from its MAST root only has a code length of zero.
 impl From<&NoteScript> for Vec<Felt> {
     fn from(value: &NoteScript) -> Self {
-        let mut bytes = value.code.to_bytes(AstSerdeOptions { serialize_imports: true });
+        let mut bytes = match &value.code {
+            Some(code) => code.to_bytes(AstSerdeOptions { serialize_imports: true }),
+            None => Vec::new(),
+        };
         let len = bytes.len();
 
         // Pad the data so that it can be encoded with u32
@@ -118,6 +142,9 @@ impl TryFrom<&[Felt]> for NoteScript {
 
         let hash = Digest::new([value[0], value[1], value[2], value[3]]);
         let len = value[4].as_int();
+        if len == 0 {
+            return Ok(NoteScript::from_root(hash));
+        }
         let mut data = Vec::with_capacity(value.len() * 4);
 
         for felt in &value[5..] {
@@ -147,15 +174,56 @@ impl TryFrom<Vec<Felt>> for NoteScript {
 impl Serializable for NoteScript {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
         self.hash.write_into(target);
-        self.code.write_into(target, CODE_SERDE_OPTIONS);
+        match &self.code {
+            Some(code) => {
+                target.write_bool(true);
+                code.write_into(target, CODE_SERDE_OPTIONS);
+            },
+            None => target.write_bool(false),
+        }
     }
 }
 
 impl Deserializable for NoteScript {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
         let hash = Digest::read_from(source)?;
-        let code = ProgramAst::read_from(source)?;
+        let code = match source.read_bool()? {
+            true => Some(ProgramAst::read_from(source)?),
+            false => None,
+        };
+
+        Ok(Self { hash, code })
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::vec::Vec;
+
+    use miden_crypto::Felt;
+
+    use super::{Digest, NoteScript, ProgramAst};
+    use crate::utils::serde::{Deserializable, Serializable};
+
+    #[test]
+    fn test_root_only_script_serialization() {
+        let root = Digest::new([Felt::new(1), Felt::new(2), Felt::new(3), Felt::new(4)]);
+        let script = NoteScript::from_root(root);
+        assert!(script.is_root_only());
+        assert_eq!(script.hash(), root);
+
+        let bytes = script.to_bytes();
+        assert_eq!(NoteScript::read_from_bytes(&bytes).unwrap(), script);
+
+        let felts: Vec<Felt> = (&script).into();
+        assert_eq!(NoteScript::try_from(felts).unwrap(), script);
 
-        Ok(Self::from_parts(code, hash))
+        let code = ProgramAst::parse("begin push.1 drop end").unwrap();
+        let script = NoteScript::from_parts(code, root);
+        assert!(!script.is_root_only());
+        assert_eq!(NoteScript::read_from_bytes(&script.to_bytes()).unwrap(), script);
     }
 }
@@ -0,0 +1,469 @@
+use alloc::{
+    collections::BTreeMap,
+    string::{String, ToString},
+};
+use core::fmt;
+
+use super::{
+    ByteReader, ByteWriter, Deserializable, DeserializationError, Digest, Note, NoteError,
+    ScriptAttestation, Serializable, Word,
+};
+
+// SCRIPT INFO
+// ================================================================================================
+
+/// Human-readable description of a known note script.
+///
+/// A script is identified by its name (e.g., "P2ID") and version. The name must be non-empty and
+/// at most [ScriptInfo::MAX_NAME_LEN] bytes long.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScriptInfo {
+    name: String,
+    version: u32,
+}
+
+impl ScriptInfo {
+    /// The maximum length of a script name in bytes.
+    pub const MAX_NAME_LEN: usize = u8::MAX as usize;
+
+    /// Returns a new [ScriptInfo] instantiated with the specified name and version.
+    ///
+    /// # Errors
+    /// Returns an error if the name is empty or longer than [ScriptInfo::MAX_NAME_LEN] bytes.
+    pub fn new(name: impl Into<String>, version: u32) -> Result<Self, NoteError> {
+        let name = name.into();
+        if name.is_empty() || name.len() > Self::MAX_NAME_LEN {
+            return Err(NoteError::InvalidScriptName(name));
+        }
+
+        Ok(Self { name, version })
+    }
+
+    /// Returns the name of the script.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the version of the script.
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+}
+
+impl Serializable for ScriptInfo {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(self.name.len() as u8);
+        target.write_bytes(self.name.as_bytes());
+        target.write_u32(self.version);
+    }
+}
+
+impl Deserializable for ScriptInfo {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let name_len = source.read_u8()?;
+        let name = String::from_utf8(source.read_vec(name_len.into())?)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+        let version = source.read_u32()?;
+
+        Self::new(name, version).map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+    }
+}
+
+// SCRIPT REGISTRY
+// ================================================================================================
+
+/// A registry of known note scripts, mapping script roots to their descriptions.
+///
+/// The registry allows users to learn what a note will do before consuming it, and can be used as
+/// an allowlist of scripts which a transaction executor is willing to execute (see
+/// `TransactionExecutor::with_script_registry()` in `miden-tx`).
+///
+/// A script root can be registered only once, while the same name can be registered for several
+/// versions of a script.
+///
+/// Since anyone can register a script under a well-known name, the registry additionally keeps a
+/// set of trusted script publishers, and the attestations of scripts made by them (see
+/// [ScriptAttestation]). This allows wallets to label scripts as attested by a known publisher or
+/// as unverified (see [ScriptRegistry::label()]).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScriptRegistry {
+    scripts: BTreeMap<Digest, ScriptInfo>,
+    publishers: BTreeMap<Digest, String>,
+    attestations: BTreeMap<Digest, ScriptAttestation>,
+}
+
+impl ScriptRegistry {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new empty [ScriptRegistry].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the description of the script with the specified root, if the script is known.
+    pub fn get(&self, script_root: &Digest) -> Option<&ScriptInfo> {
+        self.scripts.get(script_root)
+    }
+
+    /// Returns the description of the script of the specified note, if the script is known.
+    pub fn get_note_script(&self, note: &Note) -> Option<&ScriptInfo> {
+        self.get(&note.script().hash())
+    }
+
+    /// Returns true if the script with the specified root is known.
+    pub fn contains(&self, script_root: &Digest) -> bool {
+        self.scripts.contains_key(script_root)
+    }
+
+    /// Returns the root of the script with the specified name and version, if the script is
+    /// known.
+    pub fn find(&self, name: &str, version: u32) -> Option<Digest> {
+        self.scripts
+            .iter()
+            .find(|(_, info)| info.name() == name && info.version() == version)
+            .map(|(root, _)| *root)
+    }
+
+    /// Returns the number of scripts in this registry.
+    pub fn num_scripts(&self) -> usize {
+        self.scripts.len()
+    }
+
+    /// Returns true if this registry does not contain any scripts.
+    pub fn is_empty(&self) -> bool {
+        self.scripts.is_empty()
+    }
+
+    /// Returns an iterator over the (root, description) pairs of the scripts in this registry.
+    pub fn iter(&self) -> impl Iterator<Item = (&Digest, &ScriptInfo)> {
+        self.scripts.iter()
+    }
+
+    /// Returns the name of the trusted publisher with the specified public key, if the publisher
+    /// is trusted.
+    pub fn publisher(&self, pub_key: Word) -> Option<&str> {
+        self.publishers.get(&Digest::from(pub_key)).map(String::as_str)
+    }
+
+    /// Returns the attestation of the script with the specified root, if the script is attested
+    /// by a trusted publisher.
+    pub fn attestation(&self, script_root: &Digest) -> Option<&ScriptAttestation> {
+        self.attestations.get(script_root)
+    }
+
+    /// Returns the label of the script with the specified root, for display purposes.
+    pub fn label(&self, script_root: &Digest) -> ScriptLabel<'_> {
+        let Some(info) = self.get(script_root) else {
+            return ScriptLabel::Unknown;
+        };
+
+        let publisher = self
+            .attestation(script_root)
+            .and_then(|attestation| self.publisher(attestation.publisher()));
+        match publisher {
+            Some(publisher) => ScriptLabel::Attested { info, publisher },
+            None => ScriptLabel::Unverified(info),
+        }
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Registers the script with the specified root under the specified description.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - A script with the specified root is already registered.
+    /// - A script with the same name and version is already registered.
+    pub fn register(&mut self, script_root: Digest, info: ScriptInfo) -> Result<(), NoteError> {
+        if self.contains(&script_root) {
+            return Err(NoteError::DuplicateScriptRoot(script_root));
+        }
+        if let Some(root) = self.find(info.name(), info.version()) {
+            return Err(NoteError::DuplicateScriptRoot(root));
+        }
+
+        self.scripts.insert(script_root, info);
+        Ok(())
+    }
+
+    /// Removes the script with the specified root from this registry, and returns its
+    /// description if the script was registered.
+    ///
+    /// The attestation of the script, if any, is removed as well.
+    pub fn remove(&mut self, script_root: &Digest) -> Option<ScriptInfo> {
+        self.attestations.remove(script_root);
+        self.scripts.remove(script_root)
+    }
+
+    /// Adds the publisher with the specified public key to the trusted script publishers, under
+    /// the specified name (e.g., "Miden").
+    ///
+    /// If the publisher is already trusted, its name is replaced.
+    ///
+    /// # Errors
+    /// Returns an error if the name is empty or longer than [ScriptInfo::MAX_NAME_LEN] bytes.
+    pub fn add_trusted_publisher(
+        &mut self,
+        pub_key: Word,
+        name: impl Into<String>,
+    ) -> Result<(), NoteError> {
+        let name = name.into();
+        if name.is_empty() || name.len() > ScriptInfo::MAX_NAME_LEN {
+            return Err(NoteError::InvalidScriptPublisherName(name));
+        }
+
+        self.publishers.insert(Digest::from(pub_key), name);
+        Ok(())
+    }
+
+    /// Removes the publisher with the specified public key from the trusted script publishers,
+    /// together with all attestations made by the publisher.
+    pub fn remove_trusted_publisher(&mut self, pub_key: Word) {
+        self.publishers.remove(&Digest::from(pub_key));
+        self.attestations.retain(|_, attestation| attestation.publisher() != pub_key);
+    }
+
+    /// Records the provided attestation of a script by a trusted publisher.
+    ///
+    /// If the attested script is not registered yet, it is registered under the description from
+    /// the attestation. If the script already has an attestation, it is replaced.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The publisher of the attestation is not trusted.
+    /// - The signature of the attestation is not valid.
+    /// - The script is registered under a description other than the attested one.
+    /// - The script is not registered, and a script with the attested name and version is.
+    pub fn attest(&mut self, attestation: ScriptAttestation) -> Result<(), NoteError> {
+        if self.publisher(attestation.publisher()).is_none() {
+            return Err(NoteError::UntrustedScriptPublisher(attestation.publisher().into()));
+        }
+        attestation.verify()?;
+
+        let script_root = attestation.script_root();
+        match self.get(&script_root) {
+            Some(info) if info != attestation.info() => {
+                return Err(NoteError::ScriptAttestationMismatch(script_root));
+            },
+            Some(_) => (),
+            None => self.register(script_root, attestation.info().clone())?,
+        }
+
+        self.attestations.insert(script_root, attestation);
+        Ok(())
+    }
+}
+
+// SCRIPT LABEL
+// ================================================================================================
+
+/// A label of a note script for display purposes, as returned by [ScriptRegistry::label()].
+///
+/// The label is displayed as, e.g., "P2ID v1 — attested by Miden" for attested scripts, and as
+/// "P2ID v1 — unverified" for scripts which are known but not attested by a trusted publisher.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScriptLabel<'a> {
+    /// The script is known and attested by the trusted publisher with the specified name.
+    Attested { info: &'a ScriptInfo, publisher: &'a str },
+    /// The script is known, but not attested by any trusted publisher.
+    Unverified(&'a ScriptInfo),
+    /// The script is not known.
+    Unknown,
+}
+
+impl ScriptLabel<'_> {
+    /// Returns true if the script is attested by a trusted publisher.
+    pub fn is_attested(&self) -> bool {
+        matches!(self, Self::Attested { .. })
+    }
+}
+
+impl fmt::Display for ScriptLabel<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Attested { info, publisher } => {
+                write!(f, "{} v{} — attested by {publisher}", info.name(), info.version())
+            },
+            Self::Unverified(info) => write!(f, "{} v{} — unverified", info.name(), info.version()),
+            Self::Unknown => write!(f, "unknown script"),
+        }
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for ScriptRegistry {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        assert!(self.scripts.len() <= u32::MAX as usize, "too many scripts in the registry");
+        target.write_u32(self.scripts.len() as u32);
+        for (root, info) in self.scripts.iter() {
+            root.write_into(target);
+            info.write_into(target);
+        }
+
+        target.write_u32(self.publishers.len() as u32);
+        for (pub_key, name) in self.publishers.iter() {
+            pub_key.write_into(target);
+            target.write_u8(name.len() as u8);
+            target.write_bytes(name.as_bytes());
+        }
+
+        target.write_u32(self.attestations.len() as u32);
+        for attestation in self.attestations.values() {
+            attestation.write_into(target);
+        }
+    }
+}
+
+impl Deserializable for ScriptRegistry {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let num_scripts = source.read_u32()?;
+
+        let mut registry = Self::new();
+        for _ in 0..num_scripts {
+            let root = Digest::read_from(source)?;
+            let info = ScriptInfo::read_from(source)?;
+            registry
+                .register(root, info)
+                .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+        }
+
+        let num_publishers = source.read_u32()?;
+        for _ in 0..num_publishers {
+            let pub_key = Digest::read_from(source)?;
+            let name_len = source.read_u8()?;
+            let name = String::from_utf8(source.read_vec(name_len.into())?)
+                .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+            registry
+                .add_trusted_publisher(pub_key.into(), name)
+                .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+        }
+
+        // attestations are verified again, so that a tampered registry is rejected
+        let num_attestations = source.read_u32()?;
+        for _ in 0..num_attestations {
+            let attestation = ScriptAttestation::read_from(source)?;
+            registry
+                .attest(attestation)
+                .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+        }
+
+        Ok(registry)
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use alloc::string::ToString;
+
+    use super::{ScriptAttestation, ScriptInfo, ScriptLabel, ScriptRegistry};
+    use crate::{
+        crypto::{auth::AuthSecretKey, dsa::rpo_falcon512::SecretKey, rand::RpoRandomCoin},
+        utils::serde::{Deserializable, Serializable},
+        Digest, Hasher, NoteError, ONE, ZERO,
+    };
+
+    #[test]
+    fn script_registry_register_and_query() {
+        let p2id = Hasher::hash(b"P2ID");
+        let swap = Hasher::hash(b"SWAP");
+
+        let mut registry = ScriptRegistry::new();
+        registry.register(p2id, ScriptInfo::new("P2ID", 1).unwrap()).unwrap();
+        registry.register(swap, ScriptInfo::new("SWAP", 1).unwrap()).unwrap();
+
+        assert_eq!(registry.num_scripts(), 2);
+        assert_eq!(registry.get(&p2id).unwrap().name(), "P2ID");
+        assert_eq!(registry.find("SWAP", 1), Some(swap));
+        assert_eq!(registry.find("SWAP", 2), None);
+
+        assert_eq!(
+            registry.register(p2id, ScriptInfo::new("P2ID", 2).unwrap()),
+            Err(NoteError::DuplicateScriptRoot(p2id))
+        );
+        assert_eq!(
+            registry.register(Hasher::hash(b"other"), ScriptInfo::new("SWAP", 1).unwrap()),
+            Err(NoteError::DuplicateScriptRoot(swap))
+        );
+        assert!(matches!(ScriptInfo::new("", 1), Err(NoteError::InvalidScriptName(_))));
+
+        let bytes = registry.to_bytes();
+        assert_eq!(registry, ScriptRegistry::read_from_bytes(&bytes).unwrap());
+    }
+
+    #[test]
+    fn script_registry_attestations() {
+        let mut rng = RpoRandomCoin::new([ONE, ZERO, ZERO, ZERO]);
+        let miden = AuthSecretKey::RpoFalcon512(SecretKey::with_rng(&mut rng));
+        let impostor = AuthSecretKey::RpoFalcon512(SecretKey::with_rng(&mut rng));
+
+        let p2id = Hasher::hash(b"P2ID");
+        let fake_p2id = Hasher::hash(b"fake P2ID");
+        let docs_hash = Hasher::hash(b"P2ID docs");
+        let p2id_info = ScriptInfo::new("P2ID", 1).unwrap();
+
+        let mut registry = ScriptRegistry::new();
+        registry.add_trusted_publisher(miden.public_key().to_word(), "Miden").unwrap();
+        registry.register(fake_p2id, ScriptInfo::new("P2ID", 2).unwrap()).unwrap();
+        assert_eq!(registry.label(&p2id), ScriptLabel::Unknown);
+
+        // attesting an unregistered script registers it
+        let attestation =
+            miden.sign_script_attestation(p2id, p2id_info.clone(), docs_hash, &mut rng);
+        assert_eq!(attestation.verify(), Ok(()));
+        registry.attest(attestation.clone()).unwrap();
+        assert_eq!(
+            registry.label(&p2id),
+            ScriptLabel::Attested { info: &p2id_info, publisher: "Miden" }
+        );
+        assert_eq!(registry.label(&p2id).to_string(), "P2ID v1 — attested by Miden");
+        assert_eq!(registry.label(&fake_p2id).to_string(), "P2ID v2 — unverified");
+
+        // attestations of untrusted publishers are rejected
+        let fake_attestation = impostor.sign_script_attestation(
+            fake_p2id,
+            ScriptInfo::new("P2ID", 2).unwrap(),
+            docs_hash,
+            &mut rng,
+        );
+        assert_eq!(
+            registry.attest(fake_attestation),
+            Err(NoteError::UntrustedScriptPublisher(impostor.public_key().to_word().into()))
+        );
+
+        // attestations of a description other than the registered one are rejected
+        let mismatching_attestation =
+            miden.sign_script_attestation(fake_p2id, p2id_info.clone(), docs_hash, &mut rng);
+        assert_eq!(
+            registry.attest(mismatching_attestation),
+            Err(NoteError::ScriptAttestationMismatch(fake_p2id))
+        );
+
+        // the signature must cover the attested data
+        let tampered = ScriptAttestation::new(
+            p2id,
+            p2id_info,
+            Digest::default(),
+            attestation.publisher(),
+            attestation.signature().clone(),
+        );
+        assert_eq!(tampered.verify(), Err(NoteError::InvalidScriptAttestation(p2id)));
+
+        let bytes = registry.to_bytes();
+        assert_eq!(registry, ScriptRegistry::read_from_bytes(&bytes).unwrap());
+
+        // attestations are dropped together with the trust in their publisher
+        registry.remove_trusted_publisher(miden.public_key().to_word());
+        assert!(!registry.label(&p2id).is_attested());
+        assert!(registry.attestation(&p2id).is_none());
+    }
+}
@@ -0,0 +1,456 @@
+use alloc::{collections::BTreeSet, string::ToString, vec::Vec};
+
+use super::{
+    note_tag::SWAP_FAUCET_ID_BITS, AccountId, ByteReader, ByteWriter, Deserializable,
+    DeserializationError, Felt, Hasher, NoteError, NoteTag, Serializable,
+};
+
+// TAG PREFIX
+// ================================================================================================
+
+/// A wildcard matching all note tags which start with the specified bits.
+///
+/// A prefix is defined by its length in bits, which must be between 1 and 32, and by its value,
+/// i.e., the `len` most significant bits of the matching tags.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct TagPrefix {
+    len: u8,
+    value: u32,
+}
+
+impl TagPrefix {
+    /// Returns a new [TagPrefix] matching the tags whose `len` most significant bits are equal to
+    /// the `len` most significant bits of the specified tag.
+    ///
+    /// # Errors
+    /// Returns an error if the length is 0 or greater than 32.
+    pub fn new(tag: NoteTag, len: u8) -> Result<Self, NoteError> {
+        if len == 0 || len > 32 {
+            return Err(NoteError::InvalidTagPrefixLength(len));
+        }
+
+        Ok(Self { len, value: prefix_value(tag, len) })
+    }
+
+    /// Returns a new [TagPrefix] matching the tags of all public notes offering an asset issued by
+    /// the specified faucet, regardless of the asset requested in exchange (see
+    /// [NoteTag::for_swap()]).
+    pub fn for_swaps_offering(offered_faucet_id: AccountId) -> Self {
+        // the tag of a swap starts with 8 bits identifying the use case, followed by the bits
+        // derived from the ID of the faucet of the offered asset
+        let tag = NoteTag::for_swap_pair(offered_faucet_id, offered_faucet_id);
+        Self::new(tag, (TAG_LEN as u32 - SWAP_FAUCET_ID_BITS) as u8)
+            .expect("swap prefix length is valid")
+    }
+
+    /// Returns the length of this prefix in bits.
+    pub fn bit_len(&self) -> u8 {
+        self.len
+    }
+
+    /// Returns the value of this prefix, i.e., the most significant bits of the matching tags.
+    pub fn value(&self) -> u32 {
+        self.value
+    }
+
+    /// Returns true if the specified tag starts with this prefix.
+    pub fn matches(&self, tag: NoteTag) -> bool {
+        prefix_value(tag, self.len) == self.value
+    }
+}
+
+impl Serializable for TagPrefix {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(self.len);
+        target.write_u32(self.value);
+    }
+}
+
+impl Deserializable for TagPrefix {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let len = source.read_u8()?;
+        let value = source.read_u32()?;
+
+        if len == 0 || len > 32 {
+            return Err(DeserializationError::InvalidValue(
+                NoteError::InvalidTagPrefixLength(len).to_string(),
+            ));
+        }
+        if len < 32 && value >> len != 0 {
+            return Err(DeserializationError::InvalidValue(format!(
+                "tag prefix value {value} does not fit into {len} bits"
+            )));
+        }
+
+        Ok(Self { len, value })
+    }
+}
+
+// TAG SUBSCRIPTION
+// ================================================================================================
+
+/// A set of note tags and tag prefixes which a client is interested in.
+///
+/// A tag is contained in the subscription if it is one of the subscribed tags, or if it starts
+/// with one of the subscribed prefixes. Prefixes allow clients to hide the exact tags they are
+/// interested in, at the cost of receiving notes which are not relevant to them.
+///
+/// To further improve privacy, the subscription can be exported into a [TagFilter] (see
+/// [TagSubscription::to_filter()]), which can be sent to the nodes instead of the subscription
+/// itself.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TagSubscription {
+    tags: BTreeSet<NoteTag>,
+    prefixes: BTreeSet<TagPrefix>,
+}
+
+impl TagSubscription {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new empty [TagSubscription].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns true if the specified tag is one of the subscribed tags or starts with one of the
+    /// subscribed prefixes.
+    pub fn contains(&self, tag: NoteTag) -> bool {
+        self.tags.contains(&tag) || self.prefixes.iter().any(|prefix| prefix.matches(tag))
+    }
+
+    /// Returns the number of subscribed tags.
+    pub fn num_tags(&self) -> usize {
+        self.tags.len()
+    }
+
+    /// Returns the number of subscribed prefixes.
+    pub fn num_prefixes(&self) -> usize {
+        self.prefixes.len()
+    }
+
+    /// Returns true if this subscription contains neither tags nor prefixes.
+    pub fn is_empty(&self) -> bool {
+        self.tags.is_empty() && self.prefixes.is_empty()
+    }
+
+    /// Returns an iterator over the subscribed tags.
+    pub fn tags(&self) -> impl Iterator<Item = &NoteTag> {
+        self.tags.iter()
+    }
+
+    /// Returns an iterator over the subscribed prefixes.
+    pub fn prefixes(&self) -> impl Iterator<Item = &TagPrefix> {
+        self.prefixes.iter()
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Adds the specified tag to this subscription, and returns true if it was not subscribed to
+    /// before.
+    pub fn add_tag(&mut self, tag: NoteTag) -> bool {
+        self.tags.insert(tag)
+    }
+
+    /// Adds the specified prefix to this subscription, and returns true if it was not subscribed
+    /// to before.
+    pub fn add_prefix(&mut self, prefix: TagPrefix) -> bool {
+        self.prefixes.insert(prefix)
+    }
+
+    /// Removes the specified tag from this subscription, and returns true if it was subscribed to.
+    ///
+    /// The tag may still be contained in the subscription if it matches one of the prefixes.
+    pub fn remove_tag(&mut self, tag: NoteTag) -> bool {
+        self.tags.remove(&tag)
+    }
+
+    /// Removes the specified prefix from this subscription, and returns true if it was subscribed
+    /// to.
+    pub fn remove_prefix(&mut self, prefix: TagPrefix) -> bool {
+        self.prefixes.remove(&prefix)
+    }
+
+    // FILTER EXPORT
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a [TagFilter] which contains all tags and prefixes of this subscription.
+    ///
+    /// The filter allocates the specified number of bits per tag or prefix; the more bits are
+    /// allocated, the lower the false positive rate of the filter (e.g., about 1% for 10 bits).
+    ///
+    /// # Panics
+    /// Panics if `bits_per_entry` is 0.
+    pub fn to_filter(&self, bits_per_entry: u8) -> TagFilter {
+        assert!(bits_per_entry > 0, "filter must allocate at least one bit per entry");
+
+        let num_entries = self.tags.len() + self.prefixes.len();
+        let num_bits = (num_entries.max(1) * bits_per_entry as usize).next_multiple_of(64);
+        // the optimal number of hash functions is bits_per_entry * ln(2)
+        let num_hashes = (bits_per_entry as u32 * 7 / 10).max(1) as u8;
+
+        let mut filter = TagFilter {
+            num_hashes,
+            prefix_lens: self.prefixes.iter().map(|prefix| prefix.len).collect(),
+            bits: vec![0; num_bits / 64],
+        };
+        for tag in self.tags.iter() {
+            filter.insert(TAG_LEN, tag.inner());
+        }
+        for prefix in self.prefixes.iter() {
+            filter.insert(prefix.len, prefix.value);
+        }
+
+        filter
+    }
+}
+
+impl Serializable for TagSubscription {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        assert!(self.tags.len() <= u32::MAX as usize, "too many tags in the subscription");
+        assert!(
+            self.prefixes.len() <= u32::MAX as usize,
+            "too many prefixes in the subscription"
+        );
+
+        target.write_u32(self.tags.len() as u32);
+        target.write_many(&self.tags);
+        target.write_u32(self.prefixes.len() as u32);
+        target.write_many(&self.prefixes);
+    }
+}
+
+impl Deserializable for TagSubscription {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let num_tags = source.read_u32()? as usize;
+        let tags = source.read_many::<NoteTag>(num_tags)?.into_iter().collect();
+        let num_prefixes = source.read_u32()? as usize;
+        let prefixes = source.read_many::<TagPrefix>(num_prefixes)?.into_iter().collect();
+
+        Ok(Self { tags, prefixes })
+    }
+}
+
+// TAG FILTER
+// ================================================================================================
+
+/// A Bloom filter over the tags and prefixes of a [TagSubscription].
+///
+/// The filter can be sent to a node instead of the subscription itself, so that the node does not
+/// learn the exact tags a client is interested in. The node returns the notes whose tags are
+/// contained in the filter, which includes all notes relevant to the client and, with a
+/// probability depending on the size of the filter, some unrelated notes.
+///
+/// Every entry of the filter is hashed together with its length in bits (32 for tags), and thus
+/// to check whether a tag is contained in the filter, the filter also records the lengths of the
+/// subscribed prefixes.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TagFilter {
+    num_hashes: u8,
+    prefix_lens: BTreeSet<u8>,
+    bits: Vec<u64>,
+}
+
+impl TagFilter {
+    /// Returns true if the specified tag, or one of its prefixes, may be contained in the
+    /// subscription from which this filter was built.
+    ///
+    /// The filter never returns false for tags contained in the subscription, but may return true
+    /// for tags which are not.
+    pub fn contains(&self, tag: NoteTag) -> bool {
+        self.contains_entry(TAG_LEN, tag.inner())
+            || self
+                .prefix_lens
+                .iter()
+                .any(|&len| self.contains_entry(len, prefix_value(tag, len)))
+    }
+
+    /// Returns the number of bits in this filter.
+    pub fn num_bits(&self) -> usize {
+        self.bits.len() * 64
+    }
+
+    /// Returns the number of hash functions used by this filter.
+    pub fn num_hashes(&self) -> u8 {
+        self.num_hashes
+    }
+
+    // HELPER METHODS
+    // --------------------------------------------------------------------------------------------
+
+    /// Sets the bits corresponding to the specified entry.
+    fn insert(&mut self, len: u8, value: u32) {
+        for index in self.bit_indices(len, value) {
+            self.bits[index / 64] |= 1 << (index % 64);
+        }
+    }
+
+    /// Returns true if all bits corresponding to the specified entry are set.
+    fn contains_entry(&self, len: u8, value: u32) -> bool {
+        self.bit_indices(len, value)
+            .all(|index| self.bits[index / 64] & (1 << (index % 64)) != 0)
+    }
+
+    /// Returns the indices of the bits corresponding to the specified entry.
+    ///
+    /// The indices are derived from hash([len, value]) via double hashing, i.e., the i-th index is
+    /// computed as (h1 + i * h2) mod num_bits, where h1 and h2 are the first two elements of the
+    /// hash.
+    fn bit_indices(&self, len: u8, value: u32) -> impl Iterator<Item = usize> {
+        let digest = Hasher::hash_elements(&[Felt::from(len), Felt::from(value)]);
+        let h1 = digest[0].as_int();
+        let h2 = digest[1].as_int();
+        let num_bits = self.num_bits() as u64;
+
+        (0..self.num_hashes as u64)
+            .map(move |i| (h1.wrapping_add(i.wrapping_mul(h2)) % num_bits) as usize)
+    }
+}
+
+impl Serializable for TagFilter {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        assert!(self.bits.len() <= u32::MAX as usize, "too many bits in the filter");
+
+        target.write_u8(self.num_hashes);
+        target.write_u8(self.prefix_lens.len() as u8);
+        target.write_many(&self.prefix_lens);
+        target.write_u32(self.bits.len() as u32);
+        target.write_many(&self.bits);
+    }
+}
+
+impl Deserializable for TagFilter {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let num_hashes = source.read_u8()?;
+        let num_prefix_lens = source.read_u8()? as usize;
+        let prefix_lens: BTreeSet<u8> = source.read_many(num_prefix_lens)?.into_iter().collect();
+        let num_words = source.read_u32()? as usize;
+        let bits = source.read_many(num_words)?;
+
+        if num_hashes == 0 || bits.is_empty() {
+            return Err(DeserializationError::InvalidValue("tag filter is empty".to_string()));
+        }
+        if let Some(&len) = prefix_lens.iter().find(|&&len| len == 0 || len > TAG_LEN) {
+            return Err(DeserializationError::InvalidValue(
+                NoteError::InvalidTagPrefixLength(len).to_string(),
+            ));
+        }
+
+        Ok(Self { num_hashes, prefix_lens, bits })
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// The length of a note tag in bits.
+const TAG_LEN: u8 = 32;
+
+/// Returns the `len` most significant bits of the specified tag.
+fn prefix_value(tag: NoteTag, len: u8) -> u32 {
+    tag.inner().checked_shr((TAG_LEN - len) as u32).unwrap_or(0)
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{TagFilter, TagPrefix, TagSubscription};
+    use crate::{
+        accounts::{AccountId, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN},
+        assets::{Asset, FungibleAsset},
+        notes::{NoteExecutionMode, NoteTag, NoteType},
+        utils::serde::{Deserializable, Serializable},
+        NoteError,
+    };
+
+    #[test]
+    fn tag_subscription_membership() {
+        let mut subscription = TagSubscription::new();
+        assert!(subscription.add_tag(NoteTag::from(0x1234_5678)));
+        assert!(subscription.add_prefix(TagPrefix::new(NoteTag::from(0xabcd_0000), 16).unwrap()));
+        assert!(!subscription.add_tag(NoteTag::from(0x1234_5678)));
+
+        assert!(subscription.contains(NoteTag::from(0x1234_5678)));
+        assert!(!subscription.contains(NoteTag::from(0x1234_5679)));
+        assert!(subscription.contains(NoteTag::from(0xabcd_0000)));
+        assert!(subscription.contains(NoteTag::from(0xabcd_ffff)));
+        assert!(!subscription.contains(NoteTag::from(0xabce_0000)));
+
+        assert_eq!(TagPrefix::new(NoteTag::from(0), 0), Err(NoteError::InvalidTagPrefixLength(0)));
+        assert_eq!(
+            TagPrefix::new(NoteTag::from(0), 33),
+            Err(NoteError::InvalidTagPrefixLength(33))
+        );
+
+        let bytes = subscription.to_bytes();
+        assert_eq!(subscription, TagSubscription::read_from_bytes(&bytes).unwrap());
+    }
+
+    #[test]
+    fn tag_subscription_filter() {
+        let mut subscription = TagSubscription::new();
+        for tag in 0..100_u32 {
+            subscription.add_tag(NoteTag::from(tag * 7919));
+        }
+        subscription.add_prefix(TagPrefix::new(NoteTag::from(0xabcd_0000), 16).unwrap());
+
+        let filter = subscription.to_filter(10);
+        assert_eq!(filter.num_hashes(), 7);
+        assert_eq!(filter.num_bits(), 1024);
+
+        // the filter contains all subscribed tags and all tags matching the prefixes
+        assert!((0..100_u32).all(|tag| filter.contains(NoteTag::from(tag * 7919))));
+        assert!(filter.contains(NoteTag::from(0xabcd_1234)));
+
+        // and only a few other tags
+        let false_positives = (0..1000_u32)
+            .filter(|tag| filter.contains(NoteTag::from(tag * 7919 + 1)))
+            .count();
+        assert!(false_positives < 50);
+
+        let bytes = filter.to_bytes();
+        assert_eq!(filter, TagFilter::read_from_bytes(&bytes).unwrap());
+    }
+
+    #[test]
+    fn swap_tag_subscription() {
+        let faucet = |bits: u64| {
+            AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN | (bits << 48)).unwrap()
+        };
+        let (faucet_a, faucet_b, faucet_c) = (faucet(0x123), faucet(0x456), faucet(0x789));
+        let asset_a: Asset = FungibleAsset::new(faucet_a, 100).unwrap().into();
+        let asset_b: Asset = FungibleAsset::new(faucet_b, 50).unwrap().into();
+
+        // the tag depends only on the faucets of the swapped assets, and can be used only with
+        // public notes
+        let tag = NoteTag::for_swap(&asset_a, &asset_b);
+        assert_eq!(tag.inner(), 0xa012_3456);
+        assert!(tag.is_swap());
+        assert!(tag.matches_swap(faucet_a, faucet_b));
+        assert!(!tag.matches_swap(faucet_b, faucet_a));
+        assert!(!NoteTag::broadcast().is_swap());
+        assert_eq!(tag.execution_mode(), NoteExecutionMode::Local);
+        assert!(!tag.is_single_target());
+        assert_eq!(tag.group_id(), None);
+        assert!(tag.validate(NoteType::Public).is_ok());
+        assert!(tag.validate(NoteType::OffChain).is_err());
+
+        // clients can subscribe to swaps of a given pair of assets, or to all swaps offering a
+        // given asset
+        let mut subscription = TagSubscription::new();
+        subscription.add_tag(NoteTag::for_swap_pair(faucet_a, faucet_b));
+        subscription.add_prefix(TagPrefix::for_swaps_offering(faucet_c));
+
+        assert!(subscription.contains(tag));
+        assert!(!subscription.contains(NoteTag::for_swap_pair(faucet_b, faucet_a)));
+        assert!(subscription.contains(NoteTag::for_swap_pair(faucet_c, faucet_a)));
+        assert!(subscription.contains(NoteTag::for_swap_pair(faucet_c, faucet_b)));
+        assert!(!subscription.contains(NoteTag::for_swap_pair(faucet_a, faucet_c)));
+    }
+}
@@ -1,8 +1,9 @@
-use alloc::{collections::BTreeMap, vec::Vec};
+use alloc::{collections::BTreeMap, string::ToString, vec::Vec};
 
 use crate::{
-    crypto::merkle::{InnerNodeInfo, MmrPeaks, PartialMmr},
-    BlockHeader, ChainMmrError,
+    crypto::merkle::{InnerNodeInfo, MerklePath, MmrPeaks, PartialMmr},
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    BlockHeader, ChainMmrError, Digest,
 };
 
 // CHAIN MMR
@@ -84,6 +85,17 @@ impl ChainMmr {
         self.blocks.get(&block_num)
     }
 
+    /// Returns the authentication path of the specified block against the peaks of this MMR, or
+    /// None if the block is not present in this chain MMR.
+    pub fn get_block_path(&self, block_num: u32) -> Option<MerklePath> {
+        if !self.contains_block(block_num) {
+            return None;
+        }
+
+        let proof = self.mmr.open(block_num as usize).expect("block is within the chain length");
+        proof.map(|proof| proof.merkle_path)
+    }
+
     // DATA MUTATORS
     // --------------------------------------------------------------------------------------------
 
@@ -113,6 +125,50 @@ impl ChainMmr {
     }
 }
 
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for ChainMmr {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        let peaks = self.peaks();
+        target.write_u64(peaks.num_leaves() as u64);
+        target.write_u8(peaks.num_peaks() as u8);
+        target.write_many(peaks.peaks());
+
+        // the partial MMR is rebuilt from the peaks and the authentication paths of the blocks
+        target.write_u32(self.blocks.len() as u32);
+        for (&block_num, block) in self.blocks.iter() {
+            block.write_into(target);
+            self.get_block_path(block_num)
+                .expect("all blocks are tracked by the partial MMR")
+                .write_into(target);
+        }
+    }
+}
+
+impl Deserializable for ChainMmr {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let num_leaves = source.read_u64()? as usize;
+        let num_peaks = source.read_u8()? as usize;
+        let peaks = source.read_many::<Digest>(num_peaks)?;
+        let peaks = MmrPeaks::new(num_leaves, peaks)
+            .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+
+        let mut mmr = PartialMmr::from_peaks(peaks);
+        let num_blocks = source.read_u32()? as usize;
+        let mut blocks = Vec::with_capacity(num_blocks.min(u16::MAX as usize));
+        for _ in 0..num_blocks {
+            let block = BlockHeader::read_from(source)?;
+            let path = MerklePath::read_from(source)?;
+            mmr.track(block.block_num() as usize, block.hash(), &path)
+                .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+            blocks.push(block);
+        }
+
+        Self::new(mmr, blocks).map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+    }
+}
+
 // TESTS
 // ================================================================================================
 
@@ -122,6 +178,7 @@ mod tests {
     use crate::{
         alloc::vec::Vec,
         crypto::merkle::{Mmr, PartialMmr},
+        utils::serde::{Deserializable, Serializable},
         BlockHeader, Digest, ZERO,
     };
 
@@ -170,6 +227,28 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_chain_mmr_serde() {
+        let mut mmr = Mmr::default();
+        let mut headers = Vec::new();
+        for i in 0..7 {
+            let block_header = int_to_block_header(i);
+            mmr.add(block_header.hash());
+            headers.push(block_header);
+        }
+
+        let mut partial_mmr: PartialMmr = mmr.peaks(mmr.forest()).unwrap().into();
+        for &block_num in [1, 4].iter() {
+            let path = mmr.open(block_num, mmr.forest()).unwrap().merkle_path;
+            partial_mmr.track(block_num, headers[block_num].hash(), &path).unwrap();
+        }
+        let chain_mmr = ChainMmr::new(partial_mmr, vec![headers[1], headers[4]]).unwrap();
+
+        let deserialized = ChainMmr::read_from_bytes(&chain_mmr.to_bytes()).unwrap();
+        assert_eq!(deserialized, chain_mmr);
+        assert_eq!(deserialized.get_block_path(4), chain_mmr.get_block_path(4));
+    }
+
     fn int_to_block_header(block_num: u32) -> BlockHeader {
         BlockHeader::new(
             Digest::default(),
@@ -182,6 +261,7 @@ mod tests {
             Digest::default(),
             ZERO,
             ZERO,
+            Digest::default(),
         )
     }
 }
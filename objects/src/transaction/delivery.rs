@@ -0,0 +1,131 @@
+use alloc::{collections::BTreeMap, vec::Vec};
+
+use super::{OutputNote, Word};
+use crate::{
+    notes::{Note, NoteEnvelope, NoteId},
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+};
+
+// NOTE DELIVERY INSTRUCTION
+// ================================================================================================
+
+/// Specifies how the details of an output note are delivered to its recipient once the
+/// transaction which creates the note has been proven.
+///
+/// Delivery instructions are attached to output notes together with the details of the notes via
+/// [TransactionArgs::set_note_delivery()](super::TransactionArgs::set_note_delivery), and
+/// determine whether a note is included in the proven transaction as an [OutputNote::Public] or
+/// an [OutputNote::Private], regardless of the type of the note.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NoteDeliveryInstruction {
+    /// Publish the full details of the note as part of the proven transaction.
+    Publish,
+    /// Keep the details of the note private, and encrypt them to the specified public key for
+    /// delivery to the recipient.
+    Encrypt { pub_key: Word },
+    /// Keep the details of the note private, and export them for delivery outside of the network
+    /// (e.g., by writing them to a file).
+    Export,
+}
+
+impl NoteDeliveryInstruction {
+    /// Returns the output note which is included in the proven transaction for the note with the
+    /// specified details under this instruction.
+    pub fn apply(&self, note: &Note) -> OutputNote {
+        match self {
+            Self::Publish => OutputNote::Public(note.clone()),
+            Self::Encrypt { .. } | Self::Export => OutputNote::Private(NoteEnvelope::from(note)),
+        }
+    }
+}
+
+impl Serializable for NoteDeliveryInstruction {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        match self {
+            Self::Publish => target.write_u8(0),
+            Self::Encrypt { pub_key } => {
+                target.write_u8(1);
+                pub_key.write_into(target);
+            },
+            Self::Export => target.write_u8(2),
+        }
+    }
+}
+
+impl Deserializable for NoteDeliveryInstruction {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        match source.read_u8()? {
+            0 => Ok(Self::Publish),
+            1 => Ok(Self::Encrypt { pub_key: Word::read_from(source)? }),
+            2 => Ok(Self::Export),
+            v => Err(DeserializationError::InvalidValue(format!(
+                "Invalid note delivery instruction: {v}"
+            ))),
+        }
+    }
+}
+
+// NOTE DELIVERY
+// ================================================================================================
+
+/// The outcome of delivering an output note according to its [NoteDeliveryInstruction].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NoteDelivery {
+    /// The full details of the note were published as part of the proven transaction.
+    Published,
+    /// The details of the note were encrypted to the specified public key.
+    Encrypted { pub_key: Word, ciphertext: Vec<u8> },
+    /// The details of the note were exported, and must be delivered to the recipient outside of
+    /// the network.
+    Exported(Note),
+}
+
+// DELIVERY REPORT
+// ================================================================================================
+
+/// A report on the delivery of the output notes of a proven transaction, produced for all output
+/// notes with a [NoteDeliveryInstruction].
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DeliveryReport {
+    deliveries: BTreeMap<NoteId, NoteDelivery>,
+}
+
+impl DeliveryReport {
+    /// Returns a new empty [DeliveryReport].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records the delivery of the note with the specified ID.
+    pub fn insert(&mut self, note_id: NoteId, delivery: NoteDelivery) {
+        self.deliveries.insert(note_id, delivery);
+    }
+
+    /// Returns the delivery of the note with the specified ID, if any.
+    pub fn get(&self, note_id: NoteId) -> Option<&NoteDelivery> {
+        self.deliveries.get(&note_id)
+    }
+
+    /// Returns an iterator over the deliveries of all notes in this report.
+    pub fn iter(&self) -> impl Iterator<Item = (&NoteId, &NoteDelivery)> {
+        self.deliveries.iter()
+    }
+
+    /// Returns an iterator over the notes whose details were exported.
+    pub fn exported_notes(&self) -> impl Iterator<Item = &Note> {
+        self.deliveries.values().filter_map(|delivery| match delivery {
+            NoteDelivery::Exported(note) => Some(note),
+            _ => None,
+        })
+    }
+
+    /// Returns the number of notes in this report.
+    pub fn num_notes(&self) -> usize {
+        self.deliveries.len()
+    }
+
+    /// Returns true if this report does not contain any notes.
+    pub fn is_empty(&self) -> bool {
+        self.deliveries.is_empty()
+    }
+}
@@ -0,0 +1,447 @@
+use alloc::vec::Vec;
+
+use super::{AccountId, Digest, ExecutedTransaction, FeeEstimate, OutputNote, Word};
+use crate::{
+    assets::Asset,
+    notes::{NoteId, NoteTag, NoteType, ScriptInfo, ScriptRegistry},
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    EMPTY_WORD,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+const SENDER: u8 = 0;
+const RECIPIENT: u8 = 1;
+
+// TRANSACTION DESCRIPTION
+// ================================================================================================
+
+/// A structured description of the effects of an executed transaction, intended to be rendered
+/// to the user (e.g., on the confirmation screen of a wallet) before the transaction is proven and
+/// submitted.
+///
+/// The description is built from the account delta and the notes consumed and created by the
+/// transaction (see [ExecutedTransaction::describe()]):
+/// - Assets in are the assets carried by the consumed notes, grouped by the sender of the notes.
+/// - Assets out are the assets carried by the created public notes, grouped by the recipient of
+///   the notes. The assets of created private notes are not known to the executed transaction, and
+///   so the net changes of the account vault are described as well.
+/// - Storage changes are the storage items updated by the transaction, in the order of the storage
+///   delta.
+/// - The fee is the total fee estimated for the transaction (see [FeeEstimate]).
+/// - Created notes are described by their ID, type, tag, and script, where the script is named if
+///   it is known to the provided script registry.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TxSummary {
+    account_id: AccountId,
+    assets_in: Vec<AssetTransfer>,
+    assets_out: Vec<AssetTransfer>,
+    received: Vec<Asset>,
+    sent: Vec<Asset>,
+    storage_changes: Vec<StorageChange>,
+    fee: u64,
+    created_notes: Vec<CreatedNote>,
+}
+
+impl TxSummary {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the description of the specified executed transaction, naming the scripts of the
+    /// created notes via the specified script registry.
+    pub(super) fn new(executed_tx: &ExecutedTransaction, registry: &ScriptRegistry) -> Self {
+        let mut assets_in = Vec::new();
+        for input_note in executed_tx.input_notes().iter() {
+            let note = input_note.note();
+            let counterparty = Counterparty::Sender(note.metadata().sender());
+            AssetTransfer::add_all(&mut assets_in, counterparty, note.assets().iter());
+        }
+
+        let mut assets_out = Vec::new();
+        let mut created_notes = Vec::new();
+        for output_note in executed_tx.output_notes().iter() {
+            if let OutputNote::Public(note) = output_note {
+                let counterparty = Counterparty::Recipient(note.recipient_digest());
+                AssetTransfer::add_all(&mut assets_out, counterparty, note.assets().iter());
+            }
+            created_notes.push(CreatedNote::new(output_note, registry));
+        }
+
+        let vault_delta = executed_tx.account_delta().vault();
+        let storage_delta = executed_tx.account_delta().storage();
+        let storage_changes = storage_delta
+            .cleared_items
+            .iter()
+            .map(|&index| StorageChange::new(index, None, EMPTY_WORD))
+            .chain(
+                storage_delta
+                    .updated_items
+                    .iter()
+                    .map(|&(index, value)| StorageChange::new(index, None, value)),
+            )
+            .chain(storage_delta.updated_array_items.iter().map(|&(index, item_index, value)| {
+                StorageChange::new(index, Some(item_index), value)
+            }))
+            .collect();
+
+        let fee = FeeEstimate::new(
+            executed_tx.tx_inputs().kernel_config(),
+            executed_tx.input_notes().num_notes(),
+            executed_tx.output_notes().num_notes(),
+            executed_tx.tx_args().inclusion_hints().priority_fee(),
+        )
+        .total();
+
+        Self {
+            account_id: executed_tx.account_id(),
+            assets_in,
+            assets_out,
+            received: vault_delta.added_assets.clone(),
+            sent: vault_delta.removed_assets.clone(),
+            storage_changes,
+            fee,
+            created_notes,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the ID of the account against which the transaction was executed.
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Returns the assets carried by the consumed notes, grouped by the sender of the notes.
+    pub fn assets_in(&self) -> &[AssetTransfer] {
+        &self.assets_in
+    }
+
+    /// Returns the assets carried by the created public notes, grouped by the recipient of the
+    /// notes.
+    pub fn assets_out(&self) -> &[AssetTransfer] {
+        &self.assets_out
+    }
+
+    /// Returns the assets added to the account vault by the transaction.
+    pub fn received(&self) -> &[Asset] {
+        &self.received
+    }
+
+    /// Returns the assets removed from the account vault by the transaction.
+    pub fn sent(&self) -> &[Asset] {
+        &self.sent
+    }
+
+    /// Returns the changes made to the account storage by the transaction.
+    pub fn storage_changes(&self) -> &[StorageChange] {
+        &self.storage_changes
+    }
+
+    /// Returns the total fee estimated for the transaction.
+    pub fn fee(&self) -> u64 {
+        self.fee
+    }
+
+    /// Returns the descriptions of the notes created by the transaction.
+    pub fn created_notes(&self) -> &[CreatedNote] {
+        &self.created_notes
+    }
+}
+
+impl Serializable for TxSummary {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.account_id.write_into(target);
+        write_vec(target, &self.assets_in);
+        write_vec(target, &self.assets_out);
+        write_vec(target, &self.received);
+        write_vec(target, &self.sent);
+        write_vec(target, &self.storage_changes);
+        target.write_u64(self.fee);
+        write_vec(target, &self.created_notes);
+    }
+}
+
+impl Deserializable for TxSummary {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self {
+            account_id: AccountId::read_from(source)?,
+            assets_in: read_vec(source)?,
+            assets_out: read_vec(source)?,
+            received: read_vec(source)?,
+            sent: read_vec(source)?,
+            storage_changes: read_vec(source)?,
+            fee: source.read_u64()?,
+            created_notes: read_vec(source)?,
+        })
+    }
+}
+
+// COUNTERPARTY
+// ================================================================================================
+
+/// A party with which assets are exchanged in a transaction.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Counterparty {
+    /// The account which created the consumed notes carrying the assets.
+    Sender(AccountId),
+    /// The recipient of the created notes carrying the assets, i.e., the commitment to the serial
+    /// number, script and inputs of the notes (see [NoteRecipient](crate::notes::NoteRecipient)).
+    Recipient(Digest),
+}
+
+impl Serializable for Counterparty {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        match self {
+            Counterparty::Sender(account_id) => {
+                target.write_u8(SENDER);
+                account_id.write_into(target);
+            },
+            Counterparty::Recipient(recipient) => {
+                target.write_u8(RECIPIENT);
+                recipient.write_into(target);
+            },
+        }
+    }
+}
+
+impl Deserializable for Counterparty {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        match source.read_u8()? {
+            SENDER => Ok(Counterparty::Sender(AccountId::read_from(source)?)),
+            RECIPIENT => Ok(Counterparty::Recipient(Digest::read_from(source)?)),
+            v => Err(DeserializationError::InvalidValue(format!("Invalid counterparty type: {v}"))),
+        }
+    }
+}
+
+// ASSET TRANSFER
+// ================================================================================================
+
+/// The assets exchanged with a single counterparty in a transaction.
+///
+/// Fungible assets issued by the same faucet are merged into a single asset, unless their total
+/// amount exceeds the maximum amount of a fungible asset.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssetTransfer {
+    counterparty: Counterparty,
+    assets: Vec<Asset>,
+}
+
+impl AssetTransfer {
+    /// Returns the counterparty of this transfer.
+    pub fn counterparty(&self) -> Counterparty {
+        self.counterparty
+    }
+
+    /// Returns the assets exchanged with the counterparty.
+    pub fn assets(&self) -> &[Asset] {
+        &self.assets
+    }
+
+    /// Adds the specified assets to the transfer with the specified counterparty, creating the
+    /// transfer if it is not in the list yet.
+    fn add_all<'a>(
+        transfers: &mut Vec<AssetTransfer>,
+        counterparty: Counterparty,
+        assets: impl Iterator<Item = &'a Asset>,
+    ) {
+        let idx = match transfers.iter().position(|t| t.counterparty == counterparty) {
+            Some(idx) => idx,
+            None => {
+                transfers.push(AssetTransfer { counterparty, assets: Vec::new() });
+                transfers.len() - 1
+            },
+        };
+
+        for asset in assets {
+            transfers[idx].add_asset(*asset);
+        }
+    }
+
+    /// Adds the specified asset to this transfer, merging it with a fungible asset of the same
+    /// faucet if possible.
+    fn add_asset(&mut self, asset: Asset) {
+        if let Asset::Fungible(fungible_asset) = asset {
+            for existing in self.assets.iter_mut() {
+                if let Asset::Fungible(existing_asset) = existing {
+                    if let Ok(merged) = existing_asset.add(fungible_asset) {
+                        *existing = merged.into();
+                        return;
+                    }
+                }
+            }
+        }
+        self.assets.push(asset);
+    }
+}
+
+impl Serializable for AssetTransfer {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.counterparty.write_into(target);
+        write_vec(target, &self.assets);
+    }
+}
+
+impl Deserializable for AssetTransfer {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let counterparty = Counterparty::read_from(source)?;
+        let assets = read_vec(source)?;
+        Ok(Self { counterparty, assets })
+    }
+}
+
+// STORAGE CHANGE
+// ================================================================================================
+
+/// A change of an item in the account storage.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StorageChange {
+    index: u8,
+    item_index: Option<u64>,
+    value: Word,
+}
+
+impl StorageChange {
+    fn new(index: u8, item_index: Option<u64>, value: Word) -> Self {
+        Self { index, item_index, value }
+    }
+
+    /// Returns the index of the storage slot which was changed.
+    pub fn index(&self) -> u8 {
+        self.index
+    }
+
+    /// Returns the index of the item within the array stored in the slot, if an array item was
+    /// changed.
+    pub fn item_index(&self) -> Option<u64> {
+        self.item_index
+    }
+
+    /// Returns the new value of the item. The value of cleared items is [EMPTY_WORD].
+    pub fn value(&self) -> Word {
+        self.value
+    }
+}
+
+impl Serializable for StorageChange {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write_u8(self.index);
+        self.item_index.write_into(target);
+        self.value.write_into(target);
+    }
+}
+
+impl Deserializable for StorageChange {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let index = source.read_u8()?;
+        let item_index = Option::<u64>::read_from(source)?;
+        let value = Word::read_from(source)?;
+        Ok(Self { index, item_index, value })
+    }
+}
+
+// CREATED NOTE
+// ================================================================================================
+
+/// A description of a note created by a transaction.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreatedNote {
+    id: NoteId,
+    note_type: NoteType,
+    tag: NoteTag,
+    script: Option<ScriptInfo>,
+    assets: Option<Vec<Asset>>,
+}
+
+impl CreatedNote {
+    fn new(note: &OutputNote, registry: &ScriptRegistry) -> Self {
+        let (script, assets) = match note {
+            OutputNote::Public(note) => (
+                registry.get_note_script(note).cloned(),
+                Some(note.assets().iter().copied().collect()),
+            ),
+            OutputNote::Private(_) => (None, None),
+        };
+
+        Self {
+            id: note.id(),
+            note_type: note.metadata().note_type(),
+            tag: note.metadata().tag(),
+            script,
+            assets,
+        }
+    }
+
+    /// Returns the ID of the note.
+    pub fn id(&self) -> NoteId {
+        self.id
+    }
+
+    /// Returns the type of the note.
+    pub fn note_type(&self) -> NoteType {
+        self.note_type
+    }
+
+    /// Returns the tag of the note.
+    pub fn tag(&self) -> NoteTag {
+        self.tag
+    }
+
+    /// Returns the description of the script of the note, if the note is public and its script
+    /// is known to the script registry.
+    pub fn script(&self) -> Option<&ScriptInfo> {
+        self.script.as_ref()
+    }
+
+    /// Returns the assets carried by the note, if the note is public.
+    pub fn assets(&self) -> Option<&[Asset]> {
+        self.assets.as_deref()
+    }
+}
+
+impl Serializable for CreatedNote {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.id.write_into(target);
+        self.note_type.write_into(target);
+        self.tag.write_into(target);
+        self.script.write_into(target);
+        target.write_bool(self.assets.is_some());
+        if let Some(assets) = &self.assets {
+            write_vec(target, assets);
+        }
+    }
+}
+
+impl Deserializable for CreatedNote {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let id = NoteId::read_from(source)?;
+        let note_type = NoteType::read_from(source)?;
+        let tag = NoteTag::read_from(source)?;
+        let script = Option::<ScriptInfo>::read_from(source)?;
+        let assets = if source.read_bool()? {
+            Some(read_vec(source)?)
+        } else {
+            None
+        };
+
+        Ok(Self { id, note_type, tag, script, assets })
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+fn write_vec<W: ByteWriter, T: Serializable>(target: &mut W, items: &[T]) {
+    assert!(items.len() <= u32::MAX as usize, "too many items in the transaction summary");
+    target.write_u32(items.len() as u32);
+    target.write_many(items);
+}
+
+fn read_vec<R: ByteReader, T: Deserializable>(
+    source: &mut R,
+) -> Result<Vec<T>, DeserializationError> {
+    let num_items = source.read_u32()? as usize;
+    source.read_many::<T>(num_items)
+}
@@ -1,10 +1,12 @@
+use alloc::{collections::BTreeSet, vec::Vec};
 use core::cell::OnceCell;
 
 use super::{
-    Account, AccountDelta, AccountId, AccountStub, AdviceInputs, BlockHeader, InputNotes,
+    Account, AccountDelta, AccountId, AccountStub, AdviceInputs, BlockHeader, Digest, InputNotes,
     OutputNotes, Program, TransactionArgs, TransactionId, TransactionInputs, TransactionOutputs,
-    TransactionWitness,
+    TransactionWitness, TxLog, TxSummary,
 };
+use crate::notes::{NoteId, ScriptRegistry};
 
 // EXECUTED TRANSACTION
 // ================================================================================================
@@ -28,6 +30,9 @@ pub struct ExecutedTransaction {
     account_delta: AccountDelta,
     tx_args: TransactionArgs,
     advice_witness: AdviceInputs,
+    unused_advice: Option<UnusedAdviceInputs>,
+    measurements: TransactionMeasurements,
+    access_set: AccessSet,
 }
 
 impl ExecutedTransaction {
@@ -57,9 +62,32 @@ impl ExecutedTransaction {
             account_delta,
             tx_args,
             advice_witness,
+            unused_advice: None,
+            measurements: TransactionMeasurements::default(),
+            access_set: AccessSet::default(),
         }
     }
 
+    /// Attaches the advice inputs which were provided to the VM but never requested while
+    /// executing the transaction program.
+    pub fn with_unused_advice(mut self, unused_advice: UnusedAdviceInputs) -> Self {
+        self.unused_advice = Some(unused_advice);
+        self
+    }
+
+    /// Attaches the measurements taken while executing the transaction program.
+    pub fn with_measurements(mut self, measurements: TransactionMeasurements) -> Self {
+        self.measurements = measurements;
+        self
+    }
+
+    /// Attaches the set of account storage slots and vault keys accessed while executing the
+    /// transaction program.
+    pub fn with_access_set(mut self, access_set: AccessSet) -> Self {
+        self.access_set = access_set;
+        self
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -98,6 +126,11 @@ impl ExecutedTransaction {
         &self.tx_outputs.output_notes
     }
 
+    /// Returns the log entries emitted in this transaction.
+    pub fn log(&self) -> &TxLog {
+        &self.tx_outputs.log
+    }
+
     /// Returns a reference to the transaction args.
     pub fn tx_args(&self) -> &TransactionArgs {
         &self.tx_args
@@ -124,6 +157,35 @@ impl ExecutedTransaction {
         &self.advice_witness
     }
 
+    /// Returns the advice inputs which were provided to the VM but never requested while
+    /// executing the transaction program, if they were collected by the executor.
+    pub fn unused_advice(&self) -> Option<&UnusedAdviceInputs> {
+        self.unused_advice.as_ref()
+    }
+
+    /// Returns the measurements taken while executing the transaction program.
+    pub fn measurements(&self) -> &TransactionMeasurements {
+        &self.measurements
+    }
+
+    /// Returns the account storage slots and vault keys accessed while executing the transaction
+    /// program.
+    ///
+    /// The access set can be used to trim the inputs of this transaction down to the data
+    /// required to re-execute it (see [TransactionInputs::minimize()]).
+    pub fn access_set(&self) -> &AccessSet {
+        &self.access_set
+    }
+
+    /// Returns a structured description of the effects of this transaction, suitable for
+    /// rendering to the user before the transaction is proven and submitted.
+    ///
+    /// The scripts of the created public notes are named if they are known to the specified
+    /// script registry.
+    pub fn describe(&self, registry: &ScriptRegistry) -> TxSummary {
+        TxSummary::new(self, registry)
+    }
+
     // CONVERSIONS
     // --------------------------------------------------------------------------------------------
 
@@ -146,3 +208,120 @@ impl From<ExecutedTransaction> for TransactionWitness {
         tx_witness
     }
 }
+
+// UNUSED ADVICE INPUTS
+// ================================================================================================
+
+/// Advice inputs which were provided to the VM but never requested while executing a transaction.
+///
+/// Contains the keys of the advice map entries provided via [TransactionArgs] which were never
+/// read, and the inner nodes of the Merkle store (identified by their hashes) whose children were
+/// never requested. These inputs can be omitted from subsequent executions of similar
+/// transactions.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UnusedAdviceInputs {
+    map_keys: BTreeSet<Digest>,
+    merkle_nodes: BTreeSet<Digest>,
+}
+
+impl UnusedAdviceInputs {
+    /// Returns new [UnusedAdviceInputs] instantiated from the provided advice map keys and Merkle
+    /// node hashes.
+    pub fn new(map_keys: BTreeSet<Digest>, merkle_nodes: BTreeSet<Digest>) -> Self {
+        Self { map_keys, merkle_nodes }
+    }
+
+    /// Returns the keys of the advice map entries which were never requested.
+    pub fn map_keys(&self) -> &BTreeSet<Digest> {
+        &self.map_keys
+    }
+
+    /// Returns the hashes of the Merkle store nodes whose children were never requested.
+    pub fn merkle_nodes(&self) -> &BTreeSet<Digest> {
+        &self.merkle_nodes
+    }
+
+    /// Returns true if all of the provided advice inputs were requested.
+    pub fn is_empty(&self) -> bool {
+        self.map_keys.is_empty() && self.merkle_nodes.is_empty()
+    }
+}
+
+// TRANSACTION MEASUREMENTS
+// ================================================================================================
+
+/// Measurements taken while executing a transaction.
+///
+/// Contains the number of cycles consumed by the script of each input note, in the order in which
+/// the notes were processed. This allows identifying note scripts which consume a disproportionate
+/// share of the cycles of a transaction, e.g., to choose a per-note cycle budget (see
+/// [TransactionArgs::set_note_cycle_budget()]).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TransactionMeasurements {
+    note_cycles: Vec<(NoteId, u32)>,
+}
+
+impl TransactionMeasurements {
+    /// Returns new [TransactionMeasurements] instantiated from the provided per-note cycle counts.
+    pub fn new(note_cycles: Vec<(NoteId, u32)>) -> Self {
+        Self { note_cycles }
+    }
+
+    /// Returns the number of cycles consumed by the script of each input note, in the order in
+    /// which the notes were processed.
+    pub fn note_cycles(&self) -> &[(NoteId, u32)] {
+        &self.note_cycles
+    }
+
+    /// Returns the number of cycles consumed by the script of the note with the specified ID, or
+    /// None if the note was not processed.
+    pub fn cycles_of_note(&self, note_id: NoteId) -> Option<u32> {
+        self.note_cycles
+            .iter()
+            .find(|(id, _)| *id == note_id)
+            .map(|(_, cycles)| *cycles)
+    }
+
+    /// Returns the maximum number of cycles consumed by a single note script, or zero if no notes
+    /// were processed.
+    pub fn max_note_cycles(&self) -> u32 {
+        self.note_cycles.iter().map(|(_, cycles)| *cycles).max().unwrap_or_default()
+    }
+}
+
+// ACCESS SET
+// ================================================================================================
+
+/// The account storage slots and asset vault keys accessed while executing a transaction.
+///
+/// A storage slot is accessed when its value or, for array slots, any of the items of its array
+/// is read or updated. A vault key is accessed when the asset with that key is read from, added
+/// to, or removed from the account vault, regardless of whether the vault contains the asset.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct AccessSet {
+    storage_slots: BTreeSet<u8>,
+    vault_keys: BTreeSet<Digest>,
+}
+
+impl AccessSet {
+    /// Returns a new [AccessSet] instantiated from the provided storage slot indexes and vault
+    /// keys.
+    pub fn new(storage_slots: BTreeSet<u8>, vault_keys: BTreeSet<Digest>) -> Self {
+        Self { storage_slots, vault_keys }
+    }
+
+    /// Returns the indexes of the accessed storage slots.
+    pub fn storage_slots(&self) -> &BTreeSet<u8> {
+        &self.storage_slots
+    }
+
+    /// Returns the accessed vault keys.
+    pub fn vault_keys(&self) -> &BTreeSet<Digest> {
+        &self.vault_keys
+    }
+
+    /// Returns true if neither the account storage nor the account vault was accessed.
+    pub fn is_empty(&self) -> bool {
+        self.storage_slots.is_empty() && self.vault_keys.is_empty()
+    }
+}
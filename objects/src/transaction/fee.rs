@@ -0,0 +1,408 @@
+use alloc::vec::Vec;
+
+use super::{Account, AccountId, AccountStub, Digest, Felt, Word, ZERO};
+use crate::{
+    accounts::verify_storage_item,
+    block::{AcceptedFeeAsset, KernelConfig},
+    crypto::merkle::{InnerNodeInfo, LeafIndex, MerklePath},
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    ACCOUNT_TREE_DEPTH,
+};
+
+// FEE ESTIMATE
+// ================================================================================================
+
+/// An estimate of the fee charged for a transaction, computed from the fee parameters of the
+/// [KernelConfig] in effect for the reference block of the transaction.
+///
+/// The fee of a transaction consists of:
+/// - The base fee, which is charged for every transaction.
+/// - The note fee, which is charged for every note consumed or created by the transaction.
+/// - The priority fee, which the submitter of the transaction offers on top of the required fee
+///   (see [InclusionHints](super::InclusionHints)).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeeEstimate {
+    base_fee: u64,
+    fee_per_note: u64,
+    num_input_notes: usize,
+    num_output_notes: usize,
+    priority_fee: u64,
+}
+
+impl FeeEstimate {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the [FeeEstimate] for a transaction which consumes `num_input_notes` notes and
+    /// creates `num_output_notes` notes under the fee parameters of the specified kernel config.
+    pub fn new(
+        kernel_config: &KernelConfig,
+        num_input_notes: usize,
+        num_output_notes: usize,
+        priority_fee: u64,
+    ) -> Self {
+        Self {
+            base_fee: kernel_config.base_fee().into(),
+            fee_per_note: kernel_config.fee_per_note().into(),
+            num_input_notes,
+            num_output_notes,
+            priority_fee,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the fee charged for every transaction.
+    pub fn base_fee(&self) -> u64 {
+        self.base_fee
+    }
+
+    /// Returns the fee charged for the notes consumed and created by the transaction.
+    pub fn note_fee(&self) -> u64 {
+        self.fee_per_note * (self.num_input_notes + self.num_output_notes) as u64
+    }
+
+    /// Returns the priority fee offered by the submitter of the transaction.
+    pub fn priority_fee(&self) -> u64 {
+        self.priority_fee
+    }
+
+    /// Returns the fee required for the transaction to be included into a block, i.e., the sum
+    /// of the base fee and the note fee.
+    pub fn required_fee(&self) -> u64 {
+        self.base_fee + self.note_fee()
+    }
+
+    /// Returns the total fee of the transaction, i.e., the required fee plus the priority fee.
+    pub fn total(&self) -> u64 {
+        self.required_fee().saturating_add(self.priority_fee)
+    }
+
+    /// Returns the number of notes consumed by the transaction.
+    pub fn num_input_notes(&self) -> usize {
+        self.num_input_notes
+    }
+
+    /// Returns the number of notes created by the transaction.
+    pub fn num_output_notes(&self) -> usize {
+        self.num_output_notes
+    }
+}
+
+// FEE PAYMENT
+// ================================================================================================
+
+/// Specifies the asset in which the fee of a transaction is paid.
+///
+/// The fee is deducted from the vault of the account by the transaction kernel in the epilogue.
+/// It is paid either in the native fee asset of the [KernelConfig], or in one of its accepted
+/// fee assets, in which case the fee is converted into the accepted asset using the conversion
+/// rate published by the oracle of the asset (see [FeeConversionWitness]).
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub enum FeePayment {
+    /// The fee is paid in the native fee asset.
+    #[default]
+    Native,
+    /// The fee is paid in the accepted fee asset issued by the specified faucet.
+    Converted {
+        faucet_id: AccountId,
+        witness: FeeConversionWitness,
+    },
+}
+
+impl FeePayment {
+    /// Returns a [FeePayment] paying the fee in the accepted fee asset issued by the specified
+    /// faucet, with the conversion rate proven by the specified witness.
+    pub fn converted(faucet_id: AccountId, witness: FeeConversionWitness) -> Self {
+        Self::Converted { faucet_id, witness }
+    }
+
+    /// Returns the ID of the faucet issuing the asset in which the fee is paid, or None if the
+    /// fee is paid in the native fee asset.
+    pub fn faucet_id(&self) -> Option<AccountId> {
+        match self {
+            Self::Native => None,
+            Self::Converted { faucet_id, .. } => Some(*faucet_id),
+        }
+    }
+}
+
+// FEE CONVERSION WITNESS
+// ================================================================================================
+
+/// A proof of the conversion rate of an accepted fee asset, as published by the oracle of the
+/// asset in the state of the chain as of the reference block of a transaction.
+///
+/// The witness consists of:
+/// - The stub of the oracle account together with a Merkle path proving that the stub is in the
+///   account database of the reference block.
+/// - The value of the rate slot of the oracle together with a Merkle path proving that the value
+///   is in the storage of the oracle.
+///
+/// The conversion rate is the first element of the value of the rate slot (see
+/// [AcceptedFeeAsset]).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeeConversionWitness {
+    oracle: AccountStub,
+    oracle_path: MerklePath,
+    rate_slot: u8,
+    rate_value: Word,
+    rate_path: MerklePath,
+}
+
+impl FeeConversionWitness {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [FeeConversionWitness] instantiated from the specified parts.
+    pub fn new(
+        oracle: AccountStub,
+        oracle_path: MerklePath,
+        rate_slot: u8,
+        rate_value: Word,
+        rate_path: MerklePath,
+    ) -> Self {
+        Self {
+            oracle,
+            oracle_path,
+            rate_slot,
+            rate_value,
+            rate_path,
+        }
+    }
+
+    /// Returns a new [FeeConversionWitness] for the rate stored in the specified slot of the
+    /// specified oracle account.
+    ///
+    /// `oracle_path` is the path to the oracle in the account database of the reference block.
+    /// The storage of the oracle is expected to be committed.
+    pub fn from_account(oracle: &Account, oracle_path: MerklePath, rate_slot: u8) -> Self {
+        let rate_path = oracle
+            .storage()
+            .slots()
+            .open(&LeafIndex::new(rate_slot as u64).expect("slot index is within bounds"))
+            .path;
+
+        Self {
+            oracle: oracle.into(),
+            oracle_path,
+            rate_slot,
+            rate_value: oracle.storage().get_item(rate_slot).into(),
+            rate_path,
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the stub of the oracle account.
+    pub fn oracle(&self) -> &AccountStub {
+        &self.oracle
+    }
+
+    /// Returns the index of the storage slot of the oracle holding the conversion rate.
+    pub fn rate_slot(&self) -> u8 {
+        self.rate_slot
+    }
+
+    /// Returns the conversion rate, i.e., the number of units of the accepted asset charged per
+    /// unit of the native fee asset.
+    pub fn rate(&self) -> Felt {
+        self.rate_value[0]
+    }
+
+    /// Returns the amount of the accepted asset charged for the specified fee, or None if the
+    /// rate is zero, or if either the fee or the rate does not fit into a u32.
+    ///
+    /// This mirrors the conversion performed by the transaction kernel.
+    pub fn fee_amount(&self, fee: u64) -> Option<u64> {
+        let rate = self.rate().as_int();
+        if rate == 0 || rate > u32::MAX as u64 || fee > u32::MAX as u64 {
+            return None;
+        }
+
+        Some(fee * rate)
+    }
+
+    /// Returns the nodes of the account database and of the storage of the oracle which are
+    /// required by the transaction kernel to verify the conversion rate.
+    pub fn inner_nodes(&self) -> impl Iterator<Item = InnerNodeInfo> + '_ {
+        let oracle_nodes = self
+            .oracle_path
+            .inner_nodes(self.oracle.id().into(), self.oracle.hash())
+            .expect("account ID is within the depth of the path");
+        let rate_nodes = self
+            .rate_path
+            .inner_nodes(self.rate_slot as u64, self.rate_value.into())
+            .expect("slot index is within the depth of the path");
+
+        oracle_nodes.chain(rate_nodes)
+    }
+
+    /// Returns the data of the oracle account which the transaction kernel reads from the advice
+    /// map under the hash of the oracle, i.e., [id, 0, 0, nonce, VAULT_ROOT, STORAGE_ROOT,
+    /// CODE_ROOT].
+    pub fn oracle_data(&self) -> Vec<Felt> {
+        let mut data = vec![self.oracle.id().into(), ZERO, ZERO, self.oracle.nonce()];
+        data.extend_from_slice(self.oracle.vault_root().as_elements());
+        data.extend_from_slice(self.oracle.storage_root().as_elements());
+        data.extend_from_slice(self.oracle.code_root().as_elements());
+        data
+    }
+
+    // VERIFICATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns true if this witness proves the conversion rate of the specified accepted fee
+    /// asset against the account database with the specified root.
+    pub fn verify(&self, accepted_asset: &AcceptedFeeAsset, account_root: Digest) -> bool {
+        self.oracle.id() == accepted_asset.oracle_id()
+            && self.rate_slot == accepted_asset.rate_slot()
+            && self.oracle_path.depth() == ACCOUNT_TREE_DEPTH
+            && self
+                .oracle_path
+                .verify(self.oracle.id().into(), self.oracle.hash(), &account_root)
+            && verify_storage_item(
+                self.oracle.storage_root(),
+                self.rate_slot,
+                self.rate_value,
+                &self.rate_path,
+            )
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for FeePayment {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        match self {
+            Self::Native => target.write_u8(0),
+            Self::Converted { faucet_id, witness } => {
+                target.write_u8(1);
+                faucet_id.write_into(target);
+                witness.write_into(target);
+            },
+        }
+    }
+}
+
+impl Deserializable for FeePayment {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        match source.read_u8()? {
+            0 => Ok(Self::Native),
+            1 => {
+                let faucet_id = AccountId::read_from(source)?;
+                let witness = FeeConversionWitness::read_from(source)?;
+                Ok(Self::Converted { faucet_id, witness })
+            },
+            tag => {
+                Err(DeserializationError::InvalidValue(format!("invalid fee payment tag: {tag}")))
+            },
+        }
+    }
+}
+
+impl Serializable for FeeConversionWitness {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.oracle.write_into(target);
+        self.oracle_path.write_into(target);
+        target.write_u8(self.rate_slot);
+        self.rate_value.write_into(target);
+        self.rate_path.write_into(target);
+    }
+}
+
+impl Deserializable for FeeConversionWitness {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let oracle = AccountStub::read_from(source)?;
+        let oracle_path = MerklePath::read_from(source)?;
+        let rate_slot = source.read_u8()?;
+        let rate_value = Word::read_from(source)?;
+        let rate_path = MerklePath::read_from(source)?;
+
+        Ok(Self::new(oracle, oracle_path, rate_slot, rate_value, rate_path))
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use assembly::{ast::ModuleAst, Assembler};
+
+    use super::{FeeConversionWitness, FeeEstimate, FeePayment};
+    use crate::{
+        accounts::{
+            Account, AccountCode, AccountId, AccountStorage, SlotItem, StorageSlot,
+            ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN, ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1,
+            ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN,
+        },
+        assets::AssetVault,
+        block::{AcceptedFeeAsset, FeeParameters, KernelConfig, KernelLimits},
+        crypto::merkle::{LeafIndex, SimpleSmt},
+        utils::serde::{Deserializable, Serializable},
+        Felt, ACCOUNT_TREE_DEPTH, ONE, TX_KERNEL_VERSION, ZERO,
+    };
+
+    #[test]
+    fn fee_estimate_is_computed_from_kernel_config() {
+        let native_asset = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN).unwrap();
+        let kernel_config = KernelConfig::new(
+            TX_KERNEL_VERSION,
+            KernelConfig::ALL_FEATURES,
+            FeeParameters::new(100, 7).with_native_asset(native_asset),
+            KernelLimits::default(),
+        )
+        .unwrap();
+
+        let estimate = FeeEstimate::new(&kernel_config, 2, 3, 5);
+        assert_eq!(estimate.base_fee(), 100);
+        assert_eq!(estimate.note_fee(), 35);
+        assert_eq!(estimate.required_fee(), 135);
+        assert_eq!(estimate.total(), 140);
+
+        // the priority fee cannot overflow the total fee
+        let estimate = FeeEstimate::new(&kernel_config, 0, 1, u64::MAX);
+        assert_eq!(estimate.total(), u64::MAX);
+        assert_eq!(FeeEstimate::new(&KernelConfig::default(), 2, 3, 0).total(), 0);
+    }
+
+    #[test]
+    fn fee_conversion_witness_is_verified_against_account_root() {
+        let faucet_id = AccountId::try_from(ACCOUNT_ID_FUNGIBLE_FAUCET_ON_CHAIN_1).unwrap();
+        let oracle_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_IMMUTABLE_CODE_ON_CHAIN).unwrap();
+
+        // build an oracle publishing a rate of 5 in slot 3
+        let module = ModuleAst::parse("export.foo push.1 end").unwrap();
+        let code = AccountCode::new(module, &Assembler::default()).unwrap();
+        let storage = AccountStorage::new(vec![SlotItem {
+            index: 3,
+            slot: StorageSlot::new_value([Felt::new(5), ZERO, ZERO, ZERO]),
+        }])
+        .unwrap();
+        let oracle = Account::new(oracle_id, AssetVault::new(&[]).unwrap(), storage, code, ONE);
+
+        let account_db =
+            SimpleSmt::<ACCOUNT_TREE_DEPTH>::with_leaves([(oracle_id.into(), *oracle.hash())])
+                .unwrap();
+        let oracle_path = account_db.open(&LeafIndex::from(oracle_id)).path;
+        let witness = FeeConversionWitness::from_account(&oracle, oracle_path, 3);
+
+        assert_eq!(witness.rate(), Felt::new(5));
+        assert_eq!(witness.fee_amount(10), Some(50));
+        assert_eq!(witness.fee_amount(u32::MAX as u64 + 1), None);
+
+        let accepted_asset = AcceptedFeeAsset::new(faucet_id, oracle_id, 3);
+        assert!(witness.verify(&accepted_asset, account_db.root()));
+        assert!(!witness.verify(&AcceptedFeeAsset::new(faucet_id, oracle_id, 4), account_db.root()));
+        assert!(!witness.verify(&accepted_asset, oracle.hash()));
+
+        let payment = FeePayment::converted(faucet_id, witness);
+        assert_eq!(payment.faucet_id(), Some(faucet_id));
+        assert_eq!(payment, FeePayment::read_from_bytes(&payment.to_bytes()).unwrap());
+    }
+}
@@ -1,10 +1,17 @@
-use alloc::{collections::BTreeSet, string::ToString, vec::Vec};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    string::ToString,
+    vec::Vec,
+};
 use core::fmt::Debug;
 
-use super::{BlockHeader, ChainMmr, Digest, Felt, Hasher, Word};
+use super::{AccessSet, BlockHeader, ChainMmr, Digest, Felt, Hasher, Word};
 use crate::{
-    accounts::{Account, AccountId},
-    notes::{Note, NoteId, NoteInclusionProof, NoteOrigin, Nullifier},
+    accounts::{hash_account, Account, AccountId},
+    assets::{AssetVault, PartialVault},
+    block::KernelConfig,
+    crypto::merkle::{LeafIndex, MerklePath, Smt, SmtLeaf, SmtProof, SMT_DEPTH},
+    notes::{Note, NoteEnvelope, NoteId, NoteInclusionProof, NoteOrigin, Nullifier},
     utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
     TransactionInputError, MAX_INPUT_NOTES_PER_TX,
 };
@@ -18,8 +25,11 @@ pub struct TransactionInputs {
     account: Account,
     account_seed: Option<Word>,
     block_header: BlockHeader,
+    kernel_config: KernelConfig,
     block_chain: ChainMmr,
     input_notes: InputNotes,
+    nullifier_proofs: Option<Vec<SmtProof>>,
+    partial_vault: Option<PartialVault>,
 }
 
 impl TransactionInputs {
@@ -31,16 +41,35 @@ impl TransactionInputs {
     /// Returns an error if:
     /// - For a new account, account seed is not provided or the provided seed is invalid.
     /// - For an existing account, account seed was provided.
+    /// - The kernel config does not match the commitment in the block header.
+    /// - The number of input notes exceeds the limit defined by the kernel config.
     pub fn new(
         account: Account,
         account_seed: Option<Word>,
         block_header: BlockHeader,
+        kernel_config: KernelConfig,
         block_chain: ChainMmr,
         input_notes: InputNotes,
     ) -> Result<Self, TransactionInputError> {
         // make sure the provided seed is valid in the context of the provided account
         validate_account_seed(&account, account_seed)?;
 
+        // make sure the kernel config is the one committed to by the block header and that the
+        // input notes are within its limits
+        if kernel_config.commitment() != block_header.kernel_config_hash() {
+            return Err(TransactionInputError::InconsistentKernelConfig {
+                expected: block_header.kernel_config_hash(),
+                actual: kernel_config.commitment(),
+            });
+        }
+
+        if input_notes.num_notes() > kernel_config.max_input_notes() as usize {
+            return Err(TransactionInputError::TooManyInputNotes {
+                max: kernel_config.max_input_notes() as usize,
+                actual: input_notes.num_notes(),
+            });
+        }
+
         // make sure block_chain and block_header are consistent
 
         let block_num = block_header.block_num();
@@ -61,21 +90,32 @@ impl TransactionInputs {
         // make sure that block_chain has authentication paths for all input notes; for input notes
         // which were created in the current block we skip this check because their authentication
         // paths are derived implicitly
+        let mut notes_by_block = BTreeMap::<u32, Vec<&InputNote>>::new();
         for note in input_notes.iter() {
-            let note_block_num = note.origin().block_num;
+            notes_by_block.entry(note.origin().block_num).or_default().push(note);
+        }
 
+        // verify the inclusion proofs of all notes created in the same block together; the proofs
+        // are verified in parallel when the `concurrent` feature is enabled, which makes this
+        // check affordable even for transactions consuming many notes. this check does not replace
+        // the authentication of the notes by the transaction kernel, it only rejects invalid inputs
+        // before the transaction is executed
+        for (note_block_num, notes) in notes_by_block {
             let block_header = if note_block_num == block_num {
                 &block_header
             } else {
                 match block_chain.get_block(note_block_num) {
                     Some(block_header) => block_header,
-                    None => Err(TransactionInputError::InputNoteBlockNotInChainMmr(note.id()))?,
+                    None => Err(TransactionInputError::InputNoteBlockNotInChainMmr(notes[0].id()))?,
                 }
             };
 
-            // this check may have non-negligible performance impact as we need to verify inclusion
-            // proofs for all notes; TODO: consider enabling this via a feature flag
-            if !note.is_in_block(block_header) {
+            let proofs = notes
+                .iter()
+                .map(|note| (NoteEnvelope::from(note.note()), note.proof()))
+                .collect::<Vec<_>>();
+            let results = NoteInclusionProof::verify_batch(&proofs, block_header);
+            if let Some((note, _)) = notes.iter().zip(results).find(|(_, result)| result.is_err()) {
                 return Err(TransactionInputError::InputNoteNotInBlock(note.id(), note_block_num));
             }
         }
@@ -84,11 +124,118 @@ impl TransactionInputs {
             account,
             account_seed,
             block_header,
+            kernel_config,
             block_chain,
             input_notes,
+            nullifier_proofs: None,
+            partial_vault: None,
         })
     }
 
+    /// Attaches proofs that the nullifiers of the input notes are not present in the nullifier
+    /// tree of the reference block to these transaction inputs.
+    ///
+    /// The proofs must be provided in the same order as the input notes. When the proofs are
+    /// attached, the transaction kernel verifies them, and thus the transaction proves that none
+    /// of the input notes were consumed as of the reference block.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The number of proofs is not equal to the number of input notes.
+    /// - Any of the proofs does not resolve to the nullifier root of the reference block or
+    ///   does not open the leaf of the corresponding nullifier.
+    /// - Any of the nullifiers is present in the nullifier tree of the reference block.
+    pub fn with_nullifier_proofs(
+        mut self,
+        proofs: Vec<SmtProof>,
+    ) -> Result<Self, TransactionInputError> {
+        if proofs.len() != self.input_notes.num_notes() {
+            return Err(TransactionInputError::InconsistentNumNullifierProofs {
+                expected: self.input_notes.num_notes(),
+                actual: proofs.len(),
+            });
+        }
+
+        let nullifier_root = self.block_header.nullifier_root();
+        for (note, proof) in self.input_notes.iter().zip(proofs.iter()) {
+            let nullifier = note.nullifier().inner();
+            if proof.compute_root() != nullifier_root {
+                return Err(TransactionInputError::InvalidNullifierProof(note.id()));
+            }
+
+            match proof.get(&nullifier) {
+                Some(value) if value == Smt::EMPTY_VALUE => (),
+                Some(_) => return Err(TransactionInputError::InputNoteAlreadyConsumed(note.id())),
+                None => return Err(TransactionInputError::InvalidNullifierProof(note.id())),
+            }
+        }
+
+        self.nullifier_proofs = Some(proofs);
+        Ok(self)
+    }
+
+    /// Attaches a partial vault of the account to these transaction inputs.
+    ///
+    /// When a partial vault is attached, it is used in place of the vault of the account, and
+    /// thus the account can be provided without its vault (i.e., with an empty vault). This allows
+    /// executing transactions against accounts with large vaults, as long as the transaction
+    /// touches only the assets tracked by the partial vault.
+    ///
+    /// # Errors
+    /// Returns an error if the vault of the account is not empty and its commitment differs from
+    /// the commitment of the partial vault.
+    pub fn with_partial_vault(
+        mut self,
+        partial_vault: PartialVault,
+    ) -> Result<Self, TransactionInputError> {
+        let vault_root = self.account.vault().commitment();
+        if vault_root != Smt::new().root() && vault_root != partial_vault.commitment() {
+            return Err(TransactionInputError::InconsistentPartialVault {
+                expected: vault_root,
+                actual: partial_vault.commitment(),
+            });
+        }
+
+        self.partial_vault = Some(partial_vault);
+        Ok(self)
+    }
+
+    /// Returns a copy of these transaction inputs trimmed down to the account data accessed by a
+    /// transaction, as recorded in the provided access set.
+    ///
+    /// The account is provided without its vault, and a partial vault tracking only the accessed
+    /// vault keys is attached instead. The contents of the storage arrays which were not accessed
+    /// are dropped. Since the commitments to the account vault and storage are not affected, the
+    /// trimmed inputs can be used to re-execute and prove the same transaction, e.g., by a remote
+    /// prover which should not receive the entire state of the account.
+    ///
+    /// If a partial vault is already attached to these inputs, it is retained as is.
+    pub fn minimize(&self, access_set: &AccessSet) -> Self {
+        let partial_vault = match &self.partial_vault {
+            Some(partial_vault) => partial_vault.clone(),
+            None => {
+                let vault_keys = access_set.vault_keys().iter().map(|&key| Word::from(key));
+                PartialVault::from_vault(self.account.vault(), vault_keys)
+            },
+        };
+
+        let mut storage = self.account.storage().clone();
+        storage.retain_arrays(|index| access_set.storage_slots().contains(&index));
+        let account = Account::new(
+            self.account.id(),
+            AssetVault::default(),
+            storage,
+            self.account.code().clone(),
+            self.account.nonce(),
+        );
+
+        Self {
+            account,
+            partial_vault: Some(partial_vault),
+            ..self.clone()
+        }
+    }
+
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
@@ -107,6 +254,11 @@ impl TransactionInputs {
         &self.block_header
     }
 
+    /// Returns the config of the transaction kernel committed to by the block header.
+    pub fn kernel_config(&self) -> &KernelConfig {
+        &self.kernel_config
+    }
+
     /// Returns chain MMR containing authentication paths for all notes consumed by the
     /// transaction.
     pub fn block_chain(&self) -> &ChainMmr {
@@ -118,21 +270,173 @@ impl TransactionInputs {
         &self.input_notes
     }
 
+    /// Returns the proofs of non-inclusion of the input note nullifiers in the nullifier tree of
+    /// the reference block, if they were provided.
+    pub fn nullifier_proofs(&self) -> Option<&[SmtProof]> {
+        self.nullifier_proofs.as_deref()
+    }
+
+    /// Returns the partial vault of the account, if one was provided.
+    pub fn partial_vault(&self) -> Option<&PartialVault> {
+        self.partial_vault.as_ref()
+    }
+
+    /// Returns the commitment to the vault of the account against which the transaction is to be
+    /// executed.
+    ///
+    /// If a partial vault was provided, this is the commitment of the partial vault; otherwise,
+    /// this is the commitment of the account vault.
+    pub fn account_vault_root(&self) -> Digest {
+        match &self.partial_vault {
+            Some(partial_vault) => partial_vault.commitment(),
+            None => self.account.vault().commitment(),
+        }
+    }
+
+    /// Returns hash of the account as used for the initial account state hash in transaction
+    /// proofs.
+    ///
+    /// This is the same as [Account::proof_init_hash()], except that the vault root is taken
+    /// from the partial vault, if one was provided.
+    pub fn account_init_hash(&self) -> Digest {
+        if self.account.is_new() {
+            return Digest::default();
+        }
+
+        hash_account(
+            self.account.id(),
+            self.account.nonce(),
+            self.account_vault_root(),
+            self.account.storage().root(),
+            self.account.code().root(),
+        )
+    }
+
     // CONVERSIONS
     // --------------------------------------------------------------------------------------------
 
     /// Consumes these transaction inputs and returns their underlying components.
-    pub fn into_parts(self) -> (Account, Option<Word>, BlockHeader, ChainMmr, InputNotes) {
+    ///
+    /// Nullifier proofs and the partial vault are not included, and must be retrieved via
+    /// [TransactionInputs::nullifier_proofs()] and [TransactionInputs::partial_vault()] before
+    /// calling this method.
+    pub fn into_parts(
+        self,
+    ) -> (Account, Option<Word>, BlockHeader, KernelConfig, ChainMmr, InputNotes) {
         (
             self.account,
             self.account_seed,
             self.block_header,
+            self.kernel_config,
             self.block_chain,
             self.input_notes,
         )
     }
 }
 
+// SERIALIZATION
+// ------------------------------------------------------------------------------------------------
+
+impl Serializable for TransactionInputs {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.account.write_into(target);
+        self.account_seed.write_into(target);
+        self.block_header.write_into(target);
+        self.kernel_config.write_into(target);
+        self.block_chain.write_into(target);
+        self.input_notes.write_into(target);
+
+        match &self.nullifier_proofs {
+            Some(proofs) => {
+                target.write_bool(true);
+                target.write_u16(proofs.len() as u16);
+                proofs.iter().for_each(|proof| write_smt_proof(proof, target));
+            },
+            None => target.write_bool(false),
+        }
+
+        match &self.partial_vault {
+            Some(partial_vault) => {
+                target.write_bool(true);
+                partial_vault.commitment().write_into(target);
+                let openings = partial_vault.openings().collect::<Vec<_>>();
+                target.write_u32(openings.len() as u32);
+                openings.iter().for_each(|opening| write_smt_proof(opening, target));
+            },
+            None => target.write_bool(false),
+        }
+    }
+}
+
+impl Deserializable for TransactionInputs {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let account = Account::read_from(source)?;
+        let account_seed = <Option<Word>>::read_from(source)?;
+        let block_header = BlockHeader::read_from(source)?;
+        let kernel_config = KernelConfig::read_from(source)?;
+        let block_chain = ChainMmr::read_from(source)?;
+        let input_notes = InputNotes::read_from(source)?;
+
+        // the inputs are validated in the same way as the inputs which were not serialized
+        let mut tx_inputs =
+            Self::new(account, account_seed, block_header, kernel_config, block_chain, input_notes)
+                .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+
+        if source.read_bool()? {
+            let num_proofs = source.read_u16()? as usize;
+            let proofs =
+                (0..num_proofs).map(|_| read_smt_proof(source)).collect::<Result<Vec<_>, _>>()?;
+            tx_inputs = tx_inputs
+                .with_nullifier_proofs(proofs)
+                .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+        }
+
+        if source.read_bool()? {
+            let root = Digest::read_from(source)?;
+            let num_openings = source.read_u32()? as usize;
+            let openings = (0..num_openings)
+                .map(|_| read_smt_proof(source))
+                .collect::<Result<Vec<_>, _>>()?;
+            let partial_vault = PartialVault::new(root, openings)
+                .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+            tx_inputs = tx_inputs
+                .with_partial_vault(partial_vault)
+                .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+        }
+
+        Ok(tx_inputs)
+    }
+}
+
+/// Writes the provided Sparse Merkle tree opening into the target as the index and the entries of
+/// the opened leaf, followed by the authentication path of the leaf.
+fn write_smt_proof<W: ByteWriter>(proof: &SmtProof, target: &mut W) {
+    let (path, leaf) = proof.clone().into_parts();
+    let entries = leaf.entries();
+    target.write_u64(leaf.index().value());
+    target.write_u32(entries.len() as u32);
+    for (key, value) in entries {
+        key.write_into(target);
+        value.write_into(target);
+    }
+    path.write_into(target);
+}
+
+/// Reads a Sparse Merkle tree opening written via [write_smt_proof()] from the source.
+fn read_smt_proof<R: ByteReader>(source: &mut R) -> Result<SmtProof, DeserializationError> {
+    let index = LeafIndex::<SMT_DEPTH>::new_max_depth(source.read_u64()?);
+    let num_entries = source.read_u32()? as usize;
+    let mut entries = Vec::with_capacity(num_entries.min(u8::MAX as usize));
+    for _ in 0..num_entries {
+        entries.push((Digest::read_from(source)?, Word::read_from(source)?));
+    }
+    let leaf = SmtLeaf::new(entries, index)
+        .map_err(|err| DeserializationError::InvalidValue(err.to_string()))?;
+    let path = MerklePath::read_from(source)?;
+
+    SmtProof::new(path, leaf).map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+}
+
 // TO NULLIFIER TRAIT
 // ================================================================================================
 
@@ -144,12 +448,21 @@ pub trait ToNullifier:
     Debug + Clone + PartialEq + Eq + Serializable + Deserializable + Sized
 {
     fn nullifier(&self) -> Nullifier;
+
+    /// Returns the recipient digest of the note, or None if the recipient is not known.
+    fn recipient_digest(&self) -> Option<Digest> {
+        None
+    }
 }
 
 impl ToNullifier for InputNote {
     fn nullifier(&self) -> Nullifier {
         self.note.nullifier()
     }
+
+    fn recipient_digest(&self) -> Option<Digest> {
+        Some(self.note.recipient_digest())
+    }
 }
 
 impl ToNullifier for Nullifier {
@@ -163,6 +476,7 @@ impl From<InputNotes> for InputNotes<Nullifier> {
         Self {
             notes: value.notes.iter().map(|note| note.nullifier()).collect(),
             commitment: build_input_notes_commitment(&value.notes),
+            recipients: BTreeMap::new(),
         }
     }
 }
@@ -172,6 +486,7 @@ impl From<&InputNotes> for InputNotes<Nullifier> {
         Self {
             notes: value.notes.iter().map(|note| note.nullifier()).collect(),
             commitment: build_input_notes_commitment(&value.notes),
+            recipients: BTreeMap::new(),
         }
     }
 }
@@ -189,6 +504,8 @@ impl From<&InputNotes> for InputNotes<Nullifier> {
 pub struct InputNotes<T: ToNullifier = InputNote> {
     notes: Vec<T>,
     commitment: Digest,
+    /// Maps recipient digests to the index of the first note with this recipient.
+    recipients: BTreeMap<Digest, usize>,
 }
 
 impl<T: ToNullifier> InputNotes<T> {
@@ -217,7 +534,14 @@ impl<T: ToNullifier> InputNotes<T> {
 
         let commitment = build_input_notes_commitment(&notes);
 
-        Ok(Self { notes, commitment })
+        let mut recipients = BTreeMap::new();
+        for (idx, note) in notes.iter().enumerate() {
+            if let Some(recipient) = note.recipient_digest() {
+                recipients.entry(recipient).or_insert(idx);
+            }
+        }
+
+        Ok(Self { notes, commitment, recipients })
     }
 
     // PUBLIC ACCESSORS
@@ -243,6 +567,15 @@ impl<T: ToNullifier> InputNotes<T> {
         &self.notes[idx]
     }
 
+    /// Returns a reference to the first note with the specified recipient digest, or None if no
+    /// such note is present.
+    ///
+    /// Recipients are known only for notes with full details (i.e., [InputNote]), and thus this
+    /// always returns None for [InputNotes] of nullifiers.
+    pub fn find_by_recipient(&self, recipient_digest: &Digest) -> Option<&T> {
+        self.recipients.get(recipient_digest).map(|&idx| &self.notes[idx])
+    }
+
     // ITERATORS
     // --------------------------------------------------------------------------------------------
 
@@ -282,6 +615,7 @@ impl<T: ToNullifier> Default for InputNotes<T> {
         Self {
             notes: Vec::new(),
             commitment: build_input_notes_commitment::<T>(&[]),
+            recipients: BTreeMap::new(),
         }
     }
 }
@@ -348,6 +682,11 @@ impl InputNote {
         self.note.id()
     }
 
+    /// Returns the recipient digest of the note.
+    pub fn recipient_digest(&self) -> Digest {
+        self.note.recipient_digest()
+    }
+
     /// Returns a reference to the underlying note.
     pub fn note(&self) -> &Note {
         &self.note
@@ -362,13 +701,6 @@ impl InputNote {
     pub fn origin(&self) -> &NoteOrigin {
         self.proof.origin()
     }
-
-    /// Returns true if this note belongs to the note tree of the specified block.
-    fn is_in_block(&self, block_header: &BlockHeader) -> bool {
-        let note_index = self.origin().node_index.value();
-        let note_hash = self.note.authentication_hash();
-        self.proof.note_path().verify(note_index, note_hash, &block_header.note_root())
-    }
 }
 
 // SERIALIZATION
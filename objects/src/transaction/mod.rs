@@ -6,21 +6,39 @@ use super::{
 };
 
 mod chain_mmr;
+mod delivery;
+mod description;
 mod executed_tx;
+mod fee;
 mod inputs;
 mod outputs;
+mod persisted_tx;
+mod post_condition;
 mod prepared_tx;
 mod proven_tx;
+mod summary;
 mod transaction_id;
 mod tx_args;
 mod tx_witness;
+mod update_chain;
 
 pub use chain_mmr::ChainMmr;
-pub use executed_tx::ExecutedTransaction;
+pub use delivery::{DeliveryReport, NoteDelivery, NoteDeliveryInstruction};
+pub use description::{AssetTransfer, Counterparty, CreatedNote, StorageChange, TxSummary};
+pub use executed_tx::{
+    AccessSet, ExecutedTransaction, TransactionMeasurements, UnusedAdviceInputs,
+};
+pub use fee::{FeeConversionWitness, FeeEstimate, FeePayment};
 pub use inputs::{InputNote, InputNotes, ToNullifier, TransactionInputs};
-pub use outputs::{OutputNote, OutputNotes, TransactionOutputs};
+pub use outputs::{OutputNote, OutputNotes, TransactionOutputs, TxLog, TxLogEntry};
+pub use persisted_tx::PersistedTransaction;
+pub use post_condition::PostCondition;
 pub use prepared_tx::PreparedTransaction;
-pub use proven_tx::{AccountDetails, ProvenTransaction, ProvenTransactionBuilder};
+pub use proven_tx::{
+    AccountDetails, ProvenTransaction, ProvenTransactionBuilder, ProvenTransactionHeader,
+};
+pub use summary::TransactionSummary;
 pub use transaction_id::TransactionId;
-pub use tx_args::{TransactionArgs, TransactionScript};
+pub use tx_args::{InclusionHints, TransactionArgs, TransactionScript};
 pub use tx_witness::TransactionWitness;
+pub use update_chain::{AccountUpdate, AccountUpdateChain};
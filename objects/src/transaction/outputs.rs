@@ -1,4 +1,4 @@
-use alloc::{collections::BTreeSet, string::ToString, vec::Vec};
+use alloc::{collections::BTreeMap, string::ToString, vec::Vec};
 use core::fmt::Debug;
 
 use miden_crypto::utils::{ByteReader, ByteWriter, Deserializable, Serializable};
@@ -7,7 +7,8 @@ use vm_processor::DeserializationError;
 use crate::{
     accounts::AccountStub,
     notes::{Note, NoteAssets, NoteEnvelope, NoteId, NoteMetadata},
-    Digest, Felt, Hasher, TransactionOutputError, Word, MAX_OUTPUT_NOTES_PER_TX,
+    Digest, Felt, Hasher, TransactionOutputError, Word, MAX_LOG_ENTRIES_PER_TX,
+    MAX_OUTPUT_NOTES_PER_TX,
 };
 
 // TRANSACTION OUTPUTS
@@ -18,6 +19,25 @@ use crate::{
 pub struct TransactionOutputs {
     pub account: AccountStub,
     pub output_notes: OutputNotes,
+    pub log: TxLog,
+}
+
+impl Serializable for TransactionOutputs {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.account.write_into(target);
+        self.output_notes.write_into(target);
+        self.log.write_into(target);
+    }
+}
+
+impl Deserializable for TransactionOutputs {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let account = AccountStub::read_from(source)?;
+        let output_notes = OutputNotes::read_from(source)?;
+        let log = TxLog::read_from(source)?;
+
+        Ok(Self { account, output_notes, log })
+    }
 }
 
 // OUTPUT NOTES
@@ -29,6 +49,8 @@ pub struct TransactionOutputs {
 pub struct OutputNotes {
     notes: Vec<OutputNote>,
     commitment: Digest,
+    /// Maps note IDs to the index of the note in the list.
+    index: BTreeMap<NoteId, usize>,
 }
 
 impl OutputNotes {
@@ -48,16 +70,16 @@ impl OutputNotes {
             });
         }
 
-        let mut seen_notes = BTreeSet::new();
-        for note in notes.iter() {
-            if !seen_notes.insert(note.id()) {
+        let mut index = BTreeMap::new();
+        for (idx, note) in notes.iter().enumerate() {
+            if index.insert(note.id(), idx).is_some() {
                 return Err(TransactionOutputError::DuplicateOutputNote(note.id()));
             }
         }
 
         let commitment = build_output_notes_commitment(&notes);
 
-        Ok(Self { notes, commitment })
+        Ok(Self { notes, commitment, index })
     }
 
     // PUBLIC ACCESSORS
@@ -85,6 +107,21 @@ impl OutputNotes {
         &self.notes[idx]
     }
 
+    /// Returns a reference to the note with the specified ID, or None if no such note is present.
+    pub fn find_by_id(&self, note_id: &NoteId) -> Option<&OutputNote> {
+        self.index.get(note_id).map(|&idx| &self.notes[idx])
+    }
+
+    /// Returns a reference to the first public note with the specified recipient digest, or None
+    /// if no such note is present.
+    ///
+    /// Recipients of private notes are not known, and thus private notes are never matched.
+    pub fn find_by_recipient(&self, recipient_digest: &Digest) -> Option<&OutputNote> {
+        self.notes
+            .iter()
+            .find(|note| note.recipient_digest().as_ref() == Some(recipient_digest))
+    }
+
     // ITERATORS
     // --------------------------------------------------------------------------------------------
 
@@ -172,10 +209,13 @@ impl OutputNote {
 
     /// Value that represents under which condition a note can be consumed.
     ///
+    /// The recipient is known only for public notes; for private notes only the note ID (which
+    /// commits to the recipient) is available, and so None is returned.
+    ///
     /// See [super::NoteRecipient] for more details.
     pub fn recipient_digest(&self) -> Option<Digest> {
         match self {
-            OutputNote::Public(note) => Some(note.recipient().digest()),
+            OutputNote::Public(note) => Some(note.recipient_digest()),
             OutputNote::Private(_) => None,
         }
     }
@@ -192,6 +232,18 @@ impl OutputNote {
 // CONVERSIONS
 // ------------------------------------------------------------------------------------------------
 
+impl From<Note> for OutputNote {
+    fn from(note: Note) -> Self {
+        OutputNote::Public(note)
+    }
+}
+
+impl From<NoteEnvelope> for OutputNote {
+    fn from(envelope: NoteEnvelope) -> Self {
+        OutputNote::Private(envelope)
+    }
+}
+
 impl From<OutputNote> for NoteEnvelope {
     fn from(value: OutputNote) -> Self {
         (&value).into()
@@ -234,3 +286,139 @@ impl Deserializable for OutputNote {
         }
     }
 }
+
+// TRANSACTION LOG
+// ================================================================================================
+
+/// Contains a list of log entries emitted during the execution of a transaction.
+/// The list can be empty if no log entries were emitted.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct TxLog {
+    entries: Vec<TxLogEntry>,
+    commitment: Digest,
+}
+
+impl TxLog {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+    /// Returns a new [TxLog] instantiated from the provided vector of log entries.
+    ///
+    /// # Errors
+    /// Returns an error if the total number of log entries is greater than 1024.
+    pub fn new(entries: Vec<TxLogEntry>) -> Result<Self, TransactionOutputError> {
+        if entries.len() > MAX_LOG_ENTRIES_PER_TX {
+            return Err(TransactionOutputError::TooManyLogEntries {
+                max: MAX_LOG_ENTRIES_PER_TX,
+                actual: entries.len(),
+            });
+        }
+
+        let commitment = build_tx_log_commitment(&entries);
+
+        Ok(Self { entries, commitment })
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the commitment to the log entries.
+    ///
+    /// The commitment is computed by sequentially merging the entries in the order in which they
+    /// were emitted, starting from [ZERO; 4].
+    pub fn commitment(&self) -> Digest {
+        self.commitment
+    }
+
+    /// Returns total number of log entries.
+    pub fn num_entries(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns true if this [TxLog] does not contain any entries.
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns a reference to the log entry located at the specified index.
+    pub fn get_entry(&self, idx: usize) -> &TxLogEntry {
+        &self.entries[idx]
+    }
+
+    // ITERATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns an iterator over the entries in this [TxLog].
+    pub fn iter(&self) -> impl Iterator<Item = &TxLogEntry> {
+        self.entries.iter()
+    }
+}
+
+// SERIALIZATION
+// ------------------------------------------------------------------------------------------------
+
+impl Serializable for TxLog {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        // assert is OK here because we enforce max number of entries in the constructor
+        assert!(self.entries.len() <= u16::MAX.into());
+        target.write_u16(self.entries.len() as u16);
+        target.write_many(&self.entries);
+    }
+}
+
+impl Deserializable for TxLog {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let num_entries = source.read_u16()?;
+        let entries = source.read_many::<TxLogEntry>(num_entries.into())?;
+        Self::new(entries).map_err(|err| DeserializationError::InvalidValue(err.to_string()))
+    }
+}
+
+// HELPER FUNCTIONS
+// ------------------------------------------------------------------------------------------------
+
+/// Build a commitment to the log entries.
+///
+/// The commitment is computed as hash(...hash(hash(ZERO, ENTRY_0), ENTRY_1)..., ENTRY_n). For an
+/// empty list, [ZERO; 4] is returned.
+fn build_tx_log_commitment(entries: &[TxLogEntry]) -> Digest {
+    entries
+        .iter()
+        .fold(Digest::default(), |acc, entry| Hasher::merge(&[acc, entry.data().into()]))
+}
+
+// TRANSACTION LOG ENTRY
+// ================================================================================================
+
+/// An application-level event emitted via `miden::tx::emit_event` during the execution of a
+/// transaction.
+///
+/// Log entries can only be emitted by the procedures of the account against which the transaction
+/// is executed, and thus every entry originates from the code of that account.
+///
+/// The meaning of the entry data is defined by the application which emitted it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TxLogEntry(Word);
+
+impl TxLogEntry {
+    /// Returns a new [TxLogEntry] instantiated from the provided data.
+    pub fn new(data: Word) -> Self {
+        Self(data)
+    }
+
+    /// Returns the data of this log entry.
+    pub fn data(&self) -> Word {
+        self.0
+    }
+}
+
+impl Serializable for TxLogEntry {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        target.write(self.0);
+    }
+}
+
+impl Deserializable for TxLogEntry {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Ok(Self(source.read()?))
+    }
+}
@@ -0,0 +1,309 @@
+use alloc::{string::ToString, vec::Vec};
+
+use miden_crypto::utils::SliceReader;
+
+use super::{
+    AccountDelta, AdviceInputs, Digest, ExecutedTransaction, Hasher, Program, TransactionArgs,
+    TransactionInputs, TransactionOutputs, TransactionWitness,
+};
+use crate::{
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    PersistedTransactionError,
+};
+
+// CONSTANTS
+// ================================================================================================
+
+/// The magic bytes with which a serialized [PersistedTransaction] starts.
+const MAGIC: [u8; 4] = *b"MTXP";
+
+/// The length of the header of a serialized [PersistedTransaction], i.e., the magic bytes, the
+/// format version, and the commitment to the body.
+const HEADER_LEN: usize = MAGIC.len() + 1 + 32;
+
+// PERSISTED TRANSACTION
+// ================================================================================================
+
+/// A transaction which was persisted after it was executed, such that it can be proven later,
+/// possibly by another process or on another machine.
+///
+/// A persisted transaction contains the data of the [TransactionWitness] of the transaction and,
+/// if it was persisted from an [ExecutedTransaction], the outputs and the account delta of the
+/// transaction. Measurements, the access set, and unused advice inputs of an executed transaction
+/// are not persisted.
+///
+/// Transaction programs cannot be serialized, and thus only the hash of the program is persisted.
+/// The program must be recompiled from the account code, the note scripts, and the transaction
+/// script of the transaction (e.g., via `TransactionCompiler::compile_persisted_transaction()` of
+/// the `miden-tx` crate) before the transaction can be restored, and its hash must match the
+/// persisted hash.
+///
+/// A serialized persisted transaction consists of:
+/// - The magic bytes `MTXP`.
+/// - The version of the format, see [PersistedTransaction::FORMAT_VERSION].
+/// - A commitment to the body, computed as the hash of the serialized body.
+/// - The length of the body in bytes.
+/// - The body, which contains the program hash, the transaction inputs, the transaction args, the
+///   advice witness, and the optional outputs and account delta.
+///
+/// Only transactions serialized in the current version of the format can be deserialized, and
+/// deserialization fails if the body does not match its commitment. As the body is prefixed with
+/// its length, a persisted transaction can be serialized as part of other structures.
+#[derive(Debug, Clone)]
+pub struct PersistedTransaction {
+    program_hash: Digest,
+    tx_inputs: TransactionInputs,
+    tx_args: TransactionArgs,
+    advice_witness: AdviceInputs,
+    outputs: Option<(TransactionOutputs, AccountDelta)>,
+}
+
+impl PersistedTransaction {
+    // CONSTANTS
+    // --------------------------------------------------------------------------------------------
+
+    /// The version of the serialization format of persisted transactions.
+    ///
+    /// This must be incremented whenever the serialization of any component of a persisted
+    /// transaction changes.
+    pub const FORMAT_VERSION: u8 = 1;
+
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Deserializes a persisted transaction from the provided bytes.
+    ///
+    /// This performs the same checks as [Deserializable::read_from_bytes()], but reports the reason
+    /// for which the bytes were rejected as a [PersistedTransactionError].
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The bytes do not start with the header of a persisted transaction.
+    /// - The transaction was serialized in a different version of the format.
+    /// - The body does not match the commitment in the header, i.e., the bytes were corrupted.
+    /// - The body cannot be deserialized, or any of its components is invalid.
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, PersistedTransactionError> {
+        if bytes.len() < HEADER_LEN {
+            return Err(PersistedTransactionError::MissingHeader(bytes.len()));
+        }
+
+        let mut source = SliceReader::new(bytes);
+        Self::read_checked(&mut source)
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the hash of the program of the transaction.
+    pub fn program_hash(&self) -> Digest {
+        self.program_hash
+    }
+
+    /// Returns the inputs of the transaction.
+    pub fn tx_inputs(&self) -> &TransactionInputs {
+        &self.tx_inputs
+    }
+
+    /// Returns the args of the transaction.
+    pub fn tx_args(&self) -> &TransactionArgs {
+        &self.tx_args
+    }
+
+    /// Returns all the data requested by the VM from the advice provider while executing the
+    /// transaction program.
+    pub fn advice_witness(&self) -> &AdviceInputs {
+        &self.advice_witness
+    }
+
+    /// Returns the outputs of the transaction, or None if the transaction was persisted from a
+    /// [TransactionWitness].
+    pub fn tx_outputs(&self) -> Option<&TransactionOutputs> {
+        self.outputs.as_ref().map(|(tx_outputs, _)| tx_outputs)
+    }
+
+    /// Returns the account delta of the transaction, or None if the transaction was persisted from
+    /// a [TransactionWitness].
+    pub fn account_delta(&self) -> Option<&AccountDelta> {
+        self.outputs.as_ref().map(|(_, account_delta)| account_delta)
+    }
+
+    // CONVERSIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Restores the [TransactionWitness] of this transaction with the provided program.
+    ///
+    /// # Errors
+    /// Returns an error if the hash of the program differs from the persisted program hash.
+    pub fn into_witness(
+        self,
+        program: Program,
+    ) -> Result<TransactionWitness, PersistedTransactionError> {
+        self.check_program(&program)?;
+        Ok(TransactionWitness::new(
+            program,
+            self.tx_inputs,
+            self.tx_args,
+            self.advice_witness,
+        ))
+    }
+
+    /// Restores the [ExecutedTransaction] of this transaction with the provided program.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The hash of the program differs from the persisted program hash.
+    /// - The transaction was persisted from a [TransactionWitness], and thus its outputs are not
+    ///   known.
+    pub fn into_executed_transaction(
+        self,
+        program: Program,
+    ) -> Result<ExecutedTransaction, PersistedTransactionError> {
+        self.check_program(&program)?;
+        let (tx_outputs, account_delta) =
+            self.outputs.ok_or(PersistedTransactionError::ExecutionOutputsMissing)?;
+
+        Ok(ExecutedTransaction::new(
+            program,
+            self.tx_inputs,
+            tx_outputs,
+            account_delta,
+            self.tx_args,
+            self.advice_witness,
+        ))
+    }
+
+    // HELPERS
+    // --------------------------------------------------------------------------------------------
+
+    /// Makes sure the provided program is the program of this transaction.
+    fn check_program(&self, program: &Program) -> Result<(), PersistedTransactionError> {
+        if program.hash() != self.program_hash {
+            return Err(PersistedTransactionError::ProgramHashMismatch {
+                expected: self.program_hash,
+                actual: program.hash(),
+            });
+        }
+
+        Ok(())
+    }
+
+    /// Reads a persisted transaction from the provided source, checking its header and the
+    /// commitment to its body.
+    fn read_checked<R: ByteReader>(source: &mut R) -> Result<Self, PersistedTransactionError> {
+        let magic: [u8; 4] = source.read_array().map_err(PersistedTransactionError::InvalidBody)?;
+        if magic != MAGIC {
+            return Err(PersistedTransactionError::InvalidMagic);
+        }
+
+        let version = source.read_u8().map_err(PersistedTransactionError::InvalidBody)?;
+        if version != Self::FORMAT_VERSION {
+            return Err(PersistedTransactionError::UnsupportedFormatVersion {
+                supported: Self::FORMAT_VERSION,
+                actual: version,
+            });
+        }
+
+        let expected = Digest::read_from(source).map_err(PersistedTransactionError::InvalidBody)?;
+        let body_len = source.read_usize().map_err(PersistedTransactionError::InvalidBody)?;
+        let body = source.read_vec(body_len).map_err(PersistedTransactionError::InvalidBody)?;
+        let actual = Hasher::hash(&body);
+        if actual != expected {
+            return Err(PersistedTransactionError::BodyCommitmentMismatch { expected, actual });
+        }
+
+        PersistedTransactionBody::read_from_bytes(&body)
+            .map(|body| body.0)
+            .map_err(PersistedTransactionError::InvalidBody)
+    }
+
+    /// Serializes the body of this persisted transaction.
+    fn body_to_bytes(&self) -> Vec<u8> {
+        let mut body = Vec::new();
+        self.program_hash.write_into(&mut body);
+        self.tx_inputs.write_into(&mut body);
+        self.tx_args.write_into(&mut body);
+        self.advice_witness.write_into(&mut body);
+        match &self.outputs {
+            Some((tx_outputs, account_delta)) => {
+                body.write_bool(true);
+                tx_outputs.write_into(&mut body);
+                account_delta.write_into(&mut body);
+            },
+            None => body.write_bool(false),
+        }
+
+        body
+    }
+}
+
+impl From<ExecutedTransaction> for PersistedTransaction {
+    fn from(tx: ExecutedTransaction) -> Self {
+        let (account_delta, tx_outputs, tx_witness) = tx.into_parts();
+        let mut persisted_tx = Self::from(tx_witness);
+        persisted_tx.outputs = Some((tx_outputs, account_delta));
+        persisted_tx
+    }
+}
+
+impl From<TransactionWitness> for PersistedTransaction {
+    fn from(tx_witness: TransactionWitness) -> Self {
+        let (program, tx_inputs, tx_args, advice_witness) = tx_witness.into_parts();
+        Self {
+            program_hash: program.hash(),
+            tx_inputs,
+            tx_args,
+            advice_witness,
+            outputs: None,
+        }
+    }
+}
+
+// SERIALIZATION
+// ================================================================================================
+
+impl Serializable for PersistedTransaction {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        let body = self.body_to_bytes();
+        target.write_bytes(&MAGIC);
+        target.write_u8(Self::FORMAT_VERSION);
+        Hasher::hash(&body).write_into(target);
+        target.write_usize(body.len());
+        target.write_bytes(&body);
+    }
+}
+
+impl Deserializable for PersistedTransaction {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        Self::read_checked(source).map_err(|err| match err {
+            PersistedTransactionError::InvalidBody(err) => err,
+            err => DeserializationError::InvalidValue(err.to_string()),
+        })
+    }
+}
+
+/// The body of a serialized [PersistedTransaction], i.e., a serialized persisted transaction
+/// without its header.
+struct PersistedTransactionBody(PersistedTransaction);
+
+impl Deserializable for PersistedTransactionBody {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let program_hash = Digest::read_from(source)?;
+        let tx_inputs = TransactionInputs::read_from(source)?;
+        let tx_args = TransactionArgs::read_from(source)?;
+        let advice_witness = AdviceInputs::read_from(source)?;
+        let outputs = match source.read_bool()? {
+            true => {
+                Some((TransactionOutputs::read_from(source)?, AccountDelta::read_from(source)?))
+            },
+            false => None,
+        };
+
+        Ok(Self(PersistedTransaction {
+            program_hash,
+            tx_inputs,
+            tx_args,
+            advice_witness,
+            outputs,
+        }))
+    }
+}
@@ -0,0 +1,130 @@
+use super::{AccountId, ExecutedTransaction, Word};
+use crate::{
+    assets::Asset,
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
+    EMPTY_WORD,
+};
+
+// POST CONDITION
+// ================================================================================================
+
+/// An invariant which must hold after a transaction has been executed.
+///
+/// Post-conditions are declared via the [TransactionArgs](super::TransactionArgs) of a transaction
+/// and are checked by the transaction executor against the [ExecutedTransaction], i.e., before the
+/// transaction is proven. Thus, a transaction whose effects differ from the expected ones
+/// (e.g., because a note script behaved unexpectedly) is rejected without incurring the cost of
+/// proving it.
+///
+/// Post-conditions are not committed to by the transaction proof, and are not enforced by the
+/// transaction kernel.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PostCondition {
+    /// The balance of the fungible asset issued by the specified faucet in the vault of the
+    /// account must be at least the specified amount.
+    MinAssetBalance { faucet_id: AccountId, amount: u64 },
+    /// The transaction must create exactly the specified number of output notes.
+    NumOutputNotes(usize),
+    /// The storage slot with the specified index must hold the specified value.
+    StorageSlotEquals { index: u8, value: Word },
+}
+
+impl PostCondition {
+    /// Returns true if this post-condition holds for the specified executed transaction.
+    ///
+    /// The final state of the account is obtained by applying the account delta of the
+    /// transaction to the initial state of the account. Balances of faucets which do not issue
+    /// fungible assets are considered to be zero.
+    pub fn is_satisfied_by(&self, executed_tx: &ExecutedTransaction) -> bool {
+        match *self {
+            Self::MinAssetBalance { faucet_id, amount } => {
+                final_balance(executed_tx, faucet_id) >= amount as u128
+            },
+            Self::NumOutputNotes(num_notes) => executed_tx.output_notes().num_notes() == num_notes,
+            Self::StorageSlotEquals { index, value } => {
+                final_storage_item(executed_tx, index) == value
+            },
+        }
+    }
+}
+
+impl Serializable for PostCondition {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        match *self {
+            Self::MinAssetBalance { faucet_id, amount } => {
+                target.write_u8(0);
+                faucet_id.write_into(target);
+                target.write_u64(amount);
+            },
+            Self::NumOutputNotes(num_notes) => {
+                target.write_u8(1);
+                target.write_u64(num_notes as u64);
+            },
+            Self::StorageSlotEquals { index, value } => {
+                target.write_u8(2);
+                target.write_u8(index);
+                value.write_into(target);
+            },
+        }
+    }
+}
+
+impl Deserializable for PostCondition {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        match source.read_u8()? {
+            0 => {
+                let faucet_id = AccountId::read_from(source)?;
+                let amount = source.read_u64()?;
+                Ok(Self::MinAssetBalance { faucet_id, amount })
+            },
+            1 => Ok(Self::NumOutputNotes(source.read_u64()? as usize)),
+            2 => {
+                let index = source.read_u8()?;
+                let value = Word::read_from(source)?;
+                Ok(Self::StorageSlotEquals { index, value })
+            },
+            v => Err(DeserializationError::InvalidValue(format!("Invalid post-condition: {v}"))),
+        }
+    }
+}
+
+// HELPER FUNCTIONS
+// ================================================================================================
+
+/// Returns the balance of the fungible asset issued by the specified faucet after the transaction.
+///
+/// The balance is computed with extra precision, so that a delta which is inconsistent with the
+/// initial vault (e.g., for transactions executed against a partial vault) cannot overflow.
+fn final_balance(executed_tx: &ExecutedTransaction, faucet_id: AccountId) -> u128 {
+    let vault_delta = executed_tx.account_delta().vault();
+    let amounts = |assets: &[Asset]| -> u128 {
+        assets
+            .iter()
+            .filter_map(|asset| match asset {
+                Asset::Fungible(asset) if asset.faucet_id() == faucet_id => {
+                    Some(asset.amount() as u128)
+                },
+                _ => None,
+            })
+            .sum()
+    };
+
+    let initial_balance =
+        executed_tx.initial_account().vault().get_balance(faucet_id).unwrap_or(0) as u128;
+
+    (initial_balance + amounts(&vault_delta.added_assets))
+        .saturating_sub(amounts(&vault_delta.removed_assets))
+}
+
+/// Returns the value of the storage slot with the specified index after the transaction.
+fn final_storage_item(executed_tx: &ExecutedTransaction, index: u8) -> Word {
+    let storage_delta = executed_tx.account_delta().storage();
+    if let Some((_, value)) = storage_delta.updated_items.iter().find(|(idx, _)| *idx == index) {
+        return *value;
+    }
+    if storage_delta.cleared_items.contains(&index) {
+        return EMPTY_WORD;
+    }
+
+    executed_tx.initial_account().storage().get_item(index).into()
+}
@@ -2,9 +2,12 @@ use alloc::{string::ToString, vec::Vec};
 
 use miden_verifier::ExecutionProof;
 
-use super::{AccountId, Digest, InputNotes, Nullifier, OutputNote, OutputNotes, TransactionId};
+use super::{
+    AccountId, Digest, ExecutedTransaction, InclusionHints, InputNotes, Nullifier, OutputNote,
+    OutputNotes, TransactionId, TxLog, TxLogEntry,
+};
 use crate::{
-    accounts::{Account, AccountDelta},
+    accounts::{Account, AccountDelta, AccountUpdateDetails},
     utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
     ProvenTransactionError,
 };
@@ -23,8 +26,214 @@ pub enum AccountDetails {
 
 /// Result of executing and proving a transaction. Contains all the data required to verify that a
 /// transaction was executed correctly.
+///
+/// A proven transaction consists of the [ProvenTransactionHeader] and the STARK proof attesting
+/// to the correct execution of the transaction. The header is serialized before the proof, and
+/// thus can be decoded from the serialized transaction without reading the proof.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ProvenTransaction {
+    /// The data of the transaction except for the proof.
+    header: ProvenTransactionHeader,
+
+    /// A STARK proof that attests to the correct execution of the transaction.
+    proof: ExecutionProof,
+}
+
+impl ProvenTransaction {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [ProvenTransaction] instantiated from the specified header and proof, e.g.,
+    /// after the header and the proof were received separately.
+    pub fn from_parts(header: ProvenTransactionHeader, proof: ExecutionProof) -> Self {
+        Self { header, proof }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns unique identifier of this transaction.
+    pub fn id(&self) -> TransactionId {
+        self.header.id()
+    }
+
+    /// Returns ID of the account against which this transaction was executed.
+    pub fn account_id(&self) -> AccountId {
+        self.header.account_id()
+    }
+
+    /// Returns the initial account state hash.
+    pub fn initial_account_hash(&self) -> Digest {
+        self.header.initial_account_hash()
+    }
+
+    /// Returns the final account state hash.
+    pub fn final_account_hash(&self) -> Digest {
+        self.header.final_account_hash()
+    }
+
+    /// Returns the account details.
+    pub fn account_details(&self) -> Option<&AccountDetails> {
+        self.header.account_details()
+    }
+
+    /// Returns the details of the on-chain account update performed by this transaction, or
+    /// `None` if the transaction does not carry account details (i.e., for off-chain accounts).
+    pub fn account_update_details(&self) -> Option<AccountUpdateDetails> {
+        self.account_details()
+            .map(|details| AccountUpdateDetails::new(self.final_account_hash(), details.clone()))
+    }
+
+    /// Returns a reference to the notes consumed by the transaction.
+    pub fn input_notes(&self) -> &InputNotes<Nullifier> {
+        self.header.input_notes()
+    }
+
+    /// Returns a reference to the notes produced by the transaction.
+    pub fn output_notes(&self) -> &OutputNotes {
+        self.header.output_notes()
+    }
+
+    /// Returns a reference to the log entries emitted by the transaction.
+    pub fn log(&self) -> &TxLog {
+        self.header.log()
+    }
+
+    /// Returns the script root of the transaction.
+    pub fn tx_script_root(&self) -> Option<Digest> {
+        self.header.tx_script_root()
+    }
+
+    /// Returns the inclusion hints of the transaction.
+    pub fn inclusion_hints(&self) -> InclusionHints {
+        self.header.inclusion_hints()
+    }
+
+    /// Returns the proof of the transaction.
+    pub fn proof(&self) -> &ExecutionProof {
+        &self.proof
+    }
+
+    /// Returns the block reference the transaction was executed against.
+    pub fn block_ref(&self) -> Digest {
+        self.header.block_ref()
+    }
+
+    /// Returns a reference to the header of the transaction.
+    pub fn header(&self) -> &ProvenTransactionHeader {
+        &self.header
+    }
+
+    // VALIDATION
+    // --------------------------------------------------------------------------------------------
+
+    /// Checks that this proven transaction describes the specified executed transaction.
+    ///
+    /// This is intended to be used when proving is delegated to a remote prover, to make sure
+    /// that the returned transaction is the one which was sent for proving. The proof itself is
+    /// not verified by this method.
+    ///
+    /// # Errors
+    /// Returns an error if the account ID, the initial or final account hashes, the account
+    /// details, the input note nullifiers, the output notes, the block reference, the inclusion
+    /// hints or the ID of this transaction do not match the executed transaction.
+    pub fn validate_against(
+        &self,
+        executed_tx: &ExecutedTransaction,
+    ) -> Result<(), ProvenTransactionError> {
+        if self.account_id() != executed_tx.account_id() {
+            return Err(ProvenTransactionError::AccountIdMismatch(
+                executed_tx.account_id(),
+                self.account_id(),
+            ));
+        }
+
+        let initial_account_hash = executed_tx.tx_inputs().account_init_hash();
+        if self.initial_account_hash() != initial_account_hash {
+            return Err(ProvenTransactionError::InitialAccountHashMismatch {
+                expected: initial_account_hash,
+                actual: self.initial_account_hash(),
+            });
+        }
+
+        let final_account_hash = executed_tx.final_account().hash();
+        if self.final_account_hash() != final_account_hash {
+            return Err(ProvenTransactionError::AccountFinalHashMismatch(
+                final_account_hash,
+                self.final_account_hash(),
+            ));
+        }
+
+        // the full account state is validated against the final account hash on construction,
+        // and so only the delta of existing accounts needs to be compared
+        if let Some(AccountDetails::Delta(delta)) = self.account_details() {
+            if delta != executed_tx.account_delta() {
+                return Err(ProvenTransactionError::AccountDeltaMismatch(self.account_id()));
+            }
+        }
+
+        let input_notes_commitment = executed_tx.input_notes().commitment();
+        if self.input_notes().commitment() != input_notes_commitment {
+            return Err(ProvenTransactionError::InputNotesMismatch {
+                expected: input_notes_commitment,
+                actual: self.input_notes().commitment(),
+            });
+        }
+
+        let output_notes_commitment = executed_tx.output_notes().commitment();
+        if self.output_notes().commitment() != output_notes_commitment {
+            return Err(ProvenTransactionError::OutputNotesMismatch {
+                expected: output_notes_commitment,
+                actual: self.output_notes().commitment(),
+            });
+        }
+
+        let block_ref = executed_tx.block_header().hash();
+        if self.block_ref() != block_ref {
+            return Err(ProvenTransactionError::BlockRefMismatch {
+                expected: block_ref,
+                actual: self.block_ref(),
+            });
+        }
+
+        let inclusion_hints = executed_tx.tx_args().inclusion_hints();
+        if self.inclusion_hints() != inclusion_hints {
+            return Err(ProvenTransactionError::InclusionHintsMismatch {
+                expected: inclusion_hints,
+                actual: self.inclusion_hints(),
+            });
+        }
+
+        // the ID is derived from the values checked above, but is checked for completeness
+        if self.id() != executed_tx.id() {
+            return Err(ProvenTransactionError::TransactionIdMismatch {
+                expected: executed_tx.id(),
+                actual: self.id(),
+            });
+        }
+
+        Ok(())
+    }
+
+    // CONVERSIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Consumes `self` and returns the header of the transaction and its proof.
+    pub fn split_proof(self) -> (ProvenTransactionHeader, ExecutionProof) {
+        (self.header, self.proof)
+    }
+}
+
+// PROVEN TRANSACTION HEADER
+// ================================================================================================
+
+/// All data of a [ProvenTransaction] except for its proof.
+///
+/// The header can be decoded from the leading bytes of a serialized [ProvenTransaction], which
+/// allows examining a transaction (e.g., its ID, the account update and the created notes) before
+/// the proof is received.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProvenTransactionHeader {
     /// A unique identifier for the transaction, see [TransactionId] for additional details.
     id: TransactionId,
 
@@ -49,26 +258,29 @@ pub struct ProvenTransaction {
     /// The id and  metadata of all notes created by the transaction.
     output_notes: OutputNotes,
 
+    /// The log entries emitted by the transaction.
+    log: TxLog,
+
     /// The script root of the transaction, if one was used.
     tx_script_root: Option<Digest>,
 
     /// The block hash of the last known block at the time the transaction was executed.
     block_ref: Digest,
 
-    /// A STARK proof that attests to the correct execution of the transaction.
-    proof: ExecutionProof,
+    /// The inclusion preferences of the transaction submitter. These are not covered by the proof.
+    inclusion_hints: InclusionHints,
 }
 
-impl ProvenTransaction {
+impl ProvenTransactionHeader {
     // PUBLIC ACCESSORS
     // --------------------------------------------------------------------------------------------
 
-    /// Returns unique identifier of this transaction.
+    /// Returns unique identifier of the transaction.
     pub fn id(&self) -> TransactionId {
         self.id
     }
 
-    /// Returns ID of the account against which this transaction was executed.
+    /// Returns ID of the account against which the transaction was executed.
     pub fn account_id(&self) -> AccountId {
         self.account_id
     }
@@ -98,25 +310,36 @@ impl ProvenTransaction {
         &self.output_notes
     }
 
+    /// Returns a reference to the log entries emitted by the transaction.
+    pub fn log(&self) -> &TxLog {
+        &self.log
+    }
+
     /// Returns the script root of the transaction.
     pub fn tx_script_root(&self) -> Option<Digest> {
         self.tx_script_root
     }
 
-    /// Returns the proof of the transaction.
-    pub fn proof(&self) -> &ExecutionProof {
-        &self.proof
-    }
-
     /// Returns the block reference the transaction was executed against.
     pub fn block_ref(&self) -> Digest {
         self.block_ref
     }
 
+    /// Returns the inclusion hints of the transaction.
+    pub fn inclusion_hints(&self) -> InclusionHints {
+        self.inclusion_hints
+    }
+
     // HELPER METHODS
     // --------------------------------------------------------------------------------------------
 
     fn validate(self) -> Result<Self, ProvenTransactionError> {
+        // the genesis block does not contain transactions, and thus a transaction can never be
+        // included by a deadline of block 0
+        if let Some(deadline @ 0) = self.inclusion_hints.deadline() {
+            return Err(ProvenTransactionError::InvalidInclusionDeadline(deadline));
+        }
+
         if !self.account_id.is_on_chain() && self.account_details.is_some() {
             return Err(ProvenTransactionError::OffChainAccountWithDetails(self.account_id));
         }
@@ -194,12 +417,18 @@ pub struct ProvenTransactionBuilder {
     /// List of [NoteEnvelope]s of all notes created by the transaction.
     output_notes: Vec<OutputNote>,
 
+    /// List of [TxLogEntry]s emitted by the transaction.
+    log_entries: Vec<TxLogEntry>,
+
     /// The script root of the transaction, if one was used.
     tx_script_root: Option<Digest>,
 
     /// Block [Digest] of the transaction's reference block.
     block_ref: Digest,
 
+    /// Inclusion preferences of the transaction submitter.
+    inclusion_hints: InclusionHints,
+
     /// A STARK proof that attests to the correct execution of the transaction.
     proof: ExecutionProof,
 }
@@ -223,8 +452,10 @@ impl ProvenTransactionBuilder {
             account_details: None,
             input_notes: Vec::new(),
             output_notes: Vec::new(),
+            log_entries: Vec::new(),
             tx_script_root: None,
             block_ref,
+            inclusion_hints: InclusionHints::default(),
             proof,
         }
     }
@@ -256,33 +487,51 @@ impl ProvenTransactionBuilder {
         self
     }
 
+    /// Add log entries emitted by the transaction.
+    pub fn add_log_entries<T>(mut self, entries: T) -> Self
+    where
+        T: IntoIterator<Item = TxLogEntry>,
+    {
+        self.log_entries.extend(entries);
+        self
+    }
+
     /// Set transaction's script root.
     pub fn tx_script_root(mut self, tx_script_root: Digest) -> Self {
         self.tx_script_root = Some(tx_script_root);
         self
     }
 
+    /// Set transaction's inclusion hints.
+    pub fn inclusion_hints(mut self, inclusion_hints: InclusionHints) -> Self {
+        self.inclusion_hints = inclusion_hints;
+        self
+    }
+
     /// Builds the [ProvenTransaction].
     ///
     /// # Errors
     ///
     /// An error will be returned if an on-chain account is used without provided on-chain detail.
     /// Or if the account details, i.e. account id and final hash, don't match the transaction.
+    /// Or if the inclusion deadline is the genesis block.
     pub fn build(mut self) -> Result<ProvenTransaction, ProvenTransactionError> {
         let account_details = self.account_details.take();
         let input_notes =
             InputNotes::new(self.input_notes).map_err(ProvenTransactionError::InputNotesError)?;
         let output_notes = OutputNotes::new(self.output_notes)
             .map_err(ProvenTransactionError::OutputNotesError)?;
+        let log = TxLog::new(self.log_entries).map_err(ProvenTransactionError::TxLogError)?;
         let tx_script_root = self.tx_script_root;
         let id = TransactionId::new(
             self.initial_account_hash,
             self.final_account_hash,
             input_notes.commitment(),
             output_notes.commitment(),
+            self.block_ref,
         );
 
-        let proven_transaction = ProvenTransaction {
+        let header = ProvenTransactionHeader {
             id,
             account_id: self.account_id,
             initial_account_hash: self.initial_account_hash,
@@ -290,12 +539,14 @@ impl ProvenTransactionBuilder {
             account_details,
             input_notes,
             output_notes,
+            log,
             tx_script_root,
             block_ref: self.block_ref,
-            proof: self.proof,
-        };
+            inclusion_hints: self.inclusion_hints,
+        }
+        .validate()?;
 
-        proven_transaction.validate()
+        Ok(ProvenTransaction { header, proof: self.proof })
     }
 }
 
@@ -330,6 +581,22 @@ impl Deserializable for AccountDetails {
 }
 
 impl Serializable for ProvenTransaction {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.header.write_into(target);
+        self.proof.write_into(target);
+    }
+}
+
+impl Deserializable for ProvenTransaction {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let header = ProvenTransactionHeader::read_from(source)?;
+        let proof = ExecutionProof::read_from(source)?;
+
+        Ok(Self { header, proof })
+    }
+}
+
+impl Serializable for ProvenTransactionHeader {
     fn write_into<W: ByteWriter>(&self, target: &mut W) {
         self.account_id.write_into(target);
         self.initial_account_hash.write_into(target);
@@ -337,13 +604,14 @@ impl Serializable for ProvenTransaction {
         self.account_details.write_into(target);
         self.input_notes.write_into(target);
         self.output_notes.write_into(target);
+        self.log.write_into(target);
         self.tx_script_root.write_into(target);
         self.block_ref.write_into(target);
-        self.proof.write_into(target);
+        self.inclusion_hints.write_into(target);
     }
 }
 
-impl Deserializable for ProvenTransaction {
+impl Deserializable for ProvenTransactionHeader {
     fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
         let account_id = AccountId::read_from(source)?;
         let initial_account_hash = Digest::read_from(source)?;
@@ -352,20 +620,22 @@ impl Deserializable for ProvenTransaction {
 
         let input_notes = InputNotes::<Nullifier>::read_from(source)?;
         let output_notes = OutputNotes::read_from(source)?;
+        let log = TxLog::read_from(source)?;
 
         let tx_script_root = Deserializable::read_from(source)?;
 
         let block_ref = Digest::read_from(source)?;
-        let proof = ExecutionProof::read_from(source)?;
+        let inclusion_hints = InclusionHints::read_from(source)?;
 
         let id = TransactionId::new(
             initial_account_hash,
             final_account_hash,
             input_notes.commitment(),
             output_notes.commitment(),
+            block_ref,
         );
 
-        let proven_transaction = Self {
+        let header = Self {
             id,
             account_id,
             initial_account_hash,
@@ -373,12 +643,13 @@ impl Deserializable for ProvenTransaction {
             account_details,
             input_notes,
             output_notes,
+            log,
             tx_script_root,
             block_ref,
-            proof,
+            inclusion_hints,
         };
 
-        proven_transaction
+        header
             .validate()
             .map_err(|err| DeserializationError::InvalidValue(err.to_string()))
     }
@@ -386,7 +657,7 @@ impl Deserializable for ProvenTransaction {
 
 #[cfg(test)]
 mod tests {
-    use super::ProvenTransaction;
+    use super::{ProvenTransaction, ProvenTransactionHeader};
 
     fn check_if_sync<T: Sync>() {}
     fn check_if_send<T: Send>() {}
@@ -400,4 +671,10 @@ mod tests {
     fn proven_transaction_is_send() {
         check_if_send::<ProvenTransaction>();
     }
+
+    #[test]
+    fn proven_transaction_header_is_sync_and_send() {
+        check_if_sync::<ProvenTransactionHeader>();
+        check_if_send::<ProvenTransactionHeader>();
+    }
 }
@@ -0,0 +1,205 @@
+use super::{
+    Account, AccountId, Digest, Felt, Hasher, InputNotes, OutputNotes, TransactionArgs, Word, ZERO,
+};
+use crate::EMPTY_WORD;
+
+// TRANSACTION SUMMARY
+// ================================================================================================
+
+/// A summary of a transaction which is signed by the authentication procedure of an account.
+///
+/// The summary commits to:
+/// - The ID of the account against which the transaction is executed.
+/// - The account nonce at the time the authentication procedure is invoked. For the standard
+///   authentication procedures this is the initial account nonce, as the nonce is incremented
+///   only after the signature is verified.
+/// - The commitments to the input and output notes of the transaction.
+/// - The transaction script args and the args of all input notes (see
+///   [TransactionSummary::args_commitment()]).
+///
+/// The signed message is computed by [TransactionSummary::message()] in exactly the same way as
+/// by the `miden::tx::get_summary_commitment` procedure, and thus can be used by signers which
+/// are external to the transaction executor.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TransactionSummary {
+    account_id: AccountId,
+    nonce: Felt,
+    input_notes_commitment: Digest,
+    output_notes_commitment: Digest,
+    tx_script_args: Word,
+    note_args_commitment: Digest,
+}
+
+impl TransactionSummary {
+    // CONSTRUCTORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [TransactionSummary] instantiated from the specified parameters.
+    ///
+    /// The note args must be provided in the same order as the input notes of the transaction,
+    /// with an empty word for every note for which no args were specified.
+    pub fn new<T>(
+        account_id: AccountId,
+        nonce: Felt,
+        input_notes_commitment: Digest,
+        output_notes_commitment: Digest,
+        tx_script_args: Word,
+        note_args: T,
+    ) -> Self
+    where
+        T: IntoIterator<Item = Word>,
+    {
+        Self {
+            account_id,
+            nonce,
+            input_notes_commitment,
+            output_notes_commitment,
+            tx_script_args,
+            note_args_commitment: compute_note_args_commitment(note_args),
+        }
+    }
+
+    /// Returns the [TransactionSummary] of a transaction executed against the specified account
+    /// which consumes the specified input notes and creates the specified output notes.
+    ///
+    /// The nonce of the summary is set to the nonce of the provided account.
+    pub fn from_transaction(
+        account: &Account,
+        input_notes: &InputNotes,
+        output_notes: &OutputNotes,
+        tx_args: &TransactionArgs,
+    ) -> Self {
+        let note_args = input_notes.iter().map(|input_note| {
+            tx_args.get_note_args(input_note.id()).copied().unwrap_or(EMPTY_WORD)
+        });
+
+        Self::new(
+            account.id(),
+            account.nonce(),
+            input_notes.commitment(),
+            output_notes.commitment(),
+            tx_args.tx_script_args(),
+            note_args,
+        )
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the ID of the account against which the transaction is executed.
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Returns the account nonce committed to by this summary.
+    pub fn nonce(&self) -> Felt {
+        self.nonce
+    }
+
+    /// Returns the commitment to the input notes of the transaction.
+    pub fn input_notes_commitment(&self) -> Digest {
+        self.input_notes_commitment
+    }
+
+    /// Returns the commitment to the output notes of the transaction.
+    pub fn output_notes_commitment(&self) -> Digest {
+        self.output_notes_commitment
+    }
+
+    /// Returns the transaction script args.
+    pub fn tx_script_args(&self) -> Word {
+        self.tx_script_args
+    }
+
+    /// Returns the commitment to the args of the input notes.
+    ///
+    /// The commitment is computed as a sequential hash of the note args in the order in which
+    /// the notes are consumed, i.e., COM_i = hash(COM_{i-1}, NOTE_ARGS_i), starting from an
+    /// empty word.
+    pub fn note_args_commitment(&self) -> Digest {
+        self.note_args_commitment
+    }
+
+    /// Returns the commitment to the transaction script args and the note args, computed as
+    /// hash(TX_SCRIPT_ARGS, NOTE_ARGS_COMMITMENT).
+    pub fn args_commitment(&self) -> Digest {
+        Hasher::merge(&[self.tx_script_args.into(), self.note_args_commitment])
+    }
+
+    /// Returns the message which is signed by the authentication procedure of the account.
+    ///
+    /// The message is computed as:
+    ///
+    /// hash(ARGS_COM, hash(OUTPUT_NOTES_COM, hash(INPUT_NOTES_COM, hash(NONCE, ACCOUNT_ID))))
+    ///
+    /// where NONCE is [nonce, 0, 0, 0] and ACCOUNT_ID is [account_id, 0, 0, 0].
+    pub fn message(&self) -> Digest {
+        let account_id: Word = [self.account_id.into(), ZERO, ZERO, ZERO];
+        let nonce: Word = [self.nonce, ZERO, ZERO, ZERO];
+
+        let message = Hasher::merge(&[nonce.into(), account_id.into()]);
+        let message = Hasher::merge(&[self.input_notes_commitment, message]);
+        let message = Hasher::merge(&[self.output_notes_commitment, message]);
+        Hasher::merge(&[self.args_commitment(), message])
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Returns the sequential hash of the provided note args.
+fn compute_note_args_commitment<T>(note_args: T) -> Digest
+where
+    T: IntoIterator<Item = Word>,
+{
+    note_args
+        .into_iter()
+        .fold(Digest::default(), |commitment, args| Hasher::merge(&[commitment, args.into()]))
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::TransactionSummary;
+    use crate::{
+        accounts::{AccountId, ACCOUNT_ID_SENDER},
+        Digest, Felt, Hasher, EMPTY_WORD, ONE,
+    };
+
+    #[test]
+    fn transaction_summary_commits_to_args() {
+        let account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let input_notes = Hasher::hash(&[1]);
+        let output_notes = Hasher::hash(&[2]);
+        let args = [ONE, Felt::new(2), Felt::new(3), Felt::new(4)];
+
+        let summary =
+            TransactionSummary::new(account_id, ONE, input_notes, output_notes, EMPTY_WORD, []);
+        assert_eq!(summary.note_args_commitment(), Digest::default());
+
+        let with_tx_script_args =
+            TransactionSummary::new(account_id, ONE, input_notes, output_notes, args, []);
+        let with_note_args =
+            TransactionSummary::new(account_id, ONE, input_notes, output_notes, EMPTY_WORD, [args]);
+        let with_empty_note_args = TransactionSummary::new(
+            account_id,
+            ONE,
+            input_notes,
+            output_notes,
+            EMPTY_WORD,
+            [EMPTY_WORD],
+        );
+
+        // args affect the message, and an empty note args word still commits to the note
+        assert_ne!(summary.message(), with_tx_script_args.message());
+        assert_ne!(summary.message(), with_note_args.message());
+        assert_ne!(summary.message(), with_empty_note_args.message());
+        assert_ne!(with_tx_script_args.message(), with_note_args.message());
+        assert_eq!(
+            with_note_args.note_args_commitment(),
+            Hasher::merge(&[Digest::default(), args.into()])
+        );
+    }
+}
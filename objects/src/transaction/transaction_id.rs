@@ -1,9 +1,13 @@
 use alloc::string::String;
 use core::fmt::{Debug, Display};
 
-use super::{Digest, ExecutedTransaction, Felt, Hasher, ProvenTransaction, Word, WORD_SIZE, ZERO};
-use crate::utils::serde::{
-    ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable,
+use super::{
+    inputs::build_input_notes_commitment, Digest, ExecutedTransaction, Felt, Hasher,
+    ProvenTransaction, Word, WORD_SIZE, ZERO,
+};
+use crate::{
+    notes::Nullifier,
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
 };
 
 // TRANSACTION ID
@@ -13,7 +17,17 @@ use crate::utils::serde::{
 ///
 /// Transaction ID is computed as:
 ///
-/// hash(init_account_hash, final_account_hash, input_notes_hash, output_notes_hash)
+/// hash(init_account_hash, final_account_hash, input_notes_hash, output_notes_hash, block_ref)
+///
+/// where the preimage is the sequence of 20 field elements obtained by concatenating the elements
+/// of the five words in the listed order, and:
+/// - init_account_hash and final_account_hash are the hashes of the account state before and
+///   after the transaction.
+/// - input_notes_hash is the sequential hash of (nullifier, EMPTY_WORD) tuples of the input notes
+///   in the order in which they were consumed, or EMPTY_WORD if the transaction consumed no notes.
+/// - output_notes_hash is the commitment to the notes created by the transaction (see
+///   [OutputNotes::commitment()](super::OutputNotes::commitment)).
+/// - block_ref is the hash of the block header the transaction was executed against.
 ///
 /// This achieves the following properties:
 /// - Transactions are identical if and only if they have the same ID.
@@ -28,15 +42,38 @@ impl TransactionId {
         final_account_hash: Digest,
         input_notes_hash: Digest,
         output_notes_hash: Digest,
+        block_ref: Digest,
     ) -> Self {
-        let mut elements = [ZERO; 4 * WORD_SIZE];
+        let mut elements = [ZERO; 5 * WORD_SIZE];
         elements[..4].copy_from_slice(init_account_hash.as_elements());
         elements[4..8].copy_from_slice(final_account_hash.as_elements());
         elements[8..12].copy_from_slice(input_notes_hash.as_elements());
-        elements[12..].copy_from_slice(output_notes_hash.as_elements());
+        elements[12..16].copy_from_slice(output_notes_hash.as_elements());
+        elements[16..].copy_from_slice(block_ref.as_elements());
         Self(Hasher::hash_elements(&elements))
     }
 
+    /// Computes the ID of a transaction from its public components.
+    ///
+    /// This does not require the transaction itself, and thus can be used to validate the ID of
+    /// a transaction from data published about it (e.g., by a block or a mempool). The input
+    /// notes are specified by their nullifiers in the order in which they were consumed.
+    pub fn compute(
+        init_account_hash: Digest,
+        final_account_hash: Digest,
+        input_nullifiers: &[Nullifier],
+        output_notes_commitment: Digest,
+        block_ref: Digest,
+    ) -> Self {
+        Self::new(
+            init_account_hash,
+            final_account_hash,
+            build_input_notes_commitment(input_nullifiers),
+            output_notes_commitment,
+            block_ref,
+        )
+    }
+
     /// Returns the elements representation of this transaction ID.
     pub fn as_elements(&self) -> &[Felt] {
         self.0.as_elements()
@@ -80,6 +117,7 @@ impl From<&ProvenTransaction> for TransactionId {
             tx.final_account_hash(),
             tx.input_notes().commitment(),
             tx.output_notes().commitment(),
+            tx.block_ref(),
         )
     }
 }
@@ -89,10 +127,11 @@ impl From<&ExecutedTransaction> for TransactionId {
         let input_notes_hash = tx.input_notes().commitment();
         let output_notes_hash = tx.output_notes().commitment();
         Self::new(
-            tx.initial_account().proof_init_hash(),
+            tx.tx_inputs().account_init_hash(),
             tx.final_account().hash(),
             input_notes_hash,
             output_notes_hash,
+            tx.block_header().hash(),
         )
     }
 }
@@ -1,15 +1,27 @@
-use alloc::{collections::BTreeMap, vec::Vec};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    vec::Vec,
+};
 
+use assembly::ast::{AstSerdeOptions, Instruction, Node, ProcedureAst};
 use vm_processor::AdviceMap;
 
-use super::{Digest, Felt, Word};
+use super::{Digest, FeePayment, Felt, NoteDeliveryInstruction, PostCondition, Word};
 use crate::{
-    assembly::{Assembler, AssemblyContext, ProgramAst},
+    assembly::{Assembler, AssemblyContext, ModuleAst, ProgramAst},
+    crypto::merkle::{InnerNodeInfo, MerkleStore},
     notes::{Note, NoteId, NoteInputs},
+    utils::serde::{ByteReader, ByteWriter, Deserializable, DeserializationError, Serializable},
     vm::CodeBlock,
     TransactionScriptError,
 };
 
+// CONSTANTS
+// ================================================================================================
+
+/// Serialization options for transaction script code AST.
+const CODE_SERDE_OPTIONS: AstSerdeOptions = AstSerdeOptions::new(true);
+
 // TRANSACTION ARGS
 // ================================================================================================
 
@@ -17,15 +29,46 @@ use crate::{
 ///
 /// - Transaction script: a program that is executed in a transaction after all input notes
 ///   scripts have been executed.
+/// - Transaction script args: data put onto the stack right before the transaction script is
+///   executed. Defaults to an empty word.
+/// - Transaction script entrypoint: the entrypoint executed by a transaction script with multiple
+///   entrypoints, see [TransactionScript::with_entrypoints()].
 /// - Note arguments: data put onto the stack right before a note script is executed. These
 ///   are different from note inputs, as the user executing the transaction can specify arbitrary
 ///   note args.
 /// - Advice map: Provides data needed by the runtime, like the details of a public note.
+/// - Merkle store: Provides Merkle paths needed by the runtime, like the paths proving the
+///   membership of the account in a group targeted by a note.
+/// - Note dependencies: for each input note, the notes which must be processed successfully
+///   before the note is processed.
+/// - Note groups: sets of notes which must be consumed all together or not at all.
+/// - Inclusion hints: the preferred inclusion deadline of the transaction and the priority fee
+///   offered for its inclusion, see [InclusionHints].
+/// - Note delivery instructions: for output notes, how their details are delivered once the
+///   transaction has been proven, see [NoteDeliveryInstruction].
+/// - Post-conditions: invariants which must hold after the transaction has been executed, see
+///   [PostCondition].
+/// - Note cycle budget: the maximum number of cycles a single note script may consume; the
+///   kernel aborts the transaction if any note script exceeds it.
+/// - Output note merging: whether the kernel merges notes created with the same metadata and
+///   recipient into a single note carrying the combined assets.
+/// - Fee payment: the asset in which the fee of the transaction is paid, see [FeePayment].
 #[derive(Clone, Debug, Default)]
 pub struct TransactionArgs {
     tx_script: Option<TransactionScript>,
+    tx_script_args: Word,
+    tx_script_entrypoint: Option<Digest>,
     note_args: BTreeMap<NoteId, Word>,
     advice_map: AdviceMap,
+    merkle_store: MerkleStore,
+    note_dependencies: BTreeMap<NoteId, BTreeSet<NoteId>>,
+    note_groups: Vec<BTreeSet<NoteId>>,
+    inclusion_hints: InclusionHints,
+    note_delivery: BTreeMap<NoteId, (Note, NoteDeliveryInstruction)>,
+    post_conditions: Vec<PostCondition>,
+    note_cycle_budget: Option<u32>,
+    merge_output_notes: bool,
+    fee_payment: FeePayment,
 }
 
 impl TransactionArgs {
@@ -49,8 +92,19 @@ impl TransactionArgs {
 
         Self {
             tx_script,
+            tx_script_args: Word::default(),
+            tx_script_entrypoint: None,
             note_args: note_args.unwrap_or_default(),
             advice_map,
+            merkle_store: MerkleStore::default(),
+            note_dependencies: BTreeMap::new(),
+            note_groups: Vec::new(),
+            inclusion_hints: InclusionHints::default(),
+            note_delivery: BTreeMap::new(),
+            post_conditions: Vec::new(),
+            note_cycle_budget: None,
+            merge_output_notes: false,
+            fee_payment: FeePayment::Native,
         }
     }
 
@@ -64,9 +118,148 @@ impl TransactionArgs {
         Self::new(None, Some(note_args), AdviceMap::default())
     }
 
+    /// Returns these [TransactionArgs] with the provided post-condition added.
+    ///
+    /// The transaction executor checks all post-conditions against the executed transaction and
+    /// rejects the transaction if any of them is violated, before the transaction is proven.
+    pub fn with_post_condition(mut self, post_condition: PostCondition) -> Self {
+        self.post_conditions.push(post_condition);
+        self
+    }
+
     // MODIFIERS
     // --------------------------------------------------------------------------------------------
 
+    /// Sets the transaction script arguments.
+    ///
+    /// The arguments are put onto the stack right before the transaction script is executed, and
+    /// are committed to by the message signed by the account authentication procedure.
+    pub fn set_tx_script_args(&mut self, tx_script_args: Word) {
+        self.tx_script_args = tx_script_args;
+    }
+
+    /// Selects the entrypoint of the transaction script which is executed by the transaction.
+    ///
+    /// The entrypoint is specified by its root, see [TransactionScript::entrypoints()].
+    ///
+    /// # Errors
+    /// Returns an error if the transaction script does not have an entrypoint with the specified
+    /// root, or if no transaction script was provided.
+    pub fn set_tx_script_entrypoint(
+        &mut self,
+        entrypoint: Digest,
+    ) -> Result<(), TransactionScriptError> {
+        match &self.tx_script {
+            Some(tx_script) if tx_script.entrypoints().contains(&entrypoint) => {
+                self.tx_script_entrypoint = Some(entrypoint);
+                Ok(())
+            },
+            _ => Err(TransactionScriptError::UnknownEntrypoint(entrypoint)),
+        }
+    }
+
+    /// Sets the maximum number of cycles a single note script may consume.
+    ///
+    /// The budget is enforced by the kernel after each note script has been executed, so that a
+    /// single runaway note script cannot consume the cycles of the entire transaction unnoticed.
+    /// A budget of zero is treated as no budget.
+    pub fn set_note_cycle_budget(&mut self, note_cycle_budget: u32) {
+        self.note_cycle_budget = (note_cycle_budget != 0).then_some(note_cycle_budget);
+    }
+
+    /// Sets whether notes created with the same metadata and recipient are merged into a single
+    /// note.
+    ///
+    /// When merging is enabled, the kernel adds the asset of a newly created note to a note
+    /// created earlier in the transaction with the same metadata and recipient, as long as that
+    /// note has room for another asset. Fungible assets issued by the same faucet are combined
+    /// into a single asset. This keeps transactions which pay many small amounts to the same
+    /// recipient within the limit on the number of output notes.
+    pub fn set_merge_output_notes(&mut self, merge_output_notes: bool) {
+        self.merge_output_notes = merge_output_notes;
+    }
+
+    /// Sets the asset in which the fee of the transaction is paid.
+    ///
+    /// By default, the fee is paid in the native fee asset. When the fee is paid in an accepted
+    /// fee asset, the data of the conversion witness is provided to the transaction kernel
+    /// together with the other transaction inputs, so it does not need to be added to the
+    /// advice map or the Merkle store separately.
+    pub fn set_fee_payment(&mut self, fee_payment: FeePayment) {
+        self.fee_payment = fee_payment;
+    }
+
+    /// Sets the note arguments for the note with the specified ID.
+    ///
+    /// The note arguments are put onto the stack right before the note script is executed, and
+    /// can also be retrieved by the note script via the `miden::note::get_args` procedure. If
+    /// arguments were already set for the note, they are replaced.
+    pub fn add_note_args(&mut self, note_id: NoteId, note_args: Word) {
+        self.note_args.insert(note_id, note_args);
+    }
+
+    /// Sets the note arguments for each of the provided notes.
+    ///
+    /// If arguments were already set for any of the notes, they are replaced.
+    pub fn extend_note_args<T>(&mut self, note_args: T)
+    where
+        T: IntoIterator<Item = (NoteId, Word)>,
+    {
+        self.note_args.extend(note_args);
+    }
+
+    /// Adds the provided nodes to the Merkle store of the transaction.
+    ///
+    /// The nodes are made available to the kernel via the advice provider, so that note and
+    /// transaction scripts can authenticate data against the roots of the Merkle trees, e.g., the
+    /// membership of the account in a group (see
+    /// [GroupMembership::inner_nodes()](crate::notes::GroupMembership::inner_nodes)).
+    pub fn extend_merkle_store<I: IntoIterator<Item = InnerNodeInfo>>(&mut self, nodes: I) {
+        self.merkle_store.extend(nodes);
+    }
+
+    /// Declares that the note with the specified ID can be processed only after the `dependency`
+    /// note was processed successfully.
+    ///
+    /// The kernel processes input notes in the order in which they are committed to, and the
+    /// transaction fails if any of the note scripts fails. Thus, the dependency is satisfied if
+    /// the `dependency` note precedes the note in the input notes of the transaction; the
+    /// transaction executor rejects transactions in which this is not the case.
+    pub fn add_note_dependency(&mut self, note_id: NoteId, dependency: NoteId) {
+        self.note_dependencies.entry(note_id).or_default().insert(dependency);
+    }
+
+    /// Declares that the specified notes must be consumed all together or not at all.
+    ///
+    /// Since the transaction fails if any of the note scripts fails, the notes of a group are
+    /// either all consumed successfully or none of them is consumed. The transaction executor
+    /// rejects transactions which consume only some of the notes of a group.
+    pub fn add_note_group<T>(&mut self, notes: T)
+    where
+        T: IntoIterator<Item = NoteId>,
+    {
+        self.note_groups.push(notes.into_iter().collect());
+    }
+
+    /// Sets the inclusion hints of the transaction.
+    ///
+    /// The hints are carried over to the [ProvenTransaction](super::ProvenTransaction) created
+    /// from the transaction. The transaction executor rejects transactions whose inclusion
+    /// deadline is not after the reference block.
+    pub fn set_inclusion_hints(&mut self, inclusion_hints: InclusionHints) {
+        self.inclusion_hints = inclusion_hints;
+    }
+
+    /// Sets the instruction specifying how the details of the specified output note are delivered
+    /// once the transaction has been proven, replacing any previously set instruction for the note.
+    ///
+    /// The details of the note are recorded together with the instruction, so that the note can
+    /// be delivered even if its details are not provided to the transaction (e.g., for private
+    /// notes).
+    pub fn set_note_delivery(&mut self, note: &Note, instruction: NoteDeliveryInstruction) {
+        self.note_delivery.insert(note.id(), (note.clone(), instruction));
+    }
+
     /// Populates the advice inputs with the details of [Note]s.
     ///
     /// The map is extended with the following keys:
@@ -115,6 +308,32 @@ impl TransactionArgs {
         self.tx_script.as_ref()
     }
 
+    /// Returns the transaction script arguments.
+    pub fn tx_script_args(&self) -> Word {
+        self.tx_script_args
+    }
+
+    /// Returns the root of the selected transaction script entrypoint, if any.
+    pub fn tx_script_entrypoint(&self) -> Option<Digest> {
+        self.tx_script_entrypoint
+    }
+
+    /// Returns the maximum number of cycles a single note script may consume, if any.
+    pub fn note_cycle_budget(&self) -> Option<u32> {
+        self.note_cycle_budget
+    }
+
+    /// Returns true if notes created with the same metadata and recipient are merged into a
+    /// single note.
+    pub fn merge_output_notes(&self) -> bool {
+        self.merge_output_notes
+    }
+
+    /// Returns the asset in which the fee of the transaction is paid.
+    pub fn fee_payment(&self) -> &FeePayment {
+        &self.fee_payment
+    }
+
     /// Returns a reference to a specific note argument.
     pub fn get_note_args(&self, note_id: NoteId) -> Option<&Word> {
         self.note_args.get(&note_id)
@@ -124,6 +343,235 @@ impl TransactionArgs {
     pub fn advice_map(&self) -> &AdviceMap {
         &self.advice_map
     }
+
+    /// Returns a reference to the args [MerkleStore].
+    pub fn merkle_store(&self) -> &MerkleStore {
+        &self.merkle_store
+    }
+
+    /// Returns an iterator over the notes which must be processed before the note with the
+    /// specified ID.
+    pub fn note_dependencies(&self, note_id: NoteId) -> impl Iterator<Item = &NoteId> {
+        self.note_dependencies.get(&note_id).into_iter().flatten()
+    }
+
+    /// Returns the groups of notes which must be consumed all together or not at all.
+    pub fn note_groups(&self) -> &[BTreeSet<NoteId>] {
+        &self.note_groups
+    }
+
+    /// Returns the inclusion hints of the transaction.
+    pub fn inclusion_hints(&self) -> InclusionHints {
+        self.inclusion_hints
+    }
+
+    /// Returns the details and the delivery instruction of the output note with the specified ID,
+    /// if a delivery instruction was set for the note.
+    pub fn note_delivery(&self, note_id: NoteId) -> Option<(&Note, NoteDeliveryInstruction)> {
+        self.note_delivery.get(&note_id).map(|(note, instruction)| (note, *instruction))
+    }
+
+    /// Returns an iterator over the output notes with a delivery instruction, together with their
+    /// instructions.
+    pub fn note_delivery_instructions(
+        &self,
+    ) -> impl Iterator<Item = (&Note, NoteDeliveryInstruction)> {
+        self.note_delivery.values().map(|(note, instruction)| (note, *instruction))
+    }
+
+    /// Returns the post-conditions which must hold after the transaction has been executed.
+    pub fn post_conditions(&self) -> &[PostCondition] {
+        &self.post_conditions
+    }
+}
+
+impl Serializable for TransactionArgs {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.tx_script.write_into(target);
+        self.tx_script_args.write_into(target);
+        self.tx_script_entrypoint.write_into(target);
+
+        target.write_u16(self.note_args.len() as u16);
+        for (note_id, note_args) in self.note_args.iter() {
+            note_id.write_into(target);
+            note_args.write_into(target);
+        }
+
+        let advice_map = self.advice_map.clone().into_iter().collect::<Vec<_>>();
+        target.write_u32(advice_map.len() as u32);
+        for (key, values) in advice_map {
+            key.write_into(target);
+            target.write_u32(values.len() as u32);
+            target.write_many(values);
+        }
+        self.merkle_store.write_into(target);
+
+        target.write_u16(self.note_dependencies.len() as u16);
+        for (note_id, dependencies) in self.note_dependencies.iter() {
+            note_id.write_into(target);
+            target.write_u16(dependencies.len() as u16);
+            target.write_many(dependencies);
+        }
+
+        target.write_u16(self.note_groups.len() as u16);
+        for group in self.note_groups.iter() {
+            target.write_u16(group.len() as u16);
+            target.write_many(group);
+        }
+
+        self.inclusion_hints.write_into(target);
+
+        // the ID of a note is derived from the details of the note, and thus is not serialized
+        target.write_u16(self.note_delivery.len() as u16);
+        for (note, instruction) in self.note_delivery.values() {
+            note.write_into(target);
+            instruction.write_into(target);
+        }
+
+        target.write_u16(self.post_conditions.len() as u16);
+        target.write_many(&self.post_conditions);
+
+        self.note_cycle_budget.write_into(target);
+        target.write_bool(self.merge_output_notes);
+        self.fee_payment.write_into(target);
+    }
+}
+
+impl Deserializable for TransactionArgs {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let tx_script = <Option<TransactionScript>>::read_from(source)?;
+        let tx_script_args = Word::read_from(source)?;
+        let tx_script_entrypoint = <Option<Digest>>::read_from(source)?;
+
+        let num_note_args = source.read_u16()?;
+        let mut note_args = BTreeMap::new();
+        for _ in 0..num_note_args {
+            note_args.insert(NoteId::read_from(source)?, Word::read_from(source)?);
+        }
+
+        let num_map_entries = source.read_u32()?;
+        let mut advice_map = AdviceMap::default();
+        for _ in 0..num_map_entries {
+            let key = Digest::read_from(source)?;
+            let num_values = source.read_u32()? as usize;
+            advice_map.insert(key, source.read_many::<Felt>(num_values)?);
+        }
+        let merkle_store = MerkleStore::read_from(source)?;
+
+        let num_dependencies = source.read_u16()?;
+        let mut note_dependencies = BTreeMap::new();
+        for _ in 0..num_dependencies {
+            let note_id = NoteId::read_from(source)?;
+            let num_note_dependencies = source.read_u16()? as usize;
+            let dependencies = source.read_many::<NoteId>(num_note_dependencies)?;
+            note_dependencies.insert(note_id, dependencies.into_iter().collect());
+        }
+
+        let num_groups = source.read_u16()?;
+        let mut note_groups = Vec::with_capacity(num_groups as usize);
+        for _ in 0..num_groups {
+            let group_size = source.read_u16()? as usize;
+            note_groups.push(source.read_many::<NoteId>(group_size)?.into_iter().collect());
+        }
+
+        let inclusion_hints = InclusionHints::read_from(source)?;
+
+        let num_deliveries = source.read_u16()?;
+        let mut note_delivery = BTreeMap::new();
+        for _ in 0..num_deliveries {
+            let note = Note::read_from(source)?;
+            let instruction = NoteDeliveryInstruction::read_from(source)?;
+            note_delivery.insert(note.id(), (note, instruction));
+        }
+
+        let num_post_conditions = source.read_u16()? as usize;
+        let post_conditions = source.read_many::<PostCondition>(num_post_conditions)?;
+
+        let note_cycle_budget = <Option<u32>>::read_from(source)?;
+        let merge_output_notes = source.read_bool()?;
+        let fee_payment = FeePayment::read_from(source)?;
+
+        Ok(Self {
+            tx_script,
+            tx_script_args,
+            tx_script_entrypoint,
+            note_args,
+            advice_map,
+            merkle_store,
+            note_dependencies,
+            note_groups,
+            inclusion_hints,
+            note_delivery,
+            post_conditions,
+            note_cycle_budget,
+            merge_output_notes,
+            fee_payment,
+        })
+    }
+}
+
+// INCLUSION HINTS
+// ================================================================================================
+
+/// Preferences of the transaction submitter regarding the inclusion of a transaction into a block.
+///
+/// - Deadline: the number of the last block into which the transaction should be included. A
+///   sequencer honoring the hint discards the transaction once this block has been produced.
+/// - Priority fee: the fee offered by the submitter for the transaction to be included with
+///   priority, e.g., by a network executor consuming notes on behalf of their creators.
+///
+/// The hints are not committed to by the transaction proof, and thus do not affect the validity
+/// of a transaction; they are only meant to inform the ordering of transactions by a sequencer.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct InclusionHints {
+    deadline: Option<u32>,
+    priority_fee: u64,
+}
+
+impl InclusionHints {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns new [InclusionHints] instantiated with the provided deadline and priority fee.
+    pub fn new(deadline: Option<u32>, priority_fee: u64) -> Self {
+        Self { deadline, priority_fee }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the number of the last block into which the transaction should be included, if
+    /// any.
+    pub fn deadline(&self) -> Option<u32> {
+        self.deadline
+    }
+
+    /// Returns the priority fee offered for the inclusion of the transaction.
+    pub fn priority_fee(&self) -> u64 {
+        self.priority_fee
+    }
+
+    /// Returns true if the deadline has passed by the time the block with the specified number is
+    /// produced, i.e., if the transaction should not be included into that block.
+    pub fn is_expired(&self, block_num: u32) -> bool {
+        self.deadline.is_some_and(|deadline| block_num > deadline)
+    }
+}
+
+impl Serializable for InclusionHints {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.deadline.write_into(target);
+        self.priority_fee.write_into(target);
+    }
+}
+
+impl Deserializable for InclusionHints {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let deadline = <Option<u32>>::read_from(source)?;
+        let priority_fee = u64::read_from(source)?;
+
+        Ok(Self { deadline, priority_fee })
+    }
 }
 
 // TRANSACTION SCRIPT
@@ -132,18 +580,22 @@ impl TransactionArgs {
 /// A struct that represents a transaction script.
 ///
 /// A transaction script is a program that is executed in a transaction after all input notes
-/// have been executed.
+/// have been executed. A transaction script may have multiple entrypoints, in which case the
+/// entrypoint to execute is selected via [TransactionArgs::set_tx_script_entrypoint()].
 ///
 /// The [TransactionScript] object is composed of:
 /// - [code](TransactionScript::code): the transaction script source code.
 /// - [hash](TransactionScript::hash): the hash of the compiled transaction script.
 /// - [inputs](TransactionScript::inputs): a map of key, value inputs that are loaded into the
 ///   advice map such that the transaction script can access them.
+/// - [entrypoints](TransactionScript::entrypoints): the roots of the entrypoints of the script;
+///   empty if the script has a single entrypoint.
 #[derive(Clone, Debug)]
 pub struct TransactionScript {
     code: ProgramAst,
     hash: Digest,
     inputs: BTreeMap<Digest, Vec<Felt>>,
+    entrypoints: Vec<Digest>,
 }
 
 impl TransactionScript {
@@ -168,11 +620,73 @@ impl TransactionScript {
                 code,
                 hash: code_block.hash(),
                 inputs: inputs.into_iter().map(|(k, v)| (k.into(), v)).collect(),
+                entrypoints: Vec::new(),
             },
             code_block,
         ))
     }
 
+    /// Returns a new instance of a [TransactionScript] with multiple entrypoints together with
+    /// the compiled code blocks of the entrypoints.
+    ///
+    /// The entrypoints are the procedures exported by the provided module, in the order in which
+    /// they are declared; procedures which are not exported can be used by the entrypoints as
+    /// helpers. The compiled script dispatches to the entrypoint selected via
+    /// [TransactionArgs::set_tx_script_entrypoint()] and fails if no valid entrypoint is
+    /// selected. The entrypoint is executed with the stack [ENTRYPOINT, TX_SCRIPT_ARGS], where
+    /// ENTRYPOINT is the root of the entrypoint.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The module does not export any procedures.
+    /// - The module re-exports procedures of other modules.
+    /// - Script compilation fails.
+    pub fn with_entrypoints<T: IntoIterator<Item = (Word, Vec<Felt>)>>(
+        code: &ModuleAst,
+        inputs: T,
+        assembler: &Assembler,
+    ) -> Result<(Self, Vec<CodeBlock>), TransactionScriptError> {
+        if !code.reexported_procs().is_empty() {
+            return Err(TransactionScriptError::ReexportedEntrypoints);
+        }
+
+        let entrypoints = assembler
+            .compile_module(code, None, &mut AssemblyContext::for_module(false))
+            .map_err(TransactionScriptError::ScriptCompilationError)?;
+        if entrypoints.is_empty() {
+            return Err(TransactionScriptError::NoEntrypoints);
+        }
+
+        // a program can contain only local procedures, so the exported procedures are turned
+        // into local procedures which the program body dispatches to
+        let mut procs = code.procs().to_vec();
+        let mut entrypoint_indices = Vec::new();
+        for (idx, proc) in procs.iter_mut().enumerate() {
+            if proc.is_export {
+                proc.is_export = false;
+                entrypoint_indices.push(idx as u16);
+            }
+        }
+
+        // compile each entrypoint on its own such that the compatibility of the entrypoints
+        // with account interfaces can be checked
+        let mut code_blocks = Vec::with_capacity(entrypoint_indices.len());
+        for &idx in entrypoint_indices.iter() {
+            let body = vec![Node::Instruction(Instruction::ExecLocal(idx))];
+            let program = build_program(body, procs.clone(), code)?;
+            let code_block = assembler
+                .compile_in_context(&program, &mut AssemblyContext::for_program(Some(&program)))
+                .map_err(TransactionScriptError::ScriptCompilationError)?;
+            code_blocks.push(code_block);
+        }
+
+        let program = build_program(build_dispatcher(&entrypoint_indices), procs, code)?;
+        let (mut tx_script, _) = Self::new(program, inputs, assembler)?;
+        tx_script.entrypoints = entrypoints;
+
+        Ok((tx_script, code_blocks))
+    }
+
     /// Returns a new instance of a [TransactionScript] instantiated from the provided components.
     ///
     /// Note: this constructor does not verify that a compiled code in fact results in the provided
@@ -186,6 +700,7 @@ impl TransactionScript {
             code,
             hash,
             inputs: inputs.into_iter().map(|(k, v)| (k.into(), v)).collect(),
+            entrypoints: Vec::new(),
         })
     }
 
@@ -206,4 +721,101 @@ impl TransactionScript {
     pub fn inputs(&self) -> &BTreeMap<Digest, Vec<Felt>> {
         &self.inputs
     }
+
+    /// Returns the roots of the entrypoints of the script, or an empty slice if the script has a
+    /// single entrypoint.
+    pub fn entrypoints(&self) -> &[Digest] {
+        &self.entrypoints
+    }
+}
+
+impl Serializable for TransactionScript {
+    fn write_into<W: ByteWriter>(&self, target: &mut W) {
+        self.code.write_into(target, CODE_SERDE_OPTIONS);
+        self.hash.write_into(target);
+
+        target.write_u32(self.inputs.len() as u32);
+        for (key, values) in self.inputs.iter() {
+            key.write_into(target);
+            target.write_u32(values.len() as u32);
+            target.write_many(values);
+        }
+
+        target.write_u16(self.entrypoints.len() as u16);
+        target.write_many(&self.entrypoints);
+    }
+}
+
+impl Deserializable for TransactionScript {
+    fn read_from<R: ByteReader>(source: &mut R) -> Result<Self, DeserializationError> {
+        let code = ProgramAst::read_from(source)?;
+        let hash = Digest::read_from(source)?;
+
+        let num_inputs = source.read_u32()?;
+        let mut inputs = BTreeMap::new();
+        for _ in 0..num_inputs {
+            let key = Digest::read_from(source)?;
+            let num_values = source.read_u32()? as usize;
+            inputs.insert(key, source.read_many::<Felt>(num_values)?);
+        }
+
+        let num_entrypoints = source.read_u16()? as usize;
+        let entrypoints = source.read_many::<Digest>(num_entrypoints)?;
+
+        Ok(Self { code, hash, inputs, entrypoints })
+    }
+}
+
+// HELPERS
+// ================================================================================================
+
+/// Returns a program with the provided body and local procedures, and the imports of the provided
+/// module.
+fn build_program(
+    body: Vec<Node>,
+    procs: Vec<ProcedureAst>,
+    module: &ModuleAst,
+) -> Result<ProgramAst, TransactionScriptError> {
+    let program = ProgramAst::new(body, procs)
+        .map_err(|err| TransactionScriptError::ScriptCompilationError(err.into()))?;
+    Ok(program.with_import_info(module.import_info().clone()))
+}
+
+/// Returns the body of a transaction script which dispatches to the selected entrypoint.
+///
+/// The kernel executes the transaction script with the stack [TX_SCRIPT_ROOT, TX_SCRIPT_ARGS,
+/// ENTRYPOINT]. The body asserts that ENTRYPOINT is the root of one of the local procedures at
+/// the specified indices, and executes it with the stack [ENTRYPOINT, TX_SCRIPT_ARGS].
+fn build_dispatcher(entrypoint_indices: &[u16]) -> Vec<Node> {
+    // => [TX_SCRIPT_ROOT, TX_SCRIPT_ARGS, ENTRYPOINT]
+    let mut body = vec![
+        Instruction::DropW,
+        Instruction::SwapW1,
+        Instruction::PushU8(0),
+        Instruction::MovDn4,
+        // => [ENTRYPOINT, is_known, TX_SCRIPT_ARGS]
+    ];
+
+    for &idx in entrypoint_indices {
+        body.extend([
+            Instruction::ProcRefLocal(idx),
+            Instruction::Eqw,
+            Instruction::MovDn4,
+            Instruction::DropW,
+            // => [is_entrypoint, ENTRYPOINT, is_known, TX_SCRIPT_ARGS]
+            Instruction::MovUp5,
+            Instruction::Or,
+            Instruction::MovDn4,
+            // => [ENTRYPOINT, is_known, TX_SCRIPT_ARGS]
+        ]);
+    }
+
+    body.extend([
+        Instruction::MovUp4,
+        Instruction::Assert,
+        // => [ENTRYPOINT, TX_SCRIPT_ARGS]
+        Instruction::DynCall,
+    ]);
+
+    body.into_iter().map(Node::Instruction).collect()
 }
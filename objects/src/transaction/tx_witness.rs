@@ -85,4 +85,12 @@ impl TransactionWitness {
     pub fn advice_witness(&self) -> &AdviceInputs {
         &self.advice_witness
     }
+
+    // CONVERSIONS
+    // --------------------------------------------------------------------------------------------
+
+    /// Consumes this transaction witness and returns its underlying components.
+    pub fn into_parts(self) -> (Program, TransactionInputs, TransactionArgs, AdviceInputs) {
+        (self.program, self.tx_inputs, self.tx_args, self.advice_witness)
+    }
 }
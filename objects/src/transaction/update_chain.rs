@@ -0,0 +1,341 @@
+use alloc::vec::Vec;
+
+use super::{AccountDetails, AccountId, Digest, Felt, ProvenTransaction, TransactionId};
+use crate::AccountUpdateChainError;
+
+// ACCOUNT UPDATE
+// ================================================================================================
+
+/// Describes how a single transaction changed the state of an account.
+///
+/// The nonce is the account nonce after the transaction was executed. It is known only when the
+/// transaction carries the details of the account state (i.e., for on-chain accounts), and only
+/// if the transaction changed the nonce.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AccountUpdate {
+    tx_id: TransactionId,
+    account_id: AccountId,
+    initial_hash: Digest,
+    final_hash: Digest,
+    nonce: Option<Felt>,
+}
+
+impl AccountUpdate {
+    /// Returns a new [AccountUpdate] instantiated from the specified parameters.
+    pub fn new(
+        tx_id: TransactionId,
+        account_id: AccountId,
+        initial_hash: Digest,
+        final_hash: Digest,
+        nonce: Option<Felt>,
+    ) -> Self {
+        Self {
+            tx_id,
+            account_id,
+            initial_hash,
+            final_hash,
+            nonce,
+        }
+    }
+
+    /// Returns the ID of the transaction which performed this update.
+    pub fn tx_id(&self) -> TransactionId {
+        self.tx_id
+    }
+
+    /// Returns the ID of the updated account.
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Returns the hash of the account state before the update.
+    pub fn initial_hash(&self) -> Digest {
+        self.initial_hash
+    }
+
+    /// Returns the hash of the account state after the update.
+    pub fn final_hash(&self) -> Digest {
+        self.final_hash
+    }
+
+    /// Returns the account nonce after the update, if known.
+    pub fn nonce(&self) -> Option<Felt> {
+        self.nonce
+    }
+}
+
+impl From<&ProvenTransaction> for AccountUpdate {
+    fn from(tx: &ProvenTransaction) -> Self {
+        let nonce = match tx.account_details() {
+            Some(AccountDetails::Full(account)) => Some(account.nonce()),
+            Some(AccountDetails::Delta(delta)) => delta.nonce(),
+            None => None,
+        };
+
+        Self::new(
+            tx.id(),
+            tx.account_id(),
+            tx.initial_account_hash(),
+            tx.final_account_hash(),
+            nonce,
+        )
+    }
+}
+
+// ACCOUNT UPDATE CHAIN
+// ================================================================================================
+
+/// A sequence of updates of a single account, where each update starts from the account state
+/// produced by the previous one.
+///
+/// The chain starts from a known account state (e.g., the state committed to by the latest block)
+/// and is extended one update at a time. An update is accepted only if:
+/// - It was executed against the account of the chain.
+/// - It was not already added to the chain.
+/// - It starts from the latest state of the chain. An update starting from an earlier state of
+///   the chain conflicts with the update which already consumed that state (i.e., the account
+///   forked), while an update starting from an unknown state skips over missing updates (i.e.,
+///   there is a gap in the chain).
+/// - Its nonce, if known, is greater than the latest known nonce of the chain.
+///
+/// Nonces of off-chain accounts are not known, in which case the latest known nonce serves as a
+/// lower bound for the nonces of subsequent updates.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AccountUpdateChain {
+    account_id: AccountId,
+    states: Vec<Digest>,
+    nonce: Option<Felt>,
+    transactions: Vec<TransactionId>,
+}
+
+impl AccountUpdateChain {
+    // CONSTRUCTOR
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns a new [AccountUpdateChain] starting from the specified account state.
+    ///
+    /// For new accounts, the initial hash must be set to `Digest::default()` and the nonce to
+    /// `None`.
+    pub fn new(account_id: AccountId, initial_hash: Digest, nonce: Option<Felt>) -> Self {
+        Self {
+            account_id,
+            states: vec![initial_hash],
+            nonce,
+            transactions: Vec::new(),
+        }
+    }
+
+    // PUBLIC ACCESSORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Returns the ID of the account updated by this chain.
+    pub fn account_id(&self) -> AccountId {
+        self.account_id
+    }
+
+    /// Returns the hash of the account state from which this chain starts.
+    pub fn initial_hash(&self) -> Digest {
+        self.states[0]
+    }
+
+    /// Returns the hash of the account state after all updates in this chain.
+    pub fn final_hash(&self) -> Digest {
+        *self.states.last().expect("chain always contains the initial state")
+    }
+
+    /// Returns the latest known account nonce.
+    pub fn nonce(&self) -> Option<Felt> {
+        self.nonce
+    }
+
+    /// Returns the IDs of the transactions in this chain, in the order of execution.
+    pub fn transactions(&self) -> &[TransactionId] {
+        &self.transactions
+    }
+
+    /// Returns the number of updates in this chain.
+    pub fn num_updates(&self) -> usize {
+        self.transactions.len()
+    }
+
+    /// Returns true if this chain does not contain any updates.
+    pub fn is_empty(&self) -> bool {
+        self.transactions.is_empty()
+    }
+
+    // STATE MUTATORS
+    // --------------------------------------------------------------------------------------------
+
+    /// Appends the account update performed by the specified transaction to this chain.
+    ///
+    /// # Errors
+    /// Returns an error if the update cannot be appended to this chain (see
+    /// [AccountUpdateChain::add_update()]).
+    pub fn add_transaction(
+        &mut self,
+        tx: &ProvenTransaction,
+    ) -> Result<(), AccountUpdateChainError> {
+        self.add_update(AccountUpdate::from(tx))
+    }
+
+    /// Appends the specified account update to this chain.
+    ///
+    /// # Errors
+    /// Returns an error if:
+    /// - The update was performed against an account other than the account of this chain.
+    /// - The transaction which performed the update is already in this chain.
+    /// - The update starts from an earlier state of this chain which was already consumed by
+    ///   another update.
+    /// - The update starts from a state which is not in this chain.
+    /// - The nonce of the update is not greater than the latest known nonce of this chain.
+    pub fn add_update(&mut self, update: AccountUpdate) -> Result<(), AccountUpdateChainError> {
+        if update.account_id() != self.account_id {
+            return Err(AccountUpdateChainError::AccountIdMismatch {
+                expected: self.account_id,
+                actual: update.account_id(),
+            });
+        }
+
+        if self.transactions.contains(&update.tx_id()) {
+            return Err(AccountUpdateChainError::DuplicateTransaction(update.tx_id()));
+        }
+
+        if update.initial_hash() != self.final_hash() {
+            // the state at index i of the chain was consumed by the update at index i
+            let consumed_states = &self.states[..self.transactions.len()];
+            return match consumed_states.iter().position(|&state| state == update.initial_hash()) {
+                Some(idx) => Err(AccountUpdateChainError::Fork {
+                    tx_id: update.tx_id(),
+                    conflicting_tx_id: self.transactions[idx],
+                }),
+                None => Err(AccountUpdateChainError::Gap {
+                    expected: self.final_hash(),
+                    actual: update.initial_hash(),
+                }),
+            };
+        }
+
+        if let (Some(previous), Some(actual)) = (self.nonce, update.nonce()) {
+            if actual.as_int() <= previous.as_int() {
+                return Err(AccountUpdateChainError::NonceNotIncreasing { previous, actual });
+            }
+        }
+
+        if update.nonce().is_some() {
+            self.nonce = update.nonce();
+        }
+        self.states.push(update.final_hash());
+        self.transactions.push(update.tx_id());
+
+        Ok(())
+    }
+}
+
+// TESTS
+// ================================================================================================
+
+#[cfg(test)]
+mod tests {
+    use super::{AccountUpdate, AccountUpdateChain};
+    use crate::{
+        accounts::{
+            AccountId, ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN, ACCOUNT_ID_SENDER,
+        },
+        transaction::TransactionId,
+        AccountUpdateChainError, Digest, Felt, ZERO,
+    };
+
+    fn state(value: u64) -> Digest {
+        [Felt::new(value), ZERO, ZERO, ZERO].into()
+    }
+
+    fn update(initial: u64, final_state: u64, nonce: Option<u64>) -> AccountUpdate {
+        let account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        let tx_id =
+            TransactionId::new(state(initial), state(final_state), state(0), state(0), state(0));
+        AccountUpdate::new(
+            tx_id,
+            account_id,
+            state(initial),
+            state(final_state),
+            nonce.map(Felt::new),
+        )
+    }
+
+    fn chain() -> AccountUpdateChain {
+        let account_id = AccountId::try_from(ACCOUNT_ID_SENDER).unwrap();
+        AccountUpdateChain::new(account_id, state(1), Some(Felt::new(1)))
+    }
+
+    #[test]
+    fn account_update_chain_links_updates() {
+        let mut chain = chain();
+        chain.add_update(update(1, 2, Some(2))).unwrap();
+        chain.add_update(update(2, 3, None)).unwrap();
+        chain.add_update(update(3, 4, Some(5))).unwrap();
+
+        assert_eq!(chain.num_updates(), 3);
+        assert_eq!(chain.initial_hash(), state(1));
+        assert_eq!(chain.final_hash(), state(4));
+        assert_eq!(chain.nonce(), Some(Felt::new(5)));
+        assert_eq!(chain.transactions()[1], update(2, 3, None).tx_id());
+    }
+
+    #[test]
+    fn account_update_chain_detects_forks_and_gaps() {
+        let mut chain = chain();
+        let first = update(1, 2, Some(2));
+        chain.add_update(first).unwrap();
+        chain.add_update(update(2, 3, Some(3))).unwrap();
+
+        assert_eq!(
+            chain.add_update(update(1, 5, Some(4))),
+            Err(AccountUpdateChainError::Fork {
+                tx_id: update(1, 5, Some(4)).tx_id(),
+                conflicting_tx_id: first.tx_id(),
+            })
+        );
+        assert_eq!(
+            chain.add_update(update(7, 8, Some(4))),
+            Err(AccountUpdateChainError::Gap { expected: state(3), actual: state(7) })
+        );
+        assert_eq!(
+            chain.add_update(first),
+            Err(AccountUpdateChainError::DuplicateTransaction(first.tx_id()))
+        );
+        assert_eq!(chain.final_hash(), state(3));
+    }
+
+    #[test]
+    fn account_update_chain_rejects_invalid_updates() {
+        let mut chain = chain();
+        chain.add_update(update(1, 2, None)).unwrap();
+
+        // the nonce of the last update is unknown, so the initial nonce is used as a lower bound
+        assert_eq!(
+            chain.add_update(update(2, 3, Some(1))),
+            Err(AccountUpdateChainError::NonceNotIncreasing {
+                previous: Felt::new(1),
+                actual: Felt::new(1)
+            })
+        );
+
+        let other_id =
+            AccountId::try_from(ACCOUNT_ID_REGULAR_ACCOUNT_UPDATABLE_CODE_ON_CHAIN).unwrap();
+        let other = update(2, 3, Some(2));
+        let other = AccountUpdate::new(
+            other.tx_id(),
+            other_id,
+            other.initial_hash(),
+            other.final_hash(),
+            other.nonce(),
+        );
+        assert_eq!(
+            chain.add_update(other),
+            Err(AccountUpdateChainError::AccountIdMismatch {
+                expected: chain.account_id(),
+                actual: other_id
+            })
+        );
+    }
+}